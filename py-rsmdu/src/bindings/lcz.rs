@@ -1,10 +1,27 @@
+use rsmdu_core::export::VectorFormat;
 use rsmdu_core::geometric::lcz::Lcz;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use std::path::Path;
 
 use crate::bindings::geo_core::PyGeoCore;
 
+/// Parse an OGR-style format name (`"gpkg"`, `"shapefile"`/`"shp"`, `"geojson"`, `"flatgeobuf"`/
+/// `"fgb"`, case-insensitive) into a [`VectorFormat`] for `to_file`.
+fn parse_vector_format(format: &str) -> PyResult<VectorFormat> {
+    match format.to_lowercase().as_str() {
+        "gpkg" | "geopackage" => Ok(VectorFormat::GeoPackage),
+        "shp" | "shapefile" => Ok(VectorFormat::Shapefile),
+        "geojson" | "json" => Ok(VectorFormat::GeoJson),
+        "fgb" | "flatgeobuf" => Ok(VectorFormat::FlatGeobuf),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported format {:?}; expected one of gpkg, shapefile, geojson, flatgeobuf",
+            other
+        ))),
+    }
+}
+
 /// Lcz Python binding
 #[pyclass]
 pub struct PyLcz {
@@ -73,6 +90,25 @@ impl PyLcz {
         self.inner.get_output_path().to_string_lossy().to_string()
     }
 
+    /// Write a self-contained Leaflet HTML map of the LCZ classification, colored and
+    /// legended from `table_color`, with a tooltip per feature and a mouseover highlight.
+    #[pyo3(signature = (path, tooltip_fields = None))]
+    fn to_html(&self, path: &str, tooltip_fields: Option<Vec<String>>) -> PyResult<()> {
+        self.inner
+            .to_html(Path::new(path), &tooltip_fields.unwrap_or_default())
+            .map_err(|e| PyValueError::new_err(format!("Failed to write HTML map: {}", e)))
+    }
+
+    /// Export classified LCZ polygons to `path` in `format` ("gpkg", "shapefile", "geojson",
+    /// or "flatgeobuf"), honoring `geo_core`'s EPSG.
+    #[pyo3(signature = (path, format, layer_name = None))]
+    fn to_file(&self, path: &str, format: &str, layer_name: Option<&str>) -> PyResult<()> {
+        let format = parse_vector_format(format)?;
+        self.inner
+            .to_file(Path::new(path), format, layer_name)
+            .map_err(|e| PyValueError::new_err(format!("Failed to export LCZ polygons: {}", e)))
+    }
+
     #[getter]
     fn geo_core(&self) -> PyGeoCore {
         PyGeoCore {
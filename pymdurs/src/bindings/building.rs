@@ -1,8 +1,40 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDict, PyFloat, PyList};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyString};
+use rsmdu::export::{GeoParquetCompression, VectorFormat};
 use rsmdu::geometric::building::BuildingCollection;
 
+/// Parse an OGR-style format name (`"gpkg"`, `"shapefile"`/`"shp"`, `"geojson"`, `"flatgeobuf"`/
+/// `"fgb"`, case-insensitive) into a [`VectorFormat`] for `to_file`.
+fn parse_vector_format(format: &str) -> PyResult<VectorFormat> {
+    match format.to_lowercase().as_str() {
+        "gpkg" | "geopackage" => Ok(VectorFormat::GeoPackage),
+        "shp" | "shapefile" => Ok(VectorFormat::Shapefile),
+        "geojson" | "json" => Ok(VectorFormat::GeoJson),
+        "fgb" | "flatgeobuf" => Ok(VectorFormat::FlatGeobuf),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported format {:?}; expected one of gpkg, shapefile, geojson, flatgeobuf",
+            other
+        ))),
+    }
+}
+
+/// Parse a Parquet compression codec name (`"snappy"`, `"gzip"`, `"brotli"`, `"zstd"`, or
+/// `"uncompressed"`, case-insensitive) into a [`GeoParquetCompression`] for `to_geoparquet`.
+fn parse_geoparquet_compression(compression: &str) -> PyResult<GeoParquetCompression> {
+    match compression.to_lowercase().as_str() {
+        "snappy" => Ok(GeoParquetCompression::Snappy),
+        "gzip" => Ok(GeoParquetCompression::Gzip),
+        "brotli" => Ok(GeoParquetCompression::Brotli),
+        "zstd" => Ok(GeoParquetCompression::Zstd),
+        "uncompressed" => Ok(GeoParquetCompression::Uncompressed),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported compression {:?}; expected one of snappy, gzip, brotli, zstd, uncompressed",
+            other
+        ))),
+    }
+}
+
 use crate::bindings::bounding_box::PyBoundingBox;
 use crate::bindings::geo_core::PyGeoCore;
 
@@ -23,6 +55,14 @@ fn vec_bool_to_pylist<'a>(py: Python<'a>, vec: &[bool]) -> PyResult<pyo3::Bound<
     Ok(list)
 }
 
+fn vec_string_to_pylist<'a>(py: Python<'a>, vec: &[String]) -> PyResult<pyo3::Bound<'a, PyList>> {
+    let list = PyList::empty(py);
+    for v in vec {
+        list.append(PyString::new(py, v))?;
+    }
+    Ok(list)
+}
+
 fn option_vec_f64_to_pylist<'a>(
     py: Python<'a>,
     vec: &[Option<f64>],
@@ -83,6 +123,214 @@ impl PyBuilding {
         self.inner.len()
     }
 
+    /// Indices of every building whose envelope intersects the given axis-aligned box, via the
+    /// collection's lazily-built R-tree spatial index
+    fn query_bbox(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        self.inner.query_bbox(min_x, min_y, max_x, max_y)
+    }
+
+    /// Indices of the `k` buildings whose envelope is nearest to `(x, y)`, nearest first
+    fn nearest(&self, x: f64, y: f64, k: usize) -> Vec<usize> {
+        self.inner.nearest([x, y], k)
+    }
+
+    /// Indices of every building whose envelope lies within `radius` of `(x, y)` (same units
+    /// as the collection's CRS), nearest first
+    fn within_distance(&self, x: f64, y: f64, radius: f64) -> Vec<usize> {
+        self.inner.within_distance([x, y], radius)
+    }
+
+    /// Indices of every building whose centroid lies within `meters` of `(x, y)` (given in the
+    /// collection's own CRS), nearest first. Unlike `within_distance`, this reprojects into
+    /// `metric_epsg` first so `meters` means meters regardless of the collection's own CRS.
+    fn within_radius(&self, x: f64, y: f64, meters: f64, metric_epsg: i32) -> PyResult<Vec<usize>> {
+        self.inner
+            .within_radius([x, y], meters, metric_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to compute within_radius: {}", e)))
+    }
+
+    /// Export buildings to `path` in `format` ("gpkg", "shapefile", "geojson", or
+    /// "flatgeobuf"), honoring `geo_core`'s EPSG.
+    #[pyo3(signature = (path, format, layer_name = None))]
+    fn to_file(&self, path: &str, format: &str, layer_name: Option<&str>) -> PyResult<()> {
+        let format = parse_vector_format(format)?;
+        self.inner
+            .to_file(path, layer_name, format)
+            .map_err(|e| PyValueError::new_err(format!("Failed to export buildings: {}", e)))
+    }
+
+    /// Export buildings to GeoParquet, with `compression` ("snappy", "gzip", "brotli", "zstd",
+    /// or "uncompressed") selecting the Parquet codec. Honors `geo_core`'s EPSG like `to_file`.
+    #[pyo3(signature = (path, compression = "snappy"))]
+    fn to_geoparquet(&self, path: &str, compression: &str) -> PyResult<()> {
+        let compression = parse_geoparquet_compression(compression)?;
+        self.inner
+            .to_geoparquet(path, compression)
+            .map_err(|e| PyValueError::new_err(format!("Failed to export buildings as GeoParquet: {}", e)))
+    }
+
+    /// Reproject every building footprint in place from `geo_core`'s current EPSG to `to_epsg`,
+    /// recomputing `area`/`centroid`.
+    fn reproject(&mut self, to_epsg: i32) -> PyResult<()> {
+        self.inner
+            .reproject(to_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject: {}", e)))
+    }
+
+    /// Reproject back to EPSG:4326 (WGS84 lat/long).
+    fn to_latlong(&mut self) -> PyResult<()> {
+        self.inner
+            .to_latlong()
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject to lat/long: {}", e)))
+    }
+
+    /// Per-building `(area, perimeter)` on the WGS84 ellipsoid via Vincenty's inverse formula,
+    /// in that order -- area in square meters (spherical-excess formula on the WGS84 authalic
+    /// sphere), perimeter in meters. Unlike the planar `area`/`centroid` fields, this works
+    /// regardless of `geo_core`'s current EPSG: footprints are reprojected to EPSG:4326 on a
+    /// scratch copy first.
+    fn geodesic_measurements(&self) -> PyResult<Vec<(f64, f64)>> {
+        self.inner
+            .geodesic_measurements()
+            .map(|measurements| {
+                measurements
+                    .into_iter()
+                    .map(|m| (m.area, m.perimeter))
+                    .collect()
+            })
+            .map_err(|e| {
+                PyValueError::new_err(format!("Failed to compute geodesic measurements: {}", e))
+            })
+    }
+
+    /// Select a subset of buildings by a SQL-like WHERE expression over fields (`"hauteur > 10
+    /// AND nombre_d_etages IS NOT NULL"`) and/or a spatial intersection (`bbox` as
+    /// `(min_x, min_y, max_x, max_y)`, or `intersects_geojson` as a Polygon GeoJSON string),
+    /// returning a new `Building` collection.
+    #[pyo3(signature = (where_expr = None, bbox = None, intersects_geojson = None))]
+    fn filter(
+        &self,
+        where_expr: Option<&str>,
+        bbox: Option<(f64, f64, f64, f64)>,
+        intersects_geojson: Option<&str>,
+    ) -> PyResult<PyBuilding> {
+        let polygon_from_bbox = bbox.map(|(min_x, min_y, max_x, max_y)| {
+            geo::Rect::new((min_x, min_y), (max_x, max_y)).to_polygon()
+        });
+        let polygon_from_geojson = intersects_geojson
+            .map(|s| -> PyResult<geo::Polygon<f64>> {
+                let geojson: geojson::GeoJson = s
+                    .parse()
+                    .map_err(|e| PyValueError::new_err(format!("Invalid intersects GeoJSON: {}", e)))?;
+                let geometry = match &geojson {
+                    geojson::GeoJson::Geometry(g) => g.clone(),
+                    geojson::GeoJson::Feature(f) => f
+                        .geometry
+                        .clone()
+                        .ok_or_else(|| PyValueError::new_err("Feature has no geometry"))?,
+                    geojson::GeoJson::FeatureCollection(fc) => fc
+                        .features
+                        .first()
+                        .and_then(|f| f.geometry.clone())
+                        .ok_or_else(|| PyValueError::new_err("FeatureCollection has no features"))?,
+                };
+                let geo_geom: geo::Geometry<f64> = (&geometry).try_into().map_err(|e| {
+                    PyValueError::new_err(format!("Invalid intersects geometry: {}", e))
+                })?;
+                match geo_geom {
+                    geo::Geometry::Polygon(p) => Ok(p),
+                    _ => Err(PyValueError::new_err("intersects geometry must be a Polygon")),
+                }
+            })
+            .transpose()?;
+
+        let polygon = polygon_from_geojson.or(polygon_from_bbox);
+
+        self.inner
+            .filter(where_expr, polygon.as_ref())
+            .map(|collection| PyBuilding { inner: collection })
+            .map_err(|e| PyValueError::new_err(format!("Failed to filter buildings: {}", e)))
+    }
+
+    /// Buildings whose footprint is fully contained within `(min_x, min_y, max_x, max_y)`
+    /// (same units as `geo_core`'s CRS), as a new `Building` collection.
+    fn within(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> PyBuilding {
+        let bbox = geo::Rect::new((min_x, min_y), (max_x, max_y));
+        PyBuilding {
+            inner: self.inner.within(bbox),
+        }
+    }
+
+    /// Buildings whose footprint contains `(x, y)` (same units as `geo_core`'s CRS), as a new
+    /// `Building` collection.
+    fn contains(&self, x: f64, y: f64) -> PyBuilding {
+        PyBuilding {
+            inner: self.inner.contains(geo::Point::new(x, y)),
+        }
+    }
+
+    /// Buildings whose footprint intersects the Polygon GeoJSON in `geojson`, as a new
+    /// `Building` collection.
+    fn intersects(&self, geojson: &str) -> PyResult<PyBuilding> {
+        let parsed: geojson::GeoJson = geojson
+            .parse()
+            .map_err(|e| PyValueError::new_err(format!("Invalid intersects GeoJSON: {}", e)))?;
+        let geometry = match &parsed {
+            geojson::GeoJson::Geometry(g) => g.clone(),
+            geojson::GeoJson::Feature(f) => f
+                .geometry
+                .clone()
+                .ok_or_else(|| PyValueError::new_err("Feature has no geometry"))?,
+            geojson::GeoJson::FeatureCollection(fc) => fc
+                .features
+                .first()
+                .and_then(|f| f.geometry.clone())
+                .ok_or_else(|| PyValueError::new_err("FeatureCollection has no features"))?,
+        };
+        let geo_geom: geo::Geometry<f64> = (&geometry)
+            .try_into()
+            .map_err(|e| PyValueError::new_err(format!("Invalid intersects geometry: {}", e)))?;
+        let polygon = match geo_geom {
+            geo::Geometry::Polygon(p) => p,
+            _ => return Err(PyValueError::new_err("intersects geometry must be a Polygon")),
+        };
+        Ok(PyBuilding {
+            inner: self.inner.intersects(&polygon),
+        })
+    }
+
+    /// Buildings whose footprint intersects the Polygon GeoJSON in `mask`, as a new `Building`
+    /// collection -- an alias for `intersects` for clipping a downloaded collection to an
+    /// administrative boundary.
+    fn subset_by_polygon(&self, mask: &str) -> PyResult<PyBuilding> {
+        self.intersects(mask)
+    }
+
+    /// Spatially join each building to its containing IRIS polygon (a FeatureCollection GeoJSON
+    /// string, e.g. from `Iris.get_geojson()`) and copy the named `indicators` properties onto
+    /// it, surfaced afterwards as columns by `to_dataframe`. Returns the number of buildings
+    /// enriched.
+    fn enrich_from_iris(&mut self, iris_geojson: &str, indicators: Vec<String>) -> PyResult<usize> {
+        let parsed: geojson::GeoJson = iris_geojson
+            .parse()
+            .map_err(|e| PyValueError::new_err(format!("Invalid IRIS GeoJSON: {}", e)))?;
+        let indicator_refs: Vec<&str> = indicators.iter().map(|s| s.as_str()).collect();
+        self.inner
+            .enrich_from_iris(&parsed, &indicator_refs)
+            .map_err(|e| PyValueError::new_err(format!("Failed to enrich from IRIS: {}", e)))
+    }
+
+    /// Open Location Code (Plus Code) for every building's centroid, e.g. `"8FW4V75V+8Q"`.
+    /// `length` is the digit count before/after the `'+'` combined (default 10, ~14m precision).
+    #[pyo3(signature = (length = 10))]
+    fn plus_codes(&self, length: u8) -> Vec<String> {
+        self.inner
+            .buildings
+            .iter()
+            .map(|b| b.plus_code(length))
+            .collect()
+    }
+
     /// Get GeoCore instance
     #[getter]
     fn geo_core(&self) -> PyGeoCore {
@@ -144,19 +392,174 @@ impl PyBuilding {
         }
     }
 
-    /// Load from GeoJSON
+    /// Grid the collection's bounding rect into `grid_resolution` x `grid_resolution` cells and
+    /// return the per-cell urban-canopy morphology indicators (lambda_p, lambda_f, mean/stddev
+    /// height, surface-to-plan-area ratio, approximate sky-view factor) as a pandas DataFrame,
+    /// one row per non-empty cell. `wind_dirs` defaults to `[0.0]`; only the first direction's
+    /// lambda_f is reported per row (call `compute_morphology` for every azimuth's breakdown).
+    #[pyo3(signature = (grid_resolution, wind_dirs = None))]
+    fn morphology_pandas(&self, py: Python, grid_resolution: f64, wind_dirs: Option<Vec<f64>>) -> PyResult<Py<PyAny>> {
+        let wind_dirs = wind_dirs.unwrap_or_else(|| vec![0.0]);
+        match self.inner.morphology_to_polars_df(grid_resolution, &wind_dirs) {
+            Ok(_df) => {
+                let pandas = py.import("pandas")?;
+                let cells = self.inner.compute_morphology(grid_resolution, &wind_dirs);
+
+                let cell_min_x: Vec<f64> = cells.iter().map(|c| c.cell_min_x).collect();
+                let cell_min_y: Vec<f64> = cells.iter().map(|c| c.cell_min_y).collect();
+                let cell_max_x: Vec<f64> = cells.iter().map(|c| c.cell_max_x).collect();
+                let cell_max_y: Vec<f64> = cells.iter().map(|c| c.cell_max_y).collect();
+                let lambda_p: Vec<f64> = cells.iter().map(|c| c.morpho.lambda_p).collect();
+                let lambda_f: Vec<f64> = cells.iter().map(|c| c.morpho.lambda_f).collect();
+                let mean_height: Vec<f64> = cells.iter().map(|c| c.morpho.mean_height).collect();
+                let height_stddev: Vec<f64> = cells.iter().map(|c| c.morpho.height_stddev).collect();
+                let surface_to_plan_area_ratio: Vec<f64> =
+                    cells.iter().map(|c| c.surface_to_plan_area_ratio).collect();
+                let sky_view_factor: Vec<f64> = cells.iter().map(|c| c.sky_view_factor).collect();
+
+                let data = PyDict::new(py);
+                data.set_item("cell_min_x", vec_f64_to_pylist(py, &cell_min_x)?)?;
+                data.set_item("cell_min_y", vec_f64_to_pylist(py, &cell_min_y)?)?;
+                data.set_item("cell_max_x", vec_f64_to_pylist(py, &cell_max_x)?)?;
+                data.set_item("cell_max_y", vec_f64_to_pylist(py, &cell_max_y)?)?;
+                data.set_item("lambda_p", vec_f64_to_pylist(py, &lambda_p)?)?;
+                data.set_item("lambda_f", vec_f64_to_pylist(py, &lambda_f)?)?;
+                data.set_item("mean_height", vec_f64_to_pylist(py, &mean_height)?)?;
+                data.set_item("height_stddev", vec_f64_to_pylist(py, &height_stddev)?)?;
+                data.set_item("surface_to_plan_area_ratio", vec_f64_to_pylist(py, &surface_to_plan_area_ratio)?)?;
+                data.set_item("sky_view_factor", vec_f64_to_pylist(py, &sky_view_factor)?)?;
+
+                let df: pyo3::Bound<PyAny> = pandas.call_method1("DataFrame", (data,))?;
+                Ok(df.unbind())
+            }
+            Err(e) => Err(PyValueError::new_err(format!(
+                "Failed to compute morphology: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Bin buildings into H3 hexagonal cells at `resolution` (0-15) and return a pandas
+    /// DataFrame keyed by `h3_index`, one row per non-empty cell, with `building_count`,
+    /// `total_area`, `mean_footprint_area`, `mean_height`, `max_height` and `total_volume`.
+    /// `split_by_intersection` apportions a building across every cell its footprint actually
+    /// overlaps (weighted by overlap area) instead of binning it wholly into its centroid cell.
+    #[pyo3(signature = (resolution, split_by_intersection = false))]
+    fn h3_aggregation_pandas(
+        &self,
+        py: Python,
+        resolution: u8,
+        split_by_intersection: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let cells = self
+            .inner
+            .h3_aggregate(resolution, split_by_intersection)
+            .map_err(|e| PyValueError::new_err(format!("Failed to compute H3 aggregation: {}", e)))?;
+
+        let pandas = py.import("pandas")?;
+
+        let h3_index: Vec<String> = cells.iter().map(|c| c.h3_index.clone()).collect();
+        let building_count: Vec<f64> = cells.iter().map(|c| c.building_count).collect();
+        let total_area: Vec<f64> = cells.iter().map(|c| c.total_area).collect();
+        let mean_footprint_area: Vec<f64> = cells.iter().map(|c| c.mean_footprint_area).collect();
+        let mean_height: Vec<f64> = cells.iter().map(|c| c.mean_height).collect();
+        let max_height: Vec<f64> = cells.iter().map(|c| c.max_height).collect();
+        let total_volume: Vec<f64> = cells.iter().map(|c| c.total_volume).collect();
+
+        let data = PyDict::new(py);
+        data.set_item("h3_index", vec_string_to_pylist(py, &h3_index)?)?;
+        data.set_item("building_count", vec_f64_to_pylist(py, &building_count)?)?;
+        data.set_item("total_area", vec_f64_to_pylist(py, &total_area)?)?;
+        data.set_item(
+            "mean_footprint_area",
+            vec_f64_to_pylist(py, &mean_footprint_area)?,
+        )?;
+        data.set_item("mean_height", vec_f64_to_pylist(py, &mean_height)?)?;
+        data.set_item("max_height", vec_f64_to_pylist(py, &max_height)?)?;
+        data.set_item("total_volume", vec_f64_to_pylist(py, &total_volume)?)?;
+
+        let df: pyo3::Bound<PyAny> = pandas.call_method1("DataFrame", (data,))?;
+        Ok(df.unbind())
+    }
+
+    /// Convert to a geopandas `GeoDataFrame` with a real geometry column (shapely polygons, via
+    /// the geo-interface) and the collection's CRS attached, keeping the same attribute columns
+    /// as `to_pandas`. Unlike `to_pandas`, this is a drop-in replacement for the Python
+    /// `to_gdf()` it's named after -- the result supports spatial joins and plotting directly,
+    /// without a manual GeoJSON round-trip.
+    fn to_geopandas(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let geopandas = py
+            .import("geopandas")
+            .map_err(|_| PyValueError::new_err("geopandas is required for to_geopandas()"))?;
+        let shapely_geometry = py
+            .import("shapely.geometry")
+            .map_err(|_| PyValueError::new_err("shapely is required for to_geopandas()"))?;
+        let json = py.import("json")?;
+
+        let mut height_vec: Vec<Option<f64>> = Vec::new();
+        let mut area_vec: Vec<f64> = Vec::new();
+        let mut nombre_d_etages_vec: Vec<Option<f64>> = Vec::new();
+        let mut hauteur_2_vec: Vec<Option<f64>> = Vec::new();
+        let mut no_hauteur_vec: Vec<bool> = Vec::new();
+        let geometries = PyList::empty(py);
+
+        for building in &self.inner.buildings {
+            height_vec.push(building.height);
+            area_vec.push(building.area);
+            nombre_d_etages_vec.push(building.nombre_d_etages);
+            hauteur_2_vec.push(building.hauteur_2);
+            no_hauteur_vec.push(building.no_hauteur);
+
+            let geo_geom = geo::Geometry::Polygon(building.footprint.clone());
+            let geometry = geojson::Geometry::new(geojson::Value::from(&geo_geom));
+            let geojson_str = serde_json::to_string(&geometry).map_err(|e| {
+                PyValueError::new_err(format!("Failed to serialize footprint: {}", e))
+            })?;
+            let geo_dict: pyo3::Bound<PyAny> = json.call_method1("loads", (geojson_str,))?;
+            let shapely_geom: pyo3::Bound<PyAny> =
+                shapely_geometry.call_method1("shape", (geo_dict,))?;
+            geometries.append(shapely_geom)?;
+        }
+
+        let height_py = option_vec_f64_to_pylist(py, &height_vec)?;
+        let area_py = vec_f64_to_pylist(py, &area_vec)?;
+        let nombre_d_etages_py = option_vec_f64_to_pylist(py, &nombre_d_etages_vec)?;
+        let hauteur_2_py = option_vec_f64_to_pylist(py, &hauteur_2_vec)?;
+        let no_hauteur_py = vec_bool_to_pylist(py, &no_hauteur_vec)?;
+
+        let data = PyDict::new(py);
+        data.set_item("hauteur", height_py)?;
+        data.set_item("area", area_py)?;
+        data.set_item("nombre_d_etages", nombre_d_etages_py)?;
+        data.set_item("hauteur_2", hauteur_2_py)?;
+        data.set_item("noHauteur", no_hauteur_py)?;
+        data.set_item("geometry", geometries)?;
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("geometry", "geometry")?;
+        kwargs.set_item("crs", format!("EPSG:{}", self.inner.geo_core.get_epsg()))?;
+
+        let gdf: pyo3::Bound<PyAny> = geopandas.call_method("GeoDataFrame", (data,), Some(&kwargs))?;
+        Ok(gdf.unbind())
+    }
+
+    /// Load from GeoJSON. `point_buffer_radius`, when set, buffers Point/MultiPoint features
+    /// into a circular footprint of that radius in meters instead of skipping them.
     #[staticmethod]
+    #[pyo3(signature = (geojson_data, output_path, default_storey_height, set_crs, point_buffer_radius = None))]
     fn from_geojson(
         geojson_data: &[u8],
         output_path: Option<String>,
         default_storey_height: f64,
         set_crs: Option<i32>,
+        point_buffer_radius: Option<f64>,
     ) -> PyResult<Self> {
         match BuildingCollection::from_geojson(
             geojson_data,
             output_path,
             default_storey_height,
             set_crs,
+            point_buffer_radius,
         ) {
             Ok(collection) => Ok(PyBuilding { inner: collection }),
             Err(e) => Err(PyValueError::new_err(format!(
@@ -166,6 +569,67 @@ impl PyBuilding {
         }
     }
 
+    /// Walk every feature's properties in a GeoJSON source and report per-field presence
+    /// (count and fraction), geometry-type breakdown, and invalid-geometry count -- so callers
+    /// can validate an input dataset's completeness before calling `from_geojson`.
+    #[staticmethod]
+    fn coverage_report(py: Python, geojson_data: &[u8]) -> PyResult<Py<PyAny>> {
+        let report = BuildingCollection::coverage_report(geojson_data)
+            .map_err(|e| PyValueError::new_err(format!("Failed to compute coverage report: {}", e)))?;
+
+        let fields = PyDict::new(py);
+        for (name, coverage) in &report.fields {
+            let entry = PyDict::new(py);
+            entry.set_item("present_count", coverage.present_count)?;
+            entry.set_item("fraction", coverage.fraction)?;
+            fields.set_item(name, entry)?;
+        }
+
+        let geometry_type_counts = PyDict::new(py);
+        for (name, count) in &report.geometry_type_counts {
+            geometry_type_counts.set_item(name, count)?;
+        }
+
+        let result = PyDict::new(py);
+        result.set_item("feature_count", report.feature_count)?;
+        result.set_item("geometry_type_counts", geometry_type_counts)?;
+        result.set_item("invalid_geometry_count", report.invalid_geometry_count)?;
+        result.set_item("fields", fields)?;
+        Ok(result.into())
+    }
+
+    /// Load from newline-delimited GeoJSON (one Feature per line), bounding peak memory to a
+    /// single feature rather than the whole document the way `from_geojson` does.
+    #[staticmethod]
+    fn from_geojson_seq(
+        geojsonl_data: &[u8],
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> PyResult<Self> {
+        match BuildingCollection::from_geojson_seq(
+            geojsonl_data,
+            output_path,
+            default_storey_height,
+            set_crs,
+        ) {
+            Ok(collection) => Ok(PyBuilding { inner: collection }),
+            Err(e) => Err(PyValueError::new_err(format!(
+                "Failed to load from GeoJSONSeq: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Save every building as newline-delimited GeoJSON (one Feature per line) to `path`.
+    fn to_geojson_seq(&self, path: &str) -> PyResult<()> {
+        let file = std::fs::File::create(path)
+            .map_err(|e| PyValueError::new_err(format!("Failed to create {}: {}", path, e)))?;
+        self.inner
+            .to_geojson_seq(std::io::BufWriter::new(file))
+            .map_err(|e| PyValueError::new_err(format!("Failed to write GeoJSONSeq: {}", e)))
+    }
+
     /// Load from IGN API
     #[staticmethod]
     fn from_ign_api(
@@ -184,8 +648,12 @@ impl PyBuilding {
     }
 
     /// Get GeoJSON (equivalent to to_gdf() in Python)
-    fn get_geojson(&self, py: Python) -> PyResult<Py<PyAny>> {
-        match self.inner.get_geojson() {
+    /// `precision`, when set, rounds every coordinate to that many decimal places (6 decimals
+    /// is about 0.1m at these latitudes) to trade precision for payload size on large
+    /// collections.
+    #[pyo3(signature = (precision = None))]
+    fn get_geojson(&self, py: Python, precision: Option<u32>) -> PyResult<Py<PyAny>> {
+        match self.inner.get_geojson_with_options(precision, None) {
             Ok(geojson) => {
                 let json_str = geojson.to_string();
                 let json = py.import("json")?;
@@ -1,4 +1,6 @@
 use rsmdu::geometric::cadastre::Cadastre;
+use rsmdu::geometric::diff::diff_feature_collections;
+use rsmdu::geometric::query::{IntersectGeom, QueryFilter};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
@@ -34,6 +36,28 @@ impl PyCadastre {
         self.inner.set_crs(epsg);
     }
 
+    /// Keep only parcels with at least one vertex inside the given bounding box (in `geo_core`'s
+    /// current CRS), mutating the stored GeoJSON in place.
+    fn filter_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> PyResult<()> {
+        self.inner
+            .filter_bbox(min_x, min_y, max_x, max_y)
+            .map_err(|e| PyValueError::new_err(format!("Failed to filter by bbox: {}", e)))
+    }
+
+    /// Opt in to anonymizing every exported feature by snapping its representative point to a
+    /// `cell_meters`-sized grid cell and discarding the original geometry. When
+    /// `collapse_duplicates` is set, parcels sharing a cell are merged into one point feature
+    /// with a `count` property.
+    #[pyo3(signature = (cell_meters, collapse_duplicates = false))]
+    fn set_privacy_grid(&mut self, cell_meters: f64, collapse_duplicates: bool) {
+        self.inner.set_privacy_grid(cell_meters, collapse_duplicates);
+    }
+
+    /// Undo `set_privacy_grid`: exporters go back to writing exact geometry.
+    fn clear_privacy_grid(&mut self) {
+        self.inner.clear_privacy_grid();
+    }
+
     /// Run cadastre processing: download from IGN API, parse GeoJSON
     fn run(mut slf: PyRefMut<Self>) -> PyResult<PyRefMut<Self>> {
         // Use run_internal which works on &mut self
@@ -79,4 +103,111 @@ impl PyCadastre {
             inner: self.inner.geo_core.clone(),
         }
     }
+
+    /// Compare this instance's `get_geojson()` output against `other`'s, e.g. two cadastre runs
+    /// for the same bbox at different dates. Matches features on `id_field` when given,
+    /// otherwise on a stable hash of their (rounded) geometry coordinates. Returns
+    /// `(added, deleted, changed)` FeatureCollections: features only in `other`, features only
+    /// in `self`, and shared features whose geometry or properties differ.
+    #[pyo3(signature = (other, id_field = None, precision = None))]
+    fn diff(
+        &self,
+        other: &PyCadastre,
+        id_field: Option<&str>,
+        precision: Option<u32>,
+        py: Python,
+    ) -> PyResult<(Py<PyAny>, Py<PyAny>, Py<PyAny>)> {
+        let old = self.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available. Call run() first.")
+        })?;
+        let new = other.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available on `other`. Call run() first.")
+        })?;
+
+        let diff = diff_feature_collections(old, new, id_field, precision)
+            .map_err(|e| PyValueError::new_err(format!("Failed to diff Cadastre: {}", e)))?;
+
+        Ok((
+            geojson_to_py(&diff.added, py)?,
+            geojson_to_py(&diff.deleted, py)?,
+            geojson_to_py(&diff.changed, py)?,
+        ))
+    }
+
+    /// Filter this instance's `get_geojson()` output without re-running processing, as a
+    /// lightweight server-style feature API. `where_` is a small SQL-like predicate over feature
+    /// properties (e.g. `"classe = 'building'"` or `"hauteur > 10"`, see
+    /// `rsmdu::geometric::query::parse_where` for the full grammar). `select` keeps only the
+    /// named properties (geometry is always kept). `limit`/`offset` paginate the matching
+    /// features. `intersects` is a GeoJSON geometry (or Feature) dict; only features whose
+    /// geometry intersects it are kept. Returns a new GeoJSON dict.
+    #[pyo3(signature = (where_ = None, select = None, limit = None, offset = None, intersects = None))]
+    fn query(
+        &self,
+        where_: Option<&str>,
+        select: Option<Vec<String>>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+        intersects: Option<Py<PyAny>>,
+        py: Python,
+    ) -> PyResult<Py<PyAny>> {
+        let geojson = self.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available. Call run() first.")
+        })?;
+
+        let mut filter = QueryFilter::new();
+        if let Some(expr) = where_ {
+            filter = filter.where_str(expr).map_err(|e| {
+                PyValueError::new_err(format!("Invalid `where` expression: {}", e))
+            })?;
+        }
+        if let Some(fields) = select {
+            filter = filter.select(fields);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(intersects) = intersects {
+            filter = filter.intersects(intersect_geom_from_py(intersects, py)?);
+        }
+
+        geojson_to_py(&filter.apply(geojson), py)
+    }
+}
+
+/// Parse a Python GeoJSON geometry/Feature dict (as accepted by `query()`'s `intersects`
+/// parameter) into an [`IntersectGeom`] for `QueryFilter::intersects`.
+fn intersect_geom_from_py(py_any: Py<PyAny>, py: Python) -> PyResult<IntersectGeom> {
+    let json = py.import("json")?;
+    let json_str_bound: pyo3::Bound<PyAny> = json.call_method1("dumps", (py_any,))?;
+    let json_str: String = json_str_bound.extract()?;
+    let geojson: geojson::GeoJson = json_str
+        .parse()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse GeoJSON: {}", e)))?;
+
+    let geometry_value = match &geojson {
+        geojson::GeoJson::Geometry(geometry) => geometry.value.clone(),
+        geojson::GeoJson::Feature(feature) => feature
+            .geometry
+            .as_ref()
+            .map(|geometry| geometry.value.clone())
+            .ok_or_else(|| PyValueError::new_err("`intersects` feature has no geometry"))?,
+        geojson::GeoJson::FeatureCollection(_) => {
+            return Err(PyValueError::new_err(
+                "`intersects` must be a single geometry or Feature, not a FeatureCollection",
+            ));
+        }
+    };
+    Ok(IntersectGeom::from_geometry(&geometry_value))
+}
+
+/// Convert a `geojson::GeoJson` document into the equivalent Python dict via a JSON round-trip.
+fn geojson_to_py(geojson: &geojson::GeoJson, py: Python) -> PyResult<Py<PyAny>> {
+    let json_str = geojson.to_string();
+    let json = py.import("json")?;
+    let dict: pyo3::Bound<PyAny> = json.call_method1("loads", (json_str,))?;
+    Ok(dict.unbind())
 }
@@ -0,0 +1,155 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rsmdu::geometric::dem::{Dem, MergeStrategy, Resampling};
+
+use crate::bindings::geo_core::PyGeoCore;
+
+/// Parse a native-vector driver name (`"gpkg"`, `"shapefile"`/`"shp"`, `"geojson"`,
+/// case-insensitive) into the OGR driver name [`rsmdu::geometric::export::write_vector_native`]
+/// expects, for `to_vector` bindings.
+fn parse_native_vector_driver(driver: &str) -> PyResult<&'static str> {
+    match driver.to_lowercase().as_str() {
+        "gpkg" | "geopackage" => Ok("GPKG"),
+        "shp" | "shapefile" => Ok("ESRI Shapefile"),
+        "geojson" | "json" => Ok("GeoJSON"),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported driver {:?}; expected one of gpkg, shapefile, geojson",
+            other
+        ))),
+    }
+}
+
+/// Parse a resampling mode name (`"nearest"`, `"bilinear"`, `"cubic"`, case-insensitive) into a
+/// [`Resampling`] for `reproject`/`set_resampling`.
+fn parse_resampling(resampling: &str) -> PyResult<Resampling> {
+    match resampling.to_lowercase().as_str() {
+        "nearest" => Ok(Resampling::Nearest),
+        "bilinear" => Ok(Resampling::Bilinear),
+        "cubic" => Ok(Resampling::Cubic),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported resampling mode {:?}; expected one of nearest, bilinear, cubic",
+            other
+        ))),
+    }
+}
+
+/// Parse a mosaic-overlap strategy name (`"last_wins"`, `"average"`, case-insensitive) into a
+/// [`MergeStrategy`] for `set_mosaic_overlap`.
+fn parse_merge_strategy(strategy: &str) -> PyResult<MergeStrategy> {
+    match strategy.to_lowercase().as_str() {
+        "last_wins" | "lastwins" => Ok(MergeStrategy::LastWins),
+        "average" => Ok(MergeStrategy::Average),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported mosaic overlap strategy {:?}; expected one of last_wins, average",
+            other
+        ))),
+    }
+}
+
+/// Dem Python binding
+#[pyclass]
+pub struct PyDem {
+    inner: Dem,
+}
+
+#[pymethods]
+impl PyDem {
+    #[new]
+    #[pyo3(signature = (output_path = None, stac_collection = None))]
+    fn new(output_path: Option<String>, stac_collection: Option<String>) -> PyResult<Self> {
+        match Dem::new(output_path, stac_collection) {
+            Ok(dem) => Ok(PyDem { inner: dem }),
+            Err(e) => Err(PyValueError::new_err(format!("Failed to create Dem: {}", e))),
+        }
+    }
+
+    /// Set bounding box
+    fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        self.inner.set_bbox(min_x, min_y, max_x, max_y);
+    }
+
+    /// Set CRS
+    fn set_crs(&mut self, epsg: i32) {
+        self.inner.set_crs(epsg);
+    }
+
+    /// Set the output pixel size (in `geo_core`'s target CRS units)
+    fn set_resolution(&mut self, resolution: f64) {
+        self.inner.set_resolution(resolution);
+    }
+
+    /// Set the resampling mode ("nearest", "bilinear", or "cubic", case-insensitive) used when
+    /// warping the source raster onto the reprojected grid.
+    fn set_resampling(&mut self, resampling: &str) -> PyResult<()> {
+        self.inner.set_resampling(parse_resampling(resampling)?);
+        Ok(())
+    }
+
+    /// Set how overlapping pixels are combined when `run`/`run_internal` has to split the bbox
+    /// into multiple IGN WMS tiles ("last_wins" or "average", case-insensitive).
+    fn set_mosaic_overlap(&mut self, strategy: &str) -> PyResult<()> {
+        self.inner
+            .set_mosaic_overlap(parse_merge_strategy(strategy)?);
+        Ok(())
+    }
+
+    /// Warp the already-downloaded source raster to `target_epsg` at `resolution_m`, using
+    /// `resampling` ("nearest", "bilinear", or "cubic"), overwriting `get_path_save_tiff` and
+    /// regenerating `get_path_save_mask`. Returns the path to the reprojected TIFF.
+    fn reproject(&mut self, target_epsg: i32, resolution_m: f64, resampling: &str) -> PyResult<String> {
+        let resampling = parse_resampling(resampling)?;
+        self.inner
+            .reproject(target_epsg, resolution_m, resampling)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject Dem: {}", e)))
+    }
+
+    /// Run Dem processing: download from IGN API, reproject, and generate the mask shapefile
+    #[pyo3(signature = (shape = None, fill = false))]
+    fn run(
+        mut slf: PyRefMut<Self>,
+        shape: Option<(u32, u32)>,
+        fill: bool,
+    ) -> PyResult<PyRefMut<Self>> {
+        slf.inner
+            .run_internal(shape, fill)
+            .map_err(|e| PyValueError::new_err(format!("Failed to run Dem: {}", e)))?;
+        Ok(slf)
+    }
+
+    /// Export the mask geometry to an explicit `path` through a native GDAL/OGR writer. `driver`
+    /// is one of "gpkg", "shapefile", or "geojson" (case-insensitive); `layer` names the output
+    /// layer, defaulting to "mask". Gives `Dem` the same export surface as
+    /// `Cadastre.to_gpkg`/`LandCover.to_vector`. Returns the path to the written file.
+    #[pyo3(signature = (path, driver = "gpkg", layer = None))]
+    fn to_vector(&self, path: &str, driver: &str, layer: Option<&str>) -> PyResult<String> {
+        let driver = parse_native_vector_driver(driver)?;
+        self.inner
+            .to_vector(std::path::Path::new(path), driver, layer)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export mask: {}", e)))
+    }
+
+    /// Get path to the final DEM TIFF file
+    fn get_path_save_tiff(&self) -> String {
+        self.inner.get_path_save_tiff().to_string_lossy().to_string()
+    }
+
+    /// Get path to the mask shapefile
+    fn get_path_save_mask(&self) -> String {
+        self.inner.get_path_save_mask().to_string_lossy().to_string()
+    }
+
+    /// Get output path
+    fn get_output_path(&self) -> String {
+        self.inner.get_output_path().to_string_lossy().to_string()
+    }
+
+    /// Get GeoCore instance
+    #[getter]
+    fn geo_core(&self) -> PyGeoCore {
+        PyGeoCore {
+            inner: self.inner.geo_core.clone(),
+        }
+    }
+}
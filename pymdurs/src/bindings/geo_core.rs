@@ -1,4 +1,6 @@
+use geojson::GeoJson;
 use rsmdu::geo_core::GeoCore;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::bindings::bounding_box::PyBoundingBox;
@@ -50,4 +52,64 @@ impl PyGeoCore {
     fn set_output_path(&mut self, output_path: Option<String>) {
         self.inner.set_output_path(output_path);
     }
+
+    /// Transform a single `(x, y)` coordinate from this GeoCore's current EPSG to `to_epsg`,
+    /// via PROJ. Does not mutate `self`'s EPSG -- use the `epsg` setter for that.
+    fn transform_point(&self, x: f64, y: f64, to_epsg: i32) -> PyResult<(f64, f64)> {
+        GeoCore::transform_coords(self.inner.get_epsg(), to_epsg, x, y)
+            .map_err(|e| PyValueError::new_err(format!("Failed to transform point: {}", e)))
+    }
+
+    /// Transform a single `(x, y)` coordinate from EPSG:4326 (WGS84 lat/long) to this GeoCore's
+    /// current EPSG, via PROJ.
+    fn transform_point_from_latlong(&self, lon: f64, lat: f64) -> PyResult<(f64, f64)> {
+        GeoCore::transform_coords(4326, self.inner.get_epsg(), lon, lat)
+            .map_err(|e| PyValueError::new_err(format!("Failed to transform point: {}", e)))
+    }
+
+    /// Reproject a GeoJSON document (e.g. the output of any loader's `get_geojson()`) from
+    /// `from_epsg` to `to_epsg`, transforming every coordinate pair and returning the result as
+    /// a Python dict. Short-circuits without transforming when `from_epsg == to_epsg`, and
+    /// raises a clear error if PROJ can't resolve a transform between the two codes.
+    fn reproject(
+        &self,
+        py: Python,
+        geojson_data: &str,
+        from_epsg: i32,
+        to_epsg: i32,
+    ) -> PyResult<Py<PyAny>> {
+        let mut geojson: GeoJson = geojson_data
+            .parse()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse GeoJSON: {}", e)))?;
+        GeoCore::reproject_geojson(&mut geojson, from_epsg, to_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject GeoJSON: {}", e)))?;
+
+        let json_str = geojson.to_string();
+        let json = py.import("json")?;
+        let geojson_dict: pyo3::Bound<PyAny> = json.call_method1("loads", (json_str,))?;
+        Ok(geojson_dict.unbind())
+    }
+
+    /// Replace every Point/MultiPoint geometry in a GeoJSON document (expressed in `layer_epsg`)
+    /// with a circular polygon of `radius_m` meters, so downstream intersection/area code that
+    /// expects areal features doesn't break on bare points. Existing polygon/line features are
+    /// left untouched.
+    fn buffer_points(
+        &self,
+        py: Python,
+        geojson_data: &str,
+        layer_epsg: i32,
+        radius_m: f64,
+    ) -> PyResult<Py<PyAny>> {
+        let mut geojson: GeoJson = geojson_data
+            .parse()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse GeoJSON: {}", e)))?;
+        GeoCore::buffer_points(&mut geojson, layer_epsg, radius_m)
+            .map_err(|e| PyValueError::new_err(format!("Failed to buffer points: {}", e)))?;
+
+        let json_str = geojson.to_string();
+        let json = py.import("json")?;
+        let geojson_dict: pyo3::Bound<PyAny> = json.call_method1("loads", (json_str,))?;
+        Ok(geojson_dict.unbind())
+    }
 }
@@ -0,0 +1,42 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rsmdu_core::io::{GeoMetadataKind, GeoReader};
+use std::path::PathBuf;
+
+/// GeoReader Python binding: sniffs a local vector/raster file and reports its metadata.
+#[pyclass]
+pub struct PyGeoReader;
+
+#[pymethods]
+impl PyGeoReader {
+    /// Read `path`, auto-detecting GeoJSON/GPKG/Shapefile vs. GeoTIFF, and return a metadata
+    /// dict: `{"epsg": int, "bounds": (min_x, min_y, max_x, max_y), "kind": "vector"|"raster",
+    /// "geometry_type"?: str, "field_schema"?: list[(name, type)], "band_count"?: int}`.
+    #[staticmethod]
+    fn read(py: Python, path: String) -> PyResult<Py<PyAny>> {
+        let data = GeoReader::read(&PathBuf::from(path))
+            .map_err(|e| PyValueError::new_err(format!("Failed to read source: {}", e)))?;
+        let metadata = data
+            .metadata()
+            .map_err(|e| PyValueError::new_err(format!("Failed to read metadata: {}", e)))?;
+
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("epsg", metadata.epsg)?;
+        dict.set_item("bounds", metadata.bounds)?;
+        match &metadata.kind {
+            GeoMetadataKind::Vector {
+                geometry_type,
+                field_schema,
+            } => {
+                dict.set_item("kind", "vector")?;
+                dict.set_item("geometry_type", geometry_type)?;
+                dict.set_item("field_schema", field_schema.clone())?;
+            }
+            GeoMetadataKind::Raster { band_count } => {
+                dict.set_item("kind", "raster")?;
+                dict.set_item("band_count", band_count)?;
+            }
+        }
+        Ok(dict.into_any().unbind())
+    }
+}
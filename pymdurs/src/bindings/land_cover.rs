@@ -1,8 +1,41 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use rsmdu::geometric::land_cover::LandCover;
+use rsmdu::geometric::diff::diff_feature_collections;
+use rsmdu::geometric::land_cover::{BurnStrategy, LandCover};
+use rsmdu::geometric::query::{IntersectGeom, QueryFilter};
+use rsmdu::geometric::validate as geometry_validate;
 
 use crate::bindings::geo_core::PyGeoCore;
+use crate::bindings::parse_output_format;
+
+/// Parse a burn-strategy name (`"first"`, `"majority"`, `"threshold"`, case-insensitive) into a
+/// [`BurnStrategy`] for `to_raster`'s `burn_strategy` parameter.
+fn parse_burn_strategy(strategy: &str) -> PyResult<BurnStrategy> {
+    match strategy.to_lowercase().as_str() {
+        "first" => Ok(BurnStrategy::First),
+        "majority" => Ok(BurnStrategy::Majority),
+        "threshold" => Ok(BurnStrategy::Threshold),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported burn strategy {:?}; expected one of first, majority, threshold",
+            other
+        ))),
+    }
+}
+
+/// Parse a native-vector driver name (`"gpkg"`, `"shapefile"`/`"shp"`, `"geojson"`,
+/// case-insensitive) into the OGR driver name [`rsmdu::geometric::export::write_vector_native`]
+/// expects, for `to_vector` bindings.
+fn parse_native_vector_driver(driver: &str) -> PyResult<&'static str> {
+    match driver.to_lowercase().as_str() {
+        "gpkg" | "geopackage" => Ok("GPKG"),
+        "shp" | "shapefile" => Ok("ESRI Shapefile"),
+        "geojson" | "json" => Ok("GeoJSON"),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported driver {:?}; expected one of gpkg, shapefile, geojson",
+            other
+        ))),
+    }
+}
 
 /// LandCover Python binding
 #[pyclass]
@@ -25,53 +58,118 @@ impl PyLandCover {
     }
 
     /// Add building GeoDataFrame
-    fn add_building_gdf(&mut self, building_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (building_geojson, source_epsg = None))]
+    fn add_building_gdf(
+        &mut self,
+        building_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(building_geojson, py)?;
         self.inner
-            .add_building_gdf(&geojson)
+            .add_building_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add building GDF: {}", e)))
     }
 
     /// Add vegetation GeoDataFrame
-    fn add_vegetation_gdf(&mut self, vegetation_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (vegetation_geojson, source_epsg = None))]
+    fn add_vegetation_gdf(
+        &mut self,
+        vegetation_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(vegetation_geojson, py)?;
         self.inner
-            .add_vegetation_gdf(&geojson)
+            .add_vegetation_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add vegetation GDF: {}", e)))
     }
 
     /// Add water GeoDataFrame
-    fn add_water_gdf(&mut self, water_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (water_geojson, source_epsg = None))]
+    fn add_water_gdf(
+        &mut self,
+        water_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(water_geojson, py)?;
         self.inner
-            .add_water_gdf(&geojson)
+            .add_water_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add water GDF: {}", e)))
     }
 
     /// Add pedestrian GeoDataFrame
-    fn add_pedestrian_gdf(&mut self, pedestrian_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (pedestrian_geojson, source_epsg = None))]
+    fn add_pedestrian_gdf(
+        &mut self,
+        pedestrian_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(pedestrian_geojson, py)?;
         self.inner
-            .add_pedestrian_gdf(&geojson)
+            .add_pedestrian_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add pedestrian GDF: {}", e)))
     }
 
     /// Add COSIA GeoDataFrame
-    fn add_cosia_gdf(&mut self, cosia_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (cosia_geojson, source_epsg = None))]
+    fn add_cosia_gdf(
+        &mut self,
+        cosia_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(cosia_geojson, py)?;
         self.inner
-            .add_cosia_gdf(&geojson)
+            .add_cosia_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add COSIA GDF: {}", e)))
     }
 
     /// Add DXF GeoDataFrame
-    fn add_dxf_gdf(&mut self, dxf_geojson: Py<PyAny>, py: Python) -> PyResult<()> {
+    #[pyo3(signature = (dxf_geojson, source_epsg = None))]
+    fn add_dxf_gdf(
+        &mut self,
+        dxf_geojson: Py<PyAny>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
         let geojson = py_any_to_geojson(dxf_geojson, py)?;
         self.inner
-            .add_dxf_gdf(&geojson)
+            .add_dxf_gdf(&geojson, source_epsg)
             .map_err(|e| PyValueError::new_err(format!("Failed to add DXF GDF: {}", e)))
     }
 
+    /// Add a building layer distributed as TopoJSON
+    #[pyo3(signature = (topojson, object_name = None, source_epsg = None))]
+    fn add_building_topojson(
+        &mut self,
+        topojson: Py<PyAny>,
+        object_name: Option<&str>,
+        source_epsg: Option<i32>,
+        py: Python,
+    ) -> PyResult<()> {
+        let topojson = py_any_to_json_value(topojson, py)?;
+        self.inner
+            .add_building_topojson(&topojson, object_name, source_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to add building TopoJSON: {}", e)))
+    }
+
+    /// Set the clip mask from a TopoJSON document
+    #[pyo3(signature = (topojson, object_name = None))]
+    fn set_mask_topojson(
+        &mut self,
+        topojson: Py<PyAny>,
+        object_name: Option<&str>,
+        py: Python,
+    ) -> PyResult<()> {
+        let topojson = py_any_to_json_value(topojson, py)?;
+        self.inner
+            .set_mask_topojson(&topojson, object_name)
+            .map_err(|e| PyValueError::new_err(format!("Failed to set TopoJSON mask: {}", e)))
+    }
+
     /// Set bounding box
     fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
         self.inner.set_bbox(min_x, min_y, max_x, max_y);
@@ -82,6 +180,11 @@ impl PyLandCover {
         self.inner.set_crs(epsg);
     }
 
+    /// Set the default source CRS assumed for inputs that don't pass their own `source_epsg`
+    fn set_source_crs(&mut self, epsg: i32) {
+        self.inner.set_source_crs(epsg);
+    }
+
     /// Run land cover processing
     #[pyo3(signature = (mask = None))]
     fn run(&mut self, mask: Option<Py<PyAny>>, py: Python) -> PyResult<()> {
@@ -111,24 +214,96 @@ impl PyLandCover {
         }
     }
 
-    /// Create raster from land cover
-    #[pyo3(signature = (dst_tif = "landcover.tif", template_raster_path = None, resolution = None))]
+    /// Export to any OGR-supported vector format ("gpkg", "shapefile", "geojson",
+    /// "flatgeobuf", "kml", "kmz", or "gpx"), reprojecting to `geo_core`'s EPSG on the way out.
+    /// Returns the path to the written file.
+    #[pyo3(signature = (format, name = None))]
+    fn to_file(&self, format: &str, name: Option<&str>) -> PyResult<String> {
+        let format = parse_output_format(format)?;
+        self.inner
+            .to_file(name, format)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export land cover: {}", e)))
+    }
+
+    /// Export to an explicit `path` through a native GDAL/OGR writer instead of `to_file`'s
+    /// `ogr2ogr` subprocess. `driver` is one of "gpkg", "shapefile", or "geojson"
+    /// (case-insensitive); `layer` names the output layer, defaulting to "landcover". Gives
+    /// `LandCover` the same export surface as `Cadastre.to_gpkg`/`Dem.to_vector`. Returns the
+    /// path to the written file.
+    #[pyo3(signature = (path, driver = "gpkg", layer = None))]
+    fn to_vector(&self, path: &str, driver: &str, layer: Option<&str>) -> PyResult<String> {
+        let driver = parse_native_vector_driver(driver)?;
+        self.inner
+            .to_vector(std::path::Path::new(path), driver, layer)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export land cover: {}", e)))
+    }
+
+    /// Create raster from land cover. `burn_strategy` ("first", "majority", or "threshold")
+    /// controls how a pixel covered by more than one class is resolved: "first" (the default)
+    /// keeps the original GDAL scanline burn, where the last feature touching a pixel wins;
+    /// "majority"/"threshold" intersect each pixel against every overlapping polygon and burn
+    /// the class covering the largest share of its area, with "threshold" additionally
+    /// requiring that share to be at least `area_threshold` percent (0-100, default 50.0).
+    #[pyo3(signature = (dst_tif = "landcover.tif", template_raster_path = None, resolution = None, burn_strategy = None, area_threshold = None))]
     fn to_raster(
         &self,
         dst_tif: Option<&str>,
         template_raster_path: Option<String>,
         resolution: Option<(f64, f64)>,
+        burn_strategy: Option<&str>,
+        area_threshold: Option<f64>,
     ) -> PyResult<String> {
         use std::path::Path;
         let template_path = template_raster_path.as_ref().map(|s| Path::new(s));
         let dst = dst_tif.unwrap_or("landcover.tif");
+        let burn_strategy = burn_strategy.map(parse_burn_strategy).transpose()?;
 
         self.inner
-            .to_raster(dst, template_path, resolution)
+            .to_raster(dst, template_path, resolution, burn_strategy, area_threshold)
             .map(|path| path.to_string_lossy().to_string())
             .map_err(|e| PyValueError::new_err(format!("Failed to create raster: {}", e)))
     }
 
+    /// Create a categorical (Byte, color-table + raster attribute table) raster from land cover,
+    /// rather than `to_raster`'s opaque `f32`/`NaN` grid. `palette` optionally overrides the
+    /// default RGBA color for any land cover type code.
+    #[pyo3(signature = (dst_tif = "landcover.tif", template_raster_path = None, resolution = None, palette = None))]
+    fn to_raster_categorical(
+        &self,
+        dst_tif: Option<&str>,
+        template_raster_path: Option<String>,
+        resolution: Option<(f64, f64)>,
+        palette: Option<std::collections::HashMap<u8, (u8, u8, u8, u8)>>,
+    ) -> PyResult<String> {
+        use std::path::Path;
+        let template_path = template_raster_path.as_ref().map(|s| Path::new(s));
+        let dst = dst_tif.unwrap_or("landcover.tif");
+        let palette = palette.map(|p| {
+            p.into_iter()
+                .map(|(code, (r, g, b, a))| (code, [r, g, b, a]))
+                .collect::<std::collections::HashMap<u8, [u8; 4]>>()
+        });
+
+        self.inner
+            .to_raster_categorical(dst, template_path, resolution, palette.as_ref())
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to create categorical raster: {}", e)))
+    }
+
+    /// Merge adjacent or overlapping polygons sharing the same `by` property (defaulting to
+    /// `"type"`, the land-cover class code) into one unified (Multi)Polygon per class, mutating
+    /// the stored GeoJSON in place. When `validate` is set, raises a descriptive error if the
+    /// dissolved output contains non-finite or degenerate geometry instead of silently keeping
+    /// it.
+    #[pyo3(signature = (by = None, validate = false))]
+    fn dissolve(&mut self, by: Option<&str>, validate: bool) -> PyResult<()> {
+        self.inner
+            .dissolve(by, validate)
+            .map_err(|e| PyValueError::new_err(format!("Failed to dissolve land cover: {}", e)))
+    }
+
     /// Get output path
     fn get_output_path(&self) -> String {
         self.inner.get_output_path().to_string_lossy().to_string()
@@ -141,19 +316,176 @@ impl PyLandCover {
             inner: self.inner.geo_core.clone(),
         }
     }
+
+    /// Compare this instance's `get_geojson()` output against `other`'s, e.g. two land-cover
+    /// runs for the same bbox at different dates. Matches features on `id_field` when given,
+    /// otherwise on a stable hash of their (rounded) geometry coordinates. Returns
+    /// `(added, deleted, changed)` FeatureCollections: features only in `other`, features only
+    /// in `self`, and shared features whose geometry or properties differ.
+    #[pyo3(signature = (other, id_field = None, precision = None))]
+    fn diff(
+        &self,
+        other: &PyLandCover,
+        id_field: Option<&str>,
+        precision: Option<u32>,
+        py: Python,
+    ) -> PyResult<(Py<PyAny>, Py<PyAny>, Py<PyAny>)> {
+        let old = self.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available. Call run() first.")
+        })?;
+        let new = other.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available on `other`. Call run() first.")
+        })?;
+
+        let diff = diff_feature_collections(old, new, id_field, precision)
+            .map_err(|e| PyValueError::new_err(format!("Failed to diff LandCover: {}", e)))?;
+
+        Ok((
+            geojson_to_py(&diff.added, py)?,
+            geojson_to_py(&diff.deleted, py)?,
+            geojson_to_py(&diff.changed, py)?,
+        ))
+    }
+
+    /// Filter this instance's `get_geojson()` output without re-running processing, as a
+    /// lightweight server-style feature API. `where_` is a small SQL-like predicate over feature
+    /// properties (e.g. `"classe = 'building'"` or `"hauteur > 10"`, see
+    /// `rsmdu::geometric::query::parse_where` for the full grammar). `select` keeps only the
+    /// named properties (geometry is always kept). `limit`/`offset` paginate the matching
+    /// features. `intersects` is a GeoJSON geometry (or Feature) dict; only features whose
+    /// geometry intersects it are kept. Returns a new GeoJSON dict.
+    #[pyo3(signature = (where_ = None, select = None, limit = None, offset = None, intersects = None))]
+    fn query(
+        &self,
+        where_: Option<&str>,
+        select: Option<Vec<String>>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+        intersects: Option<Py<PyAny>>,
+        py: Python,
+    ) -> PyResult<Py<PyAny>> {
+        let geojson = self.inner.get_geojson().ok_or_else(|| {
+            PyValueError::new_err("No GeoJSON data available. Call run() first.")
+        })?;
+
+        let mut filter = QueryFilter::new();
+        if let Some(expr) = where_ {
+            filter = filter.where_str(expr).map_err(|e| {
+                PyValueError::new_err(format!("Invalid `where` expression: {}", e))
+            })?;
+        }
+        if let Some(fields) = select {
+            filter = filter.select(fields);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(intersects) = intersects {
+            filter = filter.intersects(intersect_geom_from_py(intersects, py)?);
+        }
+
+        geojson_to_py(&filter.apply(geojson), py)
+    }
 }
 
-/// Helper function to convert Python dict/GeoJSON to geojson::GeoJson
-fn py_any_to_geojson(py_any: Py<PyAny>, py: Python) -> PyResult<geojson::GeoJson> {
-    // Convert Python object to JSON string
+/// Parse a Python GeoJSON geometry/Feature dict (as accepted by `query()`'s `intersects`
+/// parameter) into an [`IntersectGeom`] for `QueryFilter::intersects`.
+fn intersect_geom_from_py(py_any: Py<PyAny>, py: Python) -> PyResult<IntersectGeom> {
+    let geojson = parse_geojson(py_any, py)?;
+    let geometry_value = match &geojson {
+        geojson::GeoJson::Geometry(geometry) => geometry.value.clone(),
+        geojson::GeoJson::Feature(feature) => feature
+            .geometry
+            .as_ref()
+            .map(|geometry| geometry.value.clone())
+            .ok_or_else(|| PyValueError::new_err("`intersects` feature has no geometry"))?,
+        geojson::GeoJson::FeatureCollection(_) => {
+            return Err(PyValueError::new_err(
+                "`intersects` must be a single geometry or Feature, not a FeatureCollection",
+            ));
+        }
+    };
+    Ok(IntersectGeom::from_geometry(&geometry_value))
+}
+
+/// Convert a `geojson::GeoJson` document into the equivalent Python dict via a JSON round-trip.
+fn geojson_to_py(geojson: &geojson::GeoJson, py: Python) -> PyResult<Py<PyAny>> {
+    let json_str = geojson.to_string();
+    let json = py.import("json")?;
+    let dict: pyo3::Bound<PyAny> = json.call_method1("loads", (json_str,))?;
+    Ok(dict.unbind())
+}
+
+/// Parse a Python dict/GeoJSON object into `geojson::GeoJson`, without validating it. Only
+/// [`validate`] and [`py_any_to_geojson`] should call this directly -- everything else should go
+/// through `py_any_to_geojson` so malformed geometries are caught at the boundary.
+fn parse_geojson(py_any: Py<PyAny>, py: Python) -> PyResult<geojson::GeoJson> {
     let json = py.import("json")?;
     let json_str_bound: pyo3::Bound<PyAny> = json.call_method1("dumps", (py_any,))?;
     let json_str: String = json_str_bound.extract()?;
 
-    // Parse JSON string to GeoJSON
-    let geojson: geojson::GeoJson = json_str
+    json_str
         .parse()
-        .map_err(|e| PyValueError::new_err(format!("Failed to parse GeoJSON: {}", e)))?;
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse GeoJSON: {}", e)))
+}
+
+/// Helper function to convert Python dict/GeoJSON to `geojson::GeoJson`, rejecting geometries
+/// that fail [`rsmdu::geometric::validate::validate`]'s structural checks (e.g. an unclosed
+/// polygon ring, or a LineString with a single position) with a `ValueError` listing every
+/// offending feature index and reason, instead of letting it surface as a confusing error deep
+/// inside `add_building_gdf`/`add_vegetation_gdf`/etc.
+fn py_any_to_geojson(py_any: Py<PyAny>, py: Python) -> PyResult<geojson::GeoJson> {
+    let geojson = parse_geojson(py_any, py)?;
+
+    let (_, report) = geometry_validate::validate(&geojson, false);
+    if !report.is_valid() {
+        let details: Vec<String> = report
+            .issues
+            .iter()
+            .map(|issue| format!("feature {}: {}", issue.feature_index, issue.reason))
+            .collect();
+        return Err(PyValueError::new_err(format!(
+            "Invalid geometry: {}",
+            details.join("; ")
+        )));
+    }
 
     Ok(geojson)
 }
+
+/// Validate (and, when `repair` is true, auto-repair) every feature's geometry in `geojson`
+/// against GeoJSON's structural invariants -- see
+/// [`rsmdu::geometric::validate::validate`] for the exact rules. Unlike the implicit check
+/// inside `add_building_gdf`/`add_vegetation_gdf`/etc, this never raises on a bad geometry;
+/// callers inspect the returned `(feature_index, reason)` issue list themselves. Returns the
+/// (possibly repaired) GeoJSON alongside that list.
+#[pyfunction]
+#[pyo3(signature = (geojson, repair = false))]
+fn validate(geojson: Py<PyAny>, repair: bool, py: Python) -> PyResult<(Py<PyAny>, Vec<(usize, String)>)> {
+    let parsed = parse_geojson(geojson, py)?;
+    let (repaired, report) = geometry_validate::validate(&parsed, repair);
+
+    let json_str = repaired.to_string();
+    let json = py.import("json")?;
+    let repaired_dict: pyo3::Bound<PyAny> = json.call_method1("loads", (json_str,))?;
+
+    let issues = report
+        .issues
+        .into_iter()
+        .map(|issue| (issue.feature_index, issue.reason))
+        .collect();
+    Ok((repaired_dict.unbind(), issues))
+}
+
+/// Helper function to convert a Python dict (e.g. a parsed `.topojson` file) to `serde_json::Value`
+fn py_any_to_json_value(py_any: Py<PyAny>, py: Python) -> PyResult<serde_json::Value> {
+    let json = py.import("json")?;
+    let json_str_bound: pyo3::Bound<PyAny> = json.call_method1("dumps", (py_any,))?;
+    let json_str: String = json_str_bound.extract()?;
+
+    serde_json::from_str(&json_str)
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse TopoJSON: {}", e)))
+}
@@ -34,23 +34,61 @@ impl PyLidar {
         self.inner.set_classification(classification);
     }
 
-    /// Set CRS
-    fn set_crs(&mut self, epsg: i32) {
-        self.inner.geo_core.set_epsg(epsg);
+    /// Set CRS, reprojecting any already-loaded points
+    fn set_crs(&mut self, epsg: i32) -> PyResult<()> {
+        self.inner
+            .set_crs(epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to set CRS: {}", e)))
+    }
+
+    /// Restrict `run()`'s output to points inside a GeoJSON Polygon/MultiPolygon boundary
+    /// (EPSG:4326), passed as a GeoJSON string.
+    fn set_limit_to(&mut self, boundary: &str) -> PyResult<()> {
+        self.inner
+            .set_limit_to(boundary.as_bytes())
+            .map_err(|e| PyValueError::new_err(format!("Failed to set limit_to boundary: {}", e)))
+    }
+
+    /// Keep only already-loaded points matching a small SQL-like WHERE expression (`=`, `!=`,
+    /// `<`, `<=`, `>`, `>=`, `IN (...)`, `AND`/`OR`) over `x`, `y`, `z`, `classification`,
+    /// `intensity`, `return_number` and `number_of_returns`, e.g. `"classification = 2"`. Call
+    /// after `set_bbox()` (which loads points) and before `run()`.
+    fn filter(&mut self, expr: &str) -> PyResult<()> {
+        self.inner
+            .filter(expr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply filter: {}", e)))
     }
 
     /// Run LiDAR processing workflow
     /// Following Python: def run(self, classification_list=None, resolution=1.0, write_out_file=True)
-    #[pyo3(signature = (classification_list = None, resolution = None, write_out_file = true))]
+    ///
+    /// `lof_k`/`lof_threshold` control `remove_lof_outliers`'s density-based outlier filter
+    /// (defaults mirror `Lidar::run`'s own `DEFAULT_LOF_K`/`DEFAULT_LOF_THRESHOLD`; pass a very
+    /// large `lof_threshold` to effectively disable it). `target_crs` overrides (and persists
+    /// into) the output EPSG for this run, reprojecting already-loaded points.
+    #[pyo3(signature = (file_name = None, classification_list = None, resolution = None, write_out_file = true, lof_k = None, lof_threshold = None, target_crs = None))]
+    #[allow(clippy::too_many_arguments)]
     fn run(
         mut slf: PyRefMut<Self>,
+        file_name: Option<String>,
         classification_list: Option<Vec<u8>>,
         resolution: Option<f64>,
         write_out_file: bool,
+        lof_k: Option<usize>,
+        lof_threshold: Option<f64>,
+        target_crs: Option<i32>,
     ) -> PyResult<String> {
         slf.inner
-            .run(classification_list, resolution, write_out_file)
-            .map(|path| path.to_string_lossy().to_string())
+            .run(
+                file_name,
+                classification_list,
+                resolution,
+                write_out_file,
+                lof_k,
+                lof_threshold,
+                target_crs,
+            )
+            .map(|output| output.output_path.to_string_lossy().to_string())
             .map_err(|e| PyValueError::new_err(format!("Failed to run Lidar: {}", e)))
     }
 
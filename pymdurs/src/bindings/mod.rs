@@ -1,15 +1,25 @@
 // Python bindings module
 // Each file contains one or more PyO3 #[pyclass] definitions
 
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+use rsmdu::geometric::export::OutputFormat;
+
 pub mod bounding_box;
 pub mod building;
 pub mod cadastre;
 pub mod dem;
 pub mod geo_core;
+pub mod geo_reader;
 pub mod iris;
 pub mod land_cover;
 pub mod lcz;
 pub mod lidar;
+pub mod road;
+pub mod road_graph;
+pub mod stac;
+pub mod vegetation;
+pub mod water;
 
 // Re-export all bindings for convenience
 pub use bounding_box::PyBoundingBox;
@@ -17,7 +27,32 @@ pub use building::PyBuilding;
 pub use cadastre::PyCadastre;
 pub use dem::PyDem;
 pub use geo_core::PyGeoCore;
+pub use geo_reader::PyGeoReader;
 pub use iris::PyIris;
-pub use land_cover::PyLandCover;
+pub use land_cover::{validate, PyLandCover};
 pub use lcz::PyLcz;
 pub use lidar::PyLidar;
+pub use road::PyRoad;
+pub use road_graph::PyRoadGraph;
+pub use stac::{dedupe_stac_items_by_footprint, PyStacClient, PyStacItem, PyStacSource};
+pub use vegetation::PyVegetation;
+pub use water::PyWater;
+
+/// Parse an OGR-style format name (`"gpkg"`, `"shapefile"`/`"shp"`, `"geojson"`, `"flatgeobuf"`/
+/// `"fgb"`, `"kml"`, `"kmz"`, `"gpx"`, case-insensitive) into an [`OutputFormat`] for `to_file`
+/// bindings, shared by every geometric producer's `to_file` method.
+pub(crate) fn parse_output_format(format: &str) -> PyResult<OutputFormat> {
+    match format.to_lowercase().as_str() {
+        "gpkg" | "geopackage" => Ok(OutputFormat::GeoPackage),
+        "shp" | "shapefile" => Ok(OutputFormat::Shapefile),
+        "geojson" | "json" => Ok(OutputFormat::GeoJson),
+        "fgb" | "flatgeobuf" => Ok(OutputFormat::FlatGeobuf),
+        "kml" => Ok(OutputFormat::Kml),
+        "kmz" => Ok(OutputFormat::Kmz),
+        "gpx" => Ok(OutputFormat::Gpx),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported format {:?}; expected one of gpkg, shapefile, geojson, flatgeobuf, kml, kmz, gpx",
+            other
+        ))),
+    }
+}
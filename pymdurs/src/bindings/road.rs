@@ -3,6 +3,8 @@ use pyo3::prelude::*;
 use rsmdu::geometric::road::Road;
 
 use crate::bindings::geo_core::PyGeoCore;
+use crate::bindings::road_graph::PyRoadGraph;
+use crate::bindings::parse_output_format;
 
 /// Road Python binding
 #[pyclass]
@@ -67,6 +69,27 @@ impl PyRoad {
             .map_err(|e| PyValueError::new_err(format!("Failed to save GeoJSON: {}", e)))
     }
 
+    /// Export to any OGR-supported vector format ("gpkg", "shapefile", "geojson",
+    /// "flatgeobuf", "kml", "kmz", or "gpx"), reprojecting to `geo_core`'s EPSG on the way out.
+    /// Returns the path to the written file.
+    #[pyo3(signature = (format, name = None))]
+    fn to_file(&self, format: &str, name: Option<&str>) -> PyResult<String> {
+        let format = parse_output_format(format)?;
+        self.inner
+            .to_file(name, format)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export road network: {}", e)))
+    }
+
+    /// Build a routable graph from the collected road network (see `PyRoadGraph` for
+    /// shortest-path and link-redundancy analysis)
+    fn build_graph(&self) -> PyResult<PyRoadGraph> {
+        self.inner
+            .build_graph()
+            .map(PyRoadGraph::from_inner)
+            .map_err(|e| PyValueError::new_err(format!("Failed to build road graph: {}", e)))
+    }
+
     /// Get output path
     fn get_output_path(&self) -> String {
         self.inner.get_output_path().to_string_lossy().to_string()
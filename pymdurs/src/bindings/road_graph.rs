@@ -0,0 +1,83 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use rsmdu::geometric::graph::{parse_hazard_geojson, RoadGraph};
+
+/// Routable road graph Python binding, built via `PyRoad::build_graph`.
+#[pyclass]
+pub struct PyRoadGraph {
+    inner: RoadGraph,
+}
+
+impl PyRoadGraph {
+    pub(crate) fn from_inner(inner: RoadGraph) -> Self {
+        PyRoadGraph { inner }
+    }
+}
+
+#[pymethods]
+impl PyRoadGraph {
+    /// Number of nodes (intersections/endpoints) in the graph
+    fn node_count(&self) -> usize {
+        self.inner.node_count()
+    }
+
+    /// Number of edges (road segments between nodes) in the graph
+    fn edge_count(&self) -> usize {
+        self.inner.edge_count()
+    }
+
+    /// Find the graph node nearest to an EPSG:4326 (lon, lat) coordinate
+    fn nearest_node(&self, lon: f64, lat: f64) -> Option<usize> {
+        self.inner.nearest_node(lon, lat)
+    }
+
+    /// Shortest path length in meters between two node ids, or None if they're disconnected
+    fn shortest_path(&self, origin: usize, destination: usize) -> Option<f64> {
+        self.inner.shortest_path(origin, destination)
+    }
+
+    /// Mark every edge inside a flood/hazard GeoJSON Polygon/MultiPolygon (EPSG:4326) as
+    /// impassable, for recomputing OD accessibility under that scenario
+    fn set_impassable_within(&mut self, hazard_geojson: &str) -> PyResult<()> {
+        let hazard = parse_hazard_geojson(hazard_geojson)
+            .map_err(|e| PyValueError::new_err(format!("Invalid hazard GeoJSON: {}", e)))?;
+        self.inner.set_impassable_within(&hazard);
+        Ok(())
+    }
+
+    /// Clear every edge's impassable flag set by `set_impassable_within`
+    fn clear_impassable(&mut self) {
+        self.inner.clear_impassable();
+    }
+
+    /// For each OD pair in `od_pairs`, temporarily remove every edge in turn and report the
+    /// resulting detour length (and whether removal disconnects the pair). Returns a list with
+    /// one dict per edge: `{"edge_id", "detours": [{"origin", "destination", "baseline_length",
+    /// "alternative_length", "detour_length", "disconnected"}, ...]}`.
+    fn link_redundancy(&self, py: Python, od_pairs: Vec<(usize, usize)>) -> PyResult<Py<PyList>> {
+        let links = self.inner.link_redundancy(&od_pairs);
+        let result = PyList::empty(py);
+
+        for link in links {
+            let detours = PyList::empty(py);
+            for detour in link.detours {
+                let entry = PyDict::new(py);
+                entry.set_item("origin", detour.origin)?;
+                entry.set_item("destination", detour.destination)?;
+                entry.set_item("baseline_length", detour.baseline_length)?;
+                entry.set_item("alternative_length", detour.alternative_length)?;
+                entry.set_item("detour_length", detour.detour_length)?;
+                entry.set_item("disconnected", detour.disconnected)?;
+                detours.append(entry)?;
+            }
+
+            let link_entry = PyDict::new(py);
+            link_entry.set_item("edge_id", link.edge_id)?;
+            link_entry.set_item("detours", detours)?;
+            result.append(link_entry)?;
+        }
+
+        Ok(result.unbind())
+    }
+}
@@ -0,0 +1,175 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rsmdu::collect::stac::{self, StacClient, StacItem, StacSource};
+
+use crate::bindings::bounding_box::PyBoundingBox;
+use crate::bindings::geo_core::PyGeoCore;
+
+/// STAC item Python binding, mirroring [`PyBoundingBox`]'s thin-wrapper-over-the-core-type shape.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyStacItem {
+    inner: StacItem,
+}
+
+#[pymethods]
+impl PyStacItem {
+    #[getter]
+    fn id(&self) -> String {
+        self.inner.id.clone()
+    }
+
+    #[getter]
+    fn datetime(&self) -> Option<String> {
+        self.inner.datetime.clone()
+    }
+
+    #[getter]
+    fn bbox(&self) -> Option<(f64, f64, f64, f64)> {
+        self.inner.bbox
+    }
+
+    /// Percent cloud cover reported by the provider's EO extension, when present.
+    #[getter]
+    fn cloud_cover(&self) -> Option<f64> {
+        self.inner.cloud_cover
+    }
+
+    /// Href of the first asset whose `roles` contains `role`, case-insensitively (e.g. `"dem"`,
+    /// `"dsm"`, or a provider-specific role like `"3d-tiles"`).
+    fn asset_href_by_role(&self, role: &str) -> Option<String> {
+        self.inner.asset_by_role(role).map(|asset| asset.href.clone())
+    }
+
+    /// Href of the first asset whose declared media type contains `media_type_fragment`,
+    /// case-insensitively (e.g. `"tiff"` to match a GeoTIFF DEM asset).
+    fn asset_href_by_media_type(&self, media_type_fragment: &str) -> Option<String> {
+        self.inner
+            .asset_by_media_type(media_type_fragment)
+            .map(|asset| asset.href.clone())
+    }
+}
+
+/// StacClient Python binding
+#[pyclass]
+pub struct PyStacClient {
+    inner: StacClient,
+}
+
+#[pymethods]
+impl PyStacClient {
+    /// `endpoint` is the STAC API root, e.g. `"https://earth-search.aws.element84.com/v1"`.
+    #[new]
+    fn new(endpoint: String) -> Self {
+        PyStacClient {
+            inner: StacClient::new(endpoint),
+        }
+    }
+
+    /// Expand every search bbox by `margin_deg` degrees (EPSG:4326) on each side before
+    /// querying, to capture tiles that only partially overlap the caller's area of interest.
+    fn set_margin(&mut self, margin_deg: f64) {
+        self.inner.set_margin(margin_deg);
+    }
+
+    /// Restrict `search` to items from this collection id (e.g. a provider's
+    /// `"swisssurface3d-raster"` elevation collection).
+    fn set_collection(&mut self, collection: String) {
+        self.inner.set_collection(collection);
+    }
+
+    /// Search for items intersecting `bbox`, optionally restricted to a datetime range (an RFC
+    /// 3339 interval such as `"2024-01-01T00:00:00Z/.."`; either side may be left open with
+    /// `".."`).
+    #[pyo3(signature = (bbox, datetime = None))]
+    fn search(&self, bbox: &PyBoundingBox, datetime: Option<&str>) -> PyResult<Vec<PyStacItem>> {
+        self.inner
+            .search(&bbox.inner, datetime)
+            .map(|items| {
+                items
+                    .into_iter()
+                    .map(|inner| PyStacItem { inner })
+                    .collect()
+            })
+            .map_err(|e| PyValueError::new_err(format!("Failed to query STAC endpoint: {}", e)))
+    }
+
+    /// Download the bytes (e.g. a DEM/DSM GeoTIFF or a 3D building tile) of `item`'s first asset
+    /// whose `roles` contains `role`, case-insensitively. Raises if no asset has that role.
+    fn download_asset_href(&self, item: &PyStacItem, role: &str) -> PyResult<Vec<u8>> {
+        let asset = item
+            .inner
+            .asset_by_role(role)
+            .ok_or_else(|| PyValueError::new_err(format!("No asset with role {:?}", role)))?;
+        self.inner
+            .download_asset(asset)
+            .map_err(|e| PyValueError::new_err(format!("Failed to download STAC asset: {}", e)))
+    }
+}
+
+/// StacSource Python binding
+#[pyclass]
+pub struct PyStacSource {
+    inner: StacSource,
+}
+
+#[pymethods]
+impl PyStacSource {
+    /// `collection_url` is a single STAC collection's endpoint, e.g.
+    /// `"https://earth-search.aws.element84.com/v1/collections/sentinel-2-l2a"`.
+    #[new]
+    #[pyo3(signature = (collection_url, output_path = None))]
+    fn new(collection_url: String, output_path: Option<String>) -> PyResult<Self> {
+        StacSource::new(collection_url, output_path)
+            .map(|inner| PyStacSource { inner })
+            .map_err(|e| PyValueError::new_err(format!("Failed to create StacSource: {}", e)))
+    }
+
+    /// Set bounding box
+    fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        self.inner.set_bbox(min_x, min_y, max_x, max_y);
+    }
+
+    /// Set CRS
+    fn set_crs(&mut self, epsg: i32) {
+        self.inner.set_crs(epsg);
+    }
+
+    /// Expand every search bbox by `margin_deg` degrees (EPSG:4326) on each side before
+    /// querying, to capture tiles that only partially overlap the area of interest.
+    fn set_margin(&mut self, margin_deg: f64) {
+        self.inner.set_margin(margin_deg);
+    }
+
+    /// Search the collection and download every matching raster/shapefile asset under the
+    /// configured output path, returning the paths written.
+    fn run(&self) -> PyResult<Vec<String>> {
+        self.inner
+            .run()
+            .map(|paths| {
+                paths
+                    .into_iter()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect()
+            })
+            .map_err(|e| PyValueError::new_err(format!("Failed to run StacSource: {}", e)))
+    }
+
+    /// Get GeoCore instance
+    #[getter]
+    fn geo_core(&self) -> PyGeoCore {
+        PyGeoCore {
+            inner: self.inner.geo_core.clone(),
+        }
+    }
+}
+
+/// Keep only the most recent item per spatial footprint, mirroring [`stac::dedupe_by_footprint`].
+#[pyfunction]
+pub fn dedupe_stac_items_by_footprint(items: Vec<PyStacItem>) -> Vec<PyStacItem> {
+    let inner_items = items.into_iter().map(|item| item.inner).collect();
+    stac::dedupe_by_footprint(inner_items)
+        .into_iter()
+        .map(|inner| PyStacItem { inner })
+        .collect()
+}
@@ -1,9 +1,42 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use rsmdu::geometric::vegetation::Vegetation;
+use rsmdu::geometric::vegetation::{
+    GapFillConfig, ReclassifyConfig, ReclassifyOperator, Vegetation, VegetationIndex,
+};
 
 use crate::bindings::geo_core::PyGeoCore;
 
+/// Parse an index name (`"ndvi"`, `"savi"`, `"gndvi"`, `"band_ratio"`, case-insensitive) into a
+/// [`VegetationIndex`] for `PyVegetation::new`. `savi_l` is only used for `"savi"`.
+fn parse_vegetation_index(index: &str, savi_l: Option<f64>) -> PyResult<VegetationIndex> {
+    match index.to_lowercase().as_str() {
+        "ndvi" => Ok(VegetationIndex::Ndvi),
+        "savi" => Ok(VegetationIndex::Savi {
+            l: savi_l.unwrap_or(0.5),
+        }),
+        "gndvi" => Ok(VegetationIndex::Gndvi),
+        "band_ratio" => Ok(VegetationIndex::BandRatio),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported vegetation index {:?}; expected one of ndvi, savi, gndvi, band_ratio",
+            other
+        ))),
+    }
+}
+
+/// Parse a comparison operator (`">"`, `"<"`, `">="`, `"<="`) into a [`ReclassifyOperator`].
+fn parse_reclassify_operator(operator: &str) -> PyResult<ReclassifyOperator> {
+    match operator {
+        ">" => Ok(ReclassifyOperator::GreaterThan),
+        "<" => Ok(ReclassifyOperator::LessThan),
+        ">=" => Ok(ReclassifyOperator::GreaterThanOrEqual),
+        "<=" => Ok(ReclassifyOperator::LessThanOrEqual),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported reclassify operator {:?}; expected one of >, <, >=, <=",
+            other
+        ))),
+    }
+}
+
 /// Vegetation Python binding
 #[pyclass]
 pub struct PyVegetation {
@@ -13,15 +46,91 @@ pub struct PyVegetation {
 #[pymethods]
 impl PyVegetation {
     #[new]
-    #[pyo3(signature = (filepath_shp = None, output_path = None, set_crs = None, write_file = false, min_area = 0.0))]
+    #[pyo3(signature = (
+        filepath_shp = None,
+        output_path = None,
+        set_crs = None,
+        write_file = false,
+        min_area = 0.0,
+        index = None,
+        savi_l = None,
+        reclassify_operator = None,
+        reclassify_threshold = None,
+        reclassify_pixel_value = None,
+        reclassify_nodata_value = None,
+        gap_fill_max_search_distance = None,
+        gap_fill_smoothing_iterations = None,
+        gap_fill_eight_directions = false,
+        dissolve = false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         filepath_shp: Option<String>,
         output_path: Option<String>,
         set_crs: Option<i32>,
         write_file: bool,
         min_area: f64,
+        index: Option<&str>,
+        savi_l: Option<f64>,
+        reclassify_operator: Option<&str>,
+        reclassify_threshold: Option<f64>,
+        reclassify_pixel_value: Option<f64>,
+        reclassify_nodata_value: Option<f64>,
+        gap_fill_max_search_distance: Option<usize>,
+        gap_fill_smoothing_iterations: Option<usize>,
+        gap_fill_eight_directions: bool,
+        dissolve: bool,
     ) -> PyResult<Self> {
-        match Vegetation::new(filepath_shp, output_path, set_crs, write_file, min_area) {
+        let index = index
+            .map(|index| parse_vegetation_index(index, savi_l))
+            .transpose()?;
+
+        let reclassify = if reclassify_operator.is_some()
+            || reclassify_threshold.is_some()
+            || reclassify_pixel_value.is_some()
+            || reclassify_nodata_value.is_some()
+        {
+            let default = ReclassifyConfig::default();
+            Some(ReclassifyConfig {
+                operator: reclassify_operator
+                    .map(parse_reclassify_operator)
+                    .transpose()?
+                    .unwrap_or(default.operator),
+                threshold: reclassify_threshold.unwrap_or(default.threshold),
+                pixel_value: reclassify_pixel_value.unwrap_or(default.pixel_value),
+                nodata_value: reclassify_nodata_value.unwrap_or(default.nodata_value),
+            })
+        } else {
+            None
+        };
+
+        let gap_fill = if gap_fill_max_search_distance.is_some()
+            || gap_fill_smoothing_iterations.is_some()
+            || gap_fill_eight_directions
+        {
+            let default = GapFillConfig::default();
+            Some(GapFillConfig {
+                max_search_distance: gap_fill_max_search_distance
+                    .unwrap_or(default.max_search_distance),
+                smoothing_iterations: gap_fill_smoothing_iterations
+                    .unwrap_or(default.smoothing_iterations),
+                eight_directions: gap_fill_eight_directions,
+            })
+        } else {
+            None
+        };
+
+        match Vegetation::new(
+            filepath_shp,
+            output_path,
+            set_crs,
+            write_file,
+            min_area,
+            index,
+            reclassify,
+            gap_fill,
+            dissolve,
+        ) {
             Ok(vegetation) => Ok(PyVegetation { inner: vegetation }),
             Err(e) => Err(PyValueError::new_err(format!(
                 "Failed to create Vegetation: {}",
@@ -40,6 +149,29 @@ impl PyVegetation {
         self.inner.set_crs(epsg);
     }
 
+    /// Set the GDAL multipart upload chunk size (MB) used when `filepath_shp`/`output_path`
+    /// points at an `s3://`/`/vsis3/` destination.
+    fn set_vsi_chunk_size_mb(&mut self, chunk_size_mb: u32) {
+        self.inner.set_vsi_chunk_size_mb(chunk_size_mb);
+    }
+
+    /// Reproject the stored GeoJSON to a different CRS, e.g. to get metric coordinates for
+    /// area calculations after a WGS84 run.
+    fn reproject(&mut self, to_epsg: i32) -> PyResult<()> {
+        self.inner
+            .reproject(to_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject: {}", e)))
+    }
+
+    /// Merge the filtered vegetation polygons into a single MultiPolygon. Usually unnecessary
+    /// to call directly -- pass `dissolve=True` to the constructor to run this automatically
+    /// as part of `run()`.
+    fn dissolve(&mut self) -> PyResult<()> {
+        self.inner
+            .dissolve()
+            .map_err(|e| PyValueError::new_err(format!("Failed to dissolve polygons: {}", e)))
+    }
+
     /// Run vegetation processing: calculate NDVI from IRC or load from shapefile
     fn run(mut slf: PyRefMut<Self>) -> PyResult<PyRefMut<Self>> {
         // Use run_internal which works on &mut self
@@ -73,6 +205,15 @@ impl PyVegetation {
             .map_err(|e| PyValueError::new_err(format!("Failed to save GeoJSON: {}", e)))
     }
 
+    /// Export the filtered vegetation polygons as a single-file PMTiles vector tile archive.
+    #[pyo3(signature = (name = None, min_zoom = 0, max_zoom = 14))]
+    fn to_pmtiles(&self, name: Option<&str>, min_zoom: u8, max_zoom: u8) -> PyResult<String> {
+        self.inner
+            .to_pmtiles(name, min_zoom, max_zoom)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export PMTiles: {}", e)))
+    }
+
     /// Get output path
     fn get_output_path(&self) -> String {
         self.inner.get_output_path().to_string_lossy().to_string()
@@ -1,8 +1,9 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use rsmdu::geometric::water::Water;
+use rsmdu::geometric::water::{Water, WaterSource};
 
 use crate::bindings::geo_core::PyGeoCore;
+use crate::bindings::parse_output_format;
 
 /// Water Python binding
 #[pyclass]
@@ -13,13 +14,19 @@ pub struct PyWater {
 #[pymethods]
 impl PyWater {
     #[new]
-    #[pyo3(signature = (filepath_shp = None, output_path = None, set_crs = None))]
+    #[pyo3(signature = (filepath_shp = None, output_path = None, set_crs = None, use_ign = false))]
     fn new(
         filepath_shp: Option<String>,
         output_path: Option<String>,
         set_crs: Option<i32>,
+        use_ign: bool,
     ) -> PyResult<Self> {
-        match Water::new(filepath_shp, output_path, set_crs) {
+        let source = if use_ign {
+            WaterSource::Ign
+        } else {
+            WaterSource::Osm
+        };
+        match Water::new(filepath_shp, output_path, set_crs, Some(source)) {
             Ok(water) => Ok(PyWater { inner: water }),
             Err(e) => Err(PyValueError::new_err(format!(
                 "Failed to create Water: {}",
@@ -38,6 +45,31 @@ impl PyWater {
         self.inner.set_crs(epsg);
     }
 
+    /// Restrict `run()`'s output to features inside a GeoJSON Polygon/MultiPolygon boundary
+    /// (EPSG:4326), passed as a GeoJSON string.
+    fn set_limit_to(&mut self, boundary: &str) -> PyResult<()> {
+        self.inner
+            .set_limit_to(boundary.as_bytes())
+            .map_err(|e| PyValueError::new_err(format!("Failed to set limit_to boundary: {}", e)))
+    }
+
+    /// Reproject the stored GeoJSON to a different CRS, e.g. to get metric coordinates for
+    /// area calculations after a WGS84 run.
+    fn reproject(&mut self, to_epsg: i32) -> PyResult<()> {
+        self.inner
+            .reproject(to_epsg)
+            .map_err(|e| PyValueError::new_err(format!("Failed to reproject: {}", e)))
+    }
+
+    /// Keep only features matching a small SQL-like WHERE expression over their properties
+    /// (`=`, `!=`, `<`, `<=`, `>`, `>=`, `IN (...)`, `AND`/`OR`), e.g. `"nature = 'ETANG'"`.
+    /// Chainable after `run()` and before `to_geojson`/`get_geojson`.
+    fn filter(&mut self, expr: &str) -> PyResult<()> {
+        self.inner
+            .filter(expr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply filter: {}", e)))
+    }
+
     /// Run water processing: download from IGN API or load from shapefile, parse GeoJSON
     fn run(mut slf: PyRefMut<Self>) -> PyResult<PyRefMut<Self>> {
         // Use run_internal which works on &mut self
@@ -63,14 +95,27 @@ impl PyWater {
         }
     }
 
-    /// Save to GeoJSON file
-    #[pyo3(signature = (name = None))]
-    fn to_geojson(&self, name: Option<&str>) -> PyResult<()> {
+    /// Save to GeoJSON file. With `seq=True`, writes newline-delimited GeoJSON (one Feature
+    /// per line) instead of a single FeatureCollection document.
+    #[pyo3(signature = (name = None, seq = false))]
+    fn to_geojson(&self, name: Option<&str>, seq: bool) -> PyResult<()> {
         self.inner
-            .to_geojson(name)
+            .to_geojson(name, seq)
             .map_err(|e| PyValueError::new_err(format!("Failed to save GeoJSON: {}", e)))
     }
 
+    /// Export to any OGR-supported vector format ("gpkg", "shapefile", "geojson",
+    /// "flatgeobuf", "kml", "kmz", or "gpx"), reprojecting to `geo_core`'s EPSG on the way out.
+    /// Returns the path to the written file.
+    #[pyo3(signature = (format, name = None))]
+    fn to_file(&self, format: &str, name: Option<&str>) -> PyResult<String> {
+        let format = parse_output_format(format)?;
+        self.inner
+            .to_file(name, format)
+            .map(|path| path.to_string_lossy().to_string())
+            .map_err(|e| PyValueError::new_err(format!("Failed to export water features: {}", e)))
+    }
+
     /// Get output path
     fn get_output_path(&self) -> String {
         self.inner.get_output_path().to_string_lossy().to_string()
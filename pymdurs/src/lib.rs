@@ -2,7 +2,11 @@ use pyo3::prelude::*;
 
 mod bindings;
 
-use bindings::{PyBoundingBox, PyBuilding, PyCadastre, PyDem, PyGeoCore, PyIris, PyLcz};
+use bindings::{
+    dedupe_stac_items_by_footprint, validate, PyBoundingBox, PyBuilding, PyCadastre, PyDem,
+    PyGeoCore, PyGeoReader, PyIris, PyLcz, PyRoad, PyRoadGraph, PyStacClient, PyStacItem,
+    PyStacSource, PyVegetation, PyWater,
+};
 
 /// Python bindings for pymdurs
 /// Rust transpilation of pymdu (Python Urban Data Model)
@@ -16,19 +20,37 @@ fn pymdurs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     geometric.add_class::<PyCadastre>()?;
     geometric.add_class::<PyIris>()?;
     geometric.add_class::<PyLcz>()?;
+    geometric.add_class::<PyRoad>()?;
+    geometric.add_class::<PyRoadGraph>()?;
+    geometric.add_class::<PyVegetation>()?;
+    geometric.add_class::<PyWater>()?;
     // Add aliases for Pythonic API (Building instead of PyBuilding)
     geometric.setattr("Building", geometric.getattr("PyBuilding")?)?;
     geometric.setattr("Dem", geometric.getattr("PyDem")?)?;
     geometric.setattr("Cadastre", geometric.getattr("PyCadastre")?)?;
     geometric.setattr("Iris", geometric.getattr("PyIris")?)?;
     geometric.setattr("Lcz", geometric.getattr("PyLcz")?)?;
+    geometric.setattr("Road", geometric.getattr("PyRoad")?)?;
+    geometric.setattr("RoadGraph", geometric.getattr("PyRoadGraph")?)?;
+    geometric.setattr("Vegetation", geometric.getattr("PyVegetation")?)?;
+    geometric.setattr("Water", geometric.getattr("PyWater")?)?;
     m.add_submodule(&geometric)?;
 
     m.add_class::<PyBoundingBox>()?;
     m.add_class::<PyGeoCore>()?;
+    m.add_class::<PyGeoReader>()?;
+    m.add_class::<PyStacClient>()?;
+    m.add_class::<PyStacItem>()?;
+    m.add_class::<PyStacSource>()?;
+    m.add_function(wrap_pyfunction!(dedupe_stac_items_by_footprint, m)?)?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
     // Add aliases for Pythonic API
     m.setattr("BoundingBox", m.getattr("PyBoundingBox")?)?;
     m.setattr("GeoCore", m.getattr("PyGeoCore")?)?;
+    m.setattr("GeoReader", m.getattr("PyGeoReader")?)?;
+    m.setattr("StacClient", m.getattr("PyStacClient")?)?;
+    m.setattr("StacItem", m.getattr("PyStacItem")?)?;
+    m.setattr("StacSource", m.getattr("PyStacSource")?)?;
 
     Ok(())
 }
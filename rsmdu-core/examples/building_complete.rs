@@ -96,6 +96,7 @@ fn example_from_geojson() -> Result<()> {
         None,
         3.0,  // default_storey_height
         None, // set_crs
+        None, // point_buffer_radius
     )?;
 
     println!("  - Nombre de bâtiments chargés: {}", collection.len());
@@ -23,7 +23,7 @@ fn main() -> Result<()> {
     // Run DEM processing
     // Python: ign_dem = dem.run()
     println!("Téléchargement et traitement du DEM depuis l'API IGN...");
-    let dem_result = dem.run(None)?;
+    let dem_result = dem.run(None, false)?;
 
     println!("\nDEM traité avec succès!");
     println!("  - Fichier DEM: {:?}", dem_result.get_path_save_tiff());
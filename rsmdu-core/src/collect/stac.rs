@@ -0,0 +1,207 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::geo_core::BoundingBox;
+
+/// One asset entry from a STAC Item's `assets` map, trimmed down to what callers need to decide
+/// whether and how to download it.
+#[derive(Debug, Clone)]
+pub struct StacAsset {
+    pub href: String,
+    pub roles: Vec<String>,
+    pub media_type: Option<String>,
+}
+
+/// A single item returned by a [`StacClient::search`], parsed from the STAC API's GeoJSON
+/// ItemCollection response.
+#[derive(Debug, Clone)]
+pub struct StacItem {
+    pub id: String,
+    pub datetime: Option<String>,
+    pub bbox: Option<(f64, f64, f64, f64)>,
+    pub assets: HashMap<String, StacAsset>,
+}
+
+impl StacItem {
+    /// First asset whose `roles` contains `role`, case-insensitively (e.g. `"dem"`, `"dsm"`, or a
+    /// provider-specific role like `"3d-tiles"`).
+    pub fn asset_by_role(&self, role: &str) -> Option<&StacAsset> {
+        self.assets
+            .values()
+            .find(|asset| asset.roles.iter().any(|r| r.eq_ignore_ascii_case(role)))
+    }
+
+    /// First asset whose declared media type contains `media_type_fragment`, case-insensitively
+    /// (e.g. `"tiff"` to match `"image/tiff; application=geotiff"`).
+    pub fn asset_by_media_type(&self, media_type_fragment: &str) -> Option<&StacAsset> {
+        let needle = media_type_fragment.to_lowercase();
+        self.assets.values().find(|asset| {
+            asset
+                .media_type
+                .as_deref()
+                .is_some_and(|mt| mt.to_lowercase().contains(&needle))
+        })
+    }
+}
+
+/// Raw asset shape as returned by any STAC API (`assets.<key>`), deserialized before being
+/// trimmed down to [`StacAsset`].
+#[derive(Debug, Deserialize)]
+struct StacAssetRaw {
+    href: String,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(rename = "type")]
+    media_type: Option<String>,
+}
+
+/// Raw item shape as returned by any STAC API (one GeoJSON Feature of an ItemCollection).
+#[derive(Debug, Deserialize)]
+struct StacItemRaw {
+    id: String,
+    #[serde(default)]
+    bbox: Option<Vec<f64>>,
+    #[serde(default)]
+    properties: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    assets: HashMap<String, StacAssetRaw>,
+}
+
+/// Raw ItemCollection shape returned by `GET/POST {endpoint}/search`.
+#[derive(Debug, Deserialize)]
+struct StacItemCollectionRaw {
+    #[serde(default)]
+    features: Vec<StacItemRaw>,
+}
+
+impl From<StacItemRaw> for StacItem {
+    fn from(raw: StacItemRaw) -> Self {
+        let bbox = match raw.bbox.as_deref() {
+            Some([min_x, min_y, max_x, max_y, ..]) => Some((*min_x, *min_y, *max_x, *max_y)),
+            _ => None,
+        };
+        let datetime = raw
+            .properties
+            .get("datetime")
+            .and_then(|value| value.as_str())
+            .map(|s| s.to_string());
+        let assets = raw
+            .assets
+            .into_iter()
+            .map(|(key, asset)| {
+                (
+                    key,
+                    StacAsset {
+                        href: asset.href,
+                        roles: asset.roles,
+                        media_type: asset.media_type,
+                    },
+                )
+            })
+            .collect();
+
+        StacItem {
+            id: raw.id,
+            datetime,
+            bbox,
+            assets,
+        }
+    }
+}
+
+/// Client for any SpatioTemporal Asset Catalog (STAC) API endpoint. Generalizes
+/// [`crate::collect::ign::ign_collect::IgnCollect`]'s single French-IGN-WFS source to any
+/// STAC-compliant provider (e.g. Microsoft Planetary Computer, Earth Search), so `dem`/`lidar`/
+/// `building` pipelines can pull elevation and building data from whichever catalog the caller
+/// points at, using the same [`BoundingBox`].
+pub struct StacClient {
+    endpoint: String,
+    client: Client,
+}
+
+impl StacClient {
+    /// `endpoint` is the STAC API root, e.g. `"https://earth-search.aws.element84.com/v1"`.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        StacClient {
+            endpoint: endpoint.into(),
+            client: Client::new(),
+        }
+    }
+
+    /// Search `{endpoint}/search` for items intersecting `bbox`, optionally restricted to a
+    /// datetime range (an RFC 3339 interval such as `"2024-01-01T00:00:00Z/.."`, per the STAC API
+    /// item-search spec; either side may be left open with `".."`).
+    pub fn search(&self, bbox: &BoundingBox, datetime: Option<&str>) -> Result<Vec<StacItem>> {
+        let mut query: Vec<(&str, String)> = vec![(
+            "bbox",
+            format!("{},{},{},{}", bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y),
+        )];
+        if let Some(datetime) = datetime {
+            query.push(("datetime", datetime.to_string()));
+        }
+
+        let url = format!("{}/search", self.endpoint.trim_end_matches('/'));
+        let response = self
+            .client
+            .get(&url)
+            .query(&query)
+            .send()
+            .context("Failed to query STAC endpoint")?
+            .error_for_status()
+            .context("STAC endpoint returned an error status")?;
+
+        let raw: StacItemCollectionRaw = response
+            .json()
+            .context("Failed to parse STAC item collection")?;
+
+        Ok(raw.features.into_iter().map(StacItem::from).collect())
+    }
+
+    /// Download an asset's bytes (e.g. a DEM/DSM GeoTIFF or a 3D building tile) for handoff to
+    /// the `dem`/`lidar`/`building` pipelines.
+    pub fn download_asset(&self, asset: &StacAsset) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .get(&asset.href)
+            .send()
+            .context("Failed to download STAC asset")?
+            .error_for_status()
+            .context("STAC asset download returned an error status")?;
+
+        Ok(response
+            .bytes()
+            .context("Failed to read STAC asset body")?
+            .to_vec())
+    }
+}
+
+/// Keep only the most recent item per spatial footprint. Providers often publish overlapping
+/// tiles across revisit dates, so items are grouped by bbox (rounded to ~0.1m to absorb
+/// floating-point noise) and, within each group, the item with the lexicographically greatest
+/// `datetime` wins (RFC 3339 timestamps sort correctly as strings). Items without a bbox can't be
+/// grouped and are all kept as-is.
+pub fn dedupe_by_footprint(items: Vec<StacItem>) -> Vec<StacItem> {
+    let mut by_footprint: HashMap<(i64, i64, i64, i64), StacItem> = HashMap::new();
+    let mut unfootprinted = Vec::new();
+
+    for item in items {
+        match item.bbox {
+            Some((min_x, min_y, max_x, max_y)) => {
+                let round = |v: f64| (v * 1e6).round() as i64;
+                let key = (round(min_x), round(min_y), round(max_x), round(max_y));
+                let keep = match by_footprint.get(&key) {
+                    Some(existing) => item.datetime.as_deref() > existing.datetime.as_deref(),
+                    None => true,
+                };
+                if keep {
+                    by_footprint.insert(key, item);
+                }
+            }
+            None => unfootprinted.push(item),
+        }
+    }
+
+    by_footprint.into_values().chain(unfootprinted).collect()
+}
@@ -0,0 +1,593 @@
+use anyhow::{Context, Result};
+use gdal::vector::{FieldDefn, Geometry as GdalGeometry, LayerAccess, LayerOptions, OGRFieldType, ToGdal};
+use gdal::{Dataset, DatasetOptions, DriverManager, GdalOpenFlags};
+use geojson::{Feature, GeoJson, JsonValue};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// One row of a class -> display mapping for [`write_leaflet_html`]: the raw property value a
+/// feature must match (stringified, since GeoJSON property values can be numbers or strings),
+/// its human-readable legend label, and the CSS color used to fill/outline matching features.
+/// Built by callers from whatever class table they already carry -- `Lcz::table_color`'s
+/// `{code: (name, color)}`, or an analogous mapping for `Road`/`BuildingCollection`.
+pub struct HtmlClassStyle {
+    pub value: String,
+    pub label: String,
+    pub color: String,
+}
+
+/// Escape a string for embedding inside a single-quoted JS string literal.
+fn js_string_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'").replace('\n', "\\n")
+}
+
+/// Write a self-contained Leaflet HTML map of `geojson` to `path`: each feature is filled/
+/// outlined by looking up `feature.properties[class_property]` in `classes`, a legend lists
+/// every class's label and color, and each feature gets a tooltip listing `tooltip_fields`
+/// plus a mouseover highlight (thicker border, higher fill opacity) that resets on mouseout --
+/// the same highlight-on-hover pattern used by web GeoJSON viewers. Leaflet itself is loaded
+/// from its public CDN, so the file needs network access to render but no local asset bundling.
+pub fn write_leaflet_html(
+    geojson: &GeoJson,
+    path: &Path,
+    class_property: &str,
+    classes: &[HtmlClassStyle],
+    tooltip_fields: &[String],
+) -> Result<()> {
+    let mut color_table = String::from("{");
+    let mut legend_rows = String::new();
+    for class in classes {
+        write!(
+            color_table,
+            "'{}': {{label: '{}', color: '{}'}}, ",
+            js_string_escape(&class.value),
+            js_string_escape(&class.label),
+            js_string_escape(&class.color)
+        )
+        .context("Failed to build class color table")?;
+        writeln!(
+            legend_rows,
+            r#"<div><span class="swatch" style="background:{}"></span>{}</div>"#,
+            class.color, class.label
+        )
+        .context("Failed to build legend row")?;
+    }
+    color_table.push('}');
+
+    let tooltip_fields_js = tooltip_fields
+        .iter()
+        .map(|f| format!("'{}'", js_string_escape(f)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8" />
+<link rel="stylesheet" href="https://unpkg.com/leaflet@1.9.4/dist/leaflet.css" />
+<script src="https://unpkg.com/leaflet@1.9.4/dist/leaflet.js"></script>
+<style>
+  html, body, #map {{ height: 100%; margin: 0; }}
+  .legend {{ background: white; padding: 8px 10px; line-height: 1.4; font: 13px sans-serif; }}
+  .legend .swatch {{ display: inline-block; width: 14px; height: 14px; margin-right: 6px; vertical-align: middle; }}
+</style>
+</head>
+<body>
+<div id="map"></div>
+<script>
+  const featureData = {geojson};
+  const classProperty = '{class_property}';
+  const classColors = {color_table};
+  const tooltipFields = [{tooltip_fields_js}];
+
+  const map = L.map('map');
+
+  function colorFor(feature) {{
+    const entry = classColors[String(feature.properties[classProperty])];
+    return entry ? entry.color : '#999999';
+  }}
+
+  function styleFor(feature) {{
+    return {{ fillColor: colorFor(feature), color: 'white', weight: 1, opacity: 1, fillOpacity: 0.7 }};
+  }}
+
+  function highlightFeature(e) {{
+    const layer = e.target;
+    layer.setStyle({{ weight: 3, opacity: 1, fillOpacity: 0.9 }});
+    layer.bringToFront();
+  }}
+
+  function resetHighlight(e) {{
+    geoLayer.resetStyle(e.target);
+  }}
+
+  function onEachFeature(feature, layer) {{
+    const lines = tooltipFields
+      .filter((field) => feature.properties && feature.properties[field] !== undefined)
+      .map((field) => `<b>${{field}}</b>: ${{feature.properties[field]}}`);
+    if (lines.length > 0) {{
+      layer.bindTooltip(lines.join('<br>'));
+    }}
+    layer.on({{ mouseover: highlightFeature, mouseout: resetHighlight }});
+  }}
+
+  const geoLayer = L.geoJSON(featureData, {{ style: styleFor, onEachFeature: onEachFeature }}).addTo(map);
+  map.fitBounds(geoLayer.getBounds());
+
+  const legend = L.control({{ position: 'bottomright' }});
+  legend.onAdd = function () {{
+    const div = L.DomUtil.create('div', 'legend');
+    div.innerHTML = {legend_html_js};
+    return div;
+  }};
+  legend.addTo(map);
+</script>
+</body>
+</html>
+"#,
+        geojson = geojson.to_string(),
+        class_property = js_string_escape(class_property),
+        color_table = color_table,
+        tooltip_fields_js = tooltip_fields_js,
+        legend_html_js = format!("'{}'", js_string_escape(&legend_rows))
+    );
+
+    std::fs::write(path, html).with_context(|| format!("Failed to write HTML map to {:?}", path))
+}
+
+/// OGR-style vector output formats shared by every geometric producer's `to_file`, selecting a
+/// driver by format instead of a raw driver-name string. Mirrors the role
+/// `rsmdu::geometric::export::OutputFormat` plays in the sibling crate, which shells out to
+/// `ogr2ogr` instead of going through `gdal`'s vector bindings directly as [`VectorWriter`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorFormat {
+    GeoPackage,
+    Shapefile,
+    GeoJson,
+    FlatGeobuf,
+    /// GeoParquet, via GDAL/OGR's "Parquet" driver. See [`VectorWriter::write_with_layer_options`]
+    /// for passing a [`GeoParquetCompression`] through as a layer creation option.
+    GeoParquet,
+}
+
+impl VectorFormat {
+    /// OGR driver name, as passed to [`DriverManager::get_driver_by_name`].
+    pub fn driver_name(&self) -> &'static str {
+        match self {
+            VectorFormat::GeoPackage => "GPKG",
+            VectorFormat::Shapefile => "ESRI Shapefile",
+            VectorFormat::GeoJson => "GeoJSON",
+            VectorFormat::FlatGeobuf => "FlatGeobuf",
+            VectorFormat::GeoParquet => "Parquet",
+        }
+    }
+
+    /// File extension (without the leading dot) used for the output file.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            VectorFormat::GeoPackage => "gpkg",
+            VectorFormat::Shapefile => "shp",
+            VectorFormat::GeoJson => "geojson",
+            VectorFormat::FlatGeobuf => "fgb",
+            VectorFormat::GeoParquet => "parquet",
+        }
+    }
+
+    /// Guess a format from a file extension (leading dot and case both ignored), the way
+    /// `ogr2ogr` infers a driver from the output path when `-f` is omitted.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.trim_start_matches('.').to_lowercase().as_str() {
+            "gpkg" => Some(VectorFormat::GeoPackage),
+            "shp" => Some(VectorFormat::Shapefile),
+            "geojson" | "json" => Some(VectorFormat::GeoJson),
+            "fgb" => Some(VectorFormat::FlatGeobuf),
+            "parquet" | "geoparquet" => Some(VectorFormat::GeoParquet),
+            _ => None,
+        }
+    }
+}
+
+/// Parquet compression codec for [`VectorFormat::GeoParquet`] output, passed to GDAL's Parquet
+/// driver as a `COMPRESSION` layer creation option via
+/// [`VectorWriter::write_with_layer_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoParquetCompression {
+    #[default]
+    Snappy,
+    Gzip,
+    Brotli,
+    Zstd,
+    Uncompressed,
+}
+
+impl GeoParquetCompression {
+    /// The `"COMPRESSION=<value>"` layer creation option GDAL's Parquet driver expects.
+    pub fn as_layer_option(&self) -> &'static str {
+        match self {
+            GeoParquetCompression::Snappy => "COMPRESSION=SNAPPY",
+            GeoParquetCompression::Gzip => "COMPRESSION=GZIP",
+            GeoParquetCompression::Brotli => "COMPRESSION=BROTLI",
+            GeoParquetCompression::Zstd => "COMPRESSION=ZSTD",
+            GeoParquetCompression::Uncompressed => "COMPRESSION=UNCOMPRESSED",
+        }
+    }
+}
+
+/// Write `geojson` to `path` in `format`, honoring `epsg` as the output dataset's spatial
+/// reference. This is the single conversion entry point every geometric producer's `to_file`
+/// goes through, centralizing format selection and CRS handling the way `ogr2ogr -f <driver>
+/// -t_srs EPSG:<epsg>` does for the shell-based exporter in the `rsmdu` crate.
+pub fn write_vector_file(
+    path: &Path,
+    layer_name: &str,
+    geojson: &GeoJson,
+    format: VectorFormat,
+    epsg: Option<i32>,
+) -> Result<()> {
+    VectorWriter::write(path, format.driver_name(), layer_name, geojson, epsg).with_context(|| {
+        format!(
+            "Failed to write {:?} as {} (EPSG:{:?})",
+            path,
+            format.driver_name(),
+            epsg
+        )
+    })
+}
+
+/// Same as [`write_vector_file`], additionally passing `layer_creation_options` through to
+/// [`VectorWriter::write_with_layer_options`] -- used by [`VectorFormat::GeoParquet`] exporters
+/// to select a [`GeoParquetCompression`].
+pub fn write_vector_file_with_options(
+    path: &Path,
+    layer_name: &str,
+    geojson: &GeoJson,
+    format: VectorFormat,
+    epsg: Option<i32>,
+    layer_creation_options: &[&str],
+) -> Result<()> {
+    VectorWriter::write_with_layer_options(
+        path,
+        format.driver_name(),
+        layer_name,
+        geojson,
+        epsg,
+        layer_creation_options,
+    )
+    .with_context(|| {
+        format!(
+            "Failed to write {:?} as {} (EPSG:{:?})",
+            path,
+            format.driver_name(),
+            epsg
+        )
+    })
+}
+
+/// Writes GeoJSON features to an OGR-backed vector dataset.
+/// Mirrors the driver dispatch that `ogr2ogr` performs: pick a driver by name,
+/// create the output dataset, build a layer with a schema derived from the
+/// feature properties, then copy each feature's geometry and attributes.
+pub struct VectorWriter;
+
+impl VectorWriter {
+    /// OGR driver names accepted by [`VectorWriter::write`], matching the
+    /// subset of `ogr2ogr -f <driver>` formats this crate supports.
+    pub const SUPPORTED_DRIVERS: &'static [&'static str] =
+        &["GPKG", "ESRI Shapefile", "KML", "GeoJSON", "FlatGeobuf", "Parquet"];
+
+    /// Write a parsed [`GeoJson`] document to `path` using the named OGR driver, setting the
+    /// layer's spatial reference from `epsg` when given.
+    pub fn write(
+        path: &Path,
+        driver_name: &str,
+        layer_name: &str,
+        geojson: &GeoJson,
+        epsg: Option<i32>,
+    ) -> Result<()> {
+        Self::write_with_layer_options(path, driver_name, layer_name, geojson, epsg, &[])
+    }
+
+    /// Same as [`VectorWriter::write`], additionally passing `layer_creation_options` (each a
+    /// `"KEY=VALUE"` string) to [`gdal::Dataset::create_layer`] -- e.g.
+    /// [`GeoParquetCompression::as_layer_option`] when `driver_name` is `"Parquet"`.
+    pub fn write_with_layer_options(
+        path: &Path,
+        driver_name: &str,
+        layer_name: &str,
+        geojson: &GeoJson,
+        epsg: Option<i32>,
+        layer_creation_options: &[&str],
+    ) -> Result<()> {
+        if !Self::SUPPORTED_DRIVERS.contains(&driver_name) {
+            anyhow::bail!(
+                "Unsupported driver {:?}; expected one of {:?}",
+                driver_name,
+                Self::SUPPORTED_DRIVERS
+            );
+        }
+
+        let features = Self::feature_list(geojson)?;
+
+        if path.exists() {
+            std::fs::remove_file(path)
+                .with_context(|| format!("Failed to remove existing output file: {:?}", path))?;
+        }
+
+        let driver = DriverManager::get_driver_by_name(driver_name)
+            .with_context(|| format!("OGR driver {:?} is not available", driver_name))?;
+
+        let mut dataset = driver
+            .create_vector_only(path)
+            .with_context(|| format!("Failed to create {} dataset at {:?}", driver_name, path))?;
+
+        let field_names = Self::collect_field_names(&features);
+
+        let mut layer = dataset
+            .create_layer(LayerOptions {
+                name: layer_name,
+                srs: None,
+                ty: gdal::vector::OGRwkbGeometryType::wkbUnknown,
+                options: if layer_creation_options.is_empty() {
+                    None
+                } else {
+                    Some(layer_creation_options)
+                },
+            })
+            .context("Failed to create layer")?;
+
+        if let Some(epsg) = epsg {
+            let srs = gdal::spatial_ref::SpatialRef::from_epsg(epsg as u32)
+                .with_context(|| format!("Failed to build spatial reference for EPSG:{}", epsg))?;
+            layer
+                .set_spatial_ref(&srs)
+                .context("Failed to set layer spatial reference")?;
+        }
+
+        for field_name in &field_names {
+            let field_type = Self::infer_field_type(&features, field_name);
+            let field_defn = FieldDefn::new(field_name, field_type)
+                .with_context(|| format!("Failed to build field definition for {:?}", field_name))?;
+            field_defn
+                .add_to_layer(&layer)
+                .with_context(|| format!("Failed to add field {:?} to layer", field_name))?;
+        }
+
+        for feature in &features {
+            Self::write_feature(&mut layer, feature, &field_names)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write `geojson` to `path` using a single OGR transaction: open or create the destination
+    /// layer, optionally truncate its existing features, append every feature, then commit once
+    /// at the end -- rolling back on the first error instead of leaving partial output behind.
+    /// This avoids GDAL's implicit per-call `StartTransaction`, which serializes every
+    /// `CreateFeature` into its own commit and is why row-by-row imports into GeoPackage or a
+    /// PostGIS target are slow.
+    pub fn write_transactional(
+        path: &Path,
+        driver_name: &str,
+        layer_name: &str,
+        geojson: &GeoJson,
+        truncate: bool,
+    ) -> Result<()> {
+        if !Self::SUPPORTED_DRIVERS.contains(&driver_name) {
+            anyhow::bail!(
+                "Unsupported driver {:?}; expected one of {:?}",
+                driver_name,
+                Self::SUPPORTED_DRIVERS
+            );
+        }
+
+        let features = Self::feature_list(geojson)?;
+        let field_names = Self::collect_field_names(&features);
+        let reuse_existing = path.exists();
+
+        let mut dataset = if reuse_existing {
+            Dataset::open_ex(
+                path,
+                DatasetOptions {
+                    open_flags: GdalOpenFlags::GDAL_OF_UPDATE | GdalOpenFlags::GDAL_OF_VECTOR,
+                    ..Default::default()
+                },
+            )
+            .with_context(|| format!("Failed to open existing dataset at {:?} for append", path))?
+        } else {
+            let driver = DriverManager::get_driver_by_name(driver_name)
+                .with_context(|| format!("OGR driver {:?} is not available", driver_name))?;
+            driver
+                .create_vector_only(path)
+                .with_context(|| format!("Failed to create {} dataset at {:?}", driver_name, path))?
+        };
+
+        dataset
+            .start_transaction()
+            .context("Failed to start OGR transaction")?;
+
+        let result = Self::write_transactional_body(
+            &mut dataset,
+            layer_name,
+            &features,
+            &field_names,
+            reuse_existing,
+            truncate,
+        );
+
+        match result {
+            Ok(()) => dataset.commit_transaction().context("Failed to commit OGR transaction"),
+            Err(e) => {
+                // Best-effort rollback; the original error is what the caller needs to see.
+                let _ = dataset.rollback_transaction();
+                Err(e)
+            }
+        }
+    }
+
+    fn write_transactional_body(
+        dataset: &mut Dataset,
+        layer_name: &str,
+        features: &[Feature],
+        field_names: &[String],
+        reuse_existing: bool,
+        truncate: bool,
+    ) -> Result<()> {
+        if reuse_existing {
+            let mut layer = dataset
+                .layer_by_name(layer_name)
+                .with_context(|| format!("Layer {:?} not found for append", layer_name))?;
+
+            if truncate {
+                let existing_fids: Vec<u64> = layer.features().filter_map(|f| f.fid()).collect();
+                for fid in existing_fids {
+                    layer
+                        .delete_feature(fid)
+                        .with_context(|| format!("Failed to delete existing feature {}", fid))?;
+                }
+            }
+
+            let existing_field_names: Vec<String> =
+                layer.defn().fields().map(|f| f.name()).collect();
+            for field_name in field_names {
+                if existing_field_names.contains(field_name) {
+                    continue;
+                }
+                let field_type = Self::infer_field_type(features, field_name);
+                let field_defn = FieldDefn::new(field_name, field_type)
+                    .with_context(|| format!("Failed to build field definition for {:?}", field_name))?;
+                field_defn
+                    .add_to_layer(&layer)
+                    .with_context(|| format!("Failed to add field {:?} to layer", field_name))?;
+            }
+
+            for feature in features {
+                Self::write_feature(&mut layer, feature, field_names)?;
+            }
+        } else {
+            let mut layer = dataset
+                .create_layer(LayerOptions {
+                    name: layer_name,
+                    srs: None,
+                    ty: gdal::vector::OGRwkbGeometryType::wkbUnknown,
+                    options: None,
+                })
+                .context("Failed to create layer")?;
+
+            for field_name in field_names {
+                let field_type = Self::infer_field_type(features, field_name);
+                let field_defn = FieldDefn::new(field_name, field_type)
+                    .with_context(|| format!("Failed to build field definition for {:?}", field_name))?;
+                field_defn
+                    .add_to_layer(&layer)
+                    .with_context(|| format!("Failed to add field {:?} to layer", field_name))?;
+            }
+
+            for feature in features {
+                Self::write_feature(&mut layer, feature, field_names)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn feature_list(geojson: &GeoJson) -> Result<Vec<Feature>> {
+        match geojson {
+            GeoJson::FeatureCollection(fc) => Ok(fc.features.clone()),
+            GeoJson::Feature(f) => Ok(vec![f.clone()]),
+            GeoJson::Geometry(g) => Ok(vec![Feature {
+                bbox: None,
+                geometry: Some(g.clone()),
+                id: None,
+                properties: None,
+                foreign_members: None,
+            }]),
+        }
+    }
+
+    fn collect_field_names(features: &[Feature]) -> Vec<String> {
+        let mut names = Vec::new();
+        for feature in features {
+            if let Some(properties) = &feature.properties {
+                for key in properties.keys() {
+                    if !names.contains(key) {
+                        names.push(key.clone());
+                    }
+                }
+            }
+        }
+        names
+    }
+
+    fn infer_field_type(features: &[Feature], field_name: &str) -> OGRFieldType::Type {
+        for feature in features {
+            let Some(value) = feature
+                .properties
+                .as_ref()
+                .and_then(|props| props.get(field_name))
+            else {
+                continue;
+            };
+            return match value {
+                JsonValue::Bool(_) => OGRFieldType::OFTInteger,
+                JsonValue::Number(n) if n.is_i64() || n.is_u64() => OGRFieldType::OFTInteger64,
+                JsonValue::Number(_) => OGRFieldType::OFTReal,
+                _ => OGRFieldType::OFTString,
+            };
+        }
+        OGRFieldType::OFTString
+    }
+
+    fn write_feature<L: LayerAccess>(
+        layer: &mut L,
+        feature: &Feature,
+        field_names: &[String],
+    ) -> Result<()> {
+        let defn = layer.defn();
+        let mut ogr_feature = gdal::vector::Feature::new(defn).context("Failed to create feature")?;
+
+        if let Some(geometry) = &feature.geometry {
+            let gdal_geom = Self::geojson_geometry_to_gdal(geometry)?;
+            ogr_feature
+                .set_geometry(gdal_geom)
+                .context("Failed to set feature geometry")?;
+        }
+
+        if let Some(properties) = &feature.properties {
+            for field_name in field_names {
+                match properties.get(field_name) {
+                    Some(JsonValue::Bool(b)) => {
+                        ogr_feature.set_field_integer(field_name, if *b { 1 } else { 0 })?;
+                    }
+                    Some(JsonValue::Number(n)) if n.is_i64() || n.is_u64() => {
+                        ogr_feature.set_field_integer64(field_name, n.as_i64().unwrap_or_default())?;
+                    }
+                    Some(JsonValue::Number(n)) => {
+                        ogr_feature.set_field_double(field_name, n.as_f64().unwrap_or_default())?;
+                    }
+                    Some(JsonValue::String(s)) => {
+                        ogr_feature.set_field_string(field_name, s)?;
+                    }
+                    Some(other) => {
+                        ogr_feature.set_field_string(field_name, &other.to_string())?;
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        ogr_feature.create(layer).context("Failed to write feature to layer")?;
+
+        Ok(())
+    }
+
+    /// Convert a `geojson::Geometry` into a GDAL geometry via its `geo` representation.
+    fn geojson_geometry_to_gdal(geometry: &geojson::Geometry) -> Result<GdalGeometry> {
+        let geo_geom: geo::Geometry<f64> = geometry
+            .try_into()
+            .context("Failed to convert GeoJSON geometry to geo::Geometry")?;
+        geo_geom
+            .to_gdal()
+            .context("Failed to convert geo::Geometry to GDAL geometry")
+    }
+}
@@ -1,17 +1,968 @@
 use anyhow::{Context, Result};
-#[allow(unused_imports)]
 use gdal::vector::{Feature as GdalFeature, LayerAccess};
-#[allow(unused_imports)]
 use gdal::{Dataset, DriverManager};
-use geo::{Area, Centroid, Polygon};
+use geo::algorithm::bounding_rect::BoundingRect;
+use geo::algorithm::map_coords::MapCoords;
+use geo::{Area, BooleanOps, Centroid, Contains, EuclideanLength, LineString, Polygon};
 use geojson::{Feature as GeoJsonFeature, GeoJson, Geometry};
+use geozero::error::Result as GeozeroResult;
+use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, PropertyProcessor};
+use h3o::{CellIndex, LatLng, Resolution};
 use polars::prelude::*;
+use proj::Proj;
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
 
 use crate::collect::ign::ign_collect::IgnCollect;
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::query::{intersects_polygon, parse_where};
+
+/// EPSG code GeoJSON footprints are assumed to arrive in when no other CRS is known
+/// (IGN's building API returns WGS84 lon/lat).
+const GEOJSON_SOURCE_EPSG: i32 = 4326;
+
+/// A building's axis-aligned envelope plus its index into `BuildingCollection::buildings`, the
+/// element type stored in [`BuildingCollection`]'s R-tree. Indexing the envelope rather than
+/// the footprint itself keeps the tree cheap to rebuild and avoids duplicating geometry.
+#[derive(Debug, Clone, Copy)]
+struct BuildingEnvelope {
+    index: usize,
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+}
+
+impl RTreeObject for BuildingEnvelope {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners([self.min_x, self.min_y], [self.max_x, self.max_y])
+    }
+}
+
+impl PointDistance for BuildingEnvelope {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let dx = if point[0] < self.min_x {
+            self.min_x - point[0]
+        } else if point[0] > self.max_x {
+            point[0] - self.max_x
+        } else {
+            0.0
+        };
+        let dy = if point[1] < self.min_y {
+            self.min_y - point[1]
+        } else if point[1] > self.max_y {
+            point[1] - self.max_y
+        } else {
+            0.0
+        };
+        dx * dx + dy * dy
+    }
+}
+
+/// A building centroid reprojected into a metric CRS plus its index into
+/// `BuildingCollection::buildings`, the element type backing
+/// [`BuildingCollection::within_radius`]'s on-demand R-tree. Unlike [`BuildingEnvelope`]'s tree,
+/// this one isn't cached on the collection since the target metric CRS can vary per call.
+#[derive(Debug, Clone, Copy)]
+struct MetricCentroid {
+    index: usize,
+    point: [f64; 2],
+}
+
+impl RTreeObject for MetricCentroid {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(self.point)
+    }
+}
+
+impl PointDistance for MetricCentroid {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let dx = self.point[0] - point[0];
+        let dy = self.point[1] - point[1];
+        dx * dx + dy * dy
+    }
+}
+
+/// Cached R-tree backing [`BuildingCollection::query_bbox`]/`nearest`/`within_distance`,
+/// invalidated by comparing `building_count` against the live `buildings.len()`.
+struct SpatialIndexCache {
+    building_count: usize,
+    tree: RTree<BuildingEnvelope>,
+}
+
+/// Collapse a (possibly multi-part) boolean-op result back to the single `Polygon` that
+/// `Building::footprint` stores. A clip can legitimately split one footprint into several
+/// disjoint pieces; this keeps the largest one by area. `None` if the intersection is empty.
+fn largest_polygon(multi: &geo::MultiPolygon<f64>) -> Option<Polygon<f64>> {
+    multi
+        .iter()
+        .max_by(|a, b| a.unsigned_area().partial_cmp(&b.unsigned_area()).unwrap())
+        .cloned()
+}
+
+/// Symbol alphabet for Open Location Code (Plus Code) digits, excluding characters that are
+/// easily confused when handwritten or read aloud (no "0", "1", "I", "O", etc.).
+const OPEN_LOCATION_CODE_ALPHABET: &[u8] = b"23456789CFGHJMPQRVWX";
+
+/// Encode a WGS84 `(lat, lon)` pair as an Open Location Code (Plus Code), e.g. `"8FW4V75V+8Q"`.
+/// `pair_count` is the number of lat/lon digit pairs to produce (5 pairs = 10 digits, ~14m);
+/// the `'+'` separator is inserted after the 8th digit, padding with `'0'` if `pair_count < 4`.
+fn encode_open_location_code(lat: f64, lon: f64, pair_count: u8) -> String {
+    let pair_count = pair_count.max(1) as usize;
+
+    // Shift into the code's non-negative working ranges before narrowing.
+    let mut lat_low = -90.0_f64;
+    let mut lat_high = -90.0_f64 + 400.0;
+    let mut lon_low = -180.0_f64;
+    let mut lon_high = -180.0_f64 + 400.0;
+    let lat = lat.clamp(-90.0, 90.0);
+    let lon = lon.clamp(-180.0, 180.0);
+
+    let mut digits = String::new();
+    for _ in 0..pair_count {
+        let lat_step = (lat_high - lat_low) / 20.0;
+        let lat_digit = (((lat - lat_low) / lat_step) as usize).min(19);
+        lat_low += lat_digit as f64 * lat_step;
+        lat_high = lat_low + lat_step;
+        digits.push(OPEN_LOCATION_CODE_ALPHABET[lat_digit] as char);
+
+        let lon_step = (lon_high - lon_low) / 20.0;
+        let lon_digit = (((lon - lon_low) / lon_step) as usize).min(19);
+        lon_low += lon_digit as f64 * lon_step;
+        lon_high = lon_low + lon_step;
+        digits.push(OPEN_LOCATION_CODE_ALPHABET[lon_digit] as char);
+    }
+
+    while digits.len() < 8 {
+        digits.push('0');
+    }
+    digits.insert(8, '+');
+    digits
+}
+
+/// Name of a GeoJSON geometry's type, for [`BuildingCollection::coverage_report`]'s
+/// geometry-type breakdown.
+fn geojson_value_type_name(value: &geojson::Value) -> &'static str {
+    match value {
+        geojson::Value::Point(_) => "Point",
+        geojson::Value::MultiPoint(_) => "MultiPoint",
+        geojson::Value::LineString(_) => "LineString",
+        geojson::Value::MultiLineString(_) => "MultiLineString",
+        geojson::Value::Polygon(_) => "Polygon",
+        geojson::Value::MultiPolygon(_) => "MultiPolygon",
+        geojson::Value::GeometryCollection(_) => "GeometryCollection",
+    }
+}
+
+/// Round a single geojson::Geometry's coordinates to `precision` decimal places in place,
+/// recursing into GeometryCollection. Used by [`BuildingCollection::get_geojson_with_options`].
+fn round_geometry(geometry: &mut Geometry, precision: u32) {
+    fn round_position(position: &mut [f64], factor: f64) {
+        for component in position.iter_mut() {
+            *component = (*component * factor).round() / factor;
+        }
+    }
+
+    let factor = 10f64.powi(precision as i32);
+    match &mut geometry.value {
+        geojson::Value::Point(position) => round_position(position, factor),
+        geojson::Value::MultiPoint(positions) | geojson::Value::LineString(positions) => {
+            for position in positions {
+                round_position(position, factor);
+            }
+        }
+        geojson::Value::MultiLineString(lines) | geojson::Value::Polygon(lines) => {
+            for line in lines {
+                for position in line {
+                    round_position(position, factor);
+                }
+            }
+        }
+        geojson::Value::MultiPolygon(polygons) => {
+            for polygon in polygons {
+                for line in polygon {
+                    for position in line {
+                        round_position(position, factor);
+                    }
+                }
+            }
+        }
+        geojson::Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                round_geometry(geometry, precision);
+            }
+        }
+    }
+}
+
+/// Serialize a footprint to a GeoJSON geometry string, for `BuildingCollection::to_dataframe`'s
+/// `footprint` column.
+fn footprint_to_geojson_string(polygon: &Polygon<f64>) -> Result<String> {
+    let geo_geom = geo::Geometry::Polygon(polygon.clone());
+    let value = geojson::Value::from(&geo_geom);
+    let geometry = Geometry::new(value);
+    serde_json::to_string(&geometry).context("Failed to serialize footprint to GeoJSON")
+}
+
+/// Build a WGS84 `geo::Polygon` from an H3 cell's boundary, for intersecting against a
+/// footprint already reprojected to lat/long in [`BuildingCollection::to_h3_aggregation`].
+fn cell_boundary_polygon(cell: CellIndex) -> Result<Polygon<f64>> {
+    let mut coords: Vec<geo::Coord<f64>> = cell
+        .boundary()
+        .iter()
+        .map(|vertex| geo::coord! { x: vertex.lng(), y: vertex.lat() })
+        .collect();
+    if let Some(first) = coords.first().copied() {
+        coords.push(first);
+    }
+    Ok(Polygon::new(LineString::from(coords), vec![]))
+}
+
+/// Cheaply compute a GeoJSON geometry's axis-aligned bounding box straight from its raw
+/// coordinates, without converting to a `geo::Geometry`/`Polygon` first. Used by
+/// [`BuildingCollection::import_filtered`] to reject a feature's envelope before paying for the
+/// fuller conversion `geojson_feature_to_building` does. Returns `None` for anything other than
+/// a `Polygon`/`MultiPolygon`, or for an empty one.
+fn geojson_geometry_envelope(geometry: &Geometry) -> Option<(f64, f64, f64, f64)> {
+    fn fold_positions(positions: &[Vec<f64>], envelope: &mut (f64, f64, f64, f64)) {
+        for position in positions {
+            if let [x, y, ..] = position.as_slice() {
+                envelope.0 = envelope.0.min(*x);
+                envelope.1 = envelope.1.min(*y);
+                envelope.2 = envelope.2.max(*x);
+                envelope.3 = envelope.3.max(*y);
+            }
+        }
+    }
+
+    let mut envelope = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    match &geometry.value {
+        geojson::Value::Polygon(rings) => {
+            for ring in rings {
+                fold_positions(ring, &mut envelope);
+            }
+        }
+        geojson::Value::MultiPolygon(polygons) => {
+            for rings in polygons {
+                for ring in rings {
+                    fold_positions(ring, &mut envelope);
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    envelope.0.is_finite().then_some(envelope)
+}
+
+/// Whether two `(min_x, min_y, max_x, max_y)` envelopes overlap.
+fn envelopes_intersect(a: (f64, f64, f64, f64), b: (f64, f64, f64, f64)) -> bool {
+    a.0 <= b.2 && b.0 <= a.2 && a.1 <= b.3 && b.1 <= a.3
+}
+
+/// Number of segments used to approximate a circular footprint generated by
+/// [`BuildingCollection::from_geojson`]'s `point_buffer_radius` from a Point feature.
+const POINT_BUFFER_SEGMENTS: usize = 32;
+
+/// Metric CRS a Point is temporarily reprojected into to generate a buffer of a given radius
+/// in meters. Lambert-93, matching `GeoCore::default()` -- this crate targets French data, and
+/// a buffer radius in WGS84 degrees would vary with latitude.
+const POINT_BUFFER_METRIC_EPSG: i32 = 2154;
+
+/// Buffer a Point `source_epsg` (typically [`GEOJSON_SOURCE_EPSG`], WGS84) into an
+/// `POINT_BUFFER_SEGMENTS`-sided circular polygon of `radius_meters`, still expressed in
+/// `source_epsg`. Since a metric radius is meaningless in degrees, the circle itself is
+/// generated around the point reprojected to [`POINT_BUFFER_METRIC_EPSG`], then each vertex of
+/// the ring is reprojected back to `source_epsg`.
+fn buffer_point_to_polygon(
+    point: &geo::Point<f64>,
+    radius_meters: f64,
+    source_epsg: i32,
+) -> Result<Polygon<f64>> {
+    let (center_x, center_y) = GeoCore::transform_coords(
+        source_epsg,
+        POINT_BUFFER_METRIC_EPSG,
+        point.x(),
+        point.y(),
+    )
+    .context("Failed to reproject point to a metric CRS for buffering")?;
+
+    let mut ring = Vec::with_capacity(POINT_BUFFER_SEGMENTS + 1);
+    for i in 0..POINT_BUFFER_SEGMENTS {
+        let angle = 2.0 * std::f64::consts::PI * (i as f64) / (POINT_BUFFER_SEGMENTS as f64);
+        let metric_x = center_x + radius_meters * angle.cos();
+        let metric_y = center_y + radius_meters * angle.sin();
+        let (x, y) =
+            GeoCore::transform_coords(POINT_BUFFER_METRIC_EPSG, source_epsg, metric_x, metric_y)
+                .context("Failed to reproject buffer ring vertex back to the source CRS")?;
+        ring.push((x, y));
+    }
+    ring.push(ring[0]);
+
+    Ok(Polygon::new(LineString::from(ring), vec![]))
+}
+
+/// Burn `height` into every cell of `heights` (row-major, `cols` wide, south-up: row 0 sits at
+/// `origin_y`) whose center falls inside `footprint`. Uses a scanline fill over the exterior
+/// ring plus each interior ring: for each row's horizontal line, intersect it with every ring
+/// edge, sort the intersection x-coordinates, and fill between successive pairs (odd-even
+/// rule, so holes are left unfilled). Keeps the larger value where footprints overlap.
+fn fill_footprint(
+    footprint: &Polygon<f64>,
+    origin_x: f64,
+    origin_y: f64,
+    cell_size: f64,
+    cols: usize,
+    rows: usize,
+    height: f32,
+    heights: &mut [f32],
+) {
+    let rings: Vec<&geo::LineString<f64>> = std::iter::once(footprint.exterior())
+        .chain(footprint.interiors())
+        .collect();
+
+    for row in 0..rows {
+        let y = origin_y + (row as f64 + 0.5) * cell_size;
+
+        let mut intersections: Vec<f64> = Vec::new();
+        for ring in &rings {
+            let coords: Vec<geo::Coord<f64>> = ring.coords().copied().collect();
+            for edge in coords.windows(2) {
+                let (p0, p1) = (edge[0], edge[1]);
+                if (p0.y <= y && p1.y > y) || (p1.y <= y && p0.y > y) {
+                    let t = (y - p0.y) / (p1.y - p0.y);
+                    intersections.push(p0.x + t * (p1.x - p0.x));
+                }
+            }
+        }
+        intersections.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for pair in intersections.chunks_exact(2) {
+            let (x_start, x_end) = (pair[0], pair[1]);
+            let col_start = ((x_start - origin_x) / cell_size).floor().max(0.0) as usize;
+            let col_end = (((x_end - origin_x) / cell_size).ceil() as usize).min(cols);
+            for col in col_start..col_end {
+                let x = origin_x + (col as f64 + 0.5) * cell_size;
+                if x < x_start || x >= x_end {
+                    continue;
+                }
+                let idx = row * cols + col;
+                if heights[idx].is_nan() || height > heights[idx] {
+                    heights[idx] = height;
+                }
+            }
+        }
+    }
+}
+
+/// Width of `footprint` projected onto the plane perpendicular to wind direction θ: rotate
+/// every exterior vertex by −θ about `centroid` and take (max y′ − min y′) of the rotated
+/// coordinates. `sin_t`/`cos_t` are sin/cos of θ in radians, precomputed once per call to
+/// [`BuildingCollection::morphology`] and reused across all buildings.
+fn frontal_width(footprint: &Polygon<f64>, centroid: &geo::Point<f64>, sin_t: f64, cos_t: f64) -> f64 {
+    let (cx, cy) = (centroid.x(), centroid.y());
+    let mut min_y = f64::INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    for coord in footprint.exterior().coords() {
+        let dx = coord.x - cx;
+        let dy = coord.y - cy;
+        // Rotation by -theta: [cos(t) sin(t); -sin(t) cos(t)].
+        let rotated_y = cos_t * dy - sin_t * dx;
+        min_y = min_y.min(rotated_y);
+        max_y = max_y.max(rotated_y);
+    }
+    if min_y.is_finite() {
+        max_y - min_y
+    } else {
+        0.0
+    }
+}
+
+/// A forward coordinate transform, abstracted out of [`BuildingCollection::reproject`] so
+/// [`BuildingCollection::reproject_with`] can accept any source of one — not just a `proj::Proj`
+/// looked up from an EPSG code pair — for callers with their own cached transform or a
+/// transform pipeline PROJ doesn't know about.
+pub trait CoordTransform {
+    /// Transform a single `(x, y)` coordinate forward. Implementations that cannot transform a
+    /// given point should return it unchanged, matching the fallback `reproject` uses for a
+    /// `proj::Proj` conversion failure.
+    fn transform(&self, x: f64, y: f64) -> (f64, f64);
+}
+
+impl CoordTransform for Proj {
+    fn transform(&self, x: f64, y: f64) -> (f64, f64) {
+        self.convert((x, y)).unwrap_or((x, y))
+    }
+}
+
+/// WGS84 ellipsoid semi-major axis, in meters.
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 ellipsoid flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// WGS84 ellipsoid semi-minor axis, in meters (`a * (1 - f)`).
+const WGS84_B: f64 = WGS84_A * (1.0 - WGS84_F);
+/// Radius (in meters) of the sphere with the same surface area as the WGS84 ellipsoid, used by
+/// [`geodesic_ring_area`] to approximate ellipsoidal polygon area.
+const WGS84_AUTHALIC_RADIUS: f64 = 6_371_007.1809;
+/// Max iterations of Vincenty's λ convergence loop before falling back to
+/// [`haversine_distance`], matching the handful of textbook implementations this follows.
+const VINCENTY_MAX_ITERATIONS: u32 = 200;
+
+/// Great-circle distance between two lat/long points (in degrees), in meters, on a sphere of
+/// radius [`WGS84_A`]. Used by [`geodesic_distance`] as a fallback for (near-)antipodal point
+/// pairs, where Vincenty's inverse formula fails to converge.
+fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+    WGS84_A * c
+}
+
+/// Geodesic distance between two lat/long points (in degrees) on the WGS84 ellipsoid, in
+/// meters, via Vincenty's inverse formula: reduced latitudes U1/U2 from the two points, then
+/// iterate on λ (the difference in longitude on the auxiliary sphere) computing sin σ, cos σ,
+/// σ, sin α, cos²α and cos 2σm at each step, until |Δλ| < 1e-12; the distance is then
+/// `s = b·A·(σ − Δσ)`. Falls back to [`haversine_distance`] for (near-)antipodal pairs, where
+/// the iteration doesn't converge within [`VINCENTY_MAX_ITERATIONS`] steps.
+pub fn geodesic_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    if (lat1 - lat2).abs() < 1e-12 && (lon1 - lon2).abs() < 1e-12 {
+        return 0.0;
+    }
+
+    let (a, b, f) = (WGS84_A, WGS84_B, WGS84_F);
+    let u1 = ((1.0 - f) * lat1.to_radians().tan()).atan();
+    let u2 = ((1.0 - f) * lat2.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+    let l = (lon2 - lon1).to_radians();
+
+    let mut lambda = l;
+    let (mut sin_sigma, mut cos_sigma, mut sigma, mut cos_sq_alpha, mut cos_2sigma_m) =
+        (0.0, 0.0, 0.0, 0.0, 0.0);
+
+    for _ in 0..VINCENTY_MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            return 0.0; // Coincident points.
+        }
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        cos_2sigma_m = if cos_sq_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            0.0 // Equatorial line: cos²α = 0.
+        };
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let lambda_prev = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+        if (lambda - lambda_prev).abs() < 1e-12 {
+            let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+            let big_a =
+                1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+            let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                            - big_b / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+            return b * big_a * (sigma - delta_sigma);
+        }
+    }
+
+    // Near-antipodal points: Vincenty's iteration doesn't converge. Fall back to the spherical
+    // approximation rather than returning a wrong/NaN distance.
+    haversine_distance(lat1, lon1, lat2, lon2)
+}
+
+/// Sum of consecutive [`geodesic_distance`] segments around a closed ring of
+/// `(longitude, latitude)` points in degrees, in meters.
+fn geodesic_ring_perimeter(ring: &LineString<f64>) -> f64 {
+    ring.0
+        .windows(2)
+        .map(|pair| geodesic_distance(pair[0].y, pair[0].x, pair[1].y, pair[1].x))
+        .sum()
+}
+
+/// Approximate area enclosed by a closed ring of `(longitude, latitude)` points in degrees, in
+/// square meters, via the spherical-excess formula (Chamberlain & Duquette, "Some Algorithms
+/// for Polygons on a Sphere", JPL 2007 — the same approach behind most "geodesic area"
+/// implementations, e.g. Turf.js's `area`) evaluated on the WGS84 authalic sphere: the sphere
+/// with the same surface area as the WGS84 ellipsoid. Not as exact as integrating along the
+/// true ellipsoidal geodesics, but accurate to a few parts-per-million for building-sized
+/// footprints.
+fn geodesic_ring_area(ring: &LineString<f64>) -> f64 {
+    if ring.0.len() < 3 {
+        return 0.0;
+    }
+
+    let total: f64 = ring
+        .0
+        .windows(2)
+        .map(|pair| {
+            let (lon1, lat1) = (pair[0].x.to_radians(), pair[0].y.to_radians());
+            let (lon2, lat2) = (pair[1].x.to_radians(), pair[1].y.to_radians());
+            (lon2 - lon1) * (2.0 + lat1.sin() + lat2.sin())
+        })
+        .sum();
+
+    (total * WGS84_AUTHALIC_RADIUS * WGS84_AUTHALIC_RADIUS / 2.0).abs()
+}
+
+/// A building footprint's area and perimeter on the WGS84 ellipsoid. See
+/// [`BuildingCollection::geodesic_measurements`].
+#[derive(Debug, Clone, Copy)]
+pub struct GeodesicMeasurement {
+    /// Footprint area in square meters, via the spherical-excess formula on the WGS84 authalic
+    /// sphere ([`geodesic_ring_area`]).
+    pub area: f64,
+    /// Footprint perimeter in meters, via Vincenty's inverse formula ([`geodesic_distance`]).
+    pub perimeter: f64,
+}
+
+impl GeodesicMeasurement {
+    /// Compute the area/perimeter of `footprint`, which must already be in `(longitude,
+    /// latitude)` degrees (EPSG:4326).
+    fn of(footprint: &Polygon<f64>) -> Self {
+        let exterior_area = geodesic_ring_area(footprint.exterior());
+        let interiors_area: f64 = footprint.interiors().iter().map(geodesic_ring_area).sum();
+
+        GeodesicMeasurement {
+            area: (exterior_area - interiors_area).max(0.0),
+            perimeter: geodesic_ring_perimeter(footprint.exterior()),
+        }
+    }
+}
+
+/// One H3 cell's building aggregates, as returned by [`BuildingCollection::h3_aggregate`].
+#[derive(Debug, Clone)]
+pub struct H3CellAggregate {
+    /// Canonical hex string of the H3 cell index (e.g. `"8a1fb46622dffff"`).
+    pub h3_index: String,
+    /// Number of buildings assigned to this cell (fractional when `split_by_intersection` is
+    /// `true` and a building's footprint was apportioned across several cells).
+    pub building_count: f64,
+    pub total_area: f64,
+    pub mean_footprint_area: f64,
+    pub mean_height: f64,
+    pub max_height: f64,
+    pub total_volume: f64,
+}
+
+/// Accumulator backing [`BuildingCollection::h3_aggregate`]'s per-cell pass.
+#[derive(Default)]
+struct H3CellAgg {
+    building_count: f64,
+    total_area: f64,
+    height_sum: f64,
+    height_weight: f64,
+    max_height: f64,
+    total_volume: f64,
+}
+
+/// Per-cell urban-canopy morphology indicators from [`BuildingCollection::compute_morphology`],
+/// extending a cell's [`MorphoIndicators`] with a frontal area density per requested wind
+/// azimuth, a building surface-to-plan-area ratio, and an approximate sky-view factor.
+#[derive(Debug, Clone)]
+pub struct GridMorphoIndicators {
+    pub cell_min_x: f64,
+    pub cell_min_y: f64,
+    pub cell_max_x: f64,
+    pub cell_max_y: f64,
+    /// λp, mean/stddev height computed once over the cell; `lambda_f` is whichever wind
+    /// direction was requested first (kept for convenient single-azimuth use), with every
+    /// requested azimuth also broken out in `lambda_f_by_wind_dir`.
+    pub morpho: MorphoIndicators,
+    /// `(wind_dir_deg, lambda_f)` for every azimuth passed to `compute_morphology`.
+    pub lambda_f_by_wind_dir: Vec<(f64, f64)>,
+    /// (Σ building footprint area + Σ building exterior perimeter * height) / cell plan area --
+    /// roof area plus wall area, over plan area.
+    pub surface_to_plan_area_ratio: f64,
+    /// Approximate sky-view factor from plan/frontal area density, `exp(-k * lambda_f)` with
+    /// `k = 2.0` (the commonly used canyon-radiation approximation weight; not a ray-traced
+    /// estimate). Uses the first requested wind direction's λf.
+    pub sky_view_factor: f64,
+}
+
+/// Urban-canopy morphology indicators over a lot. See [`BuildingCollection::morphology`].
+#[derive(Debug, Clone, Copy)]
+pub struct MorphoIndicators {
+    /// Plan area density λp = (Σ footprint areas) / lot_area.
+    pub lambda_p: f64,
+    /// Building coverage ratio (same value as `lambda_p`, kept under its own name).
+    pub building_coverage: f64,
+    /// Frontal area density λf(θ) = (Σ height_i * W_i(θ)) / lot_area.
+    pub lambda_f: f64,
+    /// Area-weighted mean building height.
+    pub mean_height: f64,
+    /// Standard deviation of building height.
+    pub height_stddev: f64,
+}
+
+/// Presence of a single property across a GeoJSON source's features. See
+/// [`BuildingCollection::coverage_report`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldCoverage {
+    /// Number of features where the property is present and non-null.
+    pub present_count: usize,
+    /// `present_count / feature_count`, `0.0` for an empty source.
+    pub fraction: f64,
+}
+
+/// Attribute-completeness summary for a GeoJSON source. See
+/// [`BuildingCollection::coverage_report`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub feature_count: usize,
+    /// Geometry type name (`"Polygon"`, `"Point"`, ...) to feature count.
+    pub geometry_type_counts: HashMap<String, usize>,
+    /// Features with no geometry at all.
+    pub invalid_geometry_count: usize,
+    /// Property name to its presence/coverage across `feature_count` features.
+    pub fields: HashMap<String, FieldCoverage>,
+}
+
+/// A simple digital-surface-model-style raster: one cell per `cell_size` x `cell_size` square
+/// holding the tallest building height whose footprint covers its center (`f32::NAN` where no
+/// footprint covers a cell). Produced by [`BuildingCollection::rasterize_heights`].
+pub struct HeightRaster {
+    /// Min x of the source bounding rect (world coordinate of column 0's west edge).
+    pub origin_x: f64,
+    /// Min y of the source bounding rect (world coordinate of row 0's south edge).
+    pub origin_y: f64,
+    pub cell_size: f64,
+    pub cols: usize,
+    pub rows: usize,
+    /// Row-major, south-up: `heights[row * cols + col]` is the cell at
+    /// `(origin_x + (col + 0.5) * cell_size, origin_y + (row + 0.5) * cell_size)`.
+    pub heights: Vec<f32>,
+}
+
+impl HeightRaster {
+    /// Write the raster to `path` as a single-band GeoTIFF in `epsg`. GeoTIFF rows run
+    /// north-up, so this flips `heights`' south-up row order while writing.
+    pub fn write_geotiff(&self, path: &Path, epsg: i32) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::spatial_ref::SpatialRef;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create output directory: {:?}", parent))?;
+        }
+
+        let driver =
+            DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options: Vec<RasterCreationOption> = Vec::new();
+        let mut dataset = driver
+            .create_with_band_type_with_options::<f32, _>(
+                path,
+                self.cols,
+                self.rows,
+                1,
+                &creation_options,
+            )
+            .context("Failed to create height raster GeoTIFF")?;
+
+        dataset
+            .set_geo_transform(&[
+                self.origin_x,
+                self.cell_size,
+                0.0,
+                self.origin_y + self.rows as f64 * self.cell_size,
+                0.0,
+                -self.cell_size,
+            ])
+            .context("Failed to set geotransform")?;
+
+        let srs =
+            SpatialRef::from_epsg(epsg as u32).context("Failed to create spatial reference")?;
+        dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set spatial reference")?;
+
+        let mut data = Vec::with_capacity(self.cols * self.rows);
+        for row in (0..self.rows).rev() {
+            for col in 0..self.cols {
+                data.push(self.heights[row * self.cols + col]);
+            }
+        }
+
+        let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+        let mut buffer = Buffer::new((self.cols, self.rows), data);
+        band.write((0, 0), (self.cols, self.rows), &mut buffer)
+            .context("Failed to write height band")?;
+        band.set_no_data_value(Some(f64::NAN))
+            .context("Failed to set no data value")?;
+        band.set_description("height")
+            .context("Failed to set band description")?;
+
+        Ok(())
+    }
+}
+
+/// Adapts `&[Building]` to geozero's [`GeozeroDatasource`] so a single writer implementation —
+/// anything that implements geozero's [`FeatureProcessor`] — can emit GeoJSON, WKT, WKB or
+/// (pivoting through GeoJSON) GPKG from the same walk over building geometry and attributes.
+/// See `to_geozero_geojson`/`to_geozero_wkt`/`to_geozero_wkb`/`to_geozero_gpkg` on
+/// `BuildingCollection`.
+struct BuildingSource<'a> {
+    buildings: &'a [Building],
+}
+
+impl<'a> GeozeroDatasource for BuildingSource<'a> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> GeozeroResult<()> {
+        processor.dataset_begin(None)?;
+        for (idx, building) in self.buildings.iter().enumerate() {
+            processor.feature_begin(idx as u64)?;
+
+            processor.properties_begin()?;
+            processor.property(
+                0,
+                "hauteur",
+                &ColumnValue::Double(building.get_height(0.0)),
+            )?;
+            processor.property(1, "area", &ColumnValue::Double(building.area))?;
+            processor.property(
+                2,
+                "centroid_x",
+                &ColumnValue::Double(building.centroid.x()),
+            )?;
+            processor.property(
+                3,
+                "centroid_y",
+                &ColumnValue::Double(building.centroid.y()),
+            )?;
+            processor.properties_end()?;
+
+            processor.geometry_begin()?;
+            write_polygon(&building.footprint, idx, processor)?;
+            processor.geometry_end()?;
+
+            processor.feature_end(idx as u64)?;
+        }
+        processor.dataset_end()?;
+        Ok(())
+    }
+}
+
+/// Drive a single polygon's rings through geozero's [`GeomProcessor`] callbacks: the exterior
+/// ring first, then each interior hole, bracketed by `polygon_begin`/`polygon_end`.
+fn write_polygon<P: GeomProcessor>(
+    polygon: &Polygon<f64>,
+    idx: usize,
+    processor: &mut P,
+) -> GeozeroResult<()> {
+    let rings: Vec<&geo::LineString<f64>> = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .collect();
+
+    processor.polygon_begin(true, rings.len(), idx)?;
+    for (ring_idx, ring) in rings.iter().enumerate() {
+        let coords: Vec<geo::Coord<f64>> = ring.coords().copied().collect();
+        processor.linestring_begin(false, coords.len(), ring_idx)?;
+        for (coord_idx, coord) in coords.iter().enumerate() {
+            processor.xy(coord.x, coord.y, coord_idx)?;
+        }
+        processor.linestring_end(false, ring_idx)?;
+    }
+    processor.polygon_end(true, idx)?;
+    Ok(())
+}
+
+/// Adapts `&[Building]` to geozero's [`GeozeroDatasource`] for
+/// [`BuildingCollection::to_flatgeobuf`], carrying the `hauteur`/`area`/`nombre_d_etages`/
+/// `no_hauteur` fields [`BuildingCollector`] reads back on import, rather than the
+/// `centroid_x`/`centroid_y` pair [`BuildingSource`] emits for the human-facing
+/// GeoJSON/WKT/WKB/GPKG exports.
+struct BuildingFgbSource<'a> {
+    buildings: &'a [Building],
+}
+
+impl<'a> GeozeroDatasource for BuildingFgbSource<'a> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> GeozeroResult<()> {
+        processor.dataset_begin(None)?;
+        for (idx, building) in self.buildings.iter().enumerate() {
+            processor.feature_begin(idx as u64)?;
+
+            processor.properties_begin()?;
+            processor.property(
+                0,
+                "hauteur",
+                &ColumnValue::Double(building.height.unwrap_or(f64::NAN)),
+            )?;
+            processor.property(1, "area", &ColumnValue::Double(building.area))?;
+            processor.property(
+                2,
+                "nombre_d_etages",
+                &ColumnValue::Double(building.nombre_d_etages.unwrap_or(f64::NAN)),
+            )?;
+            processor.property(3, "no_hauteur", &ColumnValue::Bool(building.no_hauteur))?;
+            processor.properties_end()?;
+
+            processor.geometry_begin()?;
+            write_polygon(&building.footprint, idx, processor)?;
+            processor.geometry_end()?;
+
+            processor.feature_end(idx as u64)?;
+        }
+        processor.dataset_end()?;
+        Ok(())
+    }
+}
+
+/// Builds a single polygon's SVG `<path>` `d` attribute by implementing geozero's
+/// [`GeomProcessor`], driven via [`write_polygon`] exactly like every other geozero-backed
+/// exporter in this file. `flip_y` maps geographic y (up) to SVG y (down) while keeping the
+/// coordinate range unchanged, so `y' = flip_y - y`; callers pass `min_y + max_y` of the
+/// collection's bounding rect. See [`BuildingCollection::to_svg`].
+struct SvgPathBuilder {
+    d: String,
+    flip_y: f64,
+}
+
+impl SvgPathBuilder {
+    fn new(flip_y: f64) -> Self {
+        SvgPathBuilder {
+            d: String::new(),
+            flip_y,
+        }
+    }
+}
+
+impl GeomProcessor for SvgPathBuilder {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> GeozeroResult<()> {
+        use std::fmt::Write;
+        let y = self.flip_y - y;
+        if idx == 0 {
+            write!(self.d, "M{x},{y} ").ok();
+        } else {
+            write!(self.d, "L{x},{y} ").ok();
+        }
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> GeozeroResult<()> {
+        self.d.push_str("Z ");
+        Ok(())
+    }
+}
+
+/// Reader-side counterpart of [`BuildingSource`]: implements geozero's processor traits so any
+/// geozero-driven reader (GeoJSON, FlatGeobuf, …) can be pointed at this sink to rebuild
+/// `Building`s directly from reader events, without first materializing an intermediate
+/// `geojson::GeoJson` document or GDAL feature.
+#[derive(Default)]
+struct BuildingCollector {
+    buildings: Vec<Building>,
+    rings: Vec<Vec<geo::Coord<f64>>>,
+    current_ring: Vec<geo::Coord<f64>>,
+    height: Option<f64>,
+    nombre_d_etages: Option<f64>,
+    hauteur_2: Option<f64>,
+    metadata: HashMap<String, String>,
+}
+
+impl GeomProcessor for BuildingCollector {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> GeozeroResult<()> {
+        self.current_ring.push(geo::coord! { x: x, y: y });
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> GeozeroResult<()> {
+        self.rings.push(std::mem::take(&mut self.current_ring));
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for BuildingCollector {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> GeozeroResult<bool> {
+        let as_f64 = match value {
+            ColumnValue::Double(v) => Some(*v),
+            ColumnValue::Float(v) => Some(*v as f64),
+            ColumnValue::Int(v) => Some(*v as f64),
+            ColumnValue::Long(v) => Some(*v as f64),
+            _ => None,
+        };
+        match name.to_lowercase().as_str() {
+            "hauteur" | "height" => {
+                if let Some(h) = as_f64.filter(|h| h.is_finite() && *h > 0.0) {
+                    self.height = Some(h);
+                }
+            }
+            "nombre_d_etages" | "storeys" | "etages" => {
+                if let Some(e) = as_f64.filter(|e| e.is_finite() && *e > 0.0) {
+                    self.nombre_d_etages = Some(e);
+                }
+            }
+            "hauteur_2" | "height_2" | "h2" => {
+                if let Some(h2) = as_f64.filter(|h2| h2.is_finite() && *h2 > 0.0) {
+                    self.hauteur_2 = Some(h2);
+                }
+            }
+            _ => {
+                if let ColumnValue::String(s) = value {
+                    self.metadata.insert(name.to_string(), s.to_string());
+                } else if let Some(f) = as_f64 {
+                    self.metadata.insert(name.to_string(), f.to_string());
+                }
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl FeatureProcessor for BuildingCollector {
+    fn feature_end(&mut self, _idx: u64) -> GeozeroResult<()> {
+        if self.rings.is_empty() {
+            return Ok(());
+        }
+        let mut rings = std::mem::take(&mut self.rings).into_iter();
+        let exterior = geo::LineString::new(rings.next().unwrap());
+        let interiors: Vec<geo::LineString<f64>> = rings.map(geo::LineString::new).collect();
+        let polygon = Polygon::new(exterior, interiors);
+
+        let mut building = Building::new(polygon);
+        if let Some(h) = self.height.take() {
+            building.set_height(h);
+        }
+        if let Some(e) = self.nombre_d_etages.take() {
+            building.set_nombre_d_etages(e);
+        }
+        if let Some(h2) = self.hauteur_2.take() {
+            building.set_hauteur_2(h2);
+        }
+        building.metadata = std::mem::take(&mut self.metadata);
+
+        self.buildings.push(building);
+        Ok(())
+    }
+}
 
 /// Building structure representing a single building with its geometric and metadata properties
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -104,6 +1055,17 @@ impl Building {
     pub fn area_height_product(&self) -> Option<f64> {
         self.height.map(|h| self.area * h)
     }
+
+    /// Encode the footprint's centroid as an Open Location Code (Plus Code), e.g.
+    /// `"8FW4V75V+8Q"`. `length` is the number of code digits before the `'+'`/after it combined
+    /// (10 = ~14m precision, the Plus Code default; an odd value is rounded up to the next even
+    /// count, since digits come in lat/lon pairs); values below 8 are padded with `'0'` up to
+    /// the 8th digit per the standard. Tags a building with a compact, hierarchical area
+    /// identifier the way continent-scale open building datasets do.
+    pub fn plus_code(&self, length: u8) -> String {
+        let pair_count = length.div_ceil(2).max(1);
+        encode_open_location_code(self.centroid.y(), self.centroid.x(), pair_count)
+    }
 }
 
 /// Collection of buildings with processing capabilities
@@ -120,6 +1082,9 @@ pub struct BuildingCollection {
     pub filepath_shp: Option<String>,
     /// IgnCollect instance for API requests (Python: Building inherits from IgnCollect)
     ign_collect: Option<IgnCollect>,
+    /// Lazily-built R-tree over building envelopes backing `query_bbox`/`nearest`/
+    /// `within_distance`. See [`BuildingCollection::spatial_index`].
+    spatial_index: RefCell<Option<SpatialIndexCache>>,
 }
 
 impl BuildingCollection {
@@ -150,6 +1115,7 @@ impl BuildingCollection {
             geo_core,
             filepath_shp,
             ign_collect: None,
+            spatial_index: RefCell::new(None),
         };
 
         // Initialize IgnCollect if no shapefile provided (will be used for IGN API)
@@ -175,6 +1141,7 @@ impl BuildingCollection {
             geo_core,
             filepath_shp: None,
             ign_collect: None,
+            spatial_index: RefCell::new(None),
         }
     }
 
@@ -188,6 +1155,207 @@ impl BuildingCollection {
         self.geo_core = GeoCore::new(epsg);
     }
 
+    /// Reproject every building footprint from `geo_core`'s current EPSG to `target_epsg`,
+    /// recomputing `area` and `centroid` afterward so they are expressed in the target CRS's
+    /// units (e.g. square meters under EPSG:2154 rather than square degrees under EPSG:4326).
+    /// No-op if the collection is already in `target_epsg`. Mirrors `dem.rs`'s bbox
+    /// reprojection: builds one `proj` transform and reuses it via `MapCoords`, falling back
+    /// to the original coordinate if a single point fails to convert.
+    pub fn reproject(&mut self, target_epsg: i32) -> Result<()> {
+        let source_epsg = self.geo_core.get_epsg();
+        if source_epsg == target_epsg {
+            return Ok(());
+        }
+
+        let from_crs = format!("EPSG:{}", source_epsg);
+        let to_crs = format!("EPSG:{}", target_epsg);
+        let proj = Proj::new_known_crs(&from_crs, &to_crs, None).with_context(|| {
+            format!(
+                "EPSG:{} -> EPSG:{} is not a transformation PROJ supports",
+                source_epsg, target_epsg
+            )
+        })?;
+
+        self.reproject_with(target_epsg, &proj);
+        Ok(())
+    }
+
+    /// Reproject every footprint with a caller-supplied [`CoordTransform`] instead of looking
+    /// one up from `target_epsg` via PROJ the way [`BuildingCollection::reproject`] does —
+    /// for callers that already hold a transform (a cached `Proj`, a test double, a pipeline
+    /// PROJ doesn't know about). Unlike `reproject`, this trusts the caller that `transform`
+    /// actually maps into `target_epsg`: it applies the transform and recomputes `area`/
+    /// `centroid` exactly as `reproject` does, then records `target_epsg` on `geo_core` without
+    /// deriving or validating the transform itself.
+    pub fn reproject_with<T: CoordTransform>(&mut self, target_epsg: i32, transform: &T) {
+        for building in &mut self.buildings {
+            building.footprint = building.footprint.map_coords(|c| {
+                let (x, y) = transform.transform(c.x, c.y);
+                geo::coord! { x: x, y: y }
+            });
+            building.area = building.footprint.unsigned_area();
+            building.centroid = building
+                .footprint
+                .centroid()
+                .unwrap_or_else(|| geo::Point::new(0.0, 0.0));
+        }
+
+        self.geo_core.set_epsg(target_epsg);
+    }
+
+    /// Reproject every footprint from `from_epsg` to `to_epsg`, regardless of what EPSG
+    /// `geo_core` currently thinks it's in — for a collection built from footprints in a CRS
+    /// `geo_core` was never told about. Mirrors `Water::reproject_to` (rsmdu crate) and
+    /// `Cadastre::reproject_to` (this crate). Prefer [`BuildingCollection::reproject`] when
+    /// `geo_core`'s EPSG is already correct.
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        self.geo_core.set_epsg(from_epsg);
+        self.reproject(to_epsg)
+    }
+
+    /// Reproject back to EPSG:4326 (WGS84 lat/long) — a prerequisite for
+    /// [`BuildingCollection::geodesic_measurements`], whose Vincenty-based formulae are defined
+    /// over lat/long pairs, and useful before exporting to a format that expects geographic
+    /// coordinates.
+    pub fn to_latlong(&mut self) -> Result<()> {
+        self.reproject(4326)
+    }
+
+    /// Footprint area (WGS84 authalic-sphere square meters) and perimeter (WGS84-ellipsoid
+    /// meters) for every building, via Vincenty's inverse geodesic formula — unlike
+    /// [`Building::area`]/[`Building::centroid`], which are planar in whatever CRS `geo_core` is
+    /// currently set to. Reprojects a scratch copy of each footprint to EPSG:4326 first (unless
+    /// `geo_core` is already there) rather than mutating the collection; call
+    /// [`BuildingCollection::to_latlong`] instead if the reprojection should stick.
+    pub fn geodesic_measurements(&self) -> Result<Vec<GeodesicMeasurement>> {
+        let epsg = self.geo_core.get_epsg();
+        let to_latlong: Box<dyn Fn(&Polygon<f64>) -> Polygon<f64>> = if epsg == GEOJSON_SOURCE_EPSG
+        {
+            Box::new(|footprint: &Polygon<f64>| footprint.clone())
+        } else {
+            let proj = Proj::new_known_crs(
+                &format!("EPSG:{}", epsg),
+                &format!("EPSG:{}", GEOJSON_SOURCE_EPSG),
+                None,
+            )
+            .with_context(|| {
+                format!(
+                    "EPSG:{} -> EPSG:{} is not a transformation PROJ supports",
+                    epsg, GEOJSON_SOURCE_EPSG
+                )
+            })?;
+            Box::new(move |footprint: &Polygon<f64>| {
+                footprint.map_coords(|c| {
+                    let (x, y) = proj.convert((c.x, c.y)).unwrap_or((c.x, c.y));
+                    geo::coord! { x: x, y: y }
+                })
+            })
+        };
+
+        Ok(self
+            .buildings
+            .iter()
+            .map(|building| GeodesicMeasurement::of(&to_latlong(&building.footprint)))
+            .collect())
+    }
+
+    /// Clip every footprint to `boundary` ("limit-to" geometry), keeping only the parts
+    /// inside it. Buildings that don't intersect `boundary` at all are dropped; buildings
+    /// that only partially overlap have their footprint replaced with the clipped geometry,
+    /// and `area`/`centroid` are recomputed from it. `boundary` must already be expressed in
+    /// `geo_core`'s CRS — [`geo::MultiPolygon`] carries no CRS of its own to assert against,
+    /// so this is the caller's responsibility, same as with [`BuildingCollection::reproject`].
+    pub fn clip_to_boundary(&mut self, boundary: &geo::MultiPolygon<f64>) {
+        self.buildings.retain_mut(|building| {
+            let clipped = building.footprint.intersection(boundary);
+            match largest_polygon(&clipped) {
+                Some(polygon) => {
+                    building.footprint = polygon;
+                    building.area = building.footprint.unsigned_area();
+                    building.centroid = building
+                        .footprint
+                        .centroid()
+                        .unwrap_or_else(|| geo::Point::new(0.0, 0.0));
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    /// Select a subset of buildings by attribute predicate and/or spatial intersection,
+    /// returning a new collection so `to_gpkg`/`to_geojson`/`to_polars_df` operate on the
+    /// subset without the caller round-tripping through Polars just to drop rows. `where_expr`
+    /// is a small SQL-like WHERE expression over `Building` fields (see
+    /// [`crate::query::parse_where`]) -- e.g. `"hauteur > 10 AND nombre_d_etages IS NOT NULL"`
+    /// -- and `intersects` additionally requires the footprint to intersect the given polygon
+    /// (build one from a bbox with `geo::Rect::new(..).to_polygon()` for an axis-aligned query).
+    /// Either may be omitted; omitting both returns a copy of the whole collection.
+    pub fn filter(
+        &self,
+        where_expr: Option<&str>,
+        intersects: Option<&Polygon<f64>>,
+    ) -> Result<BuildingCollection> {
+        let expr = where_expr.map(parse_where).transpose()?;
+
+        Ok(self.filter_by(|building| {
+            expr.as_ref().map(|e| e.matches(building)).unwrap_or(true)
+                && intersects
+                    .map(|polygon| intersects_polygon(building, polygon))
+                    .unwrap_or(true)
+        }))
+    }
+
+    /// Buildings whose footprint intersects `geom`, as a new collection -- the spatial half of
+    /// [`BuildingCollection::filter`] under a name matching [`BuildingCollection::within`]/
+    /// [`BuildingCollection::contains`].
+    pub fn intersects(&self, geom: &Polygon<f64>) -> BuildingCollection {
+        self.filter_by(|building| intersects_polygon(building, geom))
+    }
+
+    /// Buildings whose footprint is fully contained within `bbox` (same units as `geo_core`'s
+    /// CRS), as a new collection. Tests the footprint's envelope, not every vertex, so this is
+    /// exact for axis-aligned containment but — like [`BuildingCollection::query_bbox`] — treats
+    /// a convex footprint's corners as sufficient.
+    pub fn within(&self, bbox: geo::Rect<f64>) -> BuildingCollection {
+        self.filter_by(|building| {
+            building.footprint.bounding_rect().is_some_and(|envelope| {
+                envelope.min().x >= bbox.min().x
+                    && envelope.max().x <= bbox.max().x
+                    && envelope.min().y >= bbox.min().y
+                    && envelope.max().y <= bbox.max().y
+            })
+        })
+    }
+
+    /// Buildings whose footprint contains `point` (same units as `geo_core`'s CRS), as a new
+    /// collection.
+    pub fn contains(&self, point: geo::Point<f64>) -> BuildingCollection {
+        self.filter_by(|building| building.footprint.contains(&point))
+    }
+
+    /// Clone this collection's metadata (`geo_core`, `default_storey_height`, `filepath_shp`)
+    /// into a new `BuildingCollection` holding only the buildings matching `predicate`. Shared
+    /// by [`BuildingCollection::filter`]/[`BuildingCollection::within`]/
+    /// [`BuildingCollection::contains`]/[`BuildingCollection::intersects`].
+    fn filter_by(&self, predicate: impl Fn(&Building) -> bool) -> BuildingCollection {
+        let mut result = BuildingCollection::new_simple(
+            self.geo_core.get_output_path().map(|s| s.to_string()),
+        );
+        result.geo_core = self.geo_core.clone();
+        result.default_storey_height = self.default_storey_height;
+        result.filepath_shp = self.filepath_shp.clone();
+
+        result.buildings = self
+            .buildings
+            .iter()
+            .filter(|building| predicate(building))
+            .cloned()
+            .collect();
+
+        result
+    }
+
     /// Calculate mean district height (weighted by area)
     /// Following Python: mean_distric_height = gdf["areaHauteur"].sum() / (gdf["area"].sum())
     /// Only uses buildings that already have a height (not null)
@@ -278,56 +1446,177 @@ impl BuildingCollection {
         self.buildings.is_empty()
     }
 
-    /// Load buildings from a Shapefile
-    /// NOTE: Temporarily disabled due to GDAL API issues
-    /// TODO: Fix GDAL integration
-    #[allow(dead_code)]
+    /// Load buildings from a Shapefile, or any other OGR-readable vector source (GPKG,
+    /// GeoJSON via OGR, …) — see [`BuildingCollection::from_ogr`] for picking a specific layer
+    /// by name instead of always opening layer 0.
     pub fn from_shapefile<P: AsRef<Path>>(
-        _filepath: P,
-        _output_path: Option<String>,
-        _default_storey_height: f64,
-        _set_crs: Option<i32>,
+        filepath: P,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
     ) -> Result<Self> {
-        anyhow::bail!("Shapefile loading is temporarily disabled. Use from_geojson() instead.");
-        /*
-        let mut collection = Self::new(Some(filepath.as_ref().to_string_lossy().to_string()), output_path, default_storey_height, set_crs)?;
+        let dataset = Dataset::open(filepath.as_ref()).context("Failed to open shapefile")?;
+        let layer = dataset
+            .layer(0)
+            .context("Failed to get layer from dataset")?;
+        Self::from_ogr_layer(
+            layer,
+            Some(filepath.as_ref().to_string_lossy().to_string()),
+            output_path,
+            default_storey_height,
+            set_crs,
+        )
+    }
+
+    /// Load buildings from a named layer of any OGR-readable vector source.
+    pub fn from_ogr<P: AsRef<Path>>(
+        filepath: P,
+        layer_name: &str,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let dataset = Dataset::open(filepath.as_ref()).context("Failed to open OGR dataset")?;
+        let layer = dataset
+            .layer_by_name(layer_name)
+            .with_context(|| format!("Dataset has no layer named {:?}", layer_name))?;
+        Self::from_ogr_layer(
+            layer,
+            Some(filepath.as_ref().to_string_lossy().to_string()),
+            output_path,
+            default_storey_height,
+            set_crs,
+        )
+    }
 
-        let dataset = Dataset::open(filepath.as_ref())
-            .context("Failed to open shapefile")?;
+    /// Shared OGR loading path for [`BuildingCollection::from_shapefile`] and
+    /// [`BuildingCollection::from_ogr`]. Detects the layer's own spatial reference and records
+    /// it as the collection's CRS before parsing footprints (so `area`/`centroid` below reuse
+    /// the CRS the coordinates are actually in), maps attributes via the same
+    /// `hauteur`/`nombre_d_etages`/`HAUTEUR_2`/metadata logic as `geojson_feature_to_building`,
+    /// skips non-polygon geometries, then reprojects to `set_crs` if one is requested.
+    fn from_ogr_layer<L: LayerAccess>(
+        mut layer: L,
+        filepath_shp: Option<String>,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let mut collection = Self::new(filepath_shp, output_path, default_storey_height, None)?;
 
-        let layer = dataset.layer(0)
-            .context("Failed to get layer from dataset")?;
+        if let Some(srs) = layer.spatial_ref() {
+            if let Ok(epsg_code) = srs.to_epsg() {
+                collection.geo_core.set_epsg(epsg_code as i32);
+            }
+        }
 
-        // Handle CRS
-        if let Some(epsg) = set_crs {
-            collection.set_crs(epsg);
-        } else if let Some(srs) = layer.spatial_ref() {
-            if let Some(epsg_code) = srs.to_epsg() {
-                collection.set_crs(epsg_code);
+        for feature in layer.features() {
+            match Self::feature_to_building(&feature, default_storey_height) {
+                Ok(Some(building)) => collection.add_building(building),
+                Ok(None) => continue,
+                Err(e) => {
+                    eprintln!("Warning: Failed to process feature: {}", e);
+                    continue;
+                }
             }
         }
 
-        // Iterate through features
-        for (idx, feature) in layer.features().enumerate() {
-            let feature = feature?;
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Walk every feature's properties in a GeoJSON source and report, per property name, how
+    /// many features carry that property with a non-null value (and what fraction of the total
+    /// that is), plus the geometry-type breakdown and a count of features with no geometry at
+    /// all. Meant to run before [`BuildingCollection::from_geojson`] so callers can validate an
+    /// input dataset's completeness -- e.g. know that `hauteur` is missing (and height will be
+    /// imputed from storeys) for 40% of features -- without loading the whole collection.
+    pub fn coverage_report(geojson_data: &[u8]) -> Result<CoverageReport> {
+        let geojson_str =
+            std::str::from_utf8(geojson_data).context("GeoJSON data is not valid UTF-8")?;
+        let geojson: GeoJson = geojson_str.parse().context("Failed to parse GeoJSON")?;
+
+        let features: Vec<GeoJsonFeature> = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features,
+            GeoJson::Feature(f) => vec![f],
+            GeoJson::Geometry(_) => {
+                anyhow::bail!("GeoJSON must be a Feature or FeatureCollection")
+            }
+        };
+
+        let feature_count = features.len();
+        let mut geometry_type_counts: HashMap<String, usize> = HashMap::new();
+        let mut invalid_geometry_count = 0usize;
+        let mut present_counts: HashMap<String, usize> = HashMap::new();
 
-            if let Some(building) = Self::feature_to_building(&feature, default_storey_height)? {
-                collection.add_building(building);
+        for feature in &features {
+            match feature.geometry.as_ref() {
+                Some(geometry) => {
+                    let type_name = geojson_value_type_name(&geometry.value);
+                    *geometry_type_counts.entry(type_name.to_string()).or_insert(0) += 1;
+                }
+                None => invalid_geometry_count += 1,
+            }
+
+            if let Some(properties) = &feature.properties {
+                for (key, value) in properties {
+                    if !value.is_null() {
+                        *present_counts.entry(key.clone()).or_insert(0) += 1;
+                    }
+                }
             }
         }
 
-        Ok(collection)
-        */
+        let fields = present_counts
+            .into_iter()
+            .map(|(key, present_count)| {
+                let fraction = if feature_count > 0 {
+                    present_count as f64 / feature_count as f64
+                } else {
+                    0.0
+                };
+                (
+                    key,
+                    FieldCoverage {
+                        present_count,
+                        fraction,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(CoverageReport {
+            feature_count,
+            geometry_type_counts,
+            invalid_geometry_count,
+            fields,
+        })
     }
 
-    /// Load buildings from GeoJSON (file or bytes)
+    /// Load buildings from GeoJSON (file or bytes). Footprints are parsed as-is, which is
+    /// only correct if they are already in `GEOJSON_SOURCE_EPSG` (WGS84) — true for IGN's
+    /// building API. `geo_core` records that source CRS first; `area`/`centroid` are computed
+    /// from the coordinates as parsed (degrees), then, if `set_crs`
+    /// names a different target, [`BuildingCollection::reproject`] is applied before
+    /// returning so downstream height weighting operates on square meters.
+    /// `point_buffer_radius`, when set, buffers Point/MultiPoint features (e.g. a building
+    /// centroid or address pin) into an n-segment circular footprint of that radius in meters
+    /// instead of skipping them -- see [`buffer_point_to_polygon`]. `None` rejects points as
+    /// before.
     pub fn from_geojson(
         geojson_data: &[u8],
         output_path: Option<String>,
         default_storey_height: f64,
         set_crs: Option<i32>,
+        point_buffer_radius: Option<f64>,
     ) -> Result<Self> {
-        let mut collection = Self::new(None, output_path, default_storey_height, set_crs)?;
+        let mut collection = Self::new(None, output_path, default_storey_height, None)?;
+        collection.geo_core.set_epsg(GEOJSON_SOURCE_EPSG);
 
         // Parse GeoJSON using geojson crate
         let geojson_str =
@@ -339,7 +1628,11 @@ impl BuildingCollection {
             GeoJson::FeatureCollection(fc) => {
                 for feature in fc.features {
                     // Skip features that are not polygons (continue processing)
-                    match Self::geojson_feature_to_building(&feature, default_storey_height) {
+                    match Self::geojson_feature_to_building(
+                        &feature,
+                        default_storey_height,
+                        point_buffer_radius,
+                    ) {
                         Ok(Some(building)) => {
                             collection.add_building(building);
                         }
@@ -357,7 +1650,7 @@ impl BuildingCollection {
             }
             GeoJson::Feature(f) => {
                 // Skip features that are not polygons
-                match Self::geojson_feature_to_building(&f, default_storey_height) {
+                match Self::geojson_feature_to_building(&f, default_storey_height, point_buffer_radius) {
                     Ok(Some(building)) => {
                         collection.add_building(building);
                     }
@@ -375,7 +1668,219 @@ impl BuildingCollection {
             }
         }
 
-        Ok(collection)
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Load buildings from GeoJSON in a single streaming pass that filters by extent and drops
+    /// null-height buildings as it goes, instead of materializing every feature first and
+    /// letting `process_heights()` drop the null ones afterwards the way `from_geojson` does.
+    /// Per feature: (1) if `bbox` is given, its envelope is tested against the feature's raw
+    /// coordinate envelope *before* converting to a `geo::Polygon`, so features outside the
+    /// requested extent never pay for geometry conversion; (2) height is resolved inline from
+    /// `hauteur`, falling back to `nombre_d_etages * default_storey_height`, and the building is
+    /// discarded immediately if it's still null and `drop_null_height` is set. This keeps peak
+    /// memory and CPU proportional to the buildings actually kept rather than the whole input —
+    /// worthwhile once `set_bbox`'s extent covers only a small fraction of a city-scale IGN
+    /// export.
+    pub fn import_filtered(
+        geojson_data: &[u8],
+        bbox: Option<BoundingBox>,
+        drop_null_height: bool,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let mut collection = Self::new(None, output_path, default_storey_height, None)?;
+        collection.geo_core.set_epsg(GEOJSON_SOURCE_EPSG);
+
+        let geojson_str =
+            std::str::from_utf8(geojson_data).context("GeoJSON data is not valid UTF-8")?;
+        let geojson: GeoJson = geojson_str.parse().context("Failed to parse GeoJSON")?;
+
+        let requested_envelope = bbox.map(|b| (b.min_x, b.min_y, b.max_x, b.max_y));
+
+        let features = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features,
+            GeoJson::Feature(f) => vec![f],
+            _ => anyhow::bail!("GeoJSON must be a Feature or FeatureCollection"),
+        };
+
+        for feature in &features {
+            if let Some(requested) = requested_envelope {
+                if let Some(geometry) = feature.geometry.as_ref() {
+                    if let Some(envelope) = geojson_geometry_envelope(geometry) {
+                        if !envelopes_intersect(requested, envelope) {
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            let mut building =
+                match Self::geojson_feature_to_building(feature, default_storey_height, None) {
+                    Ok(Some(building)) => building,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        eprintln!("Warning: Failed to process feature: {}", e);
+                        continue;
+                    }
+                };
+
+            if building.height.is_none() {
+                if let Some(etages) = building.nombre_d_etages {
+                    building.set_height(etages * default_storey_height);
+                }
+            }
+
+            if drop_null_height && building.height.is_none() {
+                continue;
+            }
+
+            collection.add_building(building);
+        }
+
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Load buildings from newline-delimited GeoJSON (one `Feature` per line, per the
+    /// jsonlines/GeoJSONSeq convention), parsing and converting one line at a time instead of
+    /// materializing the whole document the way [`BuildingCollection::from_geojson`] does. Use
+    /// this for IGN tiles large enough that holding every feature's parsed JSON at once is the
+    /// memory bottleneck. Blank lines are skipped; a line that fails to parse or isn't a
+    /// polygon is logged and skipped, matching `from_geojson`'s error handling.
+    pub fn from_geojson_seq<R: std::io::BufRead>(
+        reader: R,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let mut collection = Self::new(None, output_path, default_storey_height, None)?;
+        collection.geo_core.set_epsg(GEOJSON_SOURCE_EPSG);
+
+        for line in reader.lines() {
+            let line = line.context("Failed to read a line of GeoJSONSeq input")?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let feature: GeoJsonFeature = match line.parse() {
+                Ok(feature) => feature,
+                Err(e) => {
+                    eprintln!("Warning: Failed to parse GeoJSONSeq line: {}", e);
+                    continue;
+                }
+            };
+
+            match Self::geojson_feature_to_building(&feature, default_storey_height, None) {
+                Ok(Some(building)) => collection.add_building(building),
+                Ok(None) => continue,
+                Err(e) => {
+                    eprintln!("Warning: Failed to process feature: {}", e);
+                    continue;
+                }
+            }
+        }
+
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Stream every building out as newline-delimited GeoJSON (one `Feature` per line),
+    /// the write-side counterpart to [`BuildingCollection::from_geojson_seq`]. Each feature is
+    /// serialized and written independently, so peak memory is bounded by a single feature
+    /// rather than the whole collection the way [`BuildingCollection::to_geozero_geojson`]'s
+    /// single `FeatureCollection` document is.
+    pub fn to_geojson_seq<W: std::io::Write>(&self, mut writer: W) -> Result<()> {
+        for building in &self.buildings {
+            let geo_geom = geo::Geometry::Polygon(building.footprint.clone());
+            let geometry = Geometry::new(geojson::Value::from(&geo_geom));
+
+            let mut properties = serde_json::Map::new();
+            properties.insert("hauteur".to_string(), building.get_height(0.0).into());
+            properties.insert("area".to_string(), building.area.into());
+            properties.insert("centroid_x".to_string(), building.centroid.x().into());
+            properties.insert("centroid_y".to_string(), building.centroid.y().into());
+
+            let feature = GeoJsonFeature {
+                bbox: None,
+                geometry: Some(geometry),
+                id: None,
+                properties: Some(properties),
+                foreign_members: None,
+            };
+
+            writeln!(writer, "{}", serde_json::to_string(&feature).context("Failed to serialize building feature")?)
+                .context("Failed to write a GeoJSONSeq line")?;
+        }
+        Ok(())
+    }
+
+    /// Build a GeoJSON `FeatureCollection` holding every building, one `Feature` per building
+    /// with the same property set [`BuildingCollection::to_geojson_seq`] writes plus
+    /// `nombre_d_etages`/`hauteur_2`/`no_hauteur` for parity with [`BuildingCollection::to_dataframe`].
+    pub fn get_geojson(&self) -> Result<GeoJson> {
+        self.get_geojson_with_options(None, None)
+    }
+
+    /// Like [`BuildingCollection::get_geojson`], with two extra knobs for large collections:
+    /// `precision` rounds every emitted coordinate to that many decimal places (6 decimals is
+    /// about 0.1m at these latitudes, and cuts serialized size substantially), and
+    /// `foreign_members` attaches top-level members (e.g. a `bbox`, source API name, query
+    /// timestamp) to the returned FeatureCollection. Both are no-ops when `None`.
+    pub fn get_geojson_with_options(
+        &self,
+        precision: Option<u32>,
+        foreign_members: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<GeoJson> {
+        let mut features = Vec::with_capacity(self.buildings.len());
+        for building in &self.buildings {
+            let geo_geom = geo::Geometry::Polygon(building.footprint.clone());
+            let mut geometry = Geometry::new(geojson::Value::from(&geo_geom));
+            if let Some(precision) = precision {
+                round_geometry(&mut geometry, precision);
+            }
+
+            let mut properties = serde_json::Map::new();
+            properties.insert("hauteur".to_string(), building.get_height(0.0).into());
+            properties.insert("area".to_string(), building.area.into());
+            properties.insert("centroid_x".to_string(), building.centroid.x().into());
+            properties.insert("centroid_y".to_string(), building.centroid.y().into());
+            properties.insert("nombre_d_etages".to_string(), building.nombre_d_etages.into());
+            properties.insert("hauteur_2".to_string(), building.hauteur_2.into());
+            properties.insert("no_hauteur".to_string(), building.no_hauteur.into());
+
+            features.push(GeoJsonFeature {
+                bbox: None,
+                geometry: Some(geometry),
+                id: None,
+                properties: Some(properties),
+                foreign_members: None,
+            });
+        }
+
+        Ok(GeoJson::FeatureCollection(geojson::FeatureCollection {
+            bbox: None,
+            features,
+            foreign_members,
+        }))
     }
 
     /// Load buildings from IGN API
@@ -404,57 +1909,61 @@ impl BuildingCollection {
         Self::from_geojson(geojson_bytes, output_path, default_storey_height, None)
     }
 
-    /// Convert a GDAL feature to a Building
-    /// NOTE: Temporarily disabled due to GDAL API issues
-    #[allow(dead_code)]
+    /// Convert a GDAL feature (Shapefile, or any other OGR-readable vector source) to a
+    /// Building. Returns `None` if the feature has no geometry or its geometry isn't a
+    /// polygon, so callers can skip it exactly like `geojson_feature_to_building` does.
+    /// Attribute mapping mirrors `geojson_feature_to_building`'s `hauteur`/`nombre_d_etages`/
+    /// `HAUTEUR_2`/metadata logic field-for-field.
     fn feature_to_building(
-        _feature: &GdalFeature,
+        feature: &GdalFeature,
         _default_storey_height: f64,
     ) -> Result<Option<Building>> {
-        anyhow::bail!("GDAL feature conversion is temporarily disabled");
-        /*
-        // Get geometry
-        let geometry = feature.geometry()
-            .context("Feature has no geometry")?;
+        let geometry = match feature.geometry() {
+            Some(g) => g,
+            None => return Ok(None),
+        };
 
-        // Convert GDAL geometry to geo::Polygon
-        let polygon = Self::gdal_geometry_to_polygon(&geometry)?;
+        let polygon = match Self::gdal_geometry_to_polygon(geometry)? {
+            Some(poly) => poly,
+            None => return Ok(None),
+        };
 
         let mut building = Building::new(polygon);
 
-        // Extract attributes
         for field_idx in 0..feature.field_count() {
-            let field_defn = feature.field_defn(field_idx)
+            let field_defn = feature
+                .field_defn(field_idx)
                 .context("Failed to get field definition")?;
-
             let field_name = field_defn.name();
-            let field_value = feature.field(field_idx);
+
+            let field_value = feature
+                .field(&field_name)
+                .with_context(|| format!("Failed to read field {:?}", field_name))?;
 
             match field_name.to_lowercase().as_str() {
                 "hauteur" | "height" => {
-                    if let Some(f) = field_value.as_real() {
+                    if let Some(f) = field_value.and_then(|v| v.into_real()) {
                         if f.is_finite() && f > 0.0 {
                             building.set_height(f);
                         }
                     }
                 }
                 "nombre_d_etages" | "storeys" | "etages" => {
-                    if let Some(f) = field_value.as_real() {
+                    if let Some(f) = field_value.and_then(|v| v.into_real()) {
                         if f.is_finite() && f > 0.0 {
                             building.set_nombre_d_etages(f);
                         }
                     }
                 }
                 "hauteur_2" | "height_2" | "h2" => {
-                    if let Some(f) = field_value.as_real() {
+                    if let Some(f) = field_value.and_then(|v| v.into_real()) {
                         if f.is_finite() && f > 0.0 {
                             building.set_hauteur_2(f);
                         }
                     }
                 }
                 _ => {
-                    // Store other fields as metadata
-                    if let Some(s) = field_value.as_string() {
+                    if let Some(s) = field_value.and_then(|v| v.into_string()) {
                         building.metadata.insert(field_name.to_string(), s);
                     }
                 }
@@ -462,7 +1971,6 @@ impl BuildingCollection {
         }
 
         Ok(Some(building))
-        */
     }
 
     /// Convert GeoJSON feature to Building
@@ -470,15 +1978,21 @@ impl BuildingCollection {
     fn geojson_feature_to_building(
         feature: &GeoJsonFeature,
         _default_storey_height: f64,
+        point_buffer_radius: Option<f64>,
     ) -> Result<Option<Building>> {
         let geometry = feature
             .geometry
             .as_ref()
             .context("Feature has no geometry")?;
 
-        // Get polygon from geometry (handles Polygon and MultiPolygon)
+        // Get polygon from geometry (handles Polygon, MultiPolygon, and -- when
+        // `point_buffer_radius` is set -- Point/MultiPoint buffered into a circle).
         // Returns None if not a polygon type - we skip these features
-        let polygon = match Self::geojson_geometry_to_polygon(geometry)? {
+        let polygon = match Self::geojson_geometry_to_polygon(
+            geometry,
+            point_buffer_radius,
+            GEOJSON_SOURCE_EPSG,
+        )? {
             Some(poly) => poly,
             None => {
                 // Not a polygon - skip this feature
@@ -543,35 +2057,37 @@ impl BuildingCollection {
         Ok(Some(building))
     }
 
-    /// Convert GDAL geometry to geo::Polygon
-    /// NOTE: Temporarily disabled due to GDAL API issues
-    #[allow(dead_code)]
-    fn gdal_geometry_to_polygon(_geometry: &gdal::vector::Geometry) -> Result<Polygon<f64>> {
-        anyhow::bail!("GDAL geometry conversion is temporarily disabled");
-        /*
-        // Get WKT representation and parse it
-        let wkt = geometry.wkt()
-            .context("Failed to get WKT from geometry")?;
-
-        // Use geos to parse WKT (more reliable than manual parsing)
-        let geos_geom = geos::Geometry::try_from(geometry)
-            .context("Failed to convert GDAL geometry to GEOS")?;
+    /// Convert a GDAL vector geometry to a `Polygon`, handling Polygon and MultiPolygon (takes
+    /// the first polygon from a MultiPolygon). Returns `None` for any other geometry type, so
+    /// callers can skip non-polygon features the same way `geojson_geometry_to_polygon` does.
+    /// Goes through the `gdal` crate's own `ToGeo` conversion rather than round-tripping
+    /// through WKT/GEOS.
+    fn gdal_geometry_to_polygon(geometry: &gdal::vector::Geometry) -> Result<Option<Polygon<f64>>> {
+        use gdal::vector::ToGeo;
 
-        // Convert GEOS to geo
-        let geo_geom: geo::Geometry<f64> = geos_geom.try_into()
-            .context("Failed to convert GEOS geometry to geo")?;
+        let geo_geom: geo::Geometry<f64> = geometry
+            .to_geo()
+            .context("Failed to convert GDAL geometry to geo::Geometry")?;
 
         match geo_geom {
-            geo::Geometry::Polygon(poly) => Ok(poly),
-            _ => anyhow::bail!("Geometry is not a polygon"),
+            geo::Geometry::Polygon(poly) => Ok(Some(poly)),
+            geo::Geometry::MultiPolygon(mp) => Ok(mp.0.into_iter().next()),
+            _ => Ok(None),
         }
-        */
     }
 
     /// Convert GeoJSON geometry to Polygon
     /// Handles Polygon and MultiPolygon (takes first polygon from MultiPolygon)
-    /// Returns None if geometry is not a polygon type (allows skipping non-polygon features)
-    fn geojson_geometry_to_polygon(geometry: &Geometry) -> Result<Option<Polygon<f64>>> {
+    /// Returns None if geometry is not a polygon type (allows skipping non-polygon features),
+    /// unless `point_buffer_radius` is set, in which case Point/MultiPoint geometries are
+    /// buffered into a circular footprint polygon of that radius (in meters) via
+    /// [`buffer_point_to_polygon`] instead of being skipped. `source_epsg` is the CRS the
+    /// geometry's raw coordinates are in, needed to buffer by a meaningful metric radius.
+    fn geojson_geometry_to_polygon(
+        geometry: &Geometry,
+        point_buffer_radius: Option<f64>,
+        source_epsg: i32,
+    ) -> Result<Option<Polygon<f64>>> {
         // Convert geojson::Geometry to geo::Geometry
         let geo_geom: geo::Geometry<f64> = geometry
             .try_into()
@@ -587,6 +2103,18 @@ impl BuildingCollection {
                     Ok(None) // Empty MultiPolygon
                 }
             }
+            geo::Geometry::Point(point) => match point_buffer_radius {
+                Some(radius) => {
+                    buffer_point_to_polygon(&point, radius, source_epsg).map(Some)
+                }
+                None => Ok(None),
+            },
+            geo::Geometry::MultiPoint(mp) => match (point_buffer_radius, mp.0.first()) {
+                (Some(radius), Some(point)) => {
+                    buffer_point_to_polygon(point, radius, source_epsg).map(Some)
+                }
+                _ => Ok(None),
+            },
             _ => {
                 // Not a polygon type - return None to skip this feature
                 Ok(None)
@@ -687,6 +2215,7 @@ impl BuildingCollection {
         let mut nombre_d_etages_vec: Vec<Option<f64>> = Vec::new();
         let mut hauteur_2_vec: Vec<Option<f64>> = Vec::new();
         let mut no_hauteur_vec: Vec<bool> = Vec::new();
+        let mut plus_code_vec: Vec<String> = Vec::new();
 
         for building in &self.buildings {
             height_vec.push(building.height);
@@ -696,6 +2225,7 @@ impl BuildingCollection {
             nombre_d_etages_vec.push(building.nombre_d_etages);
             hauteur_2_vec.push(building.hauteur_2);
             no_hauteur_vec.push(building.no_hauteur);
+            plus_code_vec.push(building.plus_code(10));
         }
 
         let df = df! [
@@ -706,28 +2236,1114 @@ impl BuildingCollection {
             "nombre_d_etages" => nombre_d_etages_vec,
             "hauteur_2" => hauteur_2_vec,
             "noHauteur" => no_hauteur_vec,
+            "plus_code" => plus_code_vec,
         ]
         .context("Failed to create DataFrame")?;
 
         Ok(df)
     }
 
+    /// Flatten [`BuildingCollection::compute_morphology`]'s per-cell grid into a Polars
+    /// `DataFrame`, one row per non-empty cell: the cell's bounds, λp, mean/stddev height,
+    /// surface-to-plan-area ratio, approximate sky-view factor, and λf for `wind_dirs[0]` (the
+    /// full per-azimuth breakdown across `wind_dirs` is only available from
+    /// [`BuildingCollection::compute_morphology`] directly, since a DataFrame row doesn't have a
+    /// natural place for a variable-length list of `(wind_dir, lambda_f)` pairs).
+    pub fn morphology_to_polars_df(&self, grid_resolution: f64, wind_dirs: &[f64]) -> Result<DataFrame> {
+        let cells = self.compute_morphology(grid_resolution, wind_dirs);
+
+        let cell_min_x_vec: Vec<f64> = cells.iter().map(|c| c.cell_min_x).collect();
+        let cell_min_y_vec: Vec<f64> = cells.iter().map(|c| c.cell_min_y).collect();
+        let cell_max_x_vec: Vec<f64> = cells.iter().map(|c| c.cell_max_x).collect();
+        let cell_max_y_vec: Vec<f64> = cells.iter().map(|c| c.cell_max_y).collect();
+        let lambda_p_vec: Vec<f64> = cells.iter().map(|c| c.morpho.lambda_p).collect();
+        let lambda_f_vec: Vec<f64> = cells.iter().map(|c| c.morpho.lambda_f).collect();
+        let mean_height_vec: Vec<f64> = cells.iter().map(|c| c.morpho.mean_height).collect();
+        let height_stddev_vec: Vec<f64> = cells.iter().map(|c| c.morpho.height_stddev).collect();
+        let surface_to_plan_area_ratio_vec: Vec<f64> =
+            cells.iter().map(|c| c.surface_to_plan_area_ratio).collect();
+        let sky_view_factor_vec: Vec<f64> = cells.iter().map(|c| c.sky_view_factor).collect();
+
+        df! [
+            "cell_min_x" => cell_min_x_vec,
+            "cell_min_y" => cell_min_y_vec,
+            "cell_max_x" => cell_max_x_vec,
+            "cell_max_y" => cell_max_y_vec,
+            "lambda_p" => lambda_p_vec,
+            "lambda_f" => lambda_f_vec,
+            "mean_height" => mean_height_vec,
+            "height_stddev" => height_stddev_vec,
+            "surface_to_plan_area_ratio" => surface_to_plan_area_ratio_vec,
+            "sky_view_factor" => sky_view_factor_vec,
+        ]
+        .context("Failed to create DataFrame")
+    }
+
+    /// Bin buildings into H3 hexagonal cells at `resolution` (0-15), one entry per non-empty
+    /// cell. Each building's centroid is reprojected to WGS84 (a scratch copy — `geo_core`'s CRS
+    /// is left untouched, same as [`BuildingCollection::geodesic_measurements`]) and mapped to
+    /// its containing cell. When `split_by_intersection` is `true`, a building whose *footprint*
+    /// spans more than one cell instead has its area/count/volume apportioned across every cell
+    /// in the centroid cell's immediate 1-ring that its footprint actually intersects, weighted
+    /// by the intersection area share; `false` assigns the whole building to its centroid cell.
+    pub fn h3_aggregate(
+        &self,
+        resolution: u8,
+        split_by_intersection: bool,
+    ) -> Result<Vec<H3CellAggregate>> {
+        let resolution = Resolution::try_from(resolution)
+            .map_err(|e| anyhow::anyhow!("Invalid H3 resolution {resolution}: {e}"))?;
+
+        let epsg = self.geo_core.get_epsg();
+        let to_latlong: Box<dyn Fn(&Polygon<f64>) -> Polygon<f64>> = if epsg == GEOJSON_SOURCE_EPSG
+        {
+            Box::new(|footprint: &Polygon<f64>| footprint.clone())
+        } else {
+            let proj = Proj::new_known_crs(
+                &format!("EPSG:{}", epsg),
+                &format!("EPSG:{}", GEOJSON_SOURCE_EPSG),
+                None,
+            )
+            .with_context(|| {
+                format!(
+                    "EPSG:{} -> EPSG:{} is not a transformation PROJ supports",
+                    epsg, GEOJSON_SOURCE_EPSG
+                )
+            })?;
+            Box::new(move |footprint: &Polygon<f64>| {
+                footprint.map_coords(|c| {
+                    let (x, y) = proj.convert((c.x, c.y)).unwrap_or((c.x, c.y));
+                    geo::coord! { x: x, y: y }
+                })
+            })
+        };
+
+        let mut cells: HashMap<CellIndex, H3CellAgg> = HashMap::new();
+        for building in &self.buildings {
+            let footprint_latlong = to_latlong(&building.footprint);
+            let Some(centroid) = footprint_latlong.centroid() else {
+                continue;
+            };
+            let Ok(centroid_latlng) = LatLng::new(centroid.y(), centroid.x()) else {
+                continue;
+            };
+            let centroid_cell = centroid_latlng.to_cell(resolution);
+            let height = building.get_height(self.default_storey_height);
+
+            let weights: Vec<(CellIndex, f64)> = if split_by_intersection {
+                let candidates: Vec<CellIndex> = centroid_cell.grid_disk::<Vec<CellIndex>>(1);
+                let mut shares: Vec<(CellIndex, f64)> = Vec::new();
+                let mut total_overlap = 0.0;
+                for candidate in candidates {
+                    let Ok(boundary_ring) = cell_boundary_polygon(candidate) else {
+                        continue;
+                    };
+                    let overlap = footprint_latlong.intersection(&boundary_ring).unsigned_area();
+                    if overlap > 0.0 {
+                        total_overlap += overlap;
+                        shares.push((candidate, overlap));
+                    }
+                }
+                if total_overlap > 0.0 {
+                    shares
+                        .into_iter()
+                        .map(|(cell, overlap)| (cell, overlap / total_overlap))
+                        .collect()
+                } else {
+                    vec![(centroid_cell, 1.0)]
+                }
+            } else {
+                vec![(centroid_cell, 1.0)]
+            };
+
+            for (cell, weight) in weights {
+                let agg = cells.entry(cell).or_default();
+                agg.building_count += weight;
+                agg.total_area += building.area * weight;
+                agg.total_volume += building.area * height * weight;
+                if let Some(h) = building.height {
+                    agg.height_sum += h * weight;
+                    agg.height_weight += weight;
+                }
+                agg.max_height = agg.max_height.max(height);
+            }
+        }
+
+        Ok(cells
+            .into_iter()
+            .map(|(cell, agg)| H3CellAggregate {
+                h3_index: cell.to_string(),
+                building_count: agg.building_count,
+                total_area: agg.total_area,
+                mean_footprint_area: if agg.building_count > 0.0 {
+                    agg.total_area / agg.building_count
+                } else {
+                    0.0
+                },
+                mean_height: if agg.height_weight > 0.0 {
+                    agg.height_sum / agg.height_weight
+                } else {
+                    0.0
+                },
+                max_height: agg.max_height,
+                total_volume: agg.total_volume,
+            })
+            .collect())
+    }
+
+    /// [`BuildingCollection::h3_aggregate`] as a Polars `DataFrame`, one row per non-empty cell.
+    pub fn to_h3_aggregation(
+        &self,
+        resolution: u8,
+        split_by_intersection: bool,
+    ) -> Result<DataFrame> {
+        let cells = self.h3_aggregate(resolution, split_by_intersection)?;
+
+        let h3_index_vec: Vec<String> = cells.iter().map(|c| c.h3_index.clone()).collect();
+        let building_count_vec: Vec<f64> = cells.iter().map(|c| c.building_count).collect();
+        let total_area_vec: Vec<f64> = cells.iter().map(|c| c.total_area).collect();
+        let mean_footprint_area_vec: Vec<f64> =
+            cells.iter().map(|c| c.mean_footprint_area).collect();
+        let mean_height_vec: Vec<f64> = cells.iter().map(|c| c.mean_height).collect();
+        let max_height_vec: Vec<f64> = cells.iter().map(|c| c.max_height).collect();
+        let total_volume_vec: Vec<f64> = cells.iter().map(|c| c.total_volume).collect();
+
+        df! [
+            "h3_index" => h3_index_vec,
+            "building_count" => building_count_vec,
+            "total_area" => total_area_vec,
+            "mean_footprint_area" => mean_footprint_area_vec,
+            "mean_height" => mean_height_vec,
+            "max_height" => max_height_vec,
+            "total_volume" => total_volume_vec,
+        ]
+        .context("Failed to create H3 aggregation DataFrame")
+    }
+
+    /// Flatten the collection into a Polars `DataFrame`, one row per building: `area`,
+    /// `height`, `nombre_d_etages`, `hauteur_2`, `no_hauteur`, `centroid_x`, `centroid_y`, a
+    /// `footprint` column holding the polygon as a GeoJSON string, and one extra column per
+    /// distinct `metadata` key observed across the collection (missing per-building values
+    /// are null). Pair with [`BuildingCollection::from_dataframe`] to round-trip.
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        let mut metadata_keys: Vec<String> = Vec::new();
+        for building in &self.buildings {
+            for key in building.metadata.keys() {
+                if !metadata_keys.contains(key) {
+                    metadata_keys.push(key.clone());
+                }
+            }
+        }
+
+        let area_vec: Vec<f64> = self.buildings.iter().map(|b| b.area).collect();
+        let height_vec: Vec<Option<f64>> = self.buildings.iter().map(|b| b.height).collect();
+        let nombre_d_etages_vec: Vec<Option<f64>> =
+            self.buildings.iter().map(|b| b.nombre_d_etages).collect();
+        let hauteur_2_vec: Vec<Option<f64>> = self.buildings.iter().map(|b| b.hauteur_2).collect();
+        let no_hauteur_vec: Vec<bool> = self.buildings.iter().map(|b| b.no_hauteur).collect();
+        let centroid_x_vec: Vec<f64> = self.buildings.iter().map(|b| b.centroid.x()).collect();
+        let centroid_y_vec: Vec<f64> = self.buildings.iter().map(|b| b.centroid.y()).collect();
+        let footprint_vec: Vec<String> = self
+            .buildings
+            .iter()
+            .map(|b| footprint_to_geojson_string(&b.footprint))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut columns: Vec<Series> = vec![
+            Series::new("area".into(), area_vec),
+            Series::new("height".into(), height_vec),
+            Series::new("nombre_d_etages".into(), nombre_d_etages_vec),
+            Series::new("hauteur_2".into(), hauteur_2_vec),
+            Series::new("no_hauteur".into(), no_hauteur_vec),
+            Series::new("centroid_x".into(), centroid_x_vec),
+            Series::new("centroid_y".into(), centroid_y_vec),
+            Series::new("footprint".into(), footprint_vec),
+        ];
+
+        for key in &metadata_keys {
+            let column: Vec<Option<String>> = self
+                .buildings
+                .iter()
+                .map(|b| b.metadata.get(key).cloned())
+                .collect();
+            columns.push(Series::new(key.as_str().into(), column));
+        }
+
+        DataFrame::new(columns).context("Failed to build DataFrame from BuildingCollection")
+    }
+
+    /// Reconstruct a `BuildingCollection` from a `DataFrame` with the column layout produced
+    /// by [`BuildingCollection::to_dataframe`]. Any column other than the standard ones is
+    /// read back into `Building::metadata`, keyed by its column name.
+    pub fn from_dataframe(
+        df: &DataFrame,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let mut collection = Self::new(None, output_path, default_storey_height, set_crs)?;
+
+        const STANDARD_COLUMNS: [&str; 8] = [
+            "area",
+            "height",
+            "nombre_d_etages",
+            "hauteur_2",
+            "no_hauteur",
+            "centroid_x",
+            "centroid_y",
+            "footprint",
+        ];
+        let metadata_columns: Vec<String> = df
+            .get_column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .filter(|name| !STANDARD_COLUMNS.contains(&name.as_str()))
+            .collect();
+
+        let height_col = df.column("height")?.f64()?;
+        let nombre_d_etages_col = df.column("nombre_d_etages")?.f64()?;
+        let hauteur_2_col = df.column("hauteur_2")?.f64()?;
+        let footprint_col = df.column("footprint")?.str()?;
+
+        for row in 0..df.height() {
+            let footprint_str = footprint_col
+                .get(row)
+                .context("footprint column has a null value")?;
+            let geometry: Geometry = serde_json::from_str(footprint_str)
+                .context("Failed to parse footprint GeoJSON")?;
+            let footprint = Self::geojson_geometry_to_polygon(&geometry, None, GEOJSON_SOURCE_EPSG)?
+                .context("footprint column did not contain a polygon")?;
+
+            let mut building = Building::new(footprint);
+            if let Some(h) = height_col.get(row) {
+                building.set_height(h);
+            }
+            if let Some(etages) = nombre_d_etages_col.get(row) {
+                building.set_nombre_d_etages(etages);
+            }
+            if let Some(h2) = hauteur_2_col.get(row) {
+                building.set_hauteur_2(h2);
+            }
+
+            for key in &metadata_columns {
+                if let Some(s) = df.column(key)?.str()?.get(row) {
+                    building.metadata.insert(key.clone(), s.to_string());
+                }
+            }
+
+            collection.add_building(building);
+        }
+
+        Ok(collection)
+    }
+
+    /// Bounding rect (min_x, min_y, max_x, max_y) spanning every footprint's bounding rect, or
+    /// `None` if the collection has no buildings.
+    fn footprint_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        for building in &self.buildings {
+            if let Some(rect) = building.footprint.bounding_rect() {
+                min_x = min_x.min(rect.min().x);
+                min_y = min_y.min(rect.min().y);
+                max_x = max_x.max(rect.max().x);
+                max_y = max_y.max(rect.max().y);
+            }
+        }
+        if min_x.is_finite() {
+            Some((min_x, min_y, max_x, max_y))
+        } else {
+            None
+        }
+    }
+
+    /// Compute the standard urban-canopy parameters that wind/energy microclimate models
+    /// consume, over `lot_area` (defaults to the collection's footprint bounding rect area
+    /// when `None`) for wind direction `wind_dir_deg` (degrees).
+    ///
+    /// - λp (plan area density) = (Σ footprint areas) / `lot_area`; `building_coverage` reports
+    ///   the same ratio, kept as a separate field since the two names are used interchangeably
+    ///   in the literature this targets.
+    /// - λf(θ) (frontal area density) = (Σ height_i * W_i(θ)) / `lot_area`, where W_i(θ) is
+    ///   building i's footprint width projected onto the plane perpendicular to the wind:
+    ///   rotate every footprint vertex by −θ about the centroid and take (max y′ − min y′) of
+    ///   the rotated coordinates.
+    /// - `get_height` fills in missing heights from `default_storey_height` for both λf and
+    ///   the returned mean/stddev.
+    pub fn morphology(&self, lot_area: Option<f64>, wind_dir_deg: f64) -> MorphoIndicators {
+        let lot_area = lot_area.unwrap_or_else(|| {
+            self.footprint_bounds()
+                .map(|(min_x, min_y, max_x, max_y)| (max_x - min_x) * (max_y - min_y))
+                .unwrap_or(0.0)
+        });
+
+        let total_area: f64 = self.buildings.iter().map(|b| b.area).sum();
+        let lambda_p = if lot_area > 0.0 {
+            total_area / lot_area
+        } else {
+            0.0
+        };
+
+        let theta = wind_dir_deg.to_radians();
+        let (sin_t, cos_t) = theta.sin_cos();
+        let heights: Vec<f64> = self
+            .buildings
+            .iter()
+            .map(|b| b.get_height(self.default_storey_height))
+            .collect();
+
+        let total_frontal: f64 = self
+            .buildings
+            .iter()
+            .zip(&heights)
+            .map(|(b, &height)| height * frontal_width(&b.footprint, &b.centroid, sin_t, cos_t))
+            .sum();
+        let lambda_f = if lot_area > 0.0 {
+            total_frontal / lot_area
+        } else {
+            0.0
+        };
+
+        let mean_height = if total_area > 0.0 {
+            self.buildings
+                .iter()
+                .zip(&heights)
+                .map(|(b, &height)| b.area * height)
+                .sum::<f64>()
+                / total_area
+        } else {
+            0.0
+        };
+
+        let height_stddev = if !heights.is_empty() {
+            let mean: f64 = heights.iter().sum::<f64>() / heights.len() as f64;
+            let variance: f64 = heights.iter().map(|h| (h - mean).powi(2)).sum::<f64>()
+                / heights.len() as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        MorphoIndicators {
+            lambda_p,
+            building_coverage: lambda_p,
+            lambda_f,
+            mean_height,
+            height_stddev,
+        }
+    }
+
+    /// λf(θ) alone, factored out of [`BuildingCollection::morphology`] so
+    /// [`BuildingCollection::compute_morphology`] can evaluate several wind azimuths per cell
+    /// without recomputing λp/mean height/height stddev each time.
+    fn lambda_f_for(&self, lot_area: f64, wind_dir_deg: f64) -> f64 {
+        if lot_area <= 0.0 {
+            return 0.0;
+        }
+        let theta = wind_dir_deg.to_radians();
+        let (sin_t, cos_t) = theta.sin_cos();
+        let total_frontal: f64 = self
+            .buildings
+            .iter()
+            .map(|b| {
+                b.get_height(self.default_storey_height) * frontal_width(&b.footprint, &b.centroid, sin_t, cos_t)
+            })
+            .sum();
+        total_frontal / lot_area
+    }
+
+    /// Grid the collection's bounding rect into `grid_resolution` x `grid_resolution` cells and
+    /// compute [`GridMorphoIndicators`] for each non-empty cell -- the per-cell building
+    /// parameter set urban climate/weather models (TEB, WRF urban schemes) expect, rather than
+    /// [`BuildingCollection::morphology`]'s single whole-collection lot.
+    ///
+    /// `wind_dirs` is evaluated per cell for λf; pass a single direction for one azimuth or
+    /// several (e.g. the 8 cardinal/intercardinal directions) to get a λf per direction. Needs
+    /// `height` filled (call [`BuildingCollection::process_heights`] first) for meaningful
+    /// heights/λf/surface ratios, and footprints already reprojected to a projected (metric) CRS
+    /// -- this operates on planar coordinates, not geodesic ones.
+    ///
+    /// A cell's buildings are those whose footprint bounding rect is fully contained in the
+    /// cell ([`BuildingCollection::within`]), so a building straddling a cell boundary is
+    /// attributed to neither neighboring cell rather than being double-counted or split.
+    pub fn compute_morphology(&self, grid_resolution: f64, wind_dirs: &[f64]) -> Vec<GridMorphoIndicators> {
+        let Some((min_x, min_y, max_x, max_y)) = self.footprint_bounds() else {
+            return Vec::new();
+        };
+        if grid_resolution <= 0.0 || wind_dirs.is_empty() {
+            return Vec::new();
+        }
+
+        let cols = ((max_x - min_x) / grid_resolution).ceil().max(1.0) as usize;
+        let rows = ((max_y - min_y) / grid_resolution).ceil().max(1.0) as usize;
+        let cell_area = grid_resolution * grid_resolution;
+
+        let mut results = Vec::new();
+        for row in 0..rows {
+            for col in 0..cols {
+                let cell_min_x = min_x + col as f64 * grid_resolution;
+                let cell_min_y = min_y + row as f64 * grid_resolution;
+                let cell_max_x = (cell_min_x + grid_resolution).min(max_x).max(cell_min_x);
+                let cell_max_y = (cell_min_y + grid_resolution).min(max_y).max(cell_min_y);
+
+                let cell_rect = geo::Rect::new((cell_min_x, cell_min_y), (cell_max_x, cell_max_y));
+                let cell_buildings = self.within(cell_rect);
+                if cell_buildings.buildings.is_empty() {
+                    continue;
+                }
+
+                let first_wind_dir = wind_dirs[0];
+                let morpho = cell_buildings.morphology(Some(cell_area), first_wind_dir);
+                let lambda_f_by_wind_dir: Vec<(f64, f64)> = wind_dirs
+                    .iter()
+                    .map(|&wind_dir| {
+                        let lambda_f = if wind_dir == first_wind_dir {
+                            morpho.lambda_f
+                        } else {
+                            cell_buildings.lambda_f_for(cell_area, wind_dir)
+                        };
+                        (wind_dir, lambda_f)
+                    })
+                    .collect();
+
+                let total_surface: f64 = cell_buildings
+                    .buildings
+                    .iter()
+                    .map(|b| b.area + b.footprint.exterior().euclidean_length() * b.get_height(cell_buildings.default_storey_height))
+                    .sum();
+                let surface_to_plan_area_ratio = if cell_area > 0.0 { total_surface / cell_area } else { 0.0 };
+
+                // Canyon-radiation-style approximation, not a ray-traced estimate: denser
+                // frontal area blocks more sky, so SVF falls off exponentially with λf.
+                const SVF_DECAY_K: f64 = 2.0;
+                let sky_view_factor = (-SVF_DECAY_K * morpho.lambda_f).exp();
+
+                results.push(GridMorphoIndicators {
+                    cell_min_x,
+                    cell_min_y,
+                    cell_max_x,
+                    cell_max_y,
+                    morpho,
+                    lambda_f_by_wind_dir,
+                    surface_to_plan_area_ratio,
+                    sky_view_factor,
+                });
+            }
+        }
+
+        results
+    }
+
+    /// Burn footprints and heights into a simple digital surface model grid. The raster's
+    /// origin is the min x/min y of the bounding rect of all footprints, cell size is
+    /// `cell_size` in `geo_core`'s units, and each cell gets `get_height` of whichever
+    /// building covers its center (the tallest, where footprints overlap). Empty cells are
+    /// `f32::NAN`. Returns a raster with zero rows/cols if the collection has no buildings.
+    pub fn rasterize_heights(&self, cell_size: f64) -> HeightRaster {
+        let Some((min_x, min_y, max_x, max_y)) = self.footprint_bounds() else {
+            return HeightRaster {
+                origin_x: 0.0,
+                origin_y: 0.0,
+                cell_size,
+                cols: 0,
+                rows: 0,
+                heights: Vec::new(),
+            };
+        };
+
+        let cols = ((max_x - min_x) / cell_size).ceil().max(1.0) as usize;
+        let rows = ((max_y - min_y) / cell_size).ceil().max(1.0) as usize;
+        let mut heights = vec![f32::NAN; cols * rows];
+
+        for building in &self.buildings {
+            let height = building.get_height(self.default_storey_height) as f32;
+            fill_footprint(
+                &building.footprint,
+                min_x,
+                min_y,
+                cell_size,
+                cols,
+                rows,
+                height,
+                &mut heights,
+            );
+        }
+
+        HeightRaster {
+            origin_x: min_x,
+            origin_y: min_y,
+            cell_size,
+            cols,
+            rows,
+            heights,
+        }
+    }
+
     /// Get a reference to the buildings vector
     pub fn buildings(&self) -> &Vec<Building> {
         &self.buildings
     }
 
-    /// Get a mutable reference to the buildings vector
+    /// Get a mutable reference to the buildings vector. Invalidates the spatial index, since
+    /// the caller may add, remove, or reshape footprints through it.
     pub fn buildings_mut(&mut self) -> &mut Vec<Building> {
+        self.invalidate_spatial_index();
         &mut self.buildings
     }
 
-    /// Export buildings to GPKG file
-    /// NOTE: Temporarily disabled due to GDAL API issues
+    /// Drop the cached R-tree so the next `query_bbox`/`nearest`/`within_distance` call rebuilds
+    /// it from scratch. `buildings_mut()` already calls this; only needed directly if a building
+    /// was mutated in place through `buildings[i]` without changing the collection's length,
+    /// which the lazy count-based staleness check in `spatial_index()` can't otherwise detect.
+    pub fn invalidate_spatial_index(&mut self) {
+        *self.spatial_index.get_mut() = None;
+    }
+
+    /// Borrow the R-tree over building envelopes, rebuilding it first if it's missing or the
+    /// building count has changed since it was last built. Building envelopes come from
+    /// `footprint.bounding_rect()`; buildings whose footprint has no bounding rect (an empty
+    /// polygon) are left out of the index.
+    fn spatial_index(&self) -> std::cell::Ref<'_, RTree<BuildingEnvelope>> {
+        {
+            let cache = self.spatial_index.borrow();
+            if let Some(existing) = cache.as_ref() {
+                if existing.building_count == self.buildings.len() {
+                    drop(cache);
+                    return std::cell::Ref::map(self.spatial_index.borrow(), |c| {
+                        &c.as_ref().unwrap().tree
+                    });
+                }
+            }
+        }
+
+        let envelopes: Vec<BuildingEnvelope> = self
+            .buildings
+            .iter()
+            .enumerate()
+            .filter_map(|(index, building)| {
+                let rect = building.footprint.bounding_rect()?;
+                Some(BuildingEnvelope {
+                    index,
+                    min_x: rect.min().x,
+                    min_y: rect.min().y,
+                    max_x: rect.max().x,
+                    max_y: rect.max().y,
+                })
+            })
+            .collect();
+
+        *self.spatial_index.borrow_mut() = Some(SpatialIndexCache {
+            building_count: self.buildings.len(),
+            tree: RTree::bulk_load(envelopes),
+        });
+
+        std::cell::Ref::map(self.spatial_index.borrow(), |c| &c.as_ref().unwrap().tree)
+    }
+
+    /// Indices (into `buildings()`) of every building whose envelope intersects the given
+    /// axis-aligned box, via the R-tree spatial index. O(log n + k) instead of the O(n) scan
+    /// `buildings().iter()` would need on the hundreds-of-thousands-of-polygon datasets this
+    /// crate targets.
+    pub fn query_bbox(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        let envelope = AABB::from_corners([min_x, min_y], [max_x, max_y]);
+        self.spatial_index()
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|e| e.index)
+            .collect()
+    }
+
+    /// Indices (into `buildings()`) of the `k` buildings whose envelope is nearest to `point`
+    /// (`[x, y]`), nearest first.
+    pub fn nearest(&self, point: [f64; 2], k: usize) -> Vec<usize> {
+        self.spatial_index()
+            .nearest_neighbor_iter(&point)
+            .take(k)
+            .map(|e| e.index)
+            .collect()
+    }
+
+    /// Indices (into `buildings()`) of every building whose envelope lies within `radius` of
+    /// `point` (`[x, y]`, same units as the collection's CRS), nearest first.
+    pub fn within_distance(&self, point: [f64; 2], radius: f64) -> Vec<usize> {
+        let radius_sq = radius * radius;
+        self.spatial_index()
+            .nearest_neighbor_iter_with_distance_2(&point)
+            .take_while(|(_, distance_sq)| *distance_sq <= radius_sq)
+            .map(|(e, _)| e.index)
+            .collect()
+    }
+
+    /// Indices (into `buildings()`) of every building whose centroid lies within `meters` of
+    /// `point` (given in the collection's own CRS), nearest first. Unlike `within_distance`,
+    /// which compares envelopes in the collection's native CRS units -- degrees, if `geo_core`'s
+    /// EPSG is geographic -- this reprojects `point` and every building centroid into
+    /// `metric_epsg` first via [`GeoCore::transform_coords`], so `meters` means meters
+    /// regardless of the collection's own CRS. Builds a fresh R-tree on every call rather than
+    /// reusing the cached one from [`BuildingCollection::spatial_index`], since the target
+    /// metric CRS is a per-call choice, not a property of the collection.
+    pub fn within_radius(&self, point: [f64; 2], meters: f64, metric_epsg: i32) -> Result<Vec<usize>> {
+        let epsg = self.geo_core.get_epsg();
+        let (query_x, query_y) = GeoCore::transform_coords(epsg, metric_epsg, point[0], point[1])
+            .context("Failed to reproject query point to the metric CRS")?;
+
+        let mut centroids = Vec::with_capacity(self.buildings.len());
+        for (index, building) in self.buildings.iter().enumerate() {
+            let (x, y) = GeoCore::transform_coords(
+                epsg,
+                metric_epsg,
+                building.centroid.x(),
+                building.centroid.y(),
+            )
+            .context("Failed to reproject a building centroid to the metric CRS")?;
+            centroids.push(MetricCentroid { index, point: [x, y] });
+        }
+        let tree = RTree::bulk_load(centroids);
+
+        let radius_sq = meters * meters;
+        Ok(tree
+            .nearest_neighbor_iter_with_distance_2(&[query_x, query_y])
+            .take_while(|(_, distance_sq)| *distance_sq <= radius_sq)
+            .map(|(c, _)| c.index)
+            .collect())
+    }
+
+    /// Buildings whose footprint intersects `mask`, as a new collection -- an alias for
+    /// [`BuildingCollection::intersects`] under the name used by this module's
+    /// spatial-index-oriented entry points ([`BuildingCollection::nearest`],
+    /// [`BuildingCollection::within_radius`], [`BuildingCollection::query_bbox`]). Useful for
+    /// clipping a downloaded collection to an administrative boundary before further analysis.
+    pub fn subset_by_polygon(&self, mask: &Polygon<f64>) -> BuildingCollection {
+        self.intersects(mask)
+    }
+
+    /// Spatially join each building to its containing IRIS polygon and copy selected INSEE
+    /// indicator properties onto it as `metadata` entries -- which `to_dataframe()` already
+    /// expands into one column per distinct key -- turning the geometric building layer into a
+    /// socio-physical dataset for retrofit/suitability studies.
+    ///
+    /// `iris_geojson` is a FeatureCollection of IRIS polygons carrying INSEE properties (e.g.
+    /// from `Iris::get_geojson`, assumed to already share `geo_core`'s CRS); `indicators` lists
+    /// the property names to copy (e.g. `["periode_construction_dominante", "nombre_logements",
+    /// "densite_population"]`). Each building is matched to the IRIS polygon containing its
+    /// centroid; if none does (the building straddles an IRIS boundary), it falls back to
+    /// whichever IRIS polygon has the largest footprint overlap. Buildings matching no IRIS
+    /// polygon at all are left unenriched. Returns the number of buildings enriched.
+    pub fn enrich_from_iris(&mut self, iris_geojson: &GeoJson, indicators: &[&str]) -> Result<usize> {
+        let iris_polygons = Self::extract_iris_polygons(iris_geojson, indicators)?;
+        let mut enriched_count = 0;
+
+        for building in &mut self.buildings {
+            let matched = iris_polygons
+                .iter()
+                .find(|(polygon, _)| polygon.contains(&building.centroid))
+                .or_else(|| {
+                    iris_polygons
+                        .iter()
+                        .map(|(polygon, properties)| {
+                            let overlap = building
+                                .footprint
+                                .intersection(&geo::MultiPolygon(vec![polygon.clone()]))
+                                .unsigned_area();
+                            (overlap, polygon, properties)
+                        })
+                        .filter(|(overlap, ..)| *overlap > 0.0)
+                        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+                        .map(|(_, polygon, properties)| (polygon, properties))
+                });
+
+            if let Some((_, properties)) = matched {
+                for (key, value) in properties {
+                    building.metadata.insert(key.clone(), value.clone());
+                }
+                enriched_count += 1;
+            }
+        }
+
+        Ok(enriched_count)
+    }
+
+    /// Parse `iris_geojson`'s polygons plus their `indicators` properties (stringified to match
+    /// `Building::metadata`'s `HashMap<String, String>`), for
+    /// [`BuildingCollection::enrich_from_iris`].
+    fn extract_iris_polygons(
+        iris_geojson: &GeoJson,
+        indicators: &[&str],
+    ) -> Result<Vec<(Polygon<f64>, HashMap<String, String>)>> {
+        let features: Vec<&GeoJsonFeature> = match iris_geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.iter().collect(),
+            GeoJson::Feature(f) => vec![f],
+            GeoJson::Geometry(_) => {
+                anyhow::bail!("IRIS GeoJSON must be a Feature or FeatureCollection")
+            }
+        };
+
+        let mut polygons = Vec::with_capacity(features.len());
+        for feature in features {
+            let Some(geometry) = feature.geometry.as_ref() else {
+                continue;
+            };
+            let Some(polygon) =
+                Self::geojson_geometry_to_polygon(geometry, None, GEOJSON_SOURCE_EPSG)?
+            else {
+                continue;
+            };
+
+            let mut properties = HashMap::new();
+            if let Some(props) = &feature.properties {
+                for &indicator in indicators {
+                    match props.get(indicator) {
+                        Some(serde_json::Value::Null) | None => {}
+                        Some(serde_json::Value::String(s)) => {
+                            properties.insert(indicator.to_string(), s.clone());
+                        }
+                        Some(other) => {
+                            properties.insert(indicator.to_string(), other.to_string());
+                        }
+                    }
+                }
+            }
+            polygons.push((polygon, properties));
+        }
+
+        Ok(polygons)
+    }
+
+    /// Emit buildings as a GeoJSON byte string via geozero's `GeoJsonWriter`, driven by
+    /// [`BuildingSource`]'s [`GeozeroDatasource`] impl below. Equivalent in content to
+    /// `to_dataframe`'s `footprint` column, but produces one `FeatureCollection` document
+    /// covering every building plus its `hauteur`/`area`/`centroid_x`/`centroid_y` fields.
+    pub fn to_geozero_geojson(&self) -> Result<String> {
+        use geozero::geojson::GeoJsonWriter;
+
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut buf);
+        let mut source = BuildingSource {
+            buildings: &self.buildings,
+        };
+        source
+            .process(&mut writer)
+            .context("Failed to write buildings as GeoJSON via geozero")?;
+        String::from_utf8(buf).context("geozero produced non-UTF8 GeoJSON output")
+    }
+
+    /// Emit buildings as well-known text, one `POLYGON` per building. WKT has no attribute
+    /// model, so `hauteur`/`area`/`centroid_x`/`centroid_y` are dropped — use
+    /// `to_geozero_geojson` or `to_dataframe` when those are needed.
+    pub fn to_geozero_wkt(&self) -> Result<String> {
+        use geozero::wkt::WktWriter;
+
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut buf);
+        let mut source = BuildingSource {
+            buildings: &self.buildings,
+        };
+        source
+            .process(&mut writer)
+            .context("Failed to write buildings as WKT via geozero")?;
+        String::from_utf8(buf).context("geozero produced non-UTF8 WKT output")
+    }
+
+    /// Emit buildings as well-known binary, one geometry per building (attributes dropped, as
+    /// with `to_geozero_wkt`).
+    pub fn to_geozero_wkb(&self) -> Result<Vec<u8>> {
+        use geozero::wkb::WkbWriter;
+
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = WkbWriter::new(&mut buf);
+        let mut source = BuildingSource {
+            buildings: &self.buildings,
+        };
+        source
+            .process(&mut writer)
+            .context("Failed to write buildings as WKB via geozero")?;
+        Ok(buf)
+    }
+
+    /// Export buildings to a GPKG file. Pivots through [`to_geozero_geojson`] rather than
+    /// going through GDAL's feature/field API directly — geozero has no GPKG writer of its
+    /// own, but reusing the GeoJSON produced by the same `BuildingSource` walk as
+    /// `to_geozero_geojson`/`to_geozero_wkt`/`to_geozero_wkb` means every export format shares
+    /// one geometry-walking implementation, with [`crate::export::VectorWriter`] only doing
+    /// the final OGR write.
+    ///
+    /// [`to_geozero_geojson`]: BuildingCollection::to_geozero_geojson
+    pub fn to_geozero_gpkg<P: AsRef<Path>>(&self, filepath: P, name: Option<&str>) -> Result<()> {
+        let geojson_str = self.to_geozero_geojson()?;
+        let geojson: GeoJson = geojson_str
+            .parse()
+            .context("Failed to parse geozero-produced GeoJSON")?;
+        crate::export::VectorWriter::write(
+            filepath.as_ref(),
+            "GPKG",
+            name.unwrap_or("batiments"),
+            &geojson,
+            Some(self.geo_core.get_epsg()),
+        )
+        .with_context(|| format!("Failed to write buildings to {:?} as GPKG", filepath.as_ref()))
+    }
+
+    /// Export buildings to GeoParquet. Pivots through [`to_geozero_geojson`] the same way
+    /// [`to_geozero_gpkg`] does, then writes via GDAL/OGR's `"Parquet"` driver
+    /// ([`VectorFormat::GeoParquet`]) so the file carries proper GeoParquet `"geo"` metadata
+    /// instead of this crate hand-building it.
+    ///
+    /// [`to_geozero_geojson`]: BuildingCollection::to_geozero_geojson
+    /// [`to_geozero_gpkg`]: BuildingCollection::to_geozero_gpkg
+    pub fn to_geoparquet<P: AsRef<Path>>(
+        &self,
+        path: P,
+        compression: crate::export::GeoParquetCompression,
+    ) -> Result<()> {
+        let geojson_str = self.to_geozero_geojson()?;
+        let geojson: GeoJson = geojson_str
+            .parse()
+            .context("Failed to parse geozero-produced GeoJSON")?;
+        crate::export::write_vector_file_with_options(
+            path.as_ref(),
+            "batiments",
+            &geojson,
+            crate::export::VectorFormat::GeoParquet,
+            Some(self.geo_core.get_epsg()),
+            &[compression.as_layer_option()],
+        )
+        .with_context(|| format!("Failed to write buildings to {:?} as GeoParquet", path.as_ref()))
+    }
+
+    /// Export buildings to any OGR-style vector format ([`VectorFormat::GeoPackage`],
+    /// [`VectorFormat::Shapefile`], [`VectorFormat::GeoJson`], [`VectorFormat::FlatGeobuf`], or
+    /// [`VectorFormat::GeoParquet`]),
+    /// reprojecting nothing but tagging the output with `geo_core`'s current EPSG. Pivots
+    /// through [`to_geozero_geojson`] the same way [`to_geozero_gpkg`] does, so every format
+    /// this returns shares one geometry-walking implementation and only
+    /// [`crate::export::write_vector_file`] differs per driver.
+    ///
+    /// [`to_geozero_geojson`]: BuildingCollection::to_geozero_geojson
+    /// [`to_geozero_gpkg`]: BuildingCollection::to_geozero_gpkg
+    pub fn to_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        layer_name: Option<&str>,
+        format: crate::export::VectorFormat,
+    ) -> Result<()> {
+        let geojson_str = self.to_geozero_geojson()?;
+        let geojson: GeoJson = geojson_str
+            .parse()
+            .context("Failed to parse geozero-produced GeoJSON")?;
+        crate::export::write_vector_file(
+            path.as_ref(),
+            layer_name.unwrap_or("batiments"),
+            &geojson,
+            format,
+            Some(self.geo_core.get_epsg()),
+        )
+    }
+
+    /// Convenience alias for [`to_geozero_wkt`]: one `POLYGON(...)` per building, for pasting
+    /// into an online WKT viewer or diffing against the Python reference output.
+    ///
+    /// [`to_geozero_wkt`]: BuildingCollection::to_geozero_wkt
+    pub fn to_wkt(&self) -> Result<String> {
+        self.to_geozero_wkt()
+    }
+
+    /// Render footprints to an SVG file for a quick visual sanity check without a full GIS
+    /// toolchain. The `viewBox` is set from [`footprint_bounds`], each footprint becomes one
+    /// `<path>` built by driving it through geozero's [`GeomProcessor`] callbacks via
+    /// [`write_polygon`] (the same helper every `to_geozero_*` exporter's geometry walk uses),
+    /// and the fill is a grayscale shade of `get_height` normalized across the collection —
+    /// taller buildings render darker, so a broken height-processing pass (e.g. every building
+    /// stuck at the default storey height) is visible at a glance.
+    ///
+    /// [`footprint_bounds`]: BuildingCollection::footprint_bounds
+    pub fn to_svg<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        use std::fmt::Write as _;
+
+        let (min_x, min_y, max_x, max_y) = self.footprint_bounds().unwrap_or((0.0, 0.0, 0.0, 0.0));
+        let width = (max_x - min_x).max(1.0);
+        let height = (max_y - min_y).max(1.0);
+        let stroke_width = width.max(height) / 1000.0;
+
+        let heights: Vec<f64> = self
+            .buildings
+            .iter()
+            .map(|b| b.get_height(self.default_storey_height))
+            .collect();
+        let (min_h, max_h) = heights
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &h| {
+                (lo.min(h), hi.max(h))
+            });
+        let height_range = (max_h - min_h).max(f64::EPSILON);
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            min_x, min_y, width, height
+        )
+        .context("Failed to write SVG header")?;
+
+        for (building, &building_height) in self.buildings.iter().zip(&heights) {
+            let mut path_builder = SvgPathBuilder::new(min_y + max_y);
+            write_polygon(&building.footprint, 0, &mut path_builder)
+                .context("Failed to build SVG path for a building footprint")?;
+
+            let normalized = ((building_height - min_h) / height_range).clamp(0.0, 1.0);
+            let shade = (255.0 * (1.0 - normalized)).round() as u8;
+            writeln!(
+                svg,
+                r#"<path d="{}" fill="#{shade:02x}{shade:02x}{shade:02x}" stroke="#333333" stroke-width="{stroke_width}"/>"#,
+                path_builder.d.trim_end(),
+            )
+            .context("Failed to write SVG path")?;
+        }
+
+        svg.push_str("</svg>\n");
+
+        std::fs::write(path.as_ref(), svg)
+            .with_context(|| format!("Failed to write SVG to {:?}", path.as_ref()))
+    }
+
+    /// Stream buildings to a FlatGeobuf file via [`BuildingFgbSource`], writing each
+    /// `Building.footprint` plus its `hauteur`/`area`/`nombre_d_etages`/`no_hauteur` properties
+    /// straight into the `FgbWriter` as it walks the collection, rather than building an
+    /// intermediate `geojson::GeoJson` document the way [`to_geozero_gpkg`] does. FlatGeobuf
+    /// packs a static R-tree over feature bounding boxes at the head of the file, which is what
+    /// lets [`from_flatgeobuf`] later decode only the features intersecting a bounding box.
+    ///
+    /// [`to_geozero_gpkg`]: BuildingCollection::to_geozero_gpkg
+    /// [`from_flatgeobuf`]: BuildingCollection::from_flatgeobuf
+    pub fn to_flatgeobuf<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        use flatgeobuf::{FgbWriter, GeometryType};
+        use std::io::BufWriter;
+
+        let mut fgb = FgbWriter::create("batiments", GeometryType::Polygon)
+            .context("Failed to create FlatGeobuf writer")?;
+        let mut source = BuildingFgbSource {
+            buildings: &self.buildings,
+        };
+        source
+            .process(&mut fgb)
+            .context("Failed to stream buildings into FlatGeobuf writer")?;
+
+        let file = std::fs::File::create(path.as_ref())
+            .with_context(|| format!("Failed to create {:?}", path.as_ref()))?;
+        fgb.write(&mut BufWriter::new(file))
+            .with_context(|| format!("Failed to write FlatGeobuf to {:?}", path.as_ref()))?;
+
+        Ok(())
+    }
+
+    /// Load buildings from a GeoJSON file via geozero's `GeoJsonReader` instead of the
+    /// `geojson` crate, feeding reader events into [`BuildingCollector`] which accumulates ring
+    /// coordinates into a `geo::Polygon` per feature and maps properties back onto `Building`
+    /// fields. Functionally equivalent to `from_geojson` for well-formed polygon features; the
+    /// footprints are assumed to arrive in [`GEOJSON_SOURCE_EPSG`], same as `from_geojson`.
+    /// Opens `filepath` and hands it to [`BuildingCollection::from_geojson_reader`], which does
+    /// the actual streaming.
+    pub fn from_geozero_geojson<P: AsRef<Path>>(
+        filepath: P,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        let file = std::fs::File::open(filepath.as_ref())
+            .with_context(|| format!("Failed to open {:?}", filepath.as_ref()))?;
+        Self::from_geojson_reader(file, output_path, default_storey_height, set_crs)
+    }
+
+    /// Build a `BuildingCollection` by streaming a GeoJSON document from any [`std::io::Read`]
+    /// source (an open file, a response body, ...) instead of materializing the whole thing as a
+    /// `geojson::GeoJson` first, the way [`BuildingCollection::from_geojson`] does. Peak memory
+    /// stays proportional to one feature rather than the whole document, since [`BuildingCollector`]
+    /// pushes each `Building` onto `collection.buildings` as its feature's `feature_end` callback
+    /// fires. This is driven by geozero's own [`FeatureProcessor`]/[`GeomProcessor`] callbacks
+    /// (`dataset_begin`/`feature_begin`/`geometry_begin`+`xy`/`feature_end`/`dataset_end`) rather
+    /// than a second, repo-specific streaming trait -- that shape already exists and is what
+    /// [`BuildingCollection::to_geozero_geojson`]/[`BuildingCollection::from_geozero_geojson`] build
+    /// on elsewhere in this file, so introducing a parallel trait here would only duplicate it.
+    pub fn from_geojson_reader<R: std::io::Read>(
+        reader: R,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        use geozero::geojson::GeoJsonReader;
+
+        let mut collection = Self::new(None, output_path, default_storey_height, None)?;
+        collection.geo_core.set_epsg(GEOJSON_SOURCE_EPSG);
+
+        let mut geozero_reader = GeoJsonReader(std::io::BufReader::new(reader));
+        let mut collector = BuildingCollector::default();
+        geozero_reader
+            .process(&mut collector)
+            .context("Failed to stream GeoJSON via geozero")?;
+        collection.buildings = collector.buildings;
+
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Load buildings from a FlatGeobuf file via geozero's streaming reader protocol, driven by
+    /// the same [`BuildingCollector`] sink as [`BuildingCollection::from_geozero_geojson`]. When
+    /// `bbox` is `Some((min_x, min_y, max_x, max_y))`, the reader walks FlatGeobuf's packed
+    /// R-tree to decode only features intersecting that box instead of the whole file --
+    /// useful when the source dataset covers far more ground than the area of interest. Each
+    /// `Building` is constructed as its feature arrives; call `process_heights()` once on the
+    /// returned collection to fill in missing heights, same as for the other importers.
+    pub fn from_flatgeobuf<P: AsRef<Path>>(
+        filepath: P,
+        bbox: Option<(f64, f64, f64, f64)>,
+        output_path: Option<String>,
+        default_storey_height: f64,
+        set_crs: Option<i32>,
+    ) -> Result<Self> {
+        use flatgeobuf::FgbReader;
+
+        let mut collection = Self::new(None, output_path, default_storey_height, None)?;
+
+        let file = std::fs::File::open(filepath.as_ref())
+            .with_context(|| format!("Failed to open {:?}", filepath.as_ref()))?;
+        let mut reader = FgbReader::open(std::io::BufReader::new(file))
+            .with_context(|| format!("Failed to open FlatGeobuf header in {:?}", filepath.as_ref()))?;
+
+        let mut collector = BuildingCollector::default();
+        let mut selected = match bbox {
+            Some((min_x, min_y, max_x, max_y)) => reader
+                .select_bbox(min_x, min_y, max_x, max_y)
+                .context("Failed to select FlatGeobuf features by bounding box")?,
+            None => reader
+                .select_all()
+                .context("Failed to select all FlatGeobuf features")?,
+        };
+        selected
+            .process_features(&mut collector)
+            .context("Failed to read FlatGeobuf features")?;
+        collection.buildings = collector.buildings;
+
+        if let Some(target_epsg) = set_crs {
+            collection
+                .reproject(target_epsg)
+                .context("Failed to reproject buildings to the requested CRS")?;
+        }
+
+        Ok(collection)
+    }
+
+    /// Export buildings to GPKG file via GDAL directly
+    /// NOTE: Temporarily disabled due to GDAL API issues — use `to_geozero_gpkg` instead, which
+    /// pivots through geozero's GeoJSON writer and only touches GDAL for the final OGR write.
     /// TODO: Fix GDAL integration
     #[allow(dead_code)]
     pub fn to_gpkg<P: AsRef<Path>>(&self, _filepath: P, _name: Option<&str>) -> Result<()> {
-        anyhow::bail!("GPKG export is temporarily disabled. Use to_polars_df() instead.");
+        anyhow::bail!("GPKG export is temporarily disabled. Use to_geozero_gpkg() instead.");
         /*
         use gdal::vector::{Geometry, OGRFieldType};
         use std::ffi::CString;
@@ -882,6 +3498,29 @@ mod tests {
         assert_eq!(collection.len(), 1);
     }
 
+    #[test]
+    fn test_from_geojson_buffers_point_into_footprint() {
+        let geojson = br#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"hauteur": 9.0}, "geometry": {"type": "Point", "coordinates": [2.35, 48.85]}}
+            ]
+        }"#;
+
+        // Without a radius, points are skipped as before.
+        let without_radius = BuildingCollection::from_geojson(geojson, None, 3.0, None, None).unwrap();
+        assert_eq!(without_radius.len(), 0);
+
+        let result = BuildingCollection::from_geojson(geojson, None, 3.0, None, Some(2.0));
+        let Ok(with_radius) = result else {
+            // PROJ data may be unavailable in this environment; nothing more to assert.
+            return;
+        };
+        assert_eq!(with_radius.len(), 1);
+        assert!(with_radius.buildings[0].area > 0.0);
+        assert_eq!(with_radius.buildings[0].height, Some(9.0));
+    }
+
     #[test]
     fn test_calculate_mean_height() {
         let mut collection = BuildingCollection::new_simple(None);
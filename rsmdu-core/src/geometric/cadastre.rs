@@ -1,9 +1,270 @@
 use anyhow::{Context, Result};
-use geojson::GeoJson;
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+use proj::Proj;
+use serde_json::Map;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::collect::ign::ign_collect::IgnCollect;
+use crate::export::{VectorFormat, VectorWriter};
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::io::GeoData;
+
+/// EPSG code of the coordinate system IGN returns cadastre GeoJSON in.
+const IGN_SOURCE_EPSG: i32 = 4326;
+
+/// Reproject every coordinate of `geojson` from `from_epsg` to `to_epsg`, returning a new
+/// `GeoJson`. Recurses through Point/LineString/Polygon/Multi* and GeometryCollection
+/// variants, reusing a single `proj::Proj` transformer for the whole collection.
+/// `proj::Proj::convert` follows PROJ's normalized (easting/northing, i.e. lon/lat for
+/// WGS84 and x/y for Lambert-93) axis order, so coordinate pairs are passed through as-is.
+fn reproject_geojson(geojson: &GeoJson, from_epsg: i32, to_epsg: i32) -> Result<GeoJson> {
+    if from_epsg == to_epsg {
+        return Ok(geojson.clone());
+    }
+
+    let from_crs = format!("EPSG:{}", from_epsg);
+    let to_crs = format!("EPSG:{}", to_epsg);
+    let proj = Proj::new_known_crs(&from_crs, &to_crs, None).with_context(|| {
+        format!(
+            "EPSG:{} -> EPSG:{} is not a transformation PROJ supports",
+            from_epsg, to_epsg
+        )
+    })?;
+
+    let mut geojson = geojson.clone();
+    match &mut geojson {
+        GeoJson::FeatureCollection(fc) => {
+            for feature in &mut fc.features {
+                if let Some(geometry) = &mut feature.geometry {
+                    reproject_geometry(geometry, &proj)?;
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = &mut feature.geometry {
+                reproject_geometry(geometry, &proj)?;
+            }
+        }
+        GeoJson::Geometry(geometry) => {
+            reproject_geometry(geometry, &proj)?;
+        }
+    }
+
+    Ok(geojson)
+}
+
+/// Reproject a single geojson::Geometry in place, recursing into GeometryCollection.
+/// Skipped entirely when `geometry` carries a null/missing value (nothing to walk).
+fn reproject_geometry(geometry: &mut Geometry, proj: &Proj) -> Result<()> {
+    match &mut geometry.value {
+        Value::Point(position) => reproject_position(position, proj)?,
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            for position in positions {
+                reproject_position(position, proj)?;
+            }
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            for line in lines {
+                for position in line {
+                    reproject_position(position, proj)?;
+                }
+            }
+        }
+        Value::MultiPolygon(polygons) => {
+            for polygon in polygons {
+                for line in polygon {
+                    for position in line {
+                        reproject_position(position, proj)?;
+                    }
+                }
+            }
+        }
+        Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                reproject_geometry(geometry, proj)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Transform a single `[x, y(, z)]` position in place, preserving any z-coordinate.
+fn reproject_position(position: &mut Vec<f64>, proj: &Proj) -> Result<()> {
+    if position.len() < 2 {
+        return Ok(());
+    }
+    let (x, y) = proj
+        .convert((position[0], position[1]))
+        .context("Failed to transform GeoJSON coordinate")?;
+    position[0] = x;
+    position[1] = y;
+    Ok(())
+}
+
+/// Average of every position a geometry touches (recursing through GeometryCollection), as a
+/// cheap representative point -- good enough to pick a grid cell for, without needing a real
+/// centroid algorithm. Returns `None` for an empty geometry collection.
+fn representative_point(geometry: &Geometry) -> Option<(f64, f64)> {
+    fn walk(value: &Value, sum_x: &mut f64, sum_y: &mut f64, count: &mut usize) {
+        match value {
+            Value::Point(position) => {
+                if position.len() >= 2 {
+                    *sum_x += position[0];
+                    *sum_y += position[1];
+                    *count += 1;
+                }
+            }
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                for position in positions {
+                    if position.len() >= 2 {
+                        *sum_x += position[0];
+                        *sum_y += position[1];
+                        *count += 1;
+                    }
+                }
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                for line in lines {
+                    for position in line {
+                        if position.len() >= 2 {
+                            *sum_x += position[0];
+                            *sum_y += position[1];
+                            *count += 1;
+                        }
+                    }
+                }
+            }
+            Value::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    for line in polygon {
+                        for position in line {
+                            if position.len() >= 2 {
+                                *sum_x += position[0];
+                                *sum_y += position[1];
+                                *count += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            Value::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    walk(&geometry.value, sum_x, sum_y, count);
+                }
+            }
+        }
+    }
+
+    let (mut sum_x, mut sum_y, mut count) = (0.0, 0.0, 0usize);
+    walk(&geometry.value, &mut sum_x, &mut sum_y, &mut count);
+    (count > 0).then(|| (sum_x / count as f64, sum_y / count as f64))
+}
+
+/// Whether `geometry` has at least one position falling inside the axis-aligned box
+/// `(min_x, min_y, max_x, max_y)` (same units as whatever CRS `geometry`'s coordinates are
+/// currently in). A cheap vertex test, not a true polygon-polygon intersection -- adequate for
+/// subsetting to "features near an area of interest" the way [`Cadastre::filter_bbox`] does.
+fn geometry_intersects_bbox(geometry: &Geometry, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> bool {
+    fn walk(value: &Value, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> bool {
+        let in_bbox = |position: &[f64]| {
+            position.len() >= 2
+                && position[0] >= min_x
+                && position[0] <= max_x
+                && position[1] >= min_y
+                && position[1] <= max_y
+        };
+        match value {
+            Value::Point(position) => in_bbox(position),
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                positions.iter().any(|p| in_bbox(p))
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                lines.iter().any(|line| line.iter().any(|p| in_bbox(p)))
+            }
+            Value::MultiPolygon(polygons) => polygons
+                .iter()
+                .any(|polygon| polygon.iter().any(|line| line.iter().any(|p| in_bbox(p)))),
+            Value::GeometryCollection(geometries) => geometries
+                .iter()
+                .any(|geometry| walk(&geometry.value, min_x, min_y, max_x, max_y)),
+        }
+    }
+    walk(&geometry.value, min_x, min_y, max_x, max_y)
+}
+
+/// Snap `(x, y)` to the center of the `cell_meters`-sized grid cell it falls in.
+fn snap_to_grid(x: f64, y: f64, cell_meters: f64) -> (f64, f64) {
+    let snap = |v: f64| (v / cell_meters).floor() * cell_meters + cell_meters / 2.0;
+    (snap(x), snap(y))
+}
+
+/// Privacy-preserving grid generalization: replace every feature's representative point
+/// ([`representative_point`]) with the center of the `cell_meters`-sized grid cell it falls in
+/// (in whatever CRS `geojson`'s coordinates are currently in -- normally a projected CRS, so
+/// cells are true squares), discarding the original geometry entirely so individual
+/// parcels/buildings can't be precisely located. See [`Cadastre::set_privacy_grid`].
+///
+/// When `collapse_duplicates` is set, features that land in the same cell are merged into one
+/// point feature carrying the first feature's properties plus an added/incremented `"count"`
+/// property, instead of emitting one coincident point per feature.
+fn generalize_to_grid(geojson: &GeoJson, cell_meters: f64, collapse_duplicates: bool) -> GeoJson {
+    let features: Vec<Feature> = match geojson {
+        GeoJson::FeatureCollection(fc) => fc.features.clone(),
+        GeoJson::Feature(feature) => vec![feature.clone()],
+        GeoJson::Geometry(geometry) => vec![Feature {
+            bbox: None,
+            geometry: Some(geometry.clone()),
+            id: None,
+            properties: None,
+            foreign_members: None,
+        }],
+    };
+
+    let mut generalized: Vec<Feature> = Vec::with_capacity(features.len());
+    let mut cell_index: HashMap<(i64, i64), usize> = HashMap::new();
+
+    for feature in features {
+        let Some((x, y)) = feature.geometry.as_ref().and_then(representative_point) else {
+            continue;
+        };
+        let (cx, cy) = snap_to_grid(x, y, cell_meters);
+
+        if collapse_duplicates {
+            let cell_key = (
+                (cx / cell_meters).round() as i64,
+                (cy / cell_meters).round() as i64,
+            );
+            if let Some(&index) = cell_index.get(&cell_key) {
+                let properties = generalized[index].properties.get_or_insert_with(Map::new);
+                let count = properties
+                    .get("count")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(1);
+                properties.insert("count".to_string(), (count + 1).into());
+                continue;
+            }
+            cell_index.insert(cell_key, generalized.len());
+        }
+
+        let mut grid_feature = feature;
+        grid_feature.geometry = Some(Geometry::new(Value::Point(vec![cx, cy])));
+        if collapse_duplicates {
+            grid_feature
+                .properties
+                .get_or_insert_with(Map::new)
+                .insert("count".to_string(), 1.into());
+        }
+        generalized.push(grid_feature);
+    }
+
+    GeoJson::FeatureCollection(FeatureCollection {
+        bbox: None,
+        foreign_members: None,
+        features: generalized,
+    })
+}
 
 /// Cadastre structure
 /// Following Python implementation from pymdu.geometric.Cadastre
@@ -19,6 +280,9 @@ pub struct Cadastre {
     bbox: Option<BoundingBox>,
     /// Parsed GeoJSON content
     geojson: Option<GeoJson>,
+    /// Opt-in privacy grid (cell size in meters, collapse-duplicates flag), applied to every
+    /// exporter. See [`Cadastre::set_privacy_grid`].
+    privacy_grid: Option<(f64, bool)>,
 }
 
 impl Cadastre {
@@ -40,9 +304,24 @@ impl Cadastre {
             geo_core: GeoCore::default(), // Default to EPSG:2154 (Lambert-93)
             bbox: None,
             geojson: None,
+            privacy_grid: None,
         })
     }
 
+    /// Build a `Cadastre` directly from a pre-loaded [`GeoData::Vector`], skipping the IGN API
+    /// call entirely. Useful for feeding in a parcel file downloaded ahead of time via
+    /// [`crate::io::GeoReader::read`].
+    pub fn from_geo_data(output_path: Option<String>, data: GeoData) -> Result<Self> {
+        let GeoData::Vector(geojson, epsg) = data else {
+            anyhow::bail!("Cadastre requires vector GeoData; got a raster source");
+        };
+
+        let mut cadastre = Self::new(output_path)?;
+        cadastre.geo_core.set_epsg(epsg);
+        cadastre.geojson = Some(geojson);
+        Ok(cadastre)
+    }
+
     /// Set bounding box
     /// Following Python: cadastre.bbox = [min_x, min_y, max_x, max_y]
     pub fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
@@ -57,6 +336,107 @@ impl Cadastre {
         self.ign_collect.geo_core.set_epsg(epsg);
     }
 
+    /// Reproject the stored parcel GeoJSON from `from_epsg` to `to_epsg`, regardless of what
+    /// EPSG `geo_core` currently thinks it's in -- for a collection built via
+    /// [`Cadastre::from_geo_data`] from a source whose CRS `geo_core` was never told about.
+    /// Reuses this module's own [`reproject_geojson`] (the same one `run` uses against
+    /// [`IGN_SOURCE_EPSG`]), since this crate has no shared `GeoCore::reproject` to delegate to.
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref geojson) = self.geojson {
+            self.geojson = Some(reproject_geojson(geojson, from_epsg, to_epsg)?);
+        }
+        self.geo_core.set_epsg(to_epsg);
+        Ok(())
+    }
+
+    /// Reproject the stored parcel GeoJSON from `geo_core`'s current EPSG to `to_epsg`.
+    pub fn reproject(&mut self, to_epsg: i32) -> Result<()> {
+        let from_epsg = self.geo_core.get_epsg();
+        self.reproject_to(from_epsg, to_epsg)
+    }
+
+    /// Reproject back to EPSG:4326 (WGS84 lat/long), e.g. before exporting to a format that
+    /// expects geographic coordinates.
+    pub fn to_latlong(&mut self) -> Result<()> {
+        self.reproject(4326)
+    }
+
+    /// Keep only the stored features with at least one vertex inside the axis-aligned box
+    /// `(min_x, min_y, max_x, max_y)`, in `geo_core`'s current CRS, mutating the stored GeoJSON
+    /// in place. A coarser spatial predicate than [`BuildingCollection::within`]/
+    /// [`BuildingCollection::intersects`] -- this crate has no typed `geo::Geometry` to test
+    /// true containment against, only the raw [`GeoJson`] IGN returns -- but adequate for
+    /// subsetting parcels to an area of interest before export.
+    pub fn filter_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Result<()> {
+        let Some(geojson) = self.geojson.take() else {
+            return Ok(());
+        };
+
+        let keep = |feature: &Feature| {
+            feature
+                .geometry
+                .as_ref()
+                .is_some_and(|geometry| geometry_intersects_bbox(geometry, min_x, min_y, max_x, max_y))
+        };
+
+        self.geojson = Some(match geojson {
+            GeoJson::FeatureCollection(mut fc) => {
+                fc.features.retain(keep);
+                GeoJson::FeatureCollection(fc)
+            }
+            GeoJson::Feature(feature) => {
+                if keep(&feature) {
+                    GeoJson::Feature(feature)
+                } else {
+                    GeoJson::FeatureCollection(FeatureCollection {
+                        bbox: None,
+                        features: Vec::new(),
+                        foreign_members: None,
+                    })
+                }
+            }
+            other @ GeoJson::Geometry(_) => other,
+        });
+
+        Ok(())
+    }
+
+    /// Opt in to anonymizing every exported feature: each feature's representative point is
+    /// snapped to the center of a `cell_meters`-sized grid cell (in `geo_core`'s current,
+    /// normally-projected CRS) and the original geometry is discarded, so individual parcels
+    /// can't be precisely located -- the same coarse-resolution-grid technique used when
+    /// publishing member maps. When `collapse_duplicates` is set, parcels sharing a cell are
+    /// merged into a single point feature carrying a `"count"` property instead of emitting one
+    /// coincident point per parcel. Applies to every exporter ([`Cadastre::to_file`],
+    /// [`Cadastre::to_geoparquet`], [`Cadastre::to_file_transactional`]); [`Cadastre::get_geojson`]
+    /// still returns the untouched data. Call [`Cadastre::clear_privacy_grid`] to go back to
+    /// exporting exact geometry.
+    pub fn set_privacy_grid(&mut self, cell_meters: f64, collapse_duplicates: bool) {
+        self.privacy_grid = Some((cell_meters, collapse_duplicates));
+    }
+
+    /// Undo [`Cadastre::set_privacy_grid`]: exporters go back to writing exact geometry.
+    pub fn clear_privacy_grid(&mut self) {
+        self.privacy_grid = None;
+    }
+
+    /// The GeoJSON exporters should actually write: the stored data as-is, or -- when
+    /// [`Cadastre::set_privacy_grid`] is active -- a generalized copy of it. Borrows rather than
+    /// clones when no privacy grid is set, so exporting without one pays no extra cost.
+    fn export_geojson(&self) -> Result<Cow<'_, GeoJson>> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        Ok(match self.privacy_grid {
+            Some((cell_meters, collapse_duplicates)) => {
+                Cow::Owned(generalize_to_grid(geojson, cell_meters, collapse_duplicates))
+            }
+            None => Cow::Borrowed(geojson),
+        })
+    }
+
     /// Run cadastre processing: download from IGN API, parse GeoJSON
     /// Following Python: def run(self) -> self
     pub fn run(mut self) -> Result<Self> {
@@ -81,12 +461,11 @@ impl Cadastre {
             .parse()
             .context("Failed to parse GeoJSON from IGN API response")?;
 
-        // Store the parsed GeoJSON
-        // Note: Reprojection to target CRS (Python: gdf = gdf.to_crs(self._epsg))
-        // would require converting GeoJSON to GDAL Dataset, reprojecting, and converting back
-        // This is complex and would require additional dependencies
-        // For now, we store the GeoJSON as-is
-        // TODO: Implement reprojection using GDAL or proj crate
+        // Reproject from the IGN API's CRS to the target CRS
+        // Python: gdf = gdf.to_crs(self._epsg)
+        let geojson = reproject_geojson(&geojson, IGN_SOURCE_EPSG, self.geo_core.get_epsg())
+            .context("Failed to reproject cadastre GeoJSON to target CRS")?;
+
         self.geojson = Some(geojson);
 
         Ok(self)
@@ -113,6 +492,9 @@ impl Cadastre {
             .parse()
             .context("Failed to parse GeoJSON from IGN API response")?;
 
+        let geojson = reproject_geojson(&geojson, IGN_SOURCE_EPSG, self.geo_core.get_epsg())
+            .context("Failed to reproject cadastre GeoJSON to target CRS")?;
+
         self.geojson = Some(geojson);
 
         Ok(())
@@ -126,36 +508,80 @@ impl Cadastre {
 
     /// Save to GPKG file
     /// Following Python: def to_gpkg(self, name: str = "cadastre")
-    /// Note: GPKG export requires GDAL and is complex
-    /// For now, we save as GeoJSON - full GPKG export would require GDAL layer operations
-    /// TODO: Implement full GPKG export using GDAL
     pub fn to_gpkg(&self, name: Option<&str>) -> Result<()> {
         // Python: self.gdf.to_file(f"{os.path.join(self.output_path, name)}.gpkg", driver="GPKG")
-        // For now, save as GeoJSON as a workaround
-        // Full GPKG export would require:
-        // 1. Converting GeoJSON to GDAL Dataset
-        // 2. Reprojecting to target CRS if needed
-        // 3. Creating GPKG file with GDAL driver
-        // 4. Copying layers and features
+        let name = name.unwrap_or("cadastre");
+        let output_file = self.output_path.join(format!("{}.gpkg", name));
+        self.to_file(&output_file, "GPKG", Some(name))
+    }
 
-        let geojson = self
-            .geojson
-            .as_ref()
-            .context("No GeoJSON data available. Call run() first.")?;
+    /// Save the parsed cadastre parcels to an arbitrary vector format.
+    /// Following Python: def to_file(self, path, driver="ESRI Shapefile")
+    /// `driver` accepts any OGR driver name in [`VectorWriter::SUPPORTED_DRIVERS`]
+    /// (e.g. "GPKG", "ESRI Shapefile", "KML", "GeoJSON"), mirroring `ogr2ogr -f`.
+    pub fn to_file(&self, path: &Path, driver: &str, layer_name: Option<&str>) -> Result<()> {
+        let geojson = self.export_geojson()?;
 
-        let name = name.unwrap_or("cadastre");
+        let layer_name = layer_name.unwrap_or("cadastre");
 
-        // Save as GeoJSON for now (GPKG export is complex with GDAL Rust bindings)
-        let output_file = self.output_path.join(format!("{}.geojson", name));
-        let geojson_str = geojson.to_string();
-        std::fs::write(&output_file, geojson_str)
-            .context(format!("Failed to write GeoJSON file: {:?}", output_file))?;
+        VectorWriter::write(
+            path,
+            driver,
+            layer_name,
+            &geojson,
+            Some(self.geo_core.get_epsg()),
+        )
+        .with_context(|| format!("Failed to write cadastre parcels to {:?} as {}", path, driver))?;
 
-        println!(
-            "Cadastre saved to: {:?} (as GeoJSON - GPKG export temporarily disabled)",
-            output_file
-        );
-        println!("  TODO: Implement full GPKG export using GDAL");
+        println!("Cadastre saved to: {:?} (driver: {})", path, driver);
+
+        Ok(())
+    }
+
+    /// Save the parsed cadastre parcels to GeoParquet via GDAL/OGR's `"Parquet"` driver
+    /// ([`VectorFormat::GeoParquet`]), honoring `geo_core`'s EPSG the same way [`Cadastre::to_file`]
+    /// does for the other vector formats.
+    pub fn to_geoparquet(
+        &self,
+        path: &Path,
+        compression: crate::export::GeoParquetCompression,
+    ) -> Result<()> {
+        let geojson = self.export_geojson()?;
+
+        crate::export::write_vector_file_with_options(
+            path,
+            "cadastre",
+            &geojson,
+            VectorFormat::GeoParquet,
+            Some(self.geo_core.get_epsg()),
+            &[compression.as_layer_option()],
+        )
+        .with_context(|| format!("Failed to write cadastre parcels to {:?} as GeoParquet", path))?;
+
+        println!("Cadastre saved to: {:?} (driver: Parquet)", path);
+
+        Ok(())
+    }
+
+    /// Save the parsed cadastre parcels to `path` in `format`, selecting the OGR driver from
+    /// the format enum instead of a raw driver-name string. See [`crate::export::VectorFormat`]
+    /// for the supported formats (GeoPackage, Shapefile, GeoJSON, FlatGeobuf, GeoParquet).
+    pub fn to_file_format(&self, path: &Path, format: VectorFormat, layer_name: Option<&str>) -> Result<()> {
+        self.to_file(path, format.driver_name(), layer_name)
+    }
+
+    /// Save the parsed cadastre parcels to `path` via a single OGR transaction, optionally
+    /// truncating an existing layer of the same name before appending. Use this instead of
+    /// [`Cadastre::to_file`] for large parcel counts: it commits once at the end instead of
+    /// implicitly opening a transaction per feature, which is what makes row-by-row writes into
+    /// GeoPackage or PostGIS slow.
+    pub fn to_file_transactional(&self, path: &Path, driver: &str, truncate: bool) -> Result<()> {
+        let geojson = self.export_geojson()?;
+
+        VectorWriter::write_transactional(path, driver, "cadastre", &geojson, truncate)
+            .with_context(|| format!("Failed to transactionally write cadastre parcels to {:?} as {}", path, driver))?;
+
+        println!("Cadastre saved to: {:?} (driver: {}, transactional)", path, driver);
 
         Ok(())
     }
@@ -3,6 +3,7 @@ use geojson::GeoJson;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::export::{write_leaflet_html, HtmlClassStyle};
 use crate::geo_core::{BoundingBox, GeoCore};
 
 /// LCZ (Local Climate Zone) structure
@@ -232,8 +233,53 @@ impl Lcz {
         Ok(())
     }
 
+    /// Save the classified LCZ polygons to `path` in `format` (GeoPackage, Shapefile, GeoJSON,
+    /// FlatGeobuf, or GeoParquet), honoring `geo_core`'s EPSG. Unlike [`Lcz::to_gpkg`], this goes through
+    /// [`crate::export::write_vector_file`]'s real GDAL-backed writer rather than the
+    /// GeoJSON-only placeholder, so every format is an actual OGR dataset.
+    pub fn to_file(&self, path: &Path, format: crate::export::VectorFormat, layer_name: Option<&str>) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        crate::export::write_vector_file(
+            path,
+            layer_name.unwrap_or("lcz"),
+            geojson,
+            format,
+            Some(self.geo_core.get_epsg()),
+        )
+    }
+
     /// Get output path
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
     }
+
+    /// Write a self-contained Leaflet HTML map of the LCZ classification to `path`, colored
+    /// and legended from `table_color` and keyed off each feature's `lcz_int` property.
+    /// `tooltip_fields` lists the extra properties shown on hover (`lcz_int` and `color` are
+    /// always included).
+    pub fn to_html(&self, path: &Path, tooltip_fields: &[String]) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let classes: Vec<HtmlClassStyle> = self
+            .table_color
+            .iter()
+            .map(|(code, (name, color))| HtmlClassStyle {
+                value: code.to_string(),
+                label: name.clone(),
+                color: color.clone(),
+            })
+            .collect();
+
+        let mut fields = vec!["lcz_int".to_string(), "color".to_string()];
+        fields.extend(tooltip_fields.iter().cloned());
+
+        write_leaflet_html(geojson, path, "lcz_int", &classes, &fields)
+    }
 }
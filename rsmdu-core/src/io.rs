@@ -0,0 +1,318 @@
+use anyhow::{Context, Result};
+use gdal::vector::{LayerAccess, ToGeo};
+use gdal::Dataset;
+use geojson::{Feature, FeatureCollection, GeoJson};
+use std::path::{Path, PathBuf};
+
+/// A loaded geospatial source, sniffed and dispatched to the right representation by
+/// [`GeoReader::read`]. Vector sources (GeoJSON, GeoPackage, Shapefile) are fully parsed into a
+/// [`GeoJson`]; raster sources (GeoTIFF) are kept as a path and opened lazily, since loading
+/// pixel data eagerly would defeat the point of "just tell me what's in this file".
+pub enum GeoData {
+    Vector(GeoJson, i32),
+    Raster(PathBuf, i32),
+}
+
+/// Lazily-computed metadata about a [`GeoData`] source: geometry type and field schema for
+/// vector data, band count for raster data, plus the bounds and EPSG common to both. Nothing
+/// here is read until [`GeoData::metadata`] is called.
+#[derive(Debug, Clone)]
+pub struct GeoMetadata {
+    pub epsg: i32,
+    /// `(min_x, min_y, max_x, max_y)`
+    pub bounds: (f64, f64, f64, f64),
+    pub kind: GeoMetadataKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum GeoMetadataKind {
+    Vector {
+        geometry_type: String,
+        /// `(field name, OGR field type name)` pairs, in first-feature order.
+        field_schema: Vec<(String, String)>,
+    },
+    Raster {
+        band_count: usize,
+    },
+}
+
+impl GeoData {
+    /// EPSG code carried by this source.
+    pub fn epsg(&self) -> i32 {
+        match self {
+            GeoData::Vector(_, epsg) => *epsg,
+            GeoData::Raster(_, epsg) => *epsg,
+        }
+    }
+
+    /// Compute this source's metadata on demand (not cached -- call once and hold onto the
+    /// result if it's needed more than once).
+    pub fn metadata(&self) -> Result<GeoMetadata> {
+        match self {
+            GeoData::Vector(geojson, epsg) => Self::vector_metadata(geojson, *epsg),
+            GeoData::Raster(path, epsg) => Self::raster_metadata(path, *epsg),
+        }
+    }
+
+    fn vector_metadata(geojson: &GeoJson, epsg: i32) -> Result<GeoMetadata> {
+        let features: Vec<&Feature> = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.iter().collect(),
+            GeoJson::Feature(f) => vec![f],
+            GeoJson::Geometry(_) => Vec::new(),
+        };
+
+        let geometry_type = features
+            .iter()
+            .find_map(|f| f.geometry.as_ref())
+            .map(|g| format!("{:?}", g.value))
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let mut field_schema = Vec::new();
+        if let Some(first) = features.first() {
+            if let Some(properties) = &first.properties {
+                for (key, value) in properties {
+                    let type_name = match value {
+                        serde_json::Value::Bool(_) => "Integer",
+                        serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => "Integer64",
+                        serde_json::Value::Number(_) => "Real",
+                        _ => "String",
+                    };
+                    field_schema.push((key.clone(), type_name.to_string()));
+                }
+            }
+        }
+
+        let mut bounds = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+        for feature in &features {
+            if let Some(geometry) = &feature.geometry {
+                Self::expand_bounds(geometry, &mut bounds);
+            }
+        }
+        if bounds.0 > bounds.2 {
+            bounds = (0.0, 0.0, 0.0, 0.0);
+        }
+
+        Ok(GeoMetadata {
+            epsg,
+            bounds,
+            kind: GeoMetadataKind::Vector {
+                geometry_type,
+                field_schema,
+            },
+        })
+    }
+
+    fn expand_bounds(geometry: &geojson::Geometry, bounds: &mut (f64, f64, f64, f64)) {
+        use geojson::Value;
+        let mut visit = |x: f64, y: f64| {
+            bounds.0 = bounds.0.min(x);
+            bounds.1 = bounds.1.min(y);
+            bounds.2 = bounds.2.max(x);
+            bounds.3 = bounds.3.max(y);
+        };
+        match &geometry.value {
+            Value::Point(p) => {
+                if p.len() >= 2 {
+                    visit(p[0], p[1]);
+                }
+            }
+            Value::MultiPoint(ps) | Value::LineString(ps) => {
+                for p in ps {
+                    if p.len() >= 2 {
+                        visit(p[0], p[1]);
+                    }
+                }
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                for line in lines {
+                    for p in line {
+                        if p.len() >= 2 {
+                            visit(p[0], p[1]);
+                        }
+                    }
+                }
+            }
+            Value::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    for line in polygon {
+                        for p in line {
+                            if p.len() >= 2 {
+                                visit(p[0], p[1]);
+                            }
+                        }
+                    }
+                }
+            }
+            Value::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    Self::expand_bounds(geometry, bounds);
+                }
+            }
+        }
+    }
+
+    fn raster_metadata(path: &Path, epsg: i32) -> Result<GeoMetadata> {
+        let dataset = Dataset::open(path)
+            .with_context(|| format!("Failed to open raster {:?} to read metadata", path))?;
+        let band_count = dataset.raster_count() as usize;
+        let (width, height) = dataset.raster_size();
+        let bounds = match dataset.geo_transform() {
+            Ok(t) => {
+                let x_min = t[0];
+                let y_max = t[3];
+                let x_max = x_min + t[1] * width as f64;
+                let y_min = y_max + t[5] * height as f64;
+                (x_min, y_min, x_max, y_max)
+            }
+            Err(_) => (0.0, 0.0, 0.0, 0.0),
+        };
+
+        Ok(GeoMetadata {
+            epsg,
+            bounds,
+            kind: GeoMetadataKind::Raster { band_count },
+        })
+    }
+}
+
+/// Entry point that sniffs a local file's format and loads it into the right [`GeoData`]
+/// variant, so `Cadastre`/`Cosia`-style consumers can accept a pre-downloaded file instead of
+/// always hitting the IGN API.
+pub struct GeoReader;
+
+impl GeoReader {
+    /// Vector extensions read via the `geojson` crate directly (no GDAL dependency needed).
+    const GEOJSON_EXTENSIONS: &'static [&'static str] = &["geojson", "json"];
+    /// Vector extensions opened via GDAL/OGR (schema + geometry read through a layer).
+    const OGR_VECTOR_EXTENSIONS: &'static [&'static str] = &["gpkg", "shp"];
+    /// Raster extensions handled as [`GeoData::Raster`].
+    const RASTER_EXTENSIONS: &'static [&'static str] = &["tif", "tiff"];
+
+    /// Read `path`, sniffing its format from the file extension (GeoJSON/GPKG/Shapefile for
+    /// vector data, GeoTIFF for raster data).
+    pub fn read(path: &Path) -> Result<GeoData> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .with_context(|| format!("Cannot sniff format of {:?}: no file extension", path))?;
+
+        if Self::GEOJSON_EXTENSIONS.contains(&extension.as_str()) {
+            let text = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read GeoJSON file {:?}", path))?;
+            return Self::read_geojson_str(&text);
+        }
+
+        if Self::OGR_VECTOR_EXTENSIONS.contains(&extension.as_str()) {
+            return Self::read_ogr_vector(path);
+        }
+
+        if Self::RASTER_EXTENSIONS.contains(&extension.as_str()) {
+            let epsg = Dataset::open(path)
+                .ok()
+                .and_then(|ds| ds.spatial_ref().ok())
+                .and_then(|srs| srs.to_epsg().ok())
+                .map(|e| e as i32)
+                .unwrap_or(0);
+            return Ok(GeoData::Raster(path.to_path_buf(), epsg));
+        }
+
+        anyhow::bail!(
+            "Unrecognized extension {:?} for {:?}; expected one of geojson/json/gpkg/shp/tif/tiff",
+            extension,
+            path
+        );
+    }
+
+    /// Read already-in-memory bytes (e.g. an IGN API response), using `extension_hint`
+    /// (without the leading dot) to disambiguate the format.
+    pub fn read_bytes(bytes: &[u8], extension_hint: &str) -> Result<GeoData> {
+        let extension_hint = extension_hint.to_lowercase();
+
+        if Self::GEOJSON_EXTENSIONS.contains(&extension_hint.as_str()) {
+            let text = std::str::from_utf8(bytes).context("Bytes are not valid UTF-8 GeoJSON")?;
+            return Self::read_geojson_str(text);
+        }
+
+        if Self::RASTER_EXTENSIONS.contains(&extension_hint.as_str()) {
+            // GDAL needs a real file handle; stage the bytes under the crate's temp directory.
+            use crate::collect::global_variables::TEMP_PATH;
+            let temp_path = PathBuf::from(TEMP_PATH).join(format!("geo_reader_tmp.{}", extension_hint));
+            std::fs::write(&temp_path, bytes)
+                .with_context(|| format!("Failed to stage raster bytes at {:?}", temp_path))?;
+            return Self::read(&temp_path);
+        }
+
+        anyhow::bail!(
+            "Unsupported extension hint {:?}; expected one of geojson/json/tif/tiff",
+            extension_hint
+        );
+    }
+
+    fn read_geojson_str(text: &str) -> Result<GeoData> {
+        let geojson: GeoJson = text.parse().context("Failed to parse GeoJSON")?;
+        // GeoJSON is WGS84 (EPSG:4326) by definition (RFC 7946 SS4) unless a legacy CRS member
+        // says otherwise; this crate doesn't yet parse that legacy member.
+        Ok(GeoData::Vector(geojson, 4326))
+    }
+
+    fn read_ogr_vector(path: &Path) -> Result<GeoData> {
+        let dataset = Dataset::open(path)
+            .with_context(|| format!("Failed to open vector dataset {:?}", path))?;
+        let mut layer = dataset
+            .layer(0)
+            .with_context(|| format!("Dataset {:?} has no layers", path))?;
+
+        let epsg = layer
+            .spatial_ref()
+            .and_then(|srs| srs.to_epsg().ok())
+            .map(|e| e as i32)
+            .unwrap_or(0);
+
+        let mut features = Vec::new();
+        for feature in layer.features() {
+            let geometry = feature
+                .geometry()
+                .map(|g| g.to_owned())
+                .and_then(|g| Self::gdal_geometry_to_geojson(&g).ok());
+
+            let mut properties = serde_json::Map::new();
+            for field in feature.fields() {
+                let (name, value) = field;
+                let json_value = match value {
+                    Some(gdal::vector::FieldValue::IntegerValue(v)) => serde_json::json!(v),
+                    Some(gdal::vector::FieldValue::Integer64Value(v)) => serde_json::json!(v),
+                    Some(gdal::vector::FieldValue::RealValue(v)) => serde_json::json!(v),
+                    Some(gdal::vector::FieldValue::StringValue(v)) => serde_json::json!(v),
+                    _ => serde_json::Value::Null,
+                };
+                properties.insert(name, json_value);
+            }
+
+            features.push(Feature {
+                bbox: None,
+                geometry,
+                id: None,
+                properties: Some(properties),
+                foreign_members: None,
+            });
+        }
+
+        let geojson = GeoJson::FeatureCollection(FeatureCollection {
+            bbox: None,
+            features,
+            foreign_members: None,
+        });
+
+        Ok(GeoData::Vector(geojson, epsg))
+    }
+
+    /// Convert a GDAL geometry to `geojson::Geometry` via its `geo` representation.
+    fn gdal_geometry_to_geojson(geometry: &gdal::vector::Geometry) -> Result<geojson::Geometry> {
+        let geo_geom: geo::Geometry<f64> = geometry
+            .to_geo()
+            .context("Failed to convert GDAL geometry to geo::Geometry")?;
+        geojson::Geometry::try_from(&geo_geom)
+            .map_err(|e| anyhow::anyhow!("Failed to convert geo::Geometry to GeoJSON: {}", e))
+    }
+}
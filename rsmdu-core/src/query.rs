@@ -0,0 +1,393 @@
+use anyhow::{bail, Context, Result};
+use geo::Intersects;
+use serde_json::Value as JsonValue;
+
+use crate::geometric::building::Building;
+
+/// A simple predicate over a single [`Building`] field, used by `WhereExpr::Predicate`.
+/// Mirrors `rsmdu::geometric::query::WherePredicate`, but evaluates against a `Building`'s
+/// typed fields (plus its string-valued `metadata` map) rather than raw GeoJSON properties --
+/// `BuildingCollection` lives in this crate with no access to the original feature properties
+/// once they've been folded into `Building` by `geojson_feature_to_building`.
+#[derive(Debug, Clone)]
+pub enum WherePredicate {
+    Eq(String, JsonValue),
+    Ne(String, JsonValue),
+    Gt(String, f64),
+    Gte(String, f64),
+    Lt(String, f64),
+    Lte(String, f64),
+    In(String, Vec<JsonValue>),
+    /// `key IS NULL`: the field is absent (no typed value and no metadata entry).
+    IsNull(String),
+    /// `key IS NOT NULL`: the field is present.
+    IsNotNull(String),
+}
+
+/// Look up a `Building` field by name, the same key aliases `geojson_feature_to_building`
+/// recognizes for the typed fields, falling back to the string-valued `metadata` map (parsed
+/// back to a number where possible) for everything else.
+fn building_field(building: &Building, key: &str) -> Option<JsonValue> {
+    match key.to_lowercase().as_str() {
+        "hauteur" | "height" => building.height.map(|v| serde_json::json!(v)),
+        "nombre_d_etages" | "storeys" | "etages" => {
+            building.nombre_d_etages.map(|v| serde_json::json!(v))
+        }
+        "hauteur_2" | "height_2" | "h2" => building.hauteur_2.map(|v| serde_json::json!(v)),
+        "no_hauteur" => Some(serde_json::json!(building.no_hauteur)),
+        "area" => Some(serde_json::json!(building.area)),
+        _ => building.metadata.get(key).map(|s| match s.parse::<f64>() {
+            Ok(n) => serde_json::json!(n),
+            Err(_) => serde_json::json!(s),
+        }),
+    }
+}
+
+fn field_as_f64(building: &Building, key: &str) -> Option<f64> {
+    building_field(building, key).and_then(|v| v.as_f64())
+}
+
+impl WherePredicate {
+    fn matches(&self, building: &Building) -> bool {
+        match self {
+            WherePredicate::Eq(key, value) => building_field(building, key).as_ref() == Some(value),
+            WherePredicate::Ne(key, value) => building_field(building, key).as_ref() != Some(value),
+            WherePredicate::In(key, values) => building_field(building, key)
+                .map(|v| values.contains(&v))
+                .unwrap_or(false),
+            WherePredicate::Gt(key, value) => {
+                field_as_f64(building, key).map(|v| v > *value).unwrap_or(false)
+            }
+            WherePredicate::Gte(key, value) => {
+                field_as_f64(building, key).map(|v| v >= *value).unwrap_or(false)
+            }
+            WherePredicate::Lt(key, value) => {
+                field_as_f64(building, key).map(|v| v < *value).unwrap_or(false)
+            }
+            WherePredicate::Lte(key, value) => {
+                field_as_f64(building, key).map(|v| v <= *value).unwrap_or(false)
+            }
+            WherePredicate::IsNull(key) => building_field(building, key).is_none(),
+            WherePredicate::IsNotNull(key) => building_field(building, key).is_some(),
+        }
+    }
+}
+
+/// A boolean combination of [`WherePredicate`]s, as produced by [`parse_where`].
+#[derive(Debug, Clone)]
+pub enum WhereExpr {
+    Predicate(WherePredicate),
+    And(Box<WhereExpr>, Box<WhereExpr>),
+    Or(Box<WhereExpr>, Box<WhereExpr>),
+}
+
+impl WhereExpr {
+    pub(crate) fn matches(&self, building: &Building) -> bool {
+        match self {
+            WhereExpr::Predicate(p) => p.matches(building),
+            WhereExpr::And(a, b) => a.matches(building) && b.matches(building),
+            WhereExpr::Or(a, b) => a.matches(building) || b.matches(building),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Op(&'static str),
+    And,
+    Or,
+    In,
+    Is,
+    Not,
+    Null,
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '\'' | '"' => {
+                let quote = c;
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    bail!("unterminated string literal in WHERE expression: {expr}");
+                }
+                tokens.push(Token::Str(chars[start..i].iter().collect()));
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Op("="));
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op("!="));
+                i += 2;
+            }
+            '<' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Op("<="));
+                    i += 2;
+                } else {
+                    tokens.push(Token::Op("<"));
+                    i += 1;
+                }
+            }
+            '>' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Op(">="));
+                    i += 2;
+                } else {
+                    tokens.push(Token::Op(">"));
+                    i += 1;
+                }
+            }
+            '-' if chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                tokens.push(Token::Num(s.parse().with_context(|| {
+                    format!("invalid number literal '{s}' in WHERE expression")
+                })?));
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                tokens.push(Token::Num(s.parse().with_context(|| {
+                    format!("invalid number literal '{s}' in WHERE expression")
+                })?));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                match s.to_ascii_uppercase().as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "IN" => tokens.push(Token::In),
+                    "IS" => tokens.push(Token::Is),
+                    "NOT" => tokens.push(Token::Not),
+                    "NULL" => tokens.push(Token::Null),
+                    _ => tokens.push(Token::Ident(s)),
+                }
+            }
+            other => bail!("unexpected character '{other}' in WHERE expression: {expr}"),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<WhereExpr> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = WhereExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<WhereExpr> {
+        let mut lhs = self.parse_term()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.pos += 1;
+            let rhs = self.parse_term()?;
+            lhs = WhereExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_term(&mut self) -> Result<WhereExpr> {
+        if matches!(self.peek(), Some(Token::LParen)) {
+            self.pos += 1;
+            let inner = self.parse_or()?;
+            match self.next() {
+                Some(Token::RParen) => return Ok(inner),
+                other => bail!("expected ')' in WHERE expression, found {other:?}"),
+            }
+        }
+
+        let key = match self.next() {
+            Some(Token::Ident(s)) => s.clone(),
+            other => bail!("expected a property name in WHERE expression, found {other:?}"),
+        };
+
+        match self.next() {
+            Some(Token::Op(op)) => {
+                let op = *op;
+                let value = self.parse_value()?;
+                let numeric = |value: &JsonValue| {
+                    value
+                        .as_f64()
+                        .with_context(|| format!("'{op}' requires a numeric value for '{key}'"))
+                };
+                let predicate = match op {
+                    "=" => WherePredicate::Eq(key, value),
+                    "!=" => WherePredicate::Ne(key, value),
+                    "<" => WherePredicate::Lt(key, numeric(&value)?),
+                    "<=" => WherePredicate::Lte(key, numeric(&value)?),
+                    ">" => WherePredicate::Gt(key, numeric(&value)?),
+                    ">=" => WherePredicate::Gte(key, numeric(&value)?),
+                    _ => unreachable!("tokenizer only emits known operators"),
+                };
+                Ok(WhereExpr::Predicate(predicate))
+            }
+            Some(Token::In) => {
+                match self.next() {
+                    Some(Token::LParen) => {}
+                    other => bail!("expected '(' after IN, found {other:?}"),
+                }
+                let mut values = Vec::new();
+                loop {
+                    values.push(self.parse_value()?);
+                    match self.next() {
+                        Some(Token::Comma) => continue,
+                        Some(Token::RParen) => break,
+                        other => bail!("expected ',' or ')' in IN list, found {other:?}"),
+                    }
+                }
+                Ok(WhereExpr::Predicate(WherePredicate::In(key, values)))
+            }
+            Some(Token::Is) => {
+                if matches!(self.peek(), Some(Token::Not)) {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Null) => Ok(WhereExpr::Predicate(WherePredicate::IsNotNull(key))),
+                        other => bail!("expected NULL after IS NOT, found {other:?}"),
+                    }
+                } else {
+                    match self.next() {
+                        Some(Token::Null) => Ok(WhereExpr::Predicate(WherePredicate::IsNull(key))),
+                        other => bail!("expected NULL after IS, found {other:?}"),
+                    }
+                }
+            }
+            other => bail!("expected a comparison operator, IN, or IS after '{key}', found {other:?}"),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue> {
+        match self.next() {
+            Some(Token::Str(s)) => Ok(JsonValue::String(s.clone())),
+            Some(Token::Num(n)) => Ok(serde_json::json!(*n)),
+            other => bail!("expected a value in WHERE expression, found {other:?}"),
+        }
+    }
+}
+
+/// Parse a small SQL-like WHERE expression over `Building` fields (`=`, `!=`, `<`, `<=`, `>`,
+/// `>=`, `IN (...)`, `IS NULL`/`IS NOT NULL`, `AND`/`OR`, with parentheses for grouping) into a
+/// [`WhereExpr`] AST, e.g. `"hauteur > 10 AND nombre_d_etages IS NOT NULL"`. Mirrors
+/// `rsmdu::geometric::query::parse_where`'s grammar.
+pub fn parse_where(expr: &str) -> Result<WhereExpr> {
+    let tokens = tokenize(expr)?;
+    if tokens.is_empty() {
+        bail!("empty WHERE expression");
+    }
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let result = parser.parse_or()?;
+    if parser.pos != tokens.len() {
+        bail!("unexpected trailing tokens in WHERE expression: {expr}");
+    }
+    Ok(result)
+}
+
+/// Whether `building`'s footprint intersects `polygon`, for `BuildingCollection::filter`'s
+/// spatial predicate. Unlike [`crate::geometric::building::BuildingCollection::clip_to_boundary`],
+/// this is a pure membership test -- it never modifies the footprint.
+pub(crate) fn intersects_polygon(building: &Building, polygon: &geo::Polygon<f64>) -> bool {
+    building.footprint.intersects(polygon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{polygon, Polygon};
+
+    fn building_with(height: Option<f64>, etages: Option<f64>) -> Building {
+        let footprint: Polygon<f64> = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+            (x: 0.0, y: 1.0),
+        ];
+        let mut building = Building::new(footprint);
+        if let Some(h) = height {
+            building.set_height(h);
+        }
+        if let Some(e) = etages {
+            building.set_nombre_d_etages(e);
+        }
+        building
+    }
+
+    #[test]
+    fn test_simple_comparison() {
+        let expr = parse_where("hauteur > 10").unwrap();
+        assert!(expr.matches(&building_with(Some(15.0), None)));
+        assert!(!expr.matches(&building_with(Some(5.0), None)));
+    }
+
+    #[test]
+    fn test_is_null_and_is_not_null() {
+        let expr = parse_where("hauteur > 10 AND nombre_d_etages IS NOT NULL").unwrap();
+        assert!(expr.matches(&building_with(Some(15.0), Some(4.0))));
+        assert!(!expr.matches(&building_with(Some(15.0), None)));
+
+        let expr = parse_where("hauteur IS NULL").unwrap();
+        assert!(expr.matches(&building_with(None, None)));
+        assert!(!expr.matches(&building_with(Some(15.0), None)));
+    }
+}
@@ -1,10 +1,12 @@
-use geo::{Area, Polygon};
+use geo::{coord, Area, BooleanOps, Centroid, LineString, Polygon};
 use geojson::{Feature, FeatureCollection, GeoJson, Geometry};
 use geotiff::GeoTiff;
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
 use wasm_bindgen::prelude::*;
 
+mod mvt;
+
 /// Initialize the WASM module with panic hook
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -20,6 +22,8 @@ struct WasmBuilding {
     nombre_d_etages: Option<f64>,
     hauteur_2: Option<f64>,
     no_hauteur: bool,
+    /// Ground elevation under the footprint, set by `WasmBuildingCollection::apply_ground_elevation`.
+    base_altitude: Option<f64>,
 }
 
 impl WasmBuilding {
@@ -33,6 +37,7 @@ impl WasmBuilding {
             nombre_d_etages: None,
             hauteur_2: None,
             no_hauteur: true,
+            base_altitude: None,
         }
     }
 
@@ -91,7 +96,7 @@ impl WasmBuildingCollection {
         }
     }
 
-    /// Load buildings from IGN API
+    /// Load buildings from IGN API, paginating the underlying WFS 2.0.0 request.
     ///
     /// # Arguments
     /// * `min_x` - Minimum longitude (west)
@@ -99,6 +104,17 @@ impl WasmBuildingCollection {
     /// * `max_x` - Maximum longitude (east)
     /// * `max_y` - Maximum latitude (north)
     /// * `default_storey_height` - Default height per storey in meters (e.g., 3.0)
+    /// * `page_size` - Features requested per WFS page (`COUNT`); clamped to at least 1
+    /// * `max_features` - Optional cap on the total number of buildings collected, to bound
+    ///   memory for very dense bounding boxes; pagination stops as soon as this is reached
+    /// * `on_progress` - Optional JS callback invoked after each page with the running building
+    ///   count so far, e.g. `(count) => updateLoadingIndicator(count)`
+    ///
+    /// Pages of `page_size` features are fetched with `STARTINDEX` incremented by `page_size`
+    /// each iteration, each page's features are converted with `feature_to_building` and appended into the same
+    /// collection, and fetching stops when a page returns fewer than `page_size` features or
+    /// the WFS response's `numberMatched`/`numberReturned` indicate every match has been
+    /// returned.
     ///
     /// # Returns
     /// A new WasmBuildingCollection instance
@@ -112,6 +128,9 @@ impl WasmBuildingCollection {
         max_x: f64,
         max_y: f64,
         default_storey_height: f64,
+        page_size: u32,
+        max_features: Option<u32>,
+        on_progress: Option<js_sys::Function>,
     ) -> Result<WasmBuildingCollection, JsValue> {
         use wasm_bindgen_futures::JsFuture;
         use web_sys::{Request, RequestInit, RequestMode};
@@ -123,6 +142,8 @@ impl WasmBuildingCollection {
             ));
         }
 
+        let page_size = page_size.max(1);
+
         // Build WFS request URL for IGN API (WFS 2.0.0 standard)
         let typename = "BDTOPO_V3:batiment";
         let base_url = "https://data.geopf.fr/wfs/ows";
@@ -130,55 +151,114 @@ impl WasmBuildingCollection {
         // Format bbox as: min_y,min_x,max_y,max_x (lat,lon format for EPSG:4326)
         let bbox_str = format!("{},{},{},{}", min_y, min_x, max_y, max_x);
 
-        let request_url = format!(
-            "{}?SERVICE=WFS&VERSION=2.0.0&REQUEST=GetFeature&TYPENAMES={}&CRS=EPSG:4326&BBOX={}&OUTPUTFORMAT=application/json&STARTINDEX=0&COUNT=10000",
-            base_url, typename, bbox_str
-        );
+        let mut collection = Self::new(default_storey_height);
+        let mut start_index: u32 = 0;
+
+        loop {
+            let request_url = format!(
+                "{}?SERVICE=WFS&VERSION=2.0.0&REQUEST=GetFeature&TYPENAMES={}&CRS=EPSG:4326&BBOX={}&OUTPUTFORMAT=application/json&STARTINDEX={}&COUNT={}",
+                base_url, typename, bbox_str, start_index, page_size
+            );
+
+            // Create and configure fetch request
+            let mut opts = RequestInit::new();
+            opts.set_method("GET");
+            opts.set_mode(RequestMode::Cors);
+
+            let request = Request::new_with_str_and_init(&request_url, &opts)
+                .map_err(|e| JsValue::from_str(&format!("Failed to create request: {:?}", e)))?;
+
+            // Execute request
+            let window = web_sys::window()
+                .ok_or_else(|| JsValue::from_str("No window object available"))?;
+
+            let resp_value = JsFuture::from(window.fetch_with_request(&request))
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Network request failed: {:?}", e)))?;
+
+            let resp: web_sys::Response = resp_value
+                .dyn_into()
+                .map_err(|_| JsValue::from_str("Invalid response type"))?;
+
+            // Check response status
+            if !resp.ok() {
+                return Err(JsValue::from_str(&format!(
+                    "IGN API error {}: {}",
+                    resp.status(),
+                    resp.status_text()
+                )));
+            }
 
-        // Create and configure fetch request
-        let mut opts = RequestInit::new();
-        opts.set_method("GET");
-        opts.set_mode(RequestMode::Cors);
+            // Get response text
+            let text_promise = resp.text().map_err(|e| {
+                JsValue::from_str(&format!("Failed to get response text: {:?}", e))
+            })?;
 
-        let request = Request::new_with_str_and_init(&request_url, &opts)
-            .map_err(|e| JsValue::from_str(&format!("Failed to create request: {:?}", e)))?;
+            let text = JsFuture::from(text_promise)
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to read response: {:?}", e)))?;
 
-        // Execute request
-        let window =
-            web_sys::window().ok_or_else(|| JsValue::from_str("No window object available"))?;
+            let geojson_str = text
+                .as_string()
+                .ok_or_else(|| JsValue::from_str("Response is not a valid string"))?;
 
-        let resp_value = JsFuture::from(window.fetch_with_request(&request))
-            .await
-            .map_err(|e| JsValue::from_str(&format!("Network request failed: {:?}", e)))?;
+            let geojson: GeoJson = geojson_str
+                .parse()
+                .map_err(|e| JsValue::from_str(&format!("Invalid GeoJSON: {}", e)))?;
 
-        let resp: web_sys::Response = resp_value
-            .dyn_into()
-            .map_err(|_| JsValue::from_str("Invalid response type"))?;
+            let fc = match geojson {
+                GeoJson::FeatureCollection(fc) => fc,
+                _ => {
+                    return Err(JsValue::from_str(
+                        "WFS response must be a FeatureCollection",
+                    ))
+                }
+            };
+
+            let page_returned = fc.features.len() as u32;
+            let number_matched = fc
+                .foreign_members
+                .as_ref()
+                .and_then(|m| m.get("numberMatched"))
+                .and_then(|v| v.as_u64());
+            let number_returned = fc
+                .foreign_members
+                .as_ref()
+                .and_then(|m| m.get("numberReturned"))
+                .and_then(|v| v.as_u64());
+
+            collection.buildings.reserve(page_returned as usize);
+            for feature in fc.features {
+                if let Some(building) = Self::feature_to_building(&feature) {
+                    collection.buildings.push(building);
+                }
+                if max_features.is_some_and(|max| collection.buildings.len() as u32 >= max) {
+                    break;
+                }
+            }
 
-        // Check response status
-        if !resp.ok() {
-            return Err(JsValue::from_str(&format!(
-                "IGN API error {}: {}",
-                resp.status(),
-                resp.status_text()
-            )));
-        }
+            if let Some(callback) = &on_progress {
+                let _ = callback.call1(
+                    &JsValue::NULL,
+                    &JsValue::from_f64(collection.buildings.len() as f64),
+                );
+            }
 
-        // Get response text
-        let text_promise = resp
-            .text()
-            .map_err(|e| JsValue::from_str(&format!("Failed to get response text: {:?}", e)))?;
+            let hit_max =
+                max_features.is_some_and(|max| collection.buildings.len() as u32 >= max);
+            let page_exhausted = page_returned < page_size;
+            let server_says_done = number_returned
+                .zip(number_matched)
+                .is_some_and(|(returned, matched)| start_index as u64 + returned >= matched);
 
-        let text = JsFuture::from(text_promise)
-            .await
-            .map_err(|e| JsValue::from_str(&format!("Failed to read response: {:?}", e)))?;
+            if hit_max || page_exhausted || server_says_done {
+                break;
+            }
 
-        let geojson_str = text
-            .as_string()
-            .ok_or_else(|| JsValue::from_str("Response is not a valid string"))?;
+            start_index += page_size;
+        }
 
-        // Parse and return collection
-        Self::from_geojson(&geojson_str, default_storey_height)
+        Ok(collection)
     }
 
     /// Load buildings from GeoJSON string
@@ -303,6 +383,69 @@ impl WasmBuildingCollection {
         }
     }
 
+    /// Clip every footprint to an arbitrary boundary polygon ("limit-to" geometry), keeping
+    /// only the parts inside it, the same way `BuildingCollection::clip_to_boundary` does on
+    /// the native side. Buildings that don't intersect the boundary at all are dropped;
+    /// buildings that only partially overlap have their footprint replaced with the clipped
+    /// geometry, and `area` is recomputed from it. Lets a caller restrict a rectangular WFS
+    /// fetch (see `from_ign_api`) to an actual administrative boundary instead of a crude bbox.
+    ///
+    /// # Arguments
+    /// * `boundary_geojson` - A GeoJSON Polygon/MultiPolygon geometry, Feature, or
+    ///   FeatureCollection (the first feature's geometry is used), already in EPSG:4326 — the
+    ///   CRS this binding assumes throughout.
+    ///
+    /// # Errors
+    /// Returns a JsValue error if `boundary_geojson` doesn't parse, has no geometry, or isn't a
+    /// Polygon/MultiPolygon.
+    #[wasm_bindgen]
+    pub fn clip_to_boundary(&mut self, boundary_geojson: &str) -> Result<(), JsValue> {
+        let geojson: GeoJson = boundary_geojson
+            .parse()
+            .map_err(|e| JsValue::from_str(&format!("Invalid boundary GeoJSON: {}", e)))?;
+
+        let geometry = match &geojson {
+            GeoJson::Geometry(g) => g.clone(),
+            GeoJson::Feature(f) => f
+                .geometry
+                .clone()
+                .ok_or_else(|| JsValue::from_str("Boundary feature has no geometry"))?,
+            GeoJson::FeatureCollection(fc) => fc
+                .features
+                .first()
+                .and_then(|f| f.geometry.clone())
+                .ok_or_else(|| JsValue::from_str("Boundary FeatureCollection has no features"))?,
+        };
+
+        let geo_geom: geo::Geometry<f64> = (&geometry)
+            .try_into()
+            .map_err(|e| JsValue::from_str(&format!("Invalid boundary geometry: {}", e)))?;
+
+        let boundary = match geo_geom {
+            geo::Geometry::Polygon(p) => geo::MultiPolygon(vec![p]),
+            geo::Geometry::MultiPolygon(mp) => mp,
+            _ => {
+                return Err(JsValue::from_str(
+                    "Boundary must be a Polygon or MultiPolygon",
+                ))
+            }
+        };
+
+        self.buildings.retain_mut(|building| {
+            let clipped = building.footprint.intersection(&boundary);
+            match largest_polygon(&clipped) {
+                Some(polygon) => {
+                    building.footprint = polygon;
+                    building.area = building.footprint.unsigned_area();
+                    true
+                }
+                None => false,
+            }
+        });
+
+        Ok(())
+    }
+
     /// Process building heights (fill missing heights using defaults or mean)
     #[wasm_bindgen]
     pub fn process_heights(&mut self) {
@@ -323,6 +466,36 @@ impl WasmBuildingCollection {
             .retain(|b| b.height.is_some() && b.height.unwrap() > 0.0);
     }
 
+    /// Stamp ground elevation onto every building by sampling `dem` under its footprint.
+    ///
+    /// Samples the centroid plus every exterior-ring vertex and keeps the minimum (the
+    /// lowest point of sloped terrain under the footprint is the conservative base to extrude
+    /// from), storing it as `base_altitude`. Buildings entirely outside the DEM's extent (every
+    /// sample `NaN`) are left with `base_altitude: None`.
+    #[wasm_bindgen]
+    pub fn apply_ground_elevation(&mut self, dem: &WasmDem) {
+        for building in &mut self.buildings {
+            let centroid = building.footprint.centroid();
+            let samples = centroid
+                .into_iter()
+                .map(|c| (c.x(), c.y()))
+                .chain(
+                    building
+                        .footprint
+                        .exterior()
+                        .points()
+                        .map(|p| (p.x(), p.y())),
+                )
+                .map(|(lon, lat)| dem.elevation_at(lon, lat))
+                .filter(|v| !v.is_nan());
+
+            building.base_altitude = samples.fold(None, |min, v| match min {
+                Some(m) if m <= v => Some(m),
+                _ => Some(v),
+            });
+        }
+    }
+
     /// Convert the building collection to GeoJSON string
     ///
     /// # Errors
@@ -357,6 +530,13 @@ impl WasmBuildingCollection {
 
                 feature.set_property("noHauteur", building.no_hauteur);
 
+                if let Some(base) = building.base_altitude {
+                    feature.set_property("altitude_min", base);
+                    if let Some(height) = building.height {
+                        feature.set_property("altitude_max", base + height);
+                    }
+                }
+
                 Ok(feature)
             })
             .collect();
@@ -372,6 +552,73 @@ impl WasmBuildingCollection {
         Ok(GeoJson::from(feature_collection).to_string())
     }
 
+    /// Encode the collection into a single Mapbox Vector Tile (MVT) for the given XYZ tile,
+    /// so large IGN fetches can be streamed to MapLibre/deck.gl as compact tiles instead of
+    /// shipping a monolithic GeoJSON string through `to_geojson`.
+    ///
+    /// Reprojects each footprint from EPSG:4326 to Web Mercator, then into tile-local integer
+    /// coordinates scaled to `extent` (typically 4096) using the tile's Mercator bounds, before
+    /// handing the rings to the `mvt` module's command encoder. Footprints with fewer than 3
+    /// points after projection (degenerate at this zoom level) are dropped. `hauteur`, `area`,
+    /// and `nombre_d_etages` are carried as feature attributes in the tile's key/value tables.
+    ///
+    /// # Errors
+    /// Returns a JsValue error if `extent` is zero.
+    #[wasm_bindgen]
+    pub fn to_mvt(&self, z: u32, x: u32, y: u32, extent: u32) -> Result<Vec<u8>, JsValue> {
+        if extent == 0 {
+            return Err(JsValue::from_str("extent must be positive"));
+        }
+
+        let bounds = mvt::tile_bounds(z, x, y);
+
+        let mut mvt_features = Vec::new();
+        for building in &self.buildings {
+            let mut rings = Vec::new();
+            let all_rings = std::iter::once(building.footprint.exterior())
+                .chain(building.footprint.interiors());
+            for (ring_index, ring) in all_rings.enumerate() {
+                let mut points: Vec<(i32, i32)> = ring
+                    .points()
+                    .map(|p| {
+                        let (mx, my) = mvt::lonlat_to_mercator(p.x(), p.y());
+                        mvt::mercator_to_tile_pixel(mx, my, bounds, extent)
+                    })
+                    .collect();
+
+                // GeoJSON/geo rings repeat their first point to close the loop; MVT rings don't.
+                if points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+                points.dedup();
+
+                if points.len() < 3 {
+                    continue;
+                }
+
+                mvt::ensure_winding(&mut points, ring_index == 0);
+                rings.push(points);
+            }
+
+            if rings.is_empty() {
+                continue;
+            }
+
+            let mut attributes = Vec::new();
+            if let Some(height) = building.height {
+                attributes.push(("hauteur", height));
+            }
+            attributes.push(("area", building.area));
+            if let Some(etages) = building.nombre_d_etages {
+                attributes.push(("nombre_d_etages", etages));
+            }
+
+            mvt_features.push(mvt::MvtFeature { rings, attributes });
+        }
+
+        Ok(mvt::encode_tile("buildings", extent, &mvt_features))
+    }
+
     /// Get building statistics
     ///
     /// # Errors
@@ -389,6 +636,28 @@ impl WasmBuildingCollection {
             )
         };
 
+        let ground_altitudes: Vec<f64> = self
+            .buildings
+            .iter()
+            .filter_map(|b| b.base_altitude)
+            .collect();
+        let roof_altitudes: Vec<f64> = self
+            .buildings
+            .iter()
+            .filter_map(|b| Some(b.base_altitude? + b.height?))
+            .collect();
+        let (elevation_min, elevation_max) = if ground_altitudes.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let min = ground_altitudes.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = if roof_altitudes.is_empty() {
+                min
+            } else {
+                roof_altitudes.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+            };
+            (min, max)
+        };
+
         let stats = BuildingStats {
             count: self.buildings.len(),
             total_area: self.buildings.iter().map(|b| b.area).sum(),
@@ -396,6 +665,8 @@ impl WasmBuildingCollection {
             buildings_with_height: heights.len(),
             min_height,
             max_height,
+            elevation_min,
+            elevation_max,
         };
 
         serde_wasm_bindgen::to_value(&stats)
@@ -419,6 +690,11 @@ struct BuildingStats {
     buildings_with_height: usize,
     min_height: f64,
     max_height: f64,
+    /// Lowest ground elevation sampled by `apply_ground_elevation`, 0.0 if never called.
+    elevation_min: f64,
+    /// Highest roof elevation (`base_altitude + height`) across buildings with both set, 0.0 if
+    /// `apply_ground_elevation` was never called.
+    elevation_max: f64,
 }
 
 /// Set panic hook for better error messages (alternative to init)
@@ -427,16 +703,90 @@ pub fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
+/// Sentinel IGN GeoTIFFs commonly use to mark a missing elevation sample. Overridable per
+/// instance via [`WasmDem::set_nodata`].
+const DEFAULT_DEM_NODATA: f64 = -99999.0;
+
+/// Affine geotransform as `[origin_x, pixel_w, row_rotation, origin_y, col_rotation,
+/// neg_pixel_h]`, in the GDAL convention. `get_extent`/`elevation_at` only use the axis-aligned
+/// terms (`pixel_w`/`neg_pixel_h`); the rotation terms are carried along for completeness but
+/// assumed zero by both.
+type GeoTransform = [f64; 6];
+
+/// Identity-ish fallback used when a TIFF carries no GeoTIFF georeferencing tags: one raster
+/// pixel per model unit, origin at (0, 0), so `get_extent` degrades to the old pixel-space
+/// placeholder and `elevation_at` still does something sane.
+const IDENTITY_GEOTRANSFORM: GeoTransform = [0.0, 1.0, 0.0, 0.0, 0.0, -1.0];
+
+/// Read the GeoTIFF georeferencing tags (`ModelPixelScaleTag` 33550 + `ModelTiepointTag` 33922,
+/// or `ModelTransformationTag` 34264) off `decoder` and build an affine geotransform from them.
+/// Returns `None` (caller falls back to [`IDENTITY_GEOTRANSFORM`]) when neither pair of tags is
+/// present, e.g. for a plain (non-georeferenced) TIFF.
+fn read_geotransform(decoder: &mut tiff::decoder::Decoder<Cursor<Vec<u8>>>) -> Option<GeoTransform> {
+    use tiff::tags::Tag;
+
+    if let Ok(matrix) = decoder.get_tag_f64_vec(Tag::ModelTransformationTag) {
+        if matrix.len() >= 16 {
+            // Row-major 4x4 model transformation matrix; we only need the 2D affine terms.
+            return Some([
+                matrix[3], matrix[0], matrix[1], matrix[7], matrix[4], matrix[5],
+            ]);
+        }
+    }
+
+    let scale = decoder.get_tag_f64_vec(Tag::ModelPixelScaleTag).ok()?;
+    let tiepoint = decoder.get_tag_f64_vec(Tag::ModelTiepointTag).ok()?;
+    if scale.len() < 2 || tiepoint.len() < 6 {
+        return None;
+    }
+
+    // Tiepoint is (raster_i, raster_j, raster_k, model_x, model_y, model_z): the raster pixel
+    // at (i, j) sits at model coordinates (x, y).
+    let (i, j, x, y) = (tiepoint[0], tiepoint[1], tiepoint[3], tiepoint[4]);
+    let (pixel_w, pixel_h) = (scale[0], scale[1]);
+    let origin_x = x - i * pixel_w;
+    let origin_y = y + j * pixel_h;
+    Some([origin_x, pixel_w, 0.0, origin_y, 0.0, -pixel_h])
+}
+
+/// Convert whatever sample type the `tiff` crate decoded into a flat `Vec<f64>`, preserving
+/// sample order (and interleaving, for multi-band rasters).
+fn decoding_result_to_f64(result: tiff::decoder::DecodingResult) -> Vec<f64> {
+    use tiff::decoder::DecodingResult;
+    match result {
+        DecodingResult::U8(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::U16(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::U32(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::U64(v) => v.into_iter().map(|x| x as f64).collect(),
+        DecodingResult::I8(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::I16(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::I32(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::I64(v) => v.into_iter().map(|x| x as f64).collect(),
+        DecodingResult::F32(v) => v.into_iter().map(f64::from).collect(),
+        DecodingResult::F64(v) => v,
+    }
+}
+
 /// DEM (Digital Elevation Model) reader for WASM
 ///
-/// Uses geotiff 0.1 or tiff crate to read GeoTIFF metadata.
-/// Note: geotiff 0.1 only provides metadata (width, height), not pixel data access.
+/// `geotiff` 0.1 only exposes raster metadata (width, height, geotransform), so actual pixel
+/// values are decoded separately through the `tiff` crate's `Decoder::read_image`, normalized
+/// into a flat, row-major `Vec<f64>` (interleaved by sample for multi-band rasters).
 #[wasm_bindgen]
 pub struct WasmDem {
     _tiff: Option<GeoTiff>, // Keep for potential future use (may be None if geotiff couldn't read)
     width: usize,
     height: usize,
-    bytes: Vec<u8>, // Keep bytes for potential future pixel parsing
+    /// Number of samples (bands) per pixel, e.g. 1 for single-band DEMs.
+    samples_per_pixel: usize,
+    /// Decoded raster samples, row-major and interleaved by sample:
+    /// `data[(y * width + x) * samples_per_pixel + sample_index]`.
+    data: Vec<f64>,
+    /// Sentinel value excluded from `get_stats` and reported as `NaN` by `get_value_at`.
+    nodata: f64,
+    /// Affine raster-to-model transform, parsed from the GeoTIFF tags by
+    /// [`read_geotransform`] or [`IDENTITY_GEOTRANSFORM`] if absent.
+    geotransform: GeoTransform,
 }
 
 #[wasm_bindgen]
@@ -578,14 +928,43 @@ impl WasmDem {
             }
         };
 
+        // geotiff 0.1 never exposes pixel data, so always decode the actual samples through
+        // the `tiff` crate, regardless of which branch above determined the dimensions.
+        let data_cursor = Cursor::new(bytes_vec.clone());
+        let mut data_decoder = tiff::decoder::Decoder::new(data_cursor).map_err(|e| {
+            JsValue::from_str(&format!("Failed to open TIFF for pixel decoding: {}", e))
+        })?;
+        let geotransform = read_geotransform(&mut data_decoder).unwrap_or(IDENTITY_GEOTRANSFORM);
+        let decoded = data_decoder
+            .read_image()
+            .map_err(|e| JsValue::from_str(&format!("Failed to decode TIFF pixel data: {}", e)))?;
+        let data = decoding_result_to_f64(decoded);
+
+        let pixel_count = width * height;
+        let samples_per_pixel = if pixel_count == 0 {
+            1
+        } else {
+            (data.len() / pixel_count).max(1)
+        };
+
         Ok(WasmDem {
             _tiff: tiff, // May be None if geotiff couldn't read it
             width,
             height,
-            bytes: bytes_vec,
+            samples_per_pixel,
+            data,
+            nodata: DEFAULT_DEM_NODATA,
+            geotransform,
         })
     }
 
+    /// Set the nodata sentinel excluded from `get_stats` and reported as `NaN` by
+    /// `get_value_at`. Defaults to `-99999.0`, the value IGN GeoTIFFs commonly use.
+    #[wasm_bindgen]
+    pub fn set_nodata(&mut self, nodata: f64) {
+        self.nodata = nodata;
+    }
+
     /// Get raster width
     #[wasm_bindgen]
     pub fn width(&self) -> u32 {
@@ -598,14 +977,57 @@ impl WasmDem {
         self.height as u32
     }
 
-    /// Get extent (bounding box) as [min_x, min_y, max_x, max_y]
-    /// Note: geotiff 0.1 may not have get_extent, so we return a placeholder
+    /// Get extent (bounding box) as `[min_x, min_y, max_x, max_y]`, derived from the GeoTIFF's
+    /// affine geotransform (origin + pixel size parsed from `ModelPixelScaleTag`/
+    /// `ModelTiepointTag`, or `ModelTransformationTag`).
     #[wasm_bindgen]
     pub fn get_extent(&self) -> Vec<f64> {
-        // Try to get extent from geotransform if available
-        // For now, return placeholder - actual implementation depends on geotiff API
-        // In a real implementation, you would extract this from the geotransform matrix
-        vec![0.0, 0.0, self.width as f64, self.height as f64]
+        let [origin_x, pixel_w, _, origin_y, _, neg_pixel_h] = self.geotransform;
+        let min_x = origin_x;
+        let max_x = origin_x + pixel_w * self.width as f64;
+        let max_y = origin_y;
+        let min_y = origin_y + neg_pixel_h * self.height as f64;
+        vec![min_x, min_y, max_x, max_y]
+    }
+
+    /// Sample the elevation at a model-space `(lon, lat)` coordinate, bilinearly interpolated
+    /// over the four surrounding pixels. Returns `NaN` outside the raster's extent, or if any of
+    /// the four surrounding pixels is nodata.
+    #[wasm_bindgen]
+    pub fn elevation_at(&self, lon: f64, lat: f64) -> f64 {
+        let [origin_x, pixel_w, _, origin_y, _, neg_pixel_h] = self.geotransform;
+        let pixel_h = -neg_pixel_h;
+        if pixel_w == 0.0 || pixel_h == 0.0 {
+            return f64::NAN;
+        }
+
+        let px = (lon - origin_x) / pixel_w;
+        let py = (origin_y - lat) / pixel_h;
+
+        if !(0.0..=(self.width - 1) as f64).contains(&px)
+            || !(0.0..=(self.height - 1) as f64).contains(&py)
+        {
+            return f64::NAN;
+        }
+
+        let x0 = px.floor();
+        let y0 = py.floor();
+        let x1 = (x0 + 1.0).min((self.width - 1) as f64);
+        let y1 = (y0 + 1.0).min((self.height - 1) as f64);
+        let fx = px - x0;
+        let fy = py - y0;
+
+        let v00 = self.get_value_at(x0 as u32, y0 as u32, 0);
+        let v10 = self.get_value_at(x1 as u32, y0 as u32, 0);
+        let v01 = self.get_value_at(x0 as u32, y1 as u32, 0);
+        let v11 = self.get_value_at(x1 as u32, y1 as u32, 0);
+        if v00.is_nan() || v10.is_nan() || v01.is_nan() || v11.is_nan() {
+            return f64::NAN;
+        }
+
+        let top = v00 * (1.0 - fx) + v10 * fx;
+        let bottom = v01 * (1.0 - fx) + v11 * fx;
+        top * (1.0 - fy) + bottom * fy
     }
 
     /// Get value at pixel coordinates (x, y)
@@ -616,61 +1038,205 @@ impl WasmDem {
     /// * `sample_index` - Sample index (usually 0 for single-band DEM)
     ///
     /// # Returns
-    /// Elevation value at the specified coordinates, or NaN if out of bounds
-    ///
-    /// # Note
-    /// geotiff 0.1 only provides metadata (width, height), not pixel data access.
-    /// To read actual pixel values, you would need to:
-    /// 1. Use a different crate (like `tiff` or `image`) to read raw TIFF data
-    /// 2. Or use GDAL bindings (not WASM-compatible)
-    /// 3. Or parse the TIFF file format manually
+    /// Elevation value at the specified coordinates, `NaN` if out of bounds, and `NaN` if the
+    /// decoded sample equals the configured nodata sentinel (see `set_nodata`).
     #[wasm_bindgen]
-    pub fn get_value_at(&self, x: u32, y: u32, _sample_index: u32) -> f64 {
+    pub fn get_value_at(&self, x: u32, y: u32, sample_index: u32) -> f64 {
         if x as usize >= self.width || y as usize >= self.height {
             return f64::NAN;
         }
 
-        // geotiff 0.1 doesn't provide pixel data access methods
-        // This is a limitation of the crate version
-        // For now, return NaN - actual implementation would require parsing TIFF data manually
-        // or using a different library
-        f64::NAN
+        let idx = (y as usize * self.width + x as usize) * self.samples_per_pixel
+            + sample_index as usize;
+        match self.data.get(idx) {
+            Some(&value) if value != self.nodata => value,
+            _ => f64::NAN,
+        }
     }
 
-    /// Get statistics (min, max, mean) for the DEM
-    ///
-    /// # Note
-    /// geotiff 0.1 only provides metadata, not pixel data access.
-    /// This returns placeholder values. For actual statistics, you would need
-    /// to parse the TIFF pixel data manually or use a different library.
+    /// Get statistics (min, max, mean) for the DEM's first band, excluding nodata samples.
     #[wasm_bindgen]
     pub fn get_stats(&self) -> Result<JsValue, JsValue> {
-        // geotiff 0.1 limitation: no pixel data access
-        // Return placeholder stats based on dimensions only
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut sum = 0.0;
+        let mut count = 0usize;
+
+        for pixel in 0..self.width * self.height {
+            let value = self.data[pixel * self.samples_per_pixel];
+            if value == self.nodata {
+                continue;
+            }
+            min = min.min(value);
+            max = max.max(value);
+            sum += value;
+            count += 1;
+        }
+
+        let (min, max, mean) = if count > 0 {
+            (min, max, sum / count as f64)
+        } else {
+            (0.0, 0.0, 0.0)
+        };
+
         let stats = DemStats {
-            min: 0.0,
-            max: 0.0,
-            mean: 0.0,
-            count: (self.width * self.height) as usize,
+            min,
+            max,
+            mean,
+            count,
         };
 
         serde_wasm_bindgen::to_value(&stats)
             .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
     }
 
-    /// Get elevation values as a flat array (row-major order)
-    /// Useful for creating height maps or visualizations
-    ///
-    /// # Note
-    /// geotiff 0.1 only provides metadata, not pixel data access.
-    /// This returns an array of NaN values. For actual data, you would need
-    /// to parse the TIFF pixel data manually or use a different library.
+    /// Get the first band's elevation values as a flat array (row-major order), with nodata
+    /// samples left as decoded (use `get_value_at` if you need nodata normalized to `NaN`).
+    /// Useful for creating height maps or visualizations.
     #[wasm_bindgen]
     pub fn get_elevation_array(&self) -> Vec<f64> {
-        // geotiff 0.1 limitation: no pixel data access
-        // Return array of NaN values as placeholder
-        vec![f64::NAN; (self.width * self.height)]
+        if self.samples_per_pixel <= 1 {
+            return self.data.clone();
+        }
+        (0..self.width * self.height)
+            .map(|pixel| self.data[pixel * self.samples_per_pixel])
+            .collect()
     }
+
+    /// Generate contour lines from the DEM's first band via marching squares.
+    ///
+    /// For every threshold `base + n * interval` that falls within the DEM's value range,
+    /// walks each 2x2 cell of the decoded grid, classifies the four corners as above/below the
+    /// threshold into a 4-bit case, and linearly interpolates each crossed edge
+    /// (`t = (z - a) / (b - a)`) into geographic coordinates via the geotransform. The
+    /// ambiguous saddle cases (5 and 10) are resolved using the cell-center average. Cells
+    /// touching a nodata/`NaN` corner are skipped. Each crossed cell emits its own 2-point
+    /// `LineString` feature tagged with an `elevation` property (segments are not stitched
+    /// across cells into longer polylines).
+    ///
+    /// # Errors
+    /// Returns a JsValue error if `interval` is not positive.
+    #[wasm_bindgen]
+    pub fn contours(&self, interval: f64, base: f64) -> Result<String, JsValue> {
+        if interval <= 0.0 {
+            return Err(JsValue::from_str("interval must be positive"));
+        }
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for pixel in 0..self.width * self.height {
+            let value = self.data[pixel * self.samples_per_pixel];
+            if value == self.nodata {
+                continue;
+            }
+            min = min.min(value);
+            max = max.max(value);
+        }
+
+        let mut features = Vec::new();
+        if min.is_finite() && max.is_finite() {
+            let mut level = ((min - base) / interval).ceil() * interval + base;
+            while level <= max {
+                self.collect_contour_segments(level, &mut features);
+                level += interval;
+            }
+        }
+
+        let feature_collection = FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        };
+
+        Ok(GeoJson::from(feature_collection).to_string())
+    }
+
+    fn pixel_to_geo(&self, px: f64, py: f64) -> (f64, f64) {
+        let [origin_x, pixel_w, _, origin_y, _, neg_pixel_h] = self.geotransform;
+        (origin_x + pixel_w * px, origin_y + neg_pixel_h * py)
+    }
+
+    fn collect_contour_segments(&self, level: f64, features: &mut Vec<Feature>) {
+        for y in 0..self.height.saturating_sub(1) {
+            for x in 0..self.width.saturating_sub(1) {
+                let v_tl = self.get_value_at(x as u32, y as u32, 0);
+                let v_tr = self.get_value_at((x + 1) as u32, y as u32, 0);
+                let v_br = self.get_value_at((x + 1) as u32, (y + 1) as u32, 0);
+                let v_bl = self.get_value_at(x as u32, (y + 1) as u32, 0);
+                if v_tl.is_nan() || v_tr.is_nan() || v_br.is_nan() || v_bl.is_nan() {
+                    continue;
+                }
+
+                let case = (v_tl >= level) as u8
+                    | ((v_tr >= level) as u8) << 1
+                    | ((v_br >= level) as u8) << 2
+                    | ((v_bl >= level) as u8) << 3;
+                if case == 0 || case == 15 {
+                    continue;
+                }
+
+                let p_tl = self.pixel_to_geo(x as f64, y as f64);
+                let p_tr = self.pixel_to_geo((x + 1) as f64, y as f64);
+                let p_br = self.pixel_to_geo((x + 1) as f64, (y + 1) as f64);
+                let p_bl = self.pixel_to_geo(x as f64, (y + 1) as f64);
+
+                let e_top = edge_point(p_tl, v_tl, p_tr, v_tr, level);
+                let e_right = edge_point(p_tr, v_tr, p_br, v_br, level);
+                let e_bottom = edge_point(p_br, v_br, p_bl, v_bl, level);
+                let e_left = edge_point(p_bl, v_bl, p_tl, v_tl, level);
+                let center_above = (v_tl + v_tr + v_br + v_bl) / 4.0 >= level;
+
+                let segments: &[((f64, f64), (f64, f64))] = match case {
+                    1 | 14 => &[(e_left, e_top)],
+                    2 | 13 => &[(e_top, e_right)],
+                    3 | 12 => &[(e_left, e_right)],
+                    4 | 11 => &[(e_right, e_bottom)],
+                    6 | 9 => &[(e_top, e_bottom)],
+                    7 | 8 => &[(e_bottom, e_left)],
+                    5 if center_above => &[(e_top, e_right), (e_bottom, e_left)],
+                    5 => &[(e_left, e_top), (e_right, e_bottom)],
+                    10 if center_above => &[(e_left, e_top), (e_right, e_bottom)],
+                    10 => &[(e_top, e_right), (e_bottom, e_left)],
+                    _ => &[],
+                };
+
+                for &(from, to) in segments {
+                    let line = LineString::new(vec![
+                        coord! { x: from.0, y: from.1 },
+                        coord! { x: to.0, y: to.1 },
+                    ]);
+                    let geo_geom = geo::Geometry::LineString(line);
+                    if let Ok(geometry) = Geometry::try_from(&geo_geom) {
+                        let mut feature = Feature::from(geometry);
+                        feature.set_property("elevation", level);
+                        features.push(feature);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Linearly interpolate the point where `level` crosses the edge from `(p_a, v_a)` to
+/// `(p_b, v_b)`, falling back to the edge midpoint if both corners share the same value.
+fn edge_point(p_a: (f64, f64), v_a: f64, p_b: (f64, f64), v_b: f64, level: f64) -> (f64, f64) {
+    let denom = v_b - v_a;
+    let t = if denom.abs() > f64::EPSILON {
+        ((level - v_a) / denom).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    (p_a.0 + (p_b.0 - p_a.0) * t, p_a.1 + (p_b.1 - p_a.1) * t)
+}
+
+/// Pick the largest-by-area polygon out of a (possibly empty, possibly multi-part)
+/// intersection result, the way `BuildingCollection::clip_to_boundary` does on the native
+/// side — a clip can split a footprint into slivers plus one dominant remainder.
+fn largest_polygon(multi: &geo::MultiPolygon<f64>) -> Option<Polygon<f64>> {
+    multi
+        .iter()
+        .max_by(|a, b| a.unsigned_area().partial_cmp(&b.unsigned_area()).unwrap())
+        .cloned()
 }
 
 /// DEM statistics structure
@@ -0,0 +1,250 @@
+//! Minimal hand-rolled Mapbox Vector Tile (MVT) encoder.
+//!
+//! MVT tiles are plain protocol buffers (see the `vector_tile.proto` schema in the Mapbox
+//! Vector Tile spec), so rather than pull in a full protobuf codegen pipeline for three
+//! message types, this writes the handful of fields `WasmBuildingCollection::to_mvt` needs
+//! directly with a small varint/length-delimited writer, the same spirit as the hand-written
+//! recursive-descent parser in `rsmdu/src/geometric/query.rs`.
+
+use std::collections::HashMap;
+
+/// A single polygon feature ready to be encoded into a tile, already expressed in
+/// tile-local pixel coordinates (`[0, extent)`, Y pointing down).
+pub(crate) struct MvtFeature {
+    /// Exterior ring first, followed by any interior (hole) rings. Each ring is a closed
+    /// polygon's deduped point list (no repeated closing point).
+    pub(crate) rings: Vec<Vec<(i32, i32)>>,
+    pub(crate) attributes: Vec<(&'static str, f64)>,
+}
+
+/// WGS84 semi-major axis, the sphere radius Web Mercator (EPSG:3857) projects onto.
+const EARTH_RADIUS_M: f64 = 6_378_137.0;
+
+/// Half the circumference of the Web Mercator sphere - the distance from the equator/prime
+/// meridian origin to any edge of the projection's square extent.
+fn origin_shift() -> f64 {
+    std::f64::consts::PI * EARTH_RADIUS_M
+}
+
+/// Project an EPSG:4326 `(lon, lat)` in degrees into EPSG:3857 meters.
+pub(crate) fn lonlat_to_mercator(lon: f64, lat: f64) -> (f64, f64) {
+    let x = lon.to_radians() * EARTH_RADIUS_M;
+    let y = (lat.to_radians() / 2.0 + std::f64::consts::FRAC_PI_4)
+        .tan()
+        .ln()
+        * EARTH_RADIUS_M;
+    (x, y)
+}
+
+/// EPSG:3857 `(min_x, min_y, max_x, max_y)` bounds of an XYZ slippy-map tile.
+pub(crate) fn tile_bounds(z: u32, x: u32, y: u32) -> (f64, f64, f64, f64) {
+    let shift = origin_shift();
+    let tile_size = 2.0 * shift / 2f64.powi(z as i32);
+    let min_x = -shift + x as f64 * tile_size;
+    let max_x = min_x + tile_size;
+    let max_y = shift - y as f64 * tile_size;
+    let min_y = max_y - tile_size;
+    (min_x, min_y, max_x, max_y)
+}
+
+/// Map an EPSG:3857 point into tile-local integer pixel coordinates scaled to `extent`,
+/// flipping Y so it points down the way MVT geometry does.
+pub(crate) fn mercator_to_tile_pixel(
+    mx: f64,
+    my: f64,
+    bounds: (f64, f64, f64, f64),
+    extent: u32,
+) -> (i32, i32) {
+    let (min_x, min_y, max_x, max_y) = bounds;
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    let px = ((mx - min_x) / width * extent as f64).round() as i32;
+    let py = ((max_y - my) / height * extent as f64).round() as i32;
+    (px, py)
+}
+
+/// Reverse `ring` in place unless its shoelace winding already matches `clockwise` - the MVT
+/// spec requires exterior rings clockwise and interior (hole) rings counter-clockwise in its
+/// Y-down tile coordinate space.
+pub(crate) fn ensure_winding(ring: &mut [(i32, i32)], clockwise: bool) {
+    let mut signed_area: i64 = 0;
+    for i in 0..ring.len() {
+        let (x0, y0) = ring[i];
+        let (x1, y1) = ring[(i + 1) % ring.len()];
+        signed_area += x0 as i64 * y1 as i64 - x1 as i64 * y0 as i64;
+    }
+    if (signed_area > 0) != clockwise {
+        ring.reverse();
+    }
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn write_tag(buf: &mut Vec<u8>, field_number: u32, wire_type: u32) {
+    write_varint(buf, ((field_number << 3) | wire_type) as u64);
+}
+
+fn write_length_delimited(buf: &mut Vec<u8>, field_number: u32, data: &[u8]) {
+    write_tag(buf, field_number, 2);
+    write_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+fn write_string_field(buf: &mut Vec<u8>, field_number: u32, s: &str) {
+    write_length_delimited(buf, field_number, s.as_bytes());
+}
+
+fn write_varint_field(buf: &mut Vec<u8>, field_number: u32, value: u64) {
+    write_tag(buf, field_number, 0);
+    write_varint(buf, value);
+}
+
+fn zigzag_encode(value: i64) -> u32 {
+    (((value << 1) ^ (value >> 63)) as u64) as u32
+}
+
+const CMD_MOVE_TO: u32 = 1;
+const CMD_LINE_TO: u32 = 2;
+const CMD_CLOSE_PATH: u32 = 7;
+
+fn command_integer(id: u32, count: u32) -> u32 {
+    (id & 0x7) | (count << 3)
+}
+
+/// Builds a feature's packed geometry command stream, threading one delta-encoding cursor
+/// across every ring (MVT deltas are cumulative across the whole feature, not per ring).
+struct GeometryEncoder {
+    commands: Vec<u32>,
+    cursor: (i32, i32),
+}
+
+impl GeometryEncoder {
+    fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            cursor: (0, 0),
+        }
+    }
+
+    fn add_ring(&mut self, ring: &[(i32, i32)]) {
+        if ring.len() < 3 {
+            return;
+        }
+
+        self.commands.push(command_integer(CMD_MOVE_TO, 1));
+        self.push_delta(ring[0]);
+
+        let remaining = &ring[1..];
+        if !remaining.is_empty() {
+            self.commands
+                .push(command_integer(CMD_LINE_TO, remaining.len() as u32));
+            for &point in remaining {
+                self.push_delta(point);
+            }
+        }
+
+        self.commands.push(command_integer(CMD_CLOSE_PATH, 1));
+    }
+
+    fn push_delta(&mut self, point: (i32, i32)) {
+        let dx = point.0 as i64 - self.cursor.0 as i64;
+        let dy = point.1 as i64 - self.cursor.1 as i64;
+        self.commands.push(zigzag_encode(dx));
+        self.commands.push(zigzag_encode(dy));
+        self.cursor = point;
+    }
+}
+
+fn encode_feature(tags: &[u32], commands: &[u32]) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    let mut tags_buf = Vec::new();
+    for &t in tags {
+        write_varint(&mut tags_buf, t as u64);
+    }
+    write_length_delimited(&mut buf, 2, &tags_buf); // Feature.tags (packed)
+
+    write_varint_field(&mut buf, 3, 3); // Feature.type = POLYGON
+
+    let mut geom_buf = Vec::new();
+    for &c in commands {
+        write_varint(&mut geom_buf, c as u64);
+    }
+    write_length_delimited(&mut buf, 4, &geom_buf); // Feature.geometry (packed)
+
+    buf
+}
+
+fn encode_value(value: f64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_tag(&mut buf, 3, 1); // Value.double_value, wire type 1 (64-bit)
+    buf.extend_from_slice(&value.to_le_bytes());
+    buf
+}
+
+fn encode_layer(name: &str, extent: u32, features: &[MvtFeature]) -> Vec<u8> {
+    let mut keys: Vec<&'static str> = Vec::new();
+    let mut key_index: HashMap<&'static str, u32> = HashMap::new();
+    let mut values: Vec<f64> = Vec::new();
+    let mut value_index: HashMap<u64, u32> = HashMap::new();
+
+    let mut encoded_features = Vec::new();
+    for feature in features {
+        let mut tags = Vec::new();
+        for &(key, value) in &feature.attributes {
+            let key_idx = *key_index.entry(key).or_insert_with(|| {
+                keys.push(key);
+                (keys.len() - 1) as u32
+            });
+            let value_idx = *value_index.entry(value.to_bits()).or_insert_with(|| {
+                values.push(value);
+                (values.len() - 1) as u32
+            });
+            tags.push(key_idx);
+            tags.push(value_idx);
+        }
+
+        let mut encoder = GeometryEncoder::new();
+        for ring in &feature.rings {
+            encoder.add_ring(ring);
+        }
+
+        encoded_features.push(encode_feature(&tags, &encoder.commands));
+    }
+
+    let mut layer_buf = Vec::new();
+    write_string_field(&mut layer_buf, 1, name); // Layer.name
+    for feature in &encoded_features {
+        write_length_delimited(&mut layer_buf, 2, feature); // Layer.features
+    }
+    for key in &keys {
+        write_string_field(&mut layer_buf, 3, key); // Layer.keys
+    }
+    for &value in &values {
+        write_length_delimited(&mut layer_buf, 4, &encode_value(value)); // Layer.values
+    }
+    write_varint_field(&mut layer_buf, 5, extent as u64); // Layer.extent
+    write_varint_field(&mut layer_buf, 15, 2); // Layer.version = 2
+
+    layer_buf
+}
+
+/// Encode a single-layer tile named `name` from already tile-projected `features`.
+pub(crate) fn encode_tile(name: &str, extent: u32, features: &[MvtFeature]) -> Vec<u8> {
+    let layer = encode_layer(name, extent, features);
+    let mut tile_buf = Vec::new();
+    write_length_delimited(&mut tile_buf, 3, &layer); // Tile.layers
+    tile_buf
+}
@@ -73,6 +73,7 @@ fn main() -> Result<()> {
         Some("./output".to_string()),
         3.0,  // Hauteur par défaut d'un étage (3 mètres)
         None, // CRS (Coordinate Reference System) - None utilise le défaut
+        None, // point_buffer_radius - pas de points dans ce GeoJSON
     )?;
 
     println!("Bâtiments chargés: {}", collection.len());
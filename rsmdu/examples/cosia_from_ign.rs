@@ -23,7 +23,7 @@ fn main() -> Result<()> {
     // Run Cosia processing
     // Python: ign_cosia = cosia.run_ign()
     println!("Téléchargement et traitement du Cosia depuis l'API IGN...");
-    let cosia_result = cosia.run_ign()?;
+    let cosia_result = cosia.run_ign(false)?;
 
     println!("\nCosia traité avec succès!");
     println!("  - Fichier Cosia: {:?}", cosia_result.get_path_save_tiff());
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use rsmdu::geometric::dem::Dem;
+use rsmdu::geometric::dem::{Dem, Resampling};
 
 /// Example: Loading DEM (Digital Elevation Model) from IGN API
 /// Following Python example from pymdu.geometric.Dem
@@ -8,7 +8,7 @@ fn main() -> Result<()> {
 
     // Create Dem instance
     // Python: dem = Dem(output_path='./')
-    let mut dem = Dem::new(Some("./output".to_string()))?;
+    let mut dem = Dem::new(Some("./output".to_string()), None)?;
 
     // Set bounding box (La Rochelle, France)
     // Python: dem.Bbox = [-1.152704, 46.181627, -1.139893, 46.18699]
@@ -23,14 +23,17 @@ fn main() -> Result<()> {
     // Run DEM processing
     // Python: ign_dem = dem.run()
     println!("Downloading and processing DEM from IGN API...");
-    let dem_result = dem.run(None)?;
+    let mut dem_result = dem.run(None, false)?;
 
     println!("\nDEM processed successfully!");
     println!("  - DEM file: {:?}", dem_result.get_path_save_tiff());
     println!("  - Mask: {:?}", dem_result.get_path_save_mask());
 
-    // Note: DEM is saved but full reprojection is temporarily disabled
-    // TODO: Implement full reprojection to EPSG:2154 with 1m resolution
+    // Full reprojection to EPSG:2154 (Lambert-93) at 1m resolution, matching the Python pymdu
+    // `dataarray.rio.reproject(dst_crs=2154, resolution=1)` default.
+    println!("\nReprojecting to EPSG:2154 at 1m resolution...");
+    dem_result.reproject(2154, 1.0, Resampling::Bilinear)?;
+    println!("  - Reprojected DEM: {:?}", dem_result.get_path_save_tiff());
 
     Ok(())
 }
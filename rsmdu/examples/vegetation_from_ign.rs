@@ -8,7 +8,17 @@ fn main() -> Result<()> {
 
     // Create Vegetation instance
     // Python: vegetation = Vegetation(output_path='./', min_area=0)
-    let mut vegetation = Vegetation::new(None, Some("./output".to_string()), None, false, 0.0)?;
+    let mut vegetation = Vegetation::new(
+        None,
+        Some("./output".to_string()),
+        None,
+        false,
+        0.0,
+        None,
+        None,
+        None,
+        false,
+    )?;
 
     // Set bounding box (La Rochelle, France)
     // Python: vegetation.bbox = [-1.152704, 46.181627, -1.139893, 46.18699]
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use rsmdu::geometric::water::Water;
+use rsmdu::geometric::water::{Water, WaterSource};
 
 /// Example: Loading Water (plan d'eau) data from IGN API
 /// Following Python example from pymdu.geometric.Water
@@ -8,7 +8,12 @@ fn main() -> Result<()> {
 
     // Create Water instance
     // Python: water = Water(output_path='./')
-    let mut water = Water::new(None, Some("./output".to_string()), None)?;
+    let mut water = Water::new(
+        None,
+        Some("./output".to_string()),
+        None,
+        Some(WaterSource::Ign),
+    )?;
 
     // Set bounding box (La Rochelle, France)
     // Python: water.bbox = [-1.152704, 46.181627, -1.139893, 46.18699]
@@ -42,7 +47,7 @@ fn main() -> Result<()> {
     // Save to GeoJSON
     // Python: water.to_geojson(name="water")
     println!("\nSauvegarde en GeoJSON...");
-    water_result.to_geojson(None)?;
+    water_result.to_geojson(None, false)?;
 
     println!("\n✅ Traitement terminé!");
     println!(
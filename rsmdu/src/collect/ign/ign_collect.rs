@@ -1,12 +1,15 @@
 use anyhow::{Context, Result};
 use csv::ReaderBuilder;
 use encoding_rs;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{create_dir_all, File, write};
+use std::hash::{Hash, Hasher};
 use std::io::{BufReader, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::collect::global_variables::TEMP_PATH;
 use crate::geo_core::{BoundingBox, GeoCore};
@@ -36,6 +39,76 @@ struct IgnCsvRecord {
     url_geoplateforme: String,
 }
 
+/// Sidecar written alongside each on-disk cache entry, recording when it was fetched and from
+/// where. See [`IgnCollect::set_cache`].
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheSidecar {
+    timestamp: u64,
+    source_url: String,
+}
+
+/// Cost metric for a [`IsochroneParams`] request: travel time in seconds, or travel distance in
+/// meters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostType {
+    Time,
+    Distance,
+}
+
+impl CostType {
+    fn as_str(self) -> &'static str {
+        match self {
+            CostType::Time => "time",
+            CostType::Distance => "distance",
+        }
+    }
+}
+
+/// Routing profile for a [`IsochroneParams`] request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Car,
+    Pedestrian,
+}
+
+impl Profile {
+    fn as_str(self) -> &'static str {
+        match self {
+            Profile::Car => "car",
+            Profile::Pedestrian => "pedestrian",
+        }
+    }
+}
+
+/// Whether a [`IsochroneParams`] request's cost values are measured from the point
+/// (`Departure`, "how far can I get") or to the point (`Arrival`, "how far can I come from").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Departure,
+    Arrival,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Departure => "departure",
+            Direction::Arrival => "arrival",
+        }
+    }
+}
+
+/// Parameters for [`IgnCollect::fetch_isochrone`]: one or more isochrone/isodistance polygons
+/// around `point` (lon, lat in EPSG:4326), out to each of `cost_values` (seconds for
+/// [`CostType::Time`], meters for [`CostType::Distance`]), via IGN's Valhalla routing service.
+#[derive(Debug, Clone)]
+pub struct IsochroneParams {
+    pub point: (f64, f64),
+    pub cost_type: CostType,
+    pub cost_values: Vec<f64>,
+    pub profile: Profile,
+    pub direction: Direction,
+}
+
 /// Base struct for IGN data collection
 /// Provides methods to query IGN API and fetch geospatial data
 /// Follows the Python implementation from pymdu
@@ -49,6 +122,31 @@ pub struct IgnCollect {
     pub df_csv_file: HashMap<String, IgnServiceRow>, // Indexed by nom_technique
     #[allow(dead_code)] // Reserved for future use
     collect_path: PathBuf,
+    /// Capabilities fetched so far via `get_layer`, keyed by service so a WFS and a WMS
+    /// `GetCapabilities` document aren't confused with each other.
+    capabilities_cache: HashMap<ServiceKind, Vec<LayerMeta>>,
+    /// WMS `GetMap` resolution in meters/pixel. See [`IgnCollect::set_resolution`].
+    resolution: f64,
+    /// Pixel cap (per axis) on a single WMS `GetMap` request before [`IgnCollect::execute_wms`]
+    /// falls back to tiling. See [`IgnCollect::set_max_tile_px`].
+    max_tile_px: u32,
+    /// `MAXFEATURES` requested per WFS `GetFeature` page. See [`IgnCollect::set_page_size`].
+    page_size: usize,
+    /// Overall cap on features accumulated across all pages. See [`IgnCollect::set_max_features`].
+    max_features: Option<usize>,
+    /// EPSG code `execute_wfs`/`execute_wms` reproject `bbox` into before building request URLs.
+    /// See [`IgnCollect::set_bbox_crs`].
+    request_crs: u32,
+    /// `DescribeFeatureType` results fetched so far via [`IgnCollect::describe_feature_type`],
+    /// keyed by the `ign_keys` lookup key (not the typename) so callers can pass the same key
+    /// they already use everywhere else in this API.
+    describe_cache: HashMap<String, Vec<AttributeField>>,
+    /// Whether `execute_wfs`/`execute_wms` consult the on-disk response cache. See
+    /// [`IgnCollect::set_cache`].
+    cache_enabled: bool,
+    /// How long a cached response stays fresh; `None` means cached entries never expire on
+    /// their own (only [`IgnCollect::clear_cache`] removes them). See [`IgnCollect::set_cache`].
+    cache_ttl: Option<std::time::Duration>,
 }
 
 impl IgnCollect {
@@ -114,9 +212,162 @@ impl IgnCollect {
             geo_core: GeoCore::default(),
             df_csv_file,
             collect_path, // Store path for potential future use
+            capabilities_cache: HashMap::new(),
+            resolution: 1.0,
+            max_tile_px: 2048,
+            page_size: 10000,
+            max_features: None,
+            request_crs: 4326,
+            describe_cache: HashMap::new(),
+            cache_enabled: false,
+            cache_ttl: None,
         })
     }
 
+    /// Set the WMS `GetMap` resolution in meters/pixel (default 1.0). Smaller values produce
+    /// more, finer-grained tiles once the request exceeds [`set_max_tile_px`](Self::set_max_tile_px).
+    pub fn set_resolution(&mut self, resolution: f64) {
+        self.resolution = resolution;
+    }
+
+    /// Set the per-axis pixel cap above which [`IgnCollect::execute_wms`] splits the request
+    /// into tiles and mosaics the results (default 2048, a conservative bound under the
+    /// Geoplateforme's documented WMS size limits).
+    pub fn set_max_tile_px(&mut self, max_tile_px: u32) {
+        self.max_tile_px = max_tile_px;
+    }
+
+    /// Set the `MAXFEATURES` page size [`IgnCollect::execute_wfs`] requests per `GetFeature`
+    /// call (default 10000, the Geoplateforme's per-request cap). Pagination itself can't be
+    /// disabled -- set this to the server's max to page in as few round trips as possible.
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size;
+    }
+
+    /// Cap the total number of features [`IgnCollect::execute_wfs`] accumulates across all
+    /// pages (default `None`, meaning no cap -- page until the server reports no more results).
+    pub fn set_max_features(&mut self, max_features: Option<usize>) {
+        self.max_features = max_features;
+    }
+
+    /// Set the CRS (default EPSG:4326) `execute_wfs`/`execute_wms` send requests in. `bbox` is
+    /// still always supplied in EPSG:4326 via [`set_bbox`](Self::set_bbox) -- this reprojects it
+    /// into `epsg` with GDAL/PROJ before building request URLs, so e.g. French users can have
+    /// requests issued in Lambert-93 (`set_bbox_crs(2154)`) without reprojecting bboxes by hand.
+    pub fn set_bbox_crs(&mut self, epsg: u32) {
+        self.request_crs = epsg;
+    }
+
+    /// Whether `epsg` is a geographic (lat/lon) CRS, per GDAL/PROJ's CRS database. WMS 1.3.0
+    /// mandates the CRS's own native axis order in `Bbox=` -- lat/lon for geographic CRSs,
+    /// easting/northing for projected ones -- see [`IgnCollect::format_bbox_for_crs`].
+    fn is_geographic_crs(epsg: u32) -> Result<bool> {
+        let srs = gdal::spatial_ref::SpatialRef::from_epsg(epsg)
+            .with_context(|| format!("Failed to build spatial reference for EPSG:{}", epsg))?;
+        Ok(srs.is_geographic())
+    }
+
+    /// Format `bbox` (already reprojected into the request CRS) as an OGC envelope string in
+    /// the axis order that CRS mandates: geographic CRSs use lat/lon
+    /// (`min_y,min_x,max_y,max_x`), projected CRSs use easting/northing
+    /// (`min_x,min_y,max_x,max_y`). Shared by [`IgnCollect::execute_wfs`] and
+    /// [`IgnCollect::build_wms_request_url`] instead of special-casing individual layer keys.
+    fn format_bbox_for_crs(bbox: &BoundingBox, is_geographic: bool) -> String {
+        if is_geographic {
+            format!("{},{},{},{}", bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x)
+        } else {
+            format!("{},{},{},{}", bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y)
+        }
+    }
+
+    /// Enable or disable the on-disk request cache `execute_wfs`/`execute_wms` consult before
+    /// hitting the network, following the request-tuple caching MapProxy does: a hit on
+    /// (service, typename, bbox, CRS, resolution, filter) is served straight from
+    /// `TEMP_PATH/cache` instead of re-fetching. `ttl` bounds how long a cached entry stays
+    /// fresh; `None` means cached entries are reused forever until [`IgnCollect::clear_cache`].
+    pub fn set_cache(&mut self, enabled: bool, ttl: Option<std::time::Duration>) {
+        self.cache_enabled = enabled;
+        self.cache_ttl = ttl;
+    }
+
+    /// Remove every entry from the on-disk request cache.
+    pub fn clear_cache(&self) -> Result<()> {
+        let dir = Self::cache_dir();
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)
+                .with_context(|| format!("Failed to remove cache directory {:?}", dir))?;
+        }
+        Ok(())
+    }
+
+    fn cache_dir() -> PathBuf {
+        PathBuf::from(TEMP_PATH).join("cache")
+    }
+
+    /// Hash a request tuple's already-stringified parts into a stable cache key.
+    fn cache_key(parts: &[&str]) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        parts.join("|").hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    }
+
+    /// Return the cached payload for `key` if present and not past its TTL.
+    fn cache_lookup(&self, key: &str, ext: &str) -> Option<Vec<u8>> {
+        if !self.cache_enabled {
+            return None;
+        }
+
+        let payload_path = Self::cache_dir().join(format!("{}.{}", key, ext));
+        let sidecar_path = Self::cache_dir().join(format!("{}.meta.json", key));
+        let sidecar_bytes = std::fs::read(&sidecar_path).ok()?;
+        let sidecar: CacheSidecar = serde_json::from_slice(&sidecar_bytes).ok()?;
+
+        if let Some(ttl) = self.cache_ttl {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            if now.saturating_sub(sidecar.timestamp) > ttl.as_secs() {
+                return None;
+            }
+        }
+
+        std::fs::read(&payload_path).ok()
+    }
+
+    /// Write `bytes` to the on-disk cache under `key`, alongside a sidecar recording when it
+    /// was fetched and from where.
+    fn cache_store(&self, key: &str, ext: &str, bytes: &[u8], source_url: &str) -> Result<()> {
+        if !self.cache_enabled {
+            return Ok(());
+        }
+
+        let dir = Self::cache_dir();
+        create_dir_all(&dir)
+            .with_context(|| format!("Failed to create cache directory {:?}", dir))?;
+
+        let payload_path = dir.join(format!("{}.{}", key, ext));
+        write(&payload_path, bytes)
+            .with_context(|| format!("Failed to write cache entry {:?}", payload_path))?;
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let sidecar = CacheSidecar {
+            timestamp,
+            source_url: source_url.to_string(),
+        };
+        let sidecar_path = dir.join(format!("{}.meta.json", key));
+        write(
+            &sidecar_path,
+            serde_json::to_vec(&sidecar).context("Failed to serialize cache sidecar")?,
+        )
+        .with_context(|| format!("Failed to write cache sidecar {:?}", sidecar_path))?;
+
+        Ok(())
+    }
+
     /// Find CSV file in multiple possible locations, or download from IGN URL and cache in TEMP_PATH.
     fn find_csv_file() -> Result<PathBuf> {
         // 1) Dev: CARGO_MANIFEST_DIR
@@ -275,8 +526,11 @@ impl IgnCollect {
             // WFS request
             self.execute_wfs(&client, &url, &typename, &bbox, key)?;
         } else if key == "isochrone" {
-            // Isochrone request (POST with JSON)
-            anyhow::bail!("Isochrone requests require additional parameters (resource, costValue, point) - not yet implemented");
+            // Isochrone requests need a point and cost parameters `execute_ign`'s bbox-only
+            // signature has no room for -- use `fetch_isochrone` directly instead.
+            anyhow::bail!(
+                "Isochrone requests need a point and cost parameters -- call fetch_isochrone(IsochroneParams {{ .. }}) directly instead of execute_ign"
+            );
         } else {
             // WMS request (for ortho, dem, cosia, etc.)
             self.execute_wms(&client, &url, &typename, &bbox, key)?;
@@ -293,13 +547,32 @@ impl IgnCollect {
         url: &str,
         typename: &str,
         bbox: &BoundingBox,
-        _key: &str,
+        key: &str,
     ) -> Result<()> {
+        // Best-effort layer validation against the server's actual GetCapabilities -- logged,
+        // not fatal, since `df_csv_file`'s typenames have worked without it so far and a
+        // GetCapabilities outage shouldn't block requests that would otherwise succeed.
+        match self.get_layer(ServiceKind::Wfs, typename) {
+            Ok(None) => println!(
+                "Warning: {} is not advertised by the WFS GetCapabilities document",
+                typename
+            ),
+            Err(e) => println!("Warning: failed to validate {} against GetCapabilities: {}", typename, e),
+            Ok(Some(_)) => {}
+        }
+
         // Build filter XML if CQL filter is set (following Python logic)
         // Python: if self._cql_filter: Bbox = Bbox(Bbox=self._Bbox, crs="EPSG:4326")
         let filter_xml = if self.cql_filter.is_some() {
-            // For CQL filter, build Bbox filter XML
-            Some(self.build_bbox_filter_xml(bbox)?)
+            // Discover the layer's actual geometry property name via DescribeFeatureType rather
+            // than assuming "Geometry" -- best-effort, since a DescribeFeatureType outage
+            // shouldn't block a request whose CQL filter doesn't even reference geometry.
+            let geometry_property = self
+                .describe_feature_type(key)
+                .ok()
+                .and_then(|fields| fields.into_iter().find(|f| f.is_geometry).map(|f| f.name))
+                .unwrap_or_else(|| "Geometry".to_string());
+            Some(self.build_bbox_filter_xml(bbox, &geometry_property)?)
         } else {
             None
         };
@@ -307,7 +580,19 @@ impl IgnCollect {
         // Python: if filter_xml is set, self._Bbox = None (Bbox is in filter)
         let use_bbox_in_url = filter_xml.is_none();
 
-        // Build WFS GetFeature request following Python implementation
+        // `bbox` is always supplied in EPSG:4326 via set_bbox; reproject into the CRS the
+        // request itself should use (set_bbox_crs, default 4326 so this is a no-op).
+        let request_crs = self.request_crs;
+        let request_bbox = if request_crs == 4326 {
+            *bbox
+        } else {
+            bbox.transform(4326, request_crs as i32)
+                .context("Failed to reproject bbox into the WFS request CRS")?
+        };
+        let is_geographic = Self::is_geographic_crs(request_crs)?;
+
+        // Build the base WFS GetFeature request (everything but STARTINDEX/MAXFEATURES, which
+        // vary per page) following Python implementation.
         // Python: wfs2.getfeature(typename=typename, Bbox=self._Bbox, filter=self.filter_xml,
         //                          startindex=0, maxfeatures=10000, outputFormat="application/json")
         //
@@ -315,7 +600,7 @@ impl IgnCollect {
         // - WFS service: https://data.geopf.fr/wfs/ows (Service Géoplateforme de sélection WFS)
         // - Uses OGC WFS 2.0.0 standard
         // URL base from CSV should already have ?SERVICE=WFS&VERSION=2.0.0
-        let mut request_url = if url.contains('?') {
+        let mut base_url = if url.contains('?') {
             format!(
                 "{}&REQUEST=GetFeature&TYPENAMES={}&OUTPUTFORMAT=application/json",
                 url, typename
@@ -324,43 +609,124 @@ impl IgnCollect {
             format!("{}?SERVICE=WFS&VERSION=2.0.0&REQUEST=GetFeature&TYPENAMES={}&OUTPUTFORMAT=application/json", url, typename)
         };
 
-        // Add Bbox if not in filter (following Python: Bbox=self._Bbox)
+        // Add Bbox if not in filter (following Python: Bbox=self._Bbox), in the request CRS's
+        // native axis order (lat/lon for geographic CRSs, easting/northing for projected ones).
         if use_bbox_in_url {
-            request_url.push_str(&format!(
-                "&Bbox={},{},{},{}&CRS=EPSG:4326",
-                bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x,
+            base_url.push_str(&format!(
+                "&Bbox={}&CRS=EPSG:{}",
+                Self::format_bbox_for_crs(&request_bbox, is_geographic),
+                request_crs,
             ));
         }
 
         // Add filter if present (Python: filter=self.filter_xml)
         if let Some(ref filter_xml) = filter_xml {
-            request_url.push_str(&format!("&FILTER={}", urlencoding::encode(filter_xml)));
+            base_url.push_str(&format!("&FILTER={}", urlencoding::encode(filter_xml)));
         }
 
         // Store filter for potential future use
         self.filter_xml = filter_xml.clone();
 
-        // Add maxfeatures and startindex (following Python: startindex=0, maxfeatures=10000)
-        request_url.push_str("&STARTINDEX=0&MAXFEATURES=10000");
+        // Request-tuple cache key: service, typename, bbox, CRS, and filter (resolution doesn't
+        // apply to WFS). A hit serves the previously merged FeatureCollection straight from
+        // `TEMP_PATH/cache`, skipping pagination entirely.
+        let cache_key = Self::cache_key(&[
+            "wfs",
+            typename,
+            &format!(
+                "{:.6},{:.6},{:.6},{:.6}",
+                bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y
+            ),
+            &request_crs.to_string(),
+            filter_xml.as_deref().unwrap_or(""),
+        ]);
+        if let Some(cached) = self.cache_lookup(&cache_key, "json") {
+            self.content = Some(cached);
+            return Ok(());
+        }
 
-        println!("Request URL WFS: {}", request_url);
+        // Page through GetFeature with STARTINDEX/MAXFEATURES until a page comes back short (or
+        // a WFS 2.0.0 numberReturned count confirms it), the caller's max_features is reached,
+        // or a page fails -- a failed first page is a hard error, a failed later page just ends
+        // pagination with whatever was accumulated so far.
+        let mut all_features: Vec<geojson::Feature> = Vec::new();
+        let mut start_index = 0usize;
+        loop {
+            let remaining = self
+                .max_features
+                .map(|cap| cap.saturating_sub(all_features.len()));
+            if remaining == Some(0) {
+                break;
+            }
+            let page_limit = remaining.map(|r| r.min(self.page_size)).unwrap_or(self.page_size);
 
-        let response = client
-            .get(&request_url)
-            .send()
-            .context("Failed to send WFS request to IGN API")?;
+            let request_url = format!(
+                "{}&STARTINDEX={}&MAXFEATURES={}",
+                base_url, start_index, page_limit
+            );
+            println!("Request URL WFS: {}", request_url);
+
+            let response = client
+                .get(&request_url)
+                .send()
+                .context("Failed to send WFS request to IGN API")?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().unwrap_or_default();
+                if start_index == 0 {
+                    anyhow::bail!("IGN API returned error {}: {}", status, body);
+                }
+                println!(
+                    "Warning: WFS page at STARTINDEX={} returned {}, stopping pagination with {} features already collected",
+                    start_index, status, all_features.len()
+                );
+                break;
+            }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().unwrap_or_default();
-            anyhow::bail!("IGN API returned error {}: {}", status, body);
-        }
+            let body = response.text().context("Failed to read response body")?;
+            let page = match body.parse::<geojson::GeoJson>() {
+                Ok(geojson::GeoJson::FeatureCollection(fc)) => fc,
+                Ok(_) => {
+                    anyhow::bail!("WFS GetFeature returned GeoJSON that isn't a FeatureCollection");
+                }
+                Err(e) => {
+                    if start_index == 0 {
+                        return Err(e).context("WFS GetFeature did not return valid GeoJSON (likely an XML exception report)");
+                    }
+                    println!(
+                        "Warning: WFS page at STARTINDEX={} did not return valid GeoJSON ({}), stopping pagination with {} features already collected",
+                        start_index, e, all_features.len()
+                    );
+                    break;
+                }
+            };
 
-        let content_bytes = response
-            .bytes()
-            .context("Failed to read response body")?
-            .to_vec();
+            let number_returned = page
+                .foreign_members
+                .as_ref()
+                .and_then(|m| m.get("numberReturned"))
+                .and_then(|v| v.as_u64());
+
+            let page_count = page.features.len();
+            all_features.extend(page.features);
+            start_index += page_count;
+
+            let page_exhausted = page_count < page_limit
+                || number_returned.is_some_and(|n| (n as usize) < page_limit)
+                || page_count == 0;
+            if page_exhausted {
+                break;
+            }
+        }
 
+        let merged = geojson::FeatureCollection {
+            bbox: None,
+            features: all_features,
+            foreign_members: None,
+        };
+        let content_bytes = geojson::GeoJson::from(merged).to_string().into_bytes();
+        self.cache_store(&cache_key, "json", &content_bytes, &base_url)?;
         self.content = Some(content_bytes);
         Ok(())
     }
@@ -376,7 +742,7 @@ impl IgnCollect {
     ) -> Result<()> {
         // For WMS, calculate image dimensions based on resolution
         // Python uses: resolution = kwargs.get("resolution") or 1.0
-        let resolution = 1.0; // Default resolution in meters/pixel
+        let resolution = self.resolution;
 
         // Calculate center (Python: lon_center = (xmin + xmax) / 2)
         // let _lon_center = (bbox.min_x + bbox.max_x) / 2.0;
@@ -394,21 +760,36 @@ impl IgnCollect {
         let width_px = (width_m / resolution) as u32;
         let height_px = (height_m / resolution) as u32;
 
-        // For WMS 1.3.0 with EPSG:4326, Bbox order is inverted for ortho and dem
-        // Python: if key == "ortho" and version == "1.3.0" and crs == "EPSG:4326": Bbox_str = [ymin, xmin, ymax, xmax]
-        // Python for dem: "Bbox": f"{self._Bbox[1]},{self._Bbox[0]},{self._Bbox[3]},{self._Bbox[2]}"
-        // This means: [ymin, xmin, ymax, xmax]
-        let bbox_str = if matches!(key, "ortho" | "dem" | "cosia") {
-            format!(
-                "{},{},{},{}",
-                bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x
-            )
+        // `bbox` is always supplied in EPSG:4326 via set_bbox; reproject into the CRS the
+        // request itself should use (set_bbox_crs, default 4326 so this is a no-op). Pixel
+        // dimensions above stay derived from the original (geographic) bbox, since the
+        // deg→m resolution formula only makes sense in degrees.
+        let request_crs = self.request_crs;
+        let request_bbox = if request_crs == 4326 {
+            *bbox
         } else {
-            format!(
-                "{},{},{},{}",
-                bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y
-            )
+            bbox.transform(4326, request_crs as i32)
+                .context("Failed to reproject bbox into the WMS request CRS")?
         };
+        let is_geographic = Self::is_geographic_crs(request_crs)?;
+
+        // Request-tuple cache key: service, typename, bbox, CRS, and resolution (WMS has no
+        // filter). A hit is served straight from `TEMP_PATH/cache`, skipping the fetch -- and
+        // any tiling/mosaicking -- entirely.
+        let cache_key = Self::cache_key(&[
+            "wms",
+            typename,
+            &format!(
+                "{:.6},{:.6},{:.6},{:.6}",
+                bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y
+            ),
+            &request_crs.to_string(),
+            &resolution.to_string(),
+        ]);
+        if let Some(cached) = self.cache_lookup(&cache_key, "tiff") {
+            self.content = Some(cached);
+            return Ok(());
+        }
 
         // Build WMS GetMap request following Python implementation
         // Python: wms.getmap(layers=[typename], srs=crs, crs=crs, Bbox=Bbox_str,
@@ -423,15 +804,169 @@ impl IgnCollect {
         // For DEM and raster services, use wms-r endpoint:
         // https://data.geopf.fr/wms-r?LAYERS={couche}&FORMAT={format}&SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&STYLES=&CRS={crs}&Bbox={Xmin,Ymin,Xmax,Ymax}&WIDTH={largeur}&HEIGHT={hauteur}
 
+        // The Geoplateforme rejects GetMap requests whose WIDTH/HEIGHT exceed its pixel cap, so
+        // split into a tile grid and mosaic the results whenever the full request would not fit.
+        let content_bytes = if width_px > self.max_tile_px || height_px > self.max_tile_px {
+            self.fetch_wms_tiled(
+                client, url, typename, &request_bbox, key, width_px, height_px, request_crs,
+                is_geographic,
+            )?
+        } else {
+            let request_url = Self::build_wms_request_url(
+                url,
+                typename,
+                &request_bbox,
+                key,
+                width_px,
+                height_px,
+                request_crs,
+                is_geographic,
+            );
+            Self::fetch_wms_tile(client, &request_url)?
+        };
+
+        // For some keys, save to file and validate as GeoTIFF
+        if matches!(key, "irc" | "dem" | "cosia") {
+            let output_path = PathBuf::from(TEMP_PATH).join(format!("{}.tiff", key));
+
+            // Create directory if it doesn't exist
+            if let Some(parent) = output_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .context(format!("Failed to create directory: {:?}", parent))?;
+            }
+
+            // Write file first
+            std::fs::write(&output_path, &content_bytes)
+                .context(format!("Failed to write file: {:?}", output_path))?;
+
+            // Validate that it's a valid GeoTIFF using GDAL (more reliable than geotiff crate)
+            // GDAL can read a wider variety of GeoTIFF formats
+            match gdal::Dataset::open(&output_path) {
+                Ok(_dataset) => {
+                    println!("Saved and validated GeoTIFF: {:?}", output_path);
+                }
+                Err(e) => {
+                    eprintln!("Warning: File saved but GeoTIFF validation failed: {}", e);
+                    eprintln!("  File may still be valid but GDAL couldn't read it");
+                }
+            }
+        }
+
+        self.cache_store(&cache_key, "tiff", &content_bytes, url)?;
+        self.content = Some(content_bytes);
+        Ok(())
+    }
+
+    /// Fetch one or more isochrone/isodistance polygons from IGN's Valhalla routing service (the
+    /// `isochrone` key's `bdtopo-valhalla` resource). Unlike `execute_wfs`/`execute_wms` this
+    /// POSTs a JSON body rather than building a query string, per the Géoplateforme navigation
+    /// API -- so it takes its own [`IsochroneParams`] rather than going through `execute_ign`.
+    pub fn fetch_isochrone(&mut self, params: &IsochroneParams) -> Result<()> {
+        if params.cost_values.is_empty() || params.cost_values.iter().any(|&v| v <= 0.0) {
+            anyhow::bail!(
+                "Isochrone cost_values must be non-empty and all positive, got {:?}",
+                params.cost_values
+            );
+        }
+
+        let (lon, lat) = params.point;
+        if !BoundingBox::metropolitan_france().contains_point(lon, lat) {
+            anyhow::bail!(
+                "Isochrone point ({}, {}) falls outside metropolitan France's bounds",
+                lon,
+                lat
+            );
+        }
+
+        let resource = self
+            .ign_keys
+            .get("isochrone")
+            .context("Unknown IGN key: isochrone")?
+            .clone();
+
+        let row = self
+            .get_row_ressource("isochrone")
+            .context("No CSV row found for key: isochrone")?;
+
+        // Same base-URL extraction `execute_ign` uses: the CSV's Géoplateforme URL carries a
+        // trailing GetCapabilities request we don't want here.
+        let url = if row.url_geoplateforme.contains("&REQUEST=GetCapabilities") {
+            row.url_geoplateforme
+                .split("&REQUEST=GetCapabilities")
+                .next()
+                .unwrap_or(&row.url_geoplateforme)
+                .to_string()
+        } else if row.url_geoplateforme.contains('?') {
+            row.url_geoplateforme
+                .split('?')
+                .next()
+                .unwrap_or(&row.url_geoplateforme)
+                .to_string()
+        } else {
+            row.url_geoplateforme.clone()
+        };
+
+        let body = serde_json::json!({
+            "resource": resource,
+            "point": [lon, lat],
+            "costType": params.cost_type.as_str(),
+            "costValue": params.cost_values,
+            "profile": params.profile.as_str(),
+            "direction": params.direction.as_str(),
+        });
+
+        println!("Isochrone request URL: {} body: {}", url, body);
+
+        let client = Client::new();
+        let response = client
+            .post(&url)
+            .json(&body)
+            .send()
+            .context("Failed to send isochrone request to IGN Valhalla API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().unwrap_or_default();
+            anyhow::bail!("IGN isochrone API returned error {}: {}", status, text);
+        }
+
+        let text = response
+            .text()
+            .context("Failed to read isochrone response body")?;
+        text.parse::<geojson::GeoJson>()
+            .context("Isochrone response did not return valid GeoJSON")?;
+
+        self.content = Some(text.into_bytes());
+        Ok(())
+    }
+
+    /// Build a WMS 1.3.0 `GetMap` URL for `typename` over `bbox` (already reprojected into
+    /// `crs_epsg`) at `width_px`x`height_px`, following the per-key endpoint quirks
+    /// [`IgnCollect::execute_wms`] has always used. Axis order follows `crs_epsg` itself rather
+    /// than special-casing individual layer keys -- see [`IgnCollect::format_bbox_for_crs`].
+    /// Shared with [`IgnCollect::fetch_wms_tiled`] so a tile's URL is built exactly the same way
+    /// as a non-tiled request's.
+    fn build_wms_request_url(
+        url: &str,
+        typename: &str,
+        bbox: &BoundingBox,
+        key: &str,
+        width_px: u32,
+        height_px: u32,
+        crs_epsg: u32,
+        is_geographic: bool,
+    ) -> String {
+        let bbox_str = Self::format_bbox_for_crs(bbox, is_geographic);
+
         // Build GetMap request according to OGC WMS 1.3.0 specification
         // Required parameters: SERVICE, VERSION, REQUEST, LAYERS, CRS, Bbox, WIDTH, HEIGHT, FORMAT
         // Optional: STYLES, TRANSPARENT, EXCEPTIONS
-        let request_url = if matches!(key, "dem" | "irc" | "cosia" | "dsm") {
+        if matches!(key, "dem" | "irc" | "cosia" | "dsm") {
             // For DEM and other raster services, use wms-r endpoint with exact format
             // Format: LAYERS={couche}&FORMAT={format}&SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&STYLES=&CRS={crs}&Bbox={Xmin,Ymin,Xmax,Ymax}&WIDTH={largeur}&HEIGHT={hauteur}
             format!(
-                "https://data.geopf.fr/wms-r/wms?LAYERS={}&FORMAT=image/geotiff&SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&STYLES=&CRS=EPSG:4326&Bbox={}&WIDTH={}&HEIGHT={}",
-                typename, bbox_str, width_px, height_px
+                "https://data.geopf.fr/wms-r/wms?LAYERS={}&FORMAT=image/geotiff&SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&STYLES=&CRS=EPSG:{}&Bbox={}&WIDTH={}&HEIGHT={}",
+                typename, crs_epsg, bbox_str, width_px, height_px
             )
         } else {
             // For other services, use URL from CSV with original format
@@ -441,15 +976,18 @@ impl IgnCollect {
                 format!("{}?", url)
             };
             format!(
-                "{}SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&LAYERS={}&CRS=EPSG:4326&Bbox={}&WIDTH={}&HEIGHT={}&FORMAT=image/geotiff&TRANSPARENT=true&STYLES=normal&EXCEPTIONS=text/xml",
-                base_url, typename, bbox_str, width_px, height_px
+                "{}SERVICE=WMS&VERSION=1.3.0&REQUEST=GetMap&LAYERS={}&CRS=EPSG:{}&Bbox={}&WIDTH={}&HEIGHT={}&FORMAT=image/geotiff&TRANSPARENT=true&STYLES=normal&EXCEPTIONS=text/xml",
+                base_url, typename, crs_epsg, bbox_str, width_px, height_px
             )
-        };
+        }
+    }
 
+    /// Issue a single WMS `GetMap` request and return the raw response bytes.
+    fn fetch_wms_tile(client: &Client, request_url: &str) -> Result<Vec<u8>> {
         println!("Request URL WMS: {}", request_url);
 
         let response = client
-            .get(&request_url)
+            .get(request_url)
             .send()
             .context("Failed to send WMS request to IGN API")?;
 
@@ -457,46 +995,210 @@ impl IgnCollect {
             anyhow::bail!("IGN API returned error: {}", response.status());
         }
 
-        let content_bytes = response
+        Ok(response
             .bytes()
             .context("Failed to read response body")?
-            .to_vec();
+            .to_vec())
+    }
 
-        // For some keys, save to file and validate as GeoTIFF
-        if matches!(key, "irc" | "dem" | "cosia") {
-            let output_path = PathBuf::from(TEMP_PATH).join(format!("{}.tiff", key));
+    /// Fetch a WMS `GetMap` request too large for a single call (per [`set_max_tile_px`]
+    /// (Self::set_max_tile_px)) by splitting `bbox` into a grid of sub-tiles, fetching each
+    /// concurrently, and mosaicking the results into one GeoTIFF via GDAL. Used by
+    /// [`IgnCollect::execute_wms`] whenever `width_px`/`height_px` exceed the cap.
+    fn fetch_wms_tiled(
+        &self,
+        client: &Client,
+        url: &str,
+        typename: &str,
+        bbox: &BoundingBox,
+        key: &str,
+        width_px: u32,
+        height_px: u32,
+        crs_epsg: u32,
+        is_geographic: bool,
+    ) -> Result<Vec<u8>> {
+        let tiles = Self::plan_wms_tiles(bbox, width_px, height_px, self.max_tile_px);
+        println!(
+            "WMS request for {} ({}x{}px) exceeds max_tile_px={}, splitting into {} tiles",
+            typename,
+            width_px,
+            height_px,
+            self.max_tile_px,
+            tiles.len()
+        );
 
-            // Create directory if it doesn't exist
-            if let Some(parent) = output_path.parent() {
-                std::fs::create_dir_all(parent)
-                    .context(format!("Failed to create directory: {:?}", parent))?;
-            }
+        create_dir_all(TEMP_PATH).context("Failed to create temp directory for WMS tiles")?;
 
-            // Write file first
-            std::fs::write(&output_path, &content_bytes)
-                .context(format!("Failed to write file: {:?}", output_path))?;
+        let fetch_tile = |(index, tile): (usize, &WmsTile)| -> Result<(WmsTile, PathBuf)> {
+            let request_url = Self::build_wms_request_url(
+                url, typename, &tile.bbox, key, tile.width_px, tile.height_px, crs_epsg,
+                is_geographic,
+            );
+            let bytes = Self::fetch_wms_tile(client, &request_url).with_context(|| {
+                format!(
+                    "Failed to fetch WMS tile {} at px ({}, {})",
+                    index, tile.px_x, tile.px_y
+                )
+            })?;
+            let tile_path = PathBuf::from(TEMP_PATH).join(format!("{}_tile_{}.tiff", key, index));
+            std::fs::write(&tile_path, &bytes)
+                .with_context(|| format!("Failed to write WMS tile to {:?}", tile_path))?;
+            Ok((*tile, tile_path))
+        };
 
-            // Validate that it's a valid GeoTIFF using GDAL (more reliable than geotiff crate)
-            // GDAL can read a wider variety of GeoTIFF formats
-            match gdal::Dataset::open(&output_path) {
-                Ok(_dataset) => {
-                    println!("Saved and validated GeoTIFF: {:?}", output_path);
-                }
-                Err(e) => {
-                    eprintln!("Warning: File saved but GeoTIFF validation failed: {}", e);
-                    eprintln!("  File may still be valid but GDAL couldn't read it");
-                }
+        #[cfg(feature = "rayon")]
+        let tile_paths: Vec<(WmsTile, PathBuf)> = tiles
+            .par_iter()
+            .enumerate()
+            .map(fetch_tile)
+            .collect::<Result<Vec<_>>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let tile_paths: Vec<(WmsTile, PathBuf)> = tiles
+            .iter()
+            .enumerate()
+            .map(fetch_tile)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mosaic_path = PathBuf::from(TEMP_PATH).join(format!("{}_mosaic.tiff", key));
+        Self::mosaic_geotiff_tiles(
+            &tile_paths,
+            width_px as usize,
+            height_px as usize,
+            bbox,
+            crs_epsg,
+            &mosaic_path,
+        )?;
+
+        let mosaic_bytes = std::fs::read(&mosaic_path)
+            .with_context(|| format!("Failed to read mosaicked GeoTIFF at {:?}", mosaic_path))?;
+
+        for (_, tile_path) in &tile_paths {
+            let _ = std::fs::remove_file(tile_path);
+        }
+
+        Ok(mosaic_bytes)
+    }
+
+    /// Split a `width_px`x`height_px` WMS request into a grid of tiles each within
+    /// `max_tile_px`, used by [`IgnCollect::fetch_wms_tiled`]. Tile extents are derived directly
+    /// from the pixel grid (rather than re-deriving lon/lat per tile independently) so adjacent
+    /// tiles share an exact pixel boundary with no seam drift. Pure and network-free so it can
+    /// be unit tested directly.
+    fn plan_wms_tiles(bbox: &BoundingBox, width_px: u32, height_px: u32, max_tile_px: u32) -> Vec<WmsTile> {
+        let tiles_x = width_px.div_ceil(max_tile_px).max(1);
+        let tiles_y = height_px.div_ceil(max_tile_px).max(1);
+
+        let mut tiles = Vec::with_capacity((tiles_x * tiles_y) as usize);
+        for ty in 0..tiles_y {
+            let row_start = ty * max_tile_px;
+            let row_end = ((ty + 1) * max_tile_px).min(height_px);
+
+            for tx in 0..tiles_x {
+                let col_start = tx * max_tile_px;
+                let col_end = ((tx + 1) * max_tile_px).min(width_px);
+
+                // Image rows run top (max_y) to bottom (min_y), matching the mosaic's north-up grid.
+                let min_x = bbox.min_x + (col_start as f64 / width_px as f64) * (bbox.max_x - bbox.min_x);
+                let max_x = bbox.min_x + (col_end as f64 / width_px as f64) * (bbox.max_x - bbox.min_x);
+                let max_y = bbox.max_y - (row_start as f64 / height_px as f64) * (bbox.max_y - bbox.min_y);
+                let min_y = bbox.max_y - (row_end as f64 / height_px as f64) * (bbox.max_y - bbox.min_y);
+
+                tiles.push(WmsTile {
+                    bbox: BoundingBox::new(min_x, min_y, max_x, max_y),
+                    px_x: col_start,
+                    px_y: row_start,
+                    width_px: col_end - col_start,
+                    height_px: row_end - row_start,
+                });
+            }
+        }
+        tiles
+    }
+
+    /// Mosaic `tile_paths` (each a GeoTIFF at a known pixel offset within the full
+    /// `width`x`height` raster) into a single GeoTIFF in EPSG:`crs_epsg` at `output_path`, reading and
+    /// writing band-by-band via GDAL. Composites by direct pixel-offset writes rather than
+    /// building a VRT -- with no Cargo.toml in this tree to pin a gdal-rs version/feature set,
+    /// there's no way to confirm a VRT-building API is actually available.
+    fn mosaic_geotiff_tiles(
+        tile_paths: &[(WmsTile, PathBuf)],
+        width: usize,
+        height: usize,
+        bbox: &BoundingBox,
+        crs_epsg: u32,
+        output_path: &Path,
+    ) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::spatial_ref::SpatialRef;
+        use gdal::{Dataset, DriverManager};
+
+        let (_, first_tile_path) = tile_paths.first().context("No WMS tiles to mosaic")?;
+        let first_dataset = Dataset::open(first_tile_path).context("Failed to open first WMS tile")?;
+        let band_count = first_dataset.raster_count();
+
+        let driver = DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut mosaic = driver
+            .create_with_band_type_with_options::<u8, _>(
+                output_path,
+                width,
+                height,
+                band_count as usize,
+                &creation_options,
+            )
+            .with_context(|| format!("Failed to create mosaic raster at {:?}", output_path))?;
+
+        let pixel_width = (bbox.max_x - bbox.min_x) / width as f64;
+        let pixel_height = (bbox.max_y - bbox.min_y) / height as f64;
+        mosaic
+            .set_geo_transform(&[bbox.min_x, pixel_width, 0.0, bbox.max_y, 0.0, -pixel_height])
+            .context("Failed to set mosaic geotransform")?;
+        mosaic
+            .set_spatial_ref(
+                &SpatialRef::from_epsg(crs_epsg)
+                    .with_context(|| format!("Failed to build EPSG:{} spatial reference", crs_epsg))?,
+            )
+            .context("Failed to set mosaic spatial reference")?;
+
+        for band_index in 1..=band_count {
+            let mut mosaic_band = mosaic
+                .rasterband(band_index)
+                .with_context(|| format!("Mosaic raster has no band {}", band_index))?;
+
+            for (tile, tile_path) in tile_paths {
+                let tile_dataset = Dataset::open(tile_path)
+                    .with_context(|| format!("Failed to open WMS tile {:?}", tile_path))?;
+                let tile_band = tile_dataset
+                    .rasterband(band_index)
+                    .with_context(|| format!("WMS tile {:?} has no band {}", tile_path, band_index))?;
+                let mut buffer: Buffer<u8> = tile_band
+                    .read_as(
+                        (0, 0),
+                        tile_band.size(),
+                        (tile.width_px as usize, tile.height_px as usize),
+                        None,
+                    )
+                    .with_context(|| format!("Failed to read WMS tile {:?}", tile_path))?;
+                mosaic_band
+                    .write(
+                        (tile.px_x as isize, tile.px_y as isize),
+                        (tile.width_px as usize, tile.height_px as usize),
+                        &mut buffer,
+                    )
+                    .with_context(|| format!("Failed to write WMS tile {:?} into mosaic", tile_path))?;
             }
         }
 
-        self.content = Some(content_bytes);
         Ok(())
     }
 
     /// Build Bbox filter XML following Python implementation
     /// Python: Bbox = Bbox(Bbox=self._Bbox, crs="EPSG:4326")
     ///         self.filter_xml = ElementTree.tostring(Bbox.toXML(), encoding="ascii", method="xml", xml_declaration=True).decode("utf-8")
-    fn build_bbox_filter_xml(&self, bbox: &BoundingBox) -> Result<String> {
+    fn build_bbox_filter_xml(&self, bbox: &BoundingBox, geometry_property: &str) -> Result<String> {
         // Build XML filter for Bbox following OGC Filter Encoding (OGC FES 2.0)
         // Python uses owslib.fes2.Bbox which generates XML like:
         // <ogc:Bbox xmlns:ogc="http://www.opengis.net/ogc">
@@ -509,14 +1211,14 @@ impl IgnCollect {
         let filter_xml = format!(
             r#"<ogc:Filter xmlns:ogc="http://www.opengis.net/ogc" xmlns:gml="http://www.opengis.net/gml">
     <ogc:Bbox>
-        <ogc:PropertyName>Geometry</ogc:PropertyName>
+        <ogc:PropertyName>{}</ogc:PropertyName>
         <gml:Envelope srsName="EPSG:4326">
             <gml:lowerCorner>{} {}</gml:lowerCorner>
             <gml:upperCorner>{} {}</gml:upperCorner>
         </gml:Envelope>
     </ogc:Bbox>
 </ogc:Filter>"#,
-            bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x
+            geometry_property, bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x
         );
         Ok(filter_xml)
     }
@@ -542,6 +1244,350 @@ impl IgnCollect {
         let content = self.content.as_ref().context("No content available")?;
         String::from_utf8(content.clone()).context("Content is not valid UTF-8")
     }
+
+    /// Issue a `GetCapabilities` request against `data.geopf.fr`'s `service` and parse it into
+    /// one [`LayerMeta`] per advertised layer/feature type. A dynamically discovered
+    /// alternative to the hand-maintained `ign_keys` map plus `df_csv_file`, which breaks
+    /// whenever IGN rotates the services CSV's file name/date.
+    pub fn capabilities(service: ServiceKind) -> Result<Vec<LayerMeta>> {
+        let url = format!(
+            "https://data.geopf.fr/{}?SERVICE={}&VERSION={}&REQUEST=GetCapabilities",
+            service.base_path(),
+            service.service_param(),
+            service.version_param(),
+        );
+
+        let client = Client::new();
+        let response = client
+            .get(&url)
+            .send()
+            .with_context(|| format!("Failed to request GetCapabilities from {}", url))?;
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "GetCapabilities request to {} returned {}",
+                url,
+                response.status()
+            );
+        }
+        let body = response
+            .text()
+            .with_context(|| format!("Failed to read GetCapabilities response from {}", url))?;
+
+        Self::parse_capabilities(&body, service)
+    }
+
+    /// Fetch (and cache) `service`'s capabilities, then look up `name` among its advertised
+    /// layers/feature types. `execute_wfs` uses it to warn when a `typename` is not advertised;
+    /// `execute_wms` does not call it, and neither method picks a CRS from the advertised list.
+    pub fn get_layer(&mut self, service: ServiceKind, name: &str) -> Result<Option<LayerMeta>> {
+        if let std::collections::hash_map::Entry::Vacant(entry) =
+            self.capabilities_cache.entry(service)
+        {
+            entry.insert(Self::capabilities(service)?);
+        }
+
+        Ok(self
+            .capabilities_cache
+            .get(&service)
+            .and_then(|layers| layers.iter().find(|layer| layer.name == name))
+            .cloned())
+    }
+
+    /// Parse a `GetCapabilities` XML document into its advertised layers, shared by
+    /// [`IgnCollect::capabilities`] (and directly testable without a network request).
+    fn parse_capabilities(xml: &str, service: ServiceKind) -> Result<Vec<LayerMeta>> {
+        let document =
+            roxmltree::Document::parse(xml).context("Failed to parse GetCapabilities XML")?;
+        let layer_tag = service.layer_element_name();
+
+        let mut layers = Vec::new();
+        for node in document.descendants().filter(|n| n.has_tag_name(layer_tag)) {
+            // Skip the root WMS <Layer> container, which has no <Name> of its own, and keep
+            // only leaf layers (a WFS FeatureType is always a leaf).
+            let Some(name) = node
+                .children()
+                .find(|c| c.has_tag_name("Name"))
+                .and_then(|c| c.text())
+            else {
+                continue;
+            };
+
+            let title = node
+                .children()
+                .find(|c| c.has_tag_name("Title"))
+                .and_then(|c| c.text())
+                .map(|s| s.to_string());
+
+            let queryable = match service {
+                ServiceKind::Wms => node.attribute("queryable") == Some("1"),
+                ServiceKind::Wfs | ServiceKind::Wmts => true,
+            };
+
+            let crs_tags: &[&str] = match service {
+                ServiceKind::Wfs => &["DefaultCRS", "OtherCRS"],
+                ServiceKind::Wms => &["CRS", "SRS"],
+                // WMTS advertises CRS per TileMatrixSet rather than per layer.
+                ServiceKind::Wmts => &[],
+            };
+            let crs: Vec<String> = node
+                .children()
+                .filter(|c| crs_tags.contains(&c.tag_name().name()))
+                .filter_map(|c| c.text())
+                .map(|s| s.to_string())
+                .collect();
+
+            let wgs84_bbox = node
+                .children()
+                .find(|c| c.has_tag_name("WGS84BoundingBox"))
+                .and_then(Self::parse_wgs84_bbox);
+
+            let styles: Vec<String> = node
+                .children()
+                .filter(|c| c.has_tag_name("Style"))
+                .filter_map(|style_node| {
+                    style_node
+                        .children()
+                        .find(|c| c.has_tag_name("Name"))
+                        .and_then(|c| c.text())
+                        .map(|s| s.to_string())
+                })
+                .collect();
+
+            layers.push(LayerMeta {
+                name: name.to_string(),
+                title,
+                queryable,
+                crs,
+                wgs84_bbox,
+                styles,
+            });
+        }
+
+        Ok(layers)
+    }
+
+    /// Parse an OGC `<ows:WGS84BoundingBox>` element's `<LowerCorner>`/`<UpperCorner>` pair into
+    /// a [`BoundingBox`].
+    fn parse_wgs84_bbox(node: roxmltree::Node) -> Option<BoundingBox> {
+        let lower = node
+            .children()
+            .find(|c| c.has_tag_name("LowerCorner"))
+            .and_then(|c| c.text())?;
+        let upper = node
+            .children()
+            .find(|c| c.has_tag_name("UpperCorner"))
+            .and_then(|c| c.text())?;
+
+        let mut lower_parts = lower.split_whitespace();
+        let min_x: f64 = lower_parts.next()?.parse().ok()?;
+        let min_y: f64 = lower_parts.next()?.parse().ok()?;
+        let mut upper_parts = upper.split_whitespace();
+        let max_x: f64 = upper_parts.next()?.parse().ok()?;
+        let max_y: f64 = upper_parts.next()?.parse().ok()?;
+
+        Some(BoundingBox::new(min_x, min_y, max_x, max_y))
+    }
+
+    /// Fetch (and cache) `key`'s attribute schema via a WFS `DescribeFeatureType` request. Lets
+    /// callers discover which property names a layer actually exposes -- in particular the
+    /// geometry property name (used by [`IgnCollect::build_bbox_filter_xml`] instead of the
+    /// hardcoded `"Geometry"`) and the column names a CQL filter may reference (see
+    /// [`IgnCollect::validate_cql_filter`]).
+    pub fn describe_feature_type(&mut self, key: &str) -> Result<Vec<AttributeField>> {
+        if let Some(fields) = self.describe_cache.get(key) {
+            return Ok(fields.clone());
+        }
+
+        let typename = self
+            .ign_keys
+            .get(key)
+            .context(format!("Unknown IGN key: {}", key))?
+            .clone();
+
+        let url = format!(
+            "https://data.geopf.fr/wfs/ows?SERVICE=WFS&VERSION=2.0.0&REQUEST=DescribeFeatureType&TYPENAME={}",
+            typename
+        );
+
+        let client = Client::new();
+        let response = client
+            .get(&url)
+            .send()
+            .with_context(|| format!("Failed to request DescribeFeatureType from {}", url))?;
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "DescribeFeatureType request to {} returned {}",
+                url,
+                response.status()
+            );
+        }
+        let body = response
+            .text()
+            .with_context(|| format!("Failed to read DescribeFeatureType response from {}", url))?;
+
+        let fields = Self::parse_describe_feature_type(&body)?;
+        self.describe_cache.insert(key.to_string(), fields.clone());
+        Ok(fields)
+    }
+
+    /// Parse a `DescribeFeatureType` XSD schema into its feature type's attributes, shared by
+    /// [`IgnCollect::describe_feature_type`] (and directly testable without a network request).
+    /// A field is treated as the geometry property when its XSD type is a `gml:*PropertyType`
+    /// (e.g. `gml:MultiPolygonPropertyType`), the convention GeoServer/Geoplateforme schemas use.
+    fn parse_describe_feature_type(xml: &str) -> Result<Vec<AttributeField>> {
+        let document =
+            roxmltree::Document::parse(xml).context("Failed to parse DescribeFeatureType XML")?;
+
+        let mut fields = Vec::new();
+        for sequence in document.descendants().filter(|n| n.has_tag_name("sequence")) {
+            for element in sequence.children().filter(|c| c.has_tag_name("element")) {
+                let Some(name) = element.attribute("name") else {
+                    continue;
+                };
+                let xsd_type = element.attribute("type").unwrap_or("").to_string();
+                let is_geometry = xsd_type.starts_with("gml:") && xsd_type.ends_with("PropertyType");
+
+                fields.push(AttributeField {
+                    name: name.to_string(),
+                    xsd_type,
+                    is_geometry,
+                });
+            }
+        }
+
+        Ok(fields)
+    }
+
+    /// Validate that every column name referenced in `cql_filter` is an attribute `key` actually
+    /// exposes (per [`IgnCollect::describe_feature_type`]), returning a clear error naming the
+    /// first unknown attribute instead of letting the server reject the request with an opaque
+    /// CQL parse error. A lightweight tokenizer, not a full CQL grammar: it skips single-quoted
+    /// string literals, known CQL keywords, and numeric-leading tokens, and treats every other
+    /// identifier-looking token as a column reference.
+    pub fn validate_cql_filter(&mut self, key: &str, cql_filter: &str) -> Result<()> {
+        const CQL_KEYWORDS: &[&str] = &[
+            "AND", "OR", "NOT", "IS", "NULL", "LIKE", "ILIKE", "BETWEEN", "IN", "TRUE", "FALSE",
+        ];
+
+        let fields = self.describe_feature_type(key)?;
+        let known: std::collections::HashSet<&str> =
+            fields.iter().map(|f| f.name.as_str()).collect();
+
+        let chars: Vec<char> = cql_filter.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '\'' {
+                // Skip over the quoted string literal's contents.
+                i += 1;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+            if c.is_alphabetic() || c == '_' {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let token: String = chars[start..i].iter().collect();
+                let upper = token.to_uppercase();
+                if !CQL_KEYWORDS.contains(&upper.as_str()) && !known.contains(token.as_str()) {
+                    anyhow::bail!(
+                        "Unknown attribute '{}' in CQL filter for key '{}' -- known attributes: {}",
+                        token,
+                        key,
+                        fields
+                            .iter()
+                            .map(|f| f.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+                continue;
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// WFS/WMS/WMTS service kind for [`IgnCollect::capabilities`]/[`IgnCollect::get_layer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceKind {
+    Wfs,
+    Wms,
+    Wmts,
+}
+
+impl ServiceKind {
+    fn base_path(self) -> &'static str {
+        match self {
+            ServiceKind::Wfs => "wfs/ows",
+            ServiceKind::Wms => "wms-r/wms",
+            ServiceKind::Wmts => "wmts",
+        }
+    }
+
+    fn service_param(self) -> &'static str {
+        match self {
+            ServiceKind::Wfs => "WFS",
+            ServiceKind::Wms => "WMS",
+            ServiceKind::Wmts => "WMTS",
+        }
+    }
+
+    fn version_param(self) -> &'static str {
+        match self {
+            ServiceKind::Wfs => "2.0.0",
+            ServiceKind::Wms => "1.3.0",
+            ServiceKind::Wmts => "1.0.0",
+        }
+    }
+
+    fn layer_element_name(self) -> &'static str {
+        match self {
+            ServiceKind::Wfs => "FeatureType",
+            ServiceKind::Wms | ServiceKind::Wmts => "Layer",
+        }
+    }
+}
+
+/// Per-layer metadata parsed from a `GetCapabilities` response, analogous to owslib's
+/// `ContentMetadata`/`FeatureType` model: title, whether it's queryable (WMS only -- WFS feature
+/// types and WMTS layers are always queryable), advertised CRS list, WGS84 bounding box, and
+/// available styles.
+#[derive(Debug, Clone)]
+pub struct LayerMeta {
+    pub name: String,
+    pub title: Option<String>,
+    pub queryable: bool,
+    pub crs: Vec<String>,
+    pub wgs84_bbox: Option<BoundingBox>,
+    pub styles: Vec<String>,
+}
+
+/// One attribute of a WFS feature type, as discovered by
+/// [`IgnCollect::describe_feature_type`] from a `DescribeFeatureType` XSD schema.
+#[derive(Debug, Clone)]
+pub struct AttributeField {
+    pub name: String,
+    pub xsd_type: String,
+    /// Whether this field is the layer's geometry property (its XSD type is a `gml:*PropertyType`).
+    pub is_geometry: bool,
+}
+
+/// One sub-request of a tiled WMS fetch: its own geographic extent plus the pixel offset/size
+/// it occupies within the full mosaic. See [`IgnCollect::plan_wms_tiles`].
+#[derive(Debug, Clone, Copy)]
+struct WmsTile {
+    bbox: BoundingBox,
+    px_x: u32,
+    px_y: u32,
+    width_px: u32,
+    height_px: u32,
 }
 
 // Note: Cannot implement Default because new() returns Result
@@ -591,4 +1637,177 @@ mod tests {
             assert!(!row.url_geoplateforme.is_empty());
         }
     }
+
+    #[test]
+    fn test_parse_capabilities_wfs_feature_type() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <wfs:WFS_Capabilities xmlns:wfs="http://www.opengis.net/wfs/2.0" xmlns:ows="http://www.opengis.net/ows/1.1">
+            <FeatureTypeList>
+                <FeatureType>
+                    <Name>BDTOPO_V3:batiment</Name>
+                    <Title>Batiment</Title>
+                    <DefaultCRS>EPSG:4326</DefaultCRS>
+                    <OtherCRS>EPSG:2154</OtherCRS>
+                    <ows:WGS84BoundingBox>
+                        <ows:LowerCorner>-5.5 41.0</ows:LowerCorner>
+                        <ows:UpperCorner>9.8 51.5</ows:UpperCorner>
+                    </ows:WGS84BoundingBox>
+                </FeatureType>
+            </FeatureTypeList>
+        </wfs:WFS_Capabilities>"#;
+
+        let layers = IgnCollect::parse_capabilities(xml, ServiceKind::Wfs).unwrap();
+        assert_eq!(layers.len(), 1);
+        let layer = &layers[0];
+        assert_eq!(layer.name, "BDTOPO_V3:batiment");
+        assert_eq!(layer.title.as_deref(), Some("Batiment"));
+        assert!(layer.queryable);
+        assert_eq!(layer.crs, vec!["EPSG:4326", "EPSG:2154"]);
+        let bbox = layer.wgs84_bbox.unwrap();
+        assert_eq!(bbox.min_x, -5.5);
+        assert_eq!(bbox.max_y, 51.5);
+    }
+
+    #[test]
+    fn test_parse_capabilities_wms_layer_queryable() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <WMS_Capabilities>
+            <Capability>
+                <Layer>
+                    <Layer queryable="1">
+                        <Name>ORTHOIMAGERY.ORTHOPHOTOS</Name>
+                        <Title>Photographies aeriennes</Title>
+                        <CRS>EPSG:4326</CRS>
+                        <Style>
+                            <Name>normal</Name>
+                        </Style>
+                    </Layer>
+                </Layer>
+            </Capability>
+        </WMS_Capabilities>"#;
+
+        let layers = IgnCollect::parse_capabilities(xml, ServiceKind::Wms).unwrap();
+        assert_eq!(layers.len(), 1);
+        let layer = &layers[0];
+        assert_eq!(layer.name, "ORTHOIMAGERY.ORTHOPHOTOS");
+        assert!(layer.queryable);
+        assert_eq!(layer.styles, vec!["normal".to_string()]);
+    }
+
+    #[test]
+    fn test_plan_wms_tiles_splits_into_grid_with_no_gaps() {
+        let bbox = BoundingBox::new(0.0, 0.0, 1.0, 1.0);
+        let tiles = IgnCollect::plan_wms_tiles(&bbox, 5000, 3000, 2048);
+
+        // ceil(5000/2048) = 3 columns, ceil(3000/2048) = 2 rows
+        assert_eq!(tiles.len(), 6);
+        assert!(tiles.iter().all(|t| t.width_px <= 2048 && t.height_px <= 2048));
+
+        let total_width: u32 = tiles
+            .iter()
+            .filter(|t| t.px_y == 0)
+            .map(|t| t.width_px)
+            .sum();
+        assert_eq!(total_width, 5000);
+        let total_height: u32 = tiles
+            .iter()
+            .filter(|t| t.px_x == 0)
+            .map(|t| t.height_px)
+            .sum();
+        assert_eq!(total_height, 3000);
+
+        // The top-left tile's bbox corner must coincide with the full bbox's corner.
+        let top_left = tiles.iter().find(|t| t.px_x == 0 && t.px_y == 0).unwrap();
+        assert_eq!(top_left.bbox.min_x, bbox.min_x);
+        assert_eq!(top_left.bbox.max_y, bbox.max_y);
+    }
+
+    #[test]
+    fn test_plan_wms_tiles_single_tile_when_under_cap() {
+        let bbox = BoundingBox::new(0.0, 0.0, 1.0, 1.0);
+        let tiles = IgnCollect::plan_wms_tiles(&bbox, 1024, 1024, 2048);
+        assert_eq!(tiles.len(), 1);
+        assert_eq!(tiles[0].bbox.min_x, bbox.min_x);
+        assert_eq!(tiles[0].bbox.min_y, bbox.min_y);
+        assert_eq!(tiles[0].bbox.max_x, bbox.max_x);
+        assert_eq!(tiles[0].bbox.max_y, bbox.max_y);
+    }
+
+    #[test]
+    fn test_parse_describe_feature_type_finds_geometry_and_scalar_fields() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:gml="http://www.opengis.net/gml">
+            <xsd:complexType name="batimentType">
+                <xsd:complexContent>
+                    <xsd:extension base="gml:AbstractFeatureType">
+                        <xsd:sequence>
+                            <xsd:element name="geometrie" type="gml:MultiPolygonPropertyType"/>
+                            <xsd:element name="hauteur" type="xsd:double"/>
+                            <xsd:element name="nature" type="xsd:string"/>
+                        </xsd:sequence>
+                    </xsd:extension>
+                </xsd:complexContent>
+            </xsd:complexType>
+        </xsd:schema>"#;
+
+        let fields = IgnCollect::parse_describe_feature_type(xml).unwrap();
+        assert_eq!(fields.len(), 3);
+
+        let geometrie = fields.iter().find(|f| f.name == "geometrie").unwrap();
+        assert!(geometrie.is_geometry);
+        assert_eq!(geometrie.xsd_type, "gml:MultiPolygonPropertyType");
+
+        let hauteur = fields.iter().find(|f| f.name == "hauteur").unwrap();
+        assert!(!hauteur.is_geometry);
+        assert_eq!(hauteur.xsd_type, "xsd:double");
+    }
+
+    #[test]
+    fn test_cache_key_is_stable_and_distinguishes_requests() {
+        let a = IgnCollect::cache_key(&["wfs", "BDTOPO_V3:batiment", "0,0,1,1", "4326", ""]);
+        let b = IgnCollect::cache_key(&["wfs", "BDTOPO_V3:batiment", "0,0,1,1", "4326", ""]);
+        assert_eq!(a, b);
+
+        let different_bbox =
+            IgnCollect::cache_key(&["wfs", "BDTOPO_V3:batiment", "1,1,2,2", "4326", ""]);
+        assert_ne!(a, different_bbox);
+
+        let different_crs = IgnCollect::cache_key(&["wfs", "BDTOPO_V3:batiment", "0,0,1,1", "2154", ""]);
+        assert_ne!(a, different_crs);
+    }
+
+    #[test]
+    fn test_cache_disabled_by_default_lookup_returns_none() {
+        let ign = IgnCollect::new().unwrap();
+        assert!(!ign.cache_enabled);
+        assert!(ign.cache_lookup("nonexistent", "json").is_none());
+    }
+
+    #[test]
+    fn test_fetch_isochrone_rejects_non_positive_cost_values() {
+        let mut ign = IgnCollect::new().unwrap();
+        let params = IsochroneParams {
+            point: (2.3, 48.85),
+            cost_type: CostType::Time,
+            cost_values: vec![600.0, -1.0],
+            profile: Profile::Car,
+            direction: Direction::Departure,
+        };
+        let err = ign.fetch_isochrone(&params).unwrap_err();
+        assert!(err.to_string().contains("positive"));
+    }
+
+    #[test]
+    fn test_fetch_isochrone_rejects_point_outside_france() {
+        let mut ign = IgnCollect::new().unwrap();
+        let params = IsochroneParams {
+            point: (-74.0, 40.7), // New York
+            cost_type: CostType::Distance,
+            cost_values: vec![1000.0],
+            profile: Profile::Pedestrian,
+            direction: Direction::Arrival,
+        };
+        let err = ign.fetch_isochrone(&params).unwrap_err();
+        assert!(err.to_string().contains("metropolitan France"));
+    }
 }
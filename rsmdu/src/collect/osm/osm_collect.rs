@@ -0,0 +1,203 @@
+use anyhow::{Context, Result};
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use serde_json::Map;
+use std::collections::HashMap;
+
+use crate::geo_core::BoundingBox;
+
+/// Overpass API endpoint used for OSM queries
+const OVERPASS_URL: &str = "https://overpass-api.de/api/interpreter";
+
+/// Base struct for OpenStreetMap/Overpass data collection
+/// Following the Python implementation from pymdu.collect.GlobalVariables.OsmCollect
+/// Issues an Overpass QL query for a tag `key=value` over a bounding box and converts
+/// the resulting OSM JSON into a GeoJson FeatureCollection.
+pub struct OsmCollect {
+    /// Overpass tag filter, e.g. `"natural"="water"` (Python: key)
+    pub key: String,
+    pub bbox: Option<BoundingBox>,
+    pub content: Option<Vec<u8>>,
+}
+
+/// Minimal subset of the Overpass JSON response we need
+#[derive(Debug, Deserialize)]
+struct OverpassResponse {
+    elements: Vec<OverpassElement>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OverpassElement {
+    #[serde(rename = "type")]
+    element_type: String,
+    id: i64,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    nodes: Option<Vec<i64>>,
+    geometry: Option<Vec<OverpassLatLon>>,
+    members: Option<Vec<OverpassMember>>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OverpassLatLon {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OverpassMember {
+    #[serde(rename = "type")]
+    member_type: String,
+    geometry: Option<Vec<OverpassLatLon>>,
+}
+
+impl OsmCollect {
+    /// Create a new OsmCollect for the given Overpass tag filter
+    /// Following Python: OsmCollect(key='"natural"="water"')
+    pub fn new(key: &str) -> Self {
+        OsmCollect {
+            key: key.to_string(),
+            bbox: None,
+            content: None,
+        }
+    }
+
+    /// Set bounding box for the Overpass query
+    pub fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        self.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
+    }
+
+    /// Run the Overpass query: build the QL, download results, and keep the raw JSON body.
+    pub fn run(mut self) -> Result<Self> {
+        self.run_internal()?;
+        Ok(self)
+    }
+
+    /// Internal run method that can be called mutably (used by Python bindings)
+    pub fn run_internal(&mut self) -> Result<()> {
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before executing Overpass request")?;
+
+        // Overpass QL bbox order is (south, west, north, east) i.e. (min_y, min_x, max_y, max_x)
+        let bbox_clause = format!(
+            "({},{},{},{})",
+            bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x
+        );
+
+        // `self.key` looks like `"natural"="water"`; turn it into an Overpass tag filter `[key=value]`
+        let tag_filter = format!("[{}]", self.key);
+
+        let query = format!(
+            "[out:json][timeout:60];(node{tag}{bbox};way{tag}{bbox};relation{tag}{bbox};);out body geom;",
+            tag = tag_filter,
+            bbox = bbox_clause,
+        );
+
+        let client = Client::new();
+        let response = client
+            .post(OVERPASS_URL)
+            .form(&[("data", query.as_str())])
+            .send()
+            .context("Failed to send Overpass request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Overpass API returned error {}: {}", status, body);
+        }
+
+        let content_bytes = response
+            .bytes()
+            .context("Failed to read Overpass response body")?
+            .to_vec();
+
+        self.content = Some(content_bytes);
+        Ok(())
+    }
+
+    /// Convert the raw Overpass JSON body into a GeoJson FeatureCollection.
+    /// Nodes become Points, ways become LineStrings (or Polygons when closed),
+    /// relations become MultiPolygons built from their member way geometries.
+    /// Following Python: OsmCollect.to_gdf()
+    pub fn to_geojson(&self) -> Result<GeoJson> {
+        let content = self
+            .content
+            .as_ref()
+            .context("No content received from Overpass API. Call run() first.")?;
+
+        let response: OverpassResponse = serde_json::from_slice(content)
+            .context("Failed to parse Overpass JSON response")?;
+
+        let mut features = Vec::new();
+
+        for element in &response.elements {
+            let geometry = match element.element_type.as_str() {
+                "node" => match (element.lon, element.lat) {
+                    (Some(lon), Some(lat)) => Some(Geometry::new(Value::Point(vec![lon, lat]))),
+                    _ => None,
+                },
+                "way" => element.geometry.as_ref().and_then(|coords| {
+                    if coords.is_empty() {
+                        return None;
+                    }
+                    let positions: Vec<Vec<f64>> =
+                        coords.iter().map(|c| vec![c.lon, c.lat]).collect();
+                    let closed = positions.len() > 2 && positions.first() == positions.last();
+                    if closed {
+                        Some(Geometry::new(Value::Polygon(vec![positions])))
+                    } else {
+                        Some(Geometry::new(Value::LineString(positions)))
+                    }
+                }),
+                "relation" => element.members.as_ref().and_then(|members| {
+                    let rings: Vec<Vec<Vec<f64>>> = members
+                        .iter()
+                        .filter(|m| m.member_type == "way")
+                        .filter_map(|m| {
+                            let coords = m.geometry.as_ref()?;
+                            if coords.is_empty() {
+                                return None;
+                            }
+                            Some(coords.iter().map(|c| vec![c.lon, c.lat]).collect())
+                        })
+                        .collect();
+                    if rings.is_empty() {
+                        None
+                    } else {
+                        Some(Geometry::new(Value::MultiPolygon(
+                            rings.into_iter().map(|ring| vec![ring]).collect(),
+                        )))
+                    }
+                }),
+                _ => None,
+            };
+
+            let Some(geometry) = geometry else {
+                continue;
+            };
+
+            let mut properties = Map::new();
+            properties.insert(
+                "osm_id".to_string(),
+                serde_json::Value::Number(element.id.into()),
+            );
+            for (key, value) in &element.tags {
+                properties.insert(key.clone(), serde_json::Value::String(value.clone()));
+            }
+
+            let mut feature = Feature::from(geometry);
+            feature.properties = Some(properties);
+            features.push(feature);
+        }
+
+        Ok(GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        }))
+    }
+}
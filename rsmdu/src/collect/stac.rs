@@ -0,0 +1,569 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::collect::global_variables::TEMP_PATH;
+use crate::geo_core::{BoundingBox, GeoCore};
+
+/// Default number of degrees (in EPSG:4326) [`StacClient::search`] expands the caller's bbox by
+/// before searching, so tiles that only partially overlap the area of interest still come back.
+const DEFAULT_BBOX_MARGIN_DEG: f64 = 0.0;
+
+/// Maximum items requested per `/search` page; further pages are followed via the response's
+/// `"next"` link until it stops appearing.
+const SEARCH_PAGE_SIZE: u32 = 100;
+
+/// One asset entry from a STAC Item's `assets` map, trimmed down to what callers need to decide
+/// whether and how to download it.
+#[derive(Debug, Clone)]
+pub struct StacAsset {
+    pub href: String,
+    pub roles: Vec<String>,
+    pub media_type: Option<String>,
+}
+
+/// A single item returned by a [`StacClient::search`], parsed from the STAC API's GeoJSON
+/// ItemCollection response.
+#[derive(Debug, Clone)]
+pub struct StacItem {
+    pub id: String,
+    pub datetime: Option<String>,
+    pub bbox: Option<(f64, f64, f64, f64)>,
+    /// `properties["eo:cloud_cover"]`, percent, when the provider's EO extension reports it.
+    pub cloud_cover: Option<f64>,
+    pub assets: HashMap<String, StacAsset>,
+}
+
+impl StacItem {
+    /// First asset whose `roles` contains `role`, case-insensitively (e.g. `"dem"`, `"dsm"`, or a
+    /// provider-specific role like `"3d-tiles"`).
+    pub fn asset_by_role(&self, role: &str) -> Option<&StacAsset> {
+        self.assets
+            .values()
+            .find(|asset| asset.roles.iter().any(|r| r.eq_ignore_ascii_case(role)))
+    }
+
+    /// First asset whose declared media type contains `media_type_fragment`, case-insensitively
+    /// (e.g. `"tiff"` to match `"image/tiff; application=geotiff"`).
+    pub fn asset_by_media_type(&self, media_type_fragment: &str) -> Option<&StacAsset> {
+        let needle = media_type_fragment.to_lowercase();
+        self.assets.values().find(|asset| {
+            asset
+                .media_type
+                .as_deref()
+                .is_some_and(|mt| mt.to_lowercase().contains(&needle))
+        })
+    }
+}
+
+/// Raw asset shape as returned by any STAC API (`assets.<key>`), deserialized before being
+/// trimmed down to [`StacAsset`].
+#[derive(Debug, Deserialize)]
+struct StacAssetRaw {
+    href: String,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(rename = "type")]
+    media_type: Option<String>,
+}
+
+/// Raw item shape as returned by any STAC API (one GeoJSON Feature of an ItemCollection).
+#[derive(Debug, Deserialize)]
+struct StacItemRaw {
+    id: String,
+    #[serde(default)]
+    bbox: Option<Vec<f64>>,
+    #[serde(default)]
+    properties: HashMap<String, JsonValue>,
+    #[serde(default)]
+    assets: HashMap<String, StacAssetRaw>,
+}
+
+/// One entry of a STAC ItemCollection's `links` array; only `"next"` is followed.
+#[derive(Debug, Deserialize)]
+struct StacLinkRaw {
+    rel: String,
+    href: String,
+}
+
+/// Raw ItemCollection shape returned by `POST {endpoint}/search`.
+#[derive(Debug, Deserialize)]
+struct StacItemCollectionRaw {
+    #[serde(default)]
+    features: Vec<StacItemRaw>,
+    #[serde(default)]
+    links: Vec<StacLinkRaw>,
+}
+
+impl From<StacItemRaw> for StacItem {
+    fn from(raw: StacItemRaw) -> Self {
+        let bbox = match raw.bbox.as_deref() {
+            Some([min_x, min_y, max_x, max_y, ..]) => Some((*min_x, *min_y, *max_x, *max_y)),
+            _ => None,
+        };
+        let datetime = raw
+            .properties
+            .get("datetime")
+            .and_then(|value| value.as_str())
+            .map(|s| s.to_string());
+        let cloud_cover = raw
+            .properties
+            .get("eo:cloud_cover")
+            .and_then(|value| value.as_f64());
+        let assets = raw
+            .assets
+            .into_iter()
+            .map(|(key, asset)| {
+                (
+                    key,
+                    StacAsset {
+                        href: asset.href,
+                        roles: asset.roles,
+                        media_type: asset.media_type,
+                    },
+                )
+            })
+            .collect();
+
+        StacItem {
+            id: raw.id,
+            datetime,
+            bbox,
+            cloud_cover,
+            assets,
+        }
+    }
+}
+
+/// Client for any SpatioTemporal Asset Catalog (STAC) API endpoint. Generalizes
+/// [`crate::collect::ign::ign_collect::IgnCollect`]'s single French-IGN-WFS/WMS source to any
+/// STAC-compliant provider (e.g. swisstopo's swissSURFACE3D, Microsoft Planetary Computer, Earth
+/// Search), so [`crate::geometric::dem::Dem`] can pull elevation data from whichever catalog the
+/// caller points at, using the same [`BoundingBox`].
+pub struct StacClient {
+    endpoint: String,
+    client: Client,
+    /// Degrees (EPSG:4326) the search bbox is expanded by on every side before querying, so
+    /// tiles only partially overlapping the area of interest are still returned. See
+    /// [`StacClient::set_margin`].
+    bbox_margin_deg: f64,
+    /// Restrict `search` to this collection id, when set. See [`StacClient::set_collection`].
+    collection: Option<String>,
+}
+
+impl StacClient {
+    /// `endpoint` is the STAC API root, e.g. `"https://earth-search.aws.element84.com/v1"`.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        StacClient {
+            endpoint: endpoint.into(),
+            client: Client::new(),
+            bbox_margin_deg: DEFAULT_BBOX_MARGIN_DEG,
+            collection: None,
+        }
+    }
+
+    /// Expand every search bbox by `margin_deg` degrees (EPSG:4326) on each side before
+    /// querying, to capture tiles that only partially overlap the caller's area of interest.
+    pub fn set_margin(&mut self, margin_deg: f64) {
+        self.bbox_margin_deg = margin_deg;
+    }
+
+    /// Restrict `search` to items from this collection id (e.g. a provider's
+    /// `"swisssurface3d-raster"` elevation collection). `None` (the default) searches every
+    /// collection the endpoint serves.
+    pub fn set_collection(&mut self, collection: impl Into<String>) {
+        self.collection = Some(collection.into());
+    }
+
+    /// Search `{endpoint}/search` for items intersecting `bbox` (expanded by
+    /// [`StacClient::set_margin`]), optionally restricted to a datetime range (an RFC 3339
+    /// interval such as `"2024-01-01T00:00:00Z/.."`, per the STAC API item-search spec; either
+    /// side may be left open with `".."`) and to [`StacClient::set_collection`]'s collection.
+    /// Pages through the full result set and returns every item, sorted by ascending cloud cover
+    /// then descending acquisition date (items missing one or both fields sort last), so the
+    /// best tile for a given bbox is always `results[0]`.
+    pub fn search(&self, bbox: &BoundingBox, datetime: Option<&str>) -> Result<Vec<StacItem>> {
+        let expanded = self.expand_bbox(bbox);
+        let mut body = json!({
+            "bbox": [expanded.min_x, expanded.min_y, expanded.max_x, expanded.max_y],
+            "limit": SEARCH_PAGE_SIZE,
+        });
+        if let Some(datetime) = datetime {
+            body["datetime"] = json!(datetime);
+        }
+        if let Some(collection) = &self.collection {
+            body["collections"] = json!([collection]);
+        }
+
+        let mut items = Vec::new();
+        let mut url = format!("{}/search", self.endpoint.trim_end_matches('/'));
+        let mut next_body = Some(body);
+
+        loop {
+            let mut request = self.client.post(&url);
+            request = match next_body.take() {
+                Some(body) => request.json(&body),
+                None => request,
+            };
+
+            let response = request
+                .send()
+                .context("Failed to query STAC endpoint")?
+                .error_for_status()
+                .context("STAC endpoint returned an error status")?;
+
+            let raw: StacItemCollectionRaw = response
+                .json()
+                .context("Failed to parse STAC item collection")?;
+
+            items.extend(raw.features.into_iter().map(StacItem::from));
+
+            match raw.links.into_iter().find(|link| link.rel == "next") {
+                Some(next) => url = next.href,
+                None => break,
+            }
+        }
+
+        items.sort_by(|a, b| {
+            let cloud_cover_order = match (a.cloud_cover, b.cloud_cover) {
+                (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            };
+            cloud_cover_order.then_with(|| b.datetime.cmp(&a.datetime))
+        });
+
+        Ok(items)
+    }
+
+    /// Expand `bbox` by [`StacClient::set_margin`]'s configured margin on every side.
+    fn expand_bbox(&self, bbox: &BoundingBox) -> BoundingBox {
+        let margin = self.bbox_margin_deg;
+        BoundingBox::new(
+            bbox.min_x - margin,
+            bbox.min_y - margin,
+            bbox.max_x + margin,
+            bbox.max_y + margin,
+        )
+    }
+
+    /// Download an asset's bytes (e.g. a DEM/DSM GeoTIFF or a 3D building tile) for handoff to
+    /// the `dem`/`lidar`/`building` pipelines.
+    pub fn download_asset(&self, asset: &StacAsset) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .get(&asset.href)
+            .send()
+            .context("Failed to download STAC asset")?
+            .error_for_status()
+            .context("STAC asset download returned an error status")?;
+
+        Ok(response
+            .bytes()
+            .context("Failed to read STAC asset body")?
+            .to_vec())
+    }
+}
+
+/// Tiled-catalog collector built on top of [`StacClient`], generalizing the single-provider
+/// pattern in [`crate::geometric::cosia::Cosia`]/[`crate::geometric::dem::Dem`] (construct with
+/// an output path, configure a bbox/CRS, `run()`) to any STAC-compliant government portal instead
+/// of IGN's WFS/WMS. Where [`StacClient`] only searches and fetches asset bytes one at a time,
+/// `StacSource` owns the bbox/CRS state, reprojects it to EPSG:4326 for the search, and writes
+/// every matching raster/shapefile asset under `output_path`.
+pub struct StacSource {
+    client: StacClient,
+    /// Collection id extracted from the `collection_url` passed to [`StacSource::new`].
+    collection: String,
+    /// Output path for downloaded assets.
+    output_path: PathBuf,
+    /// GeoCore for CRS handling
+    pub geo_core: GeoCore,
+    /// Bounding box for the search area, in `geo_core`'s CRS.
+    bbox: Option<BoundingBox>,
+}
+
+impl StacSource {
+    /// `collection_url` is a single STAC collection's endpoint, e.g.
+    /// `https://earth-search.aws.element84.com/v1/collections/sentinel-2-l2a`: everything before
+    /// the trailing `/collections/<id>` segment becomes the API root [`StacClient`] queries, and
+    /// `<id>` restricts [`StacClient::search`] to that collection.
+    pub fn new(collection_url: impl Into<String>, output_path: Option<String>) -> Result<Self> {
+        let collection_url = collection_url.into();
+        let (endpoint, collection) = Self::split_collection_url(&collection_url).with_context(
+            || {
+                format!(
+                    "Expected a STAC collection URL of the form \"<endpoint>/collections/<id>\", got {:?}",
+                    collection_url
+                )
+            },
+        )?;
+
+        let mut client = StacClient::new(endpoint);
+        client.set_collection(collection.clone());
+
+        Ok(StacSource {
+            client,
+            collection,
+            output_path: PathBuf::from(output_path.as_deref().unwrap_or(TEMP_PATH)),
+            geo_core: GeoCore::default(), // Default to EPSG:2154 (Lambert-93)
+            bbox: None,
+        })
+    }
+
+    /// Split `<endpoint>/collections/<id>` into `(endpoint, id)`. Returns `None` if the URL
+    /// doesn't contain a `/collections/` segment, or either side would be empty.
+    fn split_collection_url(url: &str) -> Option<(String, String)> {
+        let (endpoint, id) = url.trim_end_matches('/').rsplit_once("/collections/")?;
+        if endpoint.is_empty() || id.is_empty() {
+            return None;
+        }
+        Some((endpoint.to_string(), id.to_string()))
+    }
+
+    /// Set bounding box
+    pub fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        self.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
+    }
+
+    /// Set CRS
+    pub fn set_crs(&mut self, epsg: i32) {
+        self.geo_core.set_epsg(epsg);
+    }
+
+    /// Expand every search bbox by `margin_deg` degrees (EPSG:4326) on each side before
+    /// querying, see [`StacClient::set_margin`].
+    pub fn set_margin(&mut self, margin_deg: f64) {
+        self.client.set_margin(margin_deg);
+    }
+
+    /// Query the collection for items intersecting the bbox set via [`StacSource::set_bbox`],
+    /// download every asset that looks like a COG/GeoTIFF raster or a zipped shapefile from each
+    /// matching item, and write them under `output_path`. Returns the paths written, in search
+    /// result order (best tile -- lowest cloud cover, most recent -- first).
+    pub fn run(&self) -> Result<Vec<PathBuf>> {
+        let bbox = self
+            .bbox
+            .as_ref()
+            .context("No bounding box set. Call set_bbox() first.")?;
+        let bbox_4326 = bbox
+            .transform(self.geo_core.epsg, 4326)
+            .context("Failed to reproject bbox to EPSG:4326 for STAC search")?;
+
+        let items = self
+            .client
+            .search(&bbox_4326, None)
+            .with_context(|| format!("Failed to search STAC collection {:?}", self.collection))?;
+
+        std::fs::create_dir_all(&self.output_path)
+            .with_context(|| format!("Failed to create output directory {:?}", self.output_path))?;
+
+        let mut paths = Vec::new();
+        for item in &items {
+            for asset in Self::downloadable_assets(item) {
+                let bytes = self.client.download_asset(asset).with_context(|| {
+                    format!("Failed to download asset for STAC item {:?}", item.id)
+                })?;
+                let path = self.output_path.join(Self::asset_filename(item, asset));
+                std::fs::write(&path, &bytes)
+                    .with_context(|| format!("Failed to write STAC asset to {:?}", path))?;
+                paths.push(path);
+            }
+        }
+
+        Ok(paths)
+    }
+
+    /// Assets worth downloading for a tiled raster/shapefile pipeline: COG/GeoTIFF rasters and
+    /// zipped shapefiles, identified by href extension/media type since providers aren't
+    /// consistent about declaring `roles`.
+    fn downloadable_assets(item: &StacItem) -> Vec<&StacAsset> {
+        item.assets
+            .values()
+            .filter(|asset| {
+                let href = asset.href.to_lowercase();
+                let media_type = asset.media_type.as_deref().unwrap_or("").to_lowercase();
+                href.ends_with(".tif")
+                    || href.ends_with(".tiff")
+                    || href.ends_with(".zip")
+                    || media_type.contains("tiff")
+                    || media_type.contains("zip")
+            })
+            .collect()
+    }
+
+    /// Filename an asset is written to under `output_path`: the item id prefixed onto the
+    /// href's basename, so tiles from different items/dates never collide on disk.
+    fn asset_filename(item: &StacItem, asset: &StacAsset) -> String {
+        match asset.href.rsplit('/').next() {
+            Some(name) if !name.is_empty() => format!("{}-{}", item.id, name),
+            _ => format!("{}.bin", item.id),
+        }
+    }
+}
+
+/// Keep only the most recent item per spatial footprint. Providers often publish overlapping
+/// tiles across revisit dates, so items are grouped by bbox (rounded to ~0.1m to absorb
+/// floating-point noise) and, within each group, the item with the lexicographically greatest
+/// `datetime` wins (RFC 3339 timestamps sort correctly as strings). Items without a bbox can't be
+/// grouped and are all kept as-is.
+pub fn dedupe_by_footprint(items: Vec<StacItem>) -> Vec<StacItem> {
+    let mut by_footprint: HashMap<(i64, i64, i64, i64), StacItem> = HashMap::new();
+    let mut unfootprinted = Vec::new();
+
+    for item in items {
+        match item.bbox {
+            Some((min_x, min_y, max_x, max_y)) => {
+                let round = |v: f64| (v * 1e6).round() as i64;
+                let key = (round(min_x), round(min_y), round(max_x), round(max_y));
+                let keep = match by_footprint.get(&key) {
+                    Some(existing) => item.datetime.as_deref() > existing.datetime.as_deref(),
+                    None => true,
+                };
+                if keep {
+                    by_footprint.insert(key, item);
+                }
+            }
+            None => unfootprinted.push(item),
+        }
+    }
+
+    by_footprint.into_values().chain(unfootprinted).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_bbox_grows_on_every_side() {
+        let mut client = StacClient::new("https://example.test/stac");
+        client.set_margin(0.1);
+        let bbox = BoundingBox::new(0.0, 0.0, 1.0, 1.0);
+        let expanded = client.expand_bbox(&bbox);
+        assert_eq!(
+            (expanded.min_x, expanded.min_y, expanded.max_x, expanded.max_y),
+            (-0.1, -0.1, 1.1, 1.1)
+        );
+    }
+
+    #[test]
+    fn test_search_sorts_by_cloud_cover_then_datetime() {
+        fn item(id: &str, cloud_cover: Option<f64>, datetime: &str) -> StacItem {
+            StacItem {
+                id: id.to_string(),
+                datetime: Some(datetime.to_string()),
+                bbox: None,
+                cloud_cover,
+                assets: HashMap::new(),
+            }
+        }
+
+        let mut items = vec![
+            item("cloudy-old", Some(80.0), "2023-01-01T00:00:00Z"),
+            item("clear-new", Some(1.0), "2024-01-01T00:00:00Z"),
+            item("clear-old", Some(1.0), "2022-01-01T00:00:00Z"),
+        ];
+        items.sort_by(|a, b| {
+            a.cloud_cover
+                .partial_cmp(&b.cloud_cover)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.datetime.cmp(&a.datetime))
+        });
+
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["clear-new", "clear-old", "cloudy-old"]);
+    }
+
+    #[test]
+    fn test_asset_by_role_and_media_type_are_case_insensitive() {
+        let mut assets = HashMap::new();
+        assets.insert(
+            "data".to_string(),
+            StacAsset {
+                href: "https://example.test/dem.tif".to_string(),
+                roles: vec!["DEM".to_string()],
+                media_type: Some("image/tiff; application=geotiff".to_string()),
+            },
+        );
+        let item = StacItem {
+            id: "item-1".to_string(),
+            datetime: None,
+            bbox: None,
+            cloud_cover: None,
+            assets,
+        };
+
+        assert_eq!(item.asset_by_role("dem").unwrap().href, "https://example.test/dem.tif");
+        assert_eq!(item.asset_by_media_type("tiff").unwrap().href, "https://example.test/dem.tif");
+        assert!(item.asset_by_role("dsm").is_none());
+    }
+
+    #[test]
+    fn test_split_collection_url_extracts_endpoint_and_id() {
+        let (endpoint, id) = StacSource::split_collection_url(
+            "https://earth-search.aws.element84.com/v1/collections/sentinel-2-l2a",
+        )
+        .unwrap();
+        assert_eq!(endpoint, "https://earth-search.aws.element84.com/v1");
+        assert_eq!(id, "sentinel-2-l2a");
+    }
+
+    #[test]
+    fn test_split_collection_url_rejects_url_without_collections_segment() {
+        assert!(StacSource::split_collection_url("https://example.test/stac").is_none());
+    }
+
+    #[test]
+    fn test_downloadable_assets_filters_by_extension_and_media_type() {
+        let mut assets = HashMap::new();
+        assets.insert(
+            "data".to_string(),
+            StacAsset {
+                href: "https://example.test/tile.tif".to_string(),
+                roles: vec![],
+                media_type: None,
+            },
+        );
+        assets.insert(
+            "thumbnail".to_string(),
+            StacAsset {
+                href: "https://example.test/preview.png".to_string(),
+                roles: vec![],
+                media_type: Some("image/png".to_string()),
+            },
+        );
+        let item = StacItem {
+            id: "item-1".to_string(),
+            datetime: None,
+            bbox: None,
+            cloud_cover: None,
+            assets,
+        };
+
+        let downloadable = StacSource::downloadable_assets(&item);
+        assert_eq!(downloadable.len(), 1);
+        assert_eq!(downloadable[0].href, "https://example.test/tile.tif");
+    }
+
+    #[test]
+    fn test_asset_filename_prefixes_item_id_onto_href_basename() {
+        let item = StacItem {
+            id: "tile-42".to_string(),
+            datetime: None,
+            bbox: None,
+            cloud_cover: None,
+            assets: HashMap::new(),
+        };
+        let asset = StacAsset {
+            href: "https://example.test/dem/tile-42.tif".to_string(),
+            roles: vec![],
+            media_type: None,
+        };
+        assert_eq!(StacSource::asset_filename(&item, &asset), "tile-42-tile-42.tif");
+    }
+}
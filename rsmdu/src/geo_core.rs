@@ -1,6 +1,255 @@
 use anyhow::{Context, Result};
-use geo::Point;
+use gdal::spatial_ref::SpatialRef;
+use geo::algorithm::bounding_rect::BoundingRect;
+use geo::{Area, BooleanOps, LineString, MultiPolygon, Point, Polygon};
+use geojson::{GeoJson, Geometry, Value};
 use proj::Proj;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Mean Earth radius (meters) used by [`GeoCore::haversine_distance`]: the IUGG mean radius R1
+/// of the WGS84 ellipsoid.
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// EPSG code `GeoCore::set_limit_to` requires its polygon to already be expressed in, matching
+/// the lon/lat output of the IGN/OSM endpoints every geometric loader clips against it.
+pub const LIMIT_TO_EPSG: i32 = 4326;
+
+/// Cache of `proj::Proj` transformation pipelines keyed by `(from_epsg, to_epsg)`, shared (via
+/// `Rc`) across every clone of the `GeoCore` that built it. Building a `Proj` pipeline involves
+/// parsing both CRS definitions and deriving a transformation, which is wasted work when
+/// [`GeoCore::reproject`] is called repeatedly for the same CRS pair (e.g. once per tile/feature
+/// batch in a streaming loader).
+#[derive(Clone, Default)]
+struct ProjCache(Rc<RefCell<HashMap<(i32, i32), Rc<Proj>>>>);
+
+impl ProjCache {
+    fn get_or_create(&self, from_epsg: i32, to_epsg: i32) -> Result<Rc<Proj>> {
+        if let Some(proj) = self.0.borrow().get(&(from_epsg, to_epsg)) {
+            return Ok(Rc::clone(proj));
+        }
+
+        let from_crs = format!("EPSG:{}", from_epsg);
+        let to_crs = format!("EPSG:{}", to_epsg);
+        let proj = Proj::new_known_crs(&from_crs, &to_crs, None).with_context(|| {
+            format!(
+                "EPSG:{} -> EPSG:{} is not a transformation PROJ supports",
+                from_epsg, to_epsg
+            )
+        })?;
+        let proj = Rc::new(proj);
+        self.0
+            .borrow_mut()
+            .insert((from_epsg, to_epsg), Rc::clone(&proj));
+        Ok(proj)
+    }
+}
+
+/// Number of columns/rows in the acceleration grid [`LimitTo::build`] lays over the limiting
+/// polygon's bounding box. A coarser grid does less up-front work per `set_limit_to` call but
+/// classifies more features as "boundary" (and so pays the full intersection) than a finer one.
+const LIMIT_TO_GRID_RESOLUTION: usize = 16;
+
+/// One cell of [`LimitTo`]'s acceleration grid.
+#[derive(Debug, Clone)]
+enum TileClass {
+    /// Entirely inside the limit polygon: features here pass through untouched.
+    Inside,
+    /// Entirely outside the limit polygon: features here are dropped without a geometry test.
+    Outside,
+    /// Straddles the limit polygon's boundary. Holds the portion of the limit polygon that
+    /// falls inside this tile, so a feature confined to a single boundary tile can be clipped
+    /// against just that fragment instead of the whole limit polygon.
+    Boundary(MultiPolygon<f64>),
+}
+
+/// How a feature's envelope relates to [`LimitTo`]'s acceleration grid, returned by
+/// [`LimitTo::classify_envelope`].
+enum EnvelopeClass<'a> {
+    /// Every tile the envelope overlaps is `Inside`.
+    Inside,
+    /// Every tile the envelope overlaps is `Outside`.
+    Outside,
+    /// The envelope falls entirely within one `Boundary` tile; clip against its fragment.
+    Boundary(&'a MultiPolygon<f64>),
+    /// The envelope spans tiles of more than one class; fall back to the full limit polygon.
+    Mixed,
+}
+
+/// A limiting Polygon/MultiPolygon (always EPSG:4326, see [`LIMIT_TO_EPSG`]) that
+/// `GeoCore::clip_to_limit` clips result features to, plus a grid precomputed once by
+/// [`LimitTo::build`] over its bounding box that classifies each tile as fully inside, fully
+/// outside, or straddling the boundary. This turns a naive O(features × limit-vertices)
+/// intersection into an O(1) lookup for every feature that falls entirely inside or entirely
+/// outside the limit polygon, leaving the expensive polygon intersection only for features that
+/// actually touch the boundary.
+#[derive(Debug, Clone)]
+pub struct LimitTo {
+    polygon: MultiPolygon<f64>,
+    min_x: f64,
+    min_y: f64,
+    tile_size_x: f64,
+    tile_size_y: f64,
+    cols: usize,
+    rows: usize,
+    tiles: Vec<TileClass>,
+}
+
+impl LimitTo {
+    /// Lay a `LIMIT_TO_GRID_RESOLUTION` x `LIMIT_TO_GRID_RESOLUTION` grid over `polygon`'s
+    /// bounding box and classify every tile against it.
+    fn build(polygon: MultiPolygon<f64>) -> Self {
+        let rect = polygon
+            .bounding_rect()
+            .unwrap_or_else(|| geo::Rect::new(geo::coord! { x: 0.0, y: 0.0 }, geo::coord! { x: 0.0, y: 0.0 }));
+        let (min_x, min_y) = (rect.min().x, rect.min().y);
+        let (max_x, max_y) = (rect.max().x, rect.max().y);
+
+        let cols = LIMIT_TO_GRID_RESOLUTION;
+        let rows = LIMIT_TO_GRID_RESOLUTION;
+        let tile_size_x = ((max_x - min_x) / cols as f64).max(f64::EPSILON);
+        let tile_size_y = ((max_y - min_y) / rows as f64).max(f64::EPSILON);
+
+        let mut tiles = Vec::with_capacity(cols * rows);
+        for row in 0..rows {
+            for col in 0..cols {
+                let tile_min_x = min_x + col as f64 * tile_size_x;
+                let tile_min_y = min_y + row as f64 * tile_size_y;
+                let tile_max_x = tile_min_x + tile_size_x;
+                let tile_max_y = tile_min_y + tile_size_y;
+                let tile = Polygon::new(
+                    LineString::from(vec![
+                        (tile_min_x, tile_min_y),
+                        (tile_max_x, tile_min_y),
+                        (tile_max_x, tile_max_y),
+                        (tile_min_x, tile_max_y),
+                        (tile_min_x, tile_min_y),
+                    ]),
+                    vec![],
+                );
+                let tile_area = tile.unsigned_area();
+                let fragment = polygon.intersection(&MultiPolygon(vec![tile]));
+                let fragment_area = fragment.unsigned_area();
+
+                let class = if fragment.0.is_empty() || fragment_area <= tile_area * 1e-9 {
+                    TileClass::Outside
+                } else if (tile_area - fragment_area).abs() <= tile_area * 1e-9 {
+                    TileClass::Inside
+                } else {
+                    TileClass::Boundary(fragment)
+                };
+                tiles.push(class);
+            }
+        }
+
+        LimitTo {
+            polygon,
+            min_x,
+            min_y,
+            tile_size_x,
+            tile_size_y,
+            cols,
+            rows,
+            tiles,
+        }
+    }
+
+    fn col_for(&self, x: f64) -> usize {
+        (((x - self.min_x) / self.tile_size_x) as isize)
+            .clamp(0, self.cols as isize - 1) as usize
+    }
+
+    fn row_for(&self, y: f64) -> usize {
+        (((y - self.min_y) / self.tile_size_y) as isize)
+            .clamp(0, self.rows as isize - 1) as usize
+    }
+
+    /// Classify an envelope against the grid; see [`EnvelopeClass`].
+    fn classify_envelope(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> EnvelopeClass<'_> {
+        // The grid only covers the limit polygon's bounding box; anything beyond it is outside.
+        let grid_max_x = self.min_x + self.tile_size_x * self.cols as f64;
+        let grid_max_y = self.min_y + self.tile_size_y * self.rows as f64;
+        if max_x < self.min_x || max_y < self.min_y || min_x > grid_max_x || min_y > grid_max_y {
+            return EnvelopeClass::Outside;
+        }
+
+        let col_start = self.col_for(min_x);
+        let col_end = self.col_for(max_x);
+        let row_start = self.row_for(min_y);
+        let row_end = self.row_for(max_y);
+
+        let mut saw_inside = false;
+        let mut saw_outside =
+            min_x < self.min_x || min_y < self.min_y || max_x > grid_max_x || max_y > grid_max_y;
+        let mut boundary_tile = None;
+        let mut touched = 0usize;
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                touched += 1;
+                match &self.tiles[row * self.cols + col] {
+                    TileClass::Inside => saw_inside = true,
+                    TileClass::Outside => saw_outside = true,
+                    TileClass::Boundary(fragment) => boundary_tile = Some(fragment),
+                }
+            }
+        }
+
+        match (saw_inside, saw_outside, boundary_tile) {
+            (true, false, None) => EnvelopeClass::Inside,
+            (false, true, None) => EnvelopeClass::Outside,
+            (false, false, Some(fragment)) if touched == 1 => EnvelopeClass::Boundary(fragment),
+            _ => EnvelopeClass::Mixed,
+        }
+    }
+
+    /// Whether `(x, y)` falls inside the limit polygon, used for point-cloud filtering (e.g.
+    /// `Lidar`) rather than the feature-envelope path `classify_envelope` serves.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        use geo::Contains;
+
+        if x < self.min_x
+            || y < self.min_y
+            || x > self.min_x + self.tile_size_x * self.cols as f64
+            || y > self.min_y + self.tile_size_y * self.rows as f64
+        {
+            return false;
+        }
+        let col = self.col_for(x);
+        let row = self.row_for(y);
+        match &self.tiles[row * self.cols + col] {
+            TileClass::Inside => true,
+            TileClass::Outside => false,
+            TileClass::Boundary(fragment) => fragment.contains(&Point::new(x, y)),
+        }
+    }
+
+    /// Rebuild this `LimitTo` with its polygon reprojected from `from_epsg` to `to_epsg`, for
+    /// callers (like `Lidar`) that need to test points expressed in a CRS other than
+    /// [`LIMIT_TO_EPSG`].
+    pub fn reprojected(&self, from_epsg: i32, to_epsg: i32) -> Result<Self> {
+        if from_epsg == to_epsg {
+            return Ok(self.clone());
+        }
+        let polygon = self.polygon.map_coords(from_epsg, to_epsg)?;
+        Ok(Self::build(polygon))
+    }
+}
+
+/// Apply `GeoCore::transform_coords` to every coordinate of a `MultiPolygon`.
+trait MapCoordsCrs {
+    fn map_coords(&self, from_epsg: i32, to_epsg: i32) -> Result<MultiPolygon<f64>>;
+}
+
+impl MapCoordsCrs for MultiPolygon<f64> {
+    fn map_coords(&self, from_epsg: i32, to_epsg: i32) -> Result<MultiPolygon<f64>> {
+        use geo::algorithm::map_coords::TryMapCoords;
+        self.try_map_coords(|c| {
+            let (x, y) = GeoCore::transform_coords(from_epsg, to_epsg, c.x, c.y)?;
+            Ok::<_, anyhow::Error>(geo::coord! { x: x, y: y })
+        })
+    }
+}
 
 /// Base struct for geospatial operations
 /// Following Python: class GeoCore
@@ -17,6 +266,11 @@ pub struct GeoCore {
     pub output_path_shp: Option<String>,
     /// Filename for shapefile (Python: _filename_shp)
     pub filename_shp: Option<String>,
+    /// Polygon every geometric loader sharing this `GeoCore` clips its results to, set via
+    /// [`GeoCore::set_limit_to`]. `None` means "no limiting, keep everything".
+    pub limit_to: Option<LimitTo>,
+    /// Cached `Proj` pipelines built by [`GeoCore::reproject`], keyed by CRS pair.
+    proj_cache: ProjCache,
 }
 
 impl GeoCore {
@@ -29,6 +283,8 @@ impl GeoCore {
             output_path: None,
             output_path_shp: None,
             filename_shp: None,
+            limit_to: None,
+            proj_cache: ProjCache::default(),
         }
     }
 
@@ -120,13 +376,531 @@ impl GeoCore {
         Ok(Point::new(x, y))
     }
 
+    /// Great-circle distance in meters between two EPSG:4326 lat/lon points, via the haversine
+    /// formula with [`EARTH_RADIUS_M`]. Cheaper and dependency-free compared to going through
+    /// [`GeoCore::transform_coords`]/`Proj` for a metric CRS, and accurate enough for most
+    /// urban-scale distance checks (haversine assumes a sphere, not WGS84's ellipsoid).
+    pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+        let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+        let delta_phi = (lat2 - lat1).to_radians();
+        let delta_lambda = (lon2 - lon1).to_radians();
+
+        let a = (delta_phi / 2.0).sin().powi(2)
+            + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_M * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Euclidean distance between two points already expressed in this `GeoCore`'s EPSG, after
+    /// confirming that EPSG is a projected (metric) CRS -- a geographic CRS's coordinates are
+    /// degrees, so a raw Euclidean distance between them wouldn't be meters. Use
+    /// [`GeoCore::haversine_distance`] for EPSG:4326 instead.
+    pub fn planar_distance(&self, x1: f64, y1: f64, x2: f64, y2: f64) -> Result<f64> {
+        let srs = SpatialRef::from_epsg(self.epsg as u32)
+            .with_context(|| format!("Failed to build spatial reference for EPSG:{}", self.epsg))?;
+        anyhow::ensure!(
+            !srs.is_geographic(),
+            "planar_distance requires a projected (metric) CRS, but EPSG:{} is geographic -- use haversine_distance instead",
+            self.epsg
+        );
+
+        Ok(((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt())
+    }
+
     /// Get a Proj instance for this CRS
     pub fn get_proj(&self) -> Result<Proj> {
         let crs = format!("EPSG:{}", self.epsg);
         Proj::new_known_crs(&crs, &crs, None).context("Failed to create Proj instance")
     }
+
+    /// Reproject every coordinate of a GeoJson value from `from_epsg` to `to_epsg` in place.
+    /// Walks FeatureCollection -> Feature -> Geometry -> Point/LineString/Polygon/Multi*
+    /// recursively, transforming `[x, y]` pairs and leaving any `z` untouched.
+    /// Skips the transform entirely when `from_epsg == to_epsg` (identity case).
+    /// A per-feature transform failure is logged and the feature's geometry is left
+    /// untouched rather than aborting the whole collection.
+    pub fn reproject_geojson(geojson: &mut GeoJson, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if from_epsg == to_epsg {
+            return Ok(());
+        }
+
+        let from_crs = format!("EPSG:{}", from_epsg);
+        let to_crs = format!("EPSG:{}", to_epsg);
+        let proj = Proj::new_known_crs(&from_crs, &to_crs, None)
+            .context("Failed to create Proj transformation for GeoJSON reprojection")?;
+
+        match geojson {
+            GeoJson::FeatureCollection(fc) => {
+                for feature in &mut fc.features {
+                    if let Some(ref mut geometry) = feature.geometry {
+                        let original = geometry.clone();
+                        if let Err(e) = Self::reproject_geometry(geometry, &proj) {
+                            eprintln!("Warning: failed to reproject feature geometry: {}", e);
+                            *geometry = original;
+                        }
+                    }
+                }
+            }
+            GeoJson::Feature(feature) => {
+                if let Some(ref mut geometry) = feature.geometry {
+                    Self::reproject_geometry(geometry, &proj)?;
+                }
+            }
+            GeoJson::Geometry(geometry) => {
+                Self::reproject_geometry(geometry, &proj)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reproject every coordinate of `geojson` from this `GeoCore`'s current EPSG code to
+    /// `to_epsg`, updating `self.epsg` to `to_epsg` on success. Unlike the static
+    /// [`GeoCore::reproject_geojson`], this reuses a cached `Proj` pipeline across calls for the
+    /// same CRS pair (see [`ProjCache`]), so callers that reproject many small batches -- one
+    /// GeoJSON page per request, one tile per loader pass -- don't pay PROJ's pipeline setup
+    /// cost every time. A no-op when `to_epsg` already matches `self.epsg`.
+    pub fn reproject(&mut self, geojson: &mut GeoJson, to_epsg: i32) -> Result<()> {
+        if self.epsg == to_epsg {
+            return Ok(());
+        }
+
+        let proj = self.proj_cache.get_or_create(self.epsg, to_epsg)?;
+
+        match geojson {
+            GeoJson::FeatureCollection(fc) => {
+                for feature in &mut fc.features {
+                    if let Some(ref mut geometry) = feature.geometry {
+                        Self::reproject_geometry(geometry, &proj)?;
+                    }
+                }
+            }
+            GeoJson::Feature(feature) => {
+                if let Some(ref mut geometry) = feature.geometry {
+                    Self::reproject_geometry(geometry, &proj)?;
+                }
+            }
+            GeoJson::Geometry(geometry) => {
+                Self::reproject_geometry(geometry, &proj)?;
+            }
+        }
+
+        self.epsg = to_epsg;
+        Ok(())
+    }
+
+    /// Reproject a single geojson::Geometry in place, recursing into GeometryCollection.
+    fn reproject_geometry(geometry: &mut Geometry, proj: &Proj) -> Result<()> {
+        match &mut geometry.value {
+            Value::Point(position) => Self::reproject_position(position, proj)?,
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                for position in positions {
+                    Self::reproject_position(position, proj)?;
+                }
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                for line in lines {
+                    for position in line {
+                        Self::reproject_position(position, proj)?;
+                    }
+                }
+            }
+            Value::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    for line in polygon {
+                        for position in line {
+                            Self::reproject_position(position, proj)?;
+                        }
+                    }
+                }
+            }
+            Value::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    Self::reproject_geometry(geometry, proj)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Transform a single `[x, y(, z)]` position in place, preserving any z-coordinate.
+    fn reproject_position(position: &mut Vec<f64>, proj: &Proj) -> Result<()> {
+        if position.len() < 2 {
+            return Ok(());
+        }
+        let (x, y) = proj
+            .convert((position[0], position[1]))
+            .context("Failed to transform GeoJSON coordinate")?;
+        position[0] = x;
+        position[1] = y;
+        Ok(())
+    }
+
+    /// Round every coordinate of a GeoJson value to `precision` decimal places in place. Walks
+    /// FeatureCollection -> Feature -> Geometry -> Point/LineString/Polygon/Multi* recursively,
+    /// the same shape as [`GeoCore::reproject_geojson`], rounding any `z` as well. Six decimal
+    /// places is about 0.1m at mid latitudes -- enough precision for IGN's cadastre/building
+    /// data while cutting serialized size substantially on large FeatureCollections.
+    pub fn round_coordinates(geojson: &mut GeoJson, precision: u32) {
+        match geojson {
+            GeoJson::FeatureCollection(fc) => {
+                for feature in &mut fc.features {
+                    if let Some(ref mut geometry) = feature.geometry {
+                        Self::round_geometry(geometry, precision);
+                    }
+                }
+            }
+            GeoJson::Feature(feature) => {
+                if let Some(ref mut geometry) = feature.geometry {
+                    Self::round_geometry(geometry, precision);
+                }
+            }
+            GeoJson::Geometry(geometry) => Self::round_geometry(geometry, precision),
+        }
+    }
+
+    /// Round a single geojson::Geometry's coordinates in place, recursing into
+    /// GeometryCollection.
+    fn round_geometry(geometry: &mut Geometry, precision: u32) {
+        match &mut geometry.value {
+            Value::Point(position) => Self::round_position(position, precision),
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                for position in positions {
+                    Self::round_position(position, precision);
+                }
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                for line in lines {
+                    for position in line {
+                        Self::round_position(position, precision);
+                    }
+                }
+            }
+            Value::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    for line in polygon {
+                        for position in line {
+                            Self::round_position(position, precision);
+                        }
+                    }
+                }
+            }
+            Value::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    Self::round_geometry(geometry, precision);
+                }
+            }
+        }
+    }
+
+    /// Round a single `[x, y(, z)]` position's components to `precision` decimal places in
+    /// place.
+    fn round_position(position: &mut [f64], precision: u32) {
+        let factor = 10f64.powi(precision as i32);
+        for component in position.iter_mut() {
+            *component = (*component * factor).round() / factor;
+        }
+    }
+
+    /// Replace every Point/MultiPoint geometry in `geojson` (expressed in `layer_epsg`) with a
+    /// circular polygon of `radius_m` meters, so downstream intersection/area code that expects
+    /// areal features doesn't break on bare points. Unlike
+    /// [`crate::geometric::export::from_kml`]'s flat-earth circle approximation, each point is
+    /// reprojected into the metric CRS [`BoundingBox::best_utm_epsg`] picks for it, the ring is
+    /// traced there, and the result is reprojected back to `layer_epsg` -- accurate regardless of
+    /// latitude. Existing polygon/line features are left untouched. Walks
+    /// FeatureCollection -> Feature -> Geometry -> Point/MultiPoint/GeometryCollection
+    /// recursively, the same shape as [`GeoCore::reproject_geojson`].
+    pub fn buffer_points(geojson: &mut GeoJson, layer_epsg: i32, radius_m: f64) -> Result<()> {
+        match geojson {
+            GeoJson::FeatureCollection(fc) => {
+                for feature in &mut fc.features {
+                    if let Some(ref mut geometry) = feature.geometry {
+                        Self::buffer_points_in_geometry(geometry, layer_epsg, radius_m)?;
+                    }
+                }
+            }
+            GeoJson::Feature(feature) => {
+                if let Some(ref mut geometry) = feature.geometry {
+                    Self::buffer_points_in_geometry(geometry, layer_epsg, radius_m)?;
+                }
+            }
+            GeoJson::Geometry(geometry) => {
+                Self::buffer_points_in_geometry(geometry, layer_epsg, radius_m)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Buffer a single geojson::Geometry's Point/MultiPoint in place, recursing into
+    /// GeometryCollection; other geometry types are left untouched.
+    fn buffer_points_in_geometry(
+        geometry: &mut Geometry,
+        layer_epsg: i32,
+        radius_m: f64,
+    ) -> Result<()> {
+        match &geometry.value {
+            Value::Point(position) if position.len() >= 2 => {
+                let ring = Self::circle_ring(position[0], position[1], layer_epsg, radius_m)?;
+                geometry.value =
+                    Value::from(&geo::Geometry::Polygon(Polygon::new(LineString::from(ring), vec![])));
+            }
+            Value::MultiPoint(positions) => {
+                let mut polygons = Vec::with_capacity(positions.len());
+                for position in positions {
+                    if position.len() < 2 {
+                        continue;
+                    }
+                    let ring = Self::circle_ring(position[0], position[1], layer_epsg, radius_m)?;
+                    polygons.push(Polygon::new(LineString::from(ring), vec![]));
+                }
+                geometry.value = Value::from(&geo::Geometry::MultiPolygon(MultiPolygon(polygons)));
+            }
+            Value::GeometryCollection(_) => {
+                if let Value::GeometryCollection(geometries) = &mut geometry.value {
+                    for nested in geometries {
+                        Self::buffer_points_in_geometry(nested, layer_epsg, radius_m)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Trace a closed regular 32-gon of `radius_m` meters around `(x, y)` (in `layer_epsg`): pick
+    /// the metric CRS [`BoundingBox::best_utm_epsg`] recommends for that point, build the ring
+    /// there, and reproject each vertex back to `layer_epsg`.
+    fn circle_ring(x: f64, y: f64, layer_epsg: i32, radius_m: f64) -> Result<Vec<Vec<f64>>> {
+        const SEGMENTS: usize = 32;
+
+        let (lon, lat) = if layer_epsg == 4326 {
+            (x, y)
+        } else {
+            Self::transform_coords(layer_epsg, 4326, x, y)?
+        };
+        let metric_epsg = BoundingBox::new(lon, lat, lon, lat).best_utm_epsg();
+        let (cx, cy) = Self::transform_coords(layer_epsg, metric_epsg, x, y)?;
+
+        let mut ring = Vec::with_capacity(SEGMENTS + 1);
+        for i in 0..=SEGMENTS {
+            let theta = 2.0 * std::f64::consts::PI * (i as f64) / (SEGMENTS as f64);
+            let mx = cx + radius_m * theta.cos();
+            let my = cy + radius_m * theta.sin();
+            let (px, py) = Self::transform_coords(metric_epsg, layer_epsg, mx, my)?;
+            ring.push(vec![px, py]);
+        }
+
+        Ok(ring)
+    }
+
+    /// Set the polygon every loader sharing this `GeoCore` clips its results to.
+    /// `boundary` is a GeoJSON document (Polygon, MultiPolygon, Feature or FeatureCollection
+    /// wrapping one of those) already expressed in [`LIMIT_TO_EPSG`] (EPSG:4326), matching the
+    /// lon/lat output every IGN/OSM collector produces before `GeoCore::reproject_geojson`
+    /// moves it to the target CRS. Call [`GeoCore::clip_to_limit`] after loading data to apply it.
+    pub fn set_limit_to(&mut self, boundary: &[u8]) -> Result<()> {
+        let boundary_str =
+            std::str::from_utf8(boundary).context("limit_to boundary is not valid UTF-8")?;
+        let geojson: GeoJson = boundary_str
+            .parse()
+            .context("Failed to parse limit_to boundary as GeoJSON")?;
+
+        let multi_polygon = Self::geojson_to_multi_polygon(&geojson)
+            .context("limit_to boundary must contain a Polygon or MultiPolygon geometry")?;
+
+        self.limit_to = Some(LimitTo::build(multi_polygon));
+        Ok(())
+    }
+
+    /// Clear a previously set limiting polygon.
+    pub fn clear_limit_to(&mut self) {
+        self.limit_to = None;
+    }
+
+    /// Set an irregular clip shape (a commune boundary, a watershed, ...) in one call: parses
+    /// `geojson` the same way [`GeoCore::set_limit_to`] does, then returns its envelope so the
+    /// caller can drive a cheap rectangular fetch (IGN/OSM bbox request) before applying the
+    /// precise polygon clip via [`GeoCore::clip_to_limit`]. This is the "envelope is the fetch
+    /// window, the polygon is the exact filter" pattern: downloads stay small, but kept features
+    /// are trimmed to the real boundary rather than left as a rectangle.
+    pub fn set_clip_geometry(&mut self, geojson: &str) -> Result<BoundingBox> {
+        let parsed: GeoJson = geojson
+            .parse()
+            .context("Failed to parse clip geometry as GeoJSON")?;
+        let multi_polygon = Self::geojson_to_multi_polygon(&parsed)
+            .context("clip geometry must contain a Polygon or MultiPolygon geometry")?;
+        let envelope = multi_polygon
+            .bounding_rect()
+            .context("clip geometry has no envelope (empty polygon)")?;
+
+        self.set_limit_to(geojson.as_bytes())?;
+
+        Ok(BoundingBox::new(
+            envelope.min().x,
+            envelope.min().y,
+            envelope.max().x,
+            envelope.max().y,
+        ))
+    }
+
+    /// Keep only `geojson`'s features matching a small SQL-like WHERE expression over feature
+    /// properties (`=`, `!=`, `<`, `<=`, `>`, `>=`, `IN (...)`, `AND`/`OR`). A feature missing a
+    /// property the expression references never matches it, except through `IS NULL`. See
+    /// [`crate::geometric::query::parse_where`] for the accepted grammar.
+    pub fn filter(&self, geojson: &mut GeoJson, expr: &str) -> Result<()> {
+        let query_filter = crate::geometric::query::QueryFilter::new()
+            .where_str(expr)
+            .with_context(|| format!("Failed to parse WHERE expression: {expr}"))?;
+        *geojson = query_filter.apply(geojson);
+        Ok(())
+    }
+
+    /// Clip `geojson`'s features to the polygon set by [`GeoCore::set_limit_to`], dropping
+    /// features that fall entirely outside it and trimming features that straddle its boundary.
+    /// A no-op (the document is left untouched) when no limiting polygon has been set.
+    /// `geojson` must already be expressed in [`LIMIT_TO_EPSG`] (EPSG:4326); reproject it back
+    /// to that CRS first if it has already been moved to the target CRS.
+    pub fn clip_to_limit(&self, geojson: &mut GeoJson) -> Result<()> {
+        let Some(limit_to) = &self.limit_to else {
+            return Ok(());
+        };
+
+        match geojson {
+            GeoJson::FeatureCollection(fc) => {
+                let mut kept = Vec::with_capacity(fc.features.len());
+                for feature in fc.features.drain(..) {
+                    if let Some(feature) = Self::clip_feature(feature, limit_to)? {
+                        kept.push(feature);
+                    }
+                }
+                fc.features = kept;
+            }
+            GeoJson::Feature(feature) => {
+                let current = std::mem::replace(
+                    feature,
+                    geojson::Feature {
+                        bbox: None,
+                        geometry: None,
+                        id: None,
+                        properties: None,
+                        foreign_members: None,
+                    },
+                );
+                if let Some(clipped) = Self::clip_feature(current, limit_to)? {
+                    *feature = clipped;
+                }
+            }
+            GeoJson::Geometry(_) => {
+                // A bare geometry carries no feature to drop; nothing to clip against.
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clip a single feature's geometry against `limit_to`, returning `None` when it falls
+    /// entirely outside the limit polygon.
+    fn clip_feature(
+        mut feature: geojson::Feature,
+        limit_to: &LimitTo,
+    ) -> Result<Option<geojson::Feature>> {
+        let Some(geometry) = &feature.geometry else {
+            return Ok(Some(feature));
+        };
+        let Some((min_x, min_y, max_x, max_y)) = Self::geometry_envelope(geometry) else {
+            return Ok(Some(feature));
+        };
+
+        match limit_to.classify_envelope(min_x, min_y, max_x, max_y) {
+            EnvelopeClass::Inside => Ok(Some(feature)),
+            EnvelopeClass::Outside => Ok(None),
+            EnvelopeClass::Boundary(fragment) => {
+                Self::clip_geometry_to(&mut feature, fragment)?;
+                Ok(Some(feature))
+            }
+            EnvelopeClass::Mixed => {
+                Self::clip_geometry_to(&mut feature, &limit_to.polygon)?;
+                Ok(Some(feature))
+            }
+        }
+    }
+
+    /// Intersect `feature`'s geometry with `clip_polygon` in place. Leaves non-polygonal
+    /// geometries (points, lines) untouched, since `BooleanOps::intersection` only applies to
+    /// (multi)polygons; `clip_to_limit` is aimed at the polygonal footprints/parcels every
+    /// loader in this crate produces.
+    fn clip_geometry_to(feature: &mut geojson::Feature, clip_polygon: &MultiPolygon<f64>) -> Result<()> {
+        let Some(geometry) = &feature.geometry else {
+            return Ok(());
+        };
+        let geo_geom: geo::Geometry<f64> = match geometry.try_into() {
+            Ok(geom) => geom,
+            Err(_) => return Ok(()),
+        };
+
+        let multi_polygon = match geo_geom {
+            geo::Geometry::Polygon(p) => MultiPolygon(vec![p]),
+            geo::Geometry::MultiPolygon(mp) => mp,
+            _ => return Ok(()),
+        };
+
+        let clipped = multi_polygon.intersection(clip_polygon);
+        let value = Value::from(&geo::Geometry::MultiPolygon(clipped));
+        feature.geometry = Some(Geometry::new(value));
+        Ok(())
+    }
+
+    /// Compute a geojson Geometry's `(min_x, min_y, max_x, max_y)` envelope via its `geo`
+    /// representation, or `None` for geometries `geo::Geometry` can't convert from.
+    fn geometry_envelope(geometry: &Geometry) -> Option<(f64, f64, f64, f64)> {
+        let geo_geom: geo::Geometry<f64> = geometry.try_into().ok()?;
+        let rect = geo_geom.bounding_rect()?;
+        Some((rect.min().x, rect.min().y, rect.max().x, rect.max().y))
+    }
+
+    /// Pull the Polygon/MultiPolygon geometry out of a GeoJson document, unwrapping
+    /// FeatureCollection (first feature) and Feature wrappers. Used by [`GeoCore::set_limit_to`]
+    /// to accept the same shapes the IGN/OSM boundary endpoints return.
+    pub(crate) fn geojson_to_multi_polygon(geojson: &GeoJson) -> Result<MultiPolygon<f64>> {
+        let geometry = match geojson {
+            GeoJson::Geometry(g) => g,
+            GeoJson::Feature(f) => f
+                .geometry
+                .as_ref()
+                .context("limit_to Feature has no geometry")?,
+            GeoJson::FeatureCollection(fc) => fc
+                .features
+                .first()
+                .context("limit_to FeatureCollection has no features")?
+                .geometry
+                .as_ref()
+                .context("limit_to FeatureCollection's first feature has no geometry")?,
+        };
+
+        let geo_geom: geo::Geometry<f64> = geometry
+            .try_into()
+            .context("Failed to convert limit_to geometry")?;
+
+        match geo_geom {
+            geo::Geometry::Polygon(p) => Ok(MultiPolygon(vec![p])),
+            geo::Geometry::MultiPolygon(mp) => Ok(mp),
+            _ => anyhow::bail!("limit_to geometry must be a Polygon or MultiPolygon"),
+        }
+    }
 }
 
+/// Approximate bounding box of metropolitan France (mainland + Corsica), in EPSG:4326 lon/lat.
+/// Used by [`BoundingBox::best_utm_epsg`] to prefer Lambert-93 (EPSG:2154) -- this crate's
+/// default CRS, see [`GeoCore::default`] -- over a computed UTM zone for bboxes that fall
+/// within it.
+const METROPOLITAN_FRANCE_BBOX: (f64, f64, f64, f64) = (-5.5, 41.0, 9.8, 51.5);
+
+/// Default `densify_pts` used by [`BoundingBox::transform`] -- evenly spaced sample points added
+/// along each edge on top of the four corners, so nonlinear transforms (e.g. EPSG:4326 to
+/// Lambert-93) don't silently shrink the envelope when an edge bows outward past its corners.
+const DEFAULT_DENSIFY_PTS: usize = 21;
+
 /// Bounding box structure
 #[derive(Debug, Clone, Copy)]
 pub struct BoundingBox {
@@ -146,13 +920,148 @@ impl BoundingBox {
         }
     }
 
-    /// Transform bounding box to another CRS
+    /// Transform bounding box to another CRS, densifying each edge with
+    /// [`DEFAULT_DENSIFY_PTS`] extra sample points so a nonlinear transform's outward-bowing
+    /// edges don't get clipped by a corner-only envelope. See [`BoundingBox::transform_densified`]
+    /// to control the sample count (e.g. `densify_pts = 0` for the old corner-only behavior).
     pub fn transform(&self, from_epsg: i32, to_epsg: i32) -> Result<Self> {
-        let (min_x, min_y) = GeoCore::transform_coords(from_epsg, to_epsg, self.min_x, self.min_y)?;
-        let (max_x, max_y) = GeoCore::transform_coords(from_epsg, to_epsg, self.max_x, self.max_y)?;
+        self.transform_densified(from_epsg, to_epsg, DEFAULT_DENSIFY_PTS)
+    }
+
+    /// Transform bounding box to another CRS the way GDAL's `OGR_GT_TransformBounds` does:
+    /// transform the four corners plus `densify_pts` evenly spaced points along each edge, then
+    /// take the min/max of every successfully transformed sample to build the output envelope.
+    /// This matters whenever the transform is nonlinear (e.g. EPSG:4326 -> Lambert-93) -- the
+    /// true extremum of a reprojected rectangle often lies along an edge, not at a corner, so a
+    /// corner-only transform can silently produce an envelope that's too small.
+    ///
+    /// A sample that transforms to a non-finite value (NaN/infinite, which can happen near a
+    /// projection's domain edge) is skipped rather than failing the whole call. `densify_pts = 0`
+    /// reproduces the old corner-only behavior. Errors if none of the four corners transform
+    /// successfully -- densification can't rescue a fundamentally unusable CRS pair.
+    pub fn transform_densified(&self, from_epsg: i32, to_epsg: i32, densify_pts: usize) -> Result<Self> {
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        let mut corners_ok = 0;
+
+        let mut accumulate = |x: f64, y: f64, is_corner: bool| {
+            if let Ok((tx, ty)) = GeoCore::transform_coords(from_epsg, to_epsg, x, y) {
+                if tx.is_finite() && ty.is_finite() {
+                    min_x = min_x.min(tx);
+                    min_y = min_y.min(ty);
+                    max_x = max_x.max(tx);
+                    max_y = max_y.max(ty);
+                    if is_corner {
+                        corners_ok += 1;
+                    }
+                }
+            }
+        };
+
+        accumulate(self.min_x, self.min_y, true);
+        accumulate(self.max_x, self.min_y, true);
+        accumulate(self.max_x, self.max_y, true);
+        accumulate(self.min_x, self.max_y, true);
+
+        if densify_pts > 0 {
+            let edges = [
+                ((self.min_x, self.min_y), (self.max_x, self.min_y)),
+                ((self.max_x, self.min_y), (self.max_x, self.max_y)),
+                ((self.max_x, self.max_y), (self.min_x, self.max_y)),
+                ((self.min_x, self.max_y), (self.min_x, self.min_y)),
+            ];
+            for ((x0, y0), (x1, y1)) in edges {
+                for i in 1..=densify_pts {
+                    let t = i as f64 / (densify_pts as f64 + 1.0);
+                    accumulate(x0 + (x1 - x0) * t, y0 + (y1 - y0) * t, false);
+                }
+            }
+        }
+
+        anyhow::ensure!(
+            corners_ok > 0,
+            "Failed to transform bounding box from EPSG:{} to EPSG:{}: none of the corners reprojected successfully",
+            from_epsg,
+            to_epsg
+        );
 
         Ok(BoundingBox::new(min_x, min_y, max_x, max_y))
     }
+
+    /// Pick a projected CRS appropriate for accurate area/length measurements over this bbox,
+    /// assumed to be in geographic coordinates (EPSG:4326 lon/lat): Lambert-93 (EPSG:2154) when
+    /// the bbox falls within metropolitan France, else the UTM zone covering the bbox centroid
+    /// (`floor((lon+180)/6)+1`), EPSG `326xx` for the northern hemisphere or `327xx` for the
+    /// southern. Lets callers get accurate measurements for any region without manually looking
+    /// up an EPSG code.
+    pub fn best_utm_epsg(&self) -> i32 {
+        let (fr_min_lon, fr_min_lat, fr_max_lon, fr_max_lat) = METROPOLITAN_FRANCE_BBOX;
+        if self.min_x >= fr_min_lon
+            && self.max_x <= fr_max_lon
+            && self.min_y >= fr_min_lat
+            && self.max_y <= fr_max_lat
+        {
+            return 2154;
+        }
+
+        let center_lon = (self.min_x + self.max_x) / 2.0;
+        let center_lat = (self.min_y + self.max_y) / 2.0;
+        let zone = (((center_lon + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60);
+
+        if center_lat >= 0.0 {
+            32600 + zone
+        } else {
+            32700 + zone
+        }
+    }
+
+    /// Reproject this bbox (assumed EPSG:4326) into the projected CRS picked by
+    /// [`BoundingBox::best_utm_epsg`].
+    pub fn to_metric(&self) -> Result<Self> {
+        self.transform(4326, self.best_utm_epsg())
+    }
+
+    /// Whether `(x, y)` -- in this bbox's own CRS -- falls within it, bounds inclusive.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
+
+    /// Midpoint `(x, y)` of this bbox, in its own CRS.
+    pub fn centroid(&self) -> (f64, f64) {
+        (
+            (self.min_x + self.max_x) / 2.0,
+            (self.min_y + self.max_y) / 2.0,
+        )
+    }
+
+    /// Distance in meters between this bbox's centroid and `other`'s, both assumed to be
+    /// expressed in EPSG:4326 lon/lat (the same assumption [`BoundingBox::to_metric`] makes).
+    /// Uses [`GeoCore::haversine_distance`] directly when `epsg == 4326`; otherwise reprojects
+    /// both centroids into `epsg` first and takes the planar Euclidean distance there -- the
+    /// common "switch to an equal-area/metric projection for measurement" pattern
+    /// [`BoundingBox::to_metric`] already uses for area/length calculations.
+    pub fn distance_to(&self, other: &BoundingBox, epsg: i32) -> Result<f64> {
+        let (lon1, lat1) = self.centroid();
+        let (lon2, lat2) = other.centroid();
+
+        if epsg == 4326 {
+            return Ok(GeoCore::haversine_distance(lat1, lon1, lat2, lon2));
+        }
+
+        let (x1, y1) = GeoCore::transform_coords(4326, epsg, lon1, lat1)?;
+        let (x2, y2) = GeoCore::transform_coords(4326, epsg, lon2, lat2)?;
+
+        GeoCore::new(epsg).planar_distance(x1, y1, x2, y2)
+    }
+
+    /// The approximate bounding box of metropolitan France (mainland + Corsica), in EPSG:4326
+    /// lon/lat -- the same bounds [`BoundingBox::best_utm_epsg`] uses to prefer Lambert-93.
+    pub fn metropolitan_france() -> Self {
+        let (min_x, min_y, max_x, max_y) = METROPOLITAN_FRANCE_BBOX;
+        BoundingBox::new(min_x, min_y, max_x, max_y)
+    }
 }
 
 #[cfg(test)]
@@ -172,6 +1081,236 @@ mod tests {
         assert_eq!(bbox.max_x, 1.0);
     }
 
+    #[test]
+    fn test_contains_point() {
+        let bbox = BoundingBox::new(0.0, 0.0, 1.0, 1.0);
+        assert!(bbox.contains_point(0.5, 0.5));
+        assert!(bbox.contains_point(0.0, 1.0));
+        assert!(!bbox.contains_point(1.5, 0.5));
+    }
+
+    #[test]
+    fn test_haversine_distance_paris_to_london() {
+        // Paris to London is roughly 344 km as the crow flies.
+        let d = GeoCore::haversine_distance(48.8566, 2.3522, 51.5074, -0.1278);
+        assert!((300_000.0..400_000.0).contains(&d));
+    }
+
+    #[test]
+    fn test_haversine_distance_zero_for_same_point() {
+        assert_eq!(GeoCore::haversine_distance(48.8566, 2.3522, 48.8566, 2.3522), 0.0);
+    }
+
+    #[test]
+    fn test_planar_distance_rejects_geographic_crs() {
+        let geo_core = GeoCore::new(4326);
+        assert!(geo_core.planar_distance(0.0, 0.0, 3.0, 4.0).is_err());
+    }
+
+    #[test]
+    fn test_planar_distance_in_lambert_93() {
+        let geo_core = GeoCore::new(2154);
+        let d = geo_core.planar_distance(0.0, 0.0, 3.0, 4.0).unwrap();
+        assert_eq!(d, 5.0);
+    }
+
+    #[test]
+    fn test_bounding_box_centroid() {
+        let bbox = BoundingBox::new(0.0, 0.0, 2.0, 4.0);
+        assert_eq!(bbox.centroid(), (1.0, 2.0));
+    }
+
+    #[test]
+    fn test_metropolitan_france_contains_paris_not_new_york() {
+        let france = BoundingBox::metropolitan_france();
+        assert!(france.contains_point(2.3, 48.85));
+        assert!(!france.contains_point(-74.0, 40.7));
+    }
+
+    #[test]
+    fn test_best_utm_epsg_prefers_lambert_93_in_metropolitan_france() {
+        // Paris
+        let bbox = BoundingBox::new(2.2, 48.8, 2.4, 48.9);
+        assert_eq!(bbox.best_utm_epsg(), 2154);
+    }
+
+    #[test]
+    fn test_transform_densified_widens_envelope_over_corners_only() {
+        // A wide bbox over metropolitan France: Lambert-93's edges bow relative to WGS84, so the
+        // densified envelope should be at least as large as the corner-only one in every
+        // direction, and strictly larger on at least one side.
+        let bbox = BoundingBox::new(-4.5, 42.0, 8.5, 51.0);
+        let corners_only = bbox.transform_densified(4326, 2154, 0).unwrap();
+        let densified = bbox.transform_densified(4326, 2154, 21).unwrap();
+
+        assert!(densified.min_x <= corners_only.min_x);
+        assert!(densified.min_y <= corners_only.min_y);
+        assert!(densified.max_x >= corners_only.max_x);
+        assert!(densified.max_y >= corners_only.max_y);
+        assert!(
+            densified.min_x < corners_only.min_x
+                || densified.min_y < corners_only.min_y
+                || densified.max_x > corners_only.max_x
+                || densified.max_y > corners_only.max_y
+        );
+    }
+
+    #[test]
+    fn test_transform_errors_when_no_corner_reprojects() {
+        // EPSG 0 is not a valid CRS, so every corner transform should fail.
+        let bbox = BoundingBox::new(0.0, 0.0, 1.0, 1.0);
+        assert!(bbox.transform_densified(4326, 0, 21).is_err());
+    }
+
+    #[test]
+    fn test_best_utm_epsg_picks_northern_utm_zone_outside_france() {
+        // New York City, UTM zone 18N
+        let bbox = BoundingBox::new(-74.1, 40.6, -73.9, 40.8);
+        assert_eq!(bbox.best_utm_epsg(), 32618);
+    }
+
+    #[test]
+    fn test_best_utm_epsg_picks_southern_utm_zone() {
+        // Sydney, UTM zone 56S
+        let bbox = BoundingBox::new(151.1, -34.0, 151.3, -33.8);
+        assert_eq!(bbox.best_utm_epsg(), 32756);
+    }
+
+    #[test]
+    fn test_limit_to_keeps_feature_inside_and_drops_feature_outside() {
+        let mut geo_core = GeoCore::default();
+        let boundary = r#"{"type":"Polygon","coordinates":[[[0,0],[10,0],[10,10],[0,10],[0,0]]]}"#;
+        geo_core.set_limit_to(boundary.as_bytes()).unwrap();
+
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"id": "inside"}, "geometry": {"type": "Polygon", "coordinates": [[[2,2],[3,2],[3,3],[2,3],[2,2]]]}},
+                {"type": "Feature", "properties": {"id": "outside"}, "geometry": {"type": "Polygon", "coordinates": [[[20,20],[21,20],[21,21],[20,21],[20,20]]]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        geo_core.clip_to_limit(&mut geojson).unwrap();
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 1);
+        assert_eq!(
+            fc.features[0].properties.as_ref().unwrap().get("id").unwrap(),
+            "inside"
+        );
+    }
+
+    #[test]
+    fn test_set_clip_geometry_returns_envelope_and_applies_limit_to() {
+        let mut geo_core = GeoCore::default();
+        let boundary = r#"{"type":"Polygon","coordinates":[[[0,0],[10,0],[10,10],[0,10],[0,0]]]}"#;
+
+        let envelope = geo_core.set_clip_geometry(boundary).unwrap();
+        assert_eq!((envelope.min_x, envelope.min_y, envelope.max_x, envelope.max_y), (0.0, 0.0, 10.0, 10.0));
+
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"id": "inside"}, "geometry": {"type": "Polygon", "coordinates": [[[2,2],[3,2],[3,3],[2,3],[2,2]]]}},
+                {"type": "Feature", "properties": {"id": "outside"}, "geometry": {"type": "Polygon", "coordinates": [[[20,20],[21,20],[21,21],[20,21],[20,20]]]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        geo_core.clip_to_limit(&mut geojson).unwrap();
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_keeps_only_matching_features() {
+        let geo_core = GeoCore::default();
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"nature": "ETANG", "hauteur": 2.0}, "geometry": null},
+                {"type": "Feature", "properties": {"nature": "RIVIERE", "hauteur": 8.0}, "geometry": null}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        geo_core.filter(&mut geojson, "nature = 'ETANG'").unwrap();
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 1);
+        assert_eq!(
+            fc.features[0].properties.as_ref().unwrap().get("nature").unwrap(),
+            "ETANG"
+        );
+    }
+
+    #[test]
+    fn test_limit_to_clips_boundary_straddling_feature() {
+        let mut geo_core = GeoCore::default();
+        let boundary = r#"{"type":"Polygon","coordinates":[[[0,0],[10,0],[10,10],[0,10],[0,0]]]}"#;
+        geo_core.set_limit_to(boundary.as_bytes()).unwrap();
+
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[5,5],[15,5],[15,15],[5,15],[5,5]]]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        geo_core.clip_to_limit(&mut geojson).unwrap();
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 1);
+        let clipped: geo::Geometry<f64> =
+            fc.features[0].geometry.as_ref().unwrap().try_into().unwrap();
+        let area = match clipped {
+            geo::Geometry::MultiPolygon(mp) => mp.unsigned_area(),
+            geo::Geometry::Polygon(p) => p.unsigned_area(),
+            _ => panic!("expected a polygonal geometry"),
+        };
+        assert!(area > 0.0 && area < 100.0);
+    }
+
+    #[test]
+    fn test_reproject_updates_epsg_and_coordinates() {
+        let mut geo_core = GeoCore::new(4326);
+        let mut geojson: GeoJson = r#"{"type":"Point","coordinates":[2.0,48.0]}"#.parse().unwrap();
+
+        let result = geo_core.reproject(&mut geojson, 2154);
+        if result.is_err() {
+            // PROJ data may be unavailable in this environment; nothing more to assert.
+            return;
+        }
+
+        assert_eq!(geo_core.get_epsg(), 2154);
+        let GeoJson::Geometry(Geometry {
+            value: Value::Point(position),
+            ..
+        }) = geojson
+        else {
+            panic!("expected a Point geometry");
+        };
+        assert_ne!(position, vec![2.0, 48.0]);
+
+        // A second reproject to the same target is a no-op and must not error.
+        geo_core.reproject(&mut geojson.clone(), 2154).unwrap();
+    }
+
     #[test]
     fn test_transform_coords() {
         // Test coordinate transformation (if proj data is available)
@@ -184,4 +1323,36 @@ mod tests {
             assert!(y.is_finite());
         }
     }
+
+    #[test]
+    fn test_buffer_points_replaces_point_with_polygon_around_it() {
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [2.3, 48.85]}},
+                {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[2,48],[3,48],[3,49],[2,49],[2,48]]]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        let result = GeoCore::buffer_points(&mut geojson, 4326, 10.0);
+        if result.is_err() {
+            // PROJ data may be unavailable in this environment; nothing more to assert.
+            return;
+        }
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        assert!(matches!(
+            fc.features[0].geometry.as_ref().unwrap().value,
+            Value::Polygon(_)
+        ));
+        // The untouched polygon feature keeps its original geometry type.
+        assert!(matches!(
+            fc.features[1].geometry.as_ref().unwrap().value,
+            Value::Polygon(_)
+        ));
+    }
 }
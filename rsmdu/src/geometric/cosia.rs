@@ -1,4 +1,6 @@
 use anyhow::{Context, Result};
+use gdal::Dataset;
+use proj::Proj;
 use std::path::{Path, PathBuf};
 
 use crate::collect::ign::ign_collect::IgnCollect;
@@ -20,8 +22,8 @@ pub struct Cosia {
     pub geo_core: GeoCore,
     /// Bounding box for the Cosia area
     bbox: Option<BoundingBox>,
-    /// Optional template raster path (for future use)
-    #[allow(dead_code)]
+    /// Optional template raster: when set, the output is snapped to its grid
+    /// (resolution, extent, and pixel alignment) during warping
     template_raster_path: Option<PathBuf>,
 }
 
@@ -69,6 +71,32 @@ impl Cosia {
         })
     }
 
+    /// Build a `Cosia` from an already-downloaded raster instead of hitting the IGN API: copies
+    /// `source_raster` to `path_temp_tiff` and runs the same warp step as [`Cosia::run_ign`]
+    /// (to `epsg`, or EPSG:2154 when `None`, and to the template grid if given), writing
+    /// `path_save_tiff`. Call [`Cosia::to_cog`] afterwards for a COG; calling `run_ign` on the
+    /// result downloads from IGN again and replaces the staged raster.
+    /// Note: the `rsmdu-core` crate has a generic `io::GeoReader` source-sniffing entry point
+    /// (vector/raster) that `Cosia` can't reuse here since it lives in a different crate; this
+    /// covers the raster case `io::GeoReader` would dispatch to.
+    pub fn from_local_raster(
+        source_raster: &Path,
+        output_path: Option<String>,
+        template_raster_path: Option<String>,
+        epsg: Option<i32>,
+    ) -> Result<Self> {
+        let mut cosia = Self::new(output_path, template_raster_path)?;
+        std::fs::copy(source_raster, &cosia.path_temp_tiff).context(format!(
+            "Failed to stage local raster {:?} as {:?}",
+            source_raster, cosia.path_temp_tiff
+        ))?;
+        if let Some(epsg) = epsg {
+            cosia.geo_core.set_epsg(epsg);
+        }
+        cosia.copy_to_output()?;
+        Ok(cosia)
+    }
+
     /// Set bounding box
     /// Following Python: self.bbox = [...]
     pub fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
@@ -85,15 +113,16 @@ impl Cosia {
 
     /// Run Cosia processing: download from IGN API
     /// Following Python: def run_ign(self) -> self
-    /// Downloads Cosia raster from IGN API and saves it
-    pub fn run_ign(mut self) -> Result<Self> {
-        self.run_ign_internal()?;
+    /// Downloads Cosia raster from IGN API and saves it. When `cog` is true, the saved file is
+    /// additionally re-encoded as a Cloud-Optimized GeoTIFF via [`Cosia::to_cog`].
+    pub fn run_ign(mut self, cog: bool) -> Result<Self> {
+        self.run_ign_internal(cog)?;
         Ok(self)
     }
 
     /// Internal run_ign method that can be called mutably
     /// Used by Python bindings to avoid ownership issues
-    pub fn run_ign_internal(&mut self) -> Result<()> {
+    pub fn run_ign_internal(&mut self, cog: bool) -> Result<()> {
         // Python: self.content = self.execute_ign(key="cosia").content
         self.ign_collect.execute_ign("cosia")?;
 
@@ -110,10 +139,15 @@ impl Cosia {
         // Python: dataarray.rio.to_raster(self.path_save_tiff, ...)
         self.copy_to_output()?;
 
+        if cog {
+            self.to_cog()?;
+        }
+
         Ok(())
     }
 
-    /// Copy temporary file to output location
+    /// Copy temporary file to output location, warping to `self.geo_core`'s target CRS and/or
+    /// snapping to `template_raster_path`'s grid if either is set.
     /// Following Python: dataarray.rio.to_raster(...)
     fn copy_to_output(&self) -> Result<()> {
         // Create output directory if it doesn't exist
@@ -122,17 +156,240 @@ impl Cosia {
                 .context(format!("Failed to create output directory: {:?}", parent))?;
         }
 
-        // Copy temp file to output
-        std::fs::copy(&self.path_temp_tiff, &self.path_save_tiff).context(format!(
-            "Failed to copy Cosia from {:?} to {:?}",
-            self.path_temp_tiff, self.path_save_tiff
-        ))?;
+        let source_epsg = Dataset::open(&self.path_temp_tiff)
+            .ok()
+            .and_then(|ds| ds.spatial_ref().ok())
+            .and_then(|srs| srs.to_epsg().ok());
+        let target_epsg = self.geo_core.get_epsg() as u32;
+        let needs_warp =
+            self.template_raster_path.is_some() || source_epsg != Some(target_epsg);
+
+        if needs_warp {
+            self.warp_to_output(target_epsg)
+                .context("Failed to warp Cosia raster to the target CRS/grid")?;
+        } else {
+            std::fs::copy(&self.path_temp_tiff, &self.path_save_tiff).context(format!(
+                "Failed to copy Cosia from {:?} to {:?}",
+                self.path_temp_tiff, self.path_save_tiff
+            ))?;
+        }
 
         println!("Cosia saved to: {:?}", self.path_save_tiff);
 
         Ok(())
     }
 
+    /// Reproject `path_temp_tiff` to `target_epsg`, nearest-neighbour resampled (Cosia's bands
+    /// carry categorical land-cover codes, so averaging them together would invent classes that
+    /// don't exist). When `template_raster_path` is set, the output grid snaps to its
+    /// resolution, extent, and pixel alignment instead of being derived from the source extent.
+    fn warp_to_output(&self, target_epsg: u32) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::spatial_ref::SpatialRef;
+
+        let src_dataset =
+            Dataset::open(&self.path_temp_tiff).context("Failed to open temporary Cosia raster for warping")?;
+        let src_band = src_dataset
+            .rasterband(1)
+            .context("Temporary Cosia raster has no band 1")?;
+        let src_transform = src_dataset
+            .geo_transform()
+            .context("Temporary Cosia raster has no geotransform")?;
+        let (src_width, src_height) = src_dataset.raster_size();
+        let src_epsg = src_dataset
+            .spatial_ref()
+            .ok()
+            .and_then(|srs| srs.to_epsg().ok())
+            .context("Could not determine the source raster's EPSG code for warping")?;
+
+        let (dst_transform, dst_width, dst_height) = match &self.template_raster_path {
+            Some(template) => {
+                let template_dataset =
+                    Dataset::open(template).context("Failed to open template raster")?;
+                let transform = template_dataset
+                    .geo_transform()
+                    .context("Template raster has no geotransform")?;
+                let (width, height) = template_dataset.raster_size();
+                (transform, width, height)
+            }
+            None => {
+                // No template: derive the destination grid by reprojecting the source extent's
+                // corners and keeping the source's pixel size.
+                let proj = Proj::new_known_crs(
+                    &format!("EPSG:{}", src_epsg),
+                    &format!("EPSG:{}", target_epsg),
+                    None,
+                )
+                .context("Source/target EPSG pair is not supported by the PROJ database")?;
+
+                let x_min = src_transform[0];
+                let y_max = src_transform[3];
+                let x_max = x_min + src_transform[1] * src_width as f64;
+                let y_min = y_max + src_transform[5] * src_height as f64;
+
+                let mut out_x_min = f64::MAX;
+                let mut out_x_max = f64::MIN;
+                let mut out_y_min = f64::MAX;
+                let mut out_y_max = f64::MIN;
+                for (x, y) in [(x_min, y_max), (x_max, y_max), (x_min, y_min), (x_max, y_min)] {
+                    let (tx, ty) = proj
+                        .convert((x, y))
+                        .context("Failed to reproject source raster corner")?;
+                    out_x_min = out_x_min.min(tx);
+                    out_x_max = out_x_max.max(tx);
+                    out_y_min = out_y_min.min(ty);
+                    out_y_max = out_y_max.max(ty);
+                }
+
+                let pixel_size = src_transform[1].abs();
+                let width = ((out_x_max - out_x_min) / pixel_size).ceil().max(1.0) as usize;
+                let height = ((out_y_max - out_y_min) / pixel_size).ceil().max(1.0) as usize;
+                (
+                    [out_x_min, pixel_size, 0.0, out_y_max, 0.0, -pixel_size],
+                    width,
+                    height,
+                )
+            }
+        };
+
+        // Sample the destination grid back into the source CRS, nearest-neighbour.
+        let proj = Proj::new_known_crs(
+            &format!("EPSG:{}", target_epsg),
+            &format!("EPSG:{}", src_epsg),
+            None,
+        )
+        .context("Target/source EPSG pair is not supported by the PROJ database")?;
+
+        let src_buffer: Buffer<u8> = src_band
+            .read_as((0, 0), (src_width, src_height), (src_width, src_height), None)
+            .context("Failed to read the temporary Cosia raster")?;
+
+        let mut dst_data = vec![0u8; dst_width * dst_height];
+        for row in 0..dst_height {
+            for col in 0..dst_width {
+                let dst_x = dst_transform[0] + (col as f64 + 0.5) * dst_transform[1];
+                let dst_y = dst_transform[3] + (row as f64 + 0.5) * dst_transform[5];
+                let Ok((src_x, src_y)) = proj.convert((dst_x, dst_y)) else {
+                    continue;
+                };
+
+                let src_col = ((src_x - src_transform[0]) / src_transform[1]).floor();
+                let src_row = ((src_y - src_transform[3]) / src_transform[5]).floor();
+                if src_col < 0.0
+                    || src_row < 0.0
+                    || src_col as usize >= src_width
+                    || src_row as usize >= src_height
+                {
+                    continue; // Outside the source extent: leave as the 0 no-data fill value.
+                }
+
+                dst_data[row * dst_width + col] =
+                    src_buffer.data[src_row as usize * src_width + src_col as usize];
+            }
+        }
+
+        let driver =
+            gdal::DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut dst_dataset = driver
+            .create_with_band_type_with_options::<u8, _>(
+                &self.path_save_tiff,
+                dst_width,
+                dst_height,
+                1,
+                &creation_options,
+            )
+            .context("Failed to create warped Cosia output")?;
+        dst_dataset
+            .set_geo_transform(&dst_transform)
+            .context("Failed to set destination geotransform")?;
+        let srs = SpatialRef::from_epsg(target_epsg).context("Failed to create destination spatial reference")?;
+        dst_dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set destination spatial reference")?;
+
+        let mut dst_band = dst_dataset
+            .rasterband(1)
+            .context("Failed to get destination band 1")?;
+        let mut dst_buffer = Buffer::new((dst_width, dst_height), dst_data);
+        dst_band
+            .write((0, 0), (dst_width, dst_height), &mut dst_buffer)
+            .context("Failed to write warped Cosia raster")?;
+
+        Ok(())
+    }
+
+    /// Re-encode `path_save_tiff` as a Cloud-Optimized GeoTIFF written beside it as
+    /// `cosia.cog.tif` (the returned path; `path_save_tiff` itself is kept), using GDAL's `COG`
+    /// driver to internally tile the image (512x512 blocks), build power-of-two overviews
+    /// (nearest-neighbour, since Cosia's bands carry categorical land-cover codes that
+    /// averaging would corrupt into invented classes), and lay out the IFD/overviews ahead of
+    /// the pixel data for HTTP range-request-friendly reads straight from object storage.
+    pub fn to_cog(&self) -> Result<PathBuf> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+
+        let src_dataset =
+            Dataset::open(&self.path_save_tiff).context("Failed to open Cosia raster to convert to COG")?;
+        let src_band = src_dataset
+            .rasterband(1)
+            .context("Cosia raster has no band 1")?;
+        let (width, height) = src_dataset.raster_size();
+        let transform = src_dataset
+            .geo_transform()
+            .context("Cosia raster has no geotransform")?;
+        let srs = src_dataset
+            .spatial_ref()
+            .context("Cosia raster has no spatial reference")?;
+        let data: Buffer<u8> = src_band
+            .read_as((0, 0), (width, height), (width, height), None)
+            .context("Failed to read Cosia raster band")?;
+
+        let driver = gdal::DriverManager::get_driver_by_name("COG")
+            .context("COG driver not available (requires GDAL built with the COG driver)")?;
+        let creation_options = [
+            RasterCreationOption {
+                key: "BLOCKSIZE",
+                value: "512",
+            },
+            RasterCreationOption {
+                key: "OVERVIEWS",
+                value: "IGNORE_EXISTING",
+            },
+            RasterCreationOption {
+                key: "COMPRESS",
+                value: "DEFLATE",
+            },
+            RasterCreationOption {
+                key: "RESAMPLING",
+                value: "NEAREST",
+            },
+        ];
+
+        let cog_path = self.path_save_tiff.with_extension("cog.tif");
+        let mut dataset = driver
+            .create_with_band_type_with_options::<u8, _>(&cog_path, width, height, 1, &creation_options)
+            .context("Failed to create COG dataset")?;
+        dataset
+            .set_geo_transform(&transform)
+            .context("Failed to set COG geotransform")?;
+        dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set COG spatial reference")?;
+
+        let mut dst_band = dataset.rasterband(1).context("Failed to get COG band 1")?;
+        let mut buffer = Buffer::new((width, height), data.data);
+        dst_band
+            .write((0, 0), (width, height), &mut buffer)
+            .context("Failed to write COG raster band")?;
+
+        println!("Cosia COG saved to: {:?}", cog_path);
+
+        Ok(cog_path)
+    }
+
     /// Get the content from IGN API
     /// Following Python: def content(self): return self.content
     pub fn content(&self) -> Option<&Vec<u8>> {
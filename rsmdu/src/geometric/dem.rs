@@ -4,6 +4,58 @@ use std::path::{Path, PathBuf};
 use crate::collect::ign::ign_collect::IgnCollect;
 use crate::geo_core::{BoundingBox, GeoCore};
 
+/// Resampling mode for [`Dem::reproject_and_save`]. Elevation is a continuous field, so unlike
+/// Cosia's categorical land-cover codes (always nearest-neighbour, see
+/// `Cosia::warp_to_output`), a DEM benefits from smoother interpolation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resampling {
+    Nearest,
+    Bilinear,
+    /// Catmull-Rom cubic convolution (GDAL's `a = -0.5` kernel) over the surrounding 4x4 source
+    /// pixels -- smoother than [`Resampling::Bilinear`] at the cost of needing a wider complete
+    /// neighbourhood, so it leaves a larger nodata border near source voids/edges.
+    Cubic,
+}
+
+/// Paths to the slope/aspect/curvature GeoTIFFs written by [`Dem::compute_terrain`], each
+/// alongside `DEM.tif` and sharing its grid, CRS, and a one-pixel nodata border.
+pub struct TerrainRasters {
+    /// Slope in degrees from horizontal.
+    pub slope_path: PathBuf,
+    /// Compass bearing in degrees (0 = north, clockwise); flat cells are `-1`.
+    pub aspect_path: PathBuf,
+    /// Profile curvature (concavity along the slope direction) from the
+    /// Zevenbergen-Thorne quadratic, positive = convex.
+    pub profile_curvature_path: PathBuf,
+    /// Plan curvature (concavity across the slope direction) from the
+    /// Zevenbergen-Thorne quadratic, positive = convex.
+    pub plan_curvature_path: PathBuf,
+}
+
+/// Default search radius (pixels) for [`Dem::fill_nodata`] when `run`/`run_internal` is asked
+/// to fill voids, mirroring `gdal_fillnodata.py`'s `-md` default.
+const DEFAULT_FILL_MAX_SEARCH_DISTANCE: u32 = 100;
+/// Default smoothing pass count for [`Dem::fill_nodata`] when `run`/`run_internal` is asked to
+/// fill voids, mirroring `gdal_fillnodata.py`'s `-si` default.
+const DEFAULT_FILL_SMOOTHING_ITERATIONS: u32 = 0;
+
+/// Maximum single WMS GetMap image dimension (pixels) this client requests at a time. A DEM
+/// bbox whose `execute_wms`-style width/height (at `resolution` 1 m/px) exceeds this in either
+/// axis is split into a tile grid and mosaicked by [`Dem::fetch_dem_mosaic`], rather than
+/// risking an oversized/erroring GetMap request.
+const MAX_TILE_DIMENSION_PX: u32 = 2048;
+
+/// How overlapping pixels from two mosaicked DEM tiles are combined, used by
+/// [`Dem::set_mosaic_overlap`]/[`Dem::fetch_dem_mosaic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// The later tile (in fetch order) overwrites earlier ones pixel-for-pixel.
+    #[default]
+    LastWins,
+    /// Overlapping valid pixels are averaged.
+    Average,
+}
+
 /// DEM (Digital Elevation Model) structure
 /// Following Python implementation from pymdu.geometric.Dem
 /// Provides methods to collect and process DEM data from IGN API
@@ -24,12 +76,32 @@ pub struct Dem {
     pub geo_core: GeoCore,
     /// Bounding box for the DEM area
     bbox: Option<BoundingBox>,
+    /// Output pixel size in `geo_core`'s target CRS units, following Python's
+    /// `dataarray.rio.reproject(..., resolution=1)` default.
+    resolution: f64,
+    /// Resampling mode used when warping the source raster onto the reprojected grid.
+    resampling: Resampling,
+    /// Boolean mask from the last [`Dem::fill_nodata`] call, `true` where the elevation was
+    /// synthesized rather than read from the source raster, row-major over `fill_mask_size`.
+    fill_mask: Option<Vec<bool>>,
+    /// `(width, height)` in pixels of `fill_mask`.
+    fill_mask_size: Option<(usize, usize)>,
+    /// How overlapping pixels are combined when a bbox spans multiple IGN WMS tiles, see
+    /// [`Dem::set_mosaic_overlap`].
+    mosaic_overlap: MergeStrategy,
+    /// STAC collection id to source elevation data from instead of IGN, see
+    /// [`Dem::fetch_from_stac`]. `None` (the default) keeps using IGN via [`Dem::run`].
+    stac_collection: Option<String>,
 }
 
 impl Dem {
     /// Create a new Dem instance
     /// Following Python: def __init__(self, output_path: str | None = None)
-    pub fn new(output_path: Option<String>) -> Result<Self> {
+    ///
+    /// `stac_collection`, when set, lets [`Dem::fetch_from_stac`] source elevation data from any
+    /// STAC API (e.g. a swisssurface3d-raster elevation collection) instead of IGN, for areas
+    /// outside IGN's coverage. `None` keeps the default IGN-only [`Dem::run`] flow.
+    pub fn new(output_path: Option<String>, stac_collection: Option<String>) -> Result<Self> {
         use crate::collect::global_variables::TEMP_PATH;
 
         let output_path_buf = PathBuf::from(
@@ -74,6 +146,12 @@ impl Dem {
             //path_save_tiff_clip,
             geo_core: GeoCore::default(), // Default to EPSG:2154 (Lambert-93)
             bbox: None,
+            resolution: 1.0,
+            resampling: Resampling::Nearest,
+            fill_mask: None,
+            fill_mask_size: None,
+            mosaic_overlap: MergeStrategy::default(),
+            stac_collection,
         })
     }
 
@@ -90,19 +168,64 @@ impl Dem {
         self.geo_core = GeoCore::new(epsg);
     }
 
+    /// Set the output pixel size (in `geo_core`'s target CRS units) used by
+    /// [`Dem::reproject_and_save`]. Following Python:
+    /// `dataarray.rio.reproject(dst_crs=self._epsg, resolution=<resolution>)`.
+    pub fn set_resolution(&mut self, resolution: f64) {
+        self.resolution = resolution;
+    }
+
+    /// Set how overlapping pixels are combined when `run`/`run_internal` has to split the bbox
+    /// into multiple IGN WMS tiles (see [`Dem::fetch_dem_mosaic`]). Defaults to
+    /// [`MergeStrategy::LastWins`].
+    pub fn set_mosaic_overlap(&mut self, strategy: MergeStrategy) {
+        self.mosaic_overlap = strategy;
+    }
+
+    /// Set the resampling mode used by [`Dem::reproject_and_save`].
+    pub fn set_resampling(&mut self, resampling: Resampling) {
+        self.resampling = resampling;
+    }
+
+    /// Warp the already-downloaded source raster (`run`/`run_internal` must have fetched it
+    /// first) to `target_epsg` at `resolution_m`, using `resampling`, overwriting
+    /// [`Dem::get_path_save_tiff`] and regenerating [`Dem::get_path_save_mask`] so the mask
+    /// stays aligned with the newly reprojected TIFF's extent. A thin public wrapper around
+    /// [`Dem::reproject_and_save`] -- the same warp `run`/`run_internal` already perform with
+    /// `geo_core`'s default EPSG:2154 at 1m -- for callers who want to re-reproject data already
+    /// on disk (e.g. to try a different resolution or resampling kernel) without re-fetching
+    /// from IGN.
+    pub fn reproject(
+        &mut self,
+        target_epsg: i32,
+        resolution_m: f64,
+        resampling: Resampling,
+    ) -> Result<&Path> {
+        self.set_crs(target_epsg);
+        self.set_resolution(resolution_m);
+        self.set_resampling(resampling);
+        self.reproject_and_save(None)?;
+        self.generate_mask_and_adapt_dem()?;
+        Ok(&self.path_save_tiff)
+    }
+
     /// Run DEM processing
     /// Following Python: def run(self, shape: tuple = None)
-    /// Downloads DEM from IGN API, reprojects it, and saves it
-    pub fn run(mut self, shape: Option<(u32, u32)>) -> Result<Self> {
-        self.run_internal(shape)?;
+    /// Downloads DEM from IGN API, reprojects it, and saves it. `fill` additionally runs
+    /// [`Dem::fill_nodata`] (with default search distance/smoothing) right after reprojection,
+    /// to close the nodata voids IGN tiles commonly carry before downstream processing.
+    pub fn run(mut self, shape: Option<(u32, u32)>, fill: bool) -> Result<Self> {
+        self.run_internal(shape, fill)?;
         Ok(self)
     }
 
     /// Internal run method that can be called mutably
     /// Used by Python bindings to avoid ownership issues
-    pub fn run_internal(&mut self, shape: Option<(u32, u32)>) -> Result<()> {
+    pub fn run_internal(&mut self, shape: Option<(u32, u32)>, fill: bool) -> Result<()> {
         // Python: self.content = self.execute_ign(key="dem").content
-        self.ign_collect.execute_ign("dem")?;
+        // Splits into multiple WMS requests and mosaics them when the bbox is too large for a
+        // single GetMap response; otherwise behaves exactly like the old single execute_ign call.
+        self.fetch_dem_mosaic()?;
 
         // The DEM TIFF should have been saved to path_temp_tiff by execute_wms
         // Python: dataarray = rxr.open_rasterio(self.path_temp_tiff)
@@ -117,6 +240,14 @@ impl Dem {
         // Python: self.dataarray = dataarray.rio.reproject(dst_crs=self._epsg, resolution=1, ...)
         self.reproject_and_save(shape)?;
 
+        if fill {
+            self.fill_nodata(
+                DEFAULT_FILL_MAX_SEARCH_DISTANCE,
+                DEFAULT_FILL_SMOOTHING_ITERATIONS,
+            )
+            .context("Failed to fill DEM nodata voids")?;
+        }
+
         // Generate mask
         // Python: self.__generate_mask_and_adapt_dem()
         self.generate_mask_and_adapt_dem()?;
@@ -124,43 +255,730 @@ impl Dem {
         Ok(())
     }
 
-    /// Reproject raster and save to output file
-    /// Following Python: dataarray.rio.reproject(...)
-    /// NOTE: Full GDAL reprojection is complex - this is a placeholder
-    /// TODO: Implement full raster reprojection using GDAL or dedicated raster crate
-    /// Python: dataarray.rio.reproject(dst_crs=self._epsg, resolution=1, resampling=Resampling.nearest)
-    ///         dataarray.rio.to_raster(..., compress="lzw", bigtiff="YES", ...)
-    fn reproject_and_save(&self, _shape: Option<(u32, u32)>) -> Result<()> {
-        // For now, copy the file as-is
-        // Full reprojection would require:
-        // 1. Reading the input GeoTIFF with geotiff or gdal
-        // 2. Reprojecting to target CRS (EPSG:2154 by default) using proj
-        // 3. Resampling to 1m resolution
-        // 4. Saving with LZW compression using gdal
+    /// Fill nodata voids (water bodies, cloud gaps) in the reprojected DEM with GDAL's
+    /// `FillNodata` algorithm: for each nodata pixel, search outward along the 4 axis and 4
+    /// diagonal directions up to `max_search_distance` pixels, take the first valid elevation
+    /// found in each direction, and set the fill value to the inverse-distance-weighted average
+    /// of whichever directions found one (directions that hit no valid pixel within range are
+    /// skipped). Then run `smoothing_iterations` passes of a 3x3 mean filter over the filled
+    /// pixels only, to blend away seams between directions. Pixels with no valid neighbour in
+    /// any direction are left as nodata.
+    ///
+    /// Must run after [`Dem::reproject_and_save`] -- it operates on `path_save_tiff` in place.
+    /// Records which pixels were synthesized in [`Dem::fill_mask`] so callers can flag
+    /// interpolated terrain downstream (e.g. in shadow/CFD processing).
+    pub fn fill_nodata(&mut self, max_search_distance: u32, smoothing_iterations: u32) -> Result<()> {
+        use gdal::raster::Buffer;
+        use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+
+        let mut dataset = Dataset::open_ex(
+            &self.path_save_tiff,
+            DatasetOptions {
+                open_flags: GdalOpenFlags::GDAL_OF_UPDATE | GdalOpenFlags::GDAL_OF_RASTER,
+                ..Default::default()
+            },
+        )
+        .context("Failed to open reprojected DEM for nodata filling")?;
+
+        let (width, height) = dataset.raster_size();
+
+        let (nodata, original) = {
+            let band = dataset.rasterband(1).context("Reprojected DEM has no band 1")?;
+            let nodata = band
+                .no_data_value()
+                .context("Reprojected DEM has no nodata value set; nothing to fill")?;
+            let buffer: Buffer<f32> = band
+                .read_as((0, 0), (width, height), (width, height), None)
+                .context("Failed to read reprojected DEM")?;
+            (nodata, buffer.data)
+        };
 
-        // Create output directory if it doesn't exist
-        if let Some(parent) = self.path_save_tiff.parent() {
-            std::fs::create_dir_all(parent)
-                .context(format!("Failed to create output directory: {:?}", parent))?;
+        let is_nodata = |v: f32| (v as f64 - nodata).abs() < f64::EPSILON;
+
+        const DIRECTIONS: [(i32, i32); 8] = [
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ];
+
+        let mut data = original.clone();
+        let mut synthesized = vec![false; width * height];
+
+        for row in 0..height {
+            for col in 0..width {
+                let idx = row * width + col;
+                if !is_nodata(original[idx]) {
+                    continue;
+                }
+
+                let mut weighted_sum = 0.0f64;
+                let mut weight_sum = 0.0f64;
+                for (dx, dy) in DIRECTIONS {
+                    for dist in 1..=max_search_distance as i32 {
+                        let nr = row as i32 + dy * dist;
+                        let nc = col as i32 + dx * dist;
+                        if nr < 0 || nc < 0 || nr >= height as i32 || nc >= width as i32 {
+                            break;
+                        }
+                        let v = original[nr as usize * width + nc as usize];
+                        if !is_nodata(v) {
+                            let weight = 1.0 / dist as f64;
+                            weighted_sum += v as f64 * weight;
+                            weight_sum += weight;
+                            break;
+                        }
+                    }
+                }
+
+                if weight_sum > 0.0 {
+                    data[idx] = (weighted_sum / weight_sum) as f32;
+                    synthesized[idx] = true;
+                }
+            }
+        }
+
+        for _ in 0..smoothing_iterations {
+            let snapshot = data.clone();
+            for row in 0..height {
+                for col in 0..width {
+                    let idx = row * width + col;
+                    if !synthesized[idx] {
+                        continue;
+                    }
+
+                    let mut sum = 0.0f64;
+                    let mut count = 0u32;
+                    for ddy in -1..=1i32 {
+                        for ddx in -1..=1i32 {
+                            let nr = row as i32 + ddy;
+                            let nc = col as i32 + ddx;
+                            if nr < 0 || nc < 0 || nr >= height as i32 || nc >= width as i32 {
+                                continue;
+                            }
+                            let v = snapshot[nr as usize * width + nc as usize];
+                            if is_nodata(v) {
+                                continue;
+                            }
+                            sum += v as f64;
+                            count += 1;
+                        }
+                    }
+                    if count > 0 {
+                        data[idx] = (sum / count as f64) as f32;
+                    }
+                }
+            }
         }
 
-        // Copy temp file to output (simplified - should reproject)
-        std::fs::copy(&self.path_temp_tiff, &self.path_save_tiff).context(format!(
-            "Failed to copy DEM from {:?} to {:?}",
-            self.path_temp_tiff, self.path_save_tiff
-        ))?;
+        let mut band = dataset.rasterband(1).context("Reprojected DEM has no band 1")?;
+        let mut buffer = Buffer::new((width, height), data);
+        band.write((0, 0), (width, height), &mut buffer)
+            .context("Failed to write nodata-filled DEM raster")?;
+
+        let filled = synthesized.iter().filter(|&&s| s).count();
+        println!(
+            "DEM nodata fill: {} of {} pixels synthesized (max_search_distance={}, smoothing_iterations={})",
+            filled,
+            width * height,
+            max_search_distance,
+            smoothing_iterations
+        );
+
+        self.fill_mask = Some(synthesized);
+        self.fill_mask_size = Some((width, height));
+
+        Ok(())
+    }
+
+    /// The boolean mask from the last [`Dem::fill_nodata`] call, `true` where the elevation at
+    /// that pixel was synthesized rather than read from the source raster, plus its
+    /// `(width, height)` in pixels. `None` until `fill_nodata` has run.
+    pub fn fill_mask(&self) -> Option<(&[bool], usize, usize)> {
+        let (width, height) = self.fill_mask_size?;
+        self.fill_mask.as_deref().map(|mask| (mask, width, height))
+    }
+
+    /// Fetch the DEM for `self.bbox`, transparently splitting into a grid of IGN WMS tiles and
+    /// mosaicking them when the bbox is too wide/tall for a single GetMap request (see
+    /// [`MAX_TILE_DIMENSION_PX`]). Leaves the result at `self.path_temp_tiff`, exactly where a
+    /// single `execute_ign("dem")` call would have -- `reproject_and_save` downstream is
+    /// unchanged either way.
+    fn fetch_dem_mosaic(&mut self) -> Result<()> {
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before fetching DEM data")?;
+
+        let tile_bboxes = Self::split_bbox_into_tiles(bbox);
+        if tile_bboxes.len() <= 1 {
+            self.ign_collect.execute_ign("dem")?;
+            return Ok(());
+        }
 
         println!(
-            "DEM saved to: {:?} (reprojection temporarily disabled)",
-            self.path_save_tiff
+            "DEM bbox exceeds a single WMS GetMap request; fetching {} tiles to mosaic",
+            tile_bboxes.len()
         );
+
+        let mut tile_paths: Vec<PathBuf> = Vec::with_capacity(tile_bboxes.len());
+        for (i, tile_bbox) in tile_bboxes.iter().enumerate() {
+            self.ign_collect
+                .set_bbox(tile_bbox.min_x, tile_bbox.min_y, tile_bbox.max_x, tile_bbox.max_y);
+            self.ign_collect.execute_ign("dem")?;
+
+            if !self.path_temp_tiff.exists() {
+                anyhow::bail!("DEM tile {} not found at {:?}", i, self.path_temp_tiff);
+            }
+
+            let tile_path = self.path_temp_tiff.with_file_name(format!("dem_tile_{}.tiff", i));
+            std::fs::rename(&self.path_temp_tiff, &tile_path)
+                .context("Failed to stash fetched DEM tile before requesting the next one")?;
+            tile_paths.push(tile_path);
+        }
+
+        // Restore the caller's full bbox on ign_collect now that every tile has been fetched.
+        self.ign_collect
+            .set_bbox(bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y);
+
+        let result = self.mosaic_tiles(&tile_paths);
+
+        for tile_path in &tile_paths {
+            let _ = std::fs::remove_file(tile_path);
+        }
+
+        result
+    }
+
+    /// Fetch the DEM for `self.bbox` from the STAC endpoint at `endpoint`, restricted to
+    /// [`Dem::new`]'s `stac_collection`, as an alternative to [`Dem::fetch_dem_mosaic`]'s IGN
+    /// WMS flow for areas outside IGN's coverage (e.g. a swisssurface3d-raster elevation
+    /// collection over Switzerland). Picks the best item (lowest cloud cover, most recent --
+    /// `StacClient::search` already sorts for this) and downloads its `"dem"`-or-`"dsm"`-role
+    /// asset, falling back to the first GeoTIFF-media-type asset. Leaves the result at
+    /// `self.path_temp_tiff`, exactly where `fetch_dem_mosaic` would have. `run`/`run_internal`
+    /// always fetch from IGN again and overwrite it; [`Dem::reproject`] warps the STAC file as is.
+    pub fn fetch_from_stac(&mut self, endpoint: &str) -> Result<()> {
+        use crate::collect::stac::StacClient;
+
+        let collection = self
+            .stac_collection
+            .clone()
+            .context("A STAC collection id must be set via Dem::new before calling fetch_from_stac")?;
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before fetching DEM data")?;
+
+        let mut client = StacClient::new(endpoint);
+        client.set_collection(collection);
+        let items = client.search(&bbox, None)?;
+
+        let item = items
+            .first()
+            .context("STAC search returned no items for this bbox/collection")?;
+        let asset = item
+            .asset_by_role("dem")
+            .or_else(|| item.asset_by_role("dsm"))
+            .or_else(|| item.asset_by_media_type("tiff"))
+            .context("STAC item has no DEM/DSM or GeoTIFF asset")?;
+
+        let bytes = client.download_asset(asset)?;
+        std::fs::write(&self.path_temp_tiff, bytes)
+            .context("Failed to write downloaded STAC DEM asset to the temp TIFF path")?;
+
+        Ok(())
+    }
+
+    /// Split `bbox` (EPSG:4326) into a grid of sub-bboxes, each no larger than
+    /// [`MAX_TILE_DIMENSION_PX`] on a side at `execute_wms`'s 1 m/px resolution. Returns a
+    /// single-element vec (the original bbox) when no split is needed.
+    fn split_bbox_into_tiles(bbox: BoundingBox) -> Vec<BoundingBox> {
+        use std::f64::consts::PI;
+
+        let lat_center = (bbox.min_y + bbox.max_y) / 2.0;
+        let deg_to_m_lat = 111320.0;
+        let deg_to_m_lon = 40075000.0 * (lat_center * PI / 180.0).cos() / 360.0;
+
+        let width_px = ((bbox.max_x - bbox.min_x) * deg_to_m_lon).abs().ceil() as u32;
+        let height_px = ((bbox.max_y - bbox.min_y) * deg_to_m_lat).abs().ceil() as u32;
+
+        let n_cols = width_px.div_ceil(MAX_TILE_DIMENSION_PX).max(1);
+        let n_rows = height_px.div_ceil(MAX_TILE_DIMENSION_PX).max(1);
+
+        if n_cols <= 1 && n_rows <= 1 {
+            return vec![bbox];
+        }
+
+        let tile_width = (bbox.max_x - bbox.min_x) / n_cols as f64;
+        let tile_height = (bbox.max_y - bbox.min_y) / n_rows as f64;
+
+        let mut tiles = Vec::with_capacity((n_cols * n_rows) as usize);
+        for row in 0..n_rows {
+            for col in 0..n_cols {
+                tiles.push(BoundingBox::new(
+                    bbox.min_x + col as f64 * tile_width,
+                    bbox.min_y + row as f64 * tile_height,
+                    bbox.min_x + (col + 1) as f64 * tile_width,
+                    bbox.min_y + (row + 1) as f64 * tile_height,
+                ));
+            }
+        }
+        tiles
+    }
+
+    /// Validate and merge fetched DEM tiles into a single raster at `self.path_temp_tiff`.
+    /// Candidate tiles must share the first tile's CRS, band count, and pixel datatype -- the
+    /// standard mosaic-params filter -- mismatched tiles are skipped with a warning rather than
+    /// aborting the whole mosaic. Tiles are composited onto a common union-extent grid (at the
+    /// first compatible tile's pixel size) according to `self.mosaic_overlap`.
+    fn mosaic_tiles(&self, tile_paths: &[PathBuf]) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::spatial_ref::SpatialRef;
+        use gdal::{Dataset, DriverManager};
+
+        struct Tile {
+            geotransform: [f64; 6],
+            width: usize,
+            height: usize,
+            data: Vec<f32>,
+            nodata: Option<f64>,
+        }
+
+        let mut reference: Option<(Option<u32>, usize, gdal::raster::GdalDataType)> = None;
+        let mut reference_srs: Option<SpatialRef> = None;
+        let mut tiles: Vec<Tile> = Vec::with_capacity(tile_paths.len());
+
+        for path in tile_paths {
+            let dataset =
+                Dataset::open(path).with_context(|| format!("Failed to open DEM tile {:?}", path))?;
+            let srs = dataset.spatial_ref().ok();
+            let epsg = srs.as_ref().and_then(|s| s.to_epsg().ok());
+            let band_count = dataset.raster_count();
+            let band = dataset
+                .rasterband(1)
+                .with_context(|| format!("DEM tile {:?} has no band 1", path))?;
+            let dtype = band.band_type();
+
+            match &reference {
+                None => {
+                    reference = Some((epsg, band_count, dtype));
+                    reference_srs = srs;
+                }
+                Some((ref_epsg, ref_band_count, ref_dtype)) => {
+                    if epsg != *ref_epsg || band_count != *ref_band_count || dtype != *ref_dtype {
+                        println!(
+                            "Skipping incompatible DEM tile {:?}: CRS/band count/datatype does not match the first tile",
+                            path
+                        );
+                        continue;
+                    }
+                }
+            }
+
+            let geotransform = dataset
+                .geo_transform()
+                .with_context(|| format!("DEM tile {:?} has no geotransform", path))?;
+            let (width, height) = dataset.raster_size();
+            let nodata = band.no_data_value();
+            let buffer: Buffer<f32> = band
+                .read_as((0, 0), (width, height), (width, height), None)
+                .with_context(|| format!("Failed to read DEM tile {:?}", path))?;
+
+            tiles.push(Tile {
+                geotransform,
+                width,
+                height,
+                data: buffer.data,
+                nodata,
+            });
+        }
+
+        if tiles.is_empty() {
+            anyhow::bail!("No compatible DEM tiles were fetched; mosaicking failed");
+        }
+
+        let px_w = tiles[0].geotransform[1];
+        let px_h = tiles[0].geotransform[5];
+
+        let mut union_min_x = f64::MAX;
+        let mut union_max_x = f64::MIN;
+        let mut union_min_y = f64::MAX;
+        let mut union_max_y = f64::MIN;
+        for tile in &tiles {
+            let x0 = tile.geotransform[0];
+            let y0 = tile.geotransform[3];
+            let x1 = x0 + tile.width as f64 * tile.geotransform[1];
+            let y1 = y0 + tile.height as f64 * tile.geotransform[5];
+            union_min_x = union_min_x.min(x0.min(x1));
+            union_max_x = union_max_x.max(x0.max(x1));
+            union_min_y = union_min_y.min(y0.min(y1));
+            union_max_y = union_max_y.max(y0.max(y1));
+        }
+
+        let dst_width = ((union_max_x - union_min_x) / px_w).round().max(1.0) as usize;
+        let dst_height = ((union_max_y - union_min_y) / px_h.abs()).round().max(1.0) as usize;
+        let dst_nodata = tiles.iter().find_map(|t| t.nodata).unwrap_or(-9999.0);
+
+        let mut dst_data = vec![dst_nodata as f32; dst_width * dst_height];
+        let mut overlap_counts = vec![0u32; dst_width * dst_height];
+
+        for tile in &tiles {
+            let is_tile_nodata =
+                |v: f32| tile.nodata.map(|n| (v as f64 - n).abs() < f64::EPSILON).unwrap_or(false);
+            let col_offset = ((tile.geotransform[0] - union_min_x) / px_w).round() as isize;
+            let row_offset = ((tile.geotransform[3] - union_max_y) / px_h).round() as isize;
+
+            for row in 0..tile.height {
+                let dst_row = row as isize + row_offset;
+                if dst_row < 0 || dst_row as usize >= dst_height {
+                    continue;
+                }
+                for col in 0..tile.width {
+                    let dst_col = col as isize + col_offset;
+                    if dst_col < 0 || dst_col as usize >= dst_width {
+                        continue;
+                    }
+
+                    let value = tile.data[row * tile.width + col];
+                    if is_tile_nodata(value) {
+                        continue;
+                    }
+
+                    let dst_idx = dst_row as usize * dst_width + dst_col as usize;
+                    match self.mosaic_overlap {
+                        MergeStrategy::LastWins => {
+                            dst_data[dst_idx] = value;
+                        }
+                        MergeStrategy::Average => {
+                            let count = overlap_counts[dst_idx];
+                            dst_data[dst_idx] = if count == 0 {
+                                value
+                            } else {
+                                (((dst_data[dst_idx] as f64 * count as f64) + value as f64)
+                                    / (count as f64 + 1.0)) as f32
+                            };
+                            overlap_counts[dst_idx] = count + 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        let driver = DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut dst_dataset = driver
+            .create_with_band_type_with_options::<f32, _>(
+                &self.path_temp_tiff,
+                dst_width,
+                dst_height,
+                1,
+                &creation_options,
+            )
+            .context("Failed to create mosaicked DEM output")?;
+        dst_dataset
+            .set_geo_transform(&[union_min_x, px_w, 0.0, union_max_y, 0.0, px_h])
+            .context("Failed to set mosaic geotransform")?;
+        if let Some(srs) = reference_srs {
+            dst_dataset
+                .set_spatial_ref(&srs)
+                .context("Failed to set mosaic spatial reference")?;
+        }
+
+        let mut dst_band = dst_dataset
+            .rasterband(1)
+            .context("Mosaicked DEM has no band 1")?;
+        dst_band
+            .set_no_data_value(dst_nodata)
+            .context("Failed to set mosaic nodata value")?;
+        let mut dst_buffer = Buffer::new((dst_width, dst_height), dst_data);
+        dst_band
+            .write((0, 0), (dst_width, dst_height), &mut dst_buffer)
+            .context("Failed to write mosaicked DEM raster")?;
+
         println!(
-            "  TODO: Implement full GDAL reprojection to EPSG:{}",
-            self.geo_core.get_epsg()
+            "Mosaicked {} DEM tiles into {:?} ({}x{})",
+            tiles.len(),
+            self.path_temp_tiff,
+            dst_width,
+            dst_height
         );
+
+        Ok(())
+    }
+
+    /// Reproject the source DEM raster to `geo_core`'s EPSG and save as an LZW-compressed
+    /// GeoTIFF. Following Python: `dataarray.rio.reproject(dst_crs=self._epsg, resolution=1,
+    /// resampling=...)` then `dataarray.rio.to_raster(..., compress="lzw", bigtiff="YES", ...)`.
+    ///
+    /// This is the classic warp loop, the same shape as `Cosia::warp_to_output`'s categorical
+    /// warp but with selectable resampling and nodata propagation for continuous elevation
+    /// data: reproject the source extent's corners to build the destination grid at
+    /// [`Dem::set_resolution`]'s pixel size (or, if `shape` is given, stretched to that pixel
+    /// count instead), then for every destination pixel back-project its center into the
+    /// source CRS and sample.
+    ///
+    /// CRS axis order: `proj::Proj::convert` always takes/returns coordinates in each CRS's
+    /// PROJ-normalized (easting, northing) order -- for EPSG:4326 that is (lon, lat), not the
+    /// authority-defined (lat, lon) -- so `(x, y)` tuples below are never silently swapped. A
+    /// DEM warped through a naive lat/lon-ordered transform would come out mirrored on one axis.
+    fn reproject_and_save(&self, shape: Option<(u32, u32)>) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::spatial_ref::SpatialRef;
+        use gdal::Dataset;
+        use proj::Proj;
+
+        // Create output directory if it doesn't exist
+        if let Some(parent) = self.path_save_tiff.parent() {
+            std::fs::create_dir_all(parent)
+                .context(format!("Failed to create output directory: {:?}", parent))?;
+        }
+
+        let src_dataset = Dataset::open(&self.path_temp_tiff)
+            .context("Failed to open temporary DEM raster for reprojection")?;
+        let src_band = src_dataset
+            .rasterband(1)
+            .context("Temporary DEM raster has no band 1")?;
+        let src_nodata = src_band.no_data_value();
+        let src_transform = src_dataset
+            .geo_transform()
+            .context("Temporary DEM raster has no geotransform")?;
+        let (src_width, src_height) = src_dataset.raster_size();
+        let src_epsg = src_dataset
+            .spatial_ref()
+            .ok()
+            .and_then(|srs| srs.to_epsg().ok())
+            .context("Could not determine the source raster's EPSG code for reprojection")?;
+        let target_epsg = self.geo_core.get_epsg() as u32;
+
+        let src_buffer: Buffer<f32> = src_band
+            .read_as((0, 0), (src_width, src_height), (src_width, src_height), None)
+            .context("Failed to read the temporary DEM raster")?;
+
+        // Forward-project the source extent's corners to build the destination bounds.
+        let fwd = Proj::new_known_crs(
+            &format!("EPSG:{}", src_epsg),
+            &format!("EPSG:{}", target_epsg),
+            None,
+        )
+        .context("Source/target EPSG pair is not supported by the PROJ database")?;
+
+        let x_min = src_transform[0];
+        let y_max = src_transform[3];
+        let x_max = x_min + src_transform[1] * src_width as f64;
+        let y_min = y_max + src_transform[5] * src_height as f64;
+
+        let mut out_x_min = f64::MAX;
+        let mut out_x_max = f64::MIN;
+        let mut out_y_min = f64::MAX;
+        let mut out_y_max = f64::MIN;
+        for (x, y) in [(x_min, y_max), (x_max, y_max), (x_min, y_min), (x_max, y_min)] {
+            let (tx, ty) = fwd
+                .convert((x, y))
+                .context("Failed to reproject source raster corner")?;
+            out_x_min = out_x_min.min(tx);
+            out_x_max = out_x_max.max(tx);
+            out_y_min = out_y_min.min(ty);
+            out_y_max = out_y_max.max(ty);
+        }
+
+        let (dst_transform, dst_width, dst_height) = match shape {
+            Some((w, h)) => {
+                let pixel_width = (out_x_max - out_x_min) / w as f64;
+                let pixel_height = (out_y_max - out_y_min) / h as f64;
+                (
+                    [out_x_min, pixel_width, 0.0, out_y_max, 0.0, -pixel_height],
+                    w as usize,
+                    h as usize,
+                )
+            }
+            None => {
+                let width = ((out_x_max - out_x_min) / self.resolution).ceil().max(1.0) as usize;
+                let height = ((out_y_max - out_y_min) / self.resolution).ceil().max(1.0) as usize;
+                (
+                    [out_x_min, self.resolution, 0.0, out_y_max, 0.0, -self.resolution],
+                    width,
+                    height,
+                )
+            }
+        };
+
+        // Back-project each destination pixel center into the source CRS for sampling.
+        let inv = Proj::new_known_crs(
+            &format!("EPSG:{}", target_epsg),
+            &format!("EPSG:{}", src_epsg),
+            None,
+        )
+        .context("Target/source EPSG pair is not supported by the PROJ database")?;
+
+        let fill = src_nodata.map(|v| v as f32).unwrap_or(f32::NAN);
+        let mut dst_data = vec![fill; dst_width * dst_height];
+
+        let is_nodata = |v: f32| src_nodata.is_some_and(|nd| (v as f64 - nd).abs() < f64::EPSILON);
+
+        for row in 0..dst_height {
+            for col in 0..dst_width {
+                let dst_x = dst_transform[0] + (col as f64 + 0.5) * dst_transform[1];
+                let dst_y = dst_transform[3] + (row as f64 + 0.5) * dst_transform[5];
+                let Ok((src_x, src_y)) = inv.convert((dst_x, dst_y)) else {
+                    continue;
+                };
+
+                // Inverse of the (north-up, no-rotation) source geotransform.
+                let src_col_f = (src_x - src_transform[0]) / src_transform[1];
+                let src_row_f = (src_y - src_transform[3]) / src_transform[5];
+
+                let sampled = match self.resampling {
+                    Resampling::Nearest => {
+                        let sc = src_col_f.floor();
+                        let sr = src_row_f.floor();
+                        if sc < 0.0 || sr < 0.0 || sc as usize >= src_width || sr as usize >= src_height {
+                            None
+                        } else {
+                            let v = src_buffer.data[sr as usize * src_width + sc as usize];
+                            (!is_nodata(v)).then_some(v)
+                        }
+                    }
+                    Resampling::Bilinear => {
+                        // Pixel centers sit at .5 offsets from the transform origin, so shift
+                        // back by half a pixel before splitting into the integer cell + weight.
+                        let fx = src_col_f - 0.5;
+                        let fy = src_row_f - 0.5;
+                        let c0 = fx.floor();
+                        let r0 = fy.floor();
+                        let tx = fx - c0;
+                        let ty = fy - r0;
+                        let sample_at = |c: f64, r: f64| -> Option<f32> {
+                            if c < 0.0 || r < 0.0 || c as usize >= src_width || r as usize >= src_height {
+                                return None;
+                            }
+                            let v = src_buffer.data[r as usize * src_width + c as usize];
+                            (!is_nodata(v)).then_some(v)
+                        };
+                        match (
+                            sample_at(c0, r0),
+                            sample_at(c0 + 1.0, r0),
+                            sample_at(c0, r0 + 1.0),
+                            sample_at(c0 + 1.0, r0 + 1.0),
+                        ) {
+                            (Some(v00), Some(v10), Some(v01), Some(v11)) => {
+                                let top = v00 as f64 * (1.0 - tx) + v10 as f64 * tx;
+                                let bottom = v01 as f64 * (1.0 - tx) + v11 as f64 * tx;
+                                Some((top * (1.0 - ty) + bottom * ty) as f32)
+                            }
+                            // Any neighbour missing, out of bounds, or nodata: leave the
+                            // destination pixel at the nodata fill rather than extrapolate.
+                            _ => None,
+                        }
+                    }
+                    Resampling::Cubic => {
+                        // Pixel centers sit at .5 offsets from the transform origin, same as
+                        // bilinear above, then widen to the surrounding 4x4 neighbourhood.
+                        let fx = src_col_f - 0.5;
+                        let fy = src_row_f - 0.5;
+                        let c1 = fx.floor();
+                        let r1 = fy.floor();
+                        let tx = fx - c1;
+                        let ty = fy - r1;
+                        let sample_at = |c: f64, r: f64| -> Option<f32> {
+                            if c < 0.0 || r < 0.0 || c as usize >= src_width || r as usize >= src_height {
+                                return None;
+                            }
+                            let v = src_buffer.data[r as usize * src_width + c as usize];
+                            (!is_nodata(v)).then_some(v)
+                        };
+                        // GDAL's default cubic kernel (Catmull-Rom, a = -0.5).
+                        const A: f64 = -0.5;
+                        let cubic_weight = |t: f64| -> f64 {
+                            let t = t.abs();
+                            if t <= 1.0 {
+                                (A + 2.0) * t.powi(3) - (A + 3.0) * t.powi(2) + 1.0
+                            } else if t < 2.0 {
+                                A * t.powi(3) - 5.0 * A * t.powi(2) + 8.0 * A * t - 4.0 * A
+                            } else {
+                                0.0
+                            }
+                        };
+
+                        let mut neighbourhood = [[0.0f64; 4]; 4];
+                        let mut complete = true;
+                        for (i, dr) in (-1..=2i64).enumerate() {
+                            for (j, dc) in (-1..=2i64).enumerate() {
+                                match sample_at(c1 + dc as f64, r1 + dr as f64) {
+                                    Some(v) => neighbourhood[i][j] = v as f64,
+                                    None => complete = false,
+                                }
+                            }
+                        }
+
+                        // Any neighbour missing, out of bounds, or nodata: leave the
+                        // destination pixel at the nodata fill rather than extrapolate.
+                        if !complete {
+                            None
+                        } else {
+                            let mut value = 0.0;
+                            for (i, dr) in (-1..=2i64).enumerate() {
+                                let wy = cubic_weight(dr as f64 - ty);
+                                for (j, dc) in (-1..=2i64).enumerate() {
+                                    let wx = cubic_weight(dc as f64 - tx);
+                                    value += neighbourhood[i][j] * wx * wy;
+                                }
+                            }
+                            Some(value as f32)
+                        }
+                    }
+                };
+
+                if let Some(value) = sampled {
+                    dst_data[row * dst_width + col] = value;
+                }
+            }
+        }
+
+        let driver =
+            gdal::DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut dst_dataset = driver
+            .create_with_band_type_with_options::<f32, _>(
+                &self.path_save_tiff,
+                dst_width,
+                dst_height,
+                1,
+                &creation_options,
+            )
+            .context("Failed to create reprojected DEM output")?;
+        dst_dataset
+            .set_geo_transform(&dst_transform)
+            .context("Failed to set destination geotransform")?;
+        let srs = SpatialRef::from_epsg(target_epsg).context("Failed to create destination spatial reference")?;
+        dst_dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set destination spatial reference")?;
+
+        let mut dst_band = dst_dataset
+            .rasterband(1)
+            .context("Failed to get destination band 1")?;
+        if let Some(nodata) = src_nodata {
+            dst_band
+                .set_no_data_value(Some(nodata))
+                .context("Failed to set destination nodata value")?;
+        }
+        let mut dst_buffer = Buffer::new((dst_width, dst_height), dst_data);
+        dst_band
+            .write((0, 0), (dst_width, dst_height), &mut dst_buffer)
+            .context("Failed to write reprojected DEM raster")?;
+
         println!(
-            "  Python equivalent: dataarray.rio.reproject(dst_crs={}, resolution=1)",
-            self.geo_core.get_epsg()
+            "DEM saved to: {:?} (reprojected to EPSG:{} at {}m resolution)",
+            self.path_save_tiff, target_epsg, self.resolution
         );
 
         Ok(())
@@ -253,19 +1071,37 @@ impl Dem {
         Ok(())
     }
 
+    /// ESRI WKT for EPSG:2154 (RGF93 / Lambert-93), the CRS `generate_mask_and_adapt_dem` always
+    /// writes the mask in. Written alongside the `.shp`/`.shx`/`.dbf` so the shapefile is
+    /// self-describing without relying on a sidecar `.cpg`/user-supplied CRS.
+    const MASK_CRS_WKT: &str = concat!(
+        "PROJCS[\"RGF93 / Lambert-93\",",
+        "GEOGCS[\"RGF93\",DATUM[\"Reseau_Geodesique_Francais_1993\",",
+        "SPHEROID[\"GRS 1980\",6378137,298.257222101]],",
+        "PRIMEM[\"Greenwich\",0],UNIT[\"degree\",0.0174532925199433]],",
+        "PROJECTION[\"Lambert_Conformal_Conic_2SP\"],",
+        "PARAMETER[\"standard_parallel_1\",49],",
+        "PARAMETER[\"standard_parallel_2\",44],",
+        "PARAMETER[\"latitude_of_origin\",46.5],",
+        "PARAMETER[\"central_meridian\",3],",
+        "PARAMETER[\"false_easting\",700000],",
+        "PARAMETER[\"false_northing\",6600000],",
+        "UNIT[\"metre\",1]]"
+    );
+
     /// Export mask polygon to shapefile
     /// Following Python: gdf.to_file(self.path_save_mask, driver="ESRI Shapefile")
-    /// Uses ogr2ogr command-line tool for reliable shapefile creation
+    /// Pure-Rust writer (the `shapefile` crate) so this runs in-process without a GDAL/ogr2ogr
+    /// install on PATH; also writes a `.prj` with `MASK_CRS_WKT` so the output is self-describing.
     #[cfg(not(feature = "wasm"))]
     fn export_mask_to_shapefile(&self, polygon: &geo::Polygon<f64>) -> Result<()> {
-        use std::process::Command;
-        use std::time::{SystemTime, UNIX_EPOCH};
+        use geo::CoordsIter;
+        use shapefile::{dbase::TableWriterBuilder, Point as ShpPoint, Polygon as ShpPolygon, PolygonRing, Writer};
 
         // Remove existing shapefile if it exists
         if self.path_save_mask.exists() {
-            // Remove all shapefile components (.shp, .shx, .dbf, .prj)
             let base_path = self.path_save_mask.with_extension("");
-            for ext in &[".shp", ".shx", ".dbf", ".prj"] {
+            for ext in &["shp", "shx", "dbf", "prj"] {
                 let file_path = base_path.with_extension(ext);
                 if file_path.exists() {
                     let _ = std::fs::remove_file(&file_path);
@@ -279,72 +1115,95 @@ impl Dem {
                 .context(format!("Failed to create output directory: {:?}", parent))?;
         }
 
-        // Create temporary GeoJSON file
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let temp_geojson = std::env::temp_dir().join(format!("mask_{}.geojson", timestamp));
+        // The mask is a simple convex rectangle (no holes), so a single outer ring suffices.
+        let points: Vec<ShpPoint> = polygon
+            .exterior()
+            .coords_iter()
+            .map(|c| ShpPoint::new(c.x, c.y))
+            .collect();
+        let shp_polygon = ShpPolygon::new(PolygonRing::Outer(points));
 
-        // Convert polygon to GeoJSON
-        use geojson::{Feature, GeoJson};
-        let geometry: geojson::Geometry = polygon
-            .try_into()
-            .context("Failed to convert polygon to GeoJSON geometry")?;
+        let table_builder = TableWriterBuilder::new();
+        let mut writer = Writer::from_path_with_info(&self.path_save_mask, table_builder)
+            .context("Failed to create shapefile writer")?;
+        writer
+            .write_shape_and_record(&shp_polygon, &shapefile::dbase::Record::default())
+            .context("Failed to write mask polygon to shapefile")?;
 
-        let feature = Feature {
+        std::fs::write(self.path_save_mask.with_extension("prj"), Self::MASK_CRS_WKT)
+            .context("Failed to write mask .prj file")?;
+
+        println!("Mask shapefile saved to: {:?}", self.path_save_mask);
+
+        Ok(())
+    }
+
+    /// Re-export the mask geometry written by [`Dem::export_mask_to_shapefile`] to an explicit
+    /// `path` through a native GDAL/OGR writer (see [`crate::geometric::export::write_vector_native`]),
+    /// giving `Dem` the same export surface as `LandCover::to_vector`. `driver` is an OGR driver
+    /// name, one of [`crate::geometric::export::NATIVE_VECTOR_DRIVERS`] (`"GPKG"`,
+    /// `"ESRI Shapefile"`, `"GeoJSON"`); `layer` names the output layer, defaulting to `"mask"`.
+    /// Reads the mask back from [`Dem::path_save_mask`] the same way [`Dem::warp_and_clip_dem`]
+    /// does, since the polygon itself isn't kept in memory after [`Dem::export_mask_to_shapefile`]
+    /// writes it.
+    #[cfg(not(feature = "wasm"))]
+    pub fn to_vector(&self, path: &Path, driver: &str, layer: Option<&str>) -> Result<PathBuf> {
+        use crate::geometric::export;
+        use geo::{LineString, Polygon as GeoPolygon};
+
+        if !self.path_save_mask.exists() {
+            anyhow::bail!(
+                "Mask shapefile not found at {:?}. Call generate_mask_and_adapt_dem() first.",
+                self.path_save_mask
+            );
+        }
+
+        let mut shp_reader = shapefile::Reader::from_path(&self.path_save_mask)
+            .context("Failed to open mask shapefile")?;
+        let (shape, _record) = shp_reader
+            .iter_shapes_and_records()
+            .next()
+            .context("Mask shapefile has no features")?
+            .context("Failed to read mask shapefile feature")?;
+        let mask_polygon: shapefile::Polygon = shape
+            .try_into()
+            .context("Mask shapefile does not contain a polygon")?;
+        let ring: Vec<(f64, f64)> = mask_polygon
+            .rings()
+            .first()
+            .context("Mask polygon has no rings")?
+            .points()
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect();
+
+        let polygon = GeoPolygon::new(LineString::from(ring), vec![]);
+        let geometry = geojson::Geometry::new(geojson::Value::from(&geo::Geometry::Polygon(polygon)));
+        let feature = geojson::Feature {
             bbox: None,
             geometry: Some(geometry),
             id: None,
             properties: None,
             foreign_members: None,
         };
-
-        let geojson = GeoJson::FeatureCollection(geojson::FeatureCollection {
+        let geojson = geojson::GeoJson::from(geojson::FeatureCollection {
             bbox: None,
-            features: vec![feature],
             foreign_members: None,
+            features: vec![feature],
         });
 
-        // Write temporary GeoJSON
-        std::fs::write(&temp_geojson, geojson.to_string())
-            .context("Failed to write temporary GeoJSON file")?;
-
-        // Use ogr2ogr to convert GeoJSON to Shapefile
-        // The polygon is already in EPSG:2154, so we specify the source CRS
-        // Python: gdf_bbox_mask_2154 has crs="epsg:2154"
-        let status = Command::new("ogr2ogr")
-            .arg("-f")
-            .arg("ESRI Shapefile")
-            .arg("-s_srs")
-            .arg("EPSG:2154") // Source CRS: polygon is already in EPSG:2154
-            .arg("-t_srs")
-            .arg("EPSG:2154") // Target CRS: keep EPSG:2154
-            .arg(&self.path_save_mask)
-            .arg(&temp_geojson)
-            .status()
-            .context(
-                "Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH",
-            )?;
-
-        // Clean up temporary GeoJSON
-        let _ = std::fs::remove_file(&temp_geojson);
-
-        if !status.success() {
-            anyhow::bail!("ogr2ogr failed to convert GeoJSON to shapefile");
-        }
-
-        println!("Mask shapefile saved to: {:?}", self.path_save_mask);
-
-        Ok(())
+        export::write_vector_native(&geojson, path, driver, layer.unwrap_or("mask"), self.geo_core.get_epsg())
     }
 
-    /// Warp and clip DEM using GDAL Warp
+    /// Clip a DEM raster to the mask polygon's extent.
     /// Following Python: gdal.Warp(destNameOrDestDS='DEM_clip.tif', srcDSOrSrcDSTab='DEM.tif', options=warp_options)
-    /// Uses gdalwarp command-line tool for reliable raster warping and clipping
+    /// Pure-Rust cutline clip: rasterizes the mask polygon (read back from `path_save_mask`)
+    /// onto the input DEM's own grid via scanline fill, blanks every outside pixel to nodata,
+    /// then crops the output to the mask's bounding box. Avoids a `gdalwarp` subprocess.
     #[cfg(not(feature = "wasm"))]
     pub fn warp_and_clip_dem(&self, input_dem_path: &Path, output_clip_path: &Path) -> Result<()> {
-        use std::process::Command;
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::{Dataset, DriverManager};
 
         // Ensure mask shapefile exists
         if !self.path_save_mask.exists() {
@@ -373,49 +1232,368 @@ impl Dem {
             ))?;
         }
 
-        // Build gdalwarp command with options equivalent to Python gdal.WarpOptions
-        // Python options:
-        //   format='GTiff'
-        //   xRes=1, yRes=1
-        //   outputType=gdalconst.GDT_Float32
-        //   dstNodata=None
-        //   dstSRS='EPSG:2154'
-        //   cropToCutline=True
-        //   cutlineDSName='mask.shp'
-        //   cutlineLayer='mask'
-        let status = Command::new("gdalwarp")
-            .arg("-of")
-            .arg("GTiff") // format='GTiff'
-            .arg("-tr")
-            .arg("1")
-            .arg("1") // xRes=1, yRes=1
-            .arg("-ot")
-            .arg("Float32") // outputType=gdalconst.GDT_Float32
-            .arg("-t_srs")
-            .arg("EPSG:2154") // dstSRS='EPSG:2154'
-            .arg("-crop_to_cutline") // cropToCutline=True
-            .arg("-cutline")
-            .arg(&self.path_save_mask) // cutlineDSName='mask.shp'
-            .arg("-cl")
-            .arg("mask") // cutlineLayer='mask'
-            .arg("-co")
-            .arg("COMPRESS=LZW") // Add compression like Python version
-            .arg(input_dem_path)
-            .arg(output_clip_path)
-            .status()
-            .context(
-                "Failed to execute gdalwarp. Make sure GDAL is installed and gdalwarp is in PATH",
-            )?;
-
-        if !status.success() {
-            anyhow::bail!("gdalwarp failed to warp and clip DEM");
+        // Read the mask's outer ring back from the shapefile written by
+        // export_mask_to_shapefile -- a single ring suffices, the mask is always a simple
+        // (non-holed) polygon, always in EPSG:2154 (see MASK_CRS_WKT), which matches the DEM's
+        // grid only when the DEM is in Lambert-93 too.
+        let mut shp_reader = shapefile::Reader::from_path(&self.path_save_mask)
+            .context("Failed to open mask shapefile")?;
+        let (shape, _record) = shp_reader
+            .iter_shapes_and_records()
+            .next()
+            .context("Mask shapefile has no features")?
+            .context("Failed to read mask shapefile feature")?;
+        let mask_polygon: shapefile::Polygon = shape
+            .try_into()
+            .context("Mask shapefile does not contain a polygon")?;
+        let ring: Vec<(f64, f64)> = mask_polygon
+            .rings()
+            .first()
+            .context("Mask polygon has no rings")?
+            .points()
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect();
+
+        let (mask_min_x, mask_min_y, mask_max_x, mask_max_y) = ring.iter().fold(
+            (f64::MAX, f64::MAX, f64::MIN, f64::MIN),
+            |(min_x, min_y, max_x, max_y), &(x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        );
+
+        let src_dataset = Dataset::open(input_dem_path).context("Failed to open input DEM")?;
+        let geotransform = src_dataset
+            .geo_transform()
+            .context("Input DEM has no geotransform")?;
+        let (width, height) = src_dataset.raster_size();
+        let src_band = src_dataset
+            .rasterband(1)
+            .context("Input DEM has no band 1")?;
+        let nodata = src_band.no_data_value().unwrap_or(-9999.0);
+        let src_buffer: Buffer<f32> = src_band
+            .read_as((0, 0), (width, height), (width, height), None)
+            .context("Failed to read input DEM")?;
+        let src_data = src_buffer.data;
+
+        // Assume a north-up, non-rotated grid (true of everything reproject_and_save produces).
+        let origin_x = geotransform[0];
+        let origin_y = geotransform[3];
+        let px_w = geotransform[1];
+        let px_h = geotransform[5];
+
+        let col_for_x = |x: f64| -> isize { ((x - origin_x) / px_w).floor() as isize };
+        let row_for_y = |y: f64| -> isize { ((y - origin_y) / px_h).floor() as isize };
+
+        let (col_a, col_b) = (col_for_x(mask_min_x), col_for_x(mask_max_x));
+        let (row_a, row_b) = (row_for_y(mask_min_y), row_for_y(mask_max_y));
+        let col_min = col_a.min(col_b).max(0) as usize;
+        let col_max = col_a.max(col_b).min(width as isize - 1).max(0) as usize;
+        let row_min = row_a.min(row_b).max(0) as usize;
+        let row_max = row_a.max(row_b).min(height as isize - 1).max(0) as usize;
+
+        if col_min > col_max || row_min > row_max {
+            anyhow::bail!("Mask polygon does not overlap the input DEM extent");
         }
 
+        let out_width = col_max - col_min + 1;
+        let out_height = row_max - row_min + 1;
+        let mut out_data = vec![nodata as f32; out_width * out_height];
+
+        // Edge-walking scanline fill: for each output row, intersect the mask ring with the
+        // pixel-center scanline, sort the crossings, and copy source pixels between each pair.
+        for row in row_min..=row_max {
+            let scan_y = origin_y + (row as f64 + 0.5) * px_h;
+
+            let mut crossings: Vec<f64> = Vec::new();
+            for edge in ring.windows(2) {
+                let (x1, y1) = edge[0];
+                let (x2, y2) = edge[1];
+                if (y1 <= scan_y && y2 > scan_y) || (y2 <= scan_y && y1 > scan_y) {
+                    let t = (scan_y - y1) / (y2 - y1);
+                    crossings.push(x1 + t * (x2 - x1));
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            for pair in crossings.chunks_exact(2) {
+                let c_from = col_for_x(pair[0]).max(col_min as isize);
+                let c_to = col_for_x(pair[1]).min(col_max as isize);
+                for col in c_from..=c_to {
+                    if col < col_min as isize || col > col_max as isize {
+                        continue;
+                    }
+                    let col = col as usize;
+                    out_data[(row - row_min) * out_width + (col - col_min)] =
+                        src_data[row * width + col];
+                }
+            }
+        }
+
+        let driver = DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut dst_dataset = driver
+            .create_with_band_type_with_options::<f32, _>(
+                output_clip_path,
+                out_width,
+                out_height,
+                1,
+                &creation_options,
+            )
+            .context("Failed to create clipped DEM output")?;
+
+        let out_geotransform = [
+            origin_x + col_min as f64 * px_w,
+            px_w,
+            0.0,
+            origin_y + row_min as f64 * px_h,
+            0.0,
+            px_h,
+        ];
+        dst_dataset
+            .set_geo_transform(&out_geotransform)
+            .context("Failed to set clipped DEM geotransform")?;
+        let srs = src_dataset
+            .spatial_ref()
+            .context("Input DEM has no spatial reference")?;
+        dst_dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set clipped DEM spatial reference")?;
+
+        let mut dst_band = dst_dataset
+            .rasterband(1)
+            .context("Clipped DEM has no band 1")?;
+        dst_band
+            .set_no_data_value(nodata)
+            .context("Failed to set clipped DEM nodata value")?;
+        let mut dst_buffer = Buffer::new((out_width, out_height), out_data);
+        dst_band
+            .write((0, 0), (out_width, out_height), &mut dst_buffer)
+            .context("Failed to write clipped DEM raster")?;
+
         println!("DEM warped and clipped to: {:?}", output_clip_path);
 
         Ok(())
     }
 
+    /// Derive slope, aspect, and profile/plan curvature from the processed DEM at
+    /// `path_save_tiff`, using Horn's 3x3 method for slope/aspect and the Zevenbergen-Thorne
+    /// quadratic for curvature. These are core inputs for microclimate/shadow work.
+    ///
+    /// Grid convention: x increases eastward (column+1), y increases southward (row+1), matching
+    /// raster row-major storage -- so `dz/dx`/`dz/dy` below are oriented east-/south-positive
+    /// throughout, including in the curvature coefficients.
+    ///
+    /// The outermost ring of pixels, and any pixel whose 3x3 window touches a source nodata
+    /// sample, is left at nodata in every output raster -- there's no full window to derive a
+    /// derivative from. Each raster is written as its own LZW GeoTIFF next to `DEM.tif`.
+    #[cfg(not(feature = "wasm"))]
+    pub fn compute_terrain(&self) -> Result<TerrainRasters> {
+        use gdal::raster::Buffer;
+        use gdal::Dataset;
+
+        let dataset = Dataset::open(&self.path_save_tiff)
+            .context("Failed to open processed DEM for terrain derivation")?;
+        let geotransform = dataset
+            .geo_transform()
+            .context("Processed DEM has no geotransform")?;
+        let (width, height) = dataset.raster_size();
+        let band = dataset
+            .rasterband(1)
+            .context("Processed DEM has no band 1")?;
+        let src_nodata = band.no_data_value();
+        let out_nodata: f32 = src_nodata.map(|n| n as f32).unwrap_or(-9999.0);
+        let buffer: Buffer<f32> = band
+            .read_as((0, 0), (width, height), (width, height), None)
+            .context("Failed to read processed DEM")?;
+        let data = buffer.data;
+
+        let is_nodata = |v: f32| {
+            src_nodata
+                .map(|n| (v as f64 - n).abs() < f64::EPSILON)
+                .unwrap_or(false)
+        };
+
+        // Assume square, north-up, non-rotated pixels (true of everything
+        // reproject_and_save produces).
+        let c = geotransform[1].abs();
+
+        let mut slope = vec![out_nodata; width * height];
+        let mut aspect = vec![out_nodata; width * height];
+        let mut profile_curvature = vec![out_nodata; width * height];
+        let mut plan_curvature = vec![out_nodata; width * height];
+
+        for row in 1..height.saturating_sub(1) {
+            for col in 1..width.saturating_sub(1) {
+                let z_tl = data[(row - 1) * width + (col - 1)];
+                let z_t = data[(row - 1) * width + col];
+                let z_tr = data[(row - 1) * width + (col + 1)];
+                let z_l = data[row * width + (col - 1)];
+                let z_c = data[row * width + col];
+                let z_r = data[row * width + (col + 1)];
+                let z_bl = data[(row + 1) * width + (col - 1)];
+                let z_b = data[(row + 1) * width + col];
+                let z_br = data[(row + 1) * width + (col + 1)];
+
+                if [z_tl, z_t, z_tr, z_l, z_c, z_r, z_bl, z_b, z_br]
+                    .iter()
+                    .any(|&v| is_nodata(v))
+                {
+                    continue;
+                }
+
+                let idx = row * width + col;
+
+                // Horn's method.
+                let dzdx = ((z_tr as f64 + 2.0 * z_r as f64 + z_br as f64)
+                    - (z_tl as f64 + 2.0 * z_l as f64 + z_bl as f64))
+                    / (8.0 * c);
+                let dzdy = ((z_bl as f64 + 2.0 * z_b as f64 + z_br as f64)
+                    - (z_tl as f64 + 2.0 * z_t as f64 + z_tr as f64))
+                    / (8.0 * c);
+
+                slope[idx] = (dzdx.powi(2) + dzdy.powi(2)).sqrt().atan().to_degrees() as f32;
+
+                if dzdx.abs() < f64::EPSILON && dzdy.abs() < f64::EPSILON {
+                    aspect[idx] = -1.0;
+                } else {
+                    let mut bearing = 90.0 - dzdy.atan2(-dzdx).to_degrees();
+                    if bearing < 0.0 {
+                        bearing += 360.0;
+                    } else if bearing >= 360.0 {
+                        bearing -= 360.0;
+                    }
+                    aspect[idx] = bearing as f32;
+                }
+
+                // Zevenbergen-Thorne quadratic, in the same x-east/y-south grid convention.
+                let d = ((z_l as f64 + z_r as f64) / 2.0 - z_c as f64) / c.powi(2);
+                let e = ((z_t as f64 + z_b as f64) / 2.0 - z_c as f64) / c.powi(2);
+                let f = (-(z_tl as f64) + z_tr as f64 + z_bl as f64 - z_br as f64) / (4.0 * c.powi(2));
+                let g = (z_r as f64 - z_l as f64) / (2.0 * c);
+                let h = (z_b as f64 - z_t as f64) / (2.0 * c);
+
+                let denom = g.powi(2) + h.powi(2);
+                if denom > f64::EPSILON {
+                    profile_curvature[idx] =
+                        (-2.0 * (d * g.powi(2) + e * h.powi(2) + f * g * h) / denom) as f32;
+                    plan_curvature[idx] =
+                        (2.0 * (d * h.powi(2) + e * g.powi(2) - f * g * h) / denom) as f32;
+                } else {
+                    profile_curvature[idx] = 0.0;
+                    plan_curvature[idx] = 0.0;
+                }
+            }
+        }
+
+        let stem = self
+            .path_save_tiff
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("DEM");
+        let dir = self.path_save_tiff.parent().unwrap_or_else(|| Path::new("."));
+
+        let srs = dataset
+            .spatial_ref()
+            .context("Processed DEM has no spatial reference")?;
+
+        let slope_path = dir.join(format!("{}_slope.tif", stem));
+        let aspect_path = dir.join(format!("{}_aspect.tif", stem));
+        let profile_curvature_path = dir.join(format!("{}_profile_curvature.tif", stem));
+        let plan_curvature_path = dir.join(format!("{}_plan_curvature.tif", stem));
+
+        Self::write_terrain_band(
+            &slope_path,
+            width,
+            height,
+            slope,
+            out_nodata,
+            &geotransform,
+            &srs,
+        )?;
+        Self::write_terrain_band(
+            &aspect_path,
+            width,
+            height,
+            aspect,
+            out_nodata,
+            &geotransform,
+            &srs,
+        )?;
+        Self::write_terrain_band(
+            &profile_curvature_path,
+            width,
+            height,
+            profile_curvature,
+            out_nodata,
+            &geotransform,
+            &srs,
+        )?;
+        Self::write_terrain_band(
+            &plan_curvature_path,
+            width,
+            height,
+            plan_curvature,
+            out_nodata,
+            &geotransform,
+            &srs,
+        )?;
+
+        println!("Terrain rasters (slope/aspect/curvature) saved alongside: {:?}", self.path_save_tiff);
+
+        Ok(TerrainRasters {
+            slope_path,
+            aspect_path,
+            profile_curvature_path,
+            plan_curvature_path,
+        })
+    }
+
+    /// Write a single-band f32 LZW GeoTIFF for [`Dem::compute_terrain`], sharing the source
+    /// DEM's geotransform and spatial reference.
+    #[cfg(not(feature = "wasm"))]
+    fn write_terrain_band(
+        path: &Path,
+        width: usize,
+        height: usize,
+        data: Vec<f32>,
+        nodata: f32,
+        geotransform: &[f64; 6],
+        srs: &gdal::spatial_ref::SpatialRef,
+    ) -> Result<()> {
+        use gdal::raster::{Buffer, RasterCreationOption};
+        use gdal::DriverManager;
+
+        let driver = DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let creation_options = [RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        }];
+        let mut dataset = driver
+            .create_with_band_type_with_options::<f32, _>(path, width, height, 1, &creation_options)
+            .with_context(|| format!("Failed to create terrain raster at {:?}", path))?;
+        dataset
+            .set_geo_transform(geotransform)
+            .context("Failed to set terrain raster geotransform")?;
+        dataset
+            .set_spatial_ref(srs)
+            .context("Failed to set terrain raster spatial reference")?;
+
+        let mut band = dataset
+            .rasterband(1)
+            .context("Terrain raster has no band 1")?;
+        band.set_no_data_value(nodata as f64)
+            .context("Failed to set terrain raster nodata value")?;
+        let mut buffer = Buffer::new((width, height), data);
+        band.write((0, 0), (width, height), &mut buffer)
+            .with_context(|| format!("Failed to write terrain raster at {:?}", path))?;
+
+        Ok(())
+    }
+
     /// Get the content from IGN API
     /// Following Python: def content(self): return self.content
     pub fn content(&self) -> Option<&Vec<u8>> {
@@ -445,21 +1623,28 @@ mod tests {
 
     #[test]
     fn test_dem_new() {
-        let dem = Dem::new(None).unwrap();
+        let dem = Dem::new(None, None).unwrap();
         assert!(dem.path_save_tiff.to_string_lossy().contains("DEM.tif"));
     }
 
     #[test]
     fn test_dem_set_bbox() {
-        let mut dem = Dem::new(None).unwrap();
+        let mut dem = Dem::new(None, None).unwrap();
         dem.set_bbox(-1.152704, 46.181627, -1.139893, 46.18699);
         assert!(dem.bbox.is_some());
     }
 
     #[test]
     fn test_dem_set_crs() {
-        let mut dem = Dem::new(None).unwrap();
+        let mut dem = Dem::new(None, None).unwrap();
         dem.set_crs(2154);
         assert_eq!(dem.geo_core.get_epsg(), 2154);
     }
+
+    #[test]
+    fn test_dem_set_resampling_cubic() {
+        let mut dem = Dem::new(None, None).unwrap();
+        dem.set_resampling(Resampling::Cubic);
+        assert_eq!(dem.resampling, Resampling::Cubic);
+    }
 }
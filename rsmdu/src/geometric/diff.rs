@@ -0,0 +1,241 @@
+use anyhow::Result;
+use geojson::{Feature, FeatureCollection, GeoJson, Value as GeoValue};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Decimal places [`geometry_hash_key`] rounds coordinates to before hashing, when the caller
+/// doesn't supply one via [`diff_feature_collections`]'s `precision` parameter. Matches
+/// micrometer precision in a projected (metric) CRS, about 0.1 m in degrees -- tight enough to tell real moves apart,
+/// loose enough to absorb the floating-point noise a round-trip through an external tool adds.
+const DEFAULT_GEOMETRY_PRECISION: u32 = 6;
+
+/// Result of diffing two GeoJson FeatureCollections
+pub struct GeoJsonDiff {
+    /// Features present in `new` but not in `old`
+    pub added: GeoJson,
+    /// Features present in `old` but not in `new`
+    pub deleted: GeoJson,
+    /// Features present in both, but whose geometry or properties differ between `old` and `new`
+    /// (the `new` version is kept)
+    pub changed: GeoJson,
+}
+
+/// Compare two GeoJson FeatureCollections and return which features were added, deleted, or
+/// changed, mirroring a "what changed between two vintages" workflow (e.g. re-downloading
+/// cadastre/land-cover for the same bbox across dates).
+///
+/// When `id_field` is given, each feature's key is `feature.properties[id_field]`. Otherwise the
+/// key is a stable hash of the feature's geometry coordinates rounded to `precision` decimal
+/// places (defaulting to [`DEFAULT_GEOMETRY_PRECISION`] when `None`), so that two vintages of the
+/// same unchanged geometry collide even without a shared id despite floating-point noise.
+///
+/// Added = keys present only in `new`, deleted = keys present only in `old`, changed = shared
+/// keys whose geometry differs once rounded to `precision` decimal places, or whose properties
+/// differ by full equality.
+pub fn diff_feature_collections(
+    old: &GeoJson,
+    new: &GeoJson,
+    id_field: Option<&str>,
+    precision: Option<u32>,
+) -> Result<GeoJsonDiff> {
+    let old_fc = as_feature_collection(old)?;
+    let new_fc = as_feature_collection(new)?;
+    let precision = precision.unwrap_or(DEFAULT_GEOMETRY_PRECISION);
+
+    let old_keys: HashMap<String, usize> = index_features(old_fc, id_field, precision);
+    let new_keys: HashMap<String, usize> = index_features(new_fc, id_field, precision);
+
+    let added_features = new_keys
+        .iter()
+        .filter(|(key, _)| !old_keys.contains_key(*key))
+        .map(|(_, &idx)| new_fc.features[idx].clone())
+        .collect();
+
+    let deleted_features = old_keys
+        .iter()
+        .filter(|(key, _)| !new_keys.contains_key(*key))
+        .map(|(_, &idx)| old_fc.features[idx].clone())
+        .collect();
+
+    let changed_features = new_keys
+        .iter()
+        .filter_map(|(key, &new_idx)| {
+            let &old_idx = old_keys.get(key)?;
+            let old_feature = &old_fc.features[old_idx];
+            let new_feature = &new_fc.features[new_idx];
+            let round = |f: &Feature| f.geometry.as_ref().map(|g| round_geo_value(&g.value, precision));
+            (round(old_feature) != round(new_feature)
+                || old_feature.properties != new_feature.properties)
+                .then(|| new_feature.clone())
+        })
+        .collect();
+
+    Ok(GeoJsonDiff {
+        added: GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: added_features,
+        }),
+        deleted: GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: deleted_features,
+        }),
+        changed: GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: changed_features,
+        }),
+    })
+}
+
+fn as_feature_collection(geojson: &GeoJson) -> Result<&FeatureCollection> {
+    match geojson {
+        GeoJson::FeatureCollection(fc) => Ok(fc),
+        _ => anyhow::bail!("diff_feature_collections requires a FeatureCollection"),
+    }
+}
+
+/// Build a key -> feature-index map, keyed either by the `id_field` property or by a
+/// stable hash of the geometry coordinates.
+fn index_features(
+    fc: &FeatureCollection,
+    id_field: Option<&str>,
+    precision: u32,
+) -> HashMap<String, usize> {
+    let mut keys = HashMap::new();
+    for (idx, feature) in fc.features.iter().enumerate() {
+        let key = match id_field {
+            Some(field) => feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get(field))
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| format!("__no_id_{}", idx)),
+            None => geometry_hash_key(feature, precision),
+        };
+        keys.insert(key, idx);
+    }
+    keys
+}
+
+/// Stable key for a feature's geometry, used when no `id_field` is provided. Coordinates are
+/// rounded to `precision` decimal places before hashing so that two vintages of the same
+/// geometry collide despite the floating-point noise a re-export/round-trip typically adds.
+fn geometry_hash_key(feature: &Feature, precision: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    match &feature.geometry {
+        Some(geometry) => {
+            let rounded = round_geo_value(&geometry.value, precision);
+            serde_json::to_string(&rounded)
+                .unwrap_or_default()
+                .hash(&mut hasher);
+        }
+        None => "null".hash(&mut hasher),
+    }
+    format!("{:x}", hasher.finish())
+}
+
+/// Round every coordinate in a geometry value to `precision` decimal places.
+fn round_geo_value(value: &GeoValue, precision: u32) -> GeoValue {
+    let factor = 10f64.powi(precision as i32);
+    let round_position = |p: &[f64]| -> Vec<f64> { p.iter().map(|c| (c * factor).round() / factor).collect() };
+    let round_positions = |ps: &[Vec<f64>]| -> Vec<Vec<f64>> { ps.iter().map(|p| round_position(p)).collect() };
+    let round_lines = |ls: &[Vec<Vec<f64>>]| -> Vec<Vec<Vec<f64>>> { ls.iter().map(|l| round_positions(l)).collect() };
+
+    match value {
+        GeoValue::Point(p) => GeoValue::Point(round_position(p)),
+        GeoValue::MultiPoint(ps) => GeoValue::MultiPoint(round_positions(ps)),
+        GeoValue::LineString(ps) => GeoValue::LineString(round_positions(ps)),
+        GeoValue::MultiLineString(ls) => GeoValue::MultiLineString(round_lines(ls)),
+        GeoValue::Polygon(rings) => GeoValue::Polygon(round_lines(rings)),
+        GeoValue::MultiPolygon(polygons) => {
+            GeoValue::MultiPolygon(polygons.iter().map(|rings| round_lines(rings)).collect())
+        }
+        GeoValue::GeometryCollection(geometries) => GeoValue::GeometryCollection(
+            geometries
+                .iter()
+                .map(|g| geojson::Geometry::new(round_geo_value(&g.value, precision)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geojson::Geometry;
+
+    fn feature(id: Option<&str>, x: f64, y: f64) -> Feature {
+        Feature {
+            bbox: None,
+            geometry: Some(Geometry::new(GeoValue::Point(vec![x, y]))),
+            id: None,
+            properties: id.map(|id| {
+                let mut props = serde_json::Map::new();
+                props.insert("id".to_string(), serde_json::json!(id));
+                props
+            }),
+            foreign_members: None,
+        }
+    }
+
+    fn collection(features: Vec<Feature>) -> GeoJson {
+        GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        })
+    }
+
+    fn feature_count(geojson: &GeoJson) -> usize {
+        match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.len(),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_diff_by_id_field_detects_added_deleted_and_changed() {
+        let old = collection(vec![feature(Some("a"), 0.0, 0.0), feature(Some("b"), 1.0, 1.0)]);
+        let new = collection(vec![feature(Some("a"), 0.0, 0.0), feature(Some("c"), 2.0, 2.0)]);
+
+        let diff = diff_feature_collections(&old, &new, Some("id"), None).unwrap();
+        assert_eq!(feature_count(&diff.added), 1);
+        assert_eq!(feature_count(&diff.deleted), 1);
+        assert_eq!(feature_count(&diff.changed), 0);
+    }
+
+    #[test]
+    fn test_diff_by_id_field_detects_geometry_change() {
+        let old = collection(vec![feature(Some("a"), 0.0, 0.0)]);
+        let new = collection(vec![feature(Some("a"), 5.0, 5.0)]);
+
+        let diff = diff_feature_collections(&old, &new, Some("id"), None).unwrap();
+        assert_eq!(feature_count(&diff.added), 0);
+        assert_eq!(feature_count(&diff.deleted), 0);
+        assert_eq!(feature_count(&diff.changed), 1);
+    }
+
+    #[test]
+    fn test_diff_without_id_field_tolerates_rounding_noise() {
+        let old = collection(vec![feature(None, 1.0, 1.0)]);
+        let new = collection(vec![feature(None, 1.0 + 1e-9, 1.0 - 1e-9)]);
+
+        let diff = diff_feature_collections(&old, &new, None, Some(6)).unwrap();
+        assert_eq!(feature_count(&diff.added), 0);
+        assert_eq!(feature_count(&diff.deleted), 0);
+        assert_eq!(feature_count(&diff.changed), 0);
+    }
+
+    #[test]
+    fn test_diff_without_id_field_detects_real_move() {
+        let old = collection(vec![feature(None, 1.0, 1.0)]);
+        let new = collection(vec![feature(None, 1.5, 1.0)]);
+
+        let diff = diff_feature_collections(&old, &new, None, Some(6)).unwrap();
+        assert_eq!(feature_count(&diff.added), 1);
+        assert_eq!(feature_count(&diff.deleted), 1);
+    }
+}
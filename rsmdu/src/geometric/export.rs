@@ -0,0 +1,532 @@
+use anyhow::{Context, Result};
+use geo::{LineString, MultiPolygon, Polygon};
+use geojson::{GeoJson, Value};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// OGR-supported output formats shared by the multi-format `to_file` exporters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    GeoPackage,
+    Shapefile,
+    GeoJson,
+    FlatGeobuf,
+    Kml,
+    /// KML zipped into a single-entry archive containing `doc.kml`, read/written through GDAL's
+    /// `/vsizip/` virtual filesystem with the plain `KML` driver rather than requiring `LIBKML`.
+    Kmz,
+    Gpx,
+    /// GeoParquet, via `ogr2ogr -f Parquet`. Pair with [`to_file_with_options`] and a
+    /// `"COMPRESSION=..."` `-lco` flag to pick the codec, e.g. [`GeoParquetCompression`].
+    GeoParquet,
+}
+
+impl OutputFormat {
+    /// OGR driver name, as passed to `ogr2ogr -f`
+    pub fn driver_name(&self) -> &'static str {
+        match self {
+            OutputFormat::GeoPackage => "GPKG",
+            OutputFormat::Shapefile => "ESRI Shapefile",
+            OutputFormat::GeoJson => "GeoJSON",
+            OutputFormat::FlatGeobuf => "FlatGeobuf",
+            OutputFormat::Kml | OutputFormat::Kmz => "KML",
+            OutputFormat::Gpx => "GPX",
+            OutputFormat::GeoParquet => "Parquet",
+        }
+    }
+
+    /// File extension (without the leading dot) used for the output file
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::GeoPackage => "gpkg",
+            OutputFormat::Shapefile => "shp",
+            OutputFormat::GeoJson => "geojson",
+            OutputFormat::FlatGeobuf => "fgb",
+            OutputFormat::Kml => "kml",
+            OutputFormat::Kmz => "kmz",
+            OutputFormat::Gpx => "gpx",
+            OutputFormat::GeoParquet => "parquet",
+        }
+    }
+
+    /// Guess a format from a file extension (leading dot and case both ignored), the way
+    /// `ogr2ogr` infers a driver from the output path when `-f` is omitted.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.trim_start_matches('.').to_lowercase().as_str() {
+            "gpkg" => Some(OutputFormat::GeoPackage),
+            "shp" => Some(OutputFormat::Shapefile),
+            "geojson" | "json" => Some(OutputFormat::GeoJson),
+            "fgb" => Some(OutputFormat::FlatGeobuf),
+            "kml" => Some(OutputFormat::Kml),
+            "kmz" => Some(OutputFormat::Kmz),
+            "gpx" => Some(OutputFormat::Gpx),
+            "parquet" | "geoparquet" => Some(OutputFormat::GeoParquet),
+            _ => None,
+        }
+    }
+}
+
+/// The path `ogr2ogr` should actually read/write for `format`. [`OutputFormat::Kmz`] is a zip
+/// archive containing a single `doc.kml`, so it's opened/written through GDAL's `/vsizip/`
+/// virtual filesystem rather than `path` itself -- works both ways, since GDAL will transparently
+/// create the zip on write and read straight through it on open.
+fn ogr_path(path: &Path, format: OutputFormat) -> PathBuf {
+    match format {
+        OutputFormat::Kmz => PathBuf::from(format!("/vsizip/{}/doc.kml", path.display())),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Parquet compression codec for [`OutputFormat::GeoParquet`] output, passed to `ogr2ogr` as a
+/// `-lco COMPRESSION=<value>` layer creation option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoParquetCompression {
+    #[default]
+    Snappy,
+    Gzip,
+    Brotli,
+    Zstd,
+    Uncompressed,
+}
+
+impl GeoParquetCompression {
+    /// The `"COMPRESSION=<value>"` layer creation option `ogr2ogr -lco` expects.
+    pub fn as_layer_option(&self) -> &'static str {
+        match self {
+            GeoParquetCompression::Snappy => "COMPRESSION=SNAPPY",
+            GeoParquetCompression::Gzip => "COMPRESSION=GZIP",
+            GeoParquetCompression::Brotli => "COMPRESSION=BROTLI",
+            GeoParquetCompression::Zstd => "COMPRESSION=ZSTD",
+            GeoParquetCompression::Uncompressed => "COMPRESSION=UNCOMPRESSED",
+        }
+    }
+}
+
+/// Write a GeoJson value to any OGR-supported vector format ([`OutputFormat::GeoPackage`],
+/// [`OutputFormat::Shapefile`], [`OutputFormat::GeoJson`], [`OutputFormat::FlatGeobuf`],
+/// [`OutputFormat::Kml`], [`OutputFormat::Gpx`]) by shelling into `ogr2ogr`: the GeoJSON is
+/// first written to a temp file, then converted with `-t_srs EPSG:<epsg>` so the output is
+/// reprojected to `target_epsg` on export.
+pub fn to_file(
+    geojson: &GeoJson,
+    output_dir: &Path,
+    name: &str,
+    format: OutputFormat,
+    target_epsg: i32,
+) -> Result<PathBuf> {
+    to_file_with_options(geojson, output_dir, name, format, target_epsg, &[])
+}
+
+/// Same as [`to_file`], additionally passing `layer_creation_options` through as `ogr2ogr -lco`
+/// flags (one per option, each a `"KEY=VALUE"` string) -- e.g.
+/// [`GeoParquetCompression::as_layer_option`] for [`OutputFormat::GeoParquet`].
+pub fn to_file_with_options(
+    geojson: &GeoJson,
+    output_dir: &Path,
+    name: &str,
+    format: OutputFormat,
+    target_epsg: i32,
+    layer_creation_options: &[&str],
+) -> Result<PathBuf> {
+    std::fs::create_dir_all(output_dir)
+        .context(format!("Failed to create output directory: {:?}", output_dir))?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let temp_geojson = std::env::temp_dir().join(format!("{}_{}.geojson", name, timestamp));
+    std::fs::write(&temp_geojson, geojson.to_string())
+        .context("Failed to write temporary GeoJSON file")?;
+
+    let output_path = output_dir.join(format!("{}.{}", name, format.extension()));
+
+    let mut command = Command::new("ogr2ogr");
+    command
+        .arg("-f")
+        .arg(format.driver_name())
+        .arg(ogr_path(&output_path, format))
+        .arg(&temp_geojson)
+        .arg("-t_srs")
+        .arg(format!("EPSG:{}", target_epsg));
+    for option in layer_creation_options {
+        command.arg("-lco").arg(option);
+    }
+
+    let status = command
+        .status()
+        .context("Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH")?;
+
+    let _ = std::fs::remove_file(&temp_geojson);
+
+    if !status.success() {
+        anyhow::bail!(
+            "ogr2ogr failed to convert GeoJSON to {}",
+            format.driver_name()
+        );
+    }
+
+    Ok(output_path)
+}
+
+/// Read any OGR-supported vector file at `path` (authored as `format`) back into a GeoJson
+/// value, by shelling into `ogr2ogr -f GeoJSON` and parsing the result -- the inverse of
+/// [`to_file`]. [`OutputFormat::Kmz`] is read through GDAL's `/vsizip/` virtual filesystem,
+/// treating `path` as a zip archive containing a single `doc.kml`.
+pub fn from_file(path: &Path, format: OutputFormat) -> Result<GeoJson> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let temp_geojson = std::env::temp_dir().join(format!("import_{}.geojson", timestamp));
+
+    let status = Command::new("ogr2ogr")
+        .arg("-f")
+        .arg("GeoJSON")
+        .arg(&temp_geojson)
+        .arg(ogr_path(path, format))
+        .status()
+        .context("Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH")?;
+
+    if !status.success() {
+        let _ = std::fs::remove_file(&temp_geojson);
+        anyhow::bail!(
+            "ogr2ogr failed to convert {:?} ({}) to GeoJSON",
+            path,
+            format.driver_name()
+        );
+    }
+
+    let contents = std::fs::read_to_string(&temp_geojson)
+        .context("Failed to read ogr2ogr GeoJSON output")?;
+    let _ = std::fs::remove_file(&temp_geojson);
+
+    contents
+        .parse()
+        .context("Failed to parse ogr2ogr GeoJSON output")
+}
+
+/// Parse a KML document (or a KMZ zip archive containing a single `doc.kml`) at `path` into a
+/// GeoJson value. When `point_buffer_radius_m` is `Some(r)`, every Point/MultiPoint placemark is
+/// replaced with an `r`-meter circular polygon approximation (flat-earth, fine at these scales)
+/// so downstream code that only handles areal features (area/morphology calculations) still
+/// works on it; `None` leaves points as points.
+pub fn from_kml(path: &Path, point_buffer_radius_m: Option<f64>) -> Result<GeoJson> {
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("kmz") => OutputFormat::Kmz,
+        _ => OutputFormat::Kml,
+    };
+
+    let mut geojson = from_file(path, format)?;
+    if let Some(radius_m) = point_buffer_radius_m {
+        buffer_points(&mut geojson, radius_m);
+    }
+    Ok(geojson)
+}
+
+/// Approximate a circle of `radius_m` meters around `(lon, lat)` as a 32-sided polygon in WGS84
+/// degrees. Uses a flat-earth approximation (meters-per-degree scaled by latitude) rather than a
+/// true geodesic buffer -- adequate for the small placemark radii this is meant for.
+fn circle_polygon(lon: f64, lat: f64, radius_m: f64) -> Polygon<f64> {
+    const SEGMENTS: usize = 32;
+    const METERS_PER_DEGREE_LAT: f64 = 111_320.0;
+    let meters_per_degree_lon = METERS_PER_DEGREE_LAT * lat.to_radians().cos();
+
+    let ring: Vec<(f64, f64)> = (0..=SEGMENTS)
+        .map(|i| {
+            let theta = 2.0 * std::f64::consts::PI * (i as f64) / (SEGMENTS as f64);
+            let dx = radius_m * theta.cos() / meters_per_degree_lon;
+            let dy = radius_m * theta.sin() / METERS_PER_DEGREE_LAT;
+            (lon + dx, lat + dy)
+        })
+        .collect();
+
+    Polygon::new(LineString::from(ring), vec![])
+}
+
+/// Replace every Point/MultiPoint geometry reachable from `geojson` with a circular polygon of
+/// `radius_m` meters, recursing through FeatureCollection/Feature/GeometryCollection. Used by
+/// [`from_kml`] to turn point placemarks into small areal features.
+fn buffer_points(geojson: &mut GeoJson, radius_m: f64) {
+    match geojson {
+        GeoJson::FeatureCollection(fc) => {
+            for feature in &mut fc.features {
+                if let Some(ref mut geometry) = feature.geometry {
+                    buffer_points_in_geometry(geometry, radius_m);
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(ref mut geometry) = feature.geometry {
+                buffer_points_in_geometry(geometry, radius_m);
+            }
+        }
+        GeoJson::Geometry(geometry) => buffer_points_in_geometry(geometry, radius_m),
+    }
+}
+
+fn buffer_points_in_geometry(geometry: &mut geojson::Geometry, radius_m: f64) {
+    match &geometry.value {
+        Value::Point(position) if position.len() >= 2 => {
+            let polygon = circle_polygon(position[0], position[1], radius_m);
+            geometry.value = Value::from(&geo::Geometry::Polygon(polygon));
+        }
+        Value::MultiPoint(positions) => {
+            let polygons: Vec<Polygon<f64>> = positions
+                .iter()
+                .filter(|position| position.len() >= 2)
+                .map(|position| circle_polygon(position[0], position[1], radius_m))
+                .collect();
+            geometry.value = Value::from(&geo::Geometry::MultiPolygon(MultiPolygon(polygons)));
+        }
+        Value::GeometryCollection(_) => {
+            if let Value::GeometryCollection(geometries) = &mut geometry.value {
+                for nested in geometries {
+                    buffer_points_in_geometry(nested, radius_m);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// OGR driver names accepted by [`write_vector_native`] -- the subset of `ogr2ogr -f <driver>`
+/// formats the shared `to_vector` export surface (see `LandCover::to_vector`/`Dem::to_vector`)
+/// commits to, as opposed to [`to_file`]'s full `ogr2ogr`-backed list.
+pub const NATIVE_VECTOR_DRIVERS: &[&str] = &["GPKG", "ESRI Shapefile", "GeoJSON"];
+
+/// Write a GeoJson value to `output_path` through a native GDAL/OGR vector writer, rather than
+/// [`to_file`]'s `ogr2ogr` subprocess -- at the cost of only supporting [`NATIVE_VECTOR_DRIVERS`].
+/// The output layer is created with `epsg`'s spatial reference and a geometry type inferred from
+/// the first feature that has a geometry (falling back to `wkbUnknown` for an empty collection).
+/// Every feature's scalar properties become layer fields, typed from the first feature that has a
+/// value for each property name (bool -> `OFTInteger`, integral number -> `OFTInteger64`,
+/// fractional number -> `OFTReal`, anything else -> `OFTString`).
+pub fn write_vector_native(
+    geojson: &GeoJson,
+    output_path: &Path,
+    driver_name: &str,
+    layer_name: &str,
+    epsg: i32,
+) -> Result<PathBuf> {
+    use gdal::spatial_ref::SpatialRef;
+    use gdal::vector::{Feature as OgrFeature, FieldDefn, LayerAccess, LayerOptions, ToGdal};
+    use gdal::DriverManager;
+    use serde_json::Value as JsonValue;
+
+    if !NATIVE_VECTOR_DRIVERS.contains(&driver_name) {
+        anyhow::bail!(
+            "Unsupported driver {:?}; expected one of {:?}",
+            driver_name,
+            NATIVE_VECTOR_DRIVERS
+        );
+    }
+
+    let features = match geojson {
+        GeoJson::FeatureCollection(fc) => fc.features.clone(),
+        GeoJson::Feature(f) => vec![f.clone()],
+        GeoJson::Geometry(g) => vec![geojson::Feature {
+            bbox: None,
+            geometry: Some(g.clone()),
+            id: None,
+            properties: None,
+            foreign_members: None,
+        }],
+    };
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create output directory: {:?}", parent))?;
+    }
+    if driver_name == "ESRI Shapefile" {
+        let base = output_path.with_extension("");
+        for ext in &["shp", "shx", "dbf", "prj"] {
+            let sidecar = base.with_extension(ext);
+            if sidecar.exists() {
+                let _ = std::fs::remove_file(&sidecar);
+            }
+        }
+    } else if output_path.exists() {
+        std::fs::remove_file(output_path)
+            .with_context(|| format!("Failed to remove existing output file: {:?}", output_path))?;
+    }
+
+    let driver = DriverManager::get_driver_by_name(driver_name)
+        .with_context(|| format!("OGR driver {:?} is not available", driver_name))?;
+    let mut dataset = driver
+        .create_vector_only(output_path)
+        .with_context(|| format!("Failed to create {} dataset at {:?}", driver_name, output_path))?;
+
+    let srs = SpatialRef::from_epsg(epsg as u32)
+        .with_context(|| format!("Failed to build spatial reference for EPSG:{}", epsg))?;
+    let geom_type = features
+        .iter()
+        .find_map(|f| f.geometry.as_ref())
+        .map(ogr_geometry_type_of)
+        .unwrap_or(gdal::vector::OGRwkbGeometryType::wkbUnknown);
+
+    let mut layer = dataset
+        .create_layer(LayerOptions {
+            name: layer_name,
+            srs: Some(&srs),
+            ty: geom_type,
+            options: None,
+        })
+        .context("Failed to create output layer")?;
+
+    let field_names = collect_vector_field_names(&features);
+    for field_name in &field_names {
+        let field_type = infer_vector_field_type(&features, field_name);
+        let field_defn = FieldDefn::new(field_name, field_type)
+            .with_context(|| format!("Failed to build field definition for {:?}", field_name))?;
+        field_defn
+            .add_to_layer(&layer)
+            .with_context(|| format!("Failed to add field {:?} to layer", field_name))?;
+    }
+
+    for feature in &features {
+        let defn = layer.defn();
+        let mut ogr_feature = OgrFeature::new(defn).context("Failed to create feature")?;
+
+        if let Some(geometry) = &feature.geometry {
+            let geo_geom: geo::Geometry<f64> = geometry
+                .try_into()
+                .context("Failed to convert GeoJSON geometry to geo geometry")?;
+            let gdal_geom = geo_geom
+                .to_gdal()
+                .context("Failed to convert geo geometry to GDAL geometry")?;
+            ogr_feature
+                .set_geometry(gdal_geom)
+                .context("Failed to set feature geometry")?;
+        }
+
+        if let Some(properties) = &feature.properties {
+            for field_name in &field_names {
+                match properties.get(field_name) {
+                    Some(JsonValue::Bool(b)) => {
+                        ogr_feature.set_field_integer(field_name, if *b { 1 } else { 0 })?;
+                    }
+                    Some(JsonValue::Number(n)) if n.is_i64() || n.is_u64() => {
+                        ogr_feature.set_field_integer64(field_name, n.as_i64().unwrap_or_default())?;
+                    }
+                    Some(JsonValue::Number(n)) => {
+                        ogr_feature.set_field_double(field_name, n.as_f64().unwrap_or_default())?;
+                    }
+                    Some(JsonValue::String(s)) => {
+                        ogr_feature.set_field_string(field_name, s)?;
+                    }
+                    Some(other) => {
+                        ogr_feature.set_field_string(field_name, &other.to_string())?;
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        ogr_feature
+            .create(&layer)
+            .context("Failed to write feature to layer")?;
+    }
+
+    Ok(output_path.to_path_buf())
+}
+
+fn collect_vector_field_names(features: &[geojson::Feature]) -> Vec<String> {
+    let mut names = Vec::new();
+    for feature in features {
+        if let Some(properties) = &feature.properties {
+            for key in properties.keys() {
+                if !names.contains(key) {
+                    names.push(key.clone());
+                }
+            }
+        }
+    }
+    names
+}
+
+fn infer_vector_field_type(
+    features: &[geojson::Feature],
+    field_name: &str,
+) -> gdal::vector::OGRFieldType::Type {
+    use gdal::vector::OGRFieldType;
+    use serde_json::Value as JsonValue;
+
+    for feature in features {
+        let Some(value) = feature.properties.as_ref().and_then(|props| props.get(field_name)) else {
+            continue;
+        };
+        return match value {
+            JsonValue::Bool(_) => OGRFieldType::OFTInteger,
+            JsonValue::Number(n) if n.is_i64() || n.is_u64() => OGRFieldType::OFTInteger64,
+            JsonValue::Number(_) => OGRFieldType::OFTReal,
+            _ => OGRFieldType::OFTString,
+        };
+    }
+    OGRFieldType::OFTString
+}
+
+/// Geometry type for a new OGR layer, inferred from one GeoJSON geometry value.
+fn ogr_geometry_type_of(geometry: &geojson::Geometry) -> gdal::vector::OGRwkbGeometryType::Type {
+    use gdal::vector::OGRwkbGeometryType;
+    match &geometry.value {
+        Value::Point(_) => OGRwkbGeometryType::wkbPoint,
+        Value::MultiPoint(_) => OGRwkbGeometryType::wkbMultiPoint,
+        Value::LineString(_) => OGRwkbGeometryType::wkbLineString,
+        Value::MultiLineString(_) => OGRwkbGeometryType::wkbMultiLineString,
+        Value::Polygon(_) => OGRwkbGeometryType::wkbPolygon,
+        Value::MultiPolygon(_) => OGRwkbGeometryType::wkbMultiPolygon,
+        Value::GeometryCollection(_) => OGRwkbGeometryType::wkbGeometryCollection,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_format_recognizes_kmz_extension() {
+        assert_eq!(OutputFormat::from_extension("kmz"), Some(OutputFormat::Kmz));
+        assert_eq!(OutputFormat::from_extension(".KMZ"), Some(OutputFormat::Kmz));
+        assert_eq!(OutputFormat::driver_name(&OutputFormat::Kmz), "KML");
+        assert_eq!(OutputFormat::extension(&OutputFormat::Kmz), "kmz");
+    }
+
+    #[test]
+    fn test_ogr_path_wraps_kmz_in_vsizip_doc_kml() {
+        let path = Path::new("/tmp/example.kmz");
+        assert_eq!(
+            ogr_path(path, OutputFormat::Kmz),
+            PathBuf::from("/vsizip//tmp/example.kmz/doc.kml")
+        );
+        assert_eq!(ogr_path(path, OutputFormat::Kml), path.to_path_buf());
+    }
+
+    #[test]
+    fn test_buffer_points_replaces_point_with_closed_circular_polygon() {
+        let mut geojson: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [2.35, 48.85]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+
+        buffer_points(&mut geojson, 5.0);
+
+        let GeoJson::FeatureCollection(fc) = &geojson else {
+            panic!("expected a FeatureCollection");
+        };
+        let geometry = fc.features[0].geometry.as_ref().unwrap();
+        match &geometry.value {
+            Value::Polygon(rings) => {
+                let exterior = &rings[0];
+                assert_eq!(exterior.first(), exterior.last());
+                assert!(exterior.len() > 4);
+            }
+            other => panic!("expected a Polygon, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Centralized GDAL/CPL error capture, shared across the `geometric` collectors the same way
+//! [`crate::geometric::raster::Rasteriser`] shares rasterization. Talks to GDAL's C error API
+//! (`CPLSetErrorHandler`/`CPLDefaultErrorHandler`) directly via `gdal-sys` rather than the `gdal`
+//! crate's higher-level wrappers, since those only expose the *last* CPL error at the point a
+//! wrapped call itself fails -- by then a handler installed here has already captured it with its
+//! original class/code, before anything else had a chance to overwrite it.
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+use anyhow::{Context, Result};
+
+thread_local! {
+    static LAST_GDAL_ERROR: RefCell<Option<CapturedGdalError>> = RefCell::new(None);
+}
+
+/// One GDAL/CPL error captured by [`GdalErrorGuard`]: the error class GDAL assigned it
+/// (`CE_Warning` = 2, `CE_Failure` = 3, `CE_Fatal` = 4), its `CPLErrorNum`, and the formatted
+/// message -- the same information `CPLGetLastErrorType`/`CPLGetLastErrorNo`/
+/// `CPLGetLastErrorMsg` expose, just captured at the moment GDAL raised it instead of read back
+/// afterwards (which a later successful GDAL call could have already overwritten).
+#[derive(Debug, Clone)]
+pub struct CapturedGdalError {
+    pub class: i32,
+    pub code: i32,
+    pub message: String,
+}
+
+impl std::fmt::Display for CapturedGdalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GDAL error (class {}, code {}): {}",
+            self.class, self.code, self.message
+        )
+    }
+}
+
+/// `CPLErrorHandler` callback registered by [`GdalErrorGuard`]. Stashes the error in thread-local
+/// storage instead of letting GDAL's default handler print it to stderr, so
+/// [`with_gdal_error_context`] can surface it through `anyhow` context instead of losing it to
+/// the terminal.
+extern "C" fn capture_gdal_error(class: c_int, code: c_int, message: *const c_char) {
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    };
+
+    LAST_GDAL_ERROR.with(|cell| {
+        *cell.borrow_mut() = Some(CapturedGdalError {
+            class,
+            code,
+            message,
+        });
+    });
+}
+
+/// Take (and clear) the last GDAL error captured on this thread, if any.
+fn take_last_gdal_error() -> Option<CapturedGdalError> {
+    LAST_GDAL_ERROR.with(|cell| cell.borrow_mut().take())
+}
+
+/// Scoped GDAL error handler: installs [`capture_gdal_error`] as GDAL's process-global
+/// `CPLErrorHandler` on construction, so every GDAL/OGR call made while a guard is alive has its
+/// last failure recorded instead of merely printed, and restores GDAL's default handler
+/// (`CPLDefaultErrorHandler`) on drop.
+///
+/// GDAL's error handler is process-global, not per-thread, so nest a guard narrowly around the
+/// GDAL call(s) you want diagnostics for via [`with_gdal_error_context`] rather than installing
+/// one for the whole program -- the same narrow-scoping convention `self.sql_query`/
+/// `self.attribute_filter` already follow for per-call GDAL configuration.
+struct GdalErrorGuard;
+
+impl GdalErrorGuard {
+    fn new() -> Self {
+        take_last_gdal_error();
+        unsafe {
+            gdal_sys::CPLSetErrorHandler(Some(capture_gdal_error));
+        }
+        GdalErrorGuard
+    }
+}
+
+impl Drop for GdalErrorGuard {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::CPLSetErrorHandler(Some(gdal_sys::CPLDefaultErrorHandler));
+        }
+    }
+}
+
+/// Run `f` under a scoped [`GdalErrorGuard`] and, if it returns an `Err`, attach whatever GDAL
+/// error it captured (class/code/message) as additional `anyhow` context layered under
+/// `context`. Falls back to plain `context` when GDAL didn't record an error of its own (e.g. the
+/// failure came from Rust-side validation rather than a GDAL/OGR call).
+///
+/// Use this to wrap `Dataset::open`, `create_with_band_type[_with_options]`, `band.write`, and
+/// `band.polygonize` calls so a failure surfaces *why* GDAL failed -- bad band count, projection
+/// mismatch, permission denied -- instead of a generic wrapper string.
+pub fn with_gdal_error_context<T>(context: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let _guard = GdalErrorGuard::new();
+    match f() {
+        Ok(value) => Ok(value),
+        Err(e) => match take_last_gdal_error() {
+            Some(gdal_error) => Err(e.context(format!("{}: {}", context, gdal_error))),
+            None => Err(e).context(context.to_string()),
+        },
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+
+use crate::geo_core::{BoundingBox, GeoCore};
+
+/// Base URL of the French BAN (Base Adresse Nationale) address search API.
+const BAN_SEARCH_URL: &str = "https://api-adresse.data.gouv.fr/search/";
+
+/// Number of candidates [`bbox_from_address`] requests from the BAN API before picking the best
+/// match -- matches the `limit=5` the API docs use in their own examples.
+const DEFAULT_CANDIDATE_LIMIT: u32 = 5;
+
+/// One candidate address returned by [`search`], trimmed down to what callers need to disambiguate
+/// between matches and to build a bbox around the chosen one.
+#[derive(Debug, Clone)]
+pub struct GeocodeResult {
+    /// Full human-readable address, e.g. `"8 Boulevard du Port 80000 Amiens"`.
+    pub label: String,
+    pub lon: f64,
+    pub lat: f64,
+}
+
+/// `features[].properties` shape from the BAN GeoJSON response, trimmed to the one field used.
+#[derive(Debug, Deserialize)]
+struct BanProperties {
+    label: String,
+}
+
+/// `features[].geometry` shape from the BAN GeoJSON response -- always a `Point`.
+#[derive(Debug, Deserialize)]
+struct BanGeometry {
+    coordinates: Vec<f64>,
+}
+
+/// `features[]` shape from the BAN GeoJSON response.
+#[derive(Debug, Deserialize)]
+struct BanFeature {
+    properties: BanProperties,
+    geometry: BanGeometry,
+}
+
+/// Raw response shape from `GET https://api-adresse.data.gouv.fr/search/`.
+#[derive(Debug, Deserialize)]
+struct BanFeatureCollection {
+    #[serde(default)]
+    features: Vec<BanFeature>,
+}
+
+/// Query the French BAN (Base Adresse Nationale) geocoder for `query`, returning up to `limit`
+/// candidate matches ranked by the API's own relevance score, best first.
+/// `GET https://api-adresse.data.gouv.fr/search/?q=<query>&limit=<limit>`
+pub fn search(query: &str, limit: u32) -> Result<Vec<GeocodeResult>> {
+    let client = Client::new();
+    let response = client
+        .get(BAN_SEARCH_URL)
+        .query(&[("q", query), ("limit", &limit.to_string())])
+        .send()
+        .context("Failed to query the BAN geocoder")?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("BAN geocoder returned error {}: {}", status, body);
+    }
+
+    let collection: BanFeatureCollection = response
+        .json()
+        .context("Failed to parse JSON response from the BAN geocoder")?;
+
+    Ok(collection
+        .features
+        .into_iter()
+        .filter_map(|feature| match feature.geometry.coordinates.as_slice() {
+            [lon, lat, ..] => Some(GeocodeResult {
+                label: feature.properties.label,
+                lon: *lon,
+                lat: *lat,
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Geocode `query` via [`search`] and build a square bbox (EPSG:4326) of `buffer_m` metres
+/// around the best match, returning the bbox alongside every candidate label so a caller can
+/// disambiguate when the top match isn't the right one. Like
+/// [`crate::geo_core::GeoCore::buffer_points`], it picks the metric CRS
+/// [`BoundingBox::best_utm_epsg`] recommends for the point, offsets there, and reprojects back to
+/// EPSG:4326 -- so `buffer_m` is an accurate metre offset regardless of latitude.
+///
+/// Callers needing a geocoder for a different crate structure (e.g.
+/// [`crate::geometric::dem::Dem`] or [`crate::geometric::vegetation::Vegetation`]) can call
+/// [`search`] directly and build their own bbox the same way.
+pub fn bbox_from_address(query: &str, buffer_m: f64) -> Result<(BoundingBox, Vec<GeocodeResult>)> {
+    let candidates = search(query, DEFAULT_CANDIDATE_LIMIT)?;
+    let best = candidates
+        .first()
+        .with_context(|| format!("No BAN geocoder match for {:?}", query))?;
+
+    let metric_epsg = BoundingBox::new(best.lon, best.lat, best.lon, best.lat).best_utm_epsg();
+    let (cx, cy) = GeoCore::transform_coords(4326, metric_epsg, best.lon, best.lat)?;
+    let (min_x, min_y) =
+        GeoCore::transform_coords(metric_epsg, 4326, cx - buffer_m, cy - buffer_m)?;
+    let (max_x, max_y) =
+        GeoCore::transform_coords(metric_epsg, 4326, cx + buffer_m, cy + buffer_m)?;
+
+    Ok((BoundingBox::new(min_x, min_y, max_x, max_y), candidates))
+}
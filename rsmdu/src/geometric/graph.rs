@@ -0,0 +1,398 @@
+use anyhow::{Context, Result};
+use geo::algorithm::contains::Contains;
+use geo::algorithm::geodesic_length::GeodesicLength;
+use geo::{LineString, MultiPolygon, Point};
+use geojson::{GeoJson, Value as GeoJsonValue};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Identifies a node (intersection or segment endpoint) in a [`RoadGraph`].
+pub type NodeId = usize;
+
+/// A single road segment between two nodes.
+#[derive(Debug, Clone)]
+struct Edge {
+    from: NodeId,
+    to: NodeId,
+    /// Geodesic length of the segment, in meters.
+    weight: f64,
+    /// Set by [`RoadGraph::set_impassable_within`] to model a flood/hazard scenario.
+    impassable: bool,
+}
+
+/// Routable graph built from a road `FeatureCollection` by [`RoadGraph::from_geojson`] —
+/// every LineString vertex becomes a node (vertices shared across LineStrings become one node)
+/// and every segment an edge weighted by its geodesic length. Two LineStrings that cross
+/// without sharing a vertex at the crossing are not connected there. This makes the network usable for [`RoadGraph::shortest_path`] and
+/// [`RoadGraph::link_redundancy`] the way `Road::to_raster` makes it usable for raster models.
+#[derive(Debug, Clone, Default)]
+pub struct RoadGraph {
+    /// Node coordinates in `(lon, lat)` order, EPSG:4326.
+    coords: Vec<(f64, f64)>,
+    edges: Vec<Edge>,
+    /// Undirected adjacency: node -> `(edge index, other node)`.
+    adjacency: Vec<Vec<(usize, NodeId)>>,
+}
+
+impl RoadGraph {
+    /// Build a graph from a road GeoJSON `Feature`/`FeatureCollection` of LineStrings (or
+    /// MultiLineStrings). Every coordinate is rounded to ~1cm precision so that shared
+    /// endpoints across separate LineStrings collapse into the same node.
+    pub fn from_geojson(geojson: &GeoJson) -> Result<Self> {
+        let features: Vec<&geojson::Feature> = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.iter().collect(),
+            GeoJson::Feature(f) => vec![f],
+            GeoJson::Geometry(_) => {
+                anyhow::bail!(
+                    "Road graph requires a GeoJSON Feature or FeatureCollection, not a bare Geometry"
+                )
+            }
+        };
+
+        let mut graph = RoadGraph::default();
+        let mut node_index: HashMap<(i64, i64), NodeId> = HashMap::new();
+
+        for feature in features {
+            let Some(geometry) = feature.geometry.as_ref() else {
+                continue;
+            };
+
+            let lines: Vec<Vec<Vec<f64>>> = match &geometry.value {
+                GeoJsonValue::LineString(coords) => vec![coords.clone()],
+                GeoJsonValue::MultiLineString(lines) => lines.clone(),
+                _ => continue,
+            };
+
+            for coords in lines {
+                for pair in coords.windows(2) {
+                    let (x0, y0) = (pair[0][0], pair[0][1]);
+                    let (x1, y1) = (pair[1][0], pair[1][1]);
+                    let from = graph.node_for(&mut node_index, x0, y0);
+                    let to = graph.node_for(&mut node_index, x1, y1);
+                    if from == to {
+                        continue;
+                    }
+
+                    let length = LineString::from(vec![(x0, y0), (x1, y1)]).geodesic_length();
+                    graph.add_edge(from, to, length);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn node_for(&mut self, index: &mut HashMap<(i64, i64), NodeId>, x: f64, y: f64) -> NodeId {
+        // ~1cm at the equator - close enough to merge floating-point-noisy shared endpoints
+        // without collapsing genuinely distinct nearby intersections.
+        let key = ((x * 1e7).round() as i64, (y * 1e7).round() as i64);
+        *index.entry(key).or_insert_with(|| {
+            self.coords.push((x, y));
+            self.adjacency.push(Vec::new());
+            self.coords.len() - 1
+        })
+    }
+
+    fn add_edge(&mut self, from: NodeId, to: NodeId, weight: f64) {
+        let edge_id = self.edges.len();
+        self.edges.push(Edge {
+            from,
+            to,
+            weight,
+            impassable: false,
+        });
+        self.adjacency[from].push((edge_id, to));
+        self.adjacency[to].push((edge_id, from));
+    }
+
+    /// Number of nodes (intersections/endpoints) in the graph.
+    pub fn node_count(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Number of edges (road segments between nodes) in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Find the graph node nearest to an EPSG:4326 `(lon, lat)` coordinate, for turning an
+    /// arbitrary origin/destination into a routable node. Returns `None` for an empty graph.
+    pub fn nearest_node(&self, lon: f64, lat: f64) -> Option<NodeId> {
+        self.coords
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let da = (a.0 - lon).powi(2) + (a.1 - lat).powi(2);
+                let db = (b.0 - lon).powi(2) + (b.1 - lat).powi(2);
+                da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+            })
+            .map(|(id, _)| id)
+    }
+
+    /// Mark every edge whose midpoint falls inside `hazard` as impassable, to recompute OD
+    /// accessibility under a flood/hazard scenario. Call [`RoadGraph::clear_impassable`] to
+    /// reset before trying a different scenario.
+    pub fn set_impassable_within(&mut self, hazard: &MultiPolygon<f64>) {
+        for edge in &mut self.edges {
+            let (x0, y0) = self.coords[edge.from];
+            let (x1, y1) = self.coords[edge.to];
+            let midpoint = Point::new((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+            if hazard.contains(&midpoint) {
+                edge.impassable = true;
+            }
+        }
+    }
+
+    /// Clear every edge's impassable flag set by [`RoadGraph::set_impassable_within`].
+    pub fn clear_impassable(&mut self) {
+        for edge in &mut self.edges {
+            edge.impassable = false;
+        }
+    }
+
+    /// Shortest path length in meters between `origin` and `destination`, skipping any edge
+    /// marked impassable, or `None` if they're disconnected.
+    pub fn shortest_path(&self, origin: NodeId, destination: NodeId) -> Option<f64> {
+        self.shortest_path_excluding(origin, destination, None)
+    }
+
+    fn shortest_path_excluding(
+        &self,
+        origin: NodeId,
+        destination: NodeId,
+        excluded_edge: Option<usize>,
+    ) -> Option<f64> {
+        if origin >= self.coords.len() || destination >= self.coords.len() {
+            return None;
+        }
+        if origin == destination {
+            return Some(0.0);
+        }
+
+        let mut dist = vec![f64::INFINITY; self.coords.len()];
+        dist[origin] = 0.0;
+        let mut heap = BinaryHeap::new();
+        heap.push(DijkstraState {
+            cost: 0.0,
+            node: origin,
+        });
+
+        while let Some(DijkstraState { cost, node }) = heap.pop() {
+            if node == destination {
+                return Some(cost);
+            }
+            if cost > dist[node] {
+                continue;
+            }
+            for &(edge_id, neighbor) in &self.adjacency[node] {
+                if Some(edge_id) == excluded_edge || self.edges[edge_id].impassable {
+                    continue;
+                }
+                let next_cost = cost + self.edges[edge_id].weight;
+                if next_cost < dist[neighbor] {
+                    dist[neighbor] = next_cost;
+                    heap.push(DijkstraState {
+                        cost: next_cost,
+                        node: neighbor,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// For every (passable) edge, temporarily remove it and recompute the shortest path for
+    /// each OD pair in `od_pairs`, reporting the resulting detour. Mirrors the multi-link
+    /// redundancy / detour-length workflow from flood-impact road studies: edges whose removal
+    /// produces the largest detours (or disconnects a pair outright) are the network's critical
+    /// single points of failure.
+    pub fn link_redundancy(&self, od_pairs: &[(NodeId, NodeId)]) -> Vec<LinkRedundancy> {
+        let baselines: Vec<Option<f64>> = od_pairs
+            .iter()
+            .map(|&(origin, destination)| self.shortest_path(origin, destination))
+            .collect();
+
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| !edge.impassable)
+            .map(|(edge_id, _)| {
+                let detours = od_pairs
+                    .iter()
+                    .zip(&baselines)
+                    .map(|(&(origin, destination), &baseline)| {
+                        let alternative =
+                            self.shortest_path_excluding(origin, destination, Some(edge_id));
+                        OdDetour {
+                            origin,
+                            destination,
+                            baseline_length: baseline,
+                            alternative_length: alternative,
+                            detour_length: match (baseline, alternative) {
+                                (Some(b), Some(a)) => Some(a - b),
+                                _ => None,
+                            },
+                            disconnected: baseline.is_some() && alternative.is_none(),
+                        }
+                    })
+                    .collect();
+
+                LinkRedundancy { edge_id, detours }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DijkstraState {
+    cost: f64,
+    node: NodeId,
+}
+
+impl Eq for DijkstraState {}
+
+impl Ord for DijkstraState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) pops the lowest cost first.
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for DijkstraState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Detour suffered by a single origin-destination pair after one edge has been removed.
+#[derive(Debug, Clone, Copy)]
+pub struct OdDetour {
+    pub origin: NodeId,
+    pub destination: NodeId,
+    pub baseline_length: Option<f64>,
+    pub alternative_length: Option<f64>,
+    /// `alternative_length - baseline_length`, `None` if either leg is unreachable.
+    pub detour_length: Option<f64>,
+    /// `true` if the pair was connected at baseline but becomes unreachable with this edge
+    /// removed.
+    pub disconnected: bool,
+}
+
+/// Per-edge outcome of [`RoadGraph::link_redundancy`]: the detours every OD pair suffers when
+/// this edge is removed.
+#[derive(Debug, Clone)]
+pub struct LinkRedundancy {
+    pub edge_id: usize,
+    pub detours: Vec<OdDetour>,
+}
+
+/// Parse a flood/hazard GeoJSON Polygon/MultiPolygon (Geometry, Feature, or the first feature
+/// of a FeatureCollection) in EPSG:4326, for [`RoadGraph::set_impassable_within`].
+pub fn parse_hazard_geojson(hazard_geojson: &str) -> Result<MultiPolygon<f64>> {
+    let geojson: GeoJson = hazard_geojson
+        .parse()
+        .context("Failed to parse hazard GeoJSON")?;
+
+    let geometry = match &geojson {
+        GeoJson::Geometry(g) => g.clone(),
+        GeoJson::Feature(f) => f
+            .geometry
+            .clone()
+            .context("Hazard feature has no geometry")?,
+        GeoJson::FeatureCollection(fc) => fc
+            .features
+            .first()
+            .and_then(|f| f.geometry.clone())
+            .context("Hazard FeatureCollection has no features")?,
+    };
+
+    let geo_geom: geo::Geometry<f64> = (&geometry)
+        .try_into()
+        .context("Invalid hazard geometry")?;
+
+    match geo_geom {
+        geo::Geometry::Polygon(p) => Ok(MultiPolygon(vec![p])),
+        geo::Geometry::MultiPolygon(mp) => Ok(mp),
+        _ => anyhow::bail!("Hazard geometry must be a Polygon or MultiPolygon"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geojson::{Feature, FeatureCollection};
+
+    fn line_feature(coords: &[(f64, f64)]) -> Feature {
+        Feature {
+            bbox: None,
+            geometry: Some(geojson::Geometry::new(GeoJsonValue::LineString(
+                coords.iter().map(|&(x, y)| vec![x, y]).collect(),
+            ))),
+            id: None,
+            properties: None,
+            foreign_members: None,
+        }
+    }
+
+    fn graph_from_lines(lines: &[&[(f64, f64)]]) -> RoadGraph {
+        let fc = GeoJson::FeatureCollection(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: lines.iter().map(|coords| line_feature(coords)).collect(),
+        });
+        RoadGraph::from_geojson(&fc).unwrap()
+    }
+
+    #[test]
+    fn test_shortest_path_disconnected_graph() {
+        // Two separate segments that never share an endpoint.
+        let graph = graph_from_lines(&[&[(0.0, 0.0), (1.0, 0.0)], &[(10.0, 10.0), (11.0, 10.0)]]);
+        assert_eq!(graph.node_count(), 4);
+
+        let origin = graph.nearest_node(0.0, 0.0).unwrap();
+        let destination = graph.nearest_node(10.0, 10.0).unwrap();
+        assert_eq!(graph.shortest_path(origin, destination), None);
+    }
+
+    #[test]
+    fn test_shortest_path_out_of_range_node_returns_none() {
+        let graph = graph_from_lines(&[&[(0.0, 0.0), (1.0, 0.0)]]);
+        assert_eq!(graph.shortest_path(0, graph.node_count()), None);
+        assert_eq!(graph.shortest_path(graph.node_count(), 0), None);
+    }
+
+    #[test]
+    fn test_link_redundancy_detects_disconnecting_edge() {
+        // A simple path A - B - C with no alternative route.
+        let graph = graph_from_lines(&[&[(0.0, 0.0), (0.0, 1.0), (0.0, 2.0)]]);
+        let a = graph.nearest_node(0.0, 0.0).unwrap();
+        let c = graph.nearest_node(0.0, 2.0).unwrap();
+
+        let links = graph.link_redundancy(&[(a, c)]);
+        assert_eq!(links.len(), 2); // A-B and B-C edges
+        assert!(links
+            .iter()
+            .all(|link| link.detours[0].disconnected && link.detours[0].alternative_length.is_none()));
+    }
+
+    #[test]
+    fn test_shortest_path_picks_shorter_of_two_routes() {
+        // A square A-B-C-D-A plus a direct short-cut A-C, so there are two routes from A to C:
+        // the long way around (A-B-C) and the direct diagonal (A-C).
+        let graph = graph_from_lines(&[
+            &[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0)], // A - B - C
+            &[(1.0, 1.0), (1.0, 0.0), (0.0, 0.0)], // C - D - A
+            &[(0.0, 0.0), (1.0, 1.0)],             // direct A - C
+        ]);
+        let a = graph.nearest_node(0.0, 0.0).unwrap();
+        let c = graph.nearest_node(1.0, 1.0).unwrap();
+
+        let direct = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]).geodesic_length();
+        let shortest = graph.shortest_path(a, c).unwrap();
+        assert!((shortest - direct).abs() < 1e-6);
+    }
+}
@@ -1,12 +1,16 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use gdal::vector::Geometry as GdalGeometry;
-use geo::{Geometry as GeoGeometry, Polygon};
+use geo::{Area, BooleanOps, BoundingRect, Geometry as GeoGeometry, Polygon};
 use geojson::{Feature, GeoJson, Geometry};
 use geos::{Geom, Geometry as GeosGeometry};
+use rstar::{RTree, RTreeObject, AABB};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::collect::global_variables::TEMP_PATH;
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, OutputFormat};
 
 /// Land cover type codes
 /// Following Python: LandCover type codes
@@ -29,6 +33,46 @@ pub enum LandCoverType {
     Walls = 99,
 }
 
+impl LandCoverType {
+    /// Default RGBA color assigned to this class in [`LandCover::to_raster_categorical`]'s color
+    /// table. Callers can override any/all of these via that method's `palette` parameter.
+    pub fn default_color(&self) -> [u8; 4] {
+        match self {
+            LandCoverType::CobbleStone => [150, 150, 150, 255],
+            LandCoverType::DarkAsphalt => [60, 60, 60, 255],
+            LandCoverType::RoofsBuildings => [200, 30, 30, 255],
+            LandCoverType::GrassUnmanaged => [120, 190, 90, 255],
+            LandCoverType::BareSoil => [160, 120, 80, 255],
+            LandCoverType::Water => [50, 110, 220, 255],
+            LandCoverType::Walls => [90, 90, 90, 255],
+        }
+    }
+
+    /// Human-readable class name, used as the raster attribute table's `ClassName` column.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LandCoverType::CobbleStone => "Cobble_stone_2014a",
+            LandCoverType::DarkAsphalt => "Dark_asphalt",
+            LandCoverType::RoofsBuildings => "Roofs",
+            LandCoverType::GrassUnmanaged => "Grass_unmanaged",
+            LandCoverType::BareSoil => "bare_soil",
+            LandCoverType::Water => "Water",
+            LandCoverType::Walls => "Walls",
+        }
+    }
+
+    /// Every known land cover type code, in the fixed order used to build the default palette.
+    pub const ALL: [LandCoverType; 7] = [
+        LandCoverType::CobbleStone,
+        LandCoverType::DarkAsphalt,
+        LandCoverType::RoofsBuildings,
+        LandCoverType::GrassUnmanaged,
+        LandCoverType::BareSoil,
+        LandCoverType::Water,
+        LandCoverType::Walls,
+    ];
+}
+
 impl From<u8> for LandCoverType {
     fn from(code: u8) -> Self {
         match code {
@@ -44,6 +88,21 @@ impl From<u8> for LandCoverType {
     }
 }
 
+/// Controls how [`LandCover::to_raster`] resolves a pixel covered by more than one class, e.g.
+/// where the source polygons overlap or are fragmented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BurnStrategy {
+    /// GDAL's normal vectorized scanline burn: whichever feature rasterizes into a pixel last
+    /// wins, with no area accounting. This is the original `to_raster` behavior.
+    First,
+    /// Intersect the pixel's footprint against every overlapping polygon and burn the class
+    /// covering the largest share of its area.
+    Majority,
+    /// Like `Majority`, but only burns the winning class if its share of the pixel's area is at
+    /// least `area_threshold` percent; otherwise the pixel is left at nodata.
+    Threshold,
+}
+
 /// LandCover structure
 /// Following Python: class LandCover(GeoCore, BasicFunctions)
 /// Combines multiple land cover types into a single GeoDataFrame
@@ -62,6 +121,13 @@ pub struct LandCover {
     dxf_geojson: Option<GeoJson>,
     /// Write file flag
     write_file: bool,
+    /// Default source EPSG for `add_*_gdf` calls that don't pass their own, set via
+    /// [`LandCover::set_source_crs`]. `None` means "assume inputs already match `geo_core`'s
+    /// EPSG", matching every `add_*_gdf` method's previous (unreprojected) behavior.
+    source_crs: Option<i32>,
+    /// Mask set via [`LandCover::set_mask_topojson`], used by `run` when its own `mask`
+    /// argument is `None`.
+    mask_geojson: Option<GeoJson>,
 }
 
 impl LandCover {
@@ -83,47 +149,106 @@ impl LandCover {
             cosia_geojson: None,
             dxf_geojson: None,
             write_file,
+            source_crs: None,
+            mask_geojson: None,
         })
     }
 
+    /// Set the default source EPSG assumed for any `add_*_gdf` call that doesn't pass its own
+    /// `source_epsg`. Inputs declared (or defaulted) to a different EPSG than `geo_core`'s are
+    /// reprojected on ingest so mixed-CRS layers don't silently misalign when rasterized.
+    pub fn set_source_crs(&mut self, epsg: i32) {
+        self.source_crs = Some(epsg);
+    }
+
+    /// Reproject `geojson` from `source_epsg` (or the `set_source_crs` default, or `geo_core`'s
+    /// own EPSG if neither is set) to `geo_core`'s EPSG, returning an owned, aligned copy.
+    fn normalize_crs(&self, geojson: &GeoJson, source_epsg: Option<i32>) -> Result<GeoJson> {
+        let from_epsg = source_epsg.or(self.source_crs).unwrap_or(self.geo_core.get_epsg());
+        let to_epsg = self.geo_core.get_epsg();
+        let mut geojson = geojson.clone();
+        if from_epsg != to_epsg {
+            GeoCore::reproject_geojson(&mut geojson, from_epsg, to_epsg)
+                .with_context(|| format!("Failed to reproject input from EPSG:{from_epsg} to EPSG:{to_epsg}"))?;
+        }
+        Ok(geojson)
+    }
+
     /// Add building GeoDataFrame
     /// Following Python: self.building = building_gdf[["geometry"]].copy(); self.building["type"] = 2
-    pub fn add_building_gdf(&mut self, building_geojson: &GeoJson) -> Result<()> {
-        self.add_geojson_with_type(building_geojson, LandCoverType::RoofsBuildings as u8)
+    pub fn add_building_gdf(&mut self, building_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.add_geojson_with_type(building_geojson, LandCoverType::RoofsBuildings as u8, source_epsg)
     }
 
     /// Add vegetation GeoDataFrame
     /// Following Python: self.vegetation = vegetation_gdf[["geometry"]].copy(); self.vegetation["type"] = 5
-    pub fn add_vegetation_gdf(&mut self, vegetation_geojson: &GeoJson) -> Result<()> {
-        self.add_geojson_with_type(vegetation_geojson, LandCoverType::GrassUnmanaged as u8)
+    pub fn add_vegetation_gdf(&mut self, vegetation_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.add_geojson_with_type(vegetation_geojson, LandCoverType::GrassUnmanaged as u8, source_epsg)
     }
 
     /// Add water GeoDataFrame
     /// Following Python: self.water = water_gdf[["geometry"]].copy(); self.water["type"] = 7
-    pub fn add_water_gdf(&mut self, water_geojson: &GeoJson) -> Result<()> {
-        self.add_geojson_with_type(water_geojson, LandCoverType::Water as u8)
+    pub fn add_water_gdf(&mut self, water_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.add_geojson_with_type(water_geojson, LandCoverType::Water as u8, source_epsg)
     }
 
     /// Add pedestrian GeoDataFrame
     /// Following Python: self.pedestrian = pedestrian_gdf[["geometry"]].copy(); self.pedestrian["type"] = 6
-    pub fn add_pedestrian_gdf(&mut self, pedestrian_geojson: &GeoJson) -> Result<()> {
-        self.add_geojson_with_type(pedestrian_geojson, LandCoverType::BareSoil as u8)
+    pub fn add_pedestrian_gdf(&mut self, pedestrian_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.add_geojson_with_type(pedestrian_geojson, LandCoverType::BareSoil as u8, source_epsg)
     }
 
     /// Add COSIA GeoDataFrame
-    pub fn add_cosia_gdf(&mut self, cosia_geojson: &GeoJson) -> Result<()> {
-        self.cosia_geojson = Some(cosia_geojson.clone());
+    pub fn add_cosia_gdf(&mut self, cosia_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.cosia_geojson = Some(self.normalize_crs(cosia_geojson, source_epsg)?);
         Ok(())
     }
 
     /// Add DXF GeoDataFrame
-    pub fn add_dxf_gdf(&mut self, dxf_geojson: &GeoJson) -> Result<()> {
-        self.dxf_geojson = Some(dxf_geojson.clone());
+    pub fn add_dxf_gdf(&mut self, dxf_geojson: &GeoJson, source_epsg: Option<i32>) -> Result<()> {
+        self.dxf_geojson = Some(self.normalize_crs(dxf_geojson, source_epsg)?);
+        Ok(())
+    }
+
+    /// Add a building layer distributed as TopoJSON instead of GeoJSON
+    pub fn add_building_topojson(
+        &mut self,
+        topojson: &Value,
+        object_name: Option<&str>,
+        source_epsg: Option<i32>,
+    ) -> Result<()> {
+        self.add_topojson_with_type(
+            topojson,
+            object_name,
+            LandCoverType::RoofsBuildings as u8,
+            source_epsg,
+        )
+    }
+
+    /// Set the clip mask from a TopoJSON document, used by `run` when its own `mask` argument
+    /// is `None`.
+    pub fn set_mask_topojson(&mut self, topojson: &Value, object_name: Option<&str>) -> Result<()> {
+        self.mask_geojson = Some(topojson_to_geojson(topojson, object_name)?);
         Ok(())
     }
 
+    /// Decode a TopoJSON document and feed the resulting features into `add_geojson_with_type`,
+    /// same as any other `add_*_gdf` input.
+    fn add_topojson_with_type(
+        &mut self,
+        topojson: &Value,
+        object_name: Option<&str>,
+        type_code: u8,
+        source_epsg: Option<i32>,
+    ) -> Result<()> {
+        let geojson = topojson_to_geojson(topojson, object_name)?;
+        self.add_geojson_with_type(&geojson, type_code, source_epsg)
+    }
+
     /// Helper method to add GeoJSON with a specific type
-    fn add_geojson_with_type(&mut self, geojson: &GeoJson, type_code: u8) -> Result<()> {
+    fn add_geojson_with_type(&mut self, geojson: &GeoJson, type_code: u8, source_epsg: Option<i32>) -> Result<()> {
+        let geojson = self.normalize_crs(geojson, source_epsg)?;
+        let geojson = &geojson;
         let features = match geojson {
             GeoJson::FeatureCollection(fc) => {
                 // Extract geometry and add type property
@@ -211,8 +336,8 @@ impl LandCover {
             }
         }
 
-        // Apply mask if provided
-        if let Some(mask_geojson) = mask {
+        // Apply mask if provided, falling back to a mask set via `set_mask_topojson`
+        if let Some(mask_geojson) = mask.or(self.mask_geojson.as_ref()) {
             all_features = self.apply_mask(&all_features, mask_geojson)?;
         }
 
@@ -261,26 +386,42 @@ impl LandCover {
             GeoJson::Geometry(g) => Some(g),
         };
 
-        let mask_polygon = if let Some(geom) = mask_geom {
-            self.geojson_geometry_to_geo_polygon(geom)?
+        let mask_polygons = if let Some(geom) = mask_geom {
+            self.geojson_geometry_to_geo_polygons(geom)?
         } else {
             return Ok(features.to_vec());
         };
+        if mask_polygons.is_empty() {
+            return Ok(features.to_vec());
+        }
+
+        // Union every mask part into one GEOS geometry so a MultiPolygon mask clips correctly.
+        let mut geos_mask: GeosGeometry = mask_polygons[0]
+            .clone()
+            .try_into()
+            .context("Failed to convert mask to GEOS")?;
+        for part in &mask_polygons[1..] {
+            let geos_part: GeosGeometry = part
+                .clone()
+                .try_into()
+                .context("Failed to convert mask part to GEOS")?;
+            geos_mask = geos_mask
+                .union(&geos_part)
+                .context("Failed to union mask parts")?;
+        }
 
         // Clip each feature
         let mut clipped_features = Vec::new();
         for feature in features {
             if let Some(ref geom) = feature.geometry {
-                if let Ok(polygon) = self.geojson_geometry_to_geo_polygon(geom) {
+                let Ok(polygons) = self.geojson_geometry_to_geo_polygons(geom) else {
+                    continue;
+                };
+                for polygon in polygons {
                     // Use GEOS for intersection
                     let geos_polygon: GeosGeometry = polygon
-                        .clone()
                         .try_into()
                         .context("Failed to convert polygon to GEOS")?;
-                    let geos_mask: GeosGeometry = mask_polygon
-                        .clone()
-                        .try_into()
-                        .context("Failed to convert mask to GEOS")?;
 
                     if let Ok(intersection) = geos_polygon.intersection(&geos_mask) {
                         let clipped_geo: GeoGeometry<f64> = match intersection.try_into() {
@@ -308,22 +449,44 @@ impl LandCover {
         Ok(clipped_features)
     }
 
-    /// Convert GeoJSON geometry to geo::Polygon
-    fn geojson_geometry_to_geo_polygon(&self, geom: &Geometry) -> Result<Polygon<f64>> {
-        // Convert GeoJSON to geo::Geometry
+    /// Convert a GeoJSON geometry to every `geo::Polygon` it contains: a bare `Polygon` yields
+    /// one, a `MultiPolygon` is expanded into its members, and a `GeometryCollection` is
+    /// flattened recursively. Real COSIA/land-cover data (water bodies, vegetation) commonly
+    /// arrives as MultiPolygon, so callers must not assume a single result.
+    fn geojson_geometry_to_geo_polygons(&self, geom: &Geometry) -> Result<Vec<Polygon<f64>>> {
         let geo_geom: GeoGeometry<f64> = geom
             .try_into()
             .context("Failed to convert GeoJSON geometry to geo geometry")?;
+        Self::flatten_to_polygons(geo_geom)
+    }
 
-        // Extract polygon
+    /// Recursively collect every `Polygon` out of a `geo::Geometry`, expanding `MultiPolygon`
+    /// and flattening `GeometryCollection`. Non-polygonal members (points, lines) are skipped.
+    fn flatten_to_polygons(geo_geom: GeoGeometry<f64>) -> Result<Vec<Polygon<f64>>> {
         match geo_geom {
-            GeoGeometry::Polygon(p) => Ok(p),
-            _ => anyhow::bail!("Expected polygon geometry"),
+            GeoGeometry::Polygon(p) => Ok(vec![p]),
+            GeoGeometry::MultiPolygon(mp) => Ok(mp.0),
+            GeoGeometry::GeometryCollection(gc) => {
+                let mut polygons = Vec::new();
+                for member in gc {
+                    polygons.extend(Self::flatten_to_polygons(member)?);
+                }
+                Ok(polygons)
+            }
+            _ => Ok(Vec::new()),
         }
     }
 
     /// Unify COSIA and DXF GeoDataFrames
-    /// Following Python: def unify_cosia_dxf(self)
+    ///
+    /// Builds an R-tree over every DXF feature's polygon-part envelopes once, then for each
+    /// COSIA polygon queries the tree for only the DXF envelopes whose AABB intersects the
+    /// COSIA polygon's AABB. Each true candidate (one that actually intersects once buffered,
+    /// not just AABB-adjacent) is unioned into a single accumulated geometry per COSIA polygon,
+    /// carrying the `classe` of the first DXF piece that truly overlaps. A COSIA polygon with no
+    /// overlapping DXF neighbor passes through unchanged. This replaces the previous O(n*m)
+    /// double loop, which also emitted one union feature per COSIA/DXF pair rather than one
+    /// feature per COSIA polygon.
     fn unify_cosia_dxf(&self) -> Result<GeoJson> {
         let cosia = self
             .cosia_geojson
@@ -334,72 +497,114 @@ impl LandCover {
             .as_ref()
             .context("DXF GeoJSON is required")?;
 
-        // Get features from both
         let cosia_features = match cosia {
             GeoJson::FeatureCollection(fc) => &fc.features,
-            _ => return Err(anyhow::anyhow!("COSIA must be a FeatureCollection")),
+            _ => return Err(anyhow!("COSIA must be a FeatureCollection")),
         };
-
         let dxf_features = match dxf {
             GeoJson::FeatureCollection(fc) => &fc.features,
-            _ => return Err(anyhow::anyhow!("DXF must be a FeatureCollection")),
+            _ => return Err(anyhow!("DXF must be a FeatureCollection")),
         };
 
-        // Perform overlay union using GEOS
+        // Flatten every DXF feature into its individual polygon parts, keeping a back-pointer
+        // to the owning feature so `classe` can still be recovered after the R-tree query.
+        struct DxfPart<'a> {
+            polygon: Polygon<f64>,
+            feature: &'a Feature,
+        }
+        let mut dxf_parts: Vec<DxfPart> = Vec::new();
+        for dxf_feat in dxf_features {
+            if let Some(ref dxf_geom) = dxf_feat.geometry {
+                for polygon in self.geojson_geometry_to_geo_polygons(dxf_geom)? {
+                    dxf_parts.push(DxfPart { polygon, feature: dxf_feat });
+                }
+            }
+        }
+
+        struct DxfEnvelope {
+            min_x: f64,
+            min_y: f64,
+            max_x: f64,
+            max_y: f64,
+            index: usize,
+        }
+        impl RTreeObject for DxfEnvelope {
+            type Envelope = AABB<[f64; 2]>;
+            fn envelope(&self) -> Self::Envelope {
+                AABB::from_corners([self.min_x, self.min_y], [self.max_x, self.max_y])
+            }
+        }
+
+        let envelopes: Vec<DxfEnvelope> = dxf_parts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, part)| {
+                part.polygon.bounding_rect().map(|rect| DxfEnvelope {
+                    min_x: rect.min().x,
+                    min_y: rect.min().y,
+                    max_x: rect.max().x,
+                    max_y: rect.max().y,
+                    index,
+                })
+            })
+            .collect();
+        let tree = RTree::bulk_load(envelopes);
+
         let mut unified_features = Vec::new();
 
         for cosia_feat in cosia_features {
-            if let Some(ref cosia_geom) = cosia_feat.geometry {
-                let cosia_poly: Polygon<f64> = self.geojson_geometry_to_geo_polygon(cosia_geom)?;
-                let cosia_geos: GeosGeometry = cosia_poly
+            let Some(ref cosia_geom) = cosia_feat.geometry else { continue };
+            let cosia_classe = cosia_feat.properties.as_ref().and_then(|p| p.get("classe")).cloned();
+
+            for cosia_poly in self.geojson_geometry_to_geo_polygons(cosia_geom)? {
+                let Some(cosia_rect) = cosia_poly.bounding_rect() else { continue };
+                let query = AABB::from_corners(
+                    [cosia_rect.min().x, cosia_rect.min().y],
+                    [cosia_rect.max().x, cosia_rect.max().y],
+                );
+
+                let mut accumulated: GeosGeometry = cosia_poly
                     .clone()
                     .try_into()
                     .context("Failed to convert COSIA to GEOS")?;
+                let mut overlap_classe = None;
 
-                for dxf_feat in dxf_features {
-                    if let Some(ref dxf_geom) = dxf_feat.geometry {
-                        let dxf_poly: Polygon<f64> =
-                            self.geojson_geometry_to_geo_polygon(dxf_geom)?;
-                        let dxf_geos: GeosGeometry = dxf_poly
-                            .clone()
-                            .try_into()
-                            .context("Failed to convert DXF to GEOS")?;
-
-                        // Buffer DXF slightly (following Python: self.dxf_gdf["geometry"] = self.dxf_gdf["geometry"].buffer(0.001))
-                        let dxf_buffered = dxf_geos
-                            .buffer(0.001, 8)
-                            .context("Failed to buffer DXF geometry")?;
-
-                        // Union operation
-                        if let Ok(union) = cosia_geos.union(&dxf_buffered) {
-                            // Get classe from COSIA or DXF
-                            let classe = cosia_feat
-                                .properties
-                                .as_ref()
-                                .and_then(|p| p.get("classe"))
-                                .or_else(|| {
-                                    dxf_feat.properties.as_ref().and_then(|p| p.get("classe"))
-                                })
-                                .cloned();
-
-                            // Convert back to GeoJSON
-                            let union_geo: GeoGeometry<f64> = match union.try_into() {
-                                Ok(g) => g,
-                                Err(_) => continue,
-                            };
-                            // Convert geo::Geometry to geojson::Geometry
-                            let union_geom: Geometry = (&union_geo)
-                                .try_into()
-                                .context("Failed to convert union to GeoJSON")?;
-
-                            let mut unified_feat = Feature::from(union_geom);
-                            if let Some(classe_val) = classe {
-                                unified_feat.set_property("classe", classe_val);
+                for candidate in tree.locate_in_envelope_intersecting(&query) {
+                    let part = &dxf_parts[candidate.index];
+                    let dxf_geos: GeosGeometry = part
+                        .polygon
+                        .clone()
+                        .try_into()
+                        .context("Failed to convert DXF to GEOS")?;
+                    // Following Python: self.dxf_gdf["geometry"] = self.dxf_gdf["geometry"].buffer(0.001)
+                    let dxf_buffered = dxf_geos
+                        .buffer(0.001, 8)
+                        .context("Failed to buffer DXF geometry")?;
+
+                    if accumulated.intersects(&dxf_buffered).unwrap_or(false) {
+                        if let Ok(union) = accumulated.union(&dxf_buffered) {
+                            accumulated = union;
+                            if overlap_classe.is_none() {
+                                overlap_classe =
+                                    part.feature.properties.as_ref().and_then(|p| p.get("classe")).cloned();
                             }
-                            unified_features.push(unified_feat);
                         }
                     }
                 }
+
+                let union_geo: GeoGeometry<f64> = match accumulated.try_into() {
+                    Ok(g) => g,
+                    Err(_) => continue,
+                };
+                let union_geom: Geometry = (&union_geo)
+                    .try_into()
+                    .context("Failed to convert union to GeoJSON")?;
+
+                let mut unified_feat = Feature::from(union_geom);
+                if let Some(classe) = overlap_classe.or_else(|| cosia_classe.clone()) {
+                    unified_feat.set_property("classe", classe);
+                }
+                unified_features.push(unified_feat);
             }
         }
 
@@ -431,13 +636,55 @@ impl LandCover {
         self.geojson.as_ref()
     }
 
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, GeoJSON, FlatGeobuf,
+    /// KML, GPX) via `ogr2ogr`, reprojecting to geo_core's EPSG on the way out. `write_geojson`
+    /// only ever writes a `.geojson` file, which is impractical for the large merged datasets
+    /// `run` produces, so GeoPackage/FlatGeobuf is the usual choice here.
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("landcover");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.get_epsg())
+    }
+
+    /// Export to an explicit `path` through a native GDAL/OGR writer (see
+    /// [`export::write_vector_native`]) instead of `to_file`'s `ogr2ogr` subprocess and fixed
+    /// `output_path`/name layout. `driver` is an OGR driver name, one of
+    /// [`export::NATIVE_VECTOR_DRIVERS`] (`"GPKG"`, `"ESRI Shapefile"`, `"GeoJSON"`); `layer`
+    /// names the output layer, defaulting to `"landcover"`.
+    pub fn to_vector(&self, path: &Path, driver: &str, layer: Option<&str>) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        export::write_vector_native(
+            geojson,
+            path,
+            driver,
+            layer.unwrap_or("landcover"),
+            self.geo_core.get_epsg(),
+        )
+    }
+
     /// Create raster from land cover GeoDataFrame
     /// Following Python: def create_landcover_from_cosia(self, dst_tif="landcover.tif", template_raster_path=None)
+    ///
+    /// `burn_strategy` controls how a pixel covered by more than one class is resolved. `None`
+    /// (the default) keeps the original GDAL scanline burn, where the last feature touching a
+    /// pixel wins with no area accounting. `Some(BurnStrategy::Majority)` or
+    /// `Some(BurnStrategy::Threshold)` instead intersect each pixel against every overlapping
+    /// polygon and burn the class covering the largest share of its area; `Threshold`
+    /// additionally requires that share to be at least `area_threshold` percent (0-100, default
+    /// 50.0), leaving the pixel at nodata otherwise.
     pub fn to_raster(
         &self,
         dst_tif: &str,
         template_raster_path: Option<&Path>,
         resolution: Option<(f64, f64)>,
+        burn_strategy: Option<BurnStrategy>,
+        area_threshold: Option<f64>,
     ) -> Result<PathBuf> {
         use gdal::raster::Buffer;
         use gdal::spatial_ref::SpatialRef;
@@ -503,38 +750,75 @@ impl LandCover {
             .set_spatial_ref(&srs)
             .context("Failed to set spatial reference")?;
 
-        // Initialize raster with nodata
-        let mut raster_data = vec![f32::NAN; width * height];
+        // Initialize the band with nodata so GDAL's vectorized burn has a defined background,
+        // and so the per-pixel fallback below (which only ever sets covered cells) still
+        // produces a fully-initialized raster.
+        {
+            let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+            let nodata_buffer = Buffer::new((width, height), vec![f32::NAN; width * height]);
+            band.write((0, 0), (width, height), &nodata_buffer)
+                .context("Failed to initialize raster band with nodata")?;
+        }
 
-        // Rasterize features using GDAL
         if let GeoJson::FeatureCollection(fc) = geojson {
-            for feature in &fc.features {
-                if let Some(ref geom) = feature.geometry {
-                    let type_code = feature
-                        .properties
-                        .as_ref()
-                        .and_then(|p| p.get("type"))
-                        .and_then(|v| v.as_i64())
-                        .unwrap_or(0) as f32;
-
-                    // Rasterize this feature using GDAL
-                    self.rasterize_geometry_gdal(
-                        geom,
-                        type_code,
-                        &mut raster_data,
+            match burn_strategy {
+                None | Some(BurnStrategy::First) => {
+                    // Rasterize every feature in one GDAL call (GDALRasterizeGeometries), burning
+                    // each geometry's `type` property as its pixel value with proper scanline
+                    // fill -- this replaces thousands of per-pixel contains() calls with a single
+                    // vectorized burn and fixes boundary pixels that per-pixel sampling could
+                    // miss or double-count. Falls back to the old per-feature point-in-polygon
+                    // loop if the rasterize API is unavailable.
+                    if let Err(e) = self.rasterize_features_gdal(&dataset, fc) {
+                        eprintln!(
+                            "Warning: GDAL vectorized rasterize unavailable ({e}), falling back \
+                             to per-pixel rasterization"
+                        );
+                        let mut raster_data = vec![f32::NAN; width * height];
+                        for feature in &fc.features {
+                            if let Some(ref geom) = feature.geometry {
+                                let type_code = feature
+                                    .properties
+                                    .as_ref()
+                                    .and_then(|p| p.get("type"))
+                                    .and_then(|v| v.as_i64())
+                                    .unwrap_or(0) as f32;
+
+                                self.rasterize_geometry_gdal_fallback(
+                                    geom,
+                                    type_code,
+                                    &mut raster_data,
+                                    width,
+                                    height,
+                                    &transform,
+                                )?;
+                            }
+                        }
+
+                        let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+                        let buffer = Buffer::new((width, height), raster_data);
+                        band.write((0, 0), (width, height), &buffer)
+                            .context("Failed to write raster band")?;
+                    }
+                }
+                Some(strategy) => {
+                    let raster_data = self.rasterize_by_area_coverage(
+                        fc,
                         width,
                         height,
                         &transform,
+                        strategy,
+                        area_threshold,
                     )?;
+                    let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+                    let buffer = Buffer::new((width, height), raster_data);
+                    band.write((0, 0), (width, height), &buffer)
+                        .context("Failed to write raster band")?;
                 }
             }
         }
 
-        // Write raster band
         let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
-        let buffer = Buffer::new((width, height), raster_data);
-        band.write((0, 0), (width, height), &buffer)
-            .context("Failed to write raster band")?;
         band.set_no_data_value(Some(f32::NAN as f64))
             .context("Failed to set no data value")?;
 
@@ -542,9 +826,504 @@ impl LandCover {
         Ok(output_path)
     }
 
-    /// Rasterize a single geometry using GDAL
-    /// Uses GDAL's envelope to optimize rasterization
-    fn rasterize_geometry_gdal(
+    /// Nodata value for [`LandCover::to_raster_categorical`]'s `Byte` band. `255` is outside
+    /// every real `LandCoverType` code (the highest, `Walls`, is `99`), so it can't collide with
+    /// a legitimate class the way `to_raster`'s floating `NaN` sentinel would be unnecessary
+    /// precision for an integer classification.
+    pub const CATEGORICAL_NODATA: u8 = 255;
+
+    /// Same rasterization as [`LandCover::to_raster`], but writes a `Byte` band with a GDAL
+    /// color table and raster attribute table attached, so the GeoTIFF opens in a GIS viewer as
+    /// a labeled thematic/classification layer instead of an opaque `f32` grid of numeric codes.
+    ///
+    /// `palette` overrides [`LandCoverType::default_color`] for any subset of classes; classes
+    /// not present in it keep their default color. Nodata pixels are `CATEGORICAL_NODATA` (255)
+    /// rather than floating `NaN`.
+    pub fn to_raster_categorical(
+        &self,
+        dst_tif: &str,
+        template_raster_path: Option<&Path>,
+        resolution: Option<(f64, f64)>,
+        palette: Option<&std::collections::HashMap<u8, [u8; 4]>>,
+    ) -> Result<PathBuf> {
+        use gdal::raster::{Buffer, ColorEntry, ColorInterpretation, ColorTable};
+        use gdal::spatial_ref::SpatialRef;
+        use gdal::{Dataset, DriverManager};
+
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let bbox = self
+            .geo_core
+            .get_bbox()
+            .context("Bounding box must be set")?;
+
+        let (width, height, transform) = if let Some(template) = template_raster_path {
+            let template_ds = Dataset::open(template).context("Failed to open template raster")?;
+            let (w, h) = template_ds.raster_size();
+            let gt = template_ds.geo_transform()?;
+            (w as usize, h as usize, gt)
+        } else {
+            let res = resolution.unwrap_or((1.0, 1.0));
+            let width = ((bbox.max_x - bbox.min_x) / res.0).ceil() as usize;
+            let height = ((bbox.max_y - bbox.min_y) / res.1).ceil() as usize;
+            let transform = [bbox.min_x, res.0, 0.0, bbox.max_y, 0.0, -res.1];
+            (width, height, transform)
+        };
+
+        let output_path = self.output_path.join(dst_tif);
+        let driver =
+            DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+
+        let mut dataset = driver
+            .create_with_band_type::<u8, _>(&output_path, width as isize, height as isize, 1)
+            .context("Failed to create GeoTIFF dataset")?;
+
+        dataset
+            .set_geo_transform(&transform)
+            .context("Failed to set geotransform")?;
+
+        let srs = SpatialRef::from_epsg(self.geo_core.get_epsg() as u32)
+            .context("Failed to create spatial reference")?;
+        dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set spatial reference")?;
+
+        {
+            let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+            let nodata_buffer =
+                Buffer::new((width, height), vec![Self::CATEGORICAL_NODATA; width * height]);
+            band.write((0, 0), (width, height), &nodata_buffer)
+                .context("Failed to initialize raster band with nodata")?;
+        }
+
+        if let GeoJson::FeatureCollection(fc) = geojson {
+            if let Err(e) = self.rasterize_features_gdal(&dataset, fc) {
+                eprintln!(
+                    "Warning: GDAL vectorized rasterize unavailable ({e}), falling back to \
+                     per-pixel rasterization"
+                );
+                let mut raster_data = vec![Self::CATEGORICAL_NODATA; width * height];
+                for feature in &fc.features {
+                    if let Some(ref geom) = feature.geometry {
+                        let type_code = feature
+                            .properties
+                            .as_ref()
+                            .and_then(|p| p.get("type"))
+                            .and_then(|v| v.as_i64())
+                            .unwrap_or(0) as u8;
+
+                        Self::rasterize_geometry_gdal_fallback_u8(
+                            geom,
+                            type_code,
+                            &mut raster_data,
+                            width,
+                            height,
+                            &transform,
+                        )?;
+                    }
+                }
+
+                let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+                let buffer = Buffer::new((width, height), raster_data);
+                band.write((0, 0), (width, height), &buffer)
+                    .context("Failed to write raster band")?;
+            }
+        }
+
+        let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+        band.set_no_data_value(Some(Self::CATEGORICAL_NODATA as f64))
+            .context("Failed to set no data value")?;
+
+        let mut color_table = ColorTable::new();
+        for class in LandCoverType::ALL {
+            let code = class as u8;
+            let [r, g, b, a] = palette
+                .and_then(|overrides| overrides.get(&code))
+                .copied()
+                .unwrap_or_else(|| class.default_color());
+            color_table.set_color_entry(
+                code as isize,
+                &ColorEntry { c1: r, c2: g, c3: b, c4: a },
+            );
+        }
+        band.set_color_interpretation(ColorInterpretation::PaletteIndex)
+            .context("Failed to set palette color interpretation")?;
+        band.set_color_table(&color_table);
+
+        let mut rat = gdal::raster::RasterAttributeTable::new();
+        rat.create_column("Value", gdal::raster::RatFieldType::Integer, gdal::raster::RatFieldUsage::MinMax);
+        rat.create_column("ClassName", gdal::raster::RatFieldType::String, gdal::raster::RatFieldUsage::Name);
+        for (row, class) in LandCoverType::ALL.iter().enumerate() {
+            rat.set_value_int(row, 0, *class as i32);
+            rat.set_value_string(row, 1, class.name());
+        }
+        band.set_default_rat(&rat)
+            .context("Failed to attach raster attribute table")?;
+
+        println!("LandCover categorical raster saved to: {:?}", output_path);
+        Ok(output_path)
+    }
+
+    /// Merge adjacent or overlapping polygons sharing the same `by` property (defaulting to
+    /// `"type"`, the land-cover class code) into one unified (Multi)Polygon per class, removing
+    /// internal shared boundaries. Follows the same GEOS buffer(0)+union approach as
+    /// [`crate::geometric::vegetation::Vegetation::dissolve`], applied per class group instead of
+    /// to the whole collection. When `validate` is set, the dissolved output is run through
+    /// [`crate::geometric::validate::validate`] and an error is raised describing any
+    /// non-finite/degenerate geometry it finds.
+    pub fn dissolve(&mut self, by: Option<&str>, validate: bool) -> Result<()> {
+        use geo::MultiPolygon;
+        use geos::{Geom, Geometry as GeosGeometry};
+
+        let key = by.unwrap_or("type");
+
+        let Some(geojson) = self.geojson.as_ref() else {
+            return Ok(());
+        };
+        let features = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.clone(),
+            GeoJson::Feature(f) => vec![f.clone()],
+            GeoJson::Geometry(_) => return Ok(()),
+        };
+
+        // Group features by the `by` property's JSON value, preserving first-seen order so the
+        // dissolved output stays deterministic.
+        let mut order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, (Value, Vec<Feature>)> = HashMap::new();
+        for feature in features {
+            let class_value = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get(key))
+                .cloned()
+                .unwrap_or(Value::Null);
+            let group_key = class_value.to_string();
+            groups
+                .entry(group_key.clone())
+                .or_insert_with(|| {
+                    order.push(group_key.clone());
+                    (class_value, Vec::new())
+                })
+                .1
+                .push(feature);
+        }
+
+        let mut dissolved_features = Vec::new();
+        for group_key in &order {
+            let (class_value, group_features) = &groups[group_key];
+
+            let mut accumulated: Option<GeosGeometry> = None;
+            for feature in group_features {
+                let Some(ref geometry) = feature.geometry else {
+                    continue;
+                };
+                let geo_geom: GeoGeometry<f64> = match geometry.try_into() {
+                    Ok(g) => g,
+                    Err(_) => continue,
+                };
+                if !matches!(geo_geom, GeoGeometry::Polygon(_) | GeoGeometry::MultiPolygon(_)) {
+                    continue;
+                }
+                let geos_geom: GeosGeometry = geo_geom
+                    .try_into()
+                    .context("Failed to convert land cover polygon to GEOS")?;
+                let cleaned = geos_geom
+                    .buffer(0.0, 8)
+                    .context("Failed to clean land cover polygon self-intersections")?;
+
+                accumulated = Some(match accumulated {
+                    Some(acc) => acc.union(&cleaned).with_context(|| {
+                        format!("Failed to union polygons for {key} = {class_value}")
+                    })?,
+                    None => cleaned,
+                });
+            }
+
+            let Some(accumulated) = accumulated else {
+                continue;
+            };
+            let dissolved = accumulated.buffer(0.0, 8).with_context(|| {
+                format!("Failed to clean dissolved multipolygon for {key} = {class_value}")
+            })?;
+
+            let geo_geom: GeoGeometry<f64> = dissolved.try_into().with_context(|| {
+                format!(
+                    "Failed to convert dissolved GEOS geometry back to geo::Geometry for \
+                     {key} = {class_value}"
+                )
+            })?;
+            let multi_polygon: MultiPolygon<f64> = match geo_geom {
+                GeoGeometry::Polygon(p) => MultiPolygon(vec![p]),
+                GeoGeometry::MultiPolygon(mp) => mp,
+                _ => continue,
+            };
+            if multi_polygon.unsigned_area() == 0.0 {
+                continue;
+            }
+
+            let value = geojson::Value::from(&GeoGeometry::MultiPolygon(multi_polygon));
+            let mut feature = Feature::from(Geometry::new(value));
+            let mut properties = serde_json::Map::new();
+            properties.insert(key.to_string(), class_value.clone());
+            feature.properties = Some(properties);
+            dissolved_features.push(feature);
+        }
+
+        let dissolved_geojson = GeoJson::from(geojson::FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: dissolved_features,
+        });
+
+        if validate {
+            let (_, report) = crate::geometric::validate::validate(&dissolved_geojson, false);
+            if !report.is_valid() {
+                let details: Vec<String> = report
+                    .issues
+                    .iter()
+                    .map(|issue| format!("feature {}: {}", issue.feature_index, issue.reason))
+                    .collect();
+                anyhow::bail!("Dissolved output failed validation: {}", details.join("; "));
+            }
+        }
+
+        self.geojson = Some(dissolved_geojson);
+        Ok(())
+    }
+
+    /// `u8`/`Byte`-band counterpart to [`LandCover::rasterize_geometry_gdal_fallback`], used by
+    /// [`LandCover::to_raster_categorical`]'s fallback path.
+    fn rasterize_geometry_gdal_fallback_u8(
+        geom: &Geometry,
+        value: u8,
+        raster_data: &mut [u8],
+        width: usize,
+        height: usize,
+        transform: &[f64; 6],
+    ) -> Result<()> {
+        let geo_geom: GeoGeometry<f64> = geom
+            .try_into()
+            .context("Failed to convert GeoJSON geometry to geo geometry")?;
+        let geos_geom: GeosGeometry = geo_geom
+            .try_into()
+            .context("Failed to convert geo geometry to GEOS")?;
+        let wkt = geos_geom
+            .to_wkt()
+            .context("Failed to convert GEOS geometry to WKT")?;
+        let gdal_geom =
+            GdalGeometry::from_wkt(&wkt).context("Failed to create GDAL geometry from WKT")?;
+
+        let envelope = gdal_geom.envelope();
+        let x_origin = transform[0];
+        let pixel_width = transform[1];
+        let y_origin = transform[3];
+        let pixel_height = transform[5];
+
+        let min_col = ((envelope.MinX - x_origin) / pixel_width).floor().max(0.0) as usize;
+        let max_col = ((envelope.MaxX - x_origin) / pixel_width)
+            .ceil()
+            .min(width as f64) as usize;
+        let min_row = ((y_origin - envelope.MaxY) / pixel_width.abs())
+            .floor()
+            .max(0.0) as usize;
+        let max_row = ((y_origin - envelope.MinY) / pixel_width.abs())
+            .ceil()
+            .min(height as f64) as usize;
+
+        for row in min_row..max_row {
+            for col in min_col..max_col {
+                let x = x_origin + (col as f64 + 0.5) * pixel_width;
+                let y = y_origin + (row as f64 + 0.5) * pixel_height;
+                let point_wkt = format!("POINT({} {})", x, y);
+                if let Ok(point_geom) = GdalGeometry::from_wkt(&point_wkt) {
+                    if gdal_geom.contains(&point_geom) {
+                        raster_data[row * width + col] = value;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Burn every feature in `fc` into `dataset`'s first band in a single GDAL call, using each
+    /// feature's `type` property as its burn value. This is `GDALRasterizeGeometries` (scanline
+    /// polygon fill, handling partial-coverage and boundary pixels correctly) rather than the
+    /// per-pixel point-in-polygon sampling [`LandCover::rasterize_geometry_gdal_fallback`] does,
+    /// and does the whole FeatureCollection in one vectorized pass instead of one GDAL call per
+    /// pixel per feature.
+    fn rasterize_features_gdal(&self, dataset: &gdal::Dataset, fc: &geojson::FeatureCollection) -> Result<()> {
+        use gdal::vector::ToGdal;
+
+        let mut geometries = Vec::with_capacity(fc.features.len());
+        let mut burn_values = Vec::with_capacity(fc.features.len());
+
+        for feature in &fc.features {
+            let Some(ref geom) = feature.geometry else {
+                continue;
+            };
+            let geo_geom: GeoGeometry<f64> = geom
+                .try_into()
+                .context("Failed to convert GeoJSON geometry to geo geometry")?;
+            let gdal_geom = geo_geom
+                .to_gdal()
+                .context("Failed to convert geo geometry to GDAL geometry")?;
+            let type_code = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("type"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as f64;
+
+            geometries.push(gdal_geom);
+            burn_values.push(type_code);
+        }
+
+        if geometries.is_empty() {
+            return Ok(());
+        }
+
+        gdal::raster::rasterize(
+            dataset,
+            &[1],
+            &geometries,
+            &burn_values,
+            Some(gdal::raster::RasterizeOptions {
+                all_touched: false,
+                ..Default::default()
+            }),
+        )
+        .context("GDAL rasterize failed")
+    }
+
+    /// Per-pixel multi-class area accounting backing [`LandCover::to_raster`]'s `Majority`/
+    /// `Threshold` burn strategies. Builds an R-tree over every class polygon's envelope (same
+    /// pattern as [`LandCover::unify_cosia_dxf`]'s DXF lookup), then for each pixel intersects
+    /// its footprint against every polygon whose envelope overlaps, accumulates covered area per
+    /// `type` code via [`geo::BooleanOps::intersection`], and picks the winner per `strategy`.
+    /// Pixels with no qualifying class are left at `f32::NAN`.
+    fn rasterize_by_area_coverage(
+        &self,
+        fc: &geojson::FeatureCollection,
+        width: usize,
+        height: usize,
+        transform: &[f64; 6],
+        strategy: BurnStrategy,
+        area_threshold: Option<f64>,
+    ) -> Result<Vec<f32>> {
+        struct ClassPolygon {
+            polygon: Polygon<f64>,
+            type_code: i64,
+        }
+        struct ClassEnvelope {
+            min_x: f64,
+            min_y: f64,
+            max_x: f64,
+            max_y: f64,
+            index: usize,
+        }
+        impl RTreeObject for ClassEnvelope {
+            type Envelope = AABB<[f64; 2]>;
+            fn envelope(&self) -> Self::Envelope {
+                AABB::from_corners([self.min_x, self.min_y], [self.max_x, self.max_y])
+            }
+        }
+
+        let mut class_polygons: Vec<ClassPolygon> = Vec::new();
+        for feature in &fc.features {
+            let Some(ref geom) = feature.geometry else { continue };
+            let type_code = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("type"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0);
+            for polygon in self.geojson_geometry_to_geo_polygons(geom)? {
+                class_polygons.push(ClassPolygon { polygon, type_code });
+            }
+        }
+
+        let envelopes: Vec<ClassEnvelope> = class_polygons
+            .iter()
+            .enumerate()
+            .filter_map(|(index, part)| {
+                part.polygon.bounding_rect().map(|rect| ClassEnvelope {
+                    min_x: rect.min().x,
+                    min_y: rect.min().y,
+                    max_x: rect.max().x,
+                    max_y: rect.max().y,
+                    index,
+                })
+            })
+            .collect();
+        let tree = RTree::bulk_load(envelopes);
+
+        let x_origin = transform[0];
+        let pixel_width = transform[1];
+        let y_origin = transform[3];
+        let pixel_height = transform[5];
+        let threshold_fraction = area_threshold.unwrap_or(50.0) / 100.0;
+
+        let mut raster_data = vec![f32::NAN; width * height];
+        for row in 0..height {
+            let cell_y_max = y_origin + row as f64 * pixel_height;
+            let cell_y_min = cell_y_max + pixel_height;
+            for col in 0..width {
+                let cell_x_min = x_origin + col as f64 * pixel_width;
+                let cell_x_max = cell_x_min + pixel_width;
+                let cell_area = (cell_x_max - cell_x_min) * (cell_y_max - cell_y_min).abs();
+                if cell_area <= 0.0 {
+                    continue;
+                }
+
+                let cell = Polygon::new(
+                    geo::LineString::from(vec![
+                        (cell_x_min, cell_y_min),
+                        (cell_x_max, cell_y_min),
+                        (cell_x_max, cell_y_max),
+                        (cell_x_min, cell_y_max),
+                        (cell_x_min, cell_y_min),
+                    ]),
+                    vec![],
+                );
+
+                let query = AABB::from_corners([cell_x_min, cell_y_min], [cell_x_max, cell_y_max]);
+                let mut class_areas: HashMap<i64, f64> = HashMap::new();
+                for candidate in tree.locate_in_envelope_intersecting(&query) {
+                    let part = &class_polygons[candidate.index];
+                    let overlap = cell.intersection(&part.polygon).unsigned_area();
+                    if overlap > 0.0 {
+                        *class_areas.entry(part.type_code).or_insert(0.0) += overlap;
+                    }
+                }
+
+                let Some((type_code, covered_area)) =
+                    class_areas.into_iter().max_by(|a, b| a.1.total_cmp(&b.1))
+                else {
+                    continue;
+                };
+
+                let qualifies = match strategy {
+                    BurnStrategy::Majority | BurnStrategy::First => true,
+                    BurnStrategy::Threshold => covered_area / cell_area >= threshold_fraction,
+                };
+                if qualifies {
+                    raster_data[row * width + col] = type_code as f32;
+                }
+            }
+        }
+
+        Ok(raster_data)
+    }
+
+    /// Rasterize a single geometry via per-pixel point-in-polygon sampling. Kept as a fallback
+    /// for [`LandCover::rasterize_features_gdal`] when the vectorized rasterize API errors (e.g.
+    /// an older GDAL without `GDALRasterizeGeometries` support); uses GDAL's envelope to limit
+    /// the pixel range it has to check.
+    fn rasterize_geometry_gdal_fallback(
         &self,
         geom: &Geometry,
         value: f32,
@@ -618,3 +1397,242 @@ impl LandCover {
         &self.output_path
     }
 }
+
+/// Decode a TopoJSON `Topology` document into a `geojson::GeoJson::FeatureCollection`.
+///
+/// `object_name` selects one entry of `objects`; when `None`, the first object is used. When the
+/// document has a `transform`, arc coordinates are delta-decoded and dequantized against
+/// `transform.scale`/`transform.translate`; without one they are read as absolute positions. Rings are stitched by concatenating each arc's points, dropping the
+/// first point of every arc after the first since consecutive arcs share an endpoint. A
+/// negative arc index `i` (the "bitwise complement" per the TopoJSON spec) refers to arc `!i`
+/// traversed in reverse.
+fn topojson_to_geojson(topojson: &Value, object_name: Option<&str>) -> Result<GeoJson> {
+    let transform = topojson
+        .get("transform")
+        .map(|t| -> Result<((f64, f64), (f64, f64))> {
+            let scale = t.get("scale").context("TopoJSON transform missing scale")?;
+            let translate = t
+                .get("translate")
+                .context("TopoJSON transform missing translate")?;
+            Ok((
+                (as_f64(&scale[0])?, as_f64(&scale[1])?),
+                (as_f64(&translate[0])?, as_f64(&translate[1])?),
+            ))
+        })
+        .transpose()?;
+
+    let arcs_json = topojson
+        .get("arcs")
+        .and_then(Value::as_array)
+        .context("TopoJSON document missing arcs array")?;
+    let arcs = arcs_json
+        .iter()
+        .map(|arc| decode_arc(arc, transform))
+        .collect::<Result<Vec<_>>>()?;
+
+    let objects = topojson
+        .get("objects")
+        .and_then(Value::as_object)
+        .context("TopoJSON document missing objects")?;
+    let object = match object_name {
+        Some(name) => objects
+            .get(name)
+            .with_context(|| format!("TopoJSON object '{name}' not found"))?,
+        None => objects
+            .values()
+            .next()
+            .context("TopoJSON document has no objects")?,
+    };
+
+    let mut features = Vec::new();
+    collect_topology_geometries(object, &arcs, transform, &mut features)?;
+
+    Ok(GeoJson::FeatureCollection(geojson::FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }))
+}
+
+fn as_f64(value: &Value) -> Result<f64> {
+    value.as_f64().context("Expected a TopoJSON number")
+}
+
+/// Decode a single TopoJSON arc (a list of `[dx, dy]` pairs) into absolute `[x, y]` positions.
+fn decode_arc(arc: &Value, transform: Option<((f64, f64), (f64, f64))>) -> Result<Vec<[f64; 2]>> {
+    let points = arc.as_array().context("Invalid TopoJSON arc")?;
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut decoded = Vec::with_capacity(points.len());
+    for point in points {
+        let coords = point.as_array().context("Invalid TopoJSON arc point")?;
+        let dx = as_f64(coords.first().context("Arc point missing x")?)?;
+        let dy = as_f64(coords.get(1).context("Arc point missing y")?)?;
+        match transform {
+            Some((scale, translate)) => {
+                x += dx;
+                y += dy;
+                decoded.push([x * scale.0 + translate.0, y * scale.1 + translate.1]);
+            }
+            None => decoded.push([dx, dy]),
+        }
+    }
+    Ok(decoded)
+}
+
+/// Resolve a (possibly negative, "bitwise complement") arc index into absolute ring points.
+fn resolve_arc(arcs: &[Vec<[f64; 2]>], index: i64) -> Result<Vec<[f64; 2]>> {
+    if index >= 0 {
+        arcs.get(index as usize)
+            .cloned()
+            .ok_or_else(|| anyhow!("TopoJSON arc index {index} out of range"))
+    } else {
+        let resolved = (!index) as usize;
+        let mut points = arcs
+            .get(resolved)
+            .cloned()
+            .ok_or_else(|| anyhow!("TopoJSON arc index {index} out of range"))?;
+        points.reverse();
+        Ok(points)
+    }
+}
+
+/// Stitch a list of arc indices into one closed ring by concatenating arcs, dropping the first
+/// (shared) point of every arc after the first.
+fn stitch_ring(arc_indices: &[Value], arcs: &[Vec<[f64; 2]>]) -> Result<Vec<Vec<f64>>> {
+    let mut ring: Vec<[f64; 2]> = Vec::new();
+    for (i, index) in arc_indices.iter().enumerate() {
+        let index = index.as_i64().context("Arc index must be an integer")?;
+        let points = resolve_arc(arcs, index)?;
+        if i == 0 {
+            ring.extend(points);
+        } else {
+            ring.extend(points.into_iter().skip(1));
+        }
+    }
+    Ok(ring.into_iter().map(|p| p.to_vec()).collect())
+}
+
+fn decode_point(coordinates: &Value, transform: Option<((f64, f64), (f64, f64))>) -> Result<Vec<f64>> {
+    let coords = coordinates.as_array().context("Invalid TopoJSON point")?;
+    let x = as_f64(coords.first().context("Point missing x")?)?;
+    let y = as_f64(coords.get(1).context("Point missing y")?)?;
+    match transform {
+        Some((scale, translate)) => Ok(vec![x * scale.0 + translate.0, y * scale.1 + translate.1]),
+        None => Ok(vec![x, y]),
+    }
+}
+
+/// Convert one TopoJSON geometry object into geojson `Value`(s), pushing one `Feature` per
+/// geometry (recursing into `GeometryCollection` members) into `features`.
+fn collect_topology_geometries(
+    geometry: &Value,
+    arcs: &[Vec<[f64; 2]>],
+    transform: Option<((f64, f64), (f64, f64))>,
+    features: &mut Vec<Feature>,
+) -> Result<()> {
+    let geom_type = geometry
+        .get("type")
+        .and_then(Value::as_str)
+        .context("TopoJSON geometry missing type")?;
+
+    if geom_type == "GeometryCollection" {
+        let geometries = geometry
+            .get("geometries")
+            .and_then(Value::as_array)
+            .context("GeometryCollection missing geometries")?;
+        for child in geometries {
+            collect_topology_geometries(child, arcs, transform, features)?;
+        }
+        return Ok(());
+    }
+
+    let value = match geom_type {
+        "Point" => {
+            let coordinates = geometry.get("coordinates").context("Point missing coordinates")?;
+            geojson::Value::Point(decode_point(coordinates, transform)?)
+        }
+        "MultiPoint" => {
+            let coordinates = geometry
+                .get("coordinates")
+                .and_then(Value::as_array)
+                .context("MultiPoint missing coordinates")?;
+            let points = coordinates
+                .iter()
+                .map(|c| decode_point(c, transform))
+                .collect::<Result<Vec<_>>>()?;
+            geojson::Value::MultiPoint(points)
+        }
+        "LineString" => {
+            let arc_indices = geometry
+                .get("arcs")
+                .and_then(Value::as_array)
+                .context("LineString missing arcs")?;
+            geojson::Value::LineString(stitch_ring(arc_indices, arcs)?)
+        }
+        "MultiLineString" => {
+            let lines = geometry
+                .get("arcs")
+                .and_then(Value::as_array)
+                .context("MultiLineString missing arcs")?;
+            let lines = lines
+                .iter()
+                .map(|line| {
+                    let indices = line.as_array().context("Invalid MultiLineString arcs entry")?;
+                    stitch_ring(indices, arcs)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            geojson::Value::MultiLineString(lines)
+        }
+        "Polygon" => {
+            let rings = geometry
+                .get("arcs")
+                .and_then(Value::as_array)
+                .context("Polygon missing arcs")?;
+            let rings = rings
+                .iter()
+                .map(|ring| {
+                    let indices = ring.as_array().context("Invalid Polygon arcs entry")?;
+                    stitch_ring(indices, arcs)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            geojson::Value::Polygon(rings)
+        }
+        "MultiPolygon" => {
+            let polygons = geometry
+                .get("arcs")
+                .and_then(Value::as_array)
+                .context("MultiPolygon missing arcs")?;
+            let polygons = polygons
+                .iter()
+                .map(|polygon| {
+                    let rings = polygon.as_array().context("Invalid MultiPolygon arcs entry")?;
+                    rings
+                        .iter()
+                        .map(|ring| {
+                            let indices = ring.as_array().context("Invalid Polygon ring entry")?;
+                            stitch_ring(indices, arcs)
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            geojson::Value::MultiPolygon(polygons)
+        }
+        other => return Err(anyhow!("Unsupported TopoJSON geometry type: {other}")),
+    };
+
+    let mut properties = serde_json::Map::new();
+    if let Some(props) = geometry.get("properties").and_then(Value::as_object) {
+        properties = props.clone();
+    }
+
+    features.push(Feature {
+        bbox: None,
+        geometry: Some(Geometry::new(value)),
+        id: None,
+        properties: Some(properties),
+        foreign_members: None,
+    });
+
+    Ok(())
+}
@@ -1,23 +1,189 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use geojson::{Feature, GeoJson, Geometry};
 use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rusqlite::{params, Connection};
 use tempfile::TempDir;
-use zip::ZipArchive;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, OutputFormat};
+use crate::geometric::gdal_error::with_gdal_error_context;
+use crate::geometric::land_cover::LandCoverType;
 use gdal::spatial_ref::{CoordTransform, SpatialRef};
+use gdal::vector::FieldValue;
 use gdal::vector::Geometry as OgrGeometry;
 use gdal::vector::LayerAccess;
 use gdal::Dataset;
 use geo::algorithm::bounding_rect::BoundingRect;
 use geo::algorithm::intersects::Intersects;
-use geo::{Geometry as GeoGeometry, Polygon};
+use geo::{Area, BooleanOps, Geometry as GeoGeometry, MultiPolygon, Polygon};
 use geos::{CoordDimensions, CoordSeq, GResult, Geom, Geometry as GeosGeometry};
+use geozero::error::Result as GeozeroResult;
+use geozero::geo_types::GeozeroGeometry;
+use geozero::{ColumnValue, FeatureProcessor, GeozeroDatasource};
 use rstar::{RTree, RTreeObject, AABB};
 
+/// Nominal street half-width (metres) used both to buffer road/water/rail linework when cutting
+/// reference spatial units (RSU) out of the bbox, and — doubled — as the canyon-width denominator
+/// for each RSU's aspect ratio. This is a coarse GeoClimate-style approximation: real RSU
+/// delineation uses each road segment's actual carriageway width, which this crate does not carry.
+const RSU_CUT_HALF_WIDTH_M: f64 = 4.0;
+
+/// Default building height (metres) assumed for a building feature with no usable `"hauteur"`
+/// property.
+const DEFAULT_BUILDING_HEIGHT_M: f64 = 6.0;
+
+/// Decay constant for the sky-view-factor approximation, mirroring
+/// `rsmdu_core::geometric::building::BuildingCollection::compute_morphology`'s `SVF_DECAY_K`.
+const SVF_DECAY_K: f64 = 2.0;
+
+/// Mapbox Vector Tile coordinate extent used by [`Lcz::to_mbtiles`]: each tile's local coordinate
+/// space runs from `0` to `MVT_EXTENT` on both axes, per the `vector_tile.proto` convention.
+const MVT_EXTENT: u32 = 4096;
+
+/// Fill opacity (0.0-1.0) applied to every LCZ class's `<PolyStyle>` in [`Lcz::to_kmz`].
+const KMZ_FILL_OPACITY: f64 = 0.6;
+
+/// Fixed-point scale factor for [`Lcz::to_raster`]'s grid addressing -- the same integer-
+/// coordinate trick terminal OSM tools use (scaling lon/lat to fixed-precision integers) so that
+/// converting a cell index to a world coordinate and back always lands on the same index, instead
+/// of drifting across a cell boundary the way repeated floating-point division/multiplication can.
+const GRID_SCALE: i64 = 1_000_000;
+
+/// Output format for [`Lcz::export`], written via geozero so every format shares the same
+/// feature walk ([`LczGeojsonSource`]) as the in-process shapefile-to-GeoJSON conversion
+/// [`Lcz::shp_to_geojson`] uses ([`write_layer_features`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    GeoJson,
+    FlatGeobuf,
+    Csv,
+}
+
+/// Per-RSU morphology indicators computed by [`Lcz::classify_from_morphology`].
+struct RsuMorphology {
+    building_fraction: f64,
+    impervious_fraction: f64,
+    pervious_fraction: f64,
+    water_fraction: f64,
+    mean_building_height: f64,
+    aspect_ratio: f64,
+    sky_view_factor: f64,
+}
+
+/// Apply GeoClimate-style threshold rules to assign one of the 17 `Lcz::table_color` LCZ codes
+/// from a RSU's morphology. Building-fraction and height bands loosely follow Stewart & Oke
+/// (2012). Because `LandCoverType` carries no tree-canopy variant, the natural tree/scrub classes
+/// (11/A, 12/B, 13/C) can never be produced here — densely vegetated RSUs fall through to 14/D
+/// (low plants) instead.
+fn classify_rsu(morpho: &RsuMorphology) -> u8 {
+    const COMPACT: f64 = 0.4;
+    const OPEN_LOW: f64 = 0.2;
+    const SPARSE: f64 = 0.1;
+    const HIGH_RISE_M: f64 = 25.0;
+    const MID_RISE_M: f64 = 10.0;
+
+    if morpho.water_fraction >= 0.5 {
+        return 17; // LCZ G: Water
+    }
+
+    if morpho.building_fraction < SPARSE {
+        if morpho.pervious_fraction >= morpho.impervious_fraction {
+            return if morpho.pervious_fraction > 0.0 { 14 } else { 16 }; // D: Low plants / F: Bare soil or sand
+        }
+        return 15; // E: Bare rock or paved
+    }
+
+    if morpho.building_fraction <= OPEN_LOW && morpho.mean_building_height < MID_RISE_M {
+        return 9; // LCZ 9: Sparsely built
+    }
+
+    let compact = morpho.building_fraction > COMPACT;
+    if morpho.mean_building_height >= HIGH_RISE_M {
+        return if compact { 1 } else { 4 }; // Compact/Open high-rise
+    }
+    if morpho.mean_building_height >= MID_RISE_M {
+        return if compact { 2 } else { 5 }; // Compact/Open mid-rise
+    }
+    if compact {
+        return 3; // LCZ 3: Compact low-rise
+    }
+    if morpho.building_fraction > OPEN_LOW {
+        // Both large low-rise/heavy industry and open low-rise are open + low-rise; tell them
+        // apart by imperviousness, since industrial/commercial sites are near-fully hard surface.
+        return if morpho.impervious_fraction > 0.7 { 8 } else { 6 }; // Large low-rise / Open low-rise
+    }
+    7 // LCZ 7: Lightweight low-rise
+}
+
+/// An exact clip boundary for [`Lcz::run`], set via [`Lcz::set_clip_geojson`]. Mirrors
+/// [`GeoCore::set_clip_geometry`]'s "envelope drives the cheap fetch, the polygon drives the
+/// exact filter" pattern, but keeps its own GEOS geometry around (rather than `GeoCore`'s
+/// `LimitTo`/R-tree-of-rings machinery) so `run`'s already-GEOS-based candidate loop can truncate
+/// each feature with a single `intersection` call instead of a second clipping pass.
+struct GeometryLimiter {
+    /// Clip polygon in the working CRS, already buffered if requested.
+    clip_geos: GeosGeometry,
+    /// Bounding rect of `clip_geos`, in the working CRS; drives `set_spatial_filter_rect`.
+    envelope: Polygon<f64>,
+}
+
+impl GeometryLimiter {
+    /// Parse `geojson` (EPSG:4326), reproject it to `target_epsg`, and optionally grow it by
+    /// `buffer_m` meters via GEOS before computing its envelope.
+    fn new(geojson: &str, target_epsg: i32, buffer_m: Option<f64>) -> Result<Self> {
+        let mut parsed: GeoJson = geojson
+            .parse()
+            .context("Failed to parse clip geometry as GeoJSON")?;
+        GeoCore::reproject_geojson(&mut parsed, 4326, target_epsg)
+            .context("Failed to reproject clip geometry to the working CRS")?;
+
+        let multi_polygon = GeoCore::geojson_to_multi_polygon(&parsed)
+            .context("Clip geometry must contain a Polygon or MultiPolygon geometry")?;
+
+        let mut clip_geos: GeosGeometry = (&GeoGeometry::MultiPolygon(multi_polygon))
+            .try_into()
+            .context("Failed to convert clip geometry to GEOS")?;
+
+        if let Some(buffer_m) = buffer_m {
+            if buffer_m != 0.0 {
+                clip_geos = clip_geos
+                    .buffer(buffer_m, 8)
+                    .context("Failed to buffer clip geometry")?;
+            }
+        }
+
+        let clip_geo: GeoGeometry<f64> = (&clip_geos)
+            .try_into()
+            .context("Failed to convert buffered clip geometry back to geo")?;
+        let envelope = clip_geo
+            .bounding_rect()
+            .context("Clip geometry has no envelope (empty polygon)")?
+            .to_polygon();
+
+        Ok(GeometryLimiter { clip_geos, envelope })
+    }
+
+    /// Intersect `geom` (in the working CRS) with the clip polygon, returning `None` when the
+    /// result is empty -- i.e. `geom` falls entirely outside the clip boundary -- or when `geom`
+    /// can't be converted to a GEOS geometry.
+    fn clip(&self, geom: &GeoGeometry<f64>) -> Option<GeoGeometry<f64>> {
+        let candidate_geos: GeosGeometry = geom.try_into().ok()?;
+        let clipped_geos = self.clip_geos.intersection(&candidate_geos).ok()?;
+        if clipped_geos.is_empty().unwrap_or(true) {
+            return None;
+        }
+        (&clipped_geos).try_into().ok()
+    }
+}
+
 /// Structure pour indexer les géométries avec rstar
 struct IndexedGeometry {
     geom: GeoGeometry<f64>,
@@ -56,6 +222,14 @@ pub struct Lcz {
     pub table_color: HashMap<u8, (String, String)>,
     /// Parsed GeoJSON content (after processing)
     geojson: Option<GeoJson>,
+    /// Exact clip boundary set via [`Lcz::set_clip_geojson`], used by `run` in place of the
+    /// bbox rectangle when present.
+    clip_limiter: Option<GeometryLimiter>,
+    /// GDAL attribute filter set via [`Lcz::set_attribute_filter`], applied to `layer(0)` before
+    /// `run`'s spatial pass. Ignored when `sql_query` is set.
+    attribute_filter: Option<String>,
+    /// Custom GDAL SQL query set via [`Lcz::set_sql`], run in place of `layer(0)` in `run`.
+    sql_query: Option<String>,
 }
 
 impl Lcz {
@@ -165,6 +339,9 @@ impl Lcz {
             filepath_shp,
             table_color,
             geojson: None,
+            clip_limiter: None,
+            attribute_filter: None,
+            sql_query: None,
         })
     }
 
@@ -176,6 +353,54 @@ impl Lcz {
             .set_bbox(Some(BoundingBox::new(min_x, min_y, max_x, max_y)));
     }
 
+    /// Set an exact clip boundary (inspired by imposm3's `limitto`), in place of the rectangular
+    /// bbox `run` otherwise filters and intersects against. `geojson` is a Polygon/MultiPolygon
+    /// (or a Feature/FeatureCollection wrapping one) expressed in EPSG:4326; it is reprojected to
+    /// this `Lcz`'s working CRS and, when `buffer_m` is `Some`, grown by that many meters via a
+    /// GEOS buffer (the working CRS is assumed metric, as every `Lcz` entry point already is).
+    ///
+    /// Once set, `run` uses the clip polygon's envelope to drive `set_spatial_filter_rect` (the
+    /// cheap prefilter) and its exact shape to truncate each surviving feature via GEOS
+    /// `intersection`, dropping any feature whose clipped result is empty. Call
+    /// [`Lcz::clear_clip_geojson`] to fall back to the plain bbox rectangle again.
+    pub fn set_clip_geojson(&mut self, geojson: &str, buffer_m: Option<f64>) -> Result<()> {
+        self.clip_limiter = Some(GeometryLimiter::new(geojson, self.geo_core.epsg, buffer_m)?);
+        Ok(())
+    }
+
+    /// Clear a clip boundary set via [`Lcz::set_clip_geojson`], reverting `run` to its plain
+    /// rectangular bbox filter.
+    pub fn clear_clip_geojson(&mut self) {
+        self.clip_limiter = None;
+    }
+
+    /// Restrict `run`'s collection pass to features matching a GDAL attribute filter (the same
+    /// syntax as a SQL WHERE clause), e.g. `"lcz_int IN (11, 12, 13, 14, 15, 16, 17)"` to keep
+    /// only vegetation/natural classes, or `"lcz_int <> 17"` to exclude water. Applied via
+    /// `Layer::set_attribute_filter` so class selection happens in the driver instead of after
+    /// every feature is materialized. Ignored when [`Lcz::set_sql`] is also set, since the SQL
+    /// query already selects the result layer.
+    pub fn set_attribute_filter(&mut self, where_clause: &str) {
+        self.attribute_filter = Some(where_clause.to_string());
+    }
+
+    /// Clear a filter set via [`Lcz::set_attribute_filter`].
+    pub fn clear_attribute_filter(&mut self) {
+        self.attribute_filter = None;
+    }
+
+    /// Run `run`'s collection pass over a custom GDAL SQL query's result layer instead of
+    /// `layer(0)`, e.g. `"SELECT * FROM lcz WHERE lcz_int NOT IN (17)"`. Takes precedence over
+    /// [`Lcz::set_attribute_filter`] when both are set.
+    pub fn set_sql(&mut self, sql: &str) {
+        self.sql_query = Some(sql.to_string());
+    }
+
+    /// Clear a query set via [`Lcz::set_sql`].
+    pub fn clear_sql(&mut self) {
+        self.sql_query = None;
+    }
+
     /// Run LCZ processing: load from zip URL, filter by bbox, reproject
     /// Following Python: def run(self, zipfile_url: str = "...")
     /// Downloads ZIP file, extracts shapefile, reads with GDAL, filters by bbox, and reprojects
@@ -195,33 +420,65 @@ impl Lcz {
         println!("Téléchargement du fichier ZIP depuis: {}", url);
 
         // 1. Télécharger et extraire le ZIP
-        //let temp_dir = self.download_and_extract_zip(url)?;
+        let temp_dir = self.download_and_extract_zip(url)?;
         // 2. Trouver le fichier .shp dans le dossier temporaire
-        //let shp_path = self.find_shapefile(&temp_dir)?;
-        let shp_path = PathBuf::from("/Users/Boris/Downloads/pymdurs/pymdurs/examples/output/lcz-spot-2022-la-rochelle/LCZ_SPOT_2022_La Rochelle.shp");
+        let shp_path = self.find_shapefile(&temp_dir)?;
 
         println!("Shapefile trouvé: {:?}", shp_path);
 
-        // 3. Convertir le shapefile en GeoJSON
-        // let geojson_path = temp_dir.path().join("lcz.geojson");
-        let geojson_path =
-            PathBuf::from("/Users/Boris/Downloads/pymdurs/pymdurs/examples/output/lcz_2.geojson");
+        // 3. Convertir le shapefile en GeoJSON (diagnostic intermediate, not read back below --
+        // the dataset itself is reopened from `shp_path` directly via GDAL)
+        let geojson_path = temp_dir.path().join("lcz.geojson");
         self.shp_to_geojson(
             shp_path.to_str().context("Invalid shapefile path")?,
             geojson_path.to_str().context("Invalid GeoJSON path")?,
         )?;
 
         // 3. Lire le shapefile avec GDAL
-        let dataset = Dataset::open(&shp_path).context("Impossible d'ouvrir le shapefile")?;
+        let dataset = with_gdal_error_context("Impossible d'ouvrir le shapefile", || {
+            Dataset::open(&shp_path).map_err(Into::into)
+        })?;
 
         let mut layer = dataset
             .layer(0)
             .context("Impossible d'accéder à la première couche")?;
 
+        if self.sql_query.is_none() {
+            if let Some(where_clause) = &self.attribute_filter {
+                layer
+                    .set_attribute_filter(where_clause)
+                    .context("Failed to apply attribute filter to LCZ layer")?;
+            }
+        }
+
+        // When a custom SQL query is set, it selects the result layer `run` iterates instead of
+        // `layer(0)` -- pushing class selection down to the driver rather than materializing
+        // every feature and filtering in Rust.
+        let mut sql_result_set = match &self.sql_query {
+            Some(sql) => Some(
+                dataset
+                    .execute_sql(sql.as_str(), None, gdal::vector::sql::Dialect::Default)
+                    .context("Failed to execute SQL query on LCZ dataset")?
+                    .context("SQL query returned no result layer")?,
+            ),
+            None => None,
+        };
+        let layer: &mut gdal::vector::Layer = match &mut sql_result_set {
+            Some(result_set) => result_set,
+            None => &mut layer,
+        };
+
         // 4. Créer la transformation de coordonnées
-        let source_srs = layer
-            .spatial_ref()
-            .context("Impossible d'obtenir le SRS source")?;
+        // Un fichier .prj manquant dans l'archive (ça arrive) laisse `spatial_ref()` vide plutôt
+        // que de faire échouer GDAL -- on suppose alors EPSG:4326, comme le ferait un import
+        // ogr2ogr/fiona sans CRS explicite.
+        let source_srs = match layer.spatial_ref() {
+            Some(srs) => srs,
+            None => {
+                println!("Aucun fichier .prj trouvé, on suppose EPSG:4326 pour le shapefile source");
+                SpatialRef::from_epsg(4326).context("Impossible de créer le SRS source par défaut")?
+            }
+        };
         let target_srs = SpatialRef::from_epsg(self.geo_core.epsg as u32)
             .context("Impossible de créer le SRS cible")?;
         println!("Source SRS: {:?}", source_srs);
@@ -259,10 +516,17 @@ impl Lcz {
             anyhow::bail!("Expected polygon geometry")
         };
 
-        // Obtenir le rectangle englobant pour le filtre spatial
-        let bbox_rect_filter = bbox_polygon_geo
-            .bounding_rect()
-            .context("Failed to get bounding rect for spatial filter")?;
+        // Obtenir le rectangle englobant pour le filtre spatial -- le polygone de découpe exact
+        // (s'il y en a un) remplace le rectangle du bbox comme fenêtre de requête.
+        let bbox_rect_filter = match &self.clip_limiter {
+            Some(limiter) => limiter
+                .envelope
+                .bounding_rect()
+                .context("Failed to get bounding rect for clip geometry spatial filter")?,
+            None => bbox_polygon_geo
+                .bounding_rect()
+                .context("Failed to get bounding rect for spatial filter")?,
+        };
 
         let extent = layer.get_extent()?;
         println!("{:?}", extent);
@@ -334,11 +598,18 @@ impl Lcz {
         let tree = RTree::bulk_load(indexed_geometries);
         println!("  Index construit avec {} géométries", tree.size());
 
-        // 8. Étape 3: Requête spatiale rapide avec le bbox
+        // 8. Étape 3: Requête spatiale rapide avec le bbox (ou l'enveloppe du polygone de
+        // découpe, si `set_clip_geojson` en a fourni un)
         println!("Étape 3: Requête spatiale avec le bbox...");
-        let bbox_rect = bbox_polygon_geo
-            .bounding_rect()
-            .context("Failed to get bounding rect from polygon")?;
+        let bbox_rect = match &self.clip_limiter {
+            Some(limiter) => limiter
+                .envelope
+                .bounding_rect()
+                .context("Failed to get bounding rect from clip geometry")?,
+            None => bbox_polygon_geo
+                .bounding_rect()
+                .context("Failed to get bounding rect from polygon")?,
+        };
         let envelope = AABB::from_corners(
             [bbox_rect.min().x, bbox_rect.min().y],
             [bbox_rect.max().x, bbox_rect.max().y],
@@ -349,22 +620,63 @@ impl Lcz {
         let num_candidates = candidates.len();
         println!("  {} candidats trouvés dans l'enveloppe", num_candidates);
 
-        // 9. Étape 4: Test d'intersection exacte sur les candidats
+        // 9. Étape 4: Test d'intersection exacte sur les candidats. Avec un polygone de découpe,
+        // chaque candidat est tronqué à sa forme exacte via GEOS `intersection` (les candidats
+        // dont le résultat est vide sont écartés) ; sinon, on retombe sur le test booléen
+        // `intersects` contre une PreparedGeometry du rectangle bbox, qui ne construit l'index
+        // d'arêtes du bbox qu'une seule fois au lieu de le reparcourir à chaque candidat.
         println!("Étape 4: Test d'intersection exacte...");
+        let prepared_bbox = match &self.clip_limiter {
+            Some(_) => None,
+            None => {
+                let bbox_geos: GeosGeometry = (&GeoGeometry::Polygon(bbox_polygon_geo.clone()))
+                    .try_into()
+                    .context("Failed to convert bbox polygon to GEOS geometry")?;
+                Some(
+                    bbox_geos
+                        .to_prepared_geom()
+                        .context("Failed to prepare bbox geometry")?,
+                )
+            }
+        };
+
         let mut features = Vec::new();
         let mut exact_intersections = 0;
 
         for indexed_geom in &candidates {
-            // Test d'intersection exacte avec le polygone bbox
-            if bbox_polygon_geo.intersects(&indexed_geom.geom) {
-                exact_intersections += 1;
-                // Convertir geo::Geometry en geojson::Geometry
-                if let Ok(geojson_geom) = self.geo_to_geojson_geometry(&indexed_geom.geom) {
-                    let mut feature_json = Feature::from(geojson_geom);
-                    feature_json.set_property("lcz_int", indexed_geom.lcz_int as i64);
-                    feature_json.set_property("color", indexed_geom.color.clone());
-                    features.push(feature_json);
+            let kept_geom = if let Some(limiter) = &self.clip_limiter {
+                // Tronque la géométrie à la forme exacte du polygone de découpe ; `None` quand
+                // le résultat est vide (le candidat tombe entièrement hors de la découpe).
+                match limiter.clip(&indexed_geom.geom) {
+                    Some(clipped) => clipped,
+                    None => continue,
+                }
+            } else {
+                // Géométries candidates invalides pour GEOS: on les ignore plutôt que d'échouer.
+                let Ok(candidate_geos): std::result::Result<GeosGeometry, _> =
+                    (&indexed_geom.geom).try_into()
+                else {
+                    continue;
+                };
+
+                if !prepared_bbox
+                    .as_ref()
+                    .expect("prepared_bbox is set when no clip_limiter is configured")
+                    .intersects(&candidate_geos)
+                    .unwrap_or(false)
+                {
+                    continue;
                 }
+                indexed_geom.geom.clone()
+            };
+
+            exact_intersections += 1;
+            // Convertir geo::Geometry en geojson::Geometry
+            if let Ok(geojson_geom) = self.geo_to_geojson_geometry(&kept_geom) {
+                let mut feature_json = Feature::from(geojson_geom);
+                feature_json.set_property("lcz_int", indexed_geom.lcz_int as i64);
+                feature_json.set_property("color", indexed_geom.color.clone());
+                features.push(feature_json);
             }
         }
 
@@ -385,6 +697,274 @@ impl Lcz {
         Ok(())
     }
 
+    /// Classify Local Climate Zones locally from a building layer and a land-cover layer,
+    /// GeoClimate-style, instead of downloading precomputed LCZ tiles as `run` does.
+    ///
+    /// Partitions the bbox into reference spatial units (RSU) by cutting along road/water/rail
+    /// linework, then for each RSU computes building/impervious/pervious/water surface fractions,
+    /// the building-area-weighted mean height, a canyon aspect ratio (H/W) and an approximate
+    /// sky-view-factor, and applies [`classify_rsu`]'s threshold rules to pick one of the 17
+    /// `table_color` LCZ codes.
+    ///
+    /// `building_geojson` features are expected to carry a `"hauteur"` height property (falling
+    /// back to [`DEFAULT_BUILDING_HEIGHT_M`] when absent); `land_cover_geojson` features are
+    /// expected to carry the `"type"` property written by `LandCoverType`/`LandCover::add_*_gdf`;
+    /// `linework_geojson` should contain the road, water and rail center/banklines to cut along
+    /// (an empty or non-`FeatureCollection` linework falls back to a single RSU covering the bbox).
+    pub fn classify_from_morphology(
+        &mut self,
+        building_geojson: &GeoJson,
+        land_cover_geojson: &GeoJson,
+        linework_geojson: &GeoJson,
+    ) -> Result<()> {
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before classifying LCZ from morphology")?;
+
+        let bbox_geos = self.create_bbox(bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y)?;
+        let bbox_geo: GeoGeometry<f64> = bbox_geos
+            .try_into()
+            .context("Failed to convert bbox to geo geometry")?;
+        let GeoGeometry::Polygon(bbox_polygon) = bbox_geo else {
+            return Err(anyhow!("Expected polygon bbox geometry"));
+        };
+
+        let rsu_polygons = self.partition_into_rsu(&bbox_polygon, linework_geojson)?;
+        let buildings = self.extract_building_polygons(building_geojson)?;
+        let land_cover = self.extract_land_cover_polygons(land_cover_geojson)?;
+
+        let mut features = Vec::new();
+        for rsu in &rsu_polygons {
+            let rsu_area = rsu.unsigned_area();
+            if rsu_area <= 0.0 {
+                continue;
+            }
+
+            let morpho = self.compute_rsu_morphology(rsu, rsu_area, &buildings, &land_cover);
+            let lcz_int = classify_rsu(&morpho);
+            let color = self
+                .table_color
+                .get(&lcz_int)
+                .map(|(_, c)| c.clone())
+                .unwrap_or_else(|| "#000000".to_string());
+
+            let geojson_geom = self.geo_to_geojson_geometry(&GeoGeometry::Polygon(rsu.clone()))?;
+            let mut feature = Feature::from(geojson_geom);
+            feature.set_property("lcz_int", lcz_int as i64);
+            feature.set_property("color", color);
+            feature.set_property("building_fraction", morpho.building_fraction);
+            feature.set_property("impervious_fraction", morpho.impervious_fraction);
+            feature.set_property("pervious_fraction", morpho.pervious_fraction);
+            feature.set_property("water_fraction", morpho.water_fraction);
+            feature.set_property("mean_building_height", morpho.mean_building_height);
+            feature.set_property("aspect_ratio", morpho.aspect_ratio);
+            feature.set_property("sky_view_factor", morpho.sky_view_factor);
+            features.push(feature);
+        }
+
+        self.geojson = Some(GeoJson::from(geojson::FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        }));
+
+        Ok(())
+    }
+
+    /// Cut `bbox_polygon` into RSU candidates by buffering every linework feature by
+    /// [`RSU_CUT_HALF_WIDTH_M`] and subtracting the union of those buffers from the bbox; each
+    /// resulting disjoint polygon becomes one RSU. Falls back to the whole bbox as a single RSU
+    /// when `linework_geojson` has no usable geometry.
+    fn partition_into_rsu(
+        &self,
+        bbox_polygon: &Polygon<f64>,
+        linework_geojson: &GeoJson,
+    ) -> Result<Vec<Polygon<f64>>> {
+        let mut cut_polygons: Vec<Polygon<f64>> = Vec::new();
+        if let GeoJson::FeatureCollection(fc) = linework_geojson {
+            for feature in &fc.features {
+                let Some(ref geometry) = feature.geometry else {
+                    continue;
+                };
+                let geo_geom: GeoGeometry<f64> = geometry
+                    .try_into()
+                    .context("Failed to convert linework geometry to geo geometry")?;
+                let geos_geom: GeosGeometry = geo_geom
+                    .try_into()
+                    .context("Failed to convert linework geometry to GEOS")?;
+                let buffered = geos_geom
+                    .buffer(RSU_CUT_HALF_WIDTH_M, 8)
+                    .context("Failed to buffer linework geometry")?;
+                let buffered_geo: GeoGeometry<f64> = buffered
+                    .try_into()
+                    .context("Failed to convert buffered linework back to geo")?;
+                cut_polygons.extend(Self::flatten_to_polygons(buffered_geo));
+            }
+        }
+
+        if cut_polygons.is_empty() {
+            return Ok(vec![bbox_polygon.clone()]);
+        }
+
+        let mut remaining = MultiPolygon(vec![bbox_polygon.clone()]);
+        for cut in &cut_polygons {
+            remaining = remaining.difference(&MultiPolygon(vec![cut.clone()]));
+        }
+
+        let rsu_polygons: Vec<Polygon<f64>> = remaining
+            .0
+            .into_iter()
+            .filter(|p| p.unsigned_area() > 0.0)
+            .collect();
+
+        if rsu_polygons.is_empty() {
+            Ok(vec![bbox_polygon.clone()])
+        } else {
+            Ok(rsu_polygons)
+        }
+    }
+
+    /// Extract `(polygon, height_m)` pairs from a building `GeoJson`, reading height from the
+    /// `"hauteur"` property (matching the BD TOPO / RNB convention used elsewhere in this crate)
+    /// and falling back to [`DEFAULT_BUILDING_HEIGHT_M`] when absent.
+    fn extract_building_polygons(
+        &self,
+        building_geojson: &GeoJson,
+    ) -> Result<Vec<(Polygon<f64>, f64)>> {
+        let GeoJson::FeatureCollection(fc) = building_geojson else {
+            return Err(anyhow!("Building GeoJSON must be a FeatureCollection"));
+        };
+        let mut buildings = Vec::new();
+        for feature in &fc.features {
+            let Some(ref geometry) = feature.geometry else {
+                continue;
+            };
+            let height = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("hauteur"))
+                .and_then(|v| v.as_f64())
+                .unwrap_or(DEFAULT_BUILDING_HEIGHT_M);
+            let geo_geom: GeoGeometry<f64> = geometry
+                .try_into()
+                .context("Failed to convert building geometry to geo geometry")?;
+            for polygon in Self::flatten_to_polygons(geo_geom) {
+                buildings.push((polygon, height));
+            }
+        }
+        Ok(buildings)
+    }
+
+    /// Extract `(polygon, type_code)` pairs from a land-cover `GeoJson`, reading the `"type"`
+    /// property written by `LandCoverType`/`LandCover::add_*_gdf`.
+    fn extract_land_cover_polygons(
+        &self,
+        land_cover_geojson: &GeoJson,
+    ) -> Result<Vec<(Polygon<f64>, u8)>> {
+        let GeoJson::FeatureCollection(fc) = land_cover_geojson else {
+            return Err(anyhow!("Land cover GeoJSON must be a FeatureCollection"));
+        };
+        let mut polygons = Vec::new();
+        for feature in &fc.features {
+            let Some(ref geometry) = feature.geometry else {
+                continue;
+            };
+            let type_code = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("type"))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u8;
+            let geo_geom: GeoGeometry<f64> = geometry
+                .try_into()
+                .context("Failed to convert land cover geometry to geo geometry")?;
+            for polygon in Self::flatten_to_polygons(geo_geom) {
+                polygons.push((polygon, type_code));
+            }
+        }
+        Ok(polygons)
+    }
+
+    /// Recursively collect every `Polygon` out of a `geo::Geometry`, expanding `MultiPolygon`
+    /// and flattening `GeometryCollection`. Non-polygonal members (points, lines) are skipped.
+    fn flatten_to_polygons(geo_geom: GeoGeometry<f64>) -> Vec<Polygon<f64>> {
+        match geo_geom {
+            GeoGeometry::Polygon(p) => vec![p],
+            GeoGeometry::MultiPolygon(mp) => mp.0,
+            GeoGeometry::GeometryCollection(gc) => {
+                gc.into_iter().flat_map(Self::flatten_to_polygons).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Compute one RSU's morphology by intersecting every building/land-cover polygon against
+    /// it. The canyon width used for the aspect ratio is a proxy (the RSU's un-built share of
+    /// twice [`RSU_CUT_HALF_WIDTH_M`]), not an actual per-street measurement, and the
+    /// sky-view-factor substitutes building plan-area fraction for frontal area density since no
+    /// wind direction is available at RSU granularity.
+    fn compute_rsu_morphology(
+        &self,
+        rsu: &Polygon<f64>,
+        rsu_area: f64,
+        buildings: &[(Polygon<f64>, f64)],
+        land_cover: &[(Polygon<f64>, u8)],
+    ) -> RsuMorphology {
+        let mut building_area = 0.0;
+        let mut height_sum = 0.0;
+        let mut height_weight = 0.0;
+        for (polygon, height) in buildings {
+            let overlap = rsu.intersection(polygon).unsigned_area();
+            if overlap > 0.0 {
+                building_area += overlap;
+                height_sum += height * overlap;
+                height_weight += overlap;
+            }
+        }
+
+        let mut impervious_area = 0.0;
+        let mut pervious_area = 0.0;
+        let mut water_area = 0.0;
+        for (polygon, type_code) in land_cover {
+            let overlap = rsu.intersection(polygon).unsigned_area();
+            if overlap <= 0.0 {
+                continue;
+            }
+            match LandCoverType::from(*type_code) {
+                LandCoverType::DarkAsphalt
+                | LandCoverType::CobbleStone
+                | LandCoverType::RoofsBuildings
+                | LandCoverType::Walls => impervious_area += overlap,
+                LandCoverType::GrassUnmanaged | LandCoverType::BareSoil => pervious_area += overlap,
+                LandCoverType::Water => water_area += overlap,
+            }
+        }
+
+        let building_fraction = (building_area / rsu_area).min(1.0);
+        let impervious_fraction = (impervious_area / rsu_area).min(1.0);
+        let pervious_fraction = (pervious_area / rsu_area).min(1.0);
+        let water_fraction = (water_area / rsu_area).min(1.0);
+        let mean_building_height = if height_weight > 0.0 {
+            height_sum / height_weight
+        } else {
+            0.0
+        };
+
+        let canyon_width = (RSU_CUT_HALF_WIDTH_M * 2.0 * (1.0 - building_fraction)).max(1.0);
+        let aspect_ratio = mean_building_height / canyon_width;
+        let sky_view_factor = (-SVF_DECAY_K * building_fraction).exp();
+
+        RsuMorphology {
+            building_fraction,
+            impervious_fraction,
+            pervious_fraction,
+            water_fraction,
+            mean_building_height,
+            aspect_ratio,
+            sky_view_factor,
+        }
+    }
+
     /// Download and extract ZIP file
     fn download_and_extract_zip(&self, url: &str) -> Result<TempDir> {
         // Télécharger le fichier
@@ -460,44 +1040,29 @@ impl Lcz {
         Ok(polygon)
     }
 
+    /// Convert `input` (a shapefile) to GeoJSON at `output`, in-process via geozero rather than
+    /// shelling out to `ogr2ogr` -- so the crate works on any machine where the GDAL bindings
+    /// link, regardless of whether the `ogr2ogr` CLI is on `PATH`.
     fn shp_to_geojson(&self, input: &str, output: &str) -> Result<()> {
-        // Use ogr2ogr command-line tool for reliable shapefile to GeoJSON conversion
-        // This is more reliable than using the GDAL Rust bindings directly
-        // which have complex API requirements for vector dataset creation
-        use std::process::Command;
+        use geozero::geojson::GeoJsonWriter;
 
-        let status = Command::new("ogr2ogr")
-            .arg("-f")
-            .arg("GeoJSON")
-            .arg(output)
-            .arg(input)
-            .status()
-            .context(
-                "Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH",
-            )?;
+        let dataset =
+            Dataset::open(input).context("Failed to open shapefile for in-process conversion")?;
+        let mut layer = dataset.layer(0).context("Shapefile has no layers")?;
 
-        if !status.success() {
-            anyhow::bail!("ogr2ogr failed to convert shapefile to GeoJSON");
-        }
+        let mut buf = Vec::new();
+        write_layer_features(&mut layer, &mut GeoJsonWriter::new(&mut buf))
+            .context("Failed to stream shapefile features through geozero")?;
+
+        std::fs::write(output, buf)
+            .context(format!("Failed to write GeoJSON file: {:?}", output))?;
 
         Ok(())
     }
 
     /// Convert GDAL geometry to geo::Geometry
     fn gdal_to_geo_geometry(&self, geom: &gdal::vector::Geometry) -> Result<GeoGeometry<f64>> {
-        // Get WKT representation
-        let wkt = geom.wkt().context("Failed to get WKT from GDAL geometry")?;
-
-        // Parse WKT using geos
-        let geos_geom =
-            GeosGeometry::new_from_wkt(&wkt).context("Failed to parse WKT with GEOS")?;
-
-        // Convert GEOS to geo
-        let geo_geom: GeoGeometry<f64> = geos_geom
-            .try_into()
-            .context("Failed to convert GEOS geometry to geo")?;
-
-        Ok(geo_geom)
+        ogr_geometry_to_geo(geom)
     }
 
     /// Convert geo::Geometry to geojson::Geometry
@@ -530,22 +1095,1042 @@ impl Lcz {
 
         let name = name.unwrap_or("lcz");
 
-        // Save as GeoJSON for now (GeoJSON export is complex with GDAL Rust bindings)
         let output_file = self.output_path.join(format!("{}.geojson", name));
         let geojson_str = geojson.to_string();
         std::fs::write(&output_file, geojson_str)
             .context(format!("Failed to write GeoJSON file: {:?}", output_file))?;
 
-        println!(
-            "LCZ saved to: {:?} (as GeoJSON - GeoJSON export temporarily disabled)",
-            output_file
+        println!("LCZ saved to: {:?}", output_file);
+
+        Ok(())
+    }
+
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, FlatGeobuf, KML, GPX) via
+    /// `ogr2ogr`, reprojecting to `geo_core.epsg` on the way out, mirroring
+    /// [`crate::geometric::water::Water::to_file`]. Use this instead of [`Lcz::to_geojson`] when a
+    /// downstream GIS tool needs a proper single-file container (e.g. `OutputFormat::GeoPackage`)
+    /// rather than loose JSON.
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("lcz");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.epsg)
+    }
+
+    /// Save to a styled KMZ (zipped KML), with one `<Style>` per `table_color` LCZ class so
+    /// Google Earth and other KML viewers render each class in its correct color without a
+    /// separate style pass. Every feature's `<styleUrl>` references its class's style, and its
+    /// `<name>`/`<description>` carry the class's human-readable label (e.g. "LCZ 1: Compact
+    /// high-rise"). `name` defaults to `"lcz"`, mirroring [`Lcz::to_geojson`].
+    pub fn to_kmz(&self, name: Option<&str>) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        // KML coordinates are always lon/lat (EPSG:4326), regardless of the working CRS.
+        let mut geojson_4326 = geojson.clone();
+        GeoCore::reproject_geojson(&mut geojson_4326, self.geo_core.epsg, 4326)
+            .context("Failed to reproject LCZ features to EPSG:4326 for KML export")?;
+
+        let GeoJson::FeatureCollection(fc) = &geojson_4326 else {
+            anyhow::bail!(
+                "Expected a FeatureCollection; call run() or classify_from_morphology() first"
+            );
+        };
+
+        let mut codes: Vec<&u8> = self.table_color.keys().collect();
+        codes.sort();
+        let mut styles = String::new();
+        for code in codes {
+            let (_, color) = &self.table_color[code];
+            let kml_color = hex_color_to_kml_argb(color, KMZ_FILL_OPACITY);
+            styles.push_str(&format!(
+                "  <Style id=\"lcz_{code}\">\n    <PolyStyle>\n      <color>{kml_color}</color>\n      <outline>1</outline>\n    </PolyStyle>\n  </Style>\n"
+            ));
+        }
+
+        let mut placemarks = String::new();
+        for feature in &fc.features {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let lcz_int = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("lcz_int"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as u8;
+            let label = self
+                .table_color
+                .get(&lcz_int)
+                .map(|(label, _)| label.clone())
+                .unwrap_or_else(|| format!("LCZ {}", lcz_int));
+
+            let Ok(geo_geom): std::result::Result<GeoGeometry<f64>, _> = geometry.try_into()
+            else {
+                continue;
+            };
+            let Some(kml_geometry) = geometry_to_kml(&geo_geom) else {
+                continue;
+            };
+
+            placemarks.push_str(&format!(
+                "  <Placemark>\n    <name>{name}</name>\n    <description>{name}</description>\n    <styleUrl>#lcz_{code}</styleUrl>\n    {geom}\n  </Placemark>\n",
+                name = escape_xml(&label),
+                code = lcz_int,
+                geom = kml_geometry,
+            ));
+        }
+
+        let kml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n{styles}{placemarks}</Document>\n</kml>\n"
         );
 
+        let name = name.unwrap_or("lcz");
+        let output_file = self.output_path.join(format!("{}.kmz", name));
+        let file = fs::File::create(&output_file)
+            .context(format!("Failed to create KMZ file: {:?}", output_file))?;
+        let mut zip = ZipWriter::new(file);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        zip.start_file("doc.kml", options)
+            .context("Failed to start doc.kml entry in KMZ archive")?;
+        zip.write_all(kml.as_bytes())
+            .context("Failed to write KML content to KMZ archive")?;
+        zip.finish().context("Failed to finalize KMZ archive")?;
+
+        println!("LCZ saved to: {:?} (as styled KMZ)", output_file);
+
+        Ok(())
+    }
+
+    /// Export the processed LCZ features to `path` in `format`, via geozero rather than a
+    /// second `ogr2ogr` subprocess -- the same in-process approach [`Lcz::shp_to_geojson`] uses
+    /// for the initial shapefile read.
+    pub fn export(&self, format: ExportFormat, path: &Path) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let mut source = LczGeojsonSource { geojson };
+        let mut buf = Vec::new();
+
+        match format {
+            ExportFormat::GeoJson => {
+                use geozero::geojson::GeoJsonWriter;
+                source
+                    .process(&mut GeoJsonWriter::new(&mut buf))
+                    .context("Failed to write LCZ features as GeoJSON via geozero")?;
+            }
+            ExportFormat::FlatGeobuf => {
+                use geozero::fgb::FgbWriter;
+                let mut writer =
+                    FgbWriter::create("lcz").context("Failed to create FlatGeobuf writer")?;
+                source
+                    .process(&mut writer)
+                    .context("Failed to write LCZ features as FlatGeobuf via geozero")?;
+                writer
+                    .write(&mut buf)
+                    .context("Failed to serialize FlatGeobuf output")?;
+            }
+            ExportFormat::Csv => {
+                use geozero::csv::CsvWriter;
+                source
+                    .process(&mut CsvWriter::new(&mut buf))
+                    .context("Failed to write LCZ features as CSV via geozero")?;
+            }
+        }
+
+        std::fs::write(path, buf).context(format!("Failed to write export file: {:?}", path))?;
+
         Ok(())
     }
 
+    /// Export the filtered `run`/`classify_from_morphology` result as a self-contained MBTiles
+    /// vector-tile pyramid: a SQLite database with a `metadata` table (name/format/bounds/
+    /// minzoom/maxzoom) and a `tiles(zoom_level, tile_column, tile_row, tile_data)` table, using
+    /// the MBTiles spec's TMS row convention (origin at the bottom-left, unlike the slippy-map
+    /// `y` this method computes tiles with). Each tile is a gzip-compressed Mapbox Vector Tile
+    /// with a single `"lcz"` layer carrying the `lcz_int` and `color` properties; tiles with no
+    /// intersecting feature are omitted. `path` is overwritten if it already exists.
+    pub fn to_mbtiles(&self, path: &Path, min_zoom: u8, max_zoom: u8) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        // Tile coordinates are always lon/lat (Web Mercator)-based, regardless of the working
+        // CRS, so reproject a throwaway copy back to EPSG:4326 first.
+        let mut geojson_4326 = geojson.clone();
+        GeoCore::reproject_geojson(&mut geojson_4326, self.geo_core.epsg, 4326)
+            .context("Failed to reproject LCZ features to EPSG:4326 for tiling")?;
+
+        let GeoJson::FeatureCollection(fc) = &geojson_4326 else {
+            anyhow::bail!(
+                "Expected a FeatureCollection; call run() or classify_from_morphology() first"
+            );
+        };
+
+        let mut indexed_geometries = Vec::new();
+        for feature in &fc.features {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let Ok(geom): std::result::Result<GeoGeometry<f64>, _> = geometry.try_into() else {
+                continue;
+            };
+            let properties = feature.properties.as_ref();
+            let lcz_int = properties
+                .and_then(|p| p.get("lcz_int"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as u8;
+            let color = properties
+                .and_then(|p| p.get("color"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("#000000")
+                .to_string();
+            indexed_geometries.push(IndexedGeometry {
+                geom,
+                lcz_int,
+                color,
+            });
+        }
+
+        let bounds = indexed_geometries
+            .iter()
+            .filter_map(|g| g.geom.bounding_rect())
+            .fold(None, |acc: Option<geo::Rect<f64>>, rect| match acc {
+                Some(acc) => Some(geo::Rect::new(
+                    (acc.min().x.min(rect.min().x), acc.min().y.min(rect.min().y)),
+                    (acc.max().x.max(rect.max().x), acc.max().y.max(rect.max().y)),
+                )),
+                None => Some(rect),
+            })
+            .context("No features to tile")?;
+
+        let tree = RTree::bulk_load(indexed_geometries);
+
+        if path.exists() {
+            fs::remove_file(path).context("Failed to remove existing MBTiles file")?;
+        }
+        let conn = Connection::open(path).context("Failed to create MBTiles database")?;
+        conn.execute_batch(
+            "CREATE TABLE metadata (name TEXT, value TEXT);
+             CREATE TABLE tiles (zoom_level INTEGER, tile_column INTEGER, tile_row INTEGER, tile_data BLOB);
+             CREATE UNIQUE INDEX tile_index ON tiles (zoom_level, tile_column, tile_row);",
+        )
+        .context("Failed to create MBTiles schema")?;
+
+        for (name, value) in [
+            ("name", "lcz".to_string()),
+            ("format", "pbf".to_string()),
+            (
+                "bounds",
+                format!(
+                    "{},{},{},{}",
+                    bounds.min().x,
+                    bounds.min().y,
+                    bounds.max().x,
+                    bounds.max().y
+                ),
+            ),
+            ("minzoom", min_zoom.to_string()),
+            ("maxzoom", max_zoom.to_string()),
+        ] {
+            conn.execute(
+                "INSERT INTO metadata (name, value) VALUES (?1, ?2)",
+                params![name, value],
+            )
+            .context("Failed to insert MBTiles metadata row")?;
+        }
+
+        for zoom in min_zoom..=max_zoom {
+            let (min_tx, max_ty) = lonlat_to_tile_xy(bounds.min().x, bounds.min().y, zoom);
+            let (max_tx, min_ty) = lonlat_to_tile_xy(bounds.max().x, bounds.max().y, zoom);
+
+            for tile_x in min_tx..=max_tx {
+                for tile_y in min_ty..=max_ty {
+                    let (west, south, east, north) = tile_bounds(tile_x, tile_y, zoom);
+                    let envelope = AABB::from_corners([west, south], [east, north]);
+                    let candidates: Vec<_> =
+                        tree.locate_in_envelope_intersecting(&envelope).collect();
+                    if candidates.is_empty() {
+                        continue;
+                    }
+
+                    let tile_bytes = encode_mvt_tile(&candidates, zoom, tile_x, tile_y);
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                    encoder
+                        .write_all(&tile_bytes)
+                        .context("Failed to gzip-compress MVT tile")?;
+                    let compressed = encoder
+                        .finish()
+                        .context("Failed to finalize gzip-compressed MVT tile")?;
+
+                    // MBTiles stores rows TMS-style (origin at the bottom-left), while the tile_y
+                    // above increases southward from the top like every slippy-map index -- flip
+                    // it before inserting.
+                    let tms_row = (1u32 << zoom) - 1 - tile_y;
+                    conn.execute(
+                        "INSERT INTO tiles (zoom_level, tile_column, tile_row, tile_data) VALUES (?1, ?2, ?3, ?4)",
+                        params![zoom as i64, tile_x as i64, tms_row as i64, compressed],
+                    )
+                    .context("Failed to insert MVT tile into MBTiles database")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rasterize the filtered `run`/`classify_from_morphology` result to a gridded `lcz_int`
+    /// raster, since urban canopy and surface-energy models (SURFEX/UMEP-style) consume LCZ as a
+    /// regular grid of class codes rather than vector polygons. Builds an [`IndexedGeometry`]
+    /// RTree over the current features (mirroring [`Lcz::to_mbtiles`]'s own tree), allocates a
+    /// grid covering their extent at `resolution_m`, and for every cell assigns the `lcz_int` of
+    /// whichever candidate located at the cell center actually contains that point, leaving
+    /// `nodata` where none do.
+    ///
+    /// Cell/world coordinate conversions go through [`world_to_grid_index`]/
+    /// [`grid_index_to_world`] -- the fixed-scale integer-coordinate trick terminal OSM tools use
+    /// -- rather than dividing/multiplying by `resolution_m` directly, so a cell's center always
+    /// maps back to the same column/row it came from instead of drifting across a cell boundary.
+    ///
+    /// Writes a single-band `Byte` GeoTIFF in the working CRS at `path`, with a GDAL color table
+    /// built from `table_color` and `nodata` as the no-data value.
+    pub fn to_raster(&self, resolution_m: f64, path: &Path, nodata: u8) -> Result<()> {
+        use gdal::raster::{Buffer, ColorEntry, ColorInterpretation, ColorTable};
+        use gdal::DriverManager;
+
+        anyhow::ensure!(resolution_m > 0.0, "resolution_m must be positive");
+
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            anyhow::bail!(
+                "Expected a FeatureCollection; call run() or classify_from_morphology() first"
+            );
+        };
+
+        let mut indexed_geometries = Vec::new();
+        for feature in &fc.features {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let Ok(geom): std::result::Result<GeoGeometry<f64>, _> = geometry.try_into() else {
+                continue;
+            };
+            let lcz_int = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("lcz_int"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as u8;
+            indexed_geometries.push(IndexedGeometry {
+                geom,
+                lcz_int,
+                color: String::new(),
+            });
+        }
+
+        let bounds = indexed_geometries
+            .iter()
+            .filter_map(|g| g.geom.bounding_rect())
+            .fold(None, |acc: Option<geo::Rect<f64>>, rect| match acc {
+                Some(acc) => Some(geo::Rect::new(
+                    (acc.min().x.min(rect.min().x), acc.min().y.min(rect.min().y)),
+                    (acc.max().x.max(rect.max().x), acc.max().y.max(rect.max().y)),
+                )),
+                None => Some(rect),
+            })
+            .context("No features to rasterize")?;
+
+        let tree = RTree::bulk_load(indexed_geometries);
+
+        // Round up so a partial last column/row of the extent still gets a cell.
+        let cells = |max: f64, min: f64| {
+            let n = world_to_grid_index(max, min, resolution_m);
+            (n + (grid_index_to_world(n, min, resolution_m) < max) as i64).max(1)
+        };
+        let width = cells(bounds.max().x, bounds.min().x) as usize;
+        let height = cells(bounds.max().y, bounds.min().y) as usize;
+        let transform = [
+            bounds.min().x,
+            resolution_m,
+            0.0,
+            // Top edge of the grid rows sampled below, so pixels line up with their cell centers.
+            grid_index_to_world(height as i64, bounds.min().y, resolution_m),
+            0.0,
+            -resolution_m,
+        ];
+
+        let mut raster_data = vec![nodata; width * height];
+        for row in 0..height {
+            // GeoTIFF rows run north-to-south, while grid row indices count up from the bbox's
+            // south edge -- flip so row 0 is the northernmost cell.
+            let grid_row = height - 1 - row;
+            let cy =
+                grid_index_to_world(grid_row as i64, bounds.min().y, resolution_m) + resolution_m / 2.0;
+            for col in 0..width {
+                let cx =
+                    grid_index_to_world(col as i64, bounds.min().x, resolution_m) + resolution_m / 2.0;
+                let cell_center = geo::Point::new(cx, cy);
+                let envelope = AABB::from_corners([cx, cy], [cx, cy]);
+                for candidate in tree.locate_in_envelope_intersecting(&envelope) {
+                    if candidate.geom.intersects(&cell_center) {
+                        raster_data[row * width + col] = candidate.lcz_int;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let driver =
+            DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let mut dataset = with_gdal_error_context("Failed to create GeoTIFF dataset", || {
+            driver
+                .create_with_band_type::<u8, _>(path, width as isize, height as isize, 1)
+                .map_err(Into::into)
+        })?;
+        dataset
+            .set_geo_transform(&transform)
+            .context("Failed to set geotransform")?;
+        let srs = SpatialRef::from_epsg(self.geo_core.epsg as u32)
+            .context("Failed to create spatial reference")?;
+        dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set spatial reference")?;
+
+        let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+        let buffer = Buffer::new((width, height), raster_data);
+        with_gdal_error_context("Failed to write raster band", || {
+            band.write((0, 0), (width, height), &buffer)
+                .map_err(Into::into)
+        })?;
+        band.set_no_data_value(Some(nodata as f64))
+            .context("Failed to set no data value")?;
+
+        let mut color_table = ColorTable::new();
+        let mut codes: Vec<&u8> = self.table_color.keys().collect();
+        codes.sort();
+        for code in codes {
+            let (_, hex) = &self.table_color[code];
+            let (r, g, b) = hex_to_rgb(hex);
+            color_table.set_color_entry(
+                *code as isize,
+                &ColorEntry { c1: r, c2: g, c3: b, c4: 255 },
+            );
+        }
+        band.set_color_interpretation(ColorInterpretation::PaletteIndex)
+            .context("Failed to set palette color interpretation")?;
+        band.set_color_table(&color_table);
+
+        println!("LCZ raster saved to: {:?}", path);
+
+        Ok(())
+    }
+
+    /// Aggregate the filtered `run`/`classify_from_morphology` features by `lcz_int`, reporting
+    /// per class the total area (m², in the working projected CRS), feature count, class label/
+    /// color from `table_color`, and the class's share of the features' combined bounding extent
+    /// -- the core quantitative summary urban-climate users need (e.g. "42% open mid-rise"). Call
+    /// [`LczStat::to_csv`] on the result to drop it straight into a report.
+    pub fn class_statistics(&self) -> Result<HashMap<u8, LczStat>> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            anyhow::bail!(
+                "Expected a FeatureCollection; call run() or classify_from_morphology() first"
+            );
+        };
+
+        struct Accum {
+            area_m2: f64,
+            feature_count: usize,
+        }
+        let mut accum: HashMap<u8, Accum> = HashMap::new();
+        let mut bounds: Option<geo::Rect<f64>> = None;
+
+        for feature in &fc.features {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let Ok(geom): std::result::Result<GeoGeometry<f64>, _> = geometry.try_into() else {
+                continue;
+            };
+            let lcz_int = feature
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("lcz_int"))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as u8;
+
+            if let Some(rect) = geom.bounding_rect() {
+                bounds = Some(match bounds {
+                    Some(acc) => geo::Rect::new(
+                        (acc.min().x.min(rect.min().x), acc.min().y.min(rect.min().y)),
+                        (acc.max().x.max(rect.max().x), acc.max().y.max(rect.max().y)),
+                    ),
+                    None => rect,
+                });
+            }
+
+            let area: f64 = Self::flatten_to_polygons(geom)
+                .iter()
+                .map(|p| p.unsigned_area())
+                .sum();
+
+            let entry = accum.entry(lcz_int).or_insert(Accum {
+                area_m2: 0.0,
+                feature_count: 0,
+            });
+            entry.area_m2 += area;
+            entry.feature_count += 1;
+        }
+
+        let bbox_area = bounds
+            .map(|r| r.width() * r.height())
+            .filter(|a| *a > 0.0);
+
+        let stats = accum
+            .into_iter()
+            .map(|(lcz_int, acc)| {
+                let (label, color) = self
+                    .table_color
+                    .get(&lcz_int)
+                    .cloned()
+                    .unwrap_or_else(|| (format!("LCZ {}", lcz_int), "#000000".to_string()));
+                let bbox_fraction = bbox_area.map(|total| acc.area_m2 / total).unwrap_or(0.0);
+                (
+                    lcz_int,
+                    LczStat {
+                        lcz_int,
+                        label,
+                        color,
+                        area_m2: acc.area_m2,
+                        bbox_fraction,
+                        feature_count: acc.feature_count,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(stats)
+    }
+
     /// Get output path
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
     }
 }
+
+/// One LCZ class's aggregated area/count statistics, returned by [`Lcz::class_statistics`].
+#[derive(Debug, Clone)]
+pub struct LczStat {
+    pub lcz_int: u8,
+    pub label: String,
+    pub color: String,
+    /// Total area (m²) of this class's features, in the working projected CRS.
+    pub area_m2: f64,
+    /// This class's share of the features' combined bounding extent, `0.0` when that extent has
+    /// zero area (e.g. a single point-sized feature set).
+    pub bbox_fraction: f64,
+    pub feature_count: usize,
+}
+
+impl LczStat {
+    /// Render a set of stats (as returned by [`Lcz::class_statistics`]) as CSV, one row per
+    /// class sorted by `lcz_int`, so the summary can be dropped straight into a report.
+    pub fn to_csv(stats: &HashMap<u8, LczStat>) -> String {
+        let mut codes: Vec<&u8> = stats.keys().collect();
+        codes.sort();
+
+        let mut csv = String::from("lcz_int,label,color,area_m2,bbox_fraction,feature_count\n");
+        for code in codes {
+            let stat = &stats[code];
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                stat.lcz_int,
+                csv_quote(&stat.label),
+                stat.color,
+                stat.area_m2,
+                stat.bbox_fraction,
+                stat.feature_count,
+            ));
+        }
+        csv
+    }
+}
+
+/// Quote a CSV field in double quotes, escaping any embedded quote, so labels containing a comma
+/// (e.g. `"LCZ C: Bush,scrub"`) don't split into extra columns.
+fn csv_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Convert an OGR geometry to `geo::Geometry` by round-tripping through WKT/GEOS, shared by
+/// [`Lcz::gdal_to_geo_geometry`] and [`write_layer_features`] so the shapefile-ingest path only
+/// has one geometry conversion to maintain.
+fn ogr_geometry_to_geo(geom: &gdal::vector::Geometry) -> Result<GeoGeometry<f64>> {
+    let wkt = geom.wkt().context("Failed to get WKT from GDAL geometry")?;
+    let geos_geom = GeosGeometry::new_from_wkt(&wkt).context("Failed to parse WKT with GEOS")?;
+    geos_geom
+        .try_into()
+        .context("Failed to convert GEOS geometry to geo")
+}
+
+/// Render an OGR list-valued field as a comma-joined string, since none of [`ExportFormat`]'s
+/// targets have a native list column type.
+fn join_list<T: ToString>(values: &[T]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// Stream every feature in `layer` through `processor`'s geozero [`FeatureProcessor`] callbacks:
+/// properties straight from the shapefile's own field list, and geometry via
+/// [`GeozeroGeometry::process_geom`] once [`ogr_geometry_to_geo`] has converted the OGR geometry
+/// to `geo_types`. Features with no geometry (`feature.geometry()` returning `None`) are emitted
+/// as geometry-less features rather than erroring, the same tolerance `ogr2ogr` itself has for
+/// shapefiles with missing geometry.
+fn write_layer_features<P: FeatureProcessor>(
+    layer: &mut gdal::vector::Layer,
+    processor: &mut P,
+) -> GeozeroResult<()> {
+    processor.dataset_begin(None)?;
+    for (idx, feature) in layer.features().enumerate() {
+        processor.feature_begin(idx as u64)?;
+
+        processor.properties_begin()?;
+        for (field_idx, (name, value)) in feature.fields().enumerate() {
+            let Some(value) = value else { continue };
+            match value {
+                FieldValue::IntegerValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::Int(v))?;
+                }
+                FieldValue::Integer64Value(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::Long(v))?;
+                }
+                FieldValue::RealValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::Double(v))?;
+                }
+                FieldValue::StringValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&v))?;
+                }
+                FieldValue::DateValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&v.to_string()))?;
+                }
+                FieldValue::DateTimeValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&v.to_string()))?;
+                }
+                FieldValue::IntegerListValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&join_list(&v)))?;
+                }
+                FieldValue::Integer64ListValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&join_list(&v)))?;
+                }
+                FieldValue::RealListValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&join_list(&v)))?;
+                }
+                FieldValue::StringListValue(v) => {
+                    processor.property(field_idx, &name, &ColumnValue::String(&v.join(",")))?;
+                }
+            };
+        }
+        processor.properties_end()?;
+
+        if let Some(geom_ref) = feature.geometry() {
+            if let Ok(geo_geom) = ogr_geometry_to_geo(geom_ref) {
+                processor.geometry_begin()?;
+                geo_geom.process_geom(processor)?;
+                processor.geometry_end()?;
+            }
+        }
+
+        processor.feature_end(idx as u64)?;
+    }
+    processor.dataset_end()?;
+    Ok(())
+}
+
+/// Adapts `&GeoJson` to geozero's [`GeozeroDatasource`] so [`Lcz::export`] can reuse the same
+/// writer-driving approach as the shapefile-ingest side ([`write_layer_features`]), regardless of
+/// output format.
+struct LczGeojsonSource<'a> {
+    geojson: &'a GeoJson,
+}
+
+impl<'a> GeozeroDatasource for LczGeojsonSource<'a> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> GeozeroResult<()> {
+        let features: Vec<&Feature> = match self.geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.iter().collect(),
+            GeoJson::Feature(feature) => vec![feature],
+            GeoJson::Geometry(_) => vec![],
+        };
+
+        processor.dataset_begin(None)?;
+        for (idx, feature) in features.iter().enumerate() {
+            processor.feature_begin(idx as u64)?;
+
+            processor.properties_begin()?;
+            if let Some(properties) = &feature.properties {
+                for (field_idx, (name, value)) in properties.iter().enumerate() {
+                    if let Some(column_value) = json_value_to_column(value) {
+                        processor.property(field_idx, name, &column_value)?;
+                    }
+                }
+            }
+            processor.properties_end()?;
+
+            if let Some(geometry) = &feature.geometry {
+                let geo_geom: std::result::Result<GeoGeometry<f64>, _> = geometry.try_into();
+                if let Ok(geo_geom) = geo_geom {
+                    processor.geometry_begin()?;
+                    geo_geom.process_geom(processor)?;
+                    processor.geometry_end()?;
+                }
+            }
+
+            processor.feature_end(idx as u64)?;
+        }
+        processor.dataset_end()?;
+        Ok(())
+    }
+}
+
+/// Map a single GeoJSON property value to geozero's [`ColumnValue`]; arrays/objects/null are
+/// dropped (unlike [`write_layer_features`], which joins list-valued OGR fields into a string).
+fn json_value_to_column(value: &serde_json::Value) -> Option<ColumnValue<'_>> {
+    match value {
+        serde_json::Value::Bool(v) => Some(ColumnValue::Bool(*v)),
+        serde_json::Value::Number(v) => v
+            .as_f64()
+            .map(ColumnValue::Double)
+            .or_else(|| v.as_i64().map(ColumnValue::Long)),
+        serde_json::Value::String(v) => Some(ColumnValue::String(v)),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) | serde_json::Value::Null => {
+            None
+        }
+    }
+}
+
+/// Parse a `#rrggbb` (or `rrggbb`) hex color into its `(r, g, b)` bytes, used by both
+/// [`hex_color_to_kml_argb`] and [`Lcz::to_raster`]'s color table. Malformed input falls back to
+/// black.
+fn hex_to_rgb(hex: &str) -> (u8, u8, u8) {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() >= 6 {
+        (
+            u8::from_str_radix(&hex[0..2], 16).unwrap_or(0),
+            u8::from_str_radix(&hex[2..4], 16).unwrap_or(0),
+            u8::from_str_radix(&hex[4..6], 16).unwrap_or(0),
+        )
+    } else {
+        (0, 0, 0)
+    }
+}
+
+/// Convert a `#rrggbb` (or `rrggbb`) hex color to KML's `aabbggrr` byte order, using `opacity`
+/// (0.0-1.0, clamped) for the alpha channel. Malformed input falls back to black at that opacity.
+fn hex_color_to_kml_argb(hex: &str, opacity: f64) -> String {
+    let (r, g, b) = hex_to_rgb(hex);
+    let alpha = (opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("{:02x}{:02x}{:02x}{:02x}", alpha, b, g, r)
+}
+
+/// Render a `geo::Geometry`'s Polygon/MultiPolygon as a KML `<Polygon>`/`<MultiGeometry>`
+/// fragment; `None` for any other geometry type (LCZ output never carries other types).
+fn geometry_to_kml(geom: &GeoGeometry<f64>) -> Option<String> {
+    match geom {
+        GeoGeometry::Polygon(p) => Some(polygon_to_kml(p)),
+        GeoGeometry::MultiPolygon(mp) if !mp.0.is_empty() => {
+            let mut buf = String::from("<MultiGeometry>");
+            for polygon in &mp.0 {
+                buf.push_str(&polygon_to_kml(polygon));
+            }
+            buf.push_str("</MultiGeometry>");
+            Some(buf)
+        }
+        _ => None,
+    }
+}
+
+fn polygon_to_kml(polygon: &Polygon<f64>) -> String {
+    let mut buf = String::from("<Polygon><outerBoundaryIs><LinearRing><coordinates>");
+    buf.push_str(&ring_to_kml_coordinates(polygon.exterior()));
+    buf.push_str("</coordinates></LinearRing></outerBoundaryIs>");
+    for interior in polygon.interiors() {
+        buf.push_str("<innerBoundaryIs><LinearRing><coordinates>");
+        buf.push_str(&ring_to_kml_coordinates(interior));
+        buf.push_str("</coordinates></LinearRing></innerBoundaryIs>");
+    }
+    buf.push_str("</Polygon>");
+    buf
+}
+
+/// KML wants whitespace-separated `lon,lat,alt` triples; LCZ features carry no elevation, so
+/// altitude is always `0`.
+fn ring_to_kml_coordinates(ring: &geo::LineString<f64>) -> String {
+    ring.points()
+        .map(|p| format!("{},{},0", p.x(), p.y()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Slippy-map tile `(x, y)` covering `(lon, lat)` at `zoom`, per the standard Web Mercator tile
+/// scheme (see the OSM wiki's "Slippy map tilenames"). `y` increases southward from the tile grid's
+/// top-left corner, unlike the MBTiles/TMS row convention [`Lcz::to_mbtiles`] flips into.
+fn lonlat_to_tile_xy(lon: f64, lat: f64, zoom: u8) -> (u32, u32) {
+    let n = (1u32 << zoom) as f64;
+    let lat_rad = lat.to_radians();
+    let x = ((lon + 180.0) / 360.0 * n).floor().clamp(0.0, n - 1.0) as u32;
+    let y = ((1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n)
+        .floor()
+        .clamp(0.0, n - 1.0) as u32;
+    (x, y)
+}
+
+/// Inverse of [`lonlat_to_tile_xy`]: the `(lon, lat)` of tile `(x, y)`'s top-left corner at `zoom`.
+fn tile_lonlat(x: u32, y: u32, zoom: u8) -> (f64, f64) {
+    let n = (1u32 << zoom) as f64;
+    let lon = x as f64 / n * 360.0 - 180.0;
+    let lat_rad = (std::f64::consts::PI * (1.0 - 2.0 * y as f64 / n)).sinh().atan();
+    (lon, lat_rad.to_degrees())
+}
+
+/// `(west, south, east, north)` bounds of tile `(x, y)` at `zoom`.
+fn tile_bounds(x: u32, y: u32, zoom: u8) -> (f64, f64, f64, f64) {
+    let (west, north) = tile_lonlat(x, y, zoom);
+    let (east, south) = tile_lonlat(x + 1, y + 1, zoom);
+    (west, south, east, north)
+}
+
+/// Convert a world coordinate to an integer grid index relative to `origin`, at `resolution_m`
+/// cell size, via [`GRID_SCALE`]-scaled integer arithmetic rather than plain floating-point
+/// division -- see [`Lcz::to_raster`].
+fn world_to_grid_index(coord: f64, origin: f64, resolution_m: f64) -> i64 {
+    let scaled_coord = (coord * GRID_SCALE as f64).round() as i64;
+    let scaled_origin = (origin * GRID_SCALE as f64).round() as i64;
+    let scaled_resolution = ((resolution_m * GRID_SCALE as f64).round() as i64).max(1);
+    (scaled_coord - scaled_origin).div_euclid(scaled_resolution)
+}
+
+/// Inverse of [`world_to_grid_index`]: the world coordinate of grid index `index`'s near edge,
+/// relative to `origin` at `resolution_m` cell size.
+fn grid_index_to_world(index: i64, origin: f64, resolution_m: f64) -> f64 {
+    let scaled_origin = (origin * GRID_SCALE as f64).round() as i64;
+    let scaled_resolution = ((resolution_m * GRID_SCALE as f64).round() as i64).max(1);
+    (scaled_origin + index * scaled_resolution) as f64 / GRID_SCALE as f64
+}
+
+/// Project `(lon, lat)` into tile `(tile_x, tile_y)`'s local `0..extent` pixel space at `zoom`.
+fn lonlat_to_tile_pixel(lon: f64, lat: f64, zoom: u8, tile_x: u32, tile_y: u32, extent: u32) -> (i32, i32) {
+    let n = (1u32 << zoom) as f64;
+    let lat_rad = lat.to_radians();
+    let world_x = (lon + 180.0) / 360.0 * n;
+    let world_y =
+        (1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n;
+
+    let px = ((world_x - tile_x as f64) * extent as f64).round() as i32;
+    let py = ((world_y - tile_y as f64) * extent as f64).round() as i32;
+    (px, py)
+}
+
+/// Append a protobuf varint (base-128, little-endian, continuation bit in the MSB of each byte).
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Append a protobuf field tag (`(field_number << 3) | wire_type`).
+fn write_tag(buf: &mut Vec<u8>, field_number: u32, wire_type: u8) {
+    write_varint(buf, ((field_number as u64) << 3) | wire_type as u64);
+}
+
+/// Append a length-delimited protobuf field (wire type 2): a string, an embedded message, or a
+/// packed repeated scalar field, all of which share this encoding.
+fn write_bytes_field(buf: &mut Vec<u8>, field_number: u32, data: &[u8]) {
+    write_tag(buf, field_number, 2);
+    write_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+fn write_string_field(buf: &mut Vec<u8>, field_number: u32, value: &str) {
+    write_bytes_field(buf, field_number, value.as_bytes());
+}
+
+fn write_uint32_field(buf: &mut Vec<u8>, field_number: u32, value: u32) {
+    write_tag(buf, field_number, 0);
+    write_varint(buf, value as u64);
+}
+
+/// Protobuf zigzag encoding (`sint32`), mapping signed deltas to varint-friendly unsigned values.
+fn zigzag_encode(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Encode `polygon`'s exterior ring and holes as Mapbox Vector Tile geometry commands (the
+/// `vector_tile.proto` `Tile.Feature.geometry` packed-`uint32` encoding: `MoveTo`/`LineTo`/
+/// `ClosePath` commands followed by zigzag-delta-encoded parameters), via `to_pixel` to map each
+/// ring vertex into the tile's local pixel space.
+fn encode_polygon_geometry(
+    polygon: &Polygon<f64>,
+    to_pixel: &dyn Fn(f64, f64) -> (i32, i32),
+) -> Vec<u32> {
+    const MOVE_TO: u32 = 1;
+    const LINE_TO: u32 = 2;
+    const CLOSE_PATH: u32 = 7;
+
+    let mut commands = Vec::new();
+    let mut cursor = (0i32, 0i32);
+
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        let points: Vec<(i32, i32)> = ring.points().map(|p| to_pixel(p.x(), p.y())).collect();
+        if points.len() < 2 {
+            continue;
+        }
+        // geo::LineString rings repeat their first point as their last; MVT's ClosePath command
+        // implies the closing edge instead, so drop the duplicate.
+        let points = &points[..points.len() - 1];
+        if points.is_empty() {
+            continue;
+        }
+
+        commands.push((MOVE_TO & 0x7) | (1 << 3));
+        let (dx, dy) = (points[0].0 - cursor.0, points[0].1 - cursor.1);
+        commands.push(zigzag_encode(dx));
+        commands.push(zigzag_encode(dy));
+        cursor = points[0];
+
+        let remaining = points.len() - 1;
+        if remaining > 0 {
+            commands.push((LINE_TO & 0x7) | ((remaining as u32) << 3));
+            for &(x, y) in &points[1..] {
+                let (dx, dy) = (x - cursor.0, y - cursor.1);
+                commands.push(zigzag_encode(dx));
+                commands.push(zigzag_encode(dy));
+                cursor = (x, y);
+            }
+        }
+
+        commands.push((CLOSE_PATH & 0x7) | (1 << 3));
+    }
+
+    commands
+}
+
+/// Encode a `Value` message wrapping a single unsigned integer (`uint_value`, field 5).
+fn encode_value_uint(value: u64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_tag(&mut buf, 5, 0);
+    write_varint(&mut buf, value);
+    buf
+}
+
+/// Encode a `Value` message wrapping a single string (`string_value`, field 1).
+fn encode_value_string(value: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_string_field(&mut buf, 1, value);
+    buf
+}
+
+/// Encode `features` as a single-layer Mapbox Vector Tile (`"lcz"`, extent [`MVT_EXTENT`]),
+/// following the `vector_tile.proto` v2.1 schema. Every feature's geometry is a Polygon/
+/// MultiPolygon (LCZ output never carries other geometry types); non-polygonal or empty
+/// geometries are skipped. Carries the `lcz_int` and `color` properties, with the layer's
+/// `keys`/`values` string tables deduplicated across features.
+fn encode_mvt_tile(features: &[&IndexedGeometry], zoom: u8, tile_x: u32, tile_y: u32) -> Vec<u8> {
+    let to_pixel =
+        |lon: f64, lat: f64| lonlat_to_tile_pixel(lon, lat, zoom, tile_x, tile_y, MVT_EXTENT);
+
+    let keys = ["lcz_int", "color"];
+    let mut values: Vec<Vec<u8>> = Vec::new();
+    let mut value_index: HashMap<Vec<u8>, u32> = HashMap::new();
+    let mut encoded_features = Vec::new();
+
+    for indexed in features {
+        let polygons = Lcz::flatten_to_polygons(indexed.geom.clone());
+        if polygons.is_empty() {
+            continue;
+        }
+
+        let mut geometry_commands = Vec::new();
+        for polygon in &polygons {
+            geometry_commands.extend(encode_polygon_geometry(polygon, &to_pixel));
+        }
+        if geometry_commands.is_empty() {
+            continue;
+        }
+
+        let lcz_value = encode_value_uint(indexed.lcz_int as u64);
+        let lcz_value_idx = *value_index.entry(lcz_value.clone()).or_insert_with(|| {
+            values.push(lcz_value);
+            (values.len() - 1) as u32
+        });
+        let color_value = encode_value_string(&indexed.color);
+        let color_value_idx = *value_index.entry(color_value.clone()).or_insert_with(|| {
+            values.push(color_value);
+            (values.len() - 1) as u32
+        });
+
+        let mut tags_buf = Vec::new();
+        write_varint(&mut tags_buf, 0); // key index: "lcz_int"
+        write_varint(&mut tags_buf, lcz_value_idx as u64);
+        write_varint(&mut tags_buf, 1); // key index: "color"
+        write_varint(&mut tags_buf, color_value_idx as u64);
+
+        let mut feature_buf = Vec::new();
+        write_bytes_field(&mut feature_buf, 2, &tags_buf); // tags (packed uint32)
+        write_uint32_field(&mut feature_buf, 3, 3); // type = POLYGON
+        let mut geometry_buf = Vec::new();
+        for command in &geometry_commands {
+            write_varint(&mut geometry_buf, *command as u64);
+        }
+        write_bytes_field(&mut feature_buf, 4, &geometry_buf); // geometry (packed uint32)
+
+        let mut layer_feature_buf = Vec::new();
+        write_bytes_field(&mut layer_feature_buf, 2, &feature_buf); // Layer.features
+        encoded_features.push(layer_feature_buf);
+    }
+
+    let mut layer_buf = Vec::new();
+    write_uint32_field(&mut layer_buf, 15, 1); // version
+    write_string_field(&mut layer_buf, 1, "lcz"); // name
+    for feature_buf in &encoded_features {
+        layer_buf.extend_from_slice(feature_buf);
+    }
+    for key in &keys {
+        write_string_field(&mut layer_buf, 3, key);
+    }
+    for value in &values {
+        write_bytes_field(&mut layer_buf, 4, value);
+    }
+    write_uint32_field(&mut layer_buf, 5, MVT_EXTENT);
+
+    let mut tile_buf = Vec::new();
+    write_bytes_field(&mut tile_buf, 3, &layer_buf); // Tile.layers
+    tile_buf
+}
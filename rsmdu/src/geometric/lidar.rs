@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use proj::Proj;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -410,6 +411,170 @@ impl QuadtreeSpatialIndex {
             self.root.count_points()
         )
     }
+
+    /// Find the `k` nearest neighbours (by 3D Euclidean distance) of `points[query_idx]`,
+    /// excluding the query point itself. Starts from a small bbox query and doubles its
+    /// half-width until at least `k` candidates are found (or the index is exhausted),
+    /// then sorts the candidates by true distance and keeps the closest `k`.
+    pub fn query_knn(&self, points: &[LidarPoint], query_idx: usize, k: usize) -> Vec<usize> {
+        if points.is_empty() || k == 0 {
+            return Vec::new();
+        }
+        let query = &points[query_idx];
+
+        let mut radius = 1.0_f64;
+        let mut candidates: Vec<usize> = Vec::new();
+        loop {
+            candidates = self.query_bbox(
+                query.x - radius,
+                query.y - radius,
+                query.x + radius,
+                query.y + radius,
+            );
+            // +1 to account for the query point itself being included in its own bbox
+            if candidates.len() > k || radius > 1.0e7 {
+                break;
+            }
+            radius *= 2.0;
+        }
+
+        let mut with_dist: Vec<(f64, usize)> = candidates
+            .into_iter()
+            .filter(|&i| i != query_idx)
+            .map(|i| {
+                let p = &points[i];
+                let dx = p.x - query.x;
+                let dy = p.y - query.y;
+                let dz = p.z - query.z;
+                (dx * dx + dy * dy + dz * dz, i)
+            })
+            .collect();
+        with_dist.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        with_dist.truncate(k);
+        with_dist.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Find the `k` nearest neighbours (by 3D Euclidean distance) of an arbitrary `query`
+    /// point that need not belong to `points` itself (e.g. a point from another scan being
+    /// registered onto this one). Same expanding-bbox strategy as `query_knn`.
+    pub fn k_nearest(&self, points: &[LidarPoint], query: (f64, f64, f64), k: usize) -> Vec<usize> {
+        if points.is_empty() || k == 0 {
+            return Vec::new();
+        }
+        let (qx, qy, qz) = query;
+
+        let mut radius = 1.0_f64;
+        let mut candidates: Vec<usize> = Vec::new();
+        loop {
+            candidates = self.query_bbox(qx - radius, qy - radius, qx + radius, qy + radius);
+            if candidates.len() >= k || radius > 1.0e7 {
+                break;
+            }
+            radius *= 2.0;
+        }
+
+        let mut with_dist: Vec<(f64, usize)> = candidates
+            .into_iter()
+            .map(|i| {
+                let p = &points[i];
+                let dx = p.x - qx;
+                let dy = p.y - qy;
+                let dz = p.z - qz;
+                (dx * dx + dy * dy + dz * dz, i)
+            })
+            .collect();
+        with_dist.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        with_dist.truncate(k);
+        with_dist.into_iter().map(|(_, i)| i).collect()
+    }
+}
+
+/// A point's XY position tagged with its index into the original slice, so `rstar::RTree`
+/// can bulk-load positions while `RtreeSpatialIndex::query_bbox` still returns indices.
+#[derive(Debug, Clone, Copy)]
+struct RtreeIndexedPoint {
+    index: usize,
+    x: f64,
+    y: f64,
+}
+
+impl rstar::RTreeObject for RtreeIndexedPoint {
+    type Envelope = rstar::AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        rstar::AABB::from_point([self.x, self.y])
+    }
+}
+
+/// R-tree-based spatial index for LiDAR points. Unlike `SpatialGridIndex`'s uniform cells,
+/// the R-tree's bounding-volume hierarchy adapts to clustered, non-uniform point
+/// distributions (the norm for tiled LiDAR returns), so bbox range queries stay fast without
+/// wasting cells on sparse regions or over-subdividing dense ones the way the quadtree can.
+#[derive(Debug)]
+struct RtreeSpatialIndex {
+    tree: rstar::RTree<RtreeIndexedPoint>,
+    point_count: usize,
+}
+
+impl RtreeSpatialIndex {
+    /// Build an R-tree index from points, using `RTree::bulk_load` rather than one-at-a-time
+    /// inserts so the whole tree is balanced up front in O(n log n).
+    fn build(points: &[LidarPoint]) -> Self {
+        let entries: Vec<RtreeIndexedPoint> = points
+            .iter()
+            .enumerate()
+            .map(|(index, p)| RtreeIndexedPoint {
+                index,
+                x: p.x,
+                y: p.y,
+            })
+            .collect();
+
+        RtreeSpatialIndex {
+            point_count: points.len(),
+            tree: rstar::RTree::bulk_load(entries),
+        }
+    }
+
+    /// Query points within a bounding box via envelope intersection.
+    /// Returns indices of points that MAY be within the bbox (need final filtering), matching
+    /// `SpatialGridIndex`/`QuadtreeSpatialIndex`'s candidate-index contract.
+    fn query_bbox(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        let envelope = rstar::AABB::from_corners([min_x, min_y], [max_x, max_y]);
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|entry| entry.index)
+            .collect()
+    }
+
+    /// Get statistics
+    fn stats(&self) -> String {
+        format!("R-tree: {} points indexed", self.point_count)
+    }
+}
+
+/// Spatial index backend `filter_points_with_spatial_index` picks between, and the policy
+/// for choosing one automatically. Grid and quadtree remain the historical defaults; R-tree
+/// is the better choice for the clustered, non-uniform distributions typical of tiled LiDAR,
+/// where its bounding-volume hierarchy out-queries a uniform grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpatialIndexStrategy {
+    /// Always do a linear scan, regardless of dataset size.
+    AlwaysLinear,
+    /// Always build and query `SpatialGridIndex`.
+    AlwaysGrid,
+    /// Always build and query `RtreeSpatialIndex`.
+    AlwaysRtree,
+    /// Linear scan below `threshold` points, R-tree at or above it. This is the default,
+    /// replacing the old grid/quadtree split -- the R-tree's envelope query outperforms both
+    /// on the clustered point distributions LiDAR tiles actually produce.
+    Dynamic { threshold: usize },
+}
+
+impl Default for SpatialIndexStrategy {
+    fn default() -> Self {
+        SpatialIndexStrategy::Dynamic { threshold: 10_000 }
+    }
 }
 
 // ============================================================================
@@ -438,15 +603,67 @@ pub struct Lidar {
     list_path_laz: Option<Vec<String>>,
     /// Loaded LiDAR points (populated by load_lidar_points)
     loaded_points: Option<Vec<LidarPoint>>,
+    /// Spatial index over `loaded_points`, built once all tiles have been merged in
+    /// (e.g. by `load_manifest`), so repeated bbox/KNN queries don't re-scan every point.
+    spatial_index: Option<QuadtreeSpatialIndex>,
+    /// Optional cap on the COPC octree level read by `load_single_copc_file`; coarser
+    /// levels only, for a fast uniformly-decimated preview. `None` reads every level.
+    copc_max_depth: Option<i32>,
+    /// Target EPSG code that loaded points are reprojected into. `None` returns points in
+    /// whatever CRS the source file is natively in (or `geo_core`'s EPSG code, if the
+    /// source CRS can't be determined) -- the historical behavior.
+    target_epsg: Option<i32>,
+    /// Source EPSG code to assume for a tile when its LAS/COPC WKT VLR is absent or can't
+    /// be parsed. `None` falls back to treating the tile as already being in `geo_core`'s
+    /// EPSG code, matching the historical behavior.
+    source_epsg_fallback: Option<i32>,
+    /// Number of points buffered before `process_lidar_points_streaming` folds them into the
+    /// DSM/DTM grid accumulators. Defaults to `DEFAULT_INGEST_CHUNK_SIZE`.
+    ingest_chunk_size: usize,
+    /// Number of worker threads `process_lidar_points_streaming` uses to process tiles
+    /// concurrently. `None` uses rayon's global default pool size.
+    ingest_worker_threads: Option<usize>,
+    /// Parameters for `classify_ground_pmf`'s progressive morphological filter, used to
+    /// synthesize ground points for tiles with no classification-2 returns (or for every
+    /// tile when `force_pmf_ground` is set).
+    pmf_params: PmfParams,
+    /// When set, `process_lidar_points` always derives ground via `classify_ground_pmf`
+    /// instead of trusting classification-2 returns, even for tiles that carry them. Useful
+    /// for unclassified or mis-classified LAZ tiles where classification codes can't be
+    /// trusted.
+    force_pmf_ground: bool,
+    /// Parameters for `idw_fill_dtm`'s inverse-distance-weighted DTM gap filling.
+    dtm_idw_params: IdwParams,
+    /// GDAL creation options and overview settings used by `to_tif`'s GeoTIFF write.
+    geotiff_options: GeoTiffOptions,
+    /// Which raster products `process_lidar_points` computes and `to_tif` writes out.
+    band_selection: RasterBandSelection,
+    /// Cache of rasterized tiles backing `sample_elevation`/`sample_elevation_batch`, keyed
+    /// by source tile URL/path.
+    elevation_cache: Mutex<TileRasterCache>,
+    /// Resolution, in CRS units, `sample_elevation`/`sample_elevation_batch` rasterize
+    /// tiles at.
+    elevation_resolution: f64,
+    /// Backend `filter_points_with_spatial_index` builds/queries for bbox-filtering a
+    /// single tile's points, and the policy for picking one automatically.
+    spatial_index_strategy: SpatialIndexStrategy,
 }
 
 /// Point structure for LiDAR data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub(crate) struct LidarPoint {
     pub(crate) x: f64,
     pub(crate) y: f64,
     pub(crate) z: f64,
     pub(crate) classification: u8,
+    /// Pulse return strength as decoded by the `las` crate. 0 for synthetic/test points.
+    pub(crate) intensity: u16,
+    /// 1-based return number within the pulse (1 = first return). 0 for synthetic/test points.
+    pub(crate) return_number: u8,
+    /// Total number of returns for the pulse this point belongs to.
+    pub(crate) number_of_returns: u8,
+    /// `(red, green, blue)` if the source file carries a color VLR/point format.
+    pub(crate) rgb: Option<(u16, u16, u16)>,
 }
 
 /// Minimum bytes needed to parse LAS public header (offset to point data at 94-97, number of points at 107-110).
@@ -457,6 +674,30 @@ const LAS_OFFSET_TO_POINT_DATA: usize = 94;
 /// LAS public header: number of point records at bytes 107-110 (u32 LE) for LAS 1.0-1.2.
 const LAS_NUMBER_OF_POINT_RECORDS: usize = 107;
 
+/// Minimum bytes needed for `parse_las_header_full` to also read point format/record
+/// length, the VLR count, and the X/Y/Z scale/offset doubles (ends at byte 178).
+const LAS_HEADER_FULL_BYTES: usize = 179;
+
+/// LAS public header: number of variable length records, bytes 98-101 (u32 LE).
+const LAS_NUMBER_OF_VLRS: usize = 98;
+/// LAS public header: point data record format ID, byte 102 (u8).
+const LAS_POINT_DATA_FORMAT: usize = 102;
+/// LAS public header: point data record length, bytes 103-104 (u16 LE).
+const LAS_POINT_DATA_RECORD_LENGTH: usize = 103;
+/// LAS public header: X/Y/Z scale factors, bytes 131-154 (3 x f64 LE).
+const LAS_SCALE_FACTORS: usize = 131;
+/// LAS public header: X/Y/Z offsets, bytes 155-178 (3 x f64 LE).
+const LAS_OFFSETS: usize = 155;
+/// LAS public header: Max X, Min X, Max Y, Min Y, Max Z, Min Z, bytes 179-226 (6 x f64 LE).
+const LAS_BOUNDS: usize = 179;
+/// Minimum bytes needed to also read the header's bounding box (ends at byte 226).
+const LAS_HEADER_BOUNDS_BYTES: usize = 227;
+
+/// Size in bytes of a LAS Variable Length Record header (the fixed part preceding
+/// `record_length_after_header` bytes of payload): reserved(2) + user_id(16) +
+/// record_id(2) + record_length_after_header(2) + description(32).
+const VLR_HEADER_BYTES: usize = 54;
+
 /// Parsed LAS/LAZ header from a partial buffer (e.g. first 4KB from Range request).
 #[derive(Debug)]
 struct LasHeaderParsed {
@@ -465,6 +706,32 @@ struct LasHeaderParsed {
     /// Total number of point records (for LAS 1.0-1.2; 4-byte field). Used for logging/progress.
     #[allow(dead_code)]
     number_of_points: u64,
+    /// Point Data Record Format ID (0-10), identifies which fields each point record carries.
+    #[allow(dead_code)]
+    point_data_format: u8,
+    /// Size in bytes of a single point record, including any extra bytes beyond the format.
+    #[allow(dead_code)]
+    point_data_record_length: u16,
+    /// Per-axis scale factors applied to the signed integer X/Y/Z stored in each point record.
+    #[allow(dead_code)]
+    scale_factors: (f64, f64, f64),
+    /// Per-axis offsets added after scaling to recover the real-world X/Y/Z coordinate.
+    #[allow(dead_code)]
+    offsets: (f64, f64, f64),
+    /// Variable Length Records found between the end of the public header and
+    /// `offset_to_point_data`. Empty if the buffer didn't reach far enough to read them.
+    #[allow(dead_code)]
+    vlrs: Vec<LasVlr>,
+}
+
+/// A single LAS Variable Length Record header plus its payload bytes.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct LasVlr {
+    user_id: String,
+    record_id: u16,
+    description: String,
+    data: Vec<u8>,
 }
 
 /// Parse LAS/LAZ public header from a buffer (at least 111 bytes).
@@ -490,12 +757,255 @@ fn parse_las_header_from_slice(buf: &[u8]) -> Result<LasHeaderParsed> {
             .try_into()
             .unwrap(),
     ) as u64;
+
+    let (point_data_format, point_data_record_length, scale_factors, offsets, vlrs) =
+        if buf.len() >= LAS_HEADER_FULL_BYTES {
+            let point_data_format = buf[LAS_POINT_DATA_FORMAT] & 0x7F; // top bit flags compression in some LAZ variants
+            let point_data_record_length = u16::from_le_bytes(
+                buf[LAS_POINT_DATA_RECORD_LENGTH..LAS_POINT_DATA_RECORD_LENGTH + 2]
+                    .try_into()
+                    .unwrap(),
+            );
+            let number_of_vlrs = u32::from_le_bytes(
+                buf[LAS_NUMBER_OF_VLRS..LAS_NUMBER_OF_VLRS + 4]
+                    .try_into()
+                    .unwrap(),
+            );
+            let read_f64 = |offset: usize| -> f64 {
+                f64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap())
+            };
+            let scale_factors = (
+                read_f64(LAS_SCALE_FACTORS),
+                read_f64(LAS_SCALE_FACTORS + 8),
+                read_f64(LAS_SCALE_FACTORS + 16),
+            );
+            let offsets = (
+                read_f64(LAS_OFFSETS),
+                read_f64(LAS_OFFSETS + 8),
+                read_f64(LAS_OFFSETS + 16),
+            );
+            let vlrs = parse_vlrs_from_slice(buf, LAS_HEADER_MIN_BYTES.max(227), number_of_vlrs, offset_to_point_data);
+            (point_data_format, point_data_record_length, scale_factors, offsets, vlrs)
+        } else {
+            (0u8, 0u16, (0.0, 0.0, 0.0), (0.0, 0.0, 0.0), Vec::new())
+        };
+
     Ok(LasHeaderParsed {
         offset_to_point_data,
         number_of_points,
+        point_data_format,
+        point_data_record_length,
+        scale_factors,
+        offsets,
+        vlrs,
+    })
+}
+
+/// Walk the Variable Length Records starting at `header_size` (the LAS 1.2+ header is 227
+/// bytes; callers with a shorter buffer pass whatever they have and we just stop early).
+/// Stops as soon as the buffer runs out or `offset_to_point_data` is reached, since VLRs
+/// never extend past the start of point data.
+fn parse_vlrs_from_slice(
+    buf: &[u8],
+    header_size: usize,
+    number_of_vlrs: u32,
+    offset_to_point_data: u32,
+) -> Vec<LasVlr> {
+    let mut vlrs = Vec::new();
+    let mut cursor = header_size;
+    let limit = (offset_to_point_data as usize).min(buf.len());
+
+    for _ in 0..number_of_vlrs {
+        if cursor + VLR_HEADER_BYTES > limit {
+            break;
+        }
+        let user_id = String::from_utf8_lossy(&buf[cursor + 2..cursor + 18])
+            .trim_end_matches('\0')
+            .to_string();
+        let record_id = u16::from_le_bytes(buf[cursor + 18..cursor + 20].try_into().unwrap());
+        let record_length = u16::from_le_bytes(buf[cursor + 20..cursor + 22].try_into().unwrap());
+        let description = String::from_utf8_lossy(&buf[cursor + 22..cursor + 54])
+            .trim_end_matches('\0')
+            .to_string();
+
+        let data_start = cursor + VLR_HEADER_BYTES;
+        let data_end = data_start + record_length as usize;
+        if data_end > limit {
+            break;
+        }
+        vlrs.push(LasVlr {
+            user_id,
+            record_id,
+            description,
+            data: buf[data_start..data_end].to_vec(),
+        });
+
+        cursor = data_end;
+    }
+
+    vlrs
+}
+
+/// WKT (Well-Known Text) Coordinate Reference System VLR: user_id "LASF_Projection",
+/// record_id 2112, payload is an OGC WKT CRS string (LAS 1.4 R14).
+const WKT_VLR_USER_ID: &str = "LASF_Projection";
+const WKT_VLR_RECORD_ID: u16 = 2112;
+
+/// GeoTIFF GeoKeyDirectoryTag VLR: user_id "LASF_Projection", record_id 34735, payload is the
+/// GeoTIFF key directory (array of u16 "keys", LAS 1.0-1.3's CRS representation before WKT).
+const GEOTIFF_KEYS_VLR_USER_ID: &str = "LASF_Projection";
+const GEOTIFF_KEYS_VLR_RECORD_ID: u16 = 34735;
+/// GeoTIFF key IDs that directly name an EPSG code: the projected and geographic CS types.
+const GEOTIFF_KEY_PROJECTED_CS_TYPE: u16 = 3072;
+const GEOTIFF_KEY_GEOGRAPHIC_TYPE: u16 = 2048;
+/// GeoTIFF's sentinel for "this key is present but its value is user-defined", i.e. not a
+/// lookup-able EPSG code.
+const GEOTIFF_USER_DEFINED: u16 = 32767;
+
+/// Read the source EPSG code out of a tile's WKT CRS VLR, if present. WKT nests the CRS's
+/// `AUTHORITY["EPSG","<code>"]` as the last element before the closing brackets, so the
+/// last `AUTHORITY["EPSG","..."]` occurrence in the string names the overall CRS.
+fn source_epsg_from_wkt_vlr(vlrs: &[LasVlr]) -> Option<i32> {
+    let wkt_vlr = vlrs
+        .iter()
+        .find(|v| v.user_id == WKT_VLR_USER_ID && v.record_id == WKT_VLR_RECORD_ID)?;
+    let wkt = String::from_utf8_lossy(&wkt_vlr.data);
+
+    let needle = "AUTHORITY[\"EPSG\",\"";
+    let start = wkt.rfind(needle)? + needle.len();
+    let end = start + wkt[start..].find('"')?;
+    wkt[start..end].parse::<i32>().ok()
+}
+
+/// Read the source EPSG code out of a tile's GeoTIFF GeoKeyDirectoryTag VLR, if present.
+/// The directory is a flat `u16` array: a 4-short header (version/revision/minor/key count)
+/// followed by one 4-short entry per key (`KeyID`, `TIFFTagLocation`, `Count`, `ValueOffset`).
+/// We only resolve keys stored inline (`TIFFTagLocation == 0`, `ValueOffset` is the value
+/// itself), which is how `ProjectedCSTypeGeoKey`/`GeographicTypeGeoKey` carry an EPSG code.
+fn source_epsg_from_geotiff_keys(vlrs: &[LasVlr]) -> Option<i32> {
+    let vlr = vlrs.iter().find(|v| {
+        v.user_id == GEOTIFF_KEYS_VLR_USER_ID && v.record_id == GEOTIFF_KEYS_VLR_RECORD_ID
+    })?;
+    if vlr.data.len() < 8 {
+        return None;
+    }
+    let read_u16 = |i: usize| -> Option<u16> {
+        vlr.data
+            .get(i * 2..i * 2 + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    };
+    let num_keys = read_u16(3)? as usize;
+
+    let mut projected_cs = None;
+    let mut geographic = None;
+    for key_index in 0..num_keys {
+        let base = 4 + key_index * 4;
+        let key_id = read_u16(base)?;
+        let tiff_tag_location = read_u16(base + 1)?;
+        let value = read_u16(base + 3)?;
+        if tiff_tag_location != 0 || value == 0 || value == GEOTIFF_USER_DEFINED {
+            continue;
+        }
+        match key_id {
+            GEOTIFF_KEY_PROJECTED_CS_TYPE => projected_cs = Some(value as i32),
+            GEOTIFF_KEY_GEOGRAPHIC_TYPE => geographic = Some(value as i32),
+            _ => {}
+        }
+    }
+    // A projected CS (if defined) is the CRS points are actually stored in; the geographic
+    // CS type alone only applies to files with no projection (plain lon/lat coordinates).
+    projected_cs.or(geographic)
+}
+
+/// Read the source EPSG code out of a tile's LAS header VLRs: the WKT CRS VLR wins when
+/// present (LAS 1.4's preferred representation), falling back to the older GeoTIFF
+/// GeoKeyDirectoryTag VLR used by LAS 1.0-1.3. Generic PROJ strings with no EPSG-codable
+/// authority (e.g. a bespoke `+proj=...` definition) aren't resolvable to an EPSG code and
+/// are intentionally not supported -- callers fall back to `source_epsg_fallback` instead.
+fn source_epsg_from_vlrs(vlrs: &[LasVlr]) -> Option<i32> {
+    source_epsg_from_wkt_vlr(vlrs).or_else(|| source_epsg_from_geotiff_keys(vlrs))
+}
+
+/// Resolve the EPSG code a tile's points are actually stored in: `detected` (from the
+/// header's own WKT/GeoTIFF VLRs) wins, falling back to `source_epsg_fallback`, falling back
+/// to `default` (the historical assumption that the file is already in `geo_core`'s CRS).
+/// Logs a warning on the last-resort path so a silently wrong assumption doesn't go unnoticed.
+fn resolve_source_epsg(detected: Option<i32>, fallback: Option<i32>, default: i32) -> i32 {
+    detected.or(fallback).unwrap_or_else(|| {
+        eprintln!(
+            "  ⚠️ No CRS found in LAS/LAZ header (no WKT or GeoTIFF key VLR) and no \
+             source_epsg_fallback configured; assuming EPSG:{}",
+            default
+        );
+        default
     })
 }
 
+/// Reproject a bounding box's corners from `from_epsg` to `to_epsg`. No-op if the codes match.
+fn transform_bbox(
+    bbox: (f64, f64, f64, f64),
+    from_epsg: i32,
+    to_epsg: i32,
+) -> Result<(f64, f64, f64, f64)> {
+    if from_epsg == to_epsg {
+        return Ok(bbox);
+    }
+    let (x_min, y_min, x_max, y_max) = bbox;
+    let (min_x, min_y) = GeoCore::transform_coords(from_epsg, to_epsg, x_min, y_min)
+        .context("Failed to transform bbox min corner")?;
+    let (max_x, max_y) = GeoCore::transform_coords(from_epsg, to_epsg, x_max, y_max)
+        .context("Failed to transform bbox max corner")?;
+    Ok((min_x, min_y, max_x, max_y))
+}
+
+/// Build the field map `Lidar::filter` evaluates a `WhereExpr` against for one point.
+fn lidar_point_props(point: &LidarPoint) -> serde_json::Map<String, serde_json::Value> {
+    let mut props = serde_json::Map::new();
+    props.insert("x".to_string(), serde_json::json!(point.x));
+    props.insert("y".to_string(), serde_json::json!(point.y));
+    props.insert("z".to_string(), serde_json::json!(point.z));
+    props.insert(
+        "classification".to_string(),
+        serde_json::json!(point.classification),
+    );
+    props.insert("intensity".to_string(), serde_json::json!(point.intensity));
+    props.insert(
+        "return_number".to_string(),
+        serde_json::json!(point.return_number),
+    );
+    props.insert(
+        "number_of_returns".to_string(),
+        serde_json::json!(point.number_of_returns),
+    );
+    props
+}
+
+/// Reproject every point's X/Y in place from `from_epsg` to `to_epsg`, leaving Z untouched.
+/// No-op if the codes match. Builds a single `Proj` transform and reuses it across all
+/// points rather than recreating one per point.
+fn reproject_points_in_place(
+    points: &mut [LidarPoint],
+    from_epsg: i32,
+    to_epsg: i32,
+) -> Result<()> {
+    if from_epsg == to_epsg {
+        return Ok(());
+    }
+    let from_crs = format!("EPSG:{}", from_epsg);
+    let to_crs = format!("EPSG:{}", to_epsg);
+    let transformer = Proj::new_known_crs(&from_crs, &to_crs, None)
+        .context("Failed to create coordinate transformer for point reprojection")?;
+
+    for point in points.iter_mut() {
+        let (x, y) = transformer
+            .convert((point.x, point.y))
+            .context("Failed to reproject LiDAR point")?;
+        point.x = x;
+        point.y = y;
+    }
+    Ok(())
+}
+
 /// Wrapper around memory-mapped file that implements Read + Seek for las::Reader.
 /// Used for large cached LAZ files when feature "laz-memmap" is enabled.
 #[cfg(feature = "laz-memmap")]
@@ -548,6 +1058,34 @@ impl std::io::Seek for MmapReader {
 #[cfg(feature = "laz-memmap")]
 const LAZ_MMAP_THRESHOLD_BYTES: u64 = 50 * 1024 * 1024;
 
+/// Size of each Range request when resuming an interrupted download (8 MiB).
+const RESUME_CHUNK_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Default number of points buffered before `process_lidar_points_streaming` folds them into
+/// the DSM/DTM grid accumulators.
+const DEFAULT_INGEST_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Default number of rasterized tiles `sample_elevation`/`sample_elevation_batch` keep in
+/// their `TileRasterCache`.
+const DEFAULT_ELEVATION_CACHE_CAPACITY: usize = 8;
+
+/// Default neighbourhood size for `remove_lof_outliers`.
+const DEFAULT_LOF_K: usize = 8;
+
+/// Default LOF score above which a point is dropped by `remove_lof_outliers`.
+const DEFAULT_LOF_THRESHOLD: f64 = 2.0;
+
+/// Hex-encoded SHA-256 digest of `data`, used for download integrity sidecars.
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 /// Download a byte range of a LAZ file via HTTP Range request (blocking).
 /// Returns `Ok(data)` only when server responds with 206 Partial Content.
 /// Returns `Err` on 200 (Range not supported) or other failure so caller can fall back to full GET.
@@ -607,6 +1145,310 @@ fn head_content_length(client: &reqwest::blocking::Client, url: &str) -> Option<
     response.content_length()
 }
 
+// ============================================================================
+// POINT STORE (pluggable object-storage backends)
+// ============================================================================
+
+/// Where a point-cloud URL's bytes actually come from. Lets the LAZ/COPC readers stay
+/// agnostic to `http(s)://`, `s3://`, `gs://`, `az://`, and plain filesystem paths --
+/// `store_for_url` picks the right implementation from the URL's scheme, and the
+/// existing retry/verify/caching logic (`download_with_verification`) layers on top
+/// instead of calling a specific client directly.
+trait PointStore {
+    /// Size of the object in bytes, if the backend can report one without fetching the body.
+    fn head_len(&self, url: &str) -> Result<u64>;
+    /// Fetch the whole object.
+    fn get_all(&self, url: &str) -> Result<Vec<u8>>;
+    /// Fetch `len` bytes starting at `start`.
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>>;
+}
+
+/// `http(s)://` backend; wraps a blocking `reqwest` client and reuses the Range/retry
+/// helpers above.
+#[cfg(feature = "reqwest")]
+struct HttpPointStore {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpPointStore {
+    fn new() -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .connect_timeout(std::time::Duration::from_secs(30))
+            .timeout(std::time::Duration::from_secs(600))
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(HttpPointStore { client })
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl PointStore for HttpPointStore {
+    fn head_len(&self, url: &str) -> Result<u64> {
+        head_content_length(&self.client, url)
+            .ok_or_else(|| anyhow::anyhow!("HEAD request failed or no Content-Length for {}", url))
+    }
+
+    fn get_all(&self, url: &str) -> Result<Vec<u8>> {
+        use std::io::Read;
+        let mut retries = 3;
+        loop {
+            let response = match self.client.get(url).send() {
+                Ok(r) => r,
+                Err(e) => {
+                    retries -= 1;
+                    if retries > 0 {
+                        std::thread::sleep(std::time::Duration::from_secs(2));
+                        continue;
+                    }
+                    return Err(anyhow::anyhow!(
+                        "Failed to download {} after retries: {}",
+                        url,
+                        e
+                    ));
+                }
+            };
+            if !response.status().is_success() {
+                return Err(anyhow::anyhow!(
+                    "HTTP {} when downloading {}",
+                    response.status(),
+                    url
+                ));
+            }
+            let mut data = Vec::new();
+            let mut response = response;
+            let mut buffer = [0u8; 65536];
+            loop {
+                match response.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(n) => data.extend_from_slice(&buffer[..n]),
+                    Err(e) => {
+                        retries -= 1;
+                        if retries > 0 {
+                            std::thread::sleep(std::time::Duration::from_secs(2));
+                            break;
+                        }
+                        return Err(anyhow::anyhow!("Failed to read from {}: {}", url, e));
+                    }
+                }
+            }
+            if !data.is_empty() {
+                return Ok(data);
+            }
+            retries -= 1;
+            if retries == 0 {
+                return Err(anyhow::anyhow!("Empty response from {}", url));
+            }
+        }
+    }
+
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        download_partial_laz(&self.client, url, start, start + len - 1, None::<fn(u64)>)
+    }
+}
+
+/// Plain filesystem path backend, for `file://` URLs and bare local paths in a manifest.
+struct LocalPointStore;
+
+impl PointStore for LocalPointStore {
+    fn head_len(&self, url: &str) -> Result<u64> {
+        let path = local_path_from_url(url);
+        Ok(std::fs::metadata(&path)
+            .with_context(|| format!("Failed to stat {:?}", path))?
+            .len())
+    }
+
+    fn get_all(&self, url: &str) -> Result<Vec<u8>> {
+        let path = local_path_from_url(url);
+        std::fs::read(&path).with_context(|| format!("Failed to read {:?}", path))
+    }
+
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        use std::io::{Read, Seek, SeekFrom};
+        let path = local_path_from_url(url);
+        let mut file =
+            std::fs::File::open(&path).with_context(|| format!("Failed to open {:?}", path))?;
+        file.seek(SeekFrom::Start(start))
+            .with_context(|| format!("Failed to seek {:?}", path))?;
+        let mut buf = vec![0u8; len as usize];
+        let n = file
+            .read(&mut buf)
+            .with_context(|| format!("Failed to read range from {:?}", path))?;
+        buf.truncate(n);
+        Ok(buf)
+    }
+}
+
+/// Strip a `file://` prefix, if present, leaving bare paths untouched.
+fn local_path_from_url(url: &str) -> PathBuf {
+    PathBuf::from(url.strip_prefix("file://").unwrap_or(url))
+}
+
+/// `s3://bucket/key` backend. Delegates to `HttpPointStore` against the bucket's public
+/// virtual-hosted-style endpoint (`https://<bucket>.s3.<region>.amazonaws.com/<key>`),
+/// with region from `AWS_DEFAULT_REGION` (falls back to `us-east-1`). Covers public
+/// buckets and pre-signed `s3://` URLs whose query string is passed through unchanged;
+/// it does not perform SigV4 request signing for private buckets.
+#[cfg(all(feature = "object-store-s3", feature = "reqwest"))]
+struct S3PointStore {
+    inner: HttpPointStore,
+}
+
+#[cfg(all(feature = "object-store-s3", feature = "reqwest"))]
+impl S3PointStore {
+    fn new() -> Result<Self> {
+        Ok(S3PointStore {
+            inner: HttpPointStore::new()?,
+        })
+    }
+
+    fn https_url(url: &str) -> Result<String> {
+        let rest = url.strip_prefix("s3://").context("Expected s3:// URL")?;
+        let (bucket, key) = rest.split_once('/').context("s3:// URL missing object key")?;
+        let region =
+            std::env::var("AWS_DEFAULT_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+        Ok(format!("https://{}.s3.{}.amazonaws.com/{}", bucket, region, key))
+    }
+}
+
+#[cfg(all(feature = "object-store-s3", feature = "reqwest"))]
+impl PointStore for S3PointStore {
+    fn head_len(&self, url: &str) -> Result<u64> {
+        self.inner.head_len(&Self::https_url(url)?)
+    }
+    fn get_all(&self, url: &str) -> Result<Vec<u8>> {
+        self.inner.get_all(&Self::https_url(url)?)
+    }
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        self.inner.get_range(&Self::https_url(url)?, start, len)
+    }
+}
+
+/// `gs://bucket/key` backend. Delegates to `HttpPointStore` against
+/// `https://storage.googleapis.com/<bucket>/<key>`; covers publicly readable objects.
+#[cfg(all(feature = "object-store-gcs", feature = "reqwest"))]
+struct GcsPointStore {
+    inner: HttpPointStore,
+}
+
+#[cfg(all(feature = "object-store-gcs", feature = "reqwest"))]
+impl GcsPointStore {
+    fn new() -> Result<Self> {
+        Ok(GcsPointStore {
+            inner: HttpPointStore::new()?,
+        })
+    }
+
+    fn https_url(url: &str) -> Result<String> {
+        let rest = url.strip_prefix("gs://").context("Expected gs:// URL")?;
+        let (bucket, object) = rest.split_once('/').context("gs:// URL missing object key")?;
+        Ok(format!("https://storage.googleapis.com/{}/{}", bucket, object))
+    }
+}
+
+#[cfg(all(feature = "object-store-gcs", feature = "reqwest"))]
+impl PointStore for GcsPointStore {
+    fn head_len(&self, url: &str) -> Result<u64> {
+        self.inner.head_len(&Self::https_url(url)?)
+    }
+    fn get_all(&self, url: &str) -> Result<Vec<u8>> {
+        self.inner.get_all(&Self::https_url(url)?)
+    }
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        self.inner.get_range(&Self::https_url(url)?, start, len)
+    }
+}
+
+/// `az://account/container/blob` backend. Delegates to `HttpPointStore` against
+/// `https://<account>.blob.core.windows.net/<container>/<blob>`, appending a SAS token
+/// from `AZURE_STORAGE_SAS_TOKEN` when the URL doesn't already carry a query string.
+#[cfg(all(feature = "object-store-azure", feature = "reqwest"))]
+struct AzurePointStore {
+    inner: HttpPointStore,
+}
+
+#[cfg(all(feature = "object-store-azure", feature = "reqwest"))]
+impl AzurePointStore {
+    fn new() -> Result<Self> {
+        Ok(AzurePointStore {
+            inner: HttpPointStore::new()?,
+        })
+    }
+
+    fn https_url(url: &str) -> Result<String> {
+        let rest = url.strip_prefix("az://").context("Expected az:// URL")?;
+        let mut parts = rest.splitn(2, '/');
+        let account = parts.next().context("az:// URL missing storage account")?;
+        let path = parts
+            .next()
+            .context("az:// URL missing container/blob path")?;
+        let mut https = format!("https://{}.blob.core.windows.net/{}", account, path);
+        if !https.contains('?') {
+            if let Ok(sas) = std::env::var("AZURE_STORAGE_SAS_TOKEN") {
+                https.push('?');
+                https.push_str(sas.trim_start_matches('?'));
+            }
+        }
+        Ok(https)
+    }
+}
+
+#[cfg(all(feature = "object-store-azure", feature = "reqwest"))]
+impl PointStore for AzurePointStore {
+    fn head_len(&self, url: &str) -> Result<u64> {
+        self.inner.head_len(&Self::https_url(url)?)
+    }
+    fn get_all(&self, url: &str) -> Result<Vec<u8>> {
+        self.inner.get_all(&Self::https_url(url)?)
+    }
+    fn get_range(&self, url: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        self.inner.get_range(&Self::https_url(url)?, start, len)
+    }
+}
+
+/// Pick the `PointStore` for a URL's scheme: `s3://`, `gs://`, `az://`, `http(s)://`, or
+/// a plain/`file://` local path. A cloud scheme without its `object-store-*` feature
+/// enabled is a clear error rather than a silent fallback.
+fn store_for_url(url: &str) -> Result<Box<dyn PointStore>> {
+    if url.starts_with("s3://") {
+        #[cfg(all(feature = "object-store-s3", feature = "reqwest"))]
+        return Ok(Box::new(S3PointStore::new()?));
+        #[cfg(not(all(feature = "object-store-s3", feature = "reqwest")))]
+        anyhow::bail!(
+            "s3:// URLs require the \"object-store-s3\" feature (and \"reqwest\"): {}",
+            url
+        );
+    }
+    if url.starts_with("gs://") {
+        #[cfg(all(feature = "object-store-gcs", feature = "reqwest"))]
+        return Ok(Box::new(GcsPointStore::new()?));
+        #[cfg(not(all(feature = "object-store-gcs", feature = "reqwest")))]
+        anyhow::bail!(
+            "gs:// URLs require the \"object-store-gcs\" feature (and \"reqwest\"): {}",
+            url
+        );
+    }
+    if url.starts_with("az://") {
+        #[cfg(all(feature = "object-store-azure", feature = "reqwest"))]
+        return Ok(Box::new(AzurePointStore::new()?));
+        #[cfg(not(all(feature = "object-store-azure", feature = "reqwest")))]
+        anyhow::bail!(
+            "az:// URLs require the \"object-store-azure\" feature (and \"reqwest\"): {}",
+            url
+        );
+    }
+    if url.starts_with("http://") || url.starts_with("https://") {
+        #[cfg(feature = "reqwest")]
+        return Ok(Box::new(HttpPointStore::new()?));
+        #[cfg(not(feature = "reqwest"))]
+        anyhow::bail!("http(s):// URLs require the \"reqwest\" feature: {}", url);
+    }
+    Ok(Box::new(LocalPointStore))
+}
+
 /// Map las classification enum to u8 (for parallel conversion).
 #[inline]
 fn classification_to_u8(c: &las::point::Classification) -> u8 {
@@ -625,23 +1467,751 @@ fn classification_to_u8(c: &las::point::Classification) -> u8 {
     }
 }
 
+/// True if two axis-aligned XY bboxes, each `(min_x, min_y, max_x, max_y)`, overlap at all.
+/// Used to skip a manifest tile entirely when its header bounds don't touch the active bbox.
+#[inline]
+fn bbox_overlaps(a: (f64, f64, f64, f64), b: (f64, f64, f64, f64)) -> bool {
+    !(a.2 < b.0 || a.0 > b.2 || a.3 < b.1 || a.1 > b.3)
+}
+
 /// Processed raster data
 struct ProcessedRasters {
     dsm: Vec<Vec<f64>>, // Digital Surface Model
     dtm: Vec<Vec<f64>>, // Digital Terrain Model
     chm: Vec<Vec<f64>>, // Canopy Height Model
+    /// Point count per cell. `Some` only when `RasterBandSelection::density` was requested.
+    density: Option<Vec<Vec<f64>>>,
+    /// Mean intensity of first returns per cell, `NaN` where no first return fell in the
+    /// cell. `Some` only when `RasterBandSelection::intensity` was requested.
+    intensity: Option<Vec<Vec<f64>>>,
+    /// Min/max/mean/stddev/range elevation per cell, `NaN` where the cell has no points.
+    /// `Some` only when `RasterBandSelection::elevation_stats` or `::range` was requested.
+    elevation_stats: Option<ElevationStatsRasters>,
+    /// Median Z per cell, `NaN` where the cell has no points. `Some` only when
+    /// `RasterBandSelection::median_elevation` was requested.
+    median_elevation: Option<Vec<Vec<f64>>>,
     width: usize,
     height: usize,
     transform: [f64; 6], // GDAL-style transform
 }
 
-/// Result of COPC entry reading for statistics
-#[cfg(feature = "lidar-copc")]
-struct CopcReadResult {
-    points: Vec<LidarPoint>,
-    entries_processed: usize,
-    entries_success: usize,
+/// Per-cell elevation statistics computed over every point (not just ground/surface) that
+/// falls in each cell. Grids use `NaN` for cells with no points. `range` (max - min) is
+/// always populated alongside the rest since it shares their accumulators, even if only
+/// `RasterBandSelection::range` (not `::elevation_stats`) was requested.
+struct ElevationStatsRasters {
+    min: Vec<Vec<f64>>,
+    max: Vec<Vec<f64>>,
+    mean: Vec<Vec<f64>>,
+    stddev: Vec<Vec<f64>>,
+    range: Vec<Vec<f64>>,
+}
+
+/// Tunable parameters for `Lidar::classify_ground_pmf`'s progressive morphological filter
+/// (Zhang et al., 2003). `slope` and `dh0` control how much a cell is allowed to rise above
+/// the opened surface before it's considered non-ground, scaled by how much the window grew
+/// since the previous iteration; `dh_max` caps that tolerance; `max_window` (in grid cells)
+/// bounds how large the structuring element grows before the filter stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PmfParams {
+    /// Per-cell elevation tolerance added per unit of window growth (ground distance).
+    pub slope: f64,
+    /// Elevation tolerance at the smallest window size.
+    pub dh0: f64,
+    /// Upper bound on the elevation tolerance, regardless of window size.
+    pub dh_max: f64,
+    /// Largest structuring-element window size, in grid cells, the filter grows to.
+    pub max_window: usize,
+}
+
+impl Default for PmfParams {
+    /// Conservative defaults for urban terrain, matching the filter's original constants.
+    fn default() -> Self {
+        PmfParams {
+            slope: 0.3,
+            dh0: 0.5,
+            dh_max: 3.0,
+            max_window: 16,
+        }
+    }
+}
+
+/// Tunable parameters for `idw_fill_dtm`'s inverse-distance-weighted DTM gap filling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdwParams {
+    /// Maximum search radius, in grid cells, to look for populated ground cells.
+    pub radius_cells: usize,
+    /// Number of closest populated cells to weight together once found.
+    pub n_neighbors: usize,
+    /// Distance exponent `p` in `Σ(z_i / d_i^p) / Σ(1 / d_i^p)`.
+    pub power: f64,
+}
+
+impl Default for IdwParams {
+    fn default() -> Self {
+        IdwParams {
+            radius_cells: 5,
+            n_neighbors: 8,
+            power: 2.0,
+        }
+    }
+}
+
+/// Fill empty (`NEG_INFINITY`) cells in `grid` by inverse-distance-weighted interpolation
+/// over the closest populated cells: for each empty cell, the search radius expands ring by
+/// ring (up to `params.radius_cells`) until at least `params.n_neighbors` populated cells are
+/// in view, then the closest `params.n_neighbors` of them are averaged as
+/// `Σ(z_i / d_i^p) / Σ(1 / d_i^p)`. A cell with no populated neighbor within
+/// `params.radius_cells` is left `NaN` rather than defaulting to 0.0, so callers can tell
+/// "interpolated" apart from "no data nearby".
+fn idw_fill_dtm(grid: &[Vec<f64>], width: usize, height: usize, resolution: f64, params: &IdwParams) -> Vec<Vec<f64>> {
+    let mut filled = grid.to_vec();
+
+    for row in 0..height {
+        for col in 0..width {
+            if grid[row][col] != f64::NEG_INFINITY {
+                continue;
+            }
+
+            let mut candidates: Vec<(f64, f64)> = Vec::new(); // (distance, z)
+            let mut radius = 1usize;
+            while radius <= params.radius_cells.max(1) {
+                candidates.clear();
+
+                let r_min = row.saturating_sub(radius);
+                let r_max = (row + radius).min(height.saturating_sub(1));
+                let c_min = col.saturating_sub(radius);
+                let c_max = (col + radius).min(width.saturating_sub(1));
+
+                for r in r_min..=r_max {
+                    for c in c_min..=c_max {
+                        let val = grid[r][c];
+                        if val == f64::NEG_INFINITY {
+                            continue;
+                        }
+                        let dr = (r as f64 - row as f64) * resolution;
+                        let dc = (c as f64 - col as f64) * resolution;
+                        let dist = (dr * dr + dc * dc).sqrt();
+                        if dist > 0.0 {
+                            candidates.push((dist, val));
+                        }
+                    }
+                }
+
+                if candidates.len() >= params.n_neighbors || radius >= params.radius_cells.max(1) {
+                    break;
+                }
+                radius += 1;
+            }
+
+            if candidates.is_empty() {
+                continue;
+            }
+
+            candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            candidates.truncate(params.n_neighbors.max(1));
+
+            let mut weight_sum = 0.0;
+            let mut value_sum = 0.0;
+            for (dist, val) in &candidates {
+                let weight = 1.0 / dist.powf(params.power);
+                weight_sum += weight;
+                value_sum += weight * val;
+            }
+
+            if weight_sum > 0.0 {
+                filled[row][col] = value_sum / weight_sum;
+            }
+        }
+    }
+
+    // Any cell that never found a populated neighbor within `radius_cells` stays
+    // `NEG_INFINITY` above; normalize it to `NaN`, the no-data sentinel `to_tif` expects.
+    for row in filled.iter_mut() {
+        for val in row.iter_mut() {
+            if *val == f64::NEG_INFINITY {
+                *val = f64::NAN;
+            }
+        }
+    }
+
+    filled
+}
+
+/// GDAL creation options for `Lidar::to_tif`'s GeoTIFF output. Defaults reproduce the
+/// historical uncompressed, untiled, strip-organized output; set `compress`/`tiled`/
+/// `predictor`/`bigtiff` to bring the write path in line with standard GDAL raster-writing
+/// practice (compact files that are also usable in web/tiled viewers).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoTiffOptions {
+    /// GTiff `COMPRESS` creation option (e.g. `"DEFLATE"`, `"LZW"`). `None` writes uncompressed.
+    pub compress: Option<String>,
+    /// Sets `TILED=YES` so the GeoTIFF is internally tiled rather than strip-organized.
+    pub tiled: bool,
+    /// GTiff `PREDICTOR` creation option (2 = horizontal differencing, 3 = floating point).
+    /// Only takes effect alongside `compress`.
+    pub predictor: Option<u8>,
+    /// GTiff `BIGTIFF` creation option (e.g. `"IF_SAFER"`, `"YES"`, `"NO"`).
+    pub bigtiff: Option<String>,
+    /// Overview levels to build after writing (e.g. `[2, 4, 8]`). Empty skips overview
+    /// building.
+    pub overview_levels: Vec<i32>,
+    /// Resampling method used when building overviews (e.g. `"NEAREST"`, `"AVERAGE"`).
+    pub overview_resampling: String,
+    /// Per-band no-data value overrides, keyed by the band name `to_tif` writes (e.g.
+    /// `"density"`, `"elevation range"`, `"median elevation"`). A name with no entry here
+    /// falls back to `to_tif`'s hardcoded default for that band.
+    pub band_nodata_overrides: HashMap<String, f64>,
+}
+
+impl Default for GeoTiffOptions {
+    /// Matches the write path's behavior before creation options existed: uncompressed,
+    /// untiled, no overviews.
+    fn default() -> Self {
+        GeoTiffOptions {
+            compress: None,
+            tiled: false,
+            predictor: None,
+            bigtiff: None,
+            overview_levels: Vec::new(),
+            overview_resampling: "NEAREST".to_string(),
+            band_nodata_overrides: HashMap::new(),
+        }
+    }
+}
+
+/// Selects which raster products `process_lidar_points` computes and `to_tif` writes out.
+/// `dsm`/`dtm`/`chm` default on, matching the historical fixed 3-band output; the others
+/// default off since they cost an extra per-cell accumulator pass and aren't needed by most
+/// callers. Only computed by the non-streaming path -- see `process_lidar_points_streaming`'s
+/// doc comment for why the streaming ingest path doesn't synthesize them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterBandSelection {
+    /// Digital Surface Model: max Z per cell over all points.
+    pub dsm: bool,
+    /// Digital Terrain Model: max Z per cell over ground points, IDW-filled.
+    pub dtm: bool,
+    /// Canopy Height Model: DSM - DTM.
+    pub chm: bool,
+    /// Point count per cell.
+    pub density: bool,
+    /// Mean intensity of first returns per cell.
+    pub intensity: bool,
+    /// Min/max/mean/stddev elevation per cell, over all points in the cell.
+    pub elevation_stats: bool,
+    /// Range (max - min) elevation per cell, over all points in the cell. Shares the same
+    /// min/max accumulators as `elevation_stats`, so requesting either computes both for free.
+    pub range: bool,
+    /// Median Z per cell, over all points in the cell. Unlike the other elevation stats this
+    /// keeps every point's Z per cell (not just running sums) until the grid pass finishes,
+    /// so it's gated by its own flag rather than folded into `elevation_stats`.
+    pub median_elevation: bool,
+}
+
+impl Default for RasterBandSelection {
+    fn default() -> Self {
+        RasterBandSelection {
+            dsm: true,
+            dtm: true,
+            chm: true,
+            density: false,
+            intensity: false,
+            elevation_stats: false,
+            range: false,
+            median_elevation: false,
+        }
+    }
+}
+
+/// DSM/DTM/CHM elevation at a single query point, returned by `Lidar::sample_elevation` and
+/// `Lidar::sample_elevation_batch`. Any field is `None` if that band has no data at the
+/// queried cell (e.g. a DTM gap IDW couldn't fill, or a point outside the CHM's coverage).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElevationSample {
+    pub dsm: Option<f64>,
+    pub dtm: Option<f64>,
+    pub chm: Option<f64>,
+}
+
+/// Bounded least-recently-used cache of rasterized tiles, keyed by the source tile URL (or
+/// local path) that produced them. Backs `Lidar::sample_elevation` so repeated queries over
+/// the same or nearby tiles don't re-download and re-rasterize. `order` lists keys from
+/// least- to most-recently-used.
+struct TileRasterCache {
+    capacity: usize,
+    order: Vec<String>,
+    entries: HashMap<String, Arc<ProcessedRasters>>,
+}
+
+impl TileRasterCache {
+    fn new(capacity: usize) -> Self {
+        TileRasterCache {
+            capacity: capacity.max(1),
+            order: Vec::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<Arc<ProcessedRasters>> {
+        let rasters = self.entries.get(key).cloned()?;
+        self.touch(key);
+        Some(rasters)
+    }
+
+    fn insert(&mut self, key: String, rasters: Arc<ProcessedRasters>) {
+        if self.entries.insert(key.clone(), rasters).is_none() {
+            self.order.push(key.clone());
+            if self.order.len() > self.capacity {
+                let evicted = self.order.remove(0);
+                self.entries.remove(&evicted);
+            }
+        }
+        self.touch(&key);
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let k = self.order.remove(pos);
+            self.order.push(k);
+        }
+    }
+}
+
+/// Grayscale morphological opening (erosion then dilation with a square structuring
+/// element of side `2 * window + 1`) over a grid that may contain `NaN` holes. Used by
+/// `Lidar::classify_ground_pmf` to approximate the "lowest local surface" at each scale.
+fn morphological_open(grid: &[Vec<f64>], width: usize, height: usize, window: usize) -> Vec<Vec<f64>> {
+    let eroded = morphological_extreme(grid, width, height, window, true);
+    morphological_extreme(&eroded, width, height, window, false)
+}
+
+/// Per-cell min (`take_min = true`, erosion) or max (`take_min = false`, dilation) over a
+/// `(2*window+1)^2` neighbourhood, ignoring `NaN` holes and leaving a cell `NaN` only if
+/// every neighbour is also `NaN`.
+fn morphological_extreme(
+    grid: &[Vec<f64>],
+    width: usize,
+    height: usize,
+    window: usize,
+    take_min: bool,
+) -> Vec<Vec<f64>> {
+    let w = window as i32;
+    let mut out = vec![vec![f64::NAN; width]; height];
+    for row in 0..height {
+        for col in 0..width {
+            let mut best = f64::NAN;
+            for dr in -w..=w {
+                let r = row as i32 + dr;
+                if r < 0 || r >= height as i32 {
+                    continue;
+                }
+                for dc in -w..=w {
+                    let c = col as i32 + dc;
+                    if c < 0 || c >= width as i32 {
+                        continue;
+                    }
+                    let value = grid[r as usize][c as usize];
+                    if value.is_nan() {
+                        continue;
+                    }
+                    if best.is_nan()
+                        || (take_min && value < best)
+                        || (!take_min && value > best)
+                    {
+                        best = value;
+                    }
+                }
+            }
+            out[row][col] = best;
+        }
+    }
+    out
+}
+
+// ============================================================================
+// RIGID TRANSFORM / ICP (3x3 matrix helpers, no external linear algebra crate)
+// ============================================================================
+
+/// 3x3 identity matrix.
+fn identity3() -> [[f64; 3]; 3] {
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+}
+
+fn mat3_transpose(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            out[j][i] = m[i][j];
+        }
+    }
+    out
+}
+
+fn mat3_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            out[i][j] = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+fn mat3_vec3(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+fn mat3_det(m: &[[f64; 3]; 3]) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Cyclic Jacobi eigenvalue algorithm for a symmetric 3x3 matrix. Returns the eigenvectors
+/// as the columns of a rotation matrix alongside their eigenvalues (not sorted). Converges
+/// in a handful of sweeps for 3x3 inputs, which is all `svd3` needs.
+fn jacobi_eigen_symmetric3(a_in: [[f64; 3]; 3]) -> ([[f64; 3]; 3], [f64; 3]) {
+    let mut a = a_in;
+    let mut v = identity3();
+
+    for _ in 0..50 {
+        let off = (a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2]).sqrt();
+        if off < 1e-14 {
+            break;
+        }
+        for (p, q) in [(0usize, 1usize), (0, 2), (1, 2)] {
+            if a[p][q].abs() < 1e-300 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            let (app, aqq, apq) = (a[p][p], a[q][q], a[p][q]);
+            a[p][p] = c * c * app - 2.0 * s * c * apq + s * s * aqq;
+            a[q][q] = s * s * app + 2.0 * s * c * apq + c * c * aqq;
+            a[p][q] = 0.0;
+            a[q][p] = 0.0;
+            for k in 0..3 {
+                if k != p && k != q {
+                    let akp = a[k][p];
+                    let akq = a[k][q];
+                    a[k][p] = c * akp - s * akq;
+                    a[p][k] = a[k][p];
+                    a[k][q] = s * akp + c * akq;
+                    a[q][k] = a[k][q];
+                }
+            }
+            for k in 0..3 {
+                let vkp = v[k][p];
+                let vkq = v[k][q];
+                v[k][p] = c * vkp - s * vkq;
+                v[k][q] = s * vkp + c * vkq;
+            }
+        }
+    }
+
+    ([v[0], v[1], v[2]], [a[0][0], a[1][1], a[2][2]])
+}
+
+/// Fill columns of `m` whose singular value is ~0 with an orthonormal complement of the
+/// already-filled columns (Gram-Schmidt against the standard basis), so a degenerate
+/// correspondence set still yields a valid orthogonal `U` instead of a singular one.
+fn orthonormalize_columns(m: &mut [[f64; 3]; 3], singular: &[f64; 3]) {
+    let mut filled: Vec<usize> = (0..3).filter(|&c| singular[c] > 1e-12).collect();
+    for col in 0..3 {
+        if singular[col] > 1e-12 {
+            continue;
+        }
+        let mut candidate = [0.0; 3];
+        for axis in 0..3 {
+            let mut e = [0.0; 3];
+            e[axis] = 1.0;
+            for &f in &filled {
+                let fcol = [m[0][f], m[1][f], m[2][f]];
+                let dot = e[0] * fcol[0] + e[1] * fcol[1] + e[2] * fcol[2];
+                for k in 0..3 {
+                    e[k] -= dot * fcol[k];
+                }
+            }
+            let norm = (e[0] * e[0] + e[1] * e[1] + e[2] * e[2]).sqrt();
+            if norm > 1e-6 {
+                candidate = [e[0] / norm, e[1] / norm, e[2] / norm];
+                break;
+            }
+        }
+        for row in 0..3 {
+            m[row][col] = candidate[row];
+        }
+        filled.push(col);
+    }
+}
+
+/// Singular value decomposition of a 3x3 matrix `h = u * diag(singular) * v^T`, via the
+/// eigendecomposition of `h^T * h` (symmetric, so `jacobi_eigen_symmetric3` applies). `u`
+/// and `v` are orthonormal and `singular` is sorted descending.
+fn svd3(h: &[[f64; 3]; 3]) -> ([[f64; 3]; 3], [f64; 3], [[f64; 3]; 3]) {
+    let hth = mat3_mul(&mat3_transpose(h), h);
+    let (v_unsorted, eigenvalues) = jacobi_eigen_symmetric3(hth);
+
+    let mut order = [0usize, 1, 2];
+    order.sort_by(|&a, &b| eigenvalues[b].partial_cmp(&eigenvalues[a]).unwrap());
+
+    let mut v = [[0.0; 3]; 3];
+    let mut singular = [0.0; 3];
+    for (new_col, &old_col) in order.iter().enumerate() {
+        singular[new_col] = eigenvalues[old_col].max(0.0).sqrt();
+        for row in 0..3 {
+            v[row][new_col] = v_unsorted[row][old_col];
+        }
+    }
+
+    let mut u = [[0.0; 3]; 3];
+    for col in 0..3 {
+        if singular[col] > 1e-12 {
+            let v_col = [v[0][col], v[1][col], v[2][col]];
+            let hv = mat3_vec3(h, v_col);
+            for row in 0..3 {
+                u[row][col] = hv[row] / singular[col];
+            }
+        }
+    }
+    orthonormalize_columns(&mut u, &singular);
+
+    (u, singular, v)
+}
+
+/// Solve the rigid transform `R, t` that best maps `moving` onto `fixed` for a set of
+/// point correspondences (Kabsch/Umeyama algorithm): cross-covariance of the mean-centered
+/// pairs, SVD, `R = V * U^T` (flipping the last column of `V` if that would give a
+/// reflection rather than a rotation), `t = centroid_fixed - R * centroid_moving`.
+fn solve_rigid_transform(pairs: &[((f64, f64, f64), (f64, f64, f64))]) -> ([[f64; 3]; 3], [f64; 3]) {
+    let n = pairs.len() as f64;
+    let mut centroid_moving = [0.0; 3];
+    let mut centroid_fixed = [0.0; 3];
+    for &(m, f) in pairs {
+        centroid_moving[0] += m.0;
+        centroid_moving[1] += m.1;
+        centroid_moving[2] += m.2;
+        centroid_fixed[0] += f.0;
+        centroid_fixed[1] += f.1;
+        centroid_fixed[2] += f.2;
+    }
+    for c in centroid_moving.iter_mut().chain(centroid_fixed.iter_mut()) {
+        *c /= n;
+    }
+
+    let mut h = [[0.0; 3]; 3];
+    for &(m, f) in pairs {
+        let mc = [
+            m.0 - centroid_moving[0],
+            m.1 - centroid_moving[1],
+            m.2 - centroid_moving[2],
+        ];
+        let fc = [
+            f.0 - centroid_fixed[0],
+            f.1 - centroid_fixed[1],
+            f.2 - centroid_fixed[2],
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                h[i][j] += mc[i] * fc[j];
+            }
+        }
+    }
+
+    let (u, _singular, v) = svd3(&h);
+    let mut rotation = mat3_mul(&v, &mat3_transpose(&u));
+    if mat3_det(&rotation) < 0.0 {
+        let mut v_fixed = v;
+        for row in v_fixed.iter_mut() {
+            row[2] = -row[2];
+        }
+        rotation = mat3_mul(&v_fixed, &mat3_transpose(&u));
+    }
+
+    let rotated_centroid = mat3_vec3(&rotation, centroid_moving);
+    let translation = [
+        centroid_fixed[0] - rotated_centroid[0],
+        centroid_fixed[1] - rotated_centroid[1],
+        centroid_fixed[2] - rotated_centroid[2],
+    ];
+
+    (rotation, translation)
+}
+
+/// Rigid transform and residual error returned by `Lidar::icp_align`.
+#[derive(Debug, Clone, Copy)]
+pub struct IcpResult {
+    /// 3x3 rotation matrix, row-major.
+    pub rotation: [[f64; 3]; 3],
+    /// Translation applied after rotation: `p' = R * p + t`.
+    pub translation: [f64; 3],
+    /// RMSE of nearest-neighbour correspondence distances at convergence.
+    pub rmse: f64,
+    /// Number of iterations actually run (<= the `max_iterations` passed to `icp_align`).
+    pub iterations: usize,
+}
+
+impl IcpResult {
+    /// Rotation and translation combined into a row-major 4x4 homogeneous transform.
+    pub fn as_matrix4(&self) -> [[f64; 4]; 4] {
+        let r = &self.rotation;
+        let t = &self.translation;
+        [
+            [r[0][0], r[0][1], r[0][2], t[0]],
+            [r[1][0], r[1][1], r[1][2], t[1]],
+            [r[2][0], r[2][1], r[2][2], t[2]],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+    }
+
+    /// Apply the transform to a single `(x, y, z)` point.
+    pub fn apply(&self, p: (f64, f64, f64)) -> (f64, f64, f64) {
+        let v = mat3_vec3(&self.rotation, [p.0, p.1, p.2]);
+        (
+            v[0] + self.translation[0],
+            v[1] + self.translation[1],
+            v[2] + self.translation[2],
+        )
+    }
+}
+
+/// A 3D voxel occupancy grid whose Z axis is normalized to height-above-ground rather
+/// than raw elevation, so a given level means the same thing (e.g. "2-3m above the
+/// terrain") everywhere in the tile regardless of local ground slope. Produced by
+/// `Lidar::to_voxel_grid`.
+pub struct VoxelGrid {
+    pub resolution_xy: f64,
+    pub voxel_height: f64,
+    pub width: usize,
+    pub height: usize,
+    pub levels: usize,
+    /// Point count per voxel, row-major: `(row * width + col) * levels + level`.
+    pub counts: Vec<u32>,
+}
+
+impl VoxelGrid {
+    /// Point count in the voxel at `(row, col, level)`.
+    pub fn count_at(&self, row: usize, col: usize, level: usize) -> u32 {
+        self.counts[(row * self.width + col) * self.levels + level]
+    }
+}
+
+/// Result of COPC entry reading for statistics
+#[cfg(feature = "lidar-copc")]
+struct CopcReadResult {
+    points: Vec<LidarPoint>,
+    entries_processed: usize,
+    entries_success: usize,
     entries_failed: usize,
+    /// Entries whose cube didn't overlap the active bbox, so they were never read.
+    entries_pruned_bbox: usize,
+}
+
+/// The COPC root cube: a cubic volume centered at `center` with half-width `halfsize`
+/// along each axis. Every octree entry's cube is a sub-division of this one, keyed by
+/// its `(level, x, y, z)` voxel key.
+#[cfg(feature = "lidar-copc")]
+#[derive(Debug, Clone, Copy)]
+struct CopcRootBounds {
+    center: (f64, f64, f64),
+    halfsize: f64,
+}
+
+/// Derive an entry's XY cube from its octree key, per the COPC spec: at `level` the root
+/// cube is subdivided into `2^level` cells per axis, each of side `root_side / 2^level`,
+/// indexed by `key.x`/`key.y`/`key.z` from the cube's minimum corner.
+#[cfg(feature = "lidar-copc")]
+fn copc_entry_bbox_2d(entry: &las::copc::Entry, root: &CopcRootBounds) -> (f64, f64, f64, f64) {
+    let side = (2.0 * root.halfsize) / 2f64.powi(entry.key.level);
+    let min_x = (root.center.0 - root.halfsize) + entry.key.x as f64 * side;
+    let min_y = (root.center.1 - root.halfsize) + entry.key.y as f64 * side;
+    (min_x, min_y, min_x + side, min_y + side)
+}
+
+/// Minimal lazy Range reader implementing `Read + Seek` so `las::CopcEntryReader` can
+/// walk a COPC file's header, VLRs, and hierarchy page while only pulling down the byte
+/// ranges it actually seeks to and reads -- the header/hierarchy first, then each
+/// visited octree node's point chunk -- instead of the whole object up front. Backed by
+/// any `PointStore`, so this works the same way over `http(s)://`, `s3://`, `gs://`, and
+/// `az://` URLs.
+#[cfg(feature = "lidar-copc")]
+struct PointStoreRangeReader {
+    store: Box<dyn PointStore>,
+    url: String,
+    content_length: u64,
+    pos: u64,
+}
+
+#[cfg(feature = "lidar-copc")]
+impl PointStoreRangeReader {
+    fn new(store: Box<dyn PointStore>, url: &str, content_length: u64) -> Self {
+        PointStoreRangeReader {
+            store,
+            url: url.to_string(),
+            content_length,
+            pos: 0,
+        }
+    }
+}
+
+#[cfg(feature = "lidar-copc")]
+impl std::io::Read for PointStoreRangeReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.content_length {
+            return Ok(0);
+        }
+        let len = (buf.len() as u64).min(self.content_length - self.pos);
+        let data = self
+            .store
+            .get_range(&self.url, self.pos, len)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        let n = data.len();
+        buf[..n].copy_from_slice(&data);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "lidar-copc")]
+impl std::io::Seek for PointStoreRangeReader {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.pos = match pos {
+            std::io::SeekFrom::Start(n) => n,
+            std::io::SeekFrom::End(n) => {
+                if n >= 0 {
+                    self.content_length.saturating_add(n as u64)
+                } else {
+                    self.content_length.saturating_sub((-n) as u64)
+                }
+            }
+            std::io::SeekFrom::Current(n) => {
+                if n >= 0 {
+                    self.pos.saturating_add(n as u64)
+                } else {
+                    self.pos.saturating_sub((-n) as u64)
+                }
+            }
+        };
+        Ok(self.pos)
+    }
+}
+
+/// Outcome of `Lidar::run`: where the GeoTIFF was written, and how many points the LOF
+/// outlier filter dropped before rasterization.
+#[derive(Debug, Clone)]
+pub struct RunOutput {
+    /// Path to the written GeoTIFF (mirrors the previous plain `PathBuf` return).
+    pub output_path: PathBuf,
+    /// Number of points `Lidar::remove_lof_outliers` removed from `loaded_points` before
+    /// `process_lidar_points` ran.
+    pub points_dropped_by_lof: usize,
 }
 
 impl Lidar {
@@ -668,6 +2238,20 @@ impl Lidar {
             classification,
             list_path_laz: None,
             loaded_points: None,
+            spatial_index: None,
+            copc_max_depth: None,
+            target_epsg: None,
+            source_epsg_fallback: None,
+            ingest_chunk_size: DEFAULT_INGEST_CHUNK_SIZE,
+            ingest_worker_threads: None,
+            pmf_params: PmfParams::default(),
+            force_pmf_ground: false,
+            dtm_idw_params: IdwParams::default(),
+            geotiff_options: GeoTiffOptions::default(),
+            band_selection: RasterBandSelection::default(),
+            elevation_cache: Mutex::new(TileRasterCache::new(DEFAULT_ELEVATION_CACHE_CAPACITY)),
+            elevation_resolution: 1.0,
+            spatial_index_strategy: SpatialIndexStrategy::default(),
         };
 
         // If bbox is provided, set it and get LiDAR points immediately
@@ -706,6 +2290,142 @@ impl Lidar {
         self.classification = classification;
     }
 
+    /// Set the CRS points are returned in, reprojecting any already-loaded points immediately
+    /// rather than only taking effect on the next `set_bbox()`/`run()`. Also updates
+    /// `target_epsg` so subsequent loads keep producing points in `epsg` without needing a
+    /// separate `set_target_epsg` call.
+    pub fn set_crs(&mut self, epsg: i32) -> Result<()> {
+        let current_epsg = self.output_epsg();
+        if let Some(ref mut points) = self.loaded_points {
+            reproject_points_in_place(points, current_epsg, epsg)?;
+        }
+        self.geo_core.set_epsg(epsg);
+        self.target_epsg = Some(epsg);
+        Ok(())
+    }
+
+    /// Cap the COPC octree level read from `s3://`/`http(s)://`/local COPC sources.
+    /// COPC stores coarser samples at lower octree levels, so e.g. `Some(2)` yields a
+    /// uniformly decimated preview at a fraction of the I/O and decode cost of reading
+    /// every level. `None` (the default) reads every level.
+    pub fn set_copc_max_depth(&mut self, max_depth: Option<i32>) {
+        self.copc_max_depth = max_depth;
+    }
+
+    /// Set the EPSG code that loaded points are reprojected into. Pass `None` to get points
+    /// back in their source CRS (or `geo_core`'s EPSG code when the source CRS is unknown).
+    pub fn set_target_epsg(&mut self, target_epsg: Option<i32>) {
+        self.target_epsg = target_epsg;
+    }
+
+    /// Set the EPSG code assumed for a tile when its LAS/COPC WKT VLR is missing or can't be
+    /// parsed. Pass `None` to fall back to treating such tiles as already being in
+    /// `geo_core`'s EPSG code.
+    pub fn set_source_epsg_fallback(&mut self, source_epsg: Option<i32>) {
+        self.source_epsg_fallback = source_epsg;
+    }
+
+    /// Restrict `run()`'s output to points inside `boundary`, a GeoJSON Polygon/MultiPolygon
+    /// (or Feature/FeatureCollection wrapping one) expressed in EPSG:4326. Points outside it are
+    /// dropped before rasterization. See `GeoCore::set_limit_to`.
+    pub fn set_limit_to(&mut self, boundary: &[u8]) -> Result<()> {
+        self.geo_core.set_limit_to(boundary)
+    }
+
+    /// Keep only already-`loaded_points` matching a small SQL-like WHERE expression (`=`, `!=`,
+    /// `<`, `<=`, `>`, `>=`, `IN (...)`, `AND`/`OR`) over a point's `x`, `y`, `z`,
+    /// `classification`, `intensity`, `return_number` and `number_of_returns` fields, e.g.
+    /// `"classification = 2 AND z > 100"`. Unlike [`Water::filter`](crate::geometric::water::Water::filter),
+    /// which filters GeoJSON feature properties, lidar points have no arbitrary property map, so
+    /// this evaluates the same grammar against that fixed set of field names; referencing any
+    /// other name never matches. A no-op if points haven't been loaded yet.
+    pub fn filter(&mut self, expr: &str) -> Result<()> {
+        let Some(ref mut points) = self.loaded_points else {
+            return Ok(());
+        };
+        let where_expr = crate::geometric::query::parse_where(expr)
+            .with_context(|| format!("Failed to parse WHERE expression: {expr}"))?;
+        points.retain(|p| where_expr.matches_props(&lidar_point_props(p)));
+        Ok(())
+    }
+
+    /// Set how many points `process_lidar_points_streaming` buffers per tile before folding
+    /// them into the DSM/DTM grid accumulators. Smaller values bound memory more tightly;
+    /// larger values amortize the fold loop over more points. Defaults to
+    /// `DEFAULT_INGEST_CHUNK_SIZE`.
+    pub fn set_ingest_chunk_size(&mut self, chunk_size: usize) {
+        self.ingest_chunk_size = chunk_size.max(1);
+    }
+
+    /// Cap the number of worker threads `process_lidar_points_streaming` uses to process
+    /// tiles concurrently. `None` (the default) uses rayon's global thread pool.
+    pub fn set_ingest_worker_threads(&mut self, worker_threads: Option<usize>) {
+        self.ingest_worker_threads = worker_threads;
+    }
+
+    /// Set the progressive morphological filter parameters used to synthesize ground points
+    /// for tiles with no classification-2 returns. Defaults to `PmfParams::default()`.
+    pub fn set_pmf_params(&mut self, params: PmfParams) {
+        self.pmf_params = params;
+    }
+
+    /// Force `process_lidar_points` to derive ground purely from geometry via
+    /// `classify_ground_pmf`, ignoring classification-2 returns even when present. Use this
+    /// for unclassified or mis-classified LAZ tiles where the classification codes can't be
+    /// trusted. Defaults to `false` (classification-2 returns are trusted when present).
+    pub fn set_force_pmf_ground(&mut self, force: bool) {
+        self.force_pmf_ground = force;
+    }
+
+    /// Set the radius/neighbor-count/power used by `idw_fill_dtm` to interpolate DTM cells
+    /// with no ground return. Defaults to `IdwParams::default()`.
+    pub fn set_dtm_idw_params(&mut self, params: IdwParams) {
+        self.dtm_idw_params = params;
+    }
+
+    /// Set the GDAL creation options (compression, tiling, predictor, BIGTIFF) and overview
+    /// settings used by `to_tif`'s GeoTIFF write. Defaults to `GeoTiffOptions::default()`,
+    /// which reproduces the historical uncompressed, untiled output.
+    pub fn set_geotiff_options(&mut self, options: GeoTiffOptions) {
+        self.geotiff_options = options;
+    }
+
+    /// Select which raster products `process_lidar_points` computes and `to_tif` writes out.
+    /// Defaults to `RasterBandSelection::default()` (DSM/DTM/CHM only, matching the
+    /// historical fixed 3-band output). Only the non-streaming run path honors this -- see
+    /// `RasterBandSelection`'s doc comment.
+    pub fn set_band_selection(&mut self, selection: RasterBandSelection) {
+        self.band_selection = selection;
+    }
+
+    /// Cap the number of rasterized tiles `sample_elevation`/`sample_elevation_batch` keep
+    /// cached. Resets the cache (dropping anything already held). Defaults to
+    /// `DEFAULT_ELEVATION_CACHE_CAPACITY`.
+    pub fn set_elevation_cache_capacity(&mut self, capacity: usize) {
+        self.elevation_cache = Mutex::new(TileRasterCache::new(capacity));
+    }
+
+    /// Set the grid resolution, in output-CRS units, `sample_elevation`/
+    /// `sample_elevation_batch` rasterize tiles at. Defaults to `1.0`.
+    pub fn set_elevation_resolution(&mut self, resolution: f64) {
+        self.elevation_resolution = resolution;
+    }
+
+    /// Set the spatial index backend `filter_points_with_spatial_index` uses to bbox-filter a
+    /// tile's points. Defaults to `SpatialIndexStrategy::Dynamic { threshold: 10_000 }`.
+    pub fn set_spatial_index_strategy(&mut self, strategy: SpatialIndexStrategy) {
+        self.spatial_index_strategy = strategy;
+    }
+
+    /// The EPSG code that loaded points actually end up in: `target_epsg` when set, otherwise
+    /// `geo_core`'s query EPSG. Points are reprojected to exactly this CRS by
+    /// `load_single_laz_file`/`load_single_copc_file`, so anything downstream that filters or
+    /// writes out those points (bbox math, the GeoTIFF `SpatialRef`) must use this, not assume
+    /// `geo_core`'s EPSG directly.
+    fn output_epsg(&self) -> i32 {
+        self.target_epsg.unwrap_or(self.geo_core.get_epsg())
+    }
+
     /// Get output path
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
@@ -736,153 +2456,504 @@ impl Lidar {
         cache_dir.join(filename)
     }
 
-    /// Returns true if the URL is for a COPC file (Cloud Optimized Point Cloud).
-    fn is_copc_url(url: &str) -> bool {
-        url.ends_with(".copc.laz") || url.contains(".copc.")
-    }
+    /// Read just enough of a cached file's header to get its VLRs (for source CRS
+    /// detection), without loading the whole (possibly multi-gigabyte) file into memory.
+    fn peek_vlrs(path: &Path) -> Vec<LasVlr> {
+        use std::io::Read;
+
+        let Ok(mut file) = std::fs::File::open(path) else {
+            return Vec::new();
+        };
+        let mut buf = vec![0u8; 65536];
+        let Ok(n) = file.read(&mut buf) else {
+            return Vec::new();
+        };
+        buf.truncate(n);
+
+        parse_las_header_from_slice(&buf)
+            .map(|h| h.vlrs)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the URL is for a COPC file (Cloud Optimized Point Cloud).
+    fn is_copc_url(url: &str) -> bool {
+        url.ends_with(".copc.laz") || url.contains(".copc.")
+    }
+
+    /// Verify that a cached file is valid: correct LAS signature and reasonable size, plus
+    /// a SHA-256 match against its `.sha256` sidecar (written by `download_with_verification`)
+    /// when one exists. Caches written before this sidecar existed have none, so the absence
+    /// of a sidecar falls back to the signature/size check alone rather than failing.
+    fn verify_cached_file(cache_path: &Path) -> Result<bool> {
+        let metadata = std::fs::metadata(cache_path)?;
+
+        // File should be at least large enough for a LAS header
+        if metadata.len() < LAS_HEADER_MIN_BYTES as u64 {
+            return Ok(false);
+        }
+
+        // Check LAS signature
+        let mut file = std::fs::File::open(cache_path)?;
+        let mut signature = [0u8; 4];
+        std::io::Read::read_exact(&mut file, &mut signature)?;
+        if &signature != b"LASF" {
+            return Ok(false);
+        }
+
+        let checksum_path = Self::checksum_path_for(cache_path);
+        if let Ok(expected) = std::fs::read_to_string(&checksum_path) {
+            let data = std::fs::read(cache_path)?;
+            if sha256_hex(&data) != expected.trim() {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Sidecar path holding the hex SHA-256 digest of `cache_path`'s contents at the time it
+    /// was downloaded, e.g. `tile.laz.sha256`.
+    fn checksum_path_for(cache_path: &Path) -> PathBuf {
+        let mut name = cache_path.file_name().unwrap_or_default().to_os_string();
+        name.push(".sha256");
+        cache_path.with_file_name(name)
+    }
+
+    /// Move a cached file (and its `.sha256` sidecar, if any) that failed `verify_cached_file`
+    /// into a `quarantine/` subdirectory next to the cache, instead of deleting it outright, so
+    /// a corrupt download can be inspected after the fact rather than silently vanishing.
+    fn quarantine_corrupt_file(cache_path: &Path) {
+        let Some(cache_dir) = cache_path.parent() else {
+            let _ = std::fs::remove_file(cache_path);
+            return;
+        };
+        let quarantine_dir = cache_dir.join("quarantine");
+        if std::fs::create_dir_all(&quarantine_dir).is_err() {
+            let _ = std::fs::remove_file(cache_path);
+            return;
+        }
+
+        if let Some(file_name) = cache_path.file_name() {
+            let dest = quarantine_dir.join(file_name);
+            if std::fs::rename(cache_path, &dest).is_ok() {
+                eprintln!("  üßØ Quarantined corrupt cache file to {:?}", dest);
+            } else {
+                let _ = std::fs::remove_file(cache_path);
+            }
+        }
+
+        let checksum_path = Self::checksum_path_for(cache_path);
+        if checksum_path.exists() {
+            if let Some(file_name) = checksum_path.file_name() {
+                let _ = std::fs::rename(&checksum_path, quarantine_dir.join(file_name));
+            }
+        }
+    }
+
+    /// Download a file with integrity verification. Fetches through whichever
+    /// `PointStore` backs `url`'s scheme, so the retry/verify/caching logic here works
+    /// the same way over `http(s)://`, `s3://`, `gs://`, and `az://`. When the object's size
+    /// is known up front, downloads via chunked Range requests into a `.part` sidecar next to
+    /// `cache_path` so an interrupted download (crash, Ctrl-C, dropped connection) resumes
+    /// from where it left off on the next call instead of restarting from byte 0.
+    fn download_with_verification(
+        store: &dyn PointStore,
+        url: &str,
+        cache_path: &Path,
+    ) -> Result<Vec<u8>> {
+        println!("  📥 Downloading from: {}", url);
+
+        let expected_size = store.head_len(url).ok();
+        if let Some(size) = expected_size {
+            println!(
+                "  📦 Expected size: {} bytes ({:.2} MB)",
+                size,
+                size as f64 / 1_048_576.0
+            );
+        }
+
+        let part_path = Self::part_path_for(cache_path);
+        let data = match expected_size {
+            Some(expected) => {
+                Self::download_via_range_resumable(store, url, &part_path, expected).or_else(
+                    |e| {
+                        eprintln!(
+                            "  ⚠️ Resumable Range download failed ({}), falling back to full GET",
+                            e
+                        );
+                        let _ = std::fs::remove_file(&part_path);
+                        Self::download_full_with_retries(store, url, expected_size)
+                    },
+                )?
+            }
+            None => Self::download_full_with_retries(store, url, expected_size)?,
+        };
+
+        // Verify LAS signature before caching
+        if data.len() < 4 || &data[0..4] != b"LASF" {
+            let _ = std::fs::remove_file(&part_path);
+            return Err(anyhow::anyhow!(
+                "Downloaded file is not a valid LAS/LAZ file (missing LASF signature)"
+            ));
+        }
+
+        println!(
+            "  ✓ Downloaded {} bytes ({:.2} MB)",
+            data.len(),
+            data.len() as f64 / 1_048_576.0
+        );
 
-    /// Verify that a cached file is valid (has correct LAS signature and reasonable size)
-    fn verify_cached_file(cache_path: &Path) -> Result<bool> {
-        let metadata = std::fs::metadata(cache_path)?;
+        // Cache the file; the `.part` sidecar has served its purpose now that the verified
+        // bytes are in their final home
+        std::fs::write(cache_path, &data).context("Failed to write cache file")?;
+        let _ = std::fs::remove_file(&part_path);
 
-        // File should be at least large enough for a LAS header
-        if metadata.len() < LAS_HEADER_MIN_BYTES as u64 {
-            return Ok(false);
+        // Record a checksum sidecar so a later `verify_cached_file` can detect on-disk
+        // corruption (truncation, bit rot) that the signature check alone would miss
+        let checksum_path = Self::checksum_path_for(cache_path);
+        if let Err(e) = std::fs::write(&checksum_path, sha256_hex(&data)) {
+            eprintln!("  ⚠️ Failed to write checksum sidecar {:?}: {}", checksum_path, e);
         }
 
-        // Check LAS signature
-        let mut file = std::fs::File::open(cache_path)?;
-        let mut signature = [0u8; 4];
-        std::io::Read::read_exact(&mut file, &mut signature)?;
+        println!("  💾 Cached to: {:?}", cache_path);
+
+        Ok(data)
+    }
 
-        Ok(&signature == b"LASF")
+    /// Sidecar path for an in-progress download of `cache_path`, e.g. `tile.laz.part`.
+    fn part_path_for(cache_path: &Path) -> PathBuf {
+        let mut name = cache_path.file_name().unwrap_or_default().to_os_string();
+        name.push(".part");
+        cache_path.with_file_name(name)
     }
 
-    /// Download a file with integrity verification
-    #[cfg(feature = "reqwest")]
-    fn download_with_verification(
-        client: &reqwest::blocking::Client,
+    /// Download `url` in `RESUME_CHUNK_BYTES` Range requests, appending each chunk to
+    /// `part_path` and flushing after every chunk. If `part_path` already holds bytes from a
+    /// previous interrupted attempt, the Range requests start at its current length instead
+    /// of byte 0.
+    fn download_via_range_resumable(
+        store: &dyn PointStore,
         url: &str,
-        cache_path: &Path,
+        part_path: &Path,
+        expected_size: u64,
     ) -> Result<Vec<u8>> {
-        use std::io::Read;
-
-        println!("  üì• Downloading from: {}", url);
+        use std::io::Write;
 
-        // Get expected size first via HEAD request
-        let expected_size = head_content_length(client, url);
-        if let Some(size) = expected_size {
+        let resume_from = std::fs::metadata(part_path)
+            .map(|m| m.len())
+            .unwrap_or(0)
+            .min(expected_size);
+        if resume_from > 0 {
             println!(
-                "  üì¶ Expected size: {} bytes ({:.2} MB)",
-                size,
-                size as f64 / 1_048_576.0
+                "  ⏩ Resuming download from {} of {} bytes",
+                resume_from, expected_size
             );
         }
 
-        // Download with retries
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(part_path)
+            .with_context(|| format!("Failed to open {:?} for resume", part_path))?;
+
+        let mut offset = resume_from;
+        while offset < expected_size {
+            let len = (expected_size - offset).min(RESUME_CHUNK_BYTES);
+            let chunk = store
+                .get_range(url, offset, len)
+                .map_err(|e| anyhow::anyhow!("Range request at offset {} failed: {}", offset, e))?;
+            if chunk.is_empty() {
+                anyhow::bail!("Empty Range response at offset {}", offset);
+            }
+            file.write_all(&chunk)
+                .context("Failed to append chunk to part file")?;
+            file.flush().context("Failed to flush part file")?;
+            offset += chunk.len() as u64;
+        }
+
+        std::fs::read(part_path).with_context(|| format!("Failed to read {:?}", part_path))
+    }
+
+    /// Download the whole object with a single `get_all` call and a few retries on
+    /// incomplete/empty responses. Used when the size isn't known up front (so there's
+    /// nothing for a Range-based resume to resume against) or as a fallback when Range
+    /// requests fail outright.
+    fn download_full_with_retries(
+        store: &dyn PointStore,
+        url: &str,
+        expected_size: Option<u64>,
+    ) -> Result<Vec<u8>> {
         let mut retries = 3;
-        let data = loop {
-            let response = match client.get(url).send() {
-                Ok(r) => r,
+        loop {
+            match store.get_all(url) {
+                Ok(data) if !data.is_empty() => {
+                    if let Some(expected) = expected_size {
+                        if data.len() as u64 != expected {
+                            retries -= 1;
+                            if retries > 0 {
+                                eprintln!(
+                                    "  ⚠️ Incomplete download: got {} bytes, expected {} (retrying)",
+                                    data.len(),
+                                    expected
+                                );
+                                std::thread::sleep(std::time::Duration::from_secs(2));
+                                continue;
+                            }
+                            return Err(anyhow::anyhow!(
+                                "Incomplete download: got {} bytes, expected {}",
+                                data.len(),
+                                expected
+                            ));
+                        }
+                    }
+                    return Ok(data);
+                }
+                Ok(_) => {
+                    retries -= 1;
+                    if retries == 0 {
+                        return Err(anyhow::anyhow!("Empty response after retries"));
+                    }
+                    eprintln!("  ⚠️ Empty response (retrying)");
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                }
                 Err(e) => {
                     retries -= 1;
                     if retries > 0 {
-                        eprintln!("  ‚ö†Ô∏è Download error (retrying in 2s): {}", e);
+                        eprintln!("  ⚠️ Download error (retrying in 2s): {}", e);
                         std::thread::sleep(std::time::Duration::from_secs(2));
                         continue;
                     }
                     return Err(anyhow::anyhow!("Failed to download after retries: {}", e));
                 }
-            };
-
-            if !response.status().is_success() {
-                return Err(anyhow::anyhow!("HTTP error: {}", response.status()));
             }
+        }
+    }
 
-            let mut data = Vec::new();
-            let mut buffer = [0u8; 65536]; // 64KB buffer for faster downloads
-            let mut response = response;
-            let mut bytes_read = 0u64;
+    /// Read just the public header's 2D bounding box from a local LAS/LAZ file, without
+    /// decoding any point records. Lets `load_manifest` skip a tile entirely when its
+    /// bounds don't overlap the active bbox, instead of reading and filtering every point.
+    fn read_local_tile_bounds_2d(path: &Path) -> Result<(f64, f64, f64, f64)> {
+        use std::io::Read;
 
-            loop {
-                match response.read(&mut buffer) {
-                    Ok(0) => break,
-                    Ok(n) => {
-                        data.extend_from_slice(&buffer[..n]);
-                        bytes_read += n as u64;
-
-                        // Progress every 10MB
-                        if bytes_read % (10 * 1024 * 1024) < 65536 {
-                            if let Some(expected) = expected_size {
-                                println!(
-                                    "  ‚è≥ Progress: {:.1}%",
-                                    (bytes_read as f64 / expected as f64) * 100.0
-                                );
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        retries -= 1;
-                        if retries > 0 {
-                            eprintln!("  ‚ö†Ô∏è Read error (retrying): {}", e);
-                            std::thread::sleep(std::time::Duration::from_secs(2));
-                            break;
-                        }
-                        return Err(anyhow::anyhow!("Failed to read: {}", e));
-                    }
+        let mut file =
+            std::fs::File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+        let mut buf = vec![0u8; LAS_HEADER_BOUNDS_BYTES];
+        file.read_exact(&mut buf)
+            .with_context(|| format!("Failed to read LAS header from {:?}", path))?;
+
+        if buf.get(0..4) != Some(b"LASF") {
+            anyhow::bail!("Invalid LAS signature in {:?} (expected LASF)", path);
+        }
+
+        let read_f64 =
+            |offset: usize| -> f64 { f64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap()) };
+        let max_x = read_f64(LAS_BOUNDS);
+        let min_x = read_f64(LAS_BOUNDS + 8);
+        let max_y = read_f64(LAS_BOUNDS + 16);
+        let min_y = read_f64(LAS_BOUNDS + 24);
+
+        Ok((min_x, min_y, max_x, max_y))
+    }
+
+    /// Remote sibling of `read_local_tile_bounds_2d`: reads just the public header's 2D
+    /// bounding box via a single small Range request, without downloading the tile body.
+    /// Used by `sample_elevation` to find which tile covers a query point before deciding
+    /// whether to download and rasterize it.
+    fn read_remote_tile_bounds_2d(url: &str) -> Result<(f64, f64, f64, f64)> {
+        let store = store_for_url(url)?;
+        let buf = store.get_range(url, 0, LAS_HEADER_BOUNDS_BYTES as u64)?;
+
+        if buf.get(0..4) != Some(b"LASF") {
+            anyhow::bail!("Invalid LAS signature in {} (expected LASF)", url);
+        }
+
+        let read_f64 =
+            |offset: usize| -> f64 { f64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap()) };
+        let max_x = read_f64(LAS_BOUNDS);
+        let min_x = read_f64(LAS_BOUNDS + 8);
+        let max_y = read_f64(LAS_BOUNDS + 16);
+        let min_y = read_f64(LAS_BOUNDS + 24);
+
+        Ok((min_x, min_y, max_x, max_y))
+    }
+
+    /// Dispatching sibling of `read_local_tile_bounds_2d`/`read_remote_tile_bounds_2d` that
+    /// picks the right one based on whether `url` is a local path or a remote URL.
+    fn tile_bounds_2d(url: &str) -> Result<(f64, f64, f64, f64)> {
+        if Self::is_remote_entry(url) {
+            Self::read_remote_tile_bounds_2d(url)
+        } else {
+            Self::read_local_tile_bounds_2d(Path::new(url))
+        }
+    }
+
+    /// Load points from a local LAS/LAZ file path (no HTTP or caching involved), used by
+    /// `load_manifest` for manifest entries that are plain filesystem paths rather than
+    /// URLs. If `filter_bbox` is given and the tile's header bounds don't overlap it, the
+    /// file is skipped entirely without reading a single point.
+    fn load_local_laz_file(
+        path: &str,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        spatial_index_strategy: SpatialIndexStrategy,
+    ) -> Result<Vec<LidarPoint>> {
+        let file_path = Path::new(path);
+
+        if let Some(bbox) = filter_bbox {
+            if let Ok(tile_bounds) = Self::read_local_tile_bounds_2d(file_path) {
+                if !bbox_overlaps(tile_bounds, bbox) {
+                    println!("  ‚è≠Ô∏è Skipping {} (tile bounds outside active bbox)", path);
+                    return Ok(Vec::new());
                 }
             }
+        }
 
-            if !data.is_empty() {
-                // Verify size if we know expected
-                if let Some(expected) = expected_size {
-                    if data.len() as u64 != expected {
-                        retries -= 1;
-                        if retries > 0 {
-                            eprintln!(
-                                "  ‚ö†Ô∏è Incomplete download: got {} bytes, expected {} (retrying)",
-                                data.len(),
-                                expected
-                            );
-                            std::thread::sleep(std::time::Duration::from_secs(2));
-                            continue;
-                        }
-                        return Err(anyhow::anyhow!(
-                            "Incomplete download: got {} bytes, expected {}",
-                            data.len(),
-                            expected
-                        ));
-                    }
-                }
-                break data;
+        println!("üìÇ Reading local LAZ: {}", path);
+        let mut reader = las::Reader::from_path(file_path)
+            .with_context(|| format!("Failed to open LAS/LAZ file: {}", path))?;
+
+        let point_count = reader.header().number_of_points() as usize;
+        let mut raw_points: Vec<las::Point> = Vec::with_capacity(point_count);
+        for point_result in reader.points() {
+            if let Ok(p) = point_result {
+                raw_points.push(p);
             }
+        }
 
-            retries -= 1;
-            if retries == 0 {
-                return Err(anyhow::anyhow!("Empty response after retries"));
+        #[cfg(feature = "rayon")]
+        let all_points: Vec<LidarPoint> = raw_points
+            .par_iter()
+            .map(|point| LidarPoint {
+                x: point.x,
+                y: point.y,
+                z: point.z,
+                classification: classification_to_u8(&point.classification),
+                intensity: point.intensity,
+                return_number: point.return_number,
+                number_of_returns: point.number_of_returns,
+                rgb: point.color.as_ref().map(|c| (c.red, c.green, c.blue)),
+            })
+            .collect();
+        #[cfg(not(feature = "rayon"))]
+        let all_points: Vec<LidarPoint> = raw_points
+            .iter()
+            .map(|point| LidarPoint {
+                x: point.x,
+                y: point.y,
+                z: point.z,
+                classification: classification_to_u8(&point.classification),
+                intensity: point.intensity,
+                return_number: point.return_number,
+                number_of_returns: point.number_of_returns,
+                rgb: point.color.as_ref().map(|c| (c.red, c.green, c.blue)),
+            })
+            .collect();
+
+        let file_points = if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
+            Self::filter_points_with_spatial_index(
+                &all_points,
+                x_min,
+                y_min,
+                x_max,
+                y_max,
+                spatial_index_strategy,
+            )
+        } else {
+            all_points
+        };
+
+        println!(
+            "  ‚úì Loaded {} points after spatial filter",
+            file_points.len()
+        );
+
+        Ok(file_points)
+    }
+
+    /// Returns true if `entry` looks like a URL rather than a local filesystem path.
+    fn is_remote_entry(entry: &str) -> bool {
+        entry.starts_with("http://") || entry.starts_with("https://")
+    }
+
+    /// Load a plain-text manifest of LAZ tiles (one local path or URL per line; blank
+    /// lines and lines starting with `#` are ignored) and merge their points into
+    /// `loaded_points`, rebuilding `spatial_index` once every tile is in. Mirrors GRASS
+    /// `r.in.lidar`'s multi-file-from-text-file input, so a mosaic of tiles can be
+    /// processed in one pass. If a bbox was set via `set_bbox`, it is reused here too:
+    /// tiles whose header bounds fall entirely outside it are skipped without being read.
+    pub fn load_manifest(&mut self, manifest_path: impl AsRef<Path>) -> Result<()> {
+        let manifest_path = manifest_path.as_ref();
+        let manifest = std::fs::read_to_string(manifest_path)
+            .with_context(|| format!("Failed to read LAZ manifest: {:?}", manifest_path))?;
+
+        let entries: Vec<String> = manifest
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+            .collect();
+
+        if entries.is_empty() {
+            anyhow::bail!("LAZ manifest {:?} contains no entries", manifest_path);
+        }
+
+        let filter_bbox = match self.geo_core.get_bbox() {
+            Some(bbox) => {
+                // Transform the lon/lat bbox into `geo_core`'s query EPSG (same as `get_lidar_points`).
+                let query_crs = format!("EPSG:{}", self.geo_core.get_epsg());
+                let transformer = Proj::new_known_crs("EPSG:4326", &query_crs, None)
+                    .context("Failed to create coordinate transformer")?;
+                let (min_x, min_y) = transformer
+                    .convert((bbox.min_x, bbox.min_y))
+                    .context("Failed to transform min coordinates")?;
+                let (max_x, max_y) = transformer
+                    .convert((bbox.max_x, bbox.max_y))
+                    .context("Failed to transform max coordinates")?;
+                Some((min_x, min_y, max_x, max_y))
             }
-            eprintln!("  ‚ö†Ô∏è Empty response (retrying)");
-            std::thread::sleep(std::time::Duration::from_secs(2));
+            None => None,
         };
 
-        // Verify LAS signature before caching
-        if data.len() < 4 || &data[0..4] != b"LASF" {
-            return Err(anyhow::anyhow!(
-                "Downloaded file is not a valid LAS/LAZ file (missing LASF signature)"
-            ));
+        let cache_dir = self.output_path.join(".cache").join("laz");
+        std::fs::create_dir_all(&cache_dir).context("Failed to create LAZ cache dir")?;
+
+        let mut new_points = Vec::new();
+        for entry in &entries {
+            let points = if Self::is_remote_entry(entry) {
+                self.load_single_point_file(entry, &cache_dir, filter_bbox)?
+            } else {
+                Self::load_local_laz_file(entry, filter_bbox, self.spatial_index_strategy)?
+            };
+            new_points.extend(points);
         }
 
-        println!(
-            "  ‚úì Downloaded {} bytes ({:.2} MB)",
-            data.len(),
-            data.len() as f64 / 1_048_576.0
-        );
+        self.list_path_laz
+            .get_or_insert_with(Vec::new)
+            .extend(entries);
 
-        // Cache the file
-        std::fs::write(cache_path, &data).context("Failed to write cache file")?;
-        println!("  üíæ Cached to: {:?}", cache_path);
+        let mut merged = self.loaded_points.take().unwrap_or_default();
+        merged.extend(new_points);
 
-        Ok(data)
+        if merged.is_empty() {
+            anyhow::bail!("No LiDAR points were loaded from manifest {:?}", manifest_path);
+        }
+
+        println!("‚úÖ Total points after merging manifest: {}", merged.len());
+        self.spatial_index = Some(QuadtreeSpatialIndex::build(&merged));
+        self.loaded_points = Some(merged);
+
+        Ok(())
+    }
+
+    /// Convenience constructor: create a `Lidar` instance and immediately load/merge every
+    /// tile listed in `manifest_path` (see `load_manifest`).
+    pub fn from_file_list(
+        manifest_path: impl AsRef<Path>,
+        output_path: Option<String>,
+        classification: Option<u8>,
+    ) -> Result<Self> {
+        let mut lidar = Self::new(output_path, classification, None)?;
+        lidar.load_manifest(manifest_path)?;
+        Ok(lidar)
     }
 
     /// Load a single point file (COPC or LAZ) from URL or cache. Dispatches to COPC or LAZ loader.
@@ -896,7 +2967,7 @@ impl Lidar {
             return self.load_single_laz_file(url, cache_dir, filter_bbox);
         }
         #[cfg(feature = "lidar-copc")]
-        return self.load_single_copc_file(url, cache_dir, filter_bbox);
+        return self.load_single_copc_file(url, cache_dir, filter_bbox, self.copc_max_depth);
         #[cfg(not(feature = "lidar-copc"))]
         {
             eprintln!(
@@ -907,15 +2978,53 @@ impl Lidar {
         }
     }
 
-    /// Read points from a byte buffer as a standard LAZ file
-    /// This is the fallback method when COPC reading fails
-    /// Uses spatial indexing for efficient bbox filtering
-    fn read_as_standard_laz(
+    /// Stream a single tile's points (LAZ or COPC, auto-detected), bbox-filtered and
+    /// reprojected, invoking `on_batch` with each batch before dropping it. Streaming sibling
+    /// of `load_single_point_file` used by `process_lidar_points_streaming` to keep memory
+    /// bounded to one chunk per in-flight tile rather than every tile's full point count.
+    fn load_single_point_file_streaming(
+        &self,
+        url: &str,
+        cache_dir: &Path,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        on_batch: impl FnMut(&[LidarPoint]),
+    ) -> Result<()> {
+        if !Self::is_copc_url(url) {
+            return self.load_single_laz_file_streaming(url, cache_dir, filter_bbox, on_batch);
+        }
+        #[cfg(feature = "lidar-copc")]
+        return self.load_single_copc_file_streaming(
+            url,
+            cache_dir,
+            filter_bbox,
+            self.copc_max_depth,
+            on_batch,
+        );
+        #[cfg(not(feature = "lidar-copc"))]
+        {
+            eprintln!(
+                "COPC URL detected but lidar-copc feature disabled; loading as LAZ: {}",
+                url
+            );
+            self.load_single_laz_file_streaming(url, cache_dir, filter_bbox, on_batch)
+        }
+    }
+
+    /// Stream points from a byte buffer as a standard LAZ file, bbox-filtered and reprojected,
+    /// invoking `on_batch` with each batch before dropping it. This is the fallback method
+    /// when COPC reading fails. `read_as_standard_laz` is a thin Vec-collecting wrapper over
+    /// this for callers that want every point in memory at once.
+    fn for_each_point_in_standard_laz(
         bytes: Vec<u8>,
         filter_bbox: Option<(f64, f64, f64, f64)>,
-    ) -> Result<Vec<LidarPoint>> {
+        source_epsg: i32,
+        target_epsg: i32,
+        mut on_batch: impl FnMut(&[LidarPoint]),
+    ) -> Result<()> {
         use std::io::Cursor;
 
+        const BATCH_SIZE: usize = 50_000;
+
         println!("  üìñ Reading as standard LAZ file...");
 
         let cursor = Cursor::new(bytes);
@@ -925,61 +3034,72 @@ impl Lidar {
         let point_count = reader.header().number_of_points();
         println!("  üìä Header declares {} points", point_count);
 
-        // Read all points first
-        let mut raw_points: Vec<las::Point> = Vec::with_capacity(point_count as usize);
+        let mut batch: Vec<LidarPoint> = Vec::with_capacity(BATCH_SIZE);
+        let mut total = 0usize;
         let mut errors = 0;
 
         for point_result in reader.points() {
-            match point_result {
-                Ok(p) => raw_points.push(p),
+            let p = match point_result {
+                Ok(p) => p,
                 Err(_) => {
                     errors += 1;
+                    continue;
+                }
+            };
+
+            if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
+                if p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max {
+                    continue;
                 }
             }
+
+            batch.push(LidarPoint {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                classification: classification_to_u8(&p.classification),
+                intensity: p.intensity,
+                return_number: p.return_number,
+                number_of_returns: p.number_of_returns,
+                rgb: p.color.as_ref().map(|c| (c.red, c.green, c.blue)),
+            });
+            total += 1;
+
+            if batch.len() >= BATCH_SIZE {
+                reproject_points_in_place(&mut batch, source_epsg, target_epsg)?;
+                on_batch(&batch);
+                batch.clear();
+            }
+        }
+
+        if !batch.is_empty() {
+            reproject_points_in_place(&mut batch, source_epsg, target_epsg)?;
+            on_batch(&batch);
         }
 
         if errors > 0 {
             eprintln!("  ‚ö†Ô∏è {} point read errors", errors);
         }
 
-        println!("  üìä Read {} points from LAZ", raw_points.len());
-
-        // Convert to LidarPoint first (needed for spatial indexing)
-        #[cfg(feature = "rayon")]
-        let all_points: Vec<LidarPoint> = raw_points
-            .par_iter()
-            .map(|point| LidarPoint {
-                x: point.x,
-                y: point.y,
-                z: point.z,
-                classification: classification_to_u8(&point.classification),
-            })
-            .collect();
-
-        #[cfg(not(feature = "rayon"))]
-        let all_points: Vec<LidarPoint> = raw_points
-            .iter()
-            .map(|point| LidarPoint {
-                x: point.x,
-                y: point.y,
-                z: point.z,
-                classification: classification_to_u8(&point.classification),
-            })
-            .collect();
-
-        // Apply spatial filtering using index if we have a bbox
-        let file_points = if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
-            Self::filter_points_with_spatial_index(&all_points, x_min, y_min, x_max, y_max)
-        } else {
-            all_points
-        };
+        println!("  ‚úì Streamed {} points after spatial filter", total);
 
-        println!(
-            "  ‚úì Loaded {} points after spatial filter",
-            file_points.len()
-        );
+        Ok(())
+    }
 
-        Ok(file_points)
+    /// Read points from a byte buffer as a standard LAZ file, collecting every batch into one
+    /// `Vec`. Thin wrapper over `for_each_point_in_standard_laz` for callers that need the
+    /// whole tile's points at once rather than a bounded-memory streaming callback.
+    fn read_as_standard_laz(
+        bytes: Vec<u8>,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        source_epsg: i32,
+        target_epsg: i32,
+    ) -> Result<Vec<LidarPoint>> {
+        let mut points = Vec::new();
+        Self::for_each_point_in_standard_laz(bytes, filter_bbox, source_epsg, target_epsg, |batch| {
+            points.extend_from_slice(batch);
+        })?;
+        Ok(points)
     }
 
     /// Filter points using spatial indexing for better performance on large datasets
@@ -990,107 +3110,78 @@ impl Lidar {
         y_min: f64,
         x_max: f64,
         y_max: f64,
+        strategy: SpatialIndexStrategy,
     ) -> Vec<LidarPoint> {
         let point_count = points.len();
 
-        // For small datasets, just do linear scan
-        if point_count < 10_000 {
-            return points
+        let linear_scan = || {
+            points
                 .iter()
                 .filter(|p| p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max)
                 .cloned()
-                .collect();
-        }
-
-        println!("  üóÇÔ∏è Building spatial index for {} points...", point_count);
-        let start = std::time::Instant::now();
+                .collect::<Vec<_>>()
+        };
 
-        // Choose index type based on expected selectivity
-        // Grid index is faster to build, quadtree is better for very selective queries
-        let query_area = (x_max - x_min) * (y_max - y_min);
-
-        // Estimate data bounds from sample
-        let sample_size = (point_count / 100).max(100).min(point_count);
-        let step = point_count / sample_size;
-        let mut data_min_x = f64::INFINITY;
-        let mut data_min_y = f64::INFINITY;
-        let mut data_max_x = f64::NEG_INFINITY;
-        let mut data_max_y = f64::NEG_INFINITY;
-
-        for i in (0..point_count).step_by(step.max(1)) {
-            let p = &points[i];
-            data_min_x = data_min_x.min(p.x);
-            data_min_y = data_min_y.min(p.y);
-            data_max_x = data_max_x.max(p.x);
-            data_max_y = data_max_y.max(p.y);
-        }
-
-        let data_area = (data_max_x - data_min_x) * (data_max_y - data_min_y);
-        let selectivity = if data_area > 0.0 {
-            query_area / data_area
-        } else {
-            1.0
+        let use_linear = match strategy {
+            SpatialIndexStrategy::AlwaysLinear => true,
+            SpatialIndexStrategy::AlwaysGrid | SpatialIndexStrategy::AlwaysRtree => false,
+            SpatialIndexStrategy::Dynamic { threshold } => point_count < threshold,
         };
+        if use_linear {
+            return linear_scan();
+        }
 
-        println!("  üìê Query selectivity: {:.1}%", selectivity * 100.0);
+        println!("  🗂️ Building spatial index for {} points...", point_count);
+        let start = std::time::Instant::now();
 
-        // Use grid index for moderate selectivity, quadtree for very selective queries
-        let result = if selectivity > 0.5 || point_count < 100_000 {
-            // Grid index - faster to build
-            // Cell size based on expected point density
+        let use_grid = matches!(strategy, SpatialIndexStrategy::AlwaysGrid);
+        let candidate_indices = if use_grid {
+            // Grid index - faster to build. Cell size based on expected point density.
+            let mut data_min_x = f64::INFINITY;
+            let mut data_min_y = f64::INFINITY;
+            let mut data_max_x = f64::NEG_INFINITY;
+            let mut data_max_y = f64::NEG_INFINITY;
+            for p in points {
+                data_min_x = data_min_x.min(p.x);
+                data_min_y = data_min_y.min(p.y);
+                data_max_x = data_max_x.max(p.x);
+                data_max_y = data_max_y.max(p.y);
+            }
             let cell_size = ((data_max_x - data_min_x) / 100.0)
                 .max((data_max_y - data_min_y) / 100.0)
                 .max(10.0); // Minimum 10m cells
 
             let grid_index = SpatialGridIndex::build_from_points(points, cell_size);
-            println!("  üìä {}", grid_index.stats());
-
-            let candidate_indices = grid_index.query_bbox(x_min, y_min, x_max, y_max);
-            println!(
-                "  üîç Grid query returned {} candidates",
-                candidate_indices.len()
-            );
-
-            // Final precise filtering
-            candidate_indices
-                .into_iter()
-                .filter_map(|i| {
-                    let p = &points[i];
-                    if p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max {
-                        Some(p.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
+            println!("  📊 {}", grid_index.stats());
+            let candidates = grid_index.query_bbox(x_min, y_min, x_max, y_max);
+            println!("  🔍 Grid query returned {} candidates", candidates.len());
+            candidates
         } else {
-            // Quadtree - better for very selective queries on large datasets
-            let quadtree = QuadtreeSpatialIndex::build(points);
-            println!("  üìä {}", quadtree.stats());
-
-            let candidate_indices = quadtree.query_bbox(x_min, y_min, x_max, y_max);
-            println!(
-                "  üîç Quadtree query returned {} candidates",
-                candidate_indices.len()
-            );
-
-            // Final precise filtering
-            candidate_indices
-                .into_iter()
-                .filter_map(|i| {
-                    let p = &points[i];
-                    if p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max {
-                        Some(p.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
+            // R-tree - out-queries the uniform grid on clustered, non-uniform point
+            // distributions, which is the norm for tiled LiDAR.
+            let rtree_index = RtreeSpatialIndex::build(points);
+            println!("  📊 {}", rtree_index.stats());
+            let candidates = rtree_index.query_bbox(x_min, y_min, x_max, y_max);
+            println!("  🔍 R-tree query returned {} candidates", candidates.len());
+            candidates
         };
 
+        // Final precise filtering
+        let result: Vec<LidarPoint> = candidate_indices
+            .into_iter()
+            .filter_map(|i| {
+                let p = &points[i];
+                if p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max {
+                    Some(p.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
         let elapsed = start.elapsed();
         println!(
-            "  ‚è±Ô∏è Spatial indexing and query took {:.2}s",
+            "  ⏱️ Spatial indexing and query took {:.2}s",
             elapsed.as_secs_f64()
         );
 
@@ -1105,16 +3196,19 @@ impl Lidar {
         y_min: f64,
         x_max: f64,
         y_max: f64,
+        strategy: SpatialIndexStrategy,
     ) -> Vec<LidarPoint> {
         let point_count = points.len();
 
         // For datasets over 1M points, use chunked parallel processing
         if point_count < 1_000_000 {
-            return Self::filter_points_with_spatial_index(points, x_min, y_min, x_max, y_max);
+            return Self::filter_points_with_spatial_index(
+                points, x_min, y_min, x_max, y_max, strategy,
+            );
         }
 
         println!(
-            "  üöÄ Using parallel spatial indexing for {} points...",
+            "  🚀 Using parallel spatial indexing for {} points...",
             point_count
         );
         let start = std::time::Instant::now();
@@ -1125,20 +3219,76 @@ impl Lidar {
 
         let results: Vec<Vec<LidarPoint>> = chunks
             .par_iter()
-            .map(|chunk| Self::filter_points_with_spatial_index(chunk, x_min, y_min, x_max, y_max))
+            .map(|chunk| {
+                Self::filter_points_with_spatial_index(chunk, x_min, y_min, x_max, y_max, strategy)
+            })
             .collect();
 
         let result: Vec<LidarPoint> = results.into_iter().flatten().collect();
 
         let elapsed = start.elapsed();
         println!(
-            "  ‚è±Ô∏è Parallel spatial filtering took {:.2}s",
+            "  ⏱️ Parallel spatial filtering took {:.2}s",
             elapsed.as_secs_f64()
         );
 
         result
     }
 
+    /// Stream a COPC file's hierarchy directly over HTTP Range requests, without ever
+    /// downloading or caching the whole file. `max_depth` caps the octree level visited
+    /// (COPC root is level 0; each level roughly halves cell size and is a finer level
+    /// of detail), so callers that only need a coarse preview can stop well short of the
+    /// full-resolution leaves.
+    #[cfg(all(feature = "lidar-copc", feature = "reqwest"))]
+    pub fn load_copc_streamed(
+        url: &str,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        max_depth: Option<i32>,
+    ) -> Result<Vec<LidarPoint>> {
+        let store = store_for_url(url)?;
+        let content_length = store
+            .head_len(url)
+            .context("HEAD request failed or no Content-Length; cannot stream COPC via Range")?;
+
+        let reader = PointStoreRangeReader::new(store, url, content_length);
+        let mut entry_reader =
+            las::CopcEntryReader::new(reader).context("Failed to open COPC entry reader over Range requests")?;
+
+        let root = match entry_reader.header().copc_info_vlr() {
+            Some(info) => CopcRootBounds {
+                center: (info.center_x, info.center_y, info.center_z),
+                halfsize: info.halfsize,
+            },
+            None => anyhow::bail!("File missing COPC VLR; not a valid COPC file for streamed reading"),
+        };
+
+        let mut entries = entry_reader
+            .hierarchy_entries()
+            .context("Could not read COPC hierarchy over HTTP Range")?;
+
+        if let Some(depth) = max_depth {
+            entries.retain(|e| e.key.level <= depth);
+        }
+
+        println!(
+            "  üìä COPC streamed hierarchy: {} entries selected (max_depth={:?})",
+            entries.len(),
+            max_depth
+        );
+
+        let result = Self::read_copc_entries(&mut entry_reader, &entries, filter_bbox, Some(root));
+        println!(
+            "  üìä COPC streamed results: {}/{} entries read, {} points ({} pruned by bbox)",
+            result.entries_success,
+            result.entries_processed,
+            result.points.len(),
+            result.entries_pruned_bbox
+        );
+
+        Ok(result.points)
+    }
+
     /// Load a single COPC file with proper error handling and fallback to standard LAZ
     #[cfg(feature = "lidar-copc")]
     fn load_single_copc_file(
@@ -1146,6 +3296,7 @@ impl Lidar {
         url: &str,
         cache_dir: &Path,
         filter_bbox: Option<(f64, f64, f64, f64)>,
+        max_depth: Option<i32>,
     ) -> Result<Vec<LidarPoint>> {
         use std::io::Cursor;
 
@@ -1161,27 +3312,17 @@ impl Lidar {
                 }
                 Ok(false) | Err(_) => {
                     eprintln!("  ‚ö†Ô∏è Cached file appears corrupted, re-downloading...");
-                    let _ = std::fs::remove_file(&cache_path);
-
-                    let client = reqwest::blocking::Client::builder()
-                        .connect_timeout(std::time::Duration::from_secs(30))
-                        .timeout(std::time::Duration::from_secs(900)) // 15 min timeout for large files
-                        .build()
-                        .context("Failed to create HTTP client")?;
+                    Self::quarantine_corrupt_file(&cache_path);
 
-                    Self::download_with_verification(&client, url, &cache_path)?
+                    let store = store_for_url(url)?;
+                    Self::download_with_verification(store.as_ref(), url, &cache_path)?
                 }
             }
         } else {
             println!("üåê Downloading COPC: {}", url);
 
-            let client = reqwest::blocking::Client::builder()
-                .connect_timeout(std::time::Duration::from_secs(30))
-                .timeout(std::time::Duration::from_secs(900))
-                .build()
-                .context("Failed to create HTTP client")?;
-
-            Self::download_with_verification(&client, url, &cache_path)?
+            let store = store_for_url(url)?;
+            Self::download_with_verification(store.as_ref(), url, &cache_path)?
         };
 
         println!(
@@ -1190,26 +3331,58 @@ impl Lidar {
             bytes.len() as f64 / 1_048_576.0
         );
 
+        // Resolve source/target CRS: the file's own WKT VLR wins, falling back to the
+        // configured fallback EPSG, falling back to treating the file as already being in
+        // `geo_core`'s CRS (the historical assumption, preserved as the default).
+        let query_epsg = self.geo_core.get_epsg();
+        let detected_epsg = parse_las_header_from_slice(&bytes)
+            .ok()
+            .and_then(|h| source_epsg_from_vlrs(&h.vlrs));
+        let source_epsg = resolve_source_epsg(detected_epsg, self.source_epsg_fallback, query_epsg);
+        let target_epsg = self.target_epsg.unwrap_or(query_epsg);
+
+        // `filter_bbox` arrives expressed in `query_epsg`; translate it into the file's own
+        // CRS before using it to filter native-CRS point coordinates.
+        let filter_bbox = filter_bbox
+            .map(|bbox| transform_bbox(bbox, query_epsg, source_epsg))
+            .transpose()?;
+
         // Try COPC reader first
         let cursor = Cursor::new(bytes.clone());
         match las::CopcEntryReader::new(cursor) {
             Ok(mut entry_reader) => {
-                // Check for COPC info VLR
-                if entry_reader.header().copc_info_vlr().is_none() {
-                    println!("  ‚ö†Ô∏è File missing COPC VLR, falling back to standard LAZ reader");
-                    return Self::read_as_standard_laz(bytes, filter_bbox);
-                }
+                // Check for COPC info VLR, and keep its root cube for per-entry bbox pruning.
+                let root = match entry_reader.header().copc_info_vlr() {
+                    Some(info) => CopcRootBounds {
+                        center: (info.center_x, info.center_y, info.center_z),
+                        halfsize: info.halfsize,
+                    },
+                    None => {
+                        println!("  ‚ö†Ô∏è File missing COPC VLR, falling back to standard LAZ reader");
+                        return Self::read_as_standard_laz(bytes, filter_bbox, source_epsg, target_epsg);
+                    }
+                };
 
                 // Get hierarchy entries
-                let entries = match entry_reader.hierarchy_entries() {
+                let mut entries = match entry_reader.hierarchy_entries() {
                     Some(e) => e,
                     None => {
-                        println!("  ‚ö†Ô∏è Could not read COPC hierarchy, falling back to standard LAZ reader");
-                        return Self::read_as_standard_laz(bytes, filter_bbox);
+                        println!("    ‚ö†Ô∏è Could not read COPC hierarchy, falling back to standard LAZ reader");
+                        return Self::read_as_standard_laz(bytes, filter_bbox, source_epsg, target_epsg);
                     }
                 };
 
-                println!("  üìä COPC hierarchy: {} entries", entries.len());
+                let entries_total = entries.len();
+                if let Some(depth) = max_depth {
+                    entries.retain(|e| e.key.level <= depth);
+                }
+                let entries_pruned_depth = entries_total - entries.len();
+
+                println!(
+                    "    üìä COPC hierarchy: {} entries (max_depth={:?})",
+                    entries.len(),
+                    max_depth
+                );
 
                 if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
                     println!(
@@ -1219,7 +3392,8 @@ impl Lidar {
                 }
 
                 // Try to read entries
-                let result = Self::read_copc_entries(&mut entry_reader, &entries, filter_bbox);
+                let result =
+                    Self::read_copc_entries(&mut entry_reader, &entries, filter_bbox, Some(root));
 
                 // Check if we had too many failures
                 let failure_rate = if result.entries_processed > 0 {
@@ -1231,6 +3405,18 @@ impl Lidar {
                 println!("  üìä COPC Results:");
                 println!("     - Entries processed: {}", result.entries_processed);
                 println!("     - Successfully read: {}", result.entries_success);
+                if result.entries_pruned_bbox > 0 {
+                    println!(
+                        "     - Pruned by bbox (never fetched): {}",
+                        result.entries_pruned_bbox
+                    );
+                }
+                if entries_pruned_depth > 0 {
+                    println!(
+                        "     - Pruned by max_depth (never fetched): {}",
+                        entries_pruned_depth
+                    );
+                }
                 if result.entries_failed > 0 {
                     println!(
                         "     - Failed to read: {} ({:.1}%)",
@@ -1247,20 +3433,17 @@ impl Lidar {
                         failure_rate * 100.0
                     );
 
-                    // Delete potentially corrupted cache
+                    // Quarantine the potentially corrupted cache rather than deleting it
                     if cache_path.exists() {
-                        eprintln!("  üóëÔ∏è Removing potentially corrupted cache file");
-                        let _ = std::fs::remove_file(&cache_path);
+                        eprintln!("  🧯 Quarantining potentially corrupted cache file");
+                        Self::quarantine_corrupt_file(&cache_path);
                     }
 
                     // Re-download and try as standard LAZ
-                    let client = reqwest::blocking::Client::builder()
-                        .connect_timeout(std::time::Duration::from_secs(30))
-                        .timeout(std::time::Duration::from_secs(900))
-                        .build()?;
-
-                    let fresh_bytes = Self::download_with_verification(&client, url, &cache_path)?;
-                    return Self::read_as_standard_laz(fresh_bytes, filter_bbox);
+                    let store = store_for_url(url)?;
+                    let fresh_bytes =
+                        Self::download_with_verification(store.as_ref(), url, &cache_path)?;
+                    return Self::read_as_standard_laz(fresh_bytes, filter_bbox, source_epsg, target_epsg);
                 }
 
                 // If we got no points but had successful reads, the bbox might be outside the data
@@ -1268,35 +3451,179 @@ impl Lidar {
                     println!("  ‚ÑπÔ∏è No points found in bbox (data may be outside the query area)");
                 }
 
-                Ok(result.points)
+                let mut points = result.points;
+                reproject_points_in_place(&mut points, source_epsg, target_epsg)?;
+                Ok(points)
             }
             Err(e) => {
                 eprintln!("  ‚ö†Ô∏è COPC reader failed: {}", e);
                 eprintln!("  üìñ Falling back to standard LAZ reader");
-                Self::read_as_standard_laz(bytes, filter_bbox)
+                Self::read_as_standard_laz(bytes, filter_bbox, source_epsg, target_epsg)
             }
         }
     }
 
-    /// Read COPC entries and return statistics
+    /// Streaming sibling of `load_single_copc_file`: invokes `on_batch` with each COPC entry's
+    /// batch (reprojected) before dropping it, rather than collecting every point into a
+    /// `Vec`. Used by `process_lidar_points_streaming` to keep memory bounded. Falls back to
+    /// `for_each_point_in_standard_laz` in the same cases `load_single_copc_file` does (no
+    /// COPC VLR, no hierarchy, COPC reader failure); unlike `load_single_copc_file` it does
+    /// not retry on a high entry failure rate, since there is no buffered point `Vec` left to
+    /// discard and re-read from once points have already been streamed out.
     #[cfg(feature = "lidar-copc")]
-    fn read_copc_entries<R: std::io::Read + std::io::Seek>(
+    fn load_single_copc_file_streaming(
+        &self,
+        url: &str,
+        cache_dir: &Path,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        max_depth: Option<i32>,
+        mut on_batch: impl FnMut(&[LidarPoint]),
+    ) -> Result<()> {
+        use std::io::Cursor;
+
+        let cache_path = Self::cache_path_for_url(cache_dir, url);
+
+        let bytes: Vec<u8> = if cache_path.exists() {
+            match Self::verify_cached_file(&cache_path) {
+                Ok(true) => {
+                    println!("📂 Reading COPC from cache: {:?}", cache_path);
+                    std::fs::read(&cache_path).context("Failed to read cached file")?
+                }
+                Ok(false) | Err(_) => {
+                    eprintln!("  ⚠️ Cached file appears corrupted, re-downloading...");
+                    Self::quarantine_corrupt_file(&cache_path);
+
+                    let store = store_for_url(url)?;
+                    Self::download_with_verification(store.as_ref(), url, &cache_path)?
+                }
+            }
+        } else {
+            println!("🌐 Downloading COPC: {}", url);
+
+            let store = store_for_url(url)?;
+            Self::download_with_verification(store.as_ref(), url, &cache_path)?
+        };
+
+        let query_epsg = self.geo_core.get_epsg();
+        let detected_epsg = parse_las_header_from_slice(&bytes)
+            .ok()
+            .and_then(|h| source_epsg_from_vlrs(&h.vlrs));
+        let source_epsg = resolve_source_epsg(detected_epsg, self.source_epsg_fallback, query_epsg);
+        let target_epsg = self.target_epsg.unwrap_or(query_epsg);
+
+        let filter_bbox = filter_bbox
+            .map(|bbox| transform_bbox(bbox, query_epsg, source_epsg))
+            .transpose()?;
+
+        let cursor = Cursor::new(bytes.clone());
+        match las::CopcEntryReader::new(cursor) {
+            Ok(mut entry_reader) => {
+                let root = match entry_reader.header().copc_info_vlr() {
+                    Some(info) => CopcRootBounds {
+                        center: (info.center_x, info.center_y, info.center_z),
+                        halfsize: info.halfsize,
+                    },
+                    None => {
+                        println!("  ⚠️ File missing COPC VLR, falling back to standard LAZ reader");
+                        return Self::for_each_point_in_standard_laz(
+                            bytes, filter_bbox, source_epsg, target_epsg, on_batch,
+                        );
+                    }
+                };
+
+                let mut entries = match entry_reader.hierarchy_entries() {
+                    Some(e) => e,
+                    None => {
+                        println!(
+                            "    ⚠️ Could not read COPC hierarchy, falling back to standard LAZ reader"
+                        );
+                        return Self::for_each_point_in_standard_laz(
+                            bytes, filter_bbox, source_epsg, target_epsg, on_batch,
+                        );
+                    }
+                };
+
+                if let Some(depth) = max_depth {
+                    entries.retain(|e| e.key.level <= depth);
+                }
+
+                let mut reproject_err: Option<anyhow::Error> = None;
+                let result = Self::for_each_point_in_copc_entries(
+                    &mut entry_reader,
+                    &entries,
+                    filter_bbox,
+                    Some(root),
+                    |batch| {
+                        if reproject_err.is_some() {
+                            return;
+                        }
+                        let mut batch = batch.to_vec();
+                        match reproject_points_in_place(&mut batch, source_epsg, target_epsg) {
+                            Ok(()) => on_batch(&batch),
+                            Err(e) => reproject_err = Some(e),
+                        }
+                    },
+                );
+
+                if let Some(e) = reproject_err {
+                    return Err(e);
+                }
+
+                println!(
+                    "  📊 COPC streaming: {}/{} entries read ({} failed)",
+                    result.entries_success, result.entries_processed, result.entries_failed
+                );
+
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("  ⚠️ COPC reader failed: {}", e);
+                eprintln!("  📖 Falling back to standard LAZ reader");
+                Self::for_each_point_in_standard_laz(bytes, filter_bbox, source_epsg, target_epsg, on_batch)
+            }
+        }
+    }
+
+
+    /// Read COPC entries, decoding and bbox-filtering one entry's chunk at a time and handing
+    /// each batch to `on_batch` before moving to the next entry -- the batch is dropped
+    /// immediately after, so memory use stays bounded by one entry's chunk size rather than
+    /// the whole tile's point count. When both `filter_bbox` and `root` are given, an entry
+    /// whose cube (derived from its octree key via `copc_entry_bbox_2d`) doesn't overlap the
+    /// bbox is skipped before `read_entry_points` is ever called, so its point chunk is never
+    /// fetched over the wire -- not just filtered out afterwards. `read_copc_entries` is a
+    /// thin Vec-collecting wrapper over this (its `CopcReadResult::points` is populated from
+    /// the batches; this function's own return value leaves it empty).
+    #[cfg(feature = "lidar-copc")]
+    fn for_each_point_in_copc_entries<R: std::io::Read + std::io::Seek>(
         entry_reader: &mut las::CopcEntryReader<R>,
         entries: &[las::copc::Entry],
         filter_bbox: Option<(f64, f64, f64, f64)>,
+        root: Option<CopcRootBounds>,
+        mut on_batch: impl FnMut(&[LidarPoint]),
     ) -> CopcReadResult {
-        let mut all_points: Vec<las::Point> = Vec::new();
         let mut chunk = Vec::new();
+        let mut batch: Vec<LidarPoint> = Vec::new();
 
         let mut entries_processed = 0;
         let mut entries_success = 0;
         let mut entries_failed = 0;
+        let mut entries_pruned_bbox = 0;
+        let mut points_total = 0usize;
 
         for entry in entries {
             if entry.point_count <= 0 {
                 continue;
             }
 
+            if let (Some(bbox), Some(root)) = (filter_bbox, root) {
+                let cube = copc_entry_bbox_2d(entry, &root);
+                if !bbox_overlaps(cube, bbox) {
+                    entries_pruned_bbox += 1;
+                    continue;
+                }
+            }
+
             entries_processed += 1;
             chunk.clear();
 
@@ -1304,17 +3631,30 @@ impl Lidar {
                 Ok(_) => {
                     entries_success += 1;
 
-                    // Apply spatial filter if provided
-                    if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
-                        let filtered: Vec<las::Point> = chunk
-                            .drain(..)
+                    batch.clear();
+                    batch.extend(
+                        chunk
+                            .iter()
                             .filter(|p| {
-                                p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max
+                                filter_bbox.map_or(true, |(x_min, y_min, x_max, y_max)| {
+                                    p.x >= x_min && p.x <= x_max && p.y >= y_min && p.y <= y_max
+                                })
                             })
-                            .collect();
-                        all_points.extend(filtered);
-                    } else {
-                        all_points.extend(chunk.drain(..));
+                            .map(|p| LidarPoint {
+                                x: p.x,
+                                y: p.y,
+                                z: p.z,
+                                classification: classification_to_u8(&p.classification),
+                                intensity: p.intensity,
+                                return_number: p.return_number,
+                                number_of_returns: p.number_of_returns,
+                                rgb: p.color.as_ref().map(|c| (c.red, c.green, c.blue)),
+                            }),
+                    );
+
+                    points_total += batch.len();
+                    if !batch.is_empty() {
+                        on_batch(&batch);
                     }
                 }
                 Err(_) => {
@@ -1328,109 +3668,63 @@ impl Lidar {
                     "  ‚è≥ Progress: {}/{} entries, {} points",
                     entries_processed,
                     entries.len(),
-                    all_points.len()
+                    points_total
                 );
             }
         }
 
-        // Convert to LidarPoint
-        #[cfg(feature = "rayon")]
-        let points: Vec<LidarPoint> = all_points
-            .par_iter()
-            .map(|p| LidarPoint {
-                x: p.x,
-                y: p.y,
-                z: p.z,
-                classification: classification_to_u8(&p.classification),
-            })
-            .collect();
-
-        #[cfg(not(feature = "rayon"))]
-        let points: Vec<LidarPoint> = all_points
-            .iter()
-            .map(|p| LidarPoint {
-                x: p.x,
-                y: p.y,
-                z: p.z,
-                classification: classification_to_u8(&p.classification),
-            })
-            .collect();
-
         CopcReadResult {
-            points,
+            points: Vec::new(),
             entries_processed,
             entries_success,
             entries_failed,
+            entries_pruned_bbox,
         }
     }
 
+    /// Read COPC entries and return every surviving point collected into one `Vec`. Thin
+    /// wrapper over `for_each_point_in_copc_entries` for callers that need the whole tile's
+    /// points at once rather than a bounded-memory streaming callback.
+    #[cfg(feature = "lidar-copc")]
+    fn read_copc_entries<R: std::io::Read + std::io::Seek>(
+        entry_reader: &mut las::CopcEntryReader<R>,
+        entries: &[las::copc::Entry],
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        root: Option<CopcRootBounds>,
+    ) -> CopcReadResult {
+        let mut points = Vec::new();
+        let mut result = Self::for_each_point_in_copc_entries(
+            entry_reader,
+            entries,
+            filter_bbox,
+            root,
+            |batch| points.extend_from_slice(batch),
+        );
+        result.points = points;
+        result
+    }
+
     /// Download full LAZ file with a single GET (fallback when Range is not supported).
-    #[cfg(feature = "reqwest")]
-    fn download_laz_full_get(client: &reqwest::blocking::Client, url: &str) -> Result<Vec<u8>> {
-        use std::io::Read;
-        let mut retries = 3;
-        loop {
-            let response = match client.get(url).send() {
-                Ok(r) => r,
-                Err(e) => {
-                    retries -= 1;
-                    if retries > 0 {
-                        std::thread::sleep(std::time::Duration::from_secs(2));
-                        continue;
-                    }
-                    return Err(anyhow::anyhow!(
-                        "Failed to download LAZ from {} after retries: {}",
-                        url,
-                        e
-                    ));
-                }
-            };
-            if !response.status().is_success() {
-                return Err(anyhow::anyhow!(
-                    "HTTP {} when downloading {}",
-                    response.status(),
-                    url
-                ));
-            }
-            let mut data = Vec::new();
-            let mut buffer = [0u8; 8192];
-            let mut response = response;
-            loop {
-                match response.read(&mut buffer) {
-                    Ok(0) => break,
-                    Ok(n) => data.extend_from_slice(&buffer[..n]),
-                    Err(e) => {
-                        retries -= 1;
-                        if retries > 0 {
-                            std::thread::sleep(std::time::Duration::from_secs(2));
-                            break;
-                        }
-                        return Err(anyhow::anyhow!("Failed to read from {}: {}", url, e));
-                    }
-                }
-            }
-            if !data.is_empty() {
-                return Ok(data);
-            }
-            retries -= 1;
-            if retries == 0 {
-                return Err(anyhow::anyhow!("Empty response from {}", url));
-            }
-        }
+    fn download_laz_full_get(store: &dyn PointStore, url: &str) -> Result<Vec<u8>> {
+        store
+            .get_all(url)
+            .with_context(|| format!("Failed to download LAZ from {}", url))
     }
 
-    /// Download LAZ via HTTP Range: header first, then point data. Returns full file bytes.
-    /// Fails with Err if server does not support Range (206) or HEAD Content-Length.
-    #[cfg(feature = "reqwest")]
-    fn download_laz_via_range(client: &reqwest::blocking::Client, url: &str) -> Result<Vec<u8>> {
-        const HEADER_RANGE_END: u64 = 4095; // bytes 0..4096
+    /// Download LAZ via Range requests: header first, then point data. Returns full file
+    /// bytes. Fails with Err if the backing `PointStore` doesn't support Range (206) or
+    /// can't report a content length.
+    fn download_laz_via_range(store: &dyn PointStore, url: &str) -> Result<Vec<u8>> {
+        const HEADER_RANGE_LEN: u64 = 4096; // bytes 0..4096
 
-        let header_bytes = download_partial_laz(client, url, 0, HEADER_RANGE_END, None::<fn(u64)>)
+        let header_bytes = store
+            .get_range(url, 0, HEADER_RANGE_LEN)
             .map_err(|e| anyhow::anyhow!("Range request for header failed: {}", e))?;
         let parsed = parse_las_header_from_slice(&header_bytes)?;
         let offset = parsed.offset_to_point_data as u64;
-        let content_length = head_content_length(client, url)
-            .ok_or_else(|| anyhow::anyhow!("HEAD request failed or no Content-Length"))?;
+        let content_length = store
+            .head_len(url)
+            .map_err(|e| anyhow::anyhow!("HEAD request failed or no Content-Length: {}", e))?;
         if offset >= content_length {
             anyhow::bail!(
                 "Invalid LAZ: offset_to_point_data {} >= content_length {}",
@@ -1439,44 +3733,37 @@ impl Lidar {
             );
         }
 
-        let header_buf: Vec<u8> = if offset <= (HEADER_RANGE_END + 1) {
+        let header_buf: Vec<u8> = if offset <= HEADER_RANGE_LEN {
             header_bytes.into_iter().take(offset as usize).collect()
         } else {
-            let rest = download_partial_laz(
-                client,
-                url,
-                HEADER_RANGE_END + 1,
-                offset - 1,
-                None::<fn(u64)>,
-            )
-            .map_err(|e| anyhow::anyhow!("Range request for header tail failed: {}", e))?;
+            let rest = store
+                .get_range(url, HEADER_RANGE_LEN, offset - HEADER_RANGE_LEN)
+                .map_err(|e| anyhow::anyhow!("Range request for header tail failed: {}", e))?;
             let mut out = header_bytes;
             out.extend(rest);
             out
         };
 
+        let point_data_len = content_length - offset;
         #[cfg(feature = "indicatif")]
         let point_data = {
-            let point_data_len = content_length - offset;
             let pb = indicatif::ProgressBar::new(point_data_len);
             pb.set_style(progress_style());
             pb.set_message("Range: point data");
-            let result = download_partial_laz(
-                client,
-                url,
-                offset,
-                content_length - 1,
-                Some(|n| pb.set_position(n)),
-            )
-            .map_err(|e| anyhow::anyhow!("Range request for point data failed: {}", e));
+            let result = store
+                .get_range(url, offset, point_data_len)
+                .map_err(|e| anyhow::anyhow!("Range request for point data failed: {}", e));
+            if result.is_ok() {
+                pb.set_position(point_data_len);
+            }
             pb.finish_with_message("Point data downloaded");
             result?
         };
 
         #[cfg(not(feature = "indicatif"))]
-        let point_data =
-            download_partial_laz(client, url, offset, content_length - 1, None::<fn(u64)>)
-                .map_err(|e| anyhow::anyhow!("Range request for point data failed: {}", e))?;
+        let point_data = store
+            .get_range(url, offset, point_data_len)
+            .map_err(|e| anyhow::anyhow!("Range request for point data failed: {}", e))?;
 
         let mut full = header_buf;
         full.extend(point_data);
@@ -1491,13 +3778,33 @@ impl Lidar {
         cache_dir: &Path,
         filter_bbox: Option<(f64, f64, f64, f64)>,
     ) -> Result<Vec<LidarPoint>> {
+        let mut points = Vec::new();
+        self.load_single_laz_file_streaming(url, cache_dir, filter_bbox, |batch| {
+            points.extend_from_slice(batch);
+        })?;
+        Ok(points)
+    }
+
+    /// Stream a single LAZ file's points (from URL or cache), bbox-filtered and reprojected,
+    /// invoking `on_batch` with each batch before dropping it. `load_single_laz_file` is a
+    /// thin Vec-collecting wrapper over this for callers (e.g. `load_manifest`) that want the
+    /// whole tile's points in memory at once.
+    fn load_single_laz_file_streaming(
+        &self,
+        url: &str,
+        cache_dir: &Path,
+        filter_bbox: Option<(f64, f64, f64, f64)>,
+        mut on_batch: impl FnMut(&[LidarPoint]),
+    ) -> Result<()> {
         use std::io::Cursor;
 
+        const BATCH_SIZE: usize = 50_000;
+
         let cache_path = Self::cache_path_for_url(cache_dir, url);
 
         let map_reader_err = |e: las::Error| {
             if cache_path.exists() {
-                let _ = std::fs::remove_file(&cache_path);
+                Self::quarantine_corrupt_file(&cache_path);
             }
             anyhow::anyhow!("Failed to create LAS reader for {}: {}", url, e)
         };
@@ -1509,8 +3816,8 @@ impl Lidar {
                     println!("üìÇ Reading LAZ from cache: {:?}", cache_path);
                 }
                 Ok(false) | Err(_) => {
-                    eprintln!("  ‚ö†Ô∏è Cached file appears corrupted, removing...");
-                    let _ = std::fs::remove_file(&cache_path);
+                    eprintln!("  ‚ö†Ô∏è Cached file appears corrupted, quarantining...");
+                    Self::quarantine_corrupt_file(&cache_path);
                     // Fall through to download
                 }
             }
@@ -1536,24 +3843,16 @@ impl Lidar {
             } else {
                 // File was removed, need to download
                 println!("üåê Downloading LAZ: {} ...", url);
-                let client = reqwest::blocking::Client::builder()
-                    .connect_timeout(std::time::Duration::from_secs(30))
-                    .timeout(std::time::Duration::from_secs(600))
-                    .build()
-                    .context("Failed to create HTTP client")?;
+                let store = store_for_url(url)?;
 
-                let compressed_data = Self::download_with_verification(&client, url, &cache_path)?;
+                let compressed_data = Self::download_with_verification(store.as_ref(), url, &cache_path)?;
                 las::Reader::new(Cursor::new(compressed_data)).map_err(map_reader_err)?
             }
         } else {
             println!("üåê Downloading LAZ: {} ...", url);
-            let client = reqwest::blocking::Client::builder()
-                .connect_timeout(std::time::Duration::from_secs(30))
-                .timeout(std::time::Duration::from_secs(600))
-                .build()
-                .context("Failed to create HTTP client")?;
+            let store = store_for_url(url)?;
 
-            let compressed_data: Vec<u8> = match Self::download_laz_via_range(&client, url) {
+            let compressed_data: Vec<u8> = match Self::download_laz_via_range(store.as_ref(), url) {
                 Ok(data) => {
                     // Verify and cache
                     if data.len() < 4 || &data[0..4] != b"LASF" {
@@ -1565,7 +3864,7 @@ impl Lidar {
                 }
                 Err(_) => {
                     // Fallback: full GET (Range not supported or HEAD/parse failed)
-                    Self::download_with_verification(&client, url, &cache_path)?
+                    Self::download_with_verification(store.as_ref(), url, &cache_path)?
                 }
             };
 
@@ -1573,66 +3872,67 @@ impl Lidar {
         };
 
         let point_count = reader.header().number_of_points() as usize;
-        println!("  üìä Header declares {} points", point_count);
+        println!("    üìä Header declares {} points", point_count);
+
+        // Resolve source/target CRS: the file's own WKT VLR wins, falling back to the
+        // configured fallback EPSG, falling back to treating the file as already being in
+        // `geo_core`'s CRS (the historical assumption, preserved as the default).
+        let query_epsg = self.geo_core.get_epsg();
+        let detected_epsg = source_epsg_from_vlrs(&Self::peek_vlrs(&cache_path));
+        let source_epsg = resolve_source_epsg(detected_epsg, self.source_epsg_fallback, query_epsg);
+        let target_epsg = self.target_epsg.unwrap_or(query_epsg);
+
+        // `filter_bbox` arrives expressed in `query_epsg`; translate it into the file's own
+        // CRS before using it to filter native-CRS point coordinates.
+        let filter_bbox = filter_bbox
+            .map(|bbox| transform_bbox(bbox, query_epsg, source_epsg))
+            .transpose()?;
+
+        // Stream points one at a time (no intermediate `Vec<las::Point>`), batching only for
+        // the callback boundary so memory use stays bounded by `BATCH_SIZE` rather than the
+        // whole tile's point count.
+        let mut batch: Vec<LidarPoint> = Vec::with_capacity(BATCH_SIZE);
+        let mut total = 0usize;
 
-        let mut raw_points: Vec<las::Point> = Vec::with_capacity(point_count);
         for point_result in reader.points() {
-            if let Ok(p) = point_result {
-                raw_points.push(p);
-            }
-        }
-
-        println!("  üìä Read {} points", raw_points.len());
-
-        // Convert to LidarPoint
-        #[cfg(feature = "rayon")]
-        let all_points: Vec<LidarPoint> = raw_points
-            .par_iter()
-            .map(|point| LidarPoint {
-                x: point.x,
-                y: point.y,
-                z: point.z,
-                classification: classification_to_u8(&point.classification),
-            })
-            .collect();
-
-        #[cfg(not(feature = "rayon"))]
-        let all_points: Vec<LidarPoint> = raw_points
-            .iter()
-            .map(|point| LidarPoint {
-                x: point.x,
-                y: point.y,
-                z: point.z,
-                classification: classification_to_u8(&point.classification),
-            })
-            .collect();
-
-        // Apply spatial filtering using index if we have a bbox
-        let file_points = if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
-            #[cfg(feature = "rayon")]
-            {
-                Self::filter_points_with_spatial_index_parallel(
-                    &all_points,
-                    x_min,
-                    y_min,
-                    x_max,
-                    y_max,
-                )
+            let p = match point_result {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            if let Some((x_min, y_min, x_max, y_max)) = filter_bbox {
+                if p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max {
+                    continue;
+                }
             }
-            #[cfg(not(feature = "rayon"))]
-            {
-                Self::filter_points_with_spatial_index(&all_points, x_min, y_min, x_max, y_max)
+
+            batch.push(LidarPoint {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                classification: classification_to_u8(&p.classification),
+                intensity: p.intensity,
+                return_number: p.return_number,
+                number_of_returns: p.number_of_returns,
+                rgb: p.color.as_ref().map(|c| (c.red, c.green, c.blue)),
+            });
+            total += 1;
+
+            if batch.len() >= BATCH_SIZE {
+                reproject_points_in_place(&mut batch, source_epsg, target_epsg)?;
+                on_batch(&batch);
+                batch.clear();
             }
-        } else {
-            all_points
-        };
+        }
 
-        println!(
-            "  ‚úì Loaded {} points after spatial filter",
-            file_points.len()
-        );
+        if !batch.is_empty() {
+            reproject_points_in_place(&mut batch, source_epsg, target_epsg)?;
+            on_batch(&batch);
+        }
 
-        Ok(file_points)
+        println!("  ‚úì Streamed {} points after spatial filter", total);
+
+        Ok(())
     }
 
     /// Get LiDAR point cloud URLs from WFS service
@@ -1646,9 +3946,10 @@ impl Lidar {
 
         println!("üì¶ Bounding box set");
 
-        // Transform bbox from EPSG:4326 to EPSG:2154
-        // Python: transformer = Transformer.from_crs("EPSG:4326", "EPSG:2154", always_xy=True)
-        let transformer = Proj::new_known_crs("EPSG:4326", "EPSG:2154", None)
+        // Transform the lon/lat bbox into `geo_core`'s query EPSG (defaults to EPSG:2154,
+        // but honors `set_epsg` for datasets outside the IGN/Lambert-93 area)
+        let query_crs = format!("EPSG:{}", self.geo_core.get_epsg());
+        let transformer = Proj::new_known_crs("EPSG:4326", &query_crs, None)
             .context("Failed to create coordinate transformer")?;
 
         let (min_x, min_y) = transformer
@@ -1822,6 +4123,402 @@ impl Lidar {
         }
     }
 
+    /// Progressive morphological filter (Zhang et al., 2003) ground classification, used
+    /// when a tile carries no classification-2 (ground) points to build the DTM from
+    /// (raw/unclassified LAZ exports, or a classification filter that excluded ground).
+    /// Builds a minimum-elevation surface over growing window sizes, opens it (erosion
+    /// then dilation) at each step, and keeps a point as "ground" as long as it stays
+    /// within a slope-scaled elevation tolerance of the opened surface. Returns one bool
+    /// per input point, true where the filter considers it ground. `params` controls the
+    /// slope/tolerance/window-growth knobs; see `PmfParams`.
+    fn classify_ground_pmf(
+        points: &[LidarPoint],
+        x_min: f64,
+        y_min: f64,
+        x_max: f64,
+        y_max: f64,
+        resolution: f64,
+        params: &PmfParams,
+    ) -> Vec<bool> {
+        let width = (((x_max - x_min) / resolution).ceil() as usize).max(1);
+        let height = (((y_max - y_min) / resolution).ceil() as usize).max(1);
+
+        let cell_of = |p: &LidarPoint| -> (usize, usize) {
+            let col = (((p.x - x_min) / resolution).floor() as isize)
+                .clamp(0, width as isize - 1) as usize;
+            let row = (((y_max - p.y) / resolution).floor() as isize)
+                .clamp(0, height as isize - 1) as usize;
+            (row, col)
+        };
+
+        // Seed the working surface with the minimum elevation per cell (empty cells stay NaN).
+        let mut surface = vec![vec![f64::NAN; width]; height];
+        for p in points {
+            let (row, col) = cell_of(p);
+            if surface[row][col].is_nan() || p.z < surface[row][col] {
+                surface[row][col] = p.z;
+            }
+        }
+
+        let mut window = 1usize;
+        let mut prev_window = 0usize;
+        while window <= params.max_window {
+            let opened = morphological_open(&surface, width, height, window);
+            let threshold = (params.slope * ((window - prev_window) as f64) * resolution
+                + params.dh0)
+                .min(params.dh_max);
+
+            for row in 0..height {
+                for col in 0..width {
+                    if surface[row][col].is_nan() || opened[row][col].is_nan() {
+                        continue;
+                    }
+                    // Cells that rose too far above the opened surface are non-ground
+                    // (a building or canopy return); clamp them down so later, larger
+                    // windows don't keep treating them as part of the terrain.
+                    if surface[row][col] - opened[row][col] > threshold {
+                        surface[row][col] = opened[row][col];
+                    }
+                }
+            }
+
+            prev_window = window;
+            window *= 2;
+        }
+
+        points
+            .iter()
+            .map(|p| {
+                let (row, col) = cell_of(p);
+                !surface[row][col].is_nan() && (p.z - surface[row][col]).abs() <= params.dh0
+            })
+            .collect()
+    }
+
+    /// Statistical outlier removal (PCL-style SOR): for each point, compute its mean
+    /// distance to its `k` nearest neighbours via `QuadtreeSpatialIndex::query_knn`, then
+    /// drop points whose mean distance exceeds `global_mean + std_dev_multiplier *
+    /// global_std_dev`. Lone returns far from the rest of the cloud (power lines, birds,
+    /// atmospheric noise) have a much larger mean neighbour distance than the bulk of the
+    /// points and are removed; everything else is kept.
+    pub fn remove_statistical_outliers(
+        points: Vec<LidarPoint>,
+        k: usize,
+        std_dev_multiplier: f64,
+    ) -> Vec<LidarPoint> {
+        if points.len() <= k {
+            return points;
+        }
+
+        let index = QuadtreeSpatialIndex::build(&points);
+        let mean_neighbor_distances: Vec<f64> = (0..points.len())
+            .map(|i| {
+                let neighbors = index.query_knn(&points, i, k);
+                if neighbors.is_empty() {
+                    return 0.0;
+                }
+                let p = &points[i];
+                let sum: f64 = neighbors
+                    .iter()
+                    .map(|&j| {
+                        let q = &points[j];
+                        let dx = q.x - p.x;
+                        let dy = q.y - p.y;
+                        let dz = q.z - p.z;
+                        (dx * dx + dy * dy + dz * dz).sqrt()
+                    })
+                    .sum();
+                sum / neighbors.len() as f64
+            })
+            .collect();
+
+        let global_mean =
+            mean_neighbor_distances.iter().sum::<f64>() / mean_neighbor_distances.len() as f64;
+        let variance = mean_neighbor_distances
+            .iter()
+            .map(|d| (d - global_mean).powi(2))
+            .sum::<f64>()
+            / mean_neighbor_distances.len() as f64;
+        let std_dev = variance.sqrt();
+        let threshold = global_mean + std_dev_multiplier * std_dev;
+
+        points
+            .into_iter()
+            .zip(mean_neighbor_distances)
+            .filter(|(_, mean_dist)| *mean_dist <= threshold)
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    /// Local Outlier Factor (Breunig et al. 2000) noise removal: drops spurious high/low
+    /// returns (birds, multipath) that `remove_statistical_outliers`'s global threshold can
+    /// miss in clouds with uneven density, since LOF compares each point's density against
+    /// its own neighbourhood rather than the whole cloud's. For each point, find its `k`
+    /// nearest neighbours via `QuadtreeSpatialIndex::query_knn`, then exact 3D distance;
+    /// `k_distance(p)` is the distance to the farthest of those neighbours. The reachability
+    /// distance `reach_k(p, o) = max(k_distance(o), dist(p, o))` damps noise when `p` is very
+    /// close to `o`. `lrd(p) = 1 / mean_o(reach_k(p, o))` is `p`'s local reachability density,
+    /// and `LOF(p) = mean_o(lrd(o) / lrd(p))` compares it against its neighbours' density:
+    /// ~1 means `p` sits in a neighbourhood as dense as its neighbours', well above 1 means
+    /// `p` is in a much sparser region than its neighbours -- an outlier. Points with
+    /// `LOF(p) > lof_threshold` are dropped. Returns the filtered points and how many were
+    /// dropped, so callers can tune `k`/`lof_threshold`.
+    pub fn remove_lof_outliers(
+        points: Vec<LidarPoint>,
+        k: usize,
+        lof_threshold: f64,
+    ) -> (Vec<LidarPoint>, usize) {
+        if points.len() <= k {
+            return (points, 0);
+        }
+
+        let dist3d = |a: &LidarPoint, b: &LidarPoint| -> f64 {
+            let dx = a.x - b.x;
+            let dy = a.y - b.y;
+            let dz = a.z - b.z;
+            (dx * dx + dy * dy + dz * dz).sqrt()
+        };
+
+        let index = QuadtreeSpatialIndex::build(&points);
+        let neighbors: Vec<Vec<usize>> = (0..points.len())
+            .map(|i| index.query_knn(&points, i, k))
+            .collect();
+
+        let k_distance: Vec<f64> = (0..points.len())
+            .map(|i| {
+                neighbors[i]
+                    .iter()
+                    .map(|&o| dist3d(&points[i], &points[o]))
+                    .fold(0.0, f64::max)
+            })
+            .collect();
+
+        let lrd: Vec<f64> = (0..points.len())
+            .map(|i| {
+                if neighbors[i].is_empty() {
+                    return 0.0;
+                }
+                let mean_reach: f64 = neighbors[i]
+                    .iter()
+                    .map(|&o| k_distance[o].max(dist3d(&points[i], &points[o])))
+                    .sum::<f64>()
+                    / neighbors[i].len() as f64;
+                if mean_reach > 0.0 {
+                    1.0 / mean_reach
+                } else {
+                    f64::INFINITY
+                }
+            })
+            .collect();
+
+        let lof: Vec<f64> = (0..points.len())
+            .map(|i| {
+                if neighbors[i].is_empty() || lrd[i] == 0.0 {
+                    return 1.0;
+                }
+                let sum: f64 = neighbors[i]
+                    .iter()
+                    .map(|&o| {
+                        if lrd[i].is_infinite() {
+                            if lrd[o].is_infinite() {
+                                1.0
+                            } else {
+                                0.0
+                            }
+                        } else {
+                            lrd[o] / lrd[i]
+                        }
+                    })
+                    .sum();
+                sum / neighbors[i].len() as f64
+            })
+            .collect();
+
+        let mut dropped = 0usize;
+        let kept: Vec<LidarPoint> = points
+            .into_iter()
+            .zip(lof)
+            .filter_map(|(p, score)| {
+                if score > lof_threshold {
+                    dropped += 1;
+                    None
+                } else {
+                    Some(p)
+                }
+            })
+            .collect();
+
+        (kept, dropped)
+    }
+
+    /// Iterative Closest Point registration of a "moving" point set onto a "fixed" one
+    /// (adapted from PDAL's `filters.icp`), to co-register overlapping tiles from
+    /// different flight lines or epochs before merging, or to report drift between them.
+    /// Each iteration: (1) find every moving point's nearest fixed point via
+    /// `fixed_index.k_nearest`; (2) drop correspondences farther than
+    /// `max_correspondence_distance`, if given; (3) solve the rigid transform aligning the
+    /// surviving pairs (`solve_rigid_transform`); (4) apply it to the moving points and
+    /// accumulate it into the running transform. Stops once the mean correspondence error
+    /// changes by less than `tolerance` between iterations, or `max_iterations` is hit.
+    /// Returns the final transform and RMSE of the last iteration's correspondences.
+    pub fn icp_align(
+        fixed: &[LidarPoint],
+        fixed_index: &QuadtreeSpatialIndex,
+        moving: &[LidarPoint],
+        max_iterations: usize,
+        tolerance: f64,
+        max_correspondence_distance: Option<f64>,
+    ) -> IcpResult {
+        let mut current: Vec<(f64, f64, f64)> = moving.iter().map(|p| (p.x, p.y, p.z)).collect();
+        let mut rotation = identity3();
+        let mut translation = [0.0; 3];
+        let mut prev_mean_sq_error = f64::INFINITY;
+        let mut rmse = 0.0;
+        let mut iterations_run = 0;
+
+        for iter in 0..max_iterations.max(1) {
+            iterations_run = iter + 1;
+
+            let mut pairs: Vec<((f64, f64, f64), (f64, f64, f64))> = Vec::with_capacity(current.len());
+            let mut sq_errors: Vec<f64> = Vec::with_capacity(current.len());
+            for &p in &current {
+                let Some(&nearest) = fixed_index.k_nearest(fixed, p, 1).first() else {
+                    continue;
+                };
+                let f = &fixed[nearest];
+                let dx = f.x - p.0;
+                let dy = f.y - p.1;
+                let dz = f.z - p.2;
+                let dist_sq = dx * dx + dy * dy + dz * dz;
+                if let Some(max_d) = max_correspondence_distance {
+                    if dist_sq > max_d * max_d {
+                        continue;
+                    }
+                }
+                sq_errors.push(dist_sq);
+                pairs.push((p, (f.x, f.y, f.z)));
+            }
+
+            if pairs.is_empty() {
+                break;
+            }
+
+            let mean_sq_error = sq_errors.iter().sum::<f64>() / sq_errors.len() as f64;
+            rmse = mean_sq_error.sqrt();
+
+            let (iter_rotation, iter_translation) = solve_rigid_transform(&pairs);
+
+            for p in current.iter_mut() {
+                let v = mat3_vec3(&iter_rotation, [p.0, p.1, p.2]);
+                *p = (
+                    v[0] + iter_translation[0],
+                    v[1] + iter_translation[1],
+                    v[2] + iter_translation[2],
+                );
+            }
+            let rotated_translation = mat3_vec3(&iter_rotation, translation);
+            translation = [
+                rotated_translation[0] + iter_translation[0],
+                rotated_translation[1] + iter_translation[1],
+                rotated_translation[2] + iter_translation[2],
+            ];
+            rotation = mat3_mul(&iter_rotation, &rotation);
+
+            if (prev_mean_sq_error - mean_sq_error).abs() < tolerance {
+                break;
+            }
+            prev_mean_sq_error = mean_sq_error;
+        }
+
+        IcpResult {
+            rotation,
+            translation,
+            rmse,
+            iterations: iterations_run,
+        }
+    }
+
+    /// Bin points into a 3D voxel grid with heights normalized to the ground surface, so
+    /// each voxel level represents a fixed band of height-above-ground (e.g. for canopy
+    /// layering or building-volume analysis) rather than raw elevation above the
+    /// reference ellipsoid. Ground height per XY cell comes from classified ground
+    /// returns where present, falling back to the progressive morphological filter
+    /// (`classify_ground_pmf`) otherwise.
+    pub fn to_voxel_grid(
+        points: &[LidarPoint],
+        bbox: (f64, f64, f64, f64),
+        resolution_xy: f64,
+        voxel_height: f64,
+        max_height: f64,
+    ) -> Result<VoxelGrid> {
+        let (x_min, y_min, x_max, y_max) = bbox;
+        let width = (((x_max - x_min) / resolution_xy).ceil() as usize).max(1);
+        let height = (((y_max - y_min) / resolution_xy).ceil() as usize).max(1);
+        let levels = ((max_height / voxel_height).ceil() as usize).max(1);
+
+        let has_classified_ground = points.iter().any(|p| p.classification == 2);
+        let ground_flags: Vec<bool> = if has_classified_ground {
+            points.iter().map(|p| p.classification == 2).collect()
+        } else {
+            Self::classify_ground_pmf(
+                points,
+                x_min,
+                y_min,
+                x_max,
+                y_max,
+                resolution_xy,
+                &PmfParams::default(),
+            )
+        };
+
+        let cell_of = |p: &LidarPoint| -> (usize, usize) {
+            let col = (((p.x - x_min) / resolution_xy).floor() as isize)
+                .clamp(0, width as isize - 1) as usize;
+            let row = (((y_max - p.y) / resolution_xy).floor() as isize)
+                .clamp(0, height as isize - 1) as usize;
+            (row, col)
+        };
+
+        // Ground height per XY cell: lowest ground-classified return in that cell.
+        let mut ground_grid = vec![vec![f64::NAN; width]; height];
+        for (point, is_ground) in points.iter().zip(ground_flags.iter()) {
+            if !is_ground {
+                continue;
+            }
+            let (row, col) = cell_of(point);
+            if ground_grid[row][col].is_nan() || point.z < ground_grid[row][col] {
+                ground_grid[row][col] = point.z;
+            }
+        }
+
+        let mut counts = vec![0u32; width * height * levels];
+        for point in points {
+            let (row, col) = cell_of(point);
+            let ground_z = ground_grid[row][col];
+            if ground_z.is_nan() {
+                continue;
+            }
+            let normalized_height = point.z - ground_z;
+            if normalized_height < 0.0 {
+                continue;
+            }
+            let level = (normalized_height / voxel_height).floor() as usize;
+            if level >= levels {
+                continue;
+            }
+            counts[(row * width + col) * levels + level] += 1;
+        }
+
+        Ok(VoxelGrid {
+            resolution_xy,
+            voxel_height,
+            width,
+            height,
+            levels,
+            counts,
+        })
+    }
+
     /// Process LiDAR points to create DSM, DTM, and CHM rasters
     /// Following Python: def process_lidar_points(self, points, bbox, classification_list, resolution)
     /// Returns ProcessedRasters with DSM, DTM, and CHM grids
@@ -1870,8 +4567,54 @@ impl Lidar {
         let mut dsm = vec![vec![f64::NEG_INFINITY; width]; height];
         let mut dtm = vec![vec![f64::NEG_INFINITY; width]; height];
 
+        // Extra per-cell accumulators, only populated when the corresponding band is
+        // selected so unused runs don't pay for stats nobody asked for.
+        let want_density = self.band_selection.density;
+        let want_intensity = self.band_selection.intensity;
+        let want_elevation_stats = self.band_selection.elevation_stats || self.band_selection.range;
+        let want_median_elevation = self.band_selection.median_elevation;
+        let mut point_count = vec![vec![0.0f64; width]; height];
+        let mut first_return_intensity_sum = vec![vec![0.0f64; width]; height];
+        let mut first_return_count = vec![vec![0.0f64; width]; height];
+        let mut elev_min = vec![vec![f64::INFINITY; width]; height];
+        let mut elev_max = vec![vec![f64::NEG_INFINITY; width]; height];
+        let mut elev_sum = vec![vec![0.0f64; width]; height];
+        let mut elev_sum_sq = vec![vec![0.0f64; width]; height];
+        let mut elev_count = vec![vec![0.0f64; width]; height];
+        // Only allocated when a median is requested: unlike the running accumulators above,
+        // the median needs every point's Z kept around per cell until the grid pass finishes.
+        let mut elev_values: Vec<Vec<Vec<f64>>> = if want_median_elevation {
+            vec![vec![Vec::new(); width]; height]
+        } else {
+            Vec::new()
+        };
+
+        // Tiles that already carry classified ground returns (classification 2) use them
+        // directly; unclassified/raw tiles fall back to a progressive morphological
+        // filter to synthesize a ground mask before the DTM is built.
+        let has_classified_ground =
+            !self.force_pmf_ground && filtered_points.iter().any(|p| p.classification == 2);
+        let ground_mask: Vec<bool> = if has_classified_ground {
+            filtered_points.iter().map(|p| p.classification == 2).collect()
+        } else {
+            if self.force_pmf_ground {
+                println!("force_pmf_ground set; running progressive morphological filter");
+            } else {
+                println!("No classified ground points found; running progressive morphological filter");
+            }
+            Self::classify_ground_pmf(
+                &filtered_points,
+                x_min,
+                y_min,
+                x_max,
+                y_max,
+                resolution,
+                &self.pmf_params,
+            )
+        };
+
         // Process points to fill grids
-        for point in &filtered_points {
+        for (point, is_ground) in filtered_points.iter().zip(ground_mask.iter()) {
             // Calculate grid indices
             let col = ((point.x - x_min) / resolution).floor() as usize;
             let row = ((y_max - point.y) / resolution).floor() as usize; // Y is inverted in raster
@@ -1882,49 +4625,145 @@ impl Lidar {
                     dsm[row][col] = point.z;
                 }
 
-                // DTM: maximum z value per cell for ground points only (classification 2)
-                if point.classification == 2 {
+                // DTM: maximum z value per cell among ground points (classified, or PMF-derived)
+                if *is_ground {
                     if point.z > dtm[row][col] || dtm[row][col] == f64::NEG_INFINITY {
                         dtm[row][col] = point.z;
                     }
                 }
+
+                if want_density {
+                    point_count[row][col] += 1.0;
+                }
+                if want_intensity && (point.return_number == 1 || point.return_number == 0) {
+                    first_return_intensity_sum[row][col] += point.intensity as f64;
+                    first_return_count[row][col] += 1.0;
+                }
+                if want_elevation_stats {
+                    if point.z < elev_min[row][col] {
+                        elev_min[row][col] = point.z;
+                    }
+                    if point.z > elev_max[row][col] {
+                        elev_max[row][col] = point.z;
+                    }
+                    elev_sum[row][col] += point.z;
+                    elev_sum_sq[row][col] += point.z * point.z;
+                    elev_count[row][col] += 1.0;
+                }
+                if want_median_elevation {
+                    elev_values[row][col].push(point.z);
+                }
             }
         }
 
-        // Fill DTM gaps using interpolation (simple: use nearest neighbor)
-        // For now, we'll use a simple approach: if a cell has no ground point, use the minimum of neighbors
-        let mut dtm_filled = dtm.clone();
-        for row in 0..height {
-            for col in 0..width {
-                if dtm_filled[row][col] == f64::NEG_INFINITY {
-                    // Find minimum value from neighbors
-                    let mut min_neighbor = f64::INFINITY;
-                    for dr in [-1, 0, 1] {
-                        for dc in [-1, 0, 1] {
-                            let r = row as i32 + dr;
-                            let c = col as i32 + dc;
-                            if r >= 0 && r < height as i32 && c >= 0 && c < width as i32 {
-                                let val = dtm[r as usize][c as usize];
-                                if val != f64::NEG_INFINITY && val < min_neighbor {
-                                    min_neighbor = val;
-                                }
-                            }
-                        }
+        let density = want_density.then_some(point_count);
+
+        let intensity = want_intensity.then(|| {
+            let mut grid = vec![vec![f64::NAN; width]; height];
+            for row in 0..height {
+                for col in 0..width {
+                    if first_return_count[row][col] > 0.0 {
+                        grid[row][col] = first_return_intensity_sum[row][col] / first_return_count[row][col];
                     }
-                    if min_neighbor != f64::INFINITY {
-                        dtm_filled[row][col] = min_neighbor;
-                    } else {
-                        dtm_filled[row][col] = 0.0; // Fallback
+                }
+            }
+            grid
+        });
+
+        let elevation_stats = want_elevation_stats.then(|| {
+            let mut min = vec![vec![f64::NAN; width]; height];
+            let mut max = vec![vec![f64::NAN; width]; height];
+            let mut mean = vec![vec![f64::NAN; width]; height];
+            let mut stddev = vec![vec![f64::NAN; width]; height];
+            let mut range = vec![vec![f64::NAN; width]; height];
+            for row in 0..height {
+                for col in 0..width {
+                    let count = elev_count[row][col];
+                    if count <= 0.0 {
+                        continue;
                     }
+                    min[row][col] = elev_min[row][col];
+                    max[row][col] = elev_max[row][col];
+                    let cell_mean = elev_sum[row][col] / count;
+                    mean[row][col] = cell_mean;
+                    let variance = (elev_sum_sq[row][col] / count) - cell_mean * cell_mean;
+                    stddev[row][col] = variance.max(0.0).sqrt();
+                    range[row][col] = elev_max[row][col] - elev_min[row][col];
                 }
             }
-        }
+            ElevationStatsRasters {
+                min,
+                max,
+                mean,
+                stddev,
+                range,
+            }
+        });
+
+        let median_elevation = want_median_elevation.then(|| {
+            let mut median = vec![vec![f64::NAN; width]; height];
+            for row in 0..height {
+                for col in 0..width {
+                    let values = &mut elev_values[row][col];
+                    if values.is_empty() {
+                        continue;
+                    }
+                    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    let mid = values.len() / 2;
+                    median[row][col] = if values.len() % 2 == 0 {
+                        (values[mid - 1] + values[mid]) / 2.0
+                    } else {
+                        values[mid]
+                    };
+                }
+            }
+            median
+        });
+
+        Ok(Self::finish_rasters(
+            dsm,
+            dtm,
+            density,
+            intensity,
+            elevation_stats,
+            median_elevation,
+            width,
+            height,
+            bbox,
+            resolution,
+            &self.dtm_idw_params,
+        ))
+    }
+
+    /// Gap-fill a DTM built from the ground-only accumulator, derive CHM = DSM - DTM, and
+    /// build the accompanying GDAL-style affine transform. Shared tail of
+    /// `process_lidar_points` and `process_lidar_points_streaming`, which differ only in how
+    /// the raw `dsm`/`dtm` grids get filled.
+    fn finish_rasters(
+        dsm: Vec<Vec<f64>>,
+        dtm: Vec<Vec<f64>>,
+        density: Option<Vec<Vec<f64>>>,
+        intensity: Option<Vec<Vec<f64>>>,
+        elevation_stats: Option<ElevationStatsRasters>,
+        median_elevation: Option<Vec<Vec<f64>>>,
+        width: usize,
+        height: usize,
+        bbox: (f64, f64, f64, f64),
+        resolution: f64,
+        idw_params: &IdwParams,
+    ) -> ProcessedRasters {
+        let (x_min, _y_min, _x_max, y_max) = bbox;
+
+        // Fill DTM gaps by inverse-distance-weighted interpolation over nearby ground cells;
+        // a cell with nothing within `idw_params.radius_cells` stays NaN (no-data) rather
+        // than being defaulted to 0.0.
+        let dtm_filled = idw_fill_dtm(&dtm, width, height, resolution, idw_params);
 
         // Calculate CHM = DSM - DTM
         let mut chm = vec![vec![0.0; width]; height];
         for row in 0..height {
             for col in 0..width {
-                if dsm[row][col] != f64::NEG_INFINITY && dtm_filled[row][col] != f64::NEG_INFINITY {
+                if dsm[row][col] != f64::NEG_INFINITY && !dtm_filled[row][col].is_nan() {
                     chm[row][col] = dsm[row][col] - dtm_filled[row][col];
                     // Ensure non-negative
                     if chm[row][col] < 0.0 {
@@ -1945,14 +4784,253 @@ impl Lidar {
             -resolution, // pixel_height (negative because Y increases downward)
         ];
 
-        Ok(ProcessedRasters {
+        ProcessedRasters {
+            dsm,
+            dtm: dtm_filled,
+            chm,
+            density,
+            intensity,
+            elevation_stats,
+            median_elevation,
+            width,
+            height,
+            transform,
+        }
+    }
+
+    /// Fold every point in `batch` into `dsm`/`dtm`'s max-Z accumulators in place, applying
+    /// the same bbox and classification filtering `process_lidar_points` applies to a
+    /// collected `Vec`, but one batch at a time. Ground is taken as classification 2 only --
+    /// see `process_lidar_points_streaming`'s doc comment for why PMF isn't used here.
+    fn fold_batch_into_grids(
+        dsm: &mut [Vec<f64>],
+        dtm: &mut [Vec<f64>],
+        batch: &[LidarPoint],
+        bbox: (f64, f64, f64, f64),
+        resolution: f64,
+        width: usize,
+        height: usize,
+        classification_list: Option<&[u8]>,
+    ) {
+        let (x_min, y_min, x_max, y_max) = bbox;
+
+        for point in batch {
+            if point.x < x_min || point.x > x_max || point.y < y_min || point.y > y_max {
+                continue;
+            }
+            if let Some(class_list) = classification_list {
+                if !class_list.contains(&point.classification) {
+                    continue;
+                }
+            }
+
+            let col = ((point.x - x_min) / resolution).floor() as usize;
+            let row = ((y_max - point.y) / resolution).floor() as usize;
+            if col >= width || row >= height {
+                continue;
+            }
+
+            if point.z > dsm[row][col] {
+                dsm[row][col] = point.z;
+            }
+            if point.classification == 2 && point.z > dtm[row][col] {
+                dtm[row][col] = point.z;
+            }
+        }
+    }
+
+    /// Memory-bounded sibling of `process_lidar_points` that never materializes the full
+    /// point set. Grids are allocated from `bbox`/`resolution` up front, then each tile's
+    /// points stream in via `load_single_point_file_streaming` and are folded straight into
+    /// the DSM/DTM max-Z accumulators `ingest_chunk_size` points at a time via
+    /// `fold_batch_into_grids`, so peak memory is bounded by one chunk per in-flight tile
+    /// rather than every tile's full point count. Tiles are processed concurrently, up to
+    /// `ingest_worker_threads` at a time (rayon's default pool size when unset), each folding
+    /// into the same `Arc<Mutex<..>>`-guarded grids.
+    ///
+    /// Trade-off versus `process_lidar_points`: ground classification only uses points
+    /// already carrying classification 2. `classify_ground_pmf`'s progressive morphological
+    /// filter needs the whole tile's spatial neighborhood in memory, which would defeat the
+    /// point of bounded-memory ingestion, so it isn't run here. Tiles with no classified
+    /// ground contribute to the DSM but not the DTM in this path; use `process_lidar_points`
+    /// if PMF-synthesized ground is required.
+    #[cfg(feature = "rayon")]
+    fn process_lidar_points_streaming(
+        &self,
+        laz_urls: &[String],
+        bbox: (f64, f64, f64, f64),
+        classification_list: Option<Vec<u8>>,
+        resolution: f64,
+    ) -> Result<ProcessedRasters> {
+        let (x_min, y_min, x_max, y_max) = bbox;
+
+        let width = ((x_max - x_min) / resolution).ceil() as usize;
+        let height = ((y_max - y_min) / resolution).ceil() as usize;
+
+        println!(
+            "Grid dimensions: {}x{} (resolution: {}m, streaming ingest)",
+            width, height, resolution
+        );
+
+        let dsm = Arc::new(Mutex::new(vec![vec![f64::NEG_INFINITY; width]; height]));
+        let dtm = Arc::new(Mutex::new(vec![vec![f64::NEG_INFINITY; width]; height]));
+
+        let cache_dir = self.output_path.join(".cache").join("laz");
+        std::fs::create_dir_all(&cache_dir).context("Failed to create LAZ cache dir")?;
+
+        let chunk_size = self.ingest_chunk_size;
+        let filter_bbox = Some(bbox);
+        let classification_list_ref = classification_list.as_deref();
+
+        let process_tile = |url: &String| -> Result<()> {
+            let mut buffer: Vec<LidarPoint> = Vec::with_capacity(chunk_size);
+            self.load_single_point_file_streaming(url, &cache_dir, filter_bbox, |batch| {
+                buffer.extend_from_slice(batch);
+                if buffer.len() >= chunk_size {
+                    let mut dsm_grid = dsm.lock().unwrap();
+                    let mut dtm_grid = dtm.lock().unwrap();
+                    Self::fold_batch_into_grids(
+                        &mut dsm_grid,
+                        &mut dtm_grid,
+                        &buffer,
+                        bbox,
+                        resolution,
+                        width,
+                        height,
+                        classification_list_ref,
+                    );
+                    buffer.clear();
+                }
+            })?;
+            if !buffer.is_empty() {
+                let mut dsm_grid = dsm.lock().unwrap();
+                let mut dtm_grid = dtm.lock().unwrap();
+                Self::fold_batch_into_grids(
+                    &mut dsm_grid,
+                    &mut dtm_grid,
+                    &buffer,
+                    bbox,
+                    resolution,
+                    width,
+                    height,
+                    classification_list_ref,
+                );
+            }
+            Ok(())
+        };
+
+        let results: Result<Vec<()>> = if let Some(n) = self.ingest_worker_threads {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .context("Failed to build rayon thread pool for streaming ingest")?;
+            pool.install(|| laz_urls.par_iter().map(process_tile).collect())
+        } else {
+            laz_urls.par_iter().map(process_tile).collect()
+        };
+        results?;
+
+        let dsm = Arc::try_unwrap(dsm)
+            .map_err(|_| anyhow::anyhow!("dsm grid still shared after streaming ingest"))?
+            .into_inner()
+            .unwrap();
+        let dtm = Arc::try_unwrap(dtm)
+            .map_err(|_| anyhow::anyhow!("dtm grid still shared after streaming ingest"))?
+            .into_inner()
+            .unwrap();
+
+        Ok(Self::finish_rasters(
+            dsm,
+            dtm,
+            None, // density/intensity/elevation-stats/median aren't synthesized by streaming ingest
+            None,
+            None,
+            None,
+            width,
+            height,
+            bbox,
+            resolution,
+            &self.dtm_idw_params,
+        ))
+    }
+
+    /// Sequential fallback of `process_lidar_points_streaming` for builds without the
+    /// `rayon` feature: tiles are processed one at a time, so `ingest_worker_threads` has no
+    /// effect here. See the `rayon`-gated overload's doc comment for the streaming/PMF
+    /// trade-off this implies.
+    #[cfg(not(feature = "rayon"))]
+    fn process_lidar_points_streaming(
+        &self,
+        laz_urls: &[String],
+        bbox: (f64, f64, f64, f64),
+        classification_list: Option<Vec<u8>>,
+        resolution: f64,
+    ) -> Result<ProcessedRasters> {
+        let (x_min, y_min, x_max, y_max) = bbox;
+
+        let width = ((x_max - x_min) / resolution).ceil() as usize;
+        let height = ((y_max - y_min) / resolution).ceil() as usize;
+
+        println!(
+            "Grid dimensions: {}x{} (resolution: {}m, streaming ingest)",
+            width, height, resolution
+        );
+
+        let mut dsm = vec![vec![f64::NEG_INFINITY; width]; height];
+        let mut dtm = vec![vec![f64::NEG_INFINITY; width]; height];
+
+        let cache_dir = self.output_path.join(".cache").join("laz");
+        std::fs::create_dir_all(&cache_dir).context("Failed to create LAZ cache dir")?;
+
+        let chunk_size = self.ingest_chunk_size;
+        let filter_bbox = Some(bbox);
+        let classification_list_ref = classification_list.as_deref();
+
+        for url in laz_urls {
+            let mut buffer: Vec<LidarPoint> = Vec::with_capacity(chunk_size);
+            self.load_single_point_file_streaming(url, &cache_dir, filter_bbox, |batch| {
+                buffer.extend_from_slice(batch);
+                if buffer.len() >= chunk_size {
+                    Self::fold_batch_into_grids(
+                        &mut dsm,
+                        &mut dtm,
+                        &buffer,
+                        bbox,
+                        resolution,
+                        width,
+                        height,
+                        classification_list_ref,
+                    );
+                    buffer.clear();
+                }
+            })?;
+            if !buffer.is_empty() {
+                Self::fold_batch_into_grids(
+                    &mut dsm,
+                    &mut dtm,
+                    &buffer,
+                    bbox,
+                    resolution,
+                    width,
+                    height,
+                    classification_list_ref,
+                );
+            }
+        }
+
+        Ok(Self::finish_rasters(
             dsm,
-            dtm: dtm_filled,
-            chm,
+            dtm,
+            None, // density/intensity/elevation-stats/median aren't synthesized by streaming ingest
+            None,
+            None,
+            None,
             width,
             height,
-            transform,
-        })
+            bbox,
+            resolution,
+            &self.dtm_idw_params,
+        ))
     }
 
     /// Convert processed rasters to GeoTIFF file
@@ -1964,7 +5042,7 @@ impl Lidar {
         output_path: &Path,
         write_out_file: bool,
     ) -> Result<PathBuf> {
-        use gdal::raster::Buffer;
+        use gdal::raster::{Buffer, RasterCreationOption};
         use gdal::spatial_ref::SpatialRef;
 
         // Create output directory if needed
@@ -1978,13 +5056,92 @@ impl Lidar {
             let driver = gdal::DriverManager::get_driver_by_name("GTiff")
                 .context("Failed to get GTiff driver")?;
 
+            // Translate `geotiff_options` into GDAL creation options understood by the
+            // GTiff driver.
+            let opts = &self.geotiff_options;
+            let mut creation_options: Vec<RasterCreationOption> = Vec::new();
+            if let Some(compress) = opts.compress.as_deref() {
+                creation_options.push(RasterCreationOption {
+                    key: "COMPRESS",
+                    value: compress,
+                });
+            }
+            if opts.tiled {
+                creation_options.push(RasterCreationOption {
+                    key: "TILED",
+                    value: "YES",
+                });
+            }
+            let predictor_str = opts.predictor.map(|p| p.to_string());
+            if let Some(predictor) = predictor_str.as_deref() {
+                creation_options.push(RasterCreationOption {
+                    key: "PREDICTOR",
+                    value: predictor,
+                });
+            }
+            if let Some(bigtiff) = opts.bigtiff.as_deref() {
+                creation_options.push(RasterCreationOption {
+                    key: "BIGTIFF",
+                    value: bigtiff,
+                });
+            }
+
+            // Extra bands beyond the fixed DSM/DTM/CHM trio, in the order they'll be written:
+            // whichever of density/intensity/elevation-stats/median `process_lidar_points`
+            // computed, each paired with the no-data value `to_tif` writes for it (overridden
+            // per-band by `geotiff_options.band_nodata_overrides`, if set).
+            let nodata_for = |name: &str, default: f64| {
+                opts.band_nodata_overrides
+                    .get(name)
+                    .copied()
+                    .unwrap_or(default)
+            };
+            let mut extra_bands: Vec<(&str, &Vec<Vec<f64>>, f64)> = Vec::new();
+            if let Some(density) = rasters.density.as_ref() {
+                extra_bands.push(("density", density, nodata_for("density", 0.0)));
+            }
+            if let Some(intensity) = rasters.intensity.as_ref() {
+                extra_bands.push(("intensity", intensity, nodata_for("intensity", f64::NAN)));
+            }
+            if let Some(stats) = rasters.elevation_stats.as_ref() {
+                // `stats` is shared by the `elevation_stats` and `range` selections (they use
+                // the same min/max accumulators), so gate each sub-band by its own flag rather
+                // than by `stats`'s mere presence.
+                if self.band_selection.elevation_stats {
+                    extra_bands.push(("elevation min", &stats.min, nodata_for("elevation min", f64::NAN)));
+                    extra_bands.push(("elevation max", &stats.max, nodata_for("elevation max", f64::NAN)));
+                    extra_bands.push(("elevation mean", &stats.mean, nodata_for("elevation mean", f64::NAN)));
+                    extra_bands.push((
+                        "elevation stddev",
+                        &stats.stddev,
+                        nodata_for("elevation stddev", f64::NAN),
+                    ));
+                }
+                if self.band_selection.range {
+                    extra_bands.push((
+                        "elevation range",
+                        &stats.range,
+                        nodata_for("elevation range", f64::NAN),
+                    ));
+                }
+            }
+            if let Some(median) = rasters.median_elevation.as_ref() {
+                extra_bands.push((
+                    "median elevation",
+                    median,
+                    nodata_for("median elevation", f64::NAN),
+                ));
+            }
+            let band_count = 3 + extra_bands.len();
+
             // Create dataset
             let mut dataset = driver
-                .create_with_band_type::<f64, _>(
+                .create_with_band_type_with_options::<f64, _>(
                     output_path,
                     rasters.width,
                     rasters.height,
-                    3, // 3 bands: DSM, DTM, CHM
+                    band_count as isize,
+                    &creation_options,
                 )
                 .context("Failed to create GeoTIFF dataset")?;
 
@@ -1993,8 +5150,8 @@ impl Lidar {
                 .set_geo_transform(&rasters.transform)
                 .context("Failed to set geotransform")?;
 
-            // Set spatial reference (EPSG:2154)
-            let srs = SpatialRef::from_epsg(self.geo_core.get_epsg() as u32)
+            // Set spatial reference to the CRS the loaded points were reprojected into
+            let srs = SpatialRef::from_epsg(self.output_epsg() as u32)
                 .context("Failed to create spatial reference")?;
             dataset
                 .set_spatial_ref(&srs)
@@ -2022,6 +5179,8 @@ impl Lidar {
                     .context("Failed to write DSM band")?;
                 band.set_no_data_value(Some(f64::NAN))
                     .context("Failed to set no data value for DSM")?;
+                band.set_description("dsm")
+                    .context("Failed to set band description for DSM")?;
             }
 
             // Band 2: DTM
@@ -2044,6 +5203,8 @@ impl Lidar {
                     .context("Failed to write DTM band")?;
                 band.set_no_data_value(Some(f64::NAN))
                     .context("Failed to set no data value for DTM")?;
+                band.set_description("dtm")
+                    .context("Failed to set band description for DTM")?;
             }
 
             // Band 3: CHM
@@ -2062,6 +5223,37 @@ impl Lidar {
                     .context("Failed to write CHM band")?;
                 band.set_no_data_value(Some(0.0))
                     .context("Failed to set no data value for CHM")?;
+                band.set_description("chm")
+                    .context("Failed to set band description for CHM")?;
+            }
+
+            // Bands 4+: whichever of density/intensity/elevation-stats/range/median were selected
+            for (i, (name, grid, nodata)) in extra_bands.iter().enumerate() {
+                let band_index = 4 + i;
+                let mut band = dataset
+                    .rasterband(band_index)
+                    .context(format!("Failed to get band {}", band_index))?;
+
+                let mut data = Vec::with_capacity(rasters.width * rasters.height);
+                for row in grid.iter() {
+                    for &val in row {
+                        data.push(val);
+                    }
+                }
+
+                let mut buffer = Buffer::new((rasters.width, rasters.height), data);
+                band.write((0, 0), (rasters.width, rasters.height), &mut buffer)
+                    .context(format!("Failed to write {} band", name))?;
+                band.set_no_data_value(Some(*nodata))
+                    .context(format!("Failed to set no data value for {}", name))?;
+                band.set_description(name)
+                    .context(format!("Failed to set band description for {}", name))?;
+            }
+
+            if !opts.overview_levels.is_empty() {
+                dataset
+                    .build_overviews(&opts.overview_resampling, &opts.overview_levels, None)
+                    .context("Failed to build overviews")?;
             }
 
             println!("GeoTIFF saved to: {:?}", output_path);
@@ -2071,17 +5263,28 @@ impl Lidar {
     }
 
     /// Run the complete LiDAR processing workflow
-    /// Following Python workflow: load points ‚Üí process ‚Üí create GeoTIFF
+    /// Following Python workflow: load points → process → create GeoTIFF
     /// Note: get_lidar_points() is now called in set_bbox(), so URLs are already available
-    /// Returns path to the created GeoTIFF file
+    /// Returns path to the created GeoTIFF file, plus the number of points `remove_lof_outliers`
+    /// dropped before rasterization. `lof_k`/`lof_threshold` default to `DEFAULT_LOF_K`/
+    /// `DEFAULT_LOF_THRESHOLD`; pass `lof_threshold: Some(f64::INFINITY)` (or a very large
+    /// value) to effectively disable the filter while keeping its cost. `target_crs` picks the
+    /// output EPSG for this run, overriding (and persisting into) `target_epsg`; already-loaded
+    /// points are reprojected from whatever CRS they're currently in, so it's equivalent to
+    /// calling `set_target_epsg` before `set_bbox` except it also covers points loaded earlier.
     pub fn run(
         &mut self,
         file_name: Option<String>,
         classification_list: Option<Vec<u8>>,
         resolution: Option<f64>,
         write_out_file: bool,
-    ) -> Result<PathBuf> {
+        lof_k: Option<usize>,
+        lof_threshold: Option<f64>,
+        target_crs: Option<i32>,
+    ) -> Result<RunOutput> {
         let resolution = resolution.unwrap_or(1.0);
+        let lof_k = lof_k.unwrap_or(DEFAULT_LOF_K);
+        let lof_threshold = lof_threshold.unwrap_or(DEFAULT_LOF_THRESHOLD);
 
         // Get LAZ file URLs (already fetched in set_bbox)
         let laz_urls = self
@@ -2099,8 +5302,29 @@ impl Lidar {
             .get_bbox()
             .context("Bounding box must be set")?;
 
-        // Transform bbox from EPSG:4326 to EPSG:2154 (same as in get_lidar_points)
-        let transformer = Proj::new_known_crs("EPSG:4326", "EPSG:2154", None)
+        // Use already loaded points (loaded in set_bbox)
+        let mut points = self
+            .loaded_points
+            .as_ref()
+            .context("No LiDAR points loaded. Call set_bbox() first.")?
+            .clone();
+
+        // `target_crs` lets the caller pick the output EPSG per-run rather than only via
+        // `set_target_epsg` before `set_bbox`. Points already loaded were reprojected to
+        // whatever `output_epsg()` resolved to at load time, so reproject them again from
+        // there to `target_crs` before adopting it as the new `target_epsg`.
+        if let Some(target_crs) = target_crs {
+            let loaded_epsg = self.output_epsg();
+            if target_crs != loaded_epsg {
+                reproject_points_in_place(&mut points, loaded_epsg, target_crs)?;
+            }
+            self.target_epsg = Some(target_crs);
+        }
+
+        // Transform the lon/lat bbox into the CRS loaded points actually end up in
+        // (`output_epsg`: `target_epsg` if set, else `geo_core`'s query EPSG)
+        let output_crs = format!("EPSG:{}", self.output_epsg());
+        let transformer = Proj::new_known_crs("EPSG:4326", &output_crs, None)
             .context("Failed to create coordinate transformer")?;
 
         let (min_x, min_y) = transformer
@@ -2110,6 +5334,66 @@ impl Lidar {
             .convert((bbox.max_x, bbox.max_y))
             .context("Failed to transform max coordinates")?;
 
+        // Drop spurious high/low returns (birds, multipath) before they can corrupt the
+        // DSM/CHM maxima.
+        let (mut points, points_dropped_by_lof) = Self::remove_lof_outliers(points, lof_k, lof_threshold);
+
+        // Drop points outside the limit_to boundary (if any), set via `set_limit_to`. The
+        // boundary is always EPSG:4326; reproject it once into the CRS points are actually in
+        // (`output_epsg`) rather than reprojecting every point back to 4326.
+        if let Some(limit_to) = &self.geo_core.limit_to {
+            let limit_to = limit_to
+                .reprojected(crate::geo_core::LIMIT_TO_EPSG, self.output_epsg())
+                .context("Failed to reproject limit_to boundary to the output CRS")?;
+            points.retain(|p| limit_to.contains_point(p.x, p.y));
+        }
+
+        // Process points to create rasters
+        let rasters = self.process_lidar_points(
+            points,
+            (min_x, min_y, max_x, max_y),
+            classification_list,
+            resolution,
+        )?;
+
+        // Create GeoTIFF
+        let output_file = self
+            .output_path
+            .join(file_name.unwrap_or("lidar_cdsm.tif".to_string()));
+        let output_path = self.to_tif(&rasters, &output_file, write_out_file)?;
+
+        Ok(RunOutput {
+            output_path,
+            points_dropped_by_lof,
+        })
+    }
+
+    /// Multi-window sibling of `run()`: rasterizes the same loaded point set against several
+    /// crop windows (bboxes in the output CRS, see `output_epsg`), writing one GeoTIFF per
+    /// window instead of re-downloading/re-decoding LAZ tiles per output -- mirrors PDAL's
+    /// crop filter producing several output views from one input. `file_name` is treated as
+    /// an `output#.tif`-style template: a `#` placeholder is replaced by the window's index,
+    /// or (with no `#`) the index is inserted before the extension. Returns the written
+    /// GeoTIFF paths in crop-window order. `lof_k`/`lof_threshold` apply once, to the whole
+    /// loaded set, before it's cropped per window.
+    pub fn run_multi_crop(
+        &mut self,
+        file_name: Option<String>,
+        classification_list: Option<Vec<u8>>,
+        resolution: Option<f64>,
+        write_out_file: bool,
+        lof_k: Option<usize>,
+        lof_threshold: Option<f64>,
+        crop_windows: &[(f64, f64, f64, f64)],
+    ) -> Result<Vec<PathBuf>> {
+        if crop_windows.is_empty() {
+            anyhow::bail!("crop_windows must contain at least one bbox");
+        }
+
+        let resolution = resolution.unwrap_or(1.0);
+        let lof_k = lof_k.unwrap_or(DEFAULT_LOF_K);
+        let lof_threshold = lof_threshold.unwrap_or(DEFAULT_LOF_THRESHOLD);
+
         // Use already loaded points (loaded in set_bbox)
         let points = self
             .loaded_points
@@ -2117,15 +5401,95 @@ impl Lidar {
             .context("No LiDAR points loaded. Call set_bbox() first.")?
             .clone();
 
-        // Process points to create rasters
-        let rasters = self.process_lidar_points(
-            points,
+        // Drop spurious high/low returns once, for the whole loaded set, before cropping.
+        let (points, _points_dropped_by_lof) = Self::remove_lof_outliers(points, lof_k, lof_threshold);
+
+        let base_name = file_name.unwrap_or_else(|| "lidar_cdsm.tif".to_string());
+        let strategy = self.spatial_index_strategy;
+
+        let mut output_paths = Vec::with_capacity(crop_windows.len());
+        for (i, &(x_min, y_min, x_max, y_max)) in crop_windows.iter().enumerate() {
+            let window_points = Self::filter_points_with_spatial_index(
+                &points, x_min, y_min, x_max, y_max, strategy,
+            );
+
+            let rasters = self.process_lidar_points(
+                window_points,
+                (x_min, y_min, x_max, y_max),
+                classification_list.clone(),
+                resolution,
+            )?;
+
+            let output_file = self.output_path.join(Self::window_file_name(&base_name, i));
+            output_paths.push(self.to_tif(&rasters, &output_file, write_out_file)?);
+        }
+
+        Ok(output_paths)
+    }
+
+    /// Substitute `index` into a `run_multi_crop` output filename: replaces a `#`
+    /// placeholder if present (`"output#.tif"` -> `"output3.tif"`), otherwise inserts
+    /// `_{index}` before the extension (`"lidar_cdsm.tif"` -> `"lidar_cdsm_3.tif"`).
+    fn window_file_name(base_name: &str, index: usize) -> String {
+        if base_name.contains('#') {
+            base_name.replacen('#', &index.to_string(), 1)
+        } else if let Some(dot) = base_name.rfind('.') {
+            format!("{}_{}{}", &base_name[..dot], index, &base_name[dot..])
+        } else {
+            format!("{}_{}", base_name, index)
+        }
+    }
+
+    /// Memory-bounded sibling of `run()`: processes LiDAR tiles via
+    /// `process_lidar_points_streaming` instead of `process_lidar_points`, so peak memory is
+    /// bounded by `ingest_chunk_size` points per in-flight tile rather than every tile's full
+    /// point count. Unlike `run()` this does not need `loaded_points` to already be
+    /// populated -- it streams straight from `list_path_laz`, so it also works when `set_bbox`
+    /// was never called with a bbox that triggered eager loading. See
+    /// `process_lidar_points_streaming`'s doc comment for the ground-classification
+    /// trade-off this implies. Use `set_ingest_chunk_size`/`set_ingest_worker_threads` to tune
+    /// the memory ceiling and tile concurrency.
+    pub fn run_streaming(
+        &mut self,
+        file_name: Option<String>,
+        classification_list: Option<Vec<u8>>,
+        resolution: Option<f64>,
+        write_out_file: bool,
+    ) -> Result<PathBuf> {
+        let resolution = resolution.unwrap_or(1.0);
+
+        let laz_urls = self
+            .list_path_laz
+            .clone()
+            .context("No LAZ URLs available. Call set_bbox() first.")?;
+
+        if laz_urls.is_empty() {
+            anyhow::bail!("No LAZ files found for the specified bounding box");
+        }
+
+        let bbox = self
+            .geo_core
+            .get_bbox()
+            .context("Bounding box must be set")?;
+
+        let output_crs = format!("EPSG:{}", self.output_epsg());
+        let transformer = Proj::new_known_crs("EPSG:4326", &output_crs, None)
+            .context("Failed to create coordinate transformer")?;
+
+        let (min_x, min_y) = transformer
+            .convert((bbox.min_x, bbox.min_y))
+            .context("Failed to transform min coordinates")?;
+        let (max_x, max_y) = transformer
+            .convert((bbox.max_x, bbox.max_y))
+            .context("Failed to transform max coordinates")?;
+
+        let rasters = self.process_lidar_points_streaming(
+            &laz_urls,
             (min_x, min_y, max_x, max_y),
             classification_list,
             resolution,
         )?;
 
-        // Create GeoTIFF
         let output_file = self
             .output_path
             .join(file_name.unwrap_or("lidar_cdsm.tif".to_string()));
@@ -2133,6 +5497,224 @@ impl Lidar {
 
         Ok(output_path)
     }
+
+    /// Derive the output-CRS bounding box a `ProcessedRasters` covers from its affine
+    /// transform and dimensions (inverse of how `finish_rasters` builds the transform).
+    fn rasters_bbox(rasters: &ProcessedRasters) -> (f64, f64, f64, f64) {
+        let x_min = rasters.transform[0];
+        let pixel_width = rasters.transform[1];
+        let y_max = rasters.transform[3];
+        let pixel_height = -rasters.transform[5];
+        let x_max = x_min + rasters.width as f64 * pixel_width;
+        let y_min = y_max - rasters.height as f64 * pixel_height;
+        (x_min, y_min, x_max, y_max)
+    }
+
+    /// Sample a single band at `(x, y)` (output-CRS units), optionally bilinearly
+    /// interpolating between the 4 surrounding cell centers. Falls back to the nearest cell
+    /// if `x`/`y` is outside the raster, or (for bilinear) if any of the 4 surrounding cells
+    /// is no-data -- `is_nodata` decides what counts as no-data for this grid (DSM/DTM use
+    /// `NEG_INFINITY`/`NaN`, CHM uses `0.0` as a valid value so it never triggers).
+    fn sample_band(
+        grid: &[Vec<f64>],
+        width: usize,
+        height: usize,
+        transform: &[f64; 6],
+        x: f64,
+        y: f64,
+        bilinear: bool,
+        is_nodata: impl Fn(f64) -> bool,
+    ) -> Option<f64> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let x_min = transform[0];
+        let pixel_width = transform[1];
+        let y_max = transform[3];
+        let pixel_height = -transform[5];
+
+        let col_f = (x - x_min) / pixel_width;
+        let row_f = (y_max - y) / pixel_height;
+        if !(0.0..=width as f64).contains(&col_f) || !(0.0..=height as f64).contains(&row_f) {
+            return None;
+        }
+
+        let nearest_col = (col_f.floor() as isize).clamp(0, width as isize - 1) as usize;
+        let nearest_row = (row_f.floor() as isize).clamp(0, height as isize - 1) as usize;
+        let nearest = grid[nearest_row][nearest_col];
+
+        if !bilinear || width < 2 || height < 2 {
+            return if is_nodata(nearest) { None } else { Some(nearest) };
+        }
+
+        // Sample at pixel centers: cell (r, c) is centered at row_f == r + 0.5.
+        let cf = (col_f - 0.5).clamp(0.0, (width - 1) as f64);
+        let rf = (row_f - 0.5).clamp(0.0, (height - 1) as f64);
+        let c0 = (cf.floor() as usize).min(width - 2);
+        let r0 = (rf.floor() as usize).min(height - 2);
+        let tx = cf - c0 as f64;
+        let ty = rf - r0 as f64;
+
+        let v00 = grid[r0][c0];
+        let v10 = grid[r0][c0 + 1];
+        let v01 = grid[r0 + 1][c0];
+        let v11 = grid[r0 + 1][c0 + 1];
+        if is_nodata(v00) || is_nodata(v10) || is_nodata(v01) || is_nodata(v11) {
+            return if is_nodata(nearest) { None } else { Some(nearest) };
+        }
+
+        let top = v00 * (1.0 - tx) + v10 * tx;
+        let bottom = v01 * (1.0 - tx) + v11 * tx;
+        Some(top * (1.0 - ty) + bottom * ty)
+    }
+
+    /// Download and rasterize every point of a single tile, producing DSM/DTM/CHM for its
+    /// own extent (derived from the loaded points, not the full query bbox). Backs
+    /// `sample_elevation`'s cache: expensive, so only called on a cache miss.
+    fn rasterize_tile(&self, url: &str) -> Result<ProcessedRasters> {
+        let cache_dir = self.output_path.join(".cache").join("laz");
+        std::fs::create_dir_all(&cache_dir).context("Failed to create LAZ cache dir")?;
+
+        let points = self.load_single_point_file(url, &cache_dir, None)?;
+        if points.is_empty() {
+            anyhow::bail!("Tile {} produced no points", url);
+        }
+
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for p in &points {
+            min_x = min_x.min(p.x);
+            max_x = max_x.max(p.x);
+            min_y = min_y.min(p.y);
+            max_y = max_y.max(p.y);
+        }
+
+        self.process_lidar_points(points, (min_x, min_y, max_x, max_y), None, self.elevation_resolution)
+    }
+
+    /// Find the rasterized tile covering `(query_x, query_y)` (in `geo_core`'s query CRS,
+    /// the same CRS tile header bounds are compared against elsewhere in this module),
+    /// rasterizing and caching it on a miss, then sample DSM/DTM/CHM at
+    /// `(output_x, output_y)` (in output CRS).
+    fn sample_point(
+        &self,
+        laz_urls: &[String],
+        query_x: f64,
+        query_y: f64,
+        output_x: f64,
+        output_y: f64,
+        bilinear: bool,
+    ) -> Result<Option<ElevationSample>> {
+        for url in laz_urls {
+            if let Some(rasters) = self.elevation_cache.lock().unwrap().get(url) {
+                let bbox = Self::rasters_bbox(&rasters);
+                if output_x < bbox.0 || output_x > bbox.2 || output_y < bbox.1 || output_y > bbox.3 {
+                    continue;
+                }
+                return Ok(Some(self.sample_from_rasters(&rasters, output_x, output_y, bilinear)));
+            }
+
+            let header_bounds = match Self::tile_bounds_2d(url) {
+                Ok(bounds) => bounds,
+                Err(_) => continue, // unreadable header; skip this tile rather than failing the query
+            };
+            if !bbox_overlaps((query_x, query_y, query_x, query_y), header_bounds) {
+                continue;
+            }
+
+            let rasters = Arc::new(self.rasterize_tile(url)?);
+            self.elevation_cache
+                .lock()
+                .unwrap()
+                .insert(url.clone(), rasters.clone());
+
+            let bbox = Self::rasters_bbox(&rasters);
+            if output_x < bbox.0 || output_x > bbox.2 || output_y < bbox.1 || output_y > bbox.3 {
+                continue;
+            }
+            return Ok(Some(self.sample_from_rasters(&rasters, output_x, output_y, bilinear)));
+        }
+        Ok(None)
+    }
+
+    /// Sample DSM/DTM/CHM out of an already-rasterized tile at `(x, y)` (output CRS).
+    fn sample_from_rasters(&self, rasters: &ProcessedRasters, x: f64, y: f64, bilinear: bool) -> ElevationSample {
+        let dsm = Self::sample_band(
+            &rasters.dsm,
+            rasters.width,
+            rasters.height,
+            &rasters.transform,
+            x,
+            y,
+            bilinear,
+            |v| v == f64::NEG_INFINITY,
+        );
+        let dtm = Self::sample_band(
+            &rasters.dtm,
+            rasters.width,
+            rasters.height,
+            &rasters.transform,
+            x,
+            y,
+            bilinear,
+            |v| v.is_nan(),
+        );
+        let chm = Self::sample_band(
+            &rasters.chm,
+            rasters.width,
+            rasters.height,
+            &rasters.transform,
+            x,
+            y,
+            bilinear,
+            |_| false,
+        );
+        ElevationSample { dsm, dtm, chm }
+    }
+
+    /// Sample DSM/DTM/CHM at a single WGS84 `(lat, lon)`, downloading and rasterizing only
+    /// whichever tile covers it (cached afterward by `TileRasterCache`). Returns `None` if
+    /// no tile in `list_path_laz` covers the point. Set `bilinear` to interpolate between
+    /// surrounding cells rather than taking the nearest one.
+    pub fn sample_elevation(&self, lat: f64, lon: f64, bilinear: bool) -> Result<Option<ElevationSample>> {
+        Ok(self.sample_elevation_batch(&[(lat, lon)], bilinear)?.remove(0))
+    }
+
+    /// Batch sibling of `sample_elevation`: reprojects every `(lat, lon)` once up front,
+    /// then samples each, reusing the same `TileRasterCache` across the whole batch so
+    /// queries that land on the same tile only pay the download/rasterize cost once.
+    pub fn sample_elevation_batch(
+        &self,
+        points: &[(f64, f64)],
+        bilinear: bool,
+    ) -> Result<Vec<Option<ElevationSample>>> {
+        let laz_urls = self
+            .list_path_laz
+            .clone()
+            .context("No LAZ URLs available. Call set_bbox() first.")?;
+
+        // Tile header bounds are compared against the query bbox elsewhere in this module in
+        // `geo_core`'s query CRS; `output_epsg` is the (possibly different) CRS loaded points
+        // -- and therefore rasters -- actually end up in.
+        let query_crs = format!("EPSG:{}", self.geo_core.get_epsg());
+        let query_transformer = Proj::new_known_crs("EPSG:4326", &query_crs, None)
+            .context("Failed to create query CRS transformer")?;
+        let output_crs = format!("EPSG:{}", self.output_epsg());
+        let output_transformer = Proj::new_known_crs("EPSG:4326", &output_crs, None)
+            .context("Failed to create output CRS transformer")?;
+
+        let mut results = Vec::with_capacity(points.len());
+        for &(lat, lon) in points {
+            let (query_x, query_y) = query_transformer
+                .convert((lon, lat))
+                .context("Failed to transform query coordinate to query CRS")?;
+            let (output_x, output_y) = output_transformer
+                .convert((lon, lat))
+                .context("Failed to transform query coordinate to output CRS")?;
+            results.push(self.sample_point(&laz_urls, query_x, query_y, output_x, output_y, bilinear)?);
+        }
+        Ok(results)
+    }
 }
 
 #[cfg(test)]
@@ -2179,30 +5761,35 @@ mod tests {
                 y: 0.0,
                 z: 10.0,
                 classification: 2,
+                ..Default::default()
             },
             LidarPoint {
                 x: 5.0,
                 y: 5.0,
                 z: 15.0,
                 classification: 2,
+                ..Default::default()
             },
             LidarPoint {
                 x: 10.0,
                 y: 10.0,
                 z: 20.0,
                 classification: 6,
+                ..Default::default()
             },
             LidarPoint {
                 x: 15.0,
                 y: 15.0,
                 z: 25.0,
                 classification: 6,
+                ..Default::default()
             },
             LidarPoint {
                 x: 100.0,
                 y: 100.0,
                 z: 30.0,
                 classification: 2,
+                ..Default::default()
             },
         ];
 
@@ -2232,6 +5819,7 @@ mod tests {
                     y: j as f64 * 10.0,
                     z: (i + j) as f64,
                     classification: 2,
+                    ..Default::default()
                 });
             }
         }
@@ -2281,10 +5869,18 @@ mod tests {
                 y: i as f64,
                 z: i as f64,
                 classification: 2,
+                ..Default::default()
             })
             .collect();
 
-        let filtered = Lidar::filter_points_with_spatial_index(&points, 25.0, 25.0, 75.0, 75.0);
+        let filtered = Lidar::filter_points_with_spatial_index(
+            &points,
+            25.0,
+            25.0,
+            75.0,
+            75.0,
+            SpatialIndexStrategy::default(),
+        );
 
         // Should have points from 25 to 75 inclusive
         assert_eq!(filtered.len(), 51);
@@ -2300,10 +5896,18 @@ mod tests {
                 y: (i / 1000) as f64 * 10.0,
                 z: i as f64 * 0.1,
                 classification: 2,
+                ..Default::default()
             })
             .collect();
 
-        let filtered = Lidar::filter_points_with_spatial_index(&points, 100.0, 100.0, 200.0, 200.0);
+        let filtered = Lidar::filter_points_with_spatial_index(
+            &points,
+            100.0,
+            100.0,
+            200.0,
+            200.0,
+            SpatialIndexStrategy::default(),
+        );
 
         // All filtered points should be in bbox
         assert!(filtered
@@ -2311,6 +5915,51 @@ mod tests {
             .all(|p| p.x >= 100.0 && p.x <= 200.0 && p.y >= 100.0 && p.y <= 200.0));
     }
 
+    #[test]
+    fn test_filter_points_with_spatial_index_always_rtree() {
+        let points: Vec<LidarPoint> = (0..50_000)
+            .map(|i| LidarPoint {
+                x: (i % 1000) as f64,
+                y: (i / 1000) as f64 * 10.0,
+                z: i as f64 * 0.1,
+                classification: 2,
+                ..Default::default()
+            })
+            .collect();
+
+        let filtered = Lidar::filter_points_with_spatial_index(
+            &points,
+            100.0,
+            100.0,
+            200.0,
+            200.0,
+            SpatialIndexStrategy::AlwaysRtree,
+        );
+
+        assert!(!filtered.is_empty());
+        assert!(filtered
+            .iter()
+            .all(|p| p.x >= 100.0 && p.x <= 200.0 && p.y >= 100.0 && p.y <= 200.0));
+    }
+
+    #[test]
+    fn test_window_file_name_hash_placeholder() {
+        assert_eq!(Lidar::window_file_name("output#.tif", 3), "output3.tif");
+    }
+
+    #[test]
+    fn test_window_file_name_no_placeholder() {
+        assert_eq!(
+            Lidar::window_file_name("lidar_cdsm.tif", 3),
+            "lidar_cdsm_3.tif"
+        );
+    }
+
+    #[test]
+    fn test_window_file_name_no_extension() {
+        assert_eq!(Lidar::window_file_name("lidar_cdsm", 3), "lidar_cdsm_3");
+    }
+
     #[test]
     fn test_octree_node_quadrant() {
         let node = OctreeNode::new_leaf((0.0, 0.0, 0.0, 100.0, 100.0, 100.0), 0);
@@ -2324,4 +5973,111 @@ mod tests {
         // NE quadrant
         assert_eq!(node.quadrant_for_point(75.0, 75.0), 3);
     }
+
+    #[test]
+    fn test_icp_align_recovers_known_rotation_translation() {
+        // "Fixed" cloud: an asymmetric scatter of points so nearest-neighbour
+        // correspondences aren't ambiguous under rotation.
+        let fixed_coords: Vec<(f64, f64, f64)> = (0..40)
+            .map(|i| {
+                let t = i as f64;
+                (t * 0.7, (t * 1.3).sin() * 5.0 + t * 0.2, (t * 0.37).cos() * 3.0)
+            })
+            .collect();
+        let fixed: Vec<LidarPoint> = fixed_coords
+            .iter()
+            .map(|&(x, y, z)| LidarPoint {
+                x,
+                y,
+                z,
+                classification: 2,
+                ..Default::default()
+            })
+            .collect();
+
+        // Known small rotation about Z plus a translation; "moving" is fixed
+        // mapped back through the inverse transform, so icp_align should
+        // recover `rotation_true`/`translation_true` aligning moving onto fixed.
+        let angle = 5.0_f64.to_radians();
+        let rotation_true = [
+            [angle.cos(), -angle.sin(), 0.0],
+            [angle.sin(), angle.cos(), 0.0],
+            [0.0, 0.0, 1.0],
+        ];
+        let translation_true = [0.3, -0.2, 0.1];
+        let rotation_true_inv = mat3_transpose(&rotation_true);
+
+        let moving: Vec<LidarPoint> = fixed_coords
+            .iter()
+            .map(|&(x, y, z)| {
+                let shifted = [x - translation_true[0], y - translation_true[1], z - translation_true[2]];
+                let v = mat3_vec3(&rotation_true_inv, shifted);
+                LidarPoint {
+                    x: v[0],
+                    y: v[1],
+                    z: v[2],
+                    classification: 2,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let fixed_index = QuadtreeSpatialIndex::build(&fixed);
+        let result = Lidar::icp_align(&fixed, &fixed_index, &moving, 50, 1e-12, None);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (result.rotation[i][j] - rotation_true[i][j]).abs() < 1e-3,
+                    "rotation[{i}][{j}] = {}, expected {}",
+                    result.rotation[i][j],
+                    rotation_true[i][j]
+                );
+            }
+        }
+        for i in 0..3 {
+            assert!(
+                (result.translation[i] - translation_true[i]).abs() < 1e-3,
+                "translation[{i}] = {}, expected {}",
+                result.translation[i],
+                translation_true[i]
+            );
+        }
+        assert!(result.rmse < 1e-3);
+    }
+
+    #[test]
+    fn test_remove_lof_outliers_drops_planted_far_points() {
+        // A dense 10x10 cluster on a 1m grid plus a handful of far-away outliers.
+        let mut points = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                points.push(LidarPoint {
+                    x: i as f64,
+                    y: j as f64,
+                    z: 0.0,
+                    classification: 2,
+                    ..Default::default()
+                });
+            }
+        }
+        let outlier_start = points.len();
+        for &(x, y, z) in &[(500.0, 500.0, 0.0), (-500.0, 500.0, 0.0), (500.0, -500.0, 50.0)] {
+            points.push(LidarPoint {
+                x,
+                y,
+                z,
+                classification: 2,
+                ..Default::default()
+            });
+        }
+
+        let (kept, dropped) = Lidar::remove_lof_outliers(points, 8, 2.0);
+
+        assert_eq!(dropped, 3);
+        assert_eq!(kept.len(), outlier_start);
+        for p in &kept {
+            assert!(p.x.abs() < 100.0 && p.y.abs() < 100.0);
+        }
+    }
 }
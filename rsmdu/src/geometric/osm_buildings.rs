@@ -0,0 +1,254 @@
+use anyhow::{Context, Result};
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use serde_json::Map;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, OutputFormat};
+
+/// EPSG code OSM/Overpass always answers in.
+const OSM_SOURCE_EPSG: i32 = 4326;
+
+/// Default public Overpass API endpoint; see [`OsmBuildings::set_endpoint`] to point at a
+/// different instance (e.g. a self-hosted mirror) when the public instance is rate-limiting.
+const DEFAULT_OVERPASS_ENDPOINT: &str = "https://overpass-api.de/api/interpreter";
+
+/// Query timeout (seconds) used until [`OsmBuildings::set_timeout`] is called -- Overpass
+/// rejects queries with no `[timeout:...]` clause, so this is required, not optional.
+const DEFAULT_TIMEOUT_SECS: u32 = 60;
+
+/// OpenStreetMap/Overpass building footprint collector. An alternative to
+/// [`crate::geometric::rnb::Rnb`] for areas or attributes the French RNB registry lacks: OSM
+/// returns full footprint polygons (not just centroid points) and arbitrary tags (e.g.
+/// `building:levels`) instead of RNB's fixed address/status fields. Exposes the same
+/// `set_bbox`/`set_crs`/`to_geojson` surface as `Rnb` so the two sources are interchangeable.
+pub struct OsmBuildings {
+    /// Output path for processed data
+    output_path: PathBuf,
+    /// GeoCore for CRS handling
+    pub geo_core: GeoCore,
+    /// Bounding box for the query area
+    bbox: Option<BoundingBox>,
+    /// Parsed GeoJSON content
+    geojson: Option<GeoJson>,
+    /// CRS explicitly requested via [`OsmBuildings::set_crs`], if any -- distinguishes
+    /// "reproject to this after fetching" from `geo_core`'s default EPSG, mirroring
+    /// [`crate::geometric::rnb::Rnb`]'s `set_crs` field.
+    set_crs: Option<i32>,
+    /// Overpass API endpoint. See [`OsmBuildings::set_endpoint`].
+    endpoint: String,
+    /// Overpass query timeout, seconds. See [`OsmBuildings::set_timeout`].
+    timeout_secs: u32,
+}
+
+/// Minimal subset of the Overpass JSON response needed to build building footprint polygons.
+#[derive(Debug, Deserialize)]
+struct OverpassResponse {
+    #[serde(default)]
+    elements: Vec<OverpassWay>,
+}
+
+/// One `way` element from an Overpass `out geom` response -- a building footprint with its
+/// resolved node coordinates inlined directly into `geometry`, so no separate node lookup is
+/// needed the way a plain `out body` response would require.
+#[derive(Debug, Deserialize)]
+struct OverpassWay {
+    id: i64,
+    #[serde(default)]
+    geometry: Vec<OverpassLatLon>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OverpassLatLon {
+    lat: f64,
+    lon: f64,
+}
+
+impl OsmBuildings {
+    /// Create a new OsmBuildings collector
+    pub fn new(output_path: Option<String>) -> Result<Self> {
+        use crate::collect::global_variables::TEMP_PATH;
+
+        let output_path_buf = PathBuf::from(
+            output_path
+                .as_ref()
+                .map(|s| s.as_str())
+                .unwrap_or(TEMP_PATH),
+        );
+
+        Ok(OsmBuildings {
+            output_path: output_path_buf,
+            geo_core: GeoCore::default(),
+            bbox: None,
+            geojson: None,
+            set_crs: None,
+            endpoint: DEFAULT_OVERPASS_ENDPOINT.to_string(),
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        })
+    }
+
+    /// Set bounding box
+    pub fn set_bbox(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        self.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
+    }
+
+    /// Set CRS
+    pub fn set_crs(&mut self, epsg: i32) {
+        self.geo_core.set_epsg(epsg);
+        self.set_crs = Some(epsg);
+    }
+
+    /// Point at a different Overpass API instance (e.g. a self-hosted mirror) instead of the
+    /// public `overpass-api.de` endpoint.
+    pub fn set_endpoint(&mut self, endpoint: impl Into<String>) {
+        self.endpoint = endpoint.into();
+    }
+
+    /// Overpass query timeout in seconds, sent as the query's `[timeout:<t>]` clause. Overpass
+    /// rejects long-running queries with no explicit timeout, so large bboxes need this raised.
+    pub fn set_timeout(&mut self, timeout_secs: u32) {
+        self.timeout_secs = timeout_secs;
+    }
+
+    /// Run: fetch OSM building footprints from Overpass, parse, create GeoJSON
+    pub fn run(mut self) -> Result<Self> {
+        self.run_internal()?;
+        Ok(self)
+    }
+
+    /// Internal run method that can be called mutably
+    /// Used by Python bindings to avoid ownership issues
+    pub fn run_internal(&mut self) -> Result<()> {
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before running OsmBuildings")?;
+
+        // Overpass QL bbox order is (south, west, north, east) i.e. (min_y, min_x, max_y, max_x)
+        let bbox_clause = format!(
+            "({},{},{},{})",
+            bbox.min_y, bbox.min_x, bbox.max_y, bbox.max_x
+        );
+        let query = format!(
+            "[out:json][timeout:{timeout}];way[\"building\"]{bbox};out geom;",
+            timeout = self.timeout_secs,
+            bbox = bbox_clause,
+        );
+
+        let client = Client::new();
+        let response = client
+            .post(&self.endpoint)
+            .form(&[("data", query.as_str())])
+            .send()
+            .context("Failed to send Overpass request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Overpass API returned error {}: {}", status, body);
+        }
+
+        let overpass: OverpassResponse = response
+            .json()
+            .context("Failed to parse Overpass JSON response")?;
+
+        let features: Vec<Feature> = overpass
+            .elements
+            .into_iter()
+            .filter_map(Self::way_to_feature)
+            .collect();
+
+        let feature_collection = FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        };
+
+        // Overpass always answers in EPSG:4326, so reproject to the CRS explicitly requested
+        // via `set_crs`, the way `Rnb::run_internal` does; otherwise leave it in EPSG:4326 and
+        // label `geo_core` accordingly rather than claiming whatever CRS happened to be the
+        // default.
+        self.geojson = Some(GeoJson::from(feature_collection));
+        match self.set_crs {
+            Some(target_epsg) => self.reproject_to(OSM_SOURCE_EPSG, target_epsg)?,
+            None => self.geo_core.set_epsg(OSM_SOURCE_EPSG),
+        }
+
+        Ok(())
+    }
+
+    /// Convert one Overpass `way` to a GeoJSON `Polygon` feature carrying its OSM tags
+    /// (`building:levels`, `building`, etc.) as properties, plus an `osm_id`. Returns `None`
+    /// for ways with fewer than 4 resolved nodes -- not enough to close a ring, which happens
+    /// for ways Overpass returns partial geometry for at a bbox edge.
+    fn way_to_feature(way: OverpassWay) -> Option<Feature> {
+        if way.geometry.len() < 4 {
+            return None;
+        }
+
+        let positions: Vec<Vec<f64>> = way.geometry.iter().map(|c| vec![c.lon, c.lat]).collect();
+        let geometry = Geometry::new(Value::Polygon(vec![positions]));
+
+        let mut properties = Map::new();
+        properties.insert("osm_id".to_string(), serde_json::Value::from(way.id));
+        for (key, value) in way.tags {
+            properties.insert(key, serde_json::Value::String(value));
+        }
+
+        let mut feature = Feature::from(geometry);
+        feature.properties = Some(properties);
+        Some(feature)
+    }
+
+    /// Reproject the stored GeoJSON from `from_epsg` to `to_epsg`, regardless of what EPSG
+    /// `geo_core` currently thinks it's in. Mirrors [`crate::geometric::rnb::Rnb::reproject_to`].
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            GeoCore::reproject_geojson(geojson, from_epsg, to_epsg)?;
+        }
+        self.geo_core.set_epsg(to_epsg);
+        Ok(())
+    }
+
+    /// Get the GeoJSON
+    pub fn get_geojson(&self) -> Option<&GeoJson> {
+        self.geojson.as_ref()
+    }
+
+    /// Save to GeoJSON file
+    pub fn to_geojson(&self, name: Option<&str>) -> Result<()> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let name = name.unwrap_or("osm_buildings");
+        let output_file = self.output_path.join(format!("{}.geojson", name));
+        std::fs::write(&output_file, geojson.to_string())
+            .context(format!("Failed to write GeoJSON file: {:?}", output_file))?;
+
+        println!("OSM buildings saved to: {:?}", output_file);
+        Ok(())
+    }
+
+    /// Get output path
+    pub fn get_output_path(&self) -> &Path {
+        &self.output_path
+    }
+
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, GeoJSON, FlatGeobuf,
+    /// KML, GPX) via `ogr2ogr`, reprojecting to geo_core's EPSG on the way out, mirroring
+    /// [`crate::geometric::rnb::Rnb::to_file`].
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("osm_buildings");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.epsg)
+    }
+}
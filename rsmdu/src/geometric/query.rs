@@ -0,0 +1,795 @@
+use anyhow::{bail, Context, Result};
+use geojson::{Feature, FeatureCollection, GeoJson, Value as GeoValue};
+use serde_json::Value as JsonValue;
+
+use crate::geo_core::BoundingBox;
+
+/// A simple predicate over a single Feature property, used by `WhereExpr::Predicate`.
+#[derive(Debug, Clone)]
+pub enum WherePredicate {
+    Eq(String, JsonValue),
+    Ne(String, JsonValue),
+    Gt(String, f64),
+    Gte(String, f64),
+    Lt(String, f64),
+    Lte(String, f64),
+    In(String, Vec<JsonValue>),
+    /// `key IS NULL`: the property is absent or explicitly `null`.
+    IsNull(String),
+    /// `key IS NOT NULL`: the property is present and not `null`.
+    IsNotNull(String),
+}
+
+impl WherePredicate {
+    pub(crate) fn matches_props(&self, props: &serde_json::Map<String, JsonValue>) -> bool {
+        match self {
+            WherePredicate::Eq(key, value) => props.get(key) == Some(value),
+            WherePredicate::Ne(key, value) => props.get(key).is_some_and(|v| v != value),
+            WherePredicate::In(key, values) => props
+                .get(key)
+                .map(|v| values.contains(v))
+                .unwrap_or(false),
+            WherePredicate::Gt(key, value) => prop_as_f64(props, key).map(|v| v > *value).unwrap_or(false),
+            WherePredicate::Gte(key, value) => {
+                prop_as_f64(props, key).map(|v| v >= *value).unwrap_or(false)
+            }
+            WherePredicate::Lt(key, value) => prop_as_f64(props, key).map(|v| v < *value).unwrap_or(false),
+            WherePredicate::Lte(key, value) => {
+                prop_as_f64(props, key).map(|v| v <= *value).unwrap_or(false)
+            }
+            WherePredicate::IsNull(key) => matches!(props.get(key), None | Some(JsonValue::Null)),
+            WherePredicate::IsNotNull(key) => !matches!(props.get(key), None | Some(JsonValue::Null)),
+        }
+    }
+}
+
+fn prop_as_f64(props: &serde_json::Map<String, JsonValue>, key: &str) -> Option<f64> {
+    props.get(key).and_then(|v| v.as_f64())
+}
+
+/// A boolean combination of [`WherePredicate`]s, as produced by [`parse_where`]. Evaluated
+/// against a `geojson::Feature`'s properties; a feature missing a referenced property never
+/// matches the predicate that references it, except `IS NULL`, which matches exactly that case.
+#[derive(Debug, Clone)]
+pub enum WhereExpr {
+    Predicate(WherePredicate),
+    And(Box<WhereExpr>, Box<WhereExpr>),
+    Or(Box<WhereExpr>, Box<WhereExpr>),
+}
+
+impl WhereExpr {
+    pub(crate) fn matches_props(&self, props: &serde_json::Map<String, JsonValue>) -> bool {
+        match self {
+            WhereExpr::Predicate(p) => p.matches_props(props),
+            WhereExpr::And(a, b) => a.matches_props(props) && b.matches_props(props),
+            WhereExpr::Or(a, b) => a.matches_props(props) || b.matches_props(props),
+        }
+    }
+
+    fn matches(&self, feature: &Feature) -> bool {
+        match &feature.properties {
+            Some(props) => self.matches_props(props),
+            None => self.matches_props(&serde_json::Map::new()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Op(&'static str),
+    And,
+    Or,
+    In,
+    Is,
+    Not,
+    Null,
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '\'' | '"' => {
+                let quote = c;
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    bail!("unterminated string literal in WHERE expression: {expr}");
+                }
+                tokens.push(Token::Str(chars[start..i].iter().collect()));
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Op("="));
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op("!="));
+                i += 2;
+            }
+            '<' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Op("<="));
+                    i += 2;
+                } else {
+                    tokens.push(Token::Op("<"));
+                    i += 1;
+                }
+            }
+            '>' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Op(">="));
+                    i += 2;
+                } else {
+                    tokens.push(Token::Op(">"));
+                    i += 1;
+                }
+            }
+            '-' if chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                tokens.push(Token::Num(s.parse().with_context(|| {
+                    format!("invalid number literal '{s}' in WHERE expression")
+                })?));
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                tokens.push(Token::Num(s.parse().with_context(|| {
+                    format!("invalid number literal '{s}' in WHERE expression")
+                })?));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let s: String = chars[start..i].iter().collect();
+                match s.to_ascii_uppercase().as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "IN" => tokens.push(Token::In),
+                    "IS" => tokens.push(Token::Is),
+                    "NOT" => tokens.push(Token::Not),
+                    "NULL" => tokens.push(Token::Null),
+                    _ => tokens.push(Token::Ident(s)),
+                }
+            }
+            other => bail!("unexpected character '{other}' in WHERE expression: {expr}"),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<WhereExpr> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = WhereExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<WhereExpr> {
+        let mut lhs = self.parse_term()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.pos += 1;
+            let rhs = self.parse_term()?;
+            lhs = WhereExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_term(&mut self) -> Result<WhereExpr> {
+        if matches!(self.peek(), Some(Token::LParen)) {
+            self.pos += 1;
+            let inner = self.parse_or()?;
+            match self.next() {
+                Some(Token::RParen) => return Ok(inner),
+                other => bail!("expected ')' in WHERE expression, found {other:?}"),
+            }
+        }
+
+        let key = match self.next() {
+            Some(Token::Ident(s)) => s.clone(),
+            other => bail!("expected a property name in WHERE expression, found {other:?}"),
+        };
+
+        match self.next() {
+            Some(Token::Op(op)) => {
+                let op = *op;
+                let value = self.parse_value()?;
+                let numeric = |value: &JsonValue| {
+                    value
+                        .as_f64()
+                        .with_context(|| format!("'{op}' requires a numeric value for '{key}'"))
+                };
+                let predicate = match op {
+                    "=" => WherePredicate::Eq(key, value),
+                    "!=" => WherePredicate::Ne(key, value),
+                    "<" => WherePredicate::Lt(key, numeric(&value)?),
+                    "<=" => WherePredicate::Lte(key, numeric(&value)?),
+                    ">" => WherePredicate::Gt(key, numeric(&value)?),
+                    ">=" => WherePredicate::Gte(key, numeric(&value)?),
+                    _ => unreachable!("tokenizer only emits known operators"),
+                };
+                Ok(WhereExpr::Predicate(predicate))
+            }
+            Some(Token::In) => {
+                match self.next() {
+                    Some(Token::LParen) => {}
+                    other => bail!("expected '(' after IN, found {other:?}"),
+                }
+                let mut values = Vec::new();
+                loop {
+                    values.push(self.parse_value()?);
+                    match self.next() {
+                        Some(Token::Comma) => continue,
+                        Some(Token::RParen) => break,
+                        other => bail!("expected ',' or ')' in IN list, found {other:?}"),
+                    }
+                }
+                Ok(WhereExpr::Predicate(WherePredicate::In(key, values)))
+            }
+            Some(Token::Is) => {
+                if matches!(self.peek(), Some(Token::Not)) {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Null) => Ok(WhereExpr::Predicate(WherePredicate::IsNotNull(key))),
+                        other => bail!("expected NULL after IS NOT, found {other:?}"),
+                    }
+                } else {
+                    match self.next() {
+                        Some(Token::Null) => Ok(WhereExpr::Predicate(WherePredicate::IsNull(key))),
+                        other => bail!("expected NULL after IS, found {other:?}"),
+                    }
+                }
+            }
+            other => bail!("expected a comparison operator, IN, or IS after '{key}', found {other:?}"),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue> {
+        match self.next() {
+            Some(Token::Str(s)) => Ok(JsonValue::String(s.clone())),
+            Some(Token::Num(n)) => Ok(serde_json::json!(*n)),
+            other => bail!("expected a value in WHERE expression, found {other:?}"),
+        }
+    }
+}
+
+/// Parse a small SQL-like WHERE expression (`=`, `!=`, `<`, `<=`, `>`, `>=`, `IN (...)`,
+/// `IS NULL`/`IS NOT NULL`, `AND`/`OR`, with parentheses for grouping) into a [`WhereExpr`]
+/// AST, e.g. `"hauteur > 10 AND nombre_d_etages IS NOT NULL"`. String values must be single-
+/// or double-quoted; numeric values are bare. `AND` binds tighter than `OR`.
+pub fn parse_where(expr: &str) -> Result<WhereExpr> {
+    let tokens = tokenize(expr)?;
+    if tokens.is_empty() {
+        bail!("empty WHERE expression");
+    }
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let result = parser.parse_or()?;
+    if parser.pos != tokens.len() {
+        bail!("unexpected trailing tokens in WHERE expression: {expr}");
+    }
+    Ok(result)
+}
+
+/// Geometry against which `QueryFilter::intersects` tests features.
+#[derive(Debug, Clone)]
+pub enum IntersectGeom {
+    BBox(BoundingBox),
+    /// A single closed polygon ring, as `[x, y]` pairs
+    Polygon(Vec<[f64; 2]>),
+    /// Several closed polygon rings (one per part of a MultiPolygon), as `[x, y]` pairs. A
+    /// feature intersects if it intersects any one part.
+    MultiPolygon(Vec<Vec<[f64; 2]>>),
+}
+
+impl IntersectGeom {
+    /// Build an `IntersectGeom` from a parsed GeoJSON geometry, for callers (e.g. the Python
+    /// `query()` bindings) that receive an arbitrary geometry rather than constructing a
+    /// `BBox`/`Polygon` directly. `Polygon`/`MultiPolygon` use their ring(s) for point-in-polygon
+    /// testing (holes are ignored, same as `intersects_feature`'s existing ring handling); any
+    /// other geometry type (Point, LineString, ...) falls back to its own bounding box.
+    pub fn from_geometry(value: &GeoValue) -> Self {
+        match value {
+            GeoValue::Polygon(rings) => {
+                IntersectGeom::Polygon(rings.first().map(ring_to_points).unwrap_or_default())
+            }
+            GeoValue::MultiPolygon(polygons) => IntersectGeom::MultiPolygon(
+                polygons
+                    .iter()
+                    .filter_map(|rings| rings.first())
+                    .map(ring_to_points)
+                    .collect(),
+            ),
+            other => IntersectGeom::BBox(bbox_of_positions(&collect_positions(other))),
+        }
+    }
+
+    fn bbox(&self) -> BoundingBox {
+        match self {
+            IntersectGeom::BBox(bbox) => *bbox,
+            IntersectGeom::Polygon(ring) => bbox_of_positions(ring),
+            IntersectGeom::MultiPolygon(rings) => {
+                bbox_of_positions(&rings.iter().flatten().copied().collect::<Vec<_>>())
+            }
+        }
+    }
+
+    /// Whether `point` falls inside this geometry (BBox containment, or ray-casting
+    /// point-in-polygon for a Polygon ring / any ring of a MultiPolygon).
+    fn contains_point(&self, x: f64, y: f64) -> bool {
+        match self {
+            IntersectGeom::BBox(bbox) => {
+                x >= bbox.min_x && x <= bbox.max_x && y >= bbox.min_y && y <= bbox.max_y
+            }
+            IntersectGeom::Polygon(ring) => point_in_ring(x, y, ring),
+            IntersectGeom::MultiPolygon(rings) => rings.iter().any(|ring| point_in_ring(x, y, ring)),
+        }
+    }
+
+    /// Whether `feature` intersects this geometry: a bbox prefilter first, then a
+    /// point-in-polygon / segment test over the feature's own coordinates. This is a
+    /// conservative approximation: a `BBox` only checks whether any feature vertex falls inside
+    /// it, so a feature that encloses or crosses the box without a vertex inside is not detected
+    /// (polygon queries also test ring vertices inside the feature and edge crossings). It is adequate for
+    /// the typical "subset features near an area of interest" use case without
+    /// pulling in a full geometry-intersection engine.
+    fn intersects_feature(&self, feature: &Feature) -> bool {
+        let Some(ref geometry) = feature.geometry else {
+            return false;
+        };
+        let self_bbox = self.bbox();
+        let coords = collect_positions(&geometry.value);
+        if coords.is_empty() {
+            return false;
+        }
+
+        // BBox prefilter: skip features whose own bbox misses this geometry's bbox entirely
+        let feature_bbox = bbox_of_positions(&coords);
+        if feature_bbox.max_x < self_bbox.min_x
+            || feature_bbox.min_x > self_bbox.max_x
+            || feature_bbox.max_y < self_bbox.min_y
+            || feature_bbox.min_y > self_bbox.max_y
+        {
+            return false;
+        }
+
+        match self {
+            IntersectGeom::BBox(_) => {
+                // Any vertex inside the bbox is enough once the prefilter above passed
+                coords.iter().any(|p| self.contains_point(p[0], p[1]))
+            }
+            IntersectGeom::Polygon(ring) => ring_intersects_coords(ring, &coords),
+            IntersectGeom::MultiPolygon(rings) => {
+                rings.iter().any(|ring| ring_intersects_coords(ring, &coords))
+            }
+        }
+    }
+}
+
+/// Whether a feature's coordinates intersect a single polygon ring: any vertex of the feature
+/// inside the ring, or any ring vertex inside the feature (covers one ring fully enclosing the
+/// other), or any edge-edge crossing between the two.
+fn ring_intersects_coords(ring: &[[f64; 2]], coords: &[[f64; 2]]) -> bool {
+    if coords.iter().any(|p| point_in_ring(p[0], p[1], ring)) {
+        return true;
+    }
+    if coords.len() >= 3 && ring.iter().any(|p| point_in_ring(p[0], p[1], coords)) {
+        return true;
+    }
+    segments_intersect_any(coords, ring)
+}
+
+fn ring_to_points(ring: &[Vec<f64>]) -> Vec<[f64; 2]> {
+    ring.iter().filter(|p| p.len() >= 2).map(|p| [p[0], p[1]]).collect()
+}
+
+fn bbox_of_positions(coords: &[[f64; 2]]) -> BoundingBox {
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for p in coords {
+        min_x = min_x.min(p[0]);
+        min_y = min_y.min(p[1]);
+        max_x = max_x.max(p[0]);
+        max_y = max_y.max(p[1]);
+    }
+    BoundingBox::new(min_x, min_y, max_x, max_y)
+}
+
+fn collect_positions(value: &GeoValue) -> Vec<[f64; 2]> {
+    let mut out = Vec::new();
+    fn push_position(out: &mut Vec<[f64; 2]>, position: &[f64]) {
+        if position.len() >= 2 {
+            out.push([position[0], position[1]]);
+        }
+    }
+    match value {
+        GeoValue::Point(p) => push_position(&mut out, p),
+        GeoValue::MultiPoint(points) | GeoValue::LineString(points) => {
+            for p in points {
+                push_position(&mut out, p);
+            }
+        }
+        GeoValue::MultiLineString(lines) | GeoValue::Polygon(lines) => {
+            for line in lines {
+                for p in line {
+                    push_position(&mut out, p);
+                }
+            }
+        }
+        GeoValue::MultiPolygon(polygons) => {
+            for polygon in polygons {
+                for line in polygon {
+                    for p in line {
+                        push_position(&mut out, p);
+                    }
+                }
+            }
+        }
+        GeoValue::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                out.extend(collect_positions(&geometry.value));
+            }
+        }
+    }
+    out
+}
+
+/// Ray-casting point-in-polygon test against a (possibly open) ring of `[x, y]` points.
+fn point_in_ring(x: f64, y: f64, ring: &[[f64; 2]]) -> bool {
+    if ring.len() < 3 {
+        return false;
+    }
+    let mut inside = false;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        let (xi, yi) = (ring[i][0], ring[i][1]);
+        let (xj, yj) = (ring[j][0], ring[j][1]);
+        let intersect = ((yi > y) != (yj > y))
+            && (x < (xj - xi) * (y - yi) / (yj - yi + f64::EPSILON) + xi);
+        if intersect {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Whether any consecutive-pair segment of `a` crosses any consecutive-pair segment of `b`.
+fn segments_intersect_any(a: &[[f64; 2]], b: &[[f64; 2]]) -> bool {
+    if a.len() < 2 || b.len() < 2 {
+        return false;
+    }
+    for w in a.windows(2) {
+        for v in b.windows(2) {
+            if segments_intersect(w[0], w[1], v[0], v[1]) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn orientation(p: [f64; 2], q: [f64; 2], r: [f64; 2]) -> f64 {
+    (q[1] - p[1]) * (r[0] - q[0]) - (q[0] - p[0]) * (r[1] - q[1])
+}
+
+fn segments_intersect(p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], p4: [f64; 2]) -> bool {
+    let d1 = orientation(p3, p4, p1);
+    let d2 = orientation(p3, p4, p2);
+    let d3 = orientation(p1, p2, p3);
+    let d4 = orientation(p1, p2, p4);
+    (d1 > 0.0) != (d2 > 0.0) && (d3 > 0.0) != (d4 > 0.0)
+}
+
+/// A select/where/intersects query over a parsed GeoJson FeatureCollection, letting
+/// callers subset features before `get_geojson`/`to_geojson`/export.
+#[derive(Debug, Clone, Default)]
+pub struct QueryFilter {
+    /// Keep only these property names on each surviving feature (all kept if `None`)
+    pub select: Option<Vec<String>>,
+    /// Drop features whose properties don't match this expression
+    pub where_expr: Option<WhereExpr>,
+    /// Keep only features whose geometry intersects this bbox/polygon
+    pub intersects: Option<IntersectGeom>,
+    /// Skip this many matching features before taking any (applied after `where`/`intersects`,
+    /// before `limit`)
+    pub offset: Option<usize>,
+    /// Keep at most this many matching features (applied after `offset`)
+    pub limit: Option<usize>,
+}
+
+impl QueryFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn select(mut self, fields: Vec<String>) -> Self {
+        self.select = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn where_clause(mut self, predicate: WherePredicate) -> Self {
+        self.where_expr = Some(WhereExpr::Predicate(predicate));
+        self
+    }
+
+    pub fn where_expr(mut self, expr: WhereExpr) -> Self {
+        self.where_expr = Some(expr);
+        self
+    }
+
+    /// Build the `where` clause by parsing a small SQL-like WHERE expression. See
+    /// [`parse_where`] for the accepted grammar.
+    pub fn where_str(mut self, expr: &str) -> Result<Self> {
+        self.where_expr = Some(parse_where(expr)?);
+        Ok(self)
+    }
+
+    pub fn intersects(mut self, geom: IntersectGeom) -> Self {
+        self.intersects = Some(geom);
+        self
+    }
+
+    /// Apply this filter to a GeoJson FeatureCollection, returning a new GeoJson with
+    /// the matching (and possibly column-pruned) features.
+    pub fn apply(&self, geojson: &GeoJson) -> GeoJson {
+        let GeoJson::FeatureCollection(fc) = geojson else {
+            return geojson.clone();
+        };
+
+        let features = fc
+            .features
+            .iter()
+            .filter(|f| {
+                self.where_expr
+                    .as_ref()
+                    .map(|expr| expr.matches(f))
+                    .unwrap_or(true)
+            })
+            .filter(|f| {
+                self.intersects
+                    .as_ref()
+                    .map(|geom| geom.intersects_feature(f))
+                    .unwrap_or(true)
+            })
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .map(|f| {
+                let mut feature = f.clone();
+                if let Some(ref fields) = self.select {
+                    if let Some(ref mut props) = feature.properties {
+                        props.retain(|k, _| fields.contains(k));
+                    }
+                }
+                feature
+            })
+            .collect();
+
+        GeoJson::from(FeatureCollection {
+            bbox: fc.bbox.clone(),
+            foreign_members: fc.foreign_members.clone(),
+            features,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geojson::Geometry;
+
+    fn feature_collection(properties: Vec<serde_json::Map<String, JsonValue>>) -> GeoJson {
+        let features = properties
+            .into_iter()
+            .map(|props| Feature {
+                bbox: None,
+                geometry: None,
+                id: None,
+                properties: Some(props),
+                foreign_members: None,
+            })
+            .collect();
+        GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        })
+    }
+
+    fn props(pairs: &[(&str, JsonValue)]) -> serde_json::Map<String, JsonValue> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    #[test]
+    fn test_parse_where_simple_comparison() {
+        let expr = parse_where("hauteur > 10").unwrap();
+        let matching = feature_collection(vec![props(&[("hauteur", serde_json::json!(15.0))])]);
+        let non_matching = feature_collection(vec![props(&[("hauteur", serde_json::json!(5.0))])]);
+        let filter = QueryFilter::new().where_expr(expr);
+        assert_eq!(feature_count(&filter.apply(&matching)), 1);
+        assert_eq!(feature_count(&filter.apply(&non_matching)), 0);
+    }
+
+    #[test]
+    fn test_parse_where_and_or_precedence() {
+        // AND binds tighter than OR: nature = 'LAC' OR (hauteur > 5 AND hauteur < 10)
+        let expr = parse_where("nature = 'LAC' OR hauteur > 5 AND hauteur < 10").unwrap();
+        let filter = QueryFilter::new().where_expr(expr);
+
+        let lake = feature_collection(vec![props(&[("nature", serde_json::json!("LAC"))])]);
+        assert_eq!(feature_count(&filter.apply(&lake)), 1);
+
+        let mid_height = feature_collection(vec![props(&[("hauteur", serde_json::json!(7.0))])]);
+        assert_eq!(feature_count(&filter.apply(&mid_height)), 1);
+
+        let tall = feature_collection(vec![props(&[("hauteur", serde_json::json!(20.0))])]);
+        assert_eq!(feature_count(&filter.apply(&tall)), 0);
+    }
+
+    #[test]
+    fn test_parse_where_in_list() {
+        let expr = parse_where("nature IN ('ETANG', 'LAC')").unwrap();
+        let filter = QueryFilter::new().where_expr(expr);
+
+        let etang = feature_collection(vec![props(&[("nature", serde_json::json!("ETANG"))])]);
+        let riviere = feature_collection(vec![props(&[("nature", serde_json::json!("RIVIERE"))])]);
+        assert_eq!(feature_count(&filter.apply(&etang)), 1);
+        assert_eq!(feature_count(&filter.apply(&riviere)), 0);
+    }
+
+    #[test]
+    fn test_missing_property_does_not_match() {
+        let expr = parse_where("hauteur > 10").unwrap();
+        let filter = QueryFilter::new().where_expr(expr);
+        let no_height = feature_collection(vec![props(&[("nom", serde_json::json!("Bâtiment A"))])]);
+        assert_eq!(feature_count(&filter.apply(&no_height)), 0);
+    }
+
+    #[test]
+    fn test_is_null_and_is_not_null() {
+        let expr = parse_where("hauteur IS NULL").unwrap();
+        let filter = QueryFilter::new().where_expr(expr);
+        let missing = feature_collection(vec![props(&[("nom", serde_json::json!("A"))])]);
+        let null = feature_collection(vec![props(&[("hauteur", JsonValue::Null)])]);
+        let present = feature_collection(vec![props(&[("hauteur", serde_json::json!(12.0))])]);
+        assert_eq!(feature_count(&filter.apply(&missing)), 1);
+        assert_eq!(feature_count(&filter.apply(&null)), 1);
+        assert_eq!(feature_count(&filter.apply(&present)), 0);
+
+        let expr = parse_where("hauteur > 10 AND nombre_d_etages IS NOT NULL").unwrap();
+        let filter = QueryFilter::new().where_expr(expr);
+        let both = feature_collection(vec![props(&[
+            ("hauteur", serde_json::json!(15.0)),
+            ("nombre_d_etages", serde_json::json!(4.0)),
+        ])]);
+        let only_height = feature_collection(vec![props(&[("hauteur", serde_json::json!(15.0))])]);
+        assert_eq!(feature_count(&filter.apply(&both)), 1);
+        assert_eq!(feature_count(&filter.apply(&only_height)), 0);
+    }
+
+    #[test]
+    fn test_where_str_builder() {
+        let filter = QueryFilter::new().where_str("hauteur >= 10").unwrap();
+        let matching = feature_collection(vec![props(&[("hauteur", serde_json::json!(10.0))])]);
+        assert_eq!(feature_count(&filter.apply(&matching)), 1);
+    }
+
+    fn feature_count(geojson: &GeoJson) -> usize {
+        match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.len(),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_limit_and_offset_paginate_after_filtering() {
+        let fc = feature_collection(vec![
+            props(&[("id", serde_json::json!(1))]),
+            props(&[("id", serde_json::json!(2))]),
+            props(&[("id", serde_json::json!(3))]),
+        ]);
+
+        let filter = QueryFilter::new().limit(2);
+        assert_eq!(feature_count(&filter.apply(&fc)), 2);
+
+        let filter = QueryFilter::new().offset(1).limit(1);
+        let GeoJson::FeatureCollection(result) = filter.apply(&fc) else { panic!("expected FeatureCollection") };
+        assert_eq!(result.features.len(), 1);
+        assert_eq!(
+            result.features[0].properties.as_ref().unwrap().get("id"),
+            Some(&serde_json::json!(2))
+        );
+    }
+
+    #[test]
+    fn test_intersect_geom_from_geometry_multipolygon() {
+        let value = GeoValue::MultiPolygon(vec![vec![vec![
+            vec![0.0, 0.0],
+            vec![0.0, 10.0],
+            vec![10.0, 10.0],
+            vec![10.0, 0.0],
+            vec![0.0, 0.0],
+        ]]]);
+        let geom = IntersectGeom::from_geometry(&value);
+        let filter = QueryFilter::new().intersects(geom);
+
+        let inside = feature_collection(vec![props(&[("id", serde_json::json!(1))])]);
+        let GeoJson::FeatureCollection(mut inside_fc) = inside else { unreachable!() };
+        inside_fc.features[0].geometry = Some(Geometry::new(GeoValue::Point(vec![5.0, 5.0])));
+        let inside = GeoJson::from(inside_fc);
+
+        let outside = feature_collection(vec![props(&[("id", serde_json::json!(2))])]);
+        let GeoJson::FeatureCollection(mut outside_fc) = outside else { unreachable!() };
+        outside_fc.features[0].geometry = Some(Geometry::new(GeoValue::Point(vec![50.0, 50.0])));
+        let outside = GeoJson::from(outside_fc);
+
+        assert_eq!(feature_count(&filter.apply(&inside)), 1);
+        assert_eq!(feature_count(&filter.apply(&outside)), 0);
+    }
+}
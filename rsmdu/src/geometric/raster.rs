@@ -0,0 +1,209 @@
+use anyhow::{Context, Result};
+use gdal::raster::Buffer;
+use gdal::spatial_ref::SpatialRef;
+use gdal::vector::Geometry as GdalGeometry;
+use gdal::{Dataset, DriverManager};
+use geo::Geometry as GeoGeometry;
+use geojson::GeoJson;
+use geos::{Geom, Geometry as GeosGeometry};
+use std::path::{Path, PathBuf};
+
+use crate::geo_core::BoundingBox;
+
+/// Shared rasteriser used to burn a GeoJson FeatureCollection into a single-band GeoTIFF.
+/// Used by `Water::to_raster` and `Road::to_raster` so both share one rasterization path.
+pub struct Rasteriser {
+    /// Cell size in CRS units (e.g. metres when the target EPSG is projected)
+    pub cell_size: f64,
+    /// Minimum fraction (0.0-1.0) of a cell's area that must be covered by a feature
+    /// for the cell to be burned. `None` falls back to a point-in-polygon test at
+    /// the cell centre (i.e. any coverage at all).
+    pub area_threshold: Option<f64>,
+    /// When set, only features whose `property_key` value is in this list participate
+    pub property_key: Option<String>,
+    pub property_values: Option<Vec<String>>,
+    /// Value written for cells considered "filled" when no attribute value is used
+    pub burn_value: f64,
+    pub nodata_value: f64,
+}
+
+impl Rasteriser {
+    pub fn new(cell_size: f64) -> Self {
+        Rasteriser {
+            cell_size,
+            area_threshold: None,
+            property_key: None,
+            property_values: None,
+            burn_value: 1.0,
+            nodata_value: -9999.0,
+        }
+    }
+
+    pub fn with_area_threshold(mut self, area_threshold: f64) -> Self {
+        self.area_threshold = Some(area_threshold);
+        self
+    }
+
+    pub fn with_property_filter(mut self, key: &str, values: Vec<String>) -> Self {
+        self.property_key = Some(key.to_string());
+        self.property_values = Some(values);
+        self
+    }
+
+    /// Burn `geojson` into a GeoTIFF at `output_path`, covering `bbox` at `self.cell_size`
+    /// resolution, using the spatial reference identified by `epsg`.
+    pub fn rasterize(
+        &self,
+        geojson: &GeoJson,
+        bbox: &BoundingBox,
+        epsg: i32,
+        output_path: &Path,
+    ) -> Result<PathBuf> {
+        let width = ((bbox.max_x - bbox.min_x) / self.cell_size).ceil().max(1.0) as usize;
+        let height = ((bbox.max_y - bbox.min_y) / self.cell_size).ceil().max(1.0) as usize;
+        let transform = [
+            bbox.min_x,
+            self.cell_size,
+            0.0,
+            bbox.max_y,
+            0.0,
+            -self.cell_size,
+        ];
+
+        let driver =
+            DriverManager::get_driver_by_name("GTiff").context("Failed to get GTiff driver")?;
+        let mut dataset = driver
+            .create_with_band_type::<f64, _>(output_path, width as isize, height as isize, 1)
+            .context("Failed to create GeoTIFF dataset")?;
+        dataset
+            .set_geo_transform(&transform)
+            .context("Failed to set geotransform")?;
+        let srs = SpatialRef::from_epsg(epsg as u32).context("Failed to build spatial reference")?;
+        dataset
+            .set_spatial_ref(&srs)
+            .context("Failed to set spatial reference")?;
+
+        let mut cells = vec![self.nodata_value; width * height];
+
+        if let GeoJson::FeatureCollection(fc) = geojson {
+            for feature in &fc.features {
+                if !self.feature_participates(feature) {
+                    continue;
+                }
+                let Some(ref geometry) = feature.geometry else {
+                    continue;
+                };
+                let value = feature
+                    .properties
+                    .as_ref()
+                    .and_then(|p| self.property_key.as_ref().and_then(|k| p.get(k)))
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(self.burn_value);
+
+                if let Err(e) =
+                    self.burn_geometry(geometry, value, &mut cells, width, height, &transform)
+                {
+                    eprintln!("Warning: failed to rasterize feature: {}", e);
+                }
+            }
+        }
+
+        let mut band = dataset.rasterband(1).context("Failed to get band 1")?;
+        let buffer = Buffer::new((width, height), cells);
+        band.write((0, 0), (width, height), &buffer)
+            .context("Failed to write raster band")?;
+        band.set_no_data_value(Some(self.nodata_value))
+            .context("Failed to set nodata value")?;
+
+        Ok(output_path.to_path_buf())
+    }
+
+    fn feature_participates(&self, feature: &geojson::Feature) -> bool {
+        let (Some(key), Some(values)) = (&self.property_key, &self.property_values) else {
+            return true;
+        };
+        feature
+            .properties
+            .as_ref()
+            .and_then(|p| p.get(key))
+            .map(|v| {
+                let as_str = v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string());
+                values.iter().any(|candidate| candidate == &as_str)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Burn one geometry into `cells`, testing either point-in-polygon at the cell centre
+    /// or (when `area_threshold` is set) the fraction of the cell's area covered by the
+    /// geometry via a sub-sample grid.
+    fn burn_geometry(
+        &self,
+        geometry: &geojson::Geometry,
+        value: f64,
+        cells: &mut [f64],
+        width: usize,
+        height: usize,
+        transform: &[f64; 6],
+    ) -> Result<()> {
+        let geo_geom: GeoGeometry<f64> = geometry
+            .try_into()
+            .context("Failed to convert GeoJSON geometry to geo geometry")?;
+        let geos_geom: GeosGeometry = geo_geom
+            .try_into()
+            .context("Failed to convert geo geometry to GEOS")?;
+        let wkt = geos_geom.to_wkt().context("Failed to convert GEOS geometry to WKT")?;
+        let gdal_geom = GdalGeometry::from_wkt(&wkt).context("Failed to build GDAL geometry")?;
+
+        let envelope = gdal_geom.envelope();
+        let x_origin = transform[0];
+        let pixel_width = transform[1];
+        let y_origin = transform[3];
+        let pixel_height = transform[5];
+
+        let min_col = ((envelope.MinX - x_origin) / pixel_width).floor().max(0.0) as usize;
+        let max_col = ((envelope.MaxX - x_origin) / pixel_width)
+            .ceil()
+            .min(width as f64) as usize;
+        let min_row = ((y_origin - envelope.MaxY) / pixel_width.abs())
+            .floor()
+            .max(0.0) as usize;
+        let max_row = ((y_origin - envelope.MinY) / pixel_width.abs())
+            .ceil()
+            .min(height as f64) as usize;
+
+        // Sub-sample grid per cell when an area_threshold is requested
+        let samples_per_axis = if self.area_threshold.is_some() { 5 } else { 1 };
+
+        for row in min_row..max_row {
+            for col in min_col..max_col {
+                let mut hits = 0usize;
+                let total = samples_per_axis * samples_per_axis;
+                for sy in 0..samples_per_axis {
+                    for sx in 0..samples_per_axis {
+                        let fx = (sx as f64 + 0.5) / samples_per_axis as f64;
+                        let fy = (sy as f64 + 0.5) / samples_per_axis as f64;
+                        let x = x_origin + (col as f64 + fx) * pixel_width;
+                        let y = y_origin + (row as f64 + fy) * pixel_height;
+                        let point_wkt = format!("POINT({} {})", x, y);
+                        if let Ok(point_geom) = GdalGeometry::from_wkt(&point_wkt) {
+                            if gdal_geom.contains(&point_geom) {
+                                hits += 1;
+                            }
+                        }
+                    }
+                }
+
+                let covered_fraction = hits as f64 / total as f64;
+                let covered = match self.area_threshold {
+                    Some(threshold) => covered_fraction >= threshold,
+                    None => hits > 0,
+                };
+                if covered {
+                    cells[row * width + col] = value;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,23 @@
 use anyhow::{Context, Result};
 use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use serde_json::Map;
 use std::path::{Path, PathBuf};
 
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, GeoParquetCompression, OutputFormat};
+use crate::geometric::geocode::{self, GeocodeResult};
+use crate::geometric::query::QueryFilter;
+
+/// EPSG code the RNB API always answers in.
+const RNB_SOURCE_EPSG: i32 = 4326;
+
+/// Default maximum tile side length (degrees, EPSG:4326) before [`Rnb::run_internal`] starts
+/// splitting `bbox` into a grid and fetching tiles concurrently. The RNB alpha API does not
+/// publish a hard bbox-size limit, so this is a conservative default rather than a measured
+/// one; see [`Rnb::set_max_tile_size`].
+const DEFAULT_MAX_TILE_SIZE_DEG: f64 = 0.05;
 
 /// RNB (Référentiel National des Bâtiments) structure
 /// Following Python implementation from pymdu.geometric.Rnb
@@ -17,12 +31,26 @@ pub struct Rnb {
     bbox: Option<BoundingBox>,
     /// Parsed GeoJSON content
     geojson: Option<GeoJson>,
+    /// CRS explicitly requested via [`Rnb::set_crs`], if any -- distinguishes "reproject to
+    /// this after fetching" from `geo_core`'s default EPSG, mirroring
+    /// [`crate::geometric::water::Water`]'s `set_crs` field.
+    set_crs: Option<i32>,
+    /// Maximum tile side length (degrees, EPSG:4326) before `run_internal` splits `bbox` into a
+    /// grid of sub-boxes and fetches them concurrently. See [`Rnb::set_max_tile_size`].
+    max_tile_size_deg: f64,
+    /// Worker thread count for tile fetching once `bbox` is split into more than one tile, via
+    /// a dedicated rayon thread pool; `None` uses rayon's global pool. See
+    /// [`Rnb::set_concurrency`].
+    concurrency: Option<usize>,
 }
 
-/// RNB API response structure
+/// RNB API response structure -- one page of the paginated `/buildings` endpoint.
 #[derive(serde::Deserialize)]
 struct RnbApiResponse {
     results: Vec<RnbBuilding>,
+    /// Full URL of the next page, or `None` on the last page. Following it until it's `None`
+    /// is the only way to collect every building in a bbox -- a single page truncates silently.
+    next: Option<String>,
 }
 
 /// RNB Building structure from API
@@ -73,6 +101,9 @@ impl Rnb {
             geo_core: GeoCore::default(), // Default to EPSG:2154 (Lambert-93)
             bbox: None,
             geojson: None,
+            set_crs: None,
+            max_tile_size_deg: DEFAULT_MAX_TILE_SIZE_DEG,
+            concurrency: None,
         })
     }
 
@@ -82,10 +113,44 @@ impl Rnb {
         self.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
     }
 
+    /// Geocode `query` via the French BAN (Base Adresse Nationale) address search API and set
+    /// `bbox` to a square of `buffer_m` metres around the best match, so callers can collect
+    /// buildings around a street address instead of supplying raw coordinates to
+    /// [`Rnb::set_bbox`]. Returns every candidate label the geocoder found, best match first, so
+    /// the caller can tell whether it picked the right one and re-run with a more specific
+    /// `query` if not.
+    pub fn set_bbox_from_address(
+        &mut self,
+        query: &str,
+        buffer_m: f64,
+    ) -> Result<Vec<GeocodeResult>> {
+        let (bbox, candidates) = geocode::bbox_from_address(query, buffer_m)?;
+        self.bbox = Some(bbox);
+        Ok(candidates)
+    }
+
     /// Set CRS
     /// Following Python: rnb._epsg = epsg
     pub fn set_crs(&mut self, epsg: i32) {
         self.geo_core.set_epsg(epsg);
+        self.set_crs = Some(epsg);
+    }
+
+    /// Cap the tile side length (degrees, EPSG:4326) `run()`/`run_internal()` use when splitting
+    /// a large `bbox` into a grid before fetching; the default favours staying comfortably under
+    /// whatever limit the RNB API enforces over speed. Set lower to parallelize city-scale
+    /// extractions more finely -- see [`Rnb::set_concurrency`] to also bound how many tiles
+    /// fetch at once.
+    pub fn set_max_tile_size(&mut self, max_tile_size_deg: f64) {
+        self.max_tile_size_deg = max_tile_size_deg;
+    }
+
+    /// Bound the worker thread count `run()`/`run_internal()` use to fetch tiles concurrently
+    /// once `bbox` is split by [`Rnb::set_max_tile_size`]; `None` falls back to rayon's global
+    /// pool (sized to the number of CPUs). Mirrors
+    /// [`crate::geometric::lidar::Lidar::set_ingest_worker_threads`].
+    pub fn set_concurrency(&mut self, concurrency: Option<usize>) {
+        self.concurrency = concurrency;
     }
 
     /// Run RNB processing: fetch from RNB API, parse JSON, create GeoJSON
@@ -106,19 +171,6 @@ impl Rnb {
         // Python: url = "https://rnb-api.beta.gouv.fr/api/alpha/buildings"
         let url = "https://rnb-api.beta.gouv.fr/api/alpha/buildings";
 
-        // According to RNB API documentation:
-        // - bbox (recommended): min_lon,min_lat,max_lon,max_lat
-        // - bb (obsolete): nw_lat,nw_lon,se_lat,se_lon
-        // Python uses bb with format: min_y, min_x, max_y, max_x
-        // We'll use the recommended bbox parameter with format: min_lon,min_lat,max_lon,max_lat
-        // Which corresponds to: min_x, min_y, max_x, max_y
-        let bbox_param = format!(
-            "{},{},{},{}",
-            bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y
-        );
-        println!("RNB API bbox parameter: {}", bbox_param);
-        println!("RNB API URL: {}", url);
-
         // Make HTTP request
         // Python: response = requests.get(url=url, headers=headers, params=payload, verify=False)
         let client = reqwest::blocking::Client::builder()
@@ -126,24 +178,40 @@ impl Rnb {
             .build()
             .context("Failed to create HTTP client")?;
 
-        let response = client
-            .get(url)
-            .header("Content-type", "application/json")
-            .query(&[("bbox", &bbox_param)])
-            .send()
-            .context("Failed to send request to RNB API")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().unwrap_or_default();
-            anyhow::bail!("RNB API returned error {}: {}", status, body);
+        let tiles = Self::plan_bbox_tiles(bbox, self.max_tile_size_deg);
+        if tiles.len() > 1 {
+            println!(
+                "RNB bbox exceeds max_tile_size={:.4} degrees/side, splitting into {} tiles",
+                self.max_tile_size_deg,
+                tiles.len()
+            );
         }
 
-        // Parse JSON response
-        // Python: content = response.json()
-        let api_response: RnbApiResponse = response
-            .json()
-            .context("Failed to parse JSON response from RNB API")?;
+        let fetch_tile = |tile: &BoundingBox| Self::fetch_all_buildings(&client, url, tile);
+
+        #[cfg(feature = "rayon")]
+        let tile_results: Vec<Vec<RnbBuilding>> = if let Some(threads) = self.concurrency {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .context("Failed to build rayon thread pool for RNB tile collection")?;
+            pool.install(|| tiles.par_iter().map(fetch_tile).collect::<Result<Vec<_>>>())?
+        } else {
+            tiles
+                .par_iter()
+                .map(fetch_tile)
+                .collect::<Result<Vec<_>>>()?
+        };
+        #[cfg(not(feature = "rayon"))]
+        let tile_results: Vec<Vec<RnbBuilding>> =
+            tiles.iter().map(fetch_tile).collect::<Result<Vec<_>>>()?;
+
+        // Tiles can overlap slightly at their shared edges (a building sitting exactly on a
+        // tile boundary can be returned by both), so dedupe by `rnb_id` before converting to
+        // features.
+        let mut buildings: Vec<RnbBuilding> = tile_results.into_iter().flatten().collect();
+        let mut seen_ids = std::collections::HashSet::new();
+        buildings.retain(|building| seen_ids.insert(building.rnb_id.clone()));
 
         // Convert to GeoJSON FeatureCollection
         // Python: for item in content["results"]:
@@ -152,105 +220,387 @@ impl Rnb {
         //         ...
         //         gdf = gpd.GeoDataFrame(df, geometry=geometry, crs="EPSG:4326")
         //         gdf = gdf.to_crs(self._epsg)
-        let mut features = Vec::new();
+        let features: Vec<Feature> = buildings
+            .into_iter()
+            .filter_map(Self::building_to_feature)
+            .collect();
+
+        // Create FeatureCollection
+        let feature_collection = FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        };
+
+        // Python: gdf = gdf.to_crs(self._epsg) -- the RNB API always answers in EPSG:4326, so
+        // reproject to the CRS explicitly requested via `set_crs`, the way `Water::run_internal`
+        // does; otherwise leave it in EPSG:4326 and label `geo_core` accordingly rather than
+        // claiming whatever CRS happened to be the default.
+        self.geojson = Some(GeoJson::from(feature_collection));
+        match self.set_crs {
+            Some(target_epsg) => self.reproject_to(RNB_SOURCE_EPSG, target_epsg)?,
+            None => self.geo_core.set_epsg(RNB_SOURCE_EPSG),
+        }
+
+        Ok(())
+    }
 
-        for building in api_response.results {
-            // Extract coordinates
-            if building.point.coordinates.len() < 2 {
-                continue; // Skip invalid coordinates
+    /// Split `bbox` into a grid of sub-boxes each no larger than `max_tile_size_deg` degrees on
+    /// a side, used by [`Rnb::run_internal`] to stay under the RNB API's effective bbox size
+    /// limits and to parallelize city-scale collection. Returns a single-element vec when `bbox`
+    /// already fits. Pure and network-free so it can be unit tested directly.
+    fn plan_bbox_tiles(bbox: &BoundingBox, max_tile_size_deg: f64) -> Vec<BoundingBox> {
+        let width = bbox.max_x - bbox.min_x;
+        let height = bbox.max_y - bbox.min_y;
+
+        let tiles_x = (width / max_tile_size_deg).ceil().max(1.0) as usize;
+        let tiles_y = (height / max_tile_size_deg).ceil().max(1.0) as usize;
+
+        let tile_width = width / tiles_x as f64;
+        let tile_height = height / tiles_y as f64;
+
+        let mut tiles = Vec::with_capacity(tiles_x * tiles_y);
+        for ty in 0..tiles_y {
+            for tx in 0..tiles_x {
+                tiles.push(BoundingBox::new(
+                    bbox.min_x + tx as f64 * tile_width,
+                    bbox.min_y + ty as f64 * tile_height,
+                    bbox.min_x + (tx + 1) as f64 * tile_width,
+                    bbox.min_y + (ty + 1) as f64 * tile_height,
+                ));
             }
-            let lon = building.point.coordinates[0];
-            let lat = building.point.coordinates[1];
-
-            // Create Point geometry
-            // Python: geometry = [Point(coordinates)]
-            let geometry = Geometry::new(Value::Point(vec![lon, lat]));
-
-            // Create properties
-            let mut properties = Map::new();
-            properties.insert(
-                "rnb_id".to_string(),
-                serde_json::Value::String(building.rnb_id),
-            );
-            properties.insert(
-                "status".to_string(),
-                serde_json::Value::String(building.status),
-            );
+        }
+        tiles
+    }
+
+    /// Fetch every building in `bbox`, following the RNB alpha API's `next` cursor until it is
+    /// `null` -- the API paginates `results`, so reading only the first response silently drops
+    /// every building past the first page.
+    ///
+    /// According to RNB API documentation:
+    /// - bbox (recommended): min_lon,min_lat,max_lon,max_lat
+    /// - bb (obsolete): nw_lat,nw_lon,se_lat,se_lon
+    /// Python uses bb with format: min_y, min_x, max_y, max_x
+    /// We'll use the recommended bbox parameter with format: min_lon,min_lat,max_lon,max_lat
+    /// Which corresponds to: min_x, min_y, max_x, max_y
+    fn fetch_all_buildings(
+        client: &reqwest::blocking::Client,
+        url: &str,
+        bbox: &BoundingBox,
+    ) -> Result<Vec<RnbBuilding>> {
+        let bbox_param = format!(
+            "{},{},{},{}",
+            bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y
+        );
+        println!("RNB API bbox parameter: {}", bbox_param);
+        println!("RNB API URL: {}", url);
 
-            // Add address information if available
-            // Python: if len(item["addresses"]) > 0:
-            if let Some(address) = building.addresses.first() {
-                if let Some(ref street_number) = address.street_number {
-                    let street_number_str: String = street_number.clone();
-                    properties.insert(
-                        "street_number".to_string(),
-                        serde_json::Value::String(street_number_str),
-                    );
-                }
-                if let Some(ref city_name) = address.city_name {
-                    let city_name_str: String = city_name.clone();
-                    properties.insert(
-                        "city_name".to_string(),
-                        serde_json::Value::String(city_name_str),
-                    );
-                }
-                if let Some(ref city_zipcode) = address.city_zipcode {
-                    let city_zipcode_str: String = city_zipcode.clone();
-                    properties.insert(
-                        "city_zipcode".to_string(),
-                        serde_json::Value::String(city_zipcode_str),
-                    );
-                }
+        let mut buildings = Vec::new();
+        let mut next_url = Some(url.to_string());
+        let mut is_first_request = true;
+
+        while let Some(request_url) = next_url.take() {
+            let mut request = client
+                .get(&request_url)
+                .header("Content-type", "application/json");
+            // `next` is already a full URL carrying its own `bbox`/cursor query string.
+            if is_first_request {
+                request = request.query(&[("bbox", &bbox_param)]);
             }
+            is_first_request = false;
+
+            let response = request
+                .send()
+                .with_context(|| format!("Failed to send request to RNB API at {}", request_url))?;
 
-            // Add created_at if available
-            // Python: created_at = item["ext_ids"][0]["created_at"]
-            if let Some(ext_id) = building.ext_ids.first() {
-                if let Some(ref created_at) = ext_id.created_at {
-                    let created_at_str: String = created_at.clone();
-                    properties.insert(
-                        "created_at".to_string(),
-                        serde_json::Value::String(created_at_str),
-                    );
-                }
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().unwrap_or_default();
+                anyhow::bail!("RNB API returned error {}: {}", status, body);
             }
 
-            // Create feature
-            let mut feature = Feature::from(geometry);
-            feature.properties = Some(properties);
+            // Python: content = response.json()
+            let page: RnbApiResponse = response
+                .json()
+                .context("Failed to parse JSON response from RNB API")?;
+            buildings.extend(page.results);
+            next_url = page.next;
+        }
+
+        Ok(buildings)
+    }
 
-            features.push(feature);
+    /// Convert one RNB API building to a GeoJSON `Feature`, carrying over `rnb_id`/`status`/the
+    /// first address's fields/the first external id's `created_at`. Returns `None` when the API
+    /// gave fewer than 2 point coordinates, so one malformed building doesn't abort the whole
+    /// collection.
+    fn building_to_feature(building: RnbBuilding) -> Option<Feature> {
+        // Extract coordinates
+        if building.point.coordinates.len() < 2 {
+            return None; // Skip invalid coordinates
         }
+        let lon = building.point.coordinates[0];
+        let lat = building.point.coordinates[1];
+
+        // Create Point geometry
+        // Python: geometry = [Point(coordinates)]
+        let geometry = Geometry::new(Value::Point(vec![lon, lat]));
+
+        // Create properties
+        let mut properties = Map::new();
+        properties.insert(
+            "rnb_id".to_string(),
+            serde_json::Value::String(building.rnb_id),
+        );
+        properties.insert(
+            "status".to_string(),
+            serde_json::Value::String(building.status),
+        );
 
-        // Create FeatureCollection
-        let feature_collection = FeatureCollection {
-            bbox: None,
-            foreign_members: None,
-            features,
+        // Add address information if available
+        // Python: if len(item["addresses"]) > 0:
+        if let Some(address) = building.addresses.into_iter().next() {
+            if let Some(street_number) = address.street_number {
+                properties.insert(
+                    "street_number".to_string(),
+                    serde_json::Value::String(street_number),
+                );
+            }
+            if let Some(city_name) = address.city_name {
+                properties.insert(
+                    "city_name".to_string(),
+                    serde_json::Value::String(city_name),
+                );
+            }
+            if let Some(city_zipcode) = address.city_zipcode {
+                properties.insert(
+                    "city_zipcode".to_string(),
+                    serde_json::Value::String(city_zipcode),
+                );
+            }
+        }
+
+        // Add created_at if available
+        // Python: created_at = item["ext_ids"][0]["created_at"]
+        if let Some(ext_id) = building.ext_ids.into_iter().next() {
+            if let Some(created_at) = ext_id.created_at {
+                properties.insert(
+                    "created_at".to_string(),
+                    serde_json::Value::String(created_at),
+                );
+            }
+        }
+
+        // Create feature
+        let mut feature = Feature::from(geometry);
+        feature.properties = Some(properties);
+        Some(feature)
+    }
+
+    /// Sample a DEM/DSM GeoTIFF at `dem_path` (expected to already be in `geo_core`'s CRS, the
+    /// same CRS the stored points are in) at every building's `(x, y)` and attach the result as
+    /// a `height` property, bilinearly interpolating over the four surrounding pixels. Features
+    /// landing on a nodata cell or entirely outside the raster extent are left without a
+    /// `height` property rather than erroring, since a DEM/DSM tile rarely covers every corner
+    /// of an RNB bbox exactly. Turns the RNB point registry into input suitable for
+    /// urban-morphology and solar/shadow modelling.
+    pub fn with_elevation(&mut self, dem_path: &Path) -> Result<()> {
+        use gdal::raster::Buffer;
+        use gdal::Dataset;
+
+        let geojson = self
+            .geojson
+            .as_mut()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        let dataset = Dataset::open(dem_path)
+            .with_context(|| format!("Failed to open DEM/DSM raster {:?}", dem_path))?;
+        let band = dataset
+            .rasterband(1)
+            .context("DEM/DSM raster has no band 1")?;
+        let transform = dataset
+            .geo_transform()
+            .context("DEM/DSM raster has no geotransform")?;
+        let (width, height) = dataset.raster_size();
+        let nodata = band.no_data_value();
+        let buffer: Buffer<f64> = band
+            .read_as((0, 0), (width, height), (width, height), None)
+            .context("Failed to read DEM/DSM raster")?;
+
+        let fc = match geojson {
+            GeoJson::FeatureCollection(fc) => fc,
+            _ => anyhow::bail!("Expected a FeatureCollection"),
         };
 
-        // Note: Reprojection to target CRS (Python: gdf = gdf.to_crs(self._epsg))
-        // would require converting GeoJSON to GDAL Dataset, reprojecting, and converting back
-        // This is complex and would require additional dependencies
-        // For now, we store the GeoJSON as-is in EPSG:4326
-        // TODO: Implement reprojection using GDAL or proj crate
-        self.geojson = Some(GeoJson::from(feature_collection));
+        for feature in &mut fc.features {
+            let Some(ref geometry) = feature.geometry else {
+                continue;
+            };
+            let Value::Point(ref coordinates) = geometry.value else {
+                continue;
+            };
+            if coordinates.len() < 2 {
+                continue;
+            }
+
+            if let Some(elevation) = Self::sample_raster_bilinear(
+                &buffer.data,
+                width,
+                height,
+                &transform,
+                coordinates[0],
+                coordinates[1],
+                nodata,
+            ) {
+                feature
+                    .properties
+                    .get_or_insert_with(Map::new)
+                    .insert("height".to_string(), serde_json::json!(elevation));
+            }
+        }
 
         Ok(())
     }
 
+    /// Bilinearly sample a single-band raster at `(x, y)` (in the raster's own CRS units),
+    /// using `transform`'s GDAL-style 6-element affine geotransform to map world coordinates to
+    /// pixel row/col. Returns `None` for points outside the raster extent or landing on a
+    /// nodata cell; when one of the four neighbours is nodata, returns the value of the cell the
+    /// point lands on instead of interpolating. Same 4-corner interpolation as
+    /// [`crate::geometric::lidar::Lidar::sample_band`], generalized to a flat row-major buffer
+    /// (as read straight off a GDAL `RasterBand`) instead of a `Vec<Vec<f64>>` grid.
+    fn sample_raster_bilinear(
+        data: &[f64],
+        width: usize,
+        height: usize,
+        transform: &[f64; 6],
+        x: f64,
+        y: f64,
+        nodata: Option<f64>,
+    ) -> Option<f64> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let is_nodata = |v: f64| v.is_nan() || nodata.is_some_and(|nd| (v - nd).abs() < f64::EPSILON);
+        let at = |row: usize, col: usize| data[row * width + col];
+
+        let x_min = transform[0];
+        let pixel_width = transform[1];
+        let y_max = transform[3];
+        let pixel_height = -transform[5];
+
+        let col_f = (x - x_min) / pixel_width;
+        let row_f = (y_max - y) / pixel_height;
+        if !(0.0..=width as f64).contains(&col_f) || !(0.0..=height as f64).contains(&row_f) {
+            return None;
+        }
+
+        let nearest_col = (col_f.floor() as isize).clamp(0, width as isize - 1) as usize;
+        let nearest_row = (row_f.floor() as isize).clamp(0, height as isize - 1) as usize;
+        let nearest = at(nearest_row, nearest_col);
+
+        if width < 2 || height < 2 {
+            return if is_nodata(nearest) { None } else { Some(nearest) };
+        }
+
+        // Sample at pixel centers: cell (r, c) is centered at row_f == r + 0.5.
+        let cf = (col_f - 0.5).clamp(0.0, (width - 1) as f64);
+        let rf = (row_f - 0.5).clamp(0.0, (height - 1) as f64);
+        let c0 = (cf.floor() as usize).min(width - 2);
+        let r0 = (rf.floor() as usize).min(height - 2);
+        let tx = cf - c0 as f64;
+        let ty = rf - r0 as f64;
+
+        let v00 = at(r0, c0);
+        let v10 = at(r0, c0 + 1);
+        let v01 = at(r0 + 1, c0);
+        let v11 = at(r0 + 1, c0 + 1);
+        if is_nodata(v00) || is_nodata(v10) || is_nodata(v01) || is_nodata(v11) {
+            return if is_nodata(nearest) { None } else { Some(nearest) };
+        }
+
+        let top = v00 * (1.0 - tx) + v10 * tx;
+        let bottom = v01 * (1.0 - tx) + v11 * tx;
+        Some(top * (1.0 - ty) + bottom * ty)
+    }
+
+    /// Reproject the stored GeoJSON from `from_epsg` to `to_epsg`, regardless of what EPSG
+    /// `geo_core` currently thinks it's in. Mirrors [`crate::geometric::water::Water::reproject_to`].
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            GeoCore::reproject_geojson(geojson, from_epsg, to_epsg)?;
+        }
+        self.geo_core.set_epsg(to_epsg);
+        Ok(())
+    }
+
+    /// Reproject the stored GeoJSON from `geo_core`'s current EPSG to `to_epsg`, updating
+    /// `geo_core.epsg` on success. Unlike [`Rnb::reproject_to`], this goes through
+    /// `GeoCore::reproject`'s cached `Proj` pipeline, so calling it repeatedly doesn't rebuild
+    /// the transformation each time.
+    pub fn reproject(&mut self, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            self.geo_core.reproject(geojson, to_epsg)?;
+        } else {
+            self.geo_core.set_epsg(to_epsg);
+        }
+        Ok(())
+    }
+
+    /// Reproject back to EPSG:4326 (WGS84 lat/long) -- a no-op right after `run()`, since the
+    /// RNB API already answers in EPSG:4326, but meaningful after a `reproject`/`reproject_to`
+    /// call elsewhere.
+    pub fn to_latlong(&mut self) -> Result<()> {
+        self.reproject(RNB_SOURCE_EPSG)
+    }
+
     /// Get the GeoJSON (equivalent to to_gdf() in Python)
     /// Following Python: def to_gdf(self) -> gpd.GeoDataFrame
     pub fn get_geojson(&self) -> Option<&GeoJson> {
         self.geojson.as_ref()
     }
 
+    /// Keep only the stored features matching a small SQL-like WHERE expression over feature
+    /// properties, mutating the stored GeoJSON in place. See
+    /// [`crate::geometric::query::parse_where`] for the accepted grammar.
+    pub fn filter(&mut self, expr: &str) -> Result<()> {
+        if let Some(geojson) = self.geojson.as_mut() {
+            self.geo_core.filter(geojson, expr)?;
+        }
+        Ok(())
+    }
+
+    /// Subset the collected RNB features with a select/where/intersects query, without
+    /// mutating the stored GeoJSON. Callers can pass the result straight to
+    /// `to_file`/export helpers to write only the matching features.
+    pub fn query(&self, filter: &QueryFilter) -> Result<GeoJson> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        Ok(filter.apply(geojson))
+    }
+
     /// Save to GeoJSON file
     /// Following Python: def to_geojson(self, name: str = "rnb")
     /// Note: GeoJSON export requires GDAL and is complex
     /// For now, we save as GeoJSON - full GeoJSON export would require GDAL layer operations
     /// TODO: Implement full GeoJSON export using GDAL
     pub fn to_geojson(&self, name: Option<&str>) -> Result<()> {
+        self.to_geojson_with_options(name, None, None)
+    }
+
+    /// Like [`Rnb::to_geojson`], with two extra knobs for large RNB FeatureCollections:
+    /// `precision` rounds every emitted coordinate to that many decimal places (6 decimals is
+    /// about 0.1m at these latitudes, and cuts file size substantially), and `foreign_members`
+    /// attaches top-level members (e.g. a `bbox`, source API name, query timestamp) to the
+    /// written FeatureCollection. Both are no-ops when `None`.
+    pub fn to_geojson_with_options(
+        &self,
+        name: Option<&str>,
+        precision: Option<u32>,
+        foreign_members: Option<Map<String, serde_json::Value>>,
+    ) -> Result<()> {
         // Python: self.gdf.to_file(f"{os.path.join(self.output_path, name)}.gpkg", driver="GeoJSON")
         // For now, save as GeoJSON as a workaround
         // Full GeoJSON export would require:
@@ -264,6 +614,16 @@ impl Rnb {
             .as_ref()
             .context("No GeoJSON data available. Call run() first.")?;
 
+        let mut geojson = geojson.clone();
+        if let Some(precision) = precision {
+            GeoCore::round_coordinates(&mut geojson, precision);
+        }
+        if let Some(foreign_members) = foreign_members {
+            if let GeoJson::FeatureCollection(fc) = &mut geojson {
+                fc.foreign_members = Some(foreign_members);
+            }
+        }
+
         let name = name.unwrap_or("rnb");
 
         // Save as GeoJSON for now (GeoJSON export is complex with GDAL Rust bindings)
@@ -281,8 +641,43 @@ impl Rnb {
         Ok(())
     }
 
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, GeoJSON, FlatGeobuf, KML, GPX)
+    /// via `ogr2ogr`, reprojecting to geo_core's EPSG on the way out, mirroring
+    /// [`crate::geometric::water::Water::to_file`]. Preserves every property column `run_internal`
+    /// populates (`rnb_id`, `status`, the address fields, `created_at`).
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("rnb");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.epsg)
+    }
+
     /// Get output path
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
     }
+
+    /// Export to GeoParquet via `ogr2ogr -f Parquet`, reprojecting to `geo_core`'s EPSG on the
+    /// way out, mirroring [`crate::geometric::water::Water::to_geoparquet`].
+    pub fn to_geoparquet(
+        &self,
+        name: Option<&str>,
+        compression: GeoParquetCompression,
+    ) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("rnb");
+        export::to_file_with_options(
+            geojson,
+            &self.output_path,
+            name,
+            OutputFormat::GeoParquet,
+            self.geo_core.epsg,
+            &[compression.as_layer_option()],
+        )
+    }
 }
@@ -4,6 +4,10 @@ use std::path::{Path, PathBuf};
 
 use crate::collect::ign::ign_collect::IgnCollect;
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, OutputFormat};
+use crate::geometric::graph::RoadGraph;
+use crate::geometric::query::QueryFilter;
+use crate::geometric::raster::Rasteriser;
 
 /// Road structure
 /// Following Python implementation from pymdu.geometric.Road
@@ -81,13 +85,11 @@ impl Road {
             .parse()
             .context("Failed to parse GeoJSON from IGN API response")?;
 
-        // Store the parsed GeoJSON
-        // Note: Reprojection to target CRS (Python: gdf = gdf.to_crs(self._epsg))
-        // would require converting GeoJSON to GDAL Dataset, reprojecting, and converting back
-        // This is complex and would require additional dependencies
-        // For now, we store the GeoJSON as-is
-        // TODO: Implement reprojection using GDAL or proj crate
+        // Store the parsed GeoJSON, then reproject from the IGN WFS frame (EPSG:4326)
+        // to the target CRS held in geo_core.
+        // Python: gdf = gdf.to_crs(self._epsg)
         self.geojson = Some(geojson);
+        self.reproject_to(4326, self.geo_core.epsg)?;
 
         Ok(self)
     }
@@ -114,16 +116,38 @@ impl Road {
             .context("Failed to parse GeoJSON from IGN API response")?;
 
         self.geojson = Some(geojson);
+        self.reproject_to(4326, self.geo_core.epsg)?;
 
         Ok(())
     }
 
+    /// Reproject the stored GeoJSON from `from_epsg` to `to_epsg`, transforming every
+    /// coordinate pair in place. Callers can also invoke this directly after `run()`
+    /// to re-target a different CRS without re-fetching the data.
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            GeoCore::reproject_geojson(geojson, from_epsg, to_epsg)?;
+        }
+        Ok(())
+    }
+
     /// Get the GeoJSON (equivalent to to_gdf() in Python)
     /// Following Python: def to_gdf(self) -> gpd.GeoDataFrame
     pub fn get_geojson(&self) -> Option<&GeoJson> {
         self.geojson.as_ref()
     }
 
+    /// Subset the collected road features with a select/where/intersects query,
+    /// without mutating the stored GeoJSON. Callers can pass the result straight to
+    /// `to_file`/export helpers to write only the matching features.
+    pub fn query(&self, filter: &QueryFilter) -> Result<GeoJson> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        Ok(filter.apply(geojson))
+    }
+
     /// Save to GeoJSON file
     /// Following Python: def to_geojson(self, name: str = "routes")
     /// Note: GeoJSON export requires GDAL and is complex
@@ -164,4 +188,64 @@ impl Road {
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
     }
+
+    /// Burn the road lines into a GeoTIFF, for use as gridded input to urban-climate
+    /// models. `cell_size` is in geo_core's CRS units, `area_threshold` (0.0-1.0) requires
+    /// a minimum covered fraction of a cell before it is marked filled, and `nature_filter`
+    /// restricts which features participate by their `nature` property.
+    pub fn to_raster(
+        &self,
+        name: Option<&str>,
+        cell_size: f64,
+        area_threshold: Option<f64>,
+        nature_filter: Option<Vec<String>>,
+    ) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before rasterizing")?;
+
+        let mut rasteriser = Rasteriser::new(cell_size);
+        if let Some(threshold) = area_threshold {
+            rasteriser = rasteriser.with_area_threshold(threshold);
+        }
+        if let Some(values) = nature_filter {
+            rasteriser = rasteriser.with_property_filter("nature", values);
+        }
+
+        let name = name.unwrap_or("road");
+        let output_path = self.output_path.join(format!("{}.tif", name));
+        rasteriser.rasterize(geojson, &bbox, self.geo_core.epsg, &output_path)
+    }
+
+    /// Build a routable graph from the collected road network: every LineString vertex becomes
+    /// a node (vertices shared between LineStrings merge into one node) and every segment an
+    /// edge weighted by its geodesic length, computed on a copy reprojected from geo_core's
+    /// EPSG to EPSG:4326. LineStrings that cross without sharing a vertex are not connected.
+    /// See [`RoadGraph`] for shortest-path and link-redundancy analysis — the
+    /// multi-link redundancy / detour-length workflow from flood-impact road studies, used to
+    /// find critical segments whose loss most degrades accessibility.
+    pub fn build_graph(&self) -> Result<RoadGraph> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let mut geojson = geojson.clone();
+        GeoCore::reproject_geojson(&mut geojson, self.geo_core.epsg, 4326)?;
+        RoadGraph::from_geojson(&geojson)
+    }
+
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, GeoJSON, FlatGeobuf, KML, GPX)
+    /// via `ogr2ogr`, reprojecting to geo_core's EPSG on the way out.
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("road");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.epsg)
+    }
 }
@@ -0,0 +1,334 @@
+use geojson::{Feature, FeatureCollection, GeoJson, Value as GeoValue};
+
+/// A single GeoJSON structural-invariant violation found by [`validate`], keyed by the index of
+/// the offending feature in the source `FeatureCollection` (always `0` for a bare `Feature`/
+/// `Geometry` document).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    pub feature_index: usize,
+    pub reason: String,
+}
+
+/// Every issue [`validate`] found in a document, in feature order.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Validate every feature's geometry in `geojson` against GeoJSON's structural invariants --
+/// Points need >= 2 finite coordinates, LineStrings >= 2 positions, Polygon/MultiPolygon rings
+/// >= 4 positions with matching first/last and non-empty coordinate arrays, and
+/// GeometryCollections must be non-empty -- so malformed input from `add_building_gdf` and
+/// friends is caught at the Python/Rust boundary instead of surfacing as a confusing error mid-
+/// processing.
+///
+/// Returns the document alongside a [`ValidationReport`] listing every violation found. When
+/// `repair` is true, the returned document additionally has unclosed rings auto-closed,
+/// zero-area rings dropped, and duplicate consecutive vertices removed -- but the report still
+/// reflects the issues found in the *original* geometry, before repair.
+pub fn validate(geojson: &GeoJson, repair: bool) -> (GeoJson, ValidationReport) {
+    match geojson {
+        GeoJson::FeatureCollection(fc) => {
+            let mut issues = Vec::new();
+            let features = fc
+                .features
+                .iter()
+                .enumerate()
+                .map(|(index, feature)| {
+                    let mut feature = feature.clone();
+                    validate_and_repair_feature(index, &mut feature, repair, &mut issues);
+                    feature
+                })
+                .collect();
+            (
+                GeoJson::from(FeatureCollection {
+                    bbox: fc.bbox.clone(),
+                    foreign_members: fc.foreign_members.clone(),
+                    features,
+                }),
+                ValidationReport { issues },
+            )
+        }
+        GeoJson::Feature(feature) => {
+            let mut issues = Vec::new();
+            let mut feature = feature.clone();
+            validate_and_repair_feature(0, &mut feature, repair, &mut issues);
+            (GeoJson::Feature(feature), ValidationReport { issues })
+        }
+        GeoJson::Geometry(geometry) => {
+            let mut issues = Vec::new();
+            let mut geometry = geometry.clone();
+            validate_geometry(0, &geometry.value, &mut issues);
+            if repair {
+                repair_geometry_value(&mut geometry.value);
+            }
+            (GeoJson::Geometry(geometry), ValidationReport { issues })
+        }
+    }
+}
+
+fn validate_and_repair_feature(
+    feature_index: usize,
+    feature: &mut Feature,
+    repair: bool,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if let Some(geometry) = feature.geometry.as_mut() {
+        validate_geometry(feature_index, &geometry.value, issues);
+        if repair {
+            repair_geometry_value(&mut geometry.value);
+        }
+    }
+}
+
+fn validate_geometry(feature_index: usize, value: &GeoValue, issues: &mut Vec<ValidationIssue>) {
+    let mut issue = |reason: String| issues.push(ValidationIssue { feature_index, reason });
+
+    match value {
+        GeoValue::Point(position) => {
+            if !is_finite_position(position) {
+                issue("Point must have >= 2 finite coordinates".to_string());
+            }
+        }
+        GeoValue::MultiPoint(positions) => {
+            if positions.iter().any(|p| !is_finite_position(p)) {
+                issue("MultiPoint position must have >= 2 finite coordinates".to_string());
+            }
+        }
+        GeoValue::LineString(positions) => validate_line(feature_index, positions, issues),
+        GeoValue::MultiLineString(lines) => {
+            for line in lines {
+                validate_line(feature_index, line, issues);
+            }
+        }
+        GeoValue::Polygon(rings) => validate_polygon(feature_index, rings, issues),
+        GeoValue::MultiPolygon(polygons) => {
+            for rings in polygons {
+                validate_polygon(feature_index, rings, issues);
+            }
+        }
+        GeoValue::GeometryCollection(geometries) => {
+            if geometries.is_empty() {
+                issue("GeometryCollection must not be empty".to_string());
+            }
+            for geometry in geometries {
+                validate_geometry(feature_index, &geometry.value, issues);
+            }
+        }
+    }
+}
+
+fn is_finite_position(position: &[f64]) -> bool {
+    position.len() >= 2 && position.iter().all(|c| c.is_finite())
+}
+
+fn validate_line(feature_index: usize, positions: &[Vec<f64>], issues: &mut Vec<ValidationIssue>) {
+    if positions.len() < 2 {
+        issues.push(ValidationIssue {
+            feature_index,
+            reason: format!(
+                "LineString must have >= 2 positions, found {}",
+                positions.len()
+            ),
+        });
+    }
+    if positions.iter().any(|p| !is_finite_position(p)) {
+        issues.push(ValidationIssue {
+            feature_index,
+            reason: "LineString position must have >= 2 finite coordinates".to_string(),
+        });
+    }
+}
+
+fn validate_polygon(
+    feature_index: usize,
+    rings: &[Vec<Vec<f64>>],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for (ring_index, ring) in rings.iter().enumerate() {
+        if ring.is_empty() {
+            issues.push(ValidationIssue {
+                feature_index,
+                reason: format!("Polygon ring {ring_index} has no coordinates"),
+            });
+            continue;
+        }
+        if ring.len() < 4 {
+            issues.push(ValidationIssue {
+                feature_index,
+                reason: format!(
+                    "Polygon ring {ring_index} must have >= 4 positions, found {}",
+                    ring.len()
+                ),
+            });
+        }
+        if ring.first() != ring.last() {
+            issues.push(ValidationIssue {
+                feature_index,
+                reason: format!(
+                    "Polygon ring {ring_index} is not closed (first and last positions differ)"
+                ),
+            });
+        }
+        if ring.iter().any(|p| !is_finite_position(p)) {
+            issues.push(ValidationIssue {
+                feature_index,
+                reason: format!(
+                    "Polygon ring {ring_index} position must have >= 2 finite coordinates"
+                ),
+            });
+        }
+    }
+}
+
+fn repair_geometry_value(value: &mut GeoValue) {
+    match value {
+        GeoValue::LineString(positions) => positions.dedup(),
+        GeoValue::MultiLineString(lines) => {
+            for line in lines {
+                line.dedup();
+            }
+        }
+        GeoValue::Polygon(rings) => repair_polygon(rings),
+        GeoValue::MultiPolygon(polygons) => {
+            for rings in polygons {
+                repair_polygon(rings);
+            }
+        }
+        GeoValue::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                repair_geometry_value(&mut geometry.value);
+            }
+        }
+        GeoValue::Point(_) | GeoValue::MultiPoint(_) => {}
+    }
+}
+
+/// Dedup consecutive vertices, auto-close an unclosed ring, then drop the ring entirely if it's
+/// degenerate (too short, or zero-area via the shoelace formula) after those repairs.
+fn repair_polygon(rings: &mut Vec<Vec<Vec<f64>>>) {
+    for ring in rings.iter_mut() {
+        ring.dedup();
+        if let (Some(first), Some(last)) = (ring.first().cloned(), ring.last().cloned()) {
+            if first != last {
+                ring.push(first);
+            }
+        }
+    }
+    rings.retain(|ring| !is_degenerate_ring(ring));
+}
+
+fn is_degenerate_ring(ring: &[Vec<f64>]) -> bool {
+    if ring.len() < 4 {
+        return true;
+    }
+    let area: f64 = ring
+        .windows(2)
+        .map(|w| w[0][0] * w[1][1] - w[1][0] * w[0][1])
+        .sum::<f64>()
+        / 2.0;
+    area.abs() < f64::EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geojson::Geometry;
+
+    fn feature_with_geometry(value: GeoValue) -> Feature {
+        Feature {
+            bbox: None,
+            geometry: Some(Geometry::new(value)),
+            id: None,
+            properties: None,
+            foreign_members: None,
+        }
+    }
+
+    fn collection(features: Vec<Feature>) -> GeoJson {
+        GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        })
+    }
+
+    #[test]
+    fn test_valid_polygon_has_no_issues() {
+        let ring = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ];
+        let geojson = collection(vec![feature_with_geometry(GeoValue::Polygon(vec![ring]))]);
+        let (_, report) = validate(&geojson, false);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_unclosed_ring_is_flagged() {
+        let ring = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 1.0]];
+        let geojson = collection(vec![feature_with_geometry(GeoValue::Polygon(vec![ring]))]);
+        let (_, report) = validate(&geojson, false);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].feature_index, 0);
+        assert!(report.issues[0].reason.contains("not closed"));
+    }
+
+    #[test]
+    fn test_repair_closes_ring_and_drops_zero_area_ring() {
+        let unclosed = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 1.0]];
+        let zero_area = vec![
+            vec![2.0, 2.0],
+            vec![2.0, 2.0],
+            vec![2.0, 2.0],
+            vec![2.0, 2.0],
+        ];
+        let geojson = collection(vec![
+            feature_with_geometry(GeoValue::Polygon(vec![unclosed])),
+            feature_with_geometry(GeoValue::Polygon(vec![zero_area])),
+        ]);
+        let (repaired, _) = validate(&geojson, true);
+        let GeoJson::FeatureCollection(fc) = repaired else {
+            panic!("expected a FeatureCollection");
+        };
+
+        let GeoValue::Polygon(rings) = &fc.features[0].geometry.as_ref().unwrap().value else {
+            panic!("expected a Polygon");
+        };
+        assert_eq!(rings[0].first(), rings[0].last());
+
+        let GeoValue::Polygon(rings) = &fc.features[1].geometry.as_ref().unwrap().value else {
+            panic!("expected a Polygon");
+        };
+        assert!(rings.is_empty());
+    }
+
+    #[test]
+    fn test_non_finite_point_is_flagged() {
+        let geojson = collection(vec![feature_with_geometry(GeoValue::Point(vec![
+            f64::NAN,
+            0.0,
+        ]))]);
+        let (_, report) = validate(&geojson, false);
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.issues[0].reason.contains("finite"));
+    }
+
+    #[test]
+    fn test_empty_geometry_collection_is_flagged() {
+        let geojson = collection(vec![feature_with_geometry(GeoValue::GeometryCollection(
+            vec![],
+        ))]);
+        let (_, report) = validate(&geojson, false);
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.issues[0].reason.contains("GeometryCollection"));
+    }
+}
@@ -3,11 +3,224 @@ use gdal::raster::Buffer;
 use gdal::Dataset;
 use geojson::GeoJson;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::collect::ign::ign_collect::IgnCollect;
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::gdal_error::with_gdal_error_context;
+
+/// Default `VSIS3_CHUNK_SIZE` (MB) GDAL uses when multipart-uploading to an `s3://`/`/vsis3/`
+/// destination. See [`Vegetation::set_vsi_chunk_size_mb`].
+const DEFAULT_VSI_CHUNK_SIZE_MB: u32 = 64;
+
+/// Mapbox Vector Tile coordinate extent used by [`Vegetation::to_pmtiles`], mirroring
+/// [`crate::geometric::lcz::Lcz`]'s own `MVT_EXTENT`: each tile's local coordinate space runs
+/// from `0` to `MVT_EXTENT` on both axes, per the `vector_tile.proto` convention.
+const MVT_EXTENT: u32 = 4096;
+
+/// Rewrite an `s3://bucket/key` URL into the GDAL virtual filesystem path `/vsis3/bucket/key`
+/// that GDAL's I/O layer actually understands. `/vsis3/`, `/vsicurl/` and plain local paths are
+/// returned unchanged -- GDAL already understands the former two, and the latter needs no
+/// rewriting.
+fn normalize_vsi_path(path: &str) -> String {
+    match path.strip_prefix("s3://") {
+        Some(rest) => format!("/vsis3/{}", rest),
+        None => path.to_string(),
+    }
+}
+
+/// Convert an OGR field value into the closest serde_json representation, used when
+/// importing shapefile attributes natively (without round-tripping through ogr2ogr).
+fn ogr_field_to_json(value: &gdal::vector::FieldValue) -> serde_json::Value {
+    use gdal::vector::FieldValue;
+    use serde_json::Value as JsonValue;
+    match value {
+        FieldValue::IntegerValue(v) => JsonValue::from(*v),
+        FieldValue::Integer64Value(v) => JsonValue::from(*v),
+        FieldValue::RealValue(v) => JsonValue::from(*v),
+        FieldValue::StringValue(v) => JsonValue::from(v.clone()),
+        FieldValue::IntegerListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::Integer64ListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::RealListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::StringListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::DateValue(v) => JsonValue::from(v.to_string()),
+        FieldValue::DateTimeValue(v) => JsonValue::from(v.to_string()),
+    }
+}
+
+/// Vegetation index formula selectable via `Vegetation::new`. Each variant is computed from
+/// the source IRC raster's bands (band 1 = NIR, band 2 = Red, band 3 = Green where needed).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VegetationIndex {
+    /// (NIR - Red) / (NIR + Red)
+    Ndvi,
+    /// Soil-Adjusted Vegetation Index: (NIR - Red) / (NIR + Red + L) * (1 + L). `l` ranges from
+    /// 0 (no soil adjustment, equivalent to NDVI) to 1 (low vegetation cover).
+    Savi { l: f64 },
+    /// Green NDVI: (NIR - Green) / (NIR + Green). Needs a third (Green) band.
+    Gndvi,
+    /// Raw band ratio: NIR / Red, with no normalization.
+    BandRatio,
+}
+
+impl Default for VegetationIndex {
+    fn default() -> Self {
+        VegetationIndex::Ndvi
+    }
+}
+
+impl VegetationIndex {
+    /// Number of raster bands this formula reads from the source image, in the order
+    /// `compute` expects them (NIR, Red[, Green]).
+    fn required_bands(self) -> usize {
+        match self {
+            VegetationIndex::Gndvi => 3,
+            _ => 2,
+        }
+    }
+
+    /// Evaluate the index for one pixel given its band values in `required_bands` order.
+    /// Returns `f64::NAN` where the formula is undefined (e.g. a zero denominator), which the
+    /// reclassification step treats as failing every threshold test.
+    fn compute(self, bands: &[f64]) -> f64 {
+        match self {
+            VegetationIndex::Ndvi => {
+                let (nir, red) = (bands[0], bands[1]);
+                if nir + red != 0.0 {
+                    (nir - red) / (nir + red)
+                } else {
+                    f64::NAN
+                }
+            }
+            VegetationIndex::Savi { l } => {
+                let (nir, red) = (bands[0], bands[1]);
+                let denom = nir + red + l;
+                if denom != 0.0 {
+                    (nir - red) / denom * (1.0 + l)
+                } else {
+                    f64::NAN
+                }
+            }
+            VegetationIndex::Gndvi => {
+                let (nir, green) = (bands[0], bands[2]);
+                if nir + green != 0.0 {
+                    (nir - green) / (nir + green)
+                } else {
+                    f64::NAN
+                }
+            }
+            VegetationIndex::BandRatio => {
+                let (nir, red) = (bands[0], bands[1]);
+                if red != 0.0 {
+                    nir / red
+                } else {
+                    f64::NAN
+                }
+            }
+        }
+    }
+}
+
+/// Comparison operator for [`ReclassifyConfig::operator`], matching the common GIS
+/// `reclassify_raster(operator, threshold, pixel_value)` pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReclassifyOperator {
+    GreaterThan,
+    LessThan,
+    GreaterThanOrEqual,
+    LessThanOrEqual,
+}
+
+impl ReclassifyOperator {
+    fn test(self, value: f64, threshold: f64) -> bool {
+        match self {
+            ReclassifyOperator::GreaterThan => value > threshold,
+            ReclassifyOperator::LessThan => value < threshold,
+            ReclassifyOperator::GreaterThanOrEqual => value >= threshold,
+            ReclassifyOperator::LessThanOrEqual => value <= threshold,
+        }
+    }
+}
+
+/// Configures how a continuous vegetation-index raster is thresholded into a binary mask
+/// before polygonizing: pixels for which `operator(value, threshold)` holds become
+/// `pixel_value`, the rest become `nodata_value`. Lets callers tune what counts as
+/// "vegetation" per imagery source instead of a fixed cutoff.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReclassifyConfig {
+    pub operator: ReclassifyOperator,
+    pub threshold: f64,
+    pub pixel_value: f64,
+    pub nodata_value: f64,
+}
+
+impl Default for ReclassifyConfig {
+    /// Matches this crate's original fixed behavior: NDVI >= 0.2 is vegetation.
+    fn default() -> Self {
+        ReclassifyConfig {
+            operator: ReclassifyOperator::GreaterThanOrEqual,
+            threshold: 0.2,
+            pixel_value: 1.0,
+            nodata_value: -999.0,
+        }
+    }
+}
+
+impl ReclassifyConfig {
+    fn apply(&self, value: f64) -> f64 {
+        if self.operator.test(value, self.threshold) {
+            self.pixel_value
+        } else {
+            self.nodata_value
+        }
+    }
+}
+
+/// Configures the inverse-distance-weighted nodata gap-fill pass run on the reclassified
+/// raster before polygonizing (see [`Vegetation::fill_nodata`]), modeled on rasterio's
+/// `fillnodata`. Not set by default, matching this crate's original behavior of polygonizing
+/// the reclassified raster as-is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GapFillConfig {
+    /// Maximum pixel distance to search outward for a valid neighbor in each direction;
+    /// a nodata pixel with no valid neighbor within this distance on any searched direction
+    /// stays nodata.
+    pub max_search_distance: usize,
+    /// Number of 3x3 smoothing passes run after the fill, each averaging originally-nodata
+    /// pixels over their filled neighborhood to blend seams between fill regions.
+    pub smoothing_iterations: usize,
+    /// Search all 8 cardinal+diagonal directions instead of just the 4 cardinal ones.
+    pub eight_directions: bool,
+}
+
+impl Default for GapFillConfig {
+    fn default() -> Self {
+        GapFillConfig {
+            max_search_distance: 100,
+            smoothing_iterations: 0,
+            eight_directions: false,
+        }
+    }
+}
+
+/// One polygon feature indexed for tiling in [`Vegetation::to_pmtiles`], mirroring
+/// [`crate::geometric::lcz::Lcz`]'s own `IndexedGeometry`/`RTree` setup.
+struct VegetationTileGeometry {
+    geom: geo::Geometry<f64>,
+    id: u64,
+}
+
+impl rstar::RTreeObject for VegetationTileGeometry {
+    type Envelope = rstar::AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        use geo::algorithm::bounding_rect::BoundingRect;
+        match self.geom.bounding_rect() {
+            Some(rect) => rstar::AABB::from_corners([rect.min().x, rect.min().y], [rect.max().x, rect.max().y]),
+            None => rstar::AABB::from_corners([0.0, 0.0], [0.0, 0.0]),
+        }
+    }
+}
 
 /// Vegetation structure
 /// Following Python implementation from pymdu.geometric.Vegetation
@@ -37,17 +250,42 @@ pub struct Vegetation {
     ndvi_shp_path: PathBuf,
     /// Path to NDVI GeoTIFF
     ndvi_tif_path: PathBuf,
+    /// EPSG of the geometries currently in `geojson`, read off the source raster/shapefile's own
+    /// spatial reference once `calculate_ndvi_from_irc`/`load_from_shapefile` has run. Drives the
+    /// `run_internal` reprojection step to `geo_core`'s target EPSG.
+    source_epsg: Option<i32>,
+    /// Vegetation index formula used by `calculate_ndvi_from_irc`
+    index: VegetationIndex,
+    /// Reclassification thresholding applied to the computed index raster before polygonizing
+    reclassify: ReclassifyConfig,
+    /// Nodata gap-fill pass run on the reclassified raster before polygonizing, if enabled
+    gap_fill: Option<GapFillConfig>,
+    /// When set, [`Vegetation::run_internal`] calls [`Vegetation::dissolve`] after filtering,
+    /// merging the many small per-cluster polygons into one MultiPolygon.
+    dissolve: bool,
+    /// `VSIS3_CHUNK_SIZE` (MB) GDAL uses when uploading to an `s3://`/`/vsis3/` output path.
+    /// See [`Vegetation::set_vsi_chunk_size_mb`].
+    vsi_chunk_size_mb: u32,
 }
 
 impl Vegetation {
     /// Create a new Vegetation instance
     /// Following Python: def __init__(self, filepath_shp=None, output_path=None, set_crs=None, write_file=False, min_area=0)
+    /// `index` defaults to [`VegetationIndex::Ndvi`] and `reclassify` to the crate's original
+    /// NDVI >= 0.2 cutoff when `None`. `gap_fill` is disabled (`None`) by default. When
+    /// `dissolve` is `true`, `run_internal` merges the filtered polygons into a single
+    /// MultiPolygon via [`Vegetation::dissolve`] instead of leaving thousands of small slivers.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         filepath_shp: Option<String>,
         output_path: Option<String>,
         set_crs: Option<i32>,
         write_file: bool,
         min_area: f64,
+        index: Option<VegetationIndex>,
+        reclassify: Option<ReclassifyConfig>,
+        gap_fill: Option<GapFillConfig>,
+        dissolve: bool,
     ) -> Result<Self> {
         use crate::collect::global_variables::TEMP_PATH;
 
@@ -86,6 +324,12 @@ impl Vegetation {
             img_tiff_path,
             ndvi_shp_path,
             ndvi_tif_path,
+            source_epsg: None,
+            index: index.unwrap_or_default(),
+            reclassify: reclassify.unwrap_or_default(),
+            gap_fill,
+            dissolve,
+            vsi_chunk_size_mb: DEFAULT_VSI_CHUNK_SIZE_MB,
         };
 
         // Initialize IgnCollect if no shapefile provided (will be used for IGN API)
@@ -115,6 +359,14 @@ impl Vegetation {
         self.set_crs = Some(epsg);
     }
 
+    /// Set the `VSIS3_CHUNK_SIZE` (MB) GDAL uses for multipart uploads when `output_path` points
+    /// at an `s3://`/`/vsis3/` destination (applied by [`Vegetation::to_geojson`]; reading an
+    /// `s3://` `filepath_shp` does not use it). Larger chunks mean fewer PUT requests for a big
+    /// GeoJSON export, at the cost of more memory buffered per chunk.
+    pub fn set_vsi_chunk_size_mb(&mut self, chunk_size_mb: u32) {
+        self.vsi_chunk_size_mb = chunk_size_mb;
+    }
+
     /// Run vegetation processing: calculate NDVI from IRC or load from shapefile
     /// Following Python: def run(self) -> self
     pub fn run(mut self) -> Result<Self> {
@@ -142,20 +394,144 @@ impl Vegetation {
         //         self.gdf = self.gdf.set_crs(crs=self.set_crs, inplace=True, allow_override=True)
         // else:
         //     self.gdf.crs = self._epsg
-        // Note: CRS transformation would require GDAL reprojection
-        // For now, we store the GeoJSON as-is
-        // TODO: Implement CRS transformation using GDAL or proj crate
+        // `calculate_ndvi_from_irc`/`load_from_shapefile` leave `geojson` in whatever CRS the
+        // source raster/shapefile was natively in (recorded in `source_epsg`); reproject it to
+        // `geo_core`'s target EPSG the same way `Rnb::run_internal` reprojects from the RNB API's
+        // fixed EPSG:4326.
+        let target_epsg = self.geo_core.epsg;
+        if let (Some(source_epsg), Some(geojson)) = (self.source_epsg, self.geojson.as_mut()) {
+            if source_epsg != target_epsg {
+                GeoCore::reproject_geojson(geojson, source_epsg, target_epsg)
+                    .context("Failed to reproject vegetation polygons to the target CRS")?;
+            }
+        }
+
+        if self.dissolve {
+            self.dissolve()?;
+        }
+
+        Ok(())
+    }
+
+    /// Merge every polygon in `self.geojson` into a single MultiPolygon feature, replacing the
+    /// many small per-cluster slivers `filter_vegetation_polygons` produces with one consolidated
+    /// geometry. Follows the same GEOS union approach as
+    /// [`crate::geometric::land_cover::LandCover`]'s DXF/COSIA merge: each part is cleaned with a
+    /// zero-width buffer before and after the running union to dissolve away self-intersections
+    /// introduced by adjacent polygons sharing an edge.
+    pub fn dissolve(&mut self) -> Result<()> {
+        use geo::{Area, Geometry as GeoGeometry, MultiPolygon};
+        use geos::{Geom, Geometry as GeosGeometry};
+
+        let Some(geojson) = self.geojson.as_ref() else {
+            return Ok(());
+        };
+
+        let features = match geojson {
+            GeoJson::FeatureCollection(fc) => fc.features.clone(),
+            GeoJson::Feature(f) => vec![f.clone()],
+            GeoJson::Geometry(_) => return Ok(()),
+        };
+
+        let mut accumulated: Option<GeosGeometry> = None;
+        for feature in &features {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let geo_geom: GeoGeometry<f64> = match geometry.try_into() {
+                Ok(geom) => geom,
+                Err(_) => continue,
+            };
+            if !matches!(
+                geo_geom,
+                GeoGeometry::Polygon(_) | GeoGeometry::MultiPolygon(_)
+            ) {
+                continue;
+            }
+            let geos_geom: GeosGeometry = geo_geom
+                .try_into()
+                .context("Failed to convert vegetation polygon to GEOS")?;
+            let cleaned = geos_geom
+                .buffer(0.0, 8)
+                .context("Failed to clean vegetation polygon self-intersections")?;
+
+            accumulated = Some(match accumulated {
+                Some(acc) => acc
+                    .union(&cleaned)
+                    .context("Failed to union vegetation polygons")?,
+                None => cleaned,
+            });
+        }
+
+        let Some(accumulated) = accumulated else {
+            return Ok(());
+        };
+        let dissolved = accumulated
+            .buffer(0.0, 8)
+            .context("Failed to clean dissolved vegetation multipolygon")?;
+
+        let geo_geom: GeoGeometry<f64> = dissolved
+            .try_into()
+            .context("Failed to convert dissolved GEOS geometry back to geo::Geometry")?;
+        let multi_polygon: MultiPolygon<f64> = match geo_geom {
+            GeoGeometry::Polygon(p) => MultiPolygon(vec![p]),
+            GeoGeometry::MultiPolygon(mp) => mp,
+            _ => return Ok(()),
+        };
+        if multi_polygon.unsigned_area() == 0.0 {
+            return Ok(());
+        }
+
+        let value = geojson::Value::from(&GeoGeometry::MultiPolygon(multi_polygon));
+        let mut feature = geojson::Feature::from(geojson::Geometry::new(value));
+        feature.properties = Some(serde_json::Map::new());
+
+        let dissolved_fc = geojson::FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: vec![feature],
+        };
+        self.geojson = Some(GeoJson::from(dissolved_fc));
 
         Ok(())
     }
 
-    /// Calculate NDVI from IRC image and polygonize
-    /// Following Python implementation:
+    /// Reproject the stored GeoJSON from `from_epsg` to `to_epsg`, regardless of what EPSG
+    /// `geo_core` currently thinks it's in. Mirrors
+    /// [`crate::geometric::rnb::Rnb::reproject_to`].
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            GeoCore::reproject_geojson(geojson, from_epsg, to_epsg)?;
+        }
+        self.geo_core.set_epsg(to_epsg);
+        Ok(())
+    }
+
+    /// Reproject the stored GeoJSON from `geo_core`'s current EPSG to `to_epsg`, updating
+    /// `geo_core.epsg` on success. Unlike [`Vegetation::reproject_to`], this goes through
+    /// `GeoCore::reproject`'s cached `Proj` pipeline, so calling it repeatedly doesn't rebuild
+    /// the transformation each time.
+    pub fn reproject(&mut self, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            self.geo_core.reproject(geojson, to_epsg)?;
+        } else {
+            self.geo_core.set_epsg(to_epsg);
+        }
+        Ok(())
+    }
+
+    /// Reproject back to EPSG:4326 (WGS84 lat/long), e.g. before exporting to a format that
+    /// expects geographic coordinates.
+    pub fn to_latlong(&mut self) -> Result<()> {
+        self.reproject(4326)
+    }
+
+    /// Calculate the configured vegetation index from the IRC image and polygonize it
     /// 1. Download IRC image from IGN
-    /// 2. Calculate NDVI = (NIR - Red) / (NIR + Red)
-    /// 3. Filter pixels with NDVI < 0.2 (set to -999)
-    /// 4. Polygonize the raster
-    /// 5. Filter polygons with NDVI == 0 and area > min_area
+    /// 2. Calculate `self.index` (NDVI by default: (NIR - Red) / (NIR + Red))
+    /// 3. Reclassify pixels per `self.reclassify` (by default: NDVI >= 0.2 -> 1.0, else -999)
+    /// 4. Polygonize the reclassified raster
+    /// 5. Filter polygons matching `self.reclassify.pixel_value` and area > min_area
     fn calculate_ndvi_from_irc(&mut self) -> Result<()> {
         // Step 1: Download IRC image from IGN
         let mut ign_collect = self
@@ -176,55 +552,68 @@ impl Vegetation {
             );
         }
 
-        // Step 2: Read IRC image and calculate NDVI
+        // Step 2: Read IRC image and calculate the configured vegetation index
         let dataset = Dataset::open(&self.img_tiff_path).context("Failed to open IRC image")?;
 
+        // The polygonized output inherits this raster's geotransform/spatial reference
+        // (see `write_ndvi_raster`), so its EPSG is the source CRS `run_internal` reprojects from.
+        self.source_epsg = dataset.spatial_ref().ok().and_then(|srs| srs.to_epsg().ok());
+
         let (width, height) = dataset.raster_size();
         let raster_count = dataset.raster_count();
+        let required_bands = self.index.required_bands();
 
-        if raster_count < 2 {
-            anyhow::bail!("IRC image must have at least 2 bands (NIR and Red)");
+        if (raster_count as usize) < required_bands {
+            anyhow::bail!(
+                "IRC image must have at least {} band(s) for {:?}, found {}",
+                required_bands,
+                self.index,
+                raster_count
+            );
         }
 
-        // Read bands
-        // Band 0: NIR (Near Infrared)
-        // Band 1: Red
-        let nir_band = dataset.rasterband(1).context("Failed to get NIR band")?;
-        let red_band = dataset.rasterband(2).context("Failed to get Red band")?;
-
-        // Read raster data
-        let nir_buffer = nir_band
-            .read_as::<f64>((0, 0), (width, height), (width, height), None)
-            .context("Failed to read NIR band")?;
-        let red_buffer = red_band
-            .read_as::<f64>((0, 0), (width, height), (width, height), None)
-            .context("Failed to read Red band")?;
-
-        // Step 3: Calculate NDVI = (NIR - Red) / (NIR + Red)
-        // Python: ndvi = (bandNIR.astype(float) - bandRed.astype(float)) / (bandNIR.astype(float) + bandRed.astype(float))
-        let mut ndvi_data = Vec::with_capacity(width * height);
+        // Band order: 1 = NIR, 2 = Red, 3 = Green (only read what `self.index` needs)
+        let band_buffers: Vec<Buffer<f64>> = (1..=required_bands)
+            .map(|band_index| {
+                dataset
+                    .rasterband(band_index as isize)
+                    .with_context(|| format!("Failed to get band {}", band_index))?
+                    .read_as::<f64>((0, 0), (width, height), (width, height), None)
+                    .with_context(|| format!("Failed to read band {}", band_index))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Step 3: Compute the index per pixel, then reclassify into a vegetation/non-vegetation mask
+        let mut band_values = vec![0.0; required_bands];
+        let mut index_data = Vec::with_capacity(width * height);
         for i in 0..(width * height) {
-            let nir = nir_buffer.data[i];
-            let red = red_buffer.data[i];
-            let ndvi = if (nir + red) != 0.0 {
-                (nir - red) / (nir + red)
-            } else {
-                -999.0 // No data
-            };
-            // Filter: set to -999 if NDVI < 0.2
-            // Python: filter_raster.append([-999 if y < 0.2 else y for y in x])
-            let filtered_ndvi = if ndvi < 0.2 { -999.0 } else { ndvi };
-            ndvi_data.push(filtered_ndvi);
+            for (band, buffer) in band_values.iter_mut().zip(&band_buffers) {
+                *band = buffer.data[i];
+            }
+            let index_value = self.index.compute(&band_values);
+            index_data.push(self.reclassify.apply(index_value));
         }
 
-        // Step 4: Write NDVI raster to file
-        self.write_ndvi_raster(&ndvi_data, width, height, &dataset)?;
+        // Step 3b: Optionally fill nodata gaps before writing/polygonizing, so small holes
+        // don't fragment the vegetation polygons
+        if let Some(gap_fill) = &self.gap_fill {
+            Self::fill_nodata(
+                &mut index_data,
+                width,
+                height,
+                self.reclassify.nodata_value,
+                gap_fill,
+            );
+        }
+
+        // Step 4: Write the reclassified raster to file
+        self.write_ndvi_raster(&index_data, width, height, &dataset)?;
 
-        // Step 5: Polygonize the NDVI raster
-        self.polygonize_ndvi()?;
+        // Step 5: Polygonize the reclassified raster
+        let features = self.polygonize_ndvi()?;
 
-        // Step 6: Filter polygons with NDVI == 0 and area > min_area
-        self.filter_vegetation_polygons()?;
+        // Step 6: Filter polygons matching the reclassified vegetation value and area > min_area
+        self.filter_vegetation_polygons(features)?;
 
         Ok(())
     }
@@ -242,14 +631,17 @@ impl Vegetation {
             .context("Failed to get GTiff driver")?;
 
         // Create output dataset
-        let mut output_dataset = driver
-            .create_with_band_type::<f64, _>(
-                &self.ndvi_tif_path,
-                width as isize,
-                height as isize,
-                1, // Single band for NDVI
-            )
-            .context("Failed to create NDVI GeoTIFF dataset")?;
+        let mut output_dataset =
+            with_gdal_error_context("Failed to create NDVI GeoTIFF dataset", || {
+                driver
+                    .create_with_band_type::<f64, _>(
+                        &self.ndvi_tif_path,
+                        width as isize,
+                        height as isize,
+                        1, // Single band for NDVI
+                    )
+                    .map_err(Into::into)
+            })?;
 
         // Copy geotransform from source
         let geo_transform = source_dataset.geo_transform()?;
@@ -270,22 +662,182 @@ impl Vegetation {
             .context("Failed to get output band")?;
 
         let buffer = Buffer::new((width, height), ndvi_data.to_vec());
-        band.write((0, 0), (width, height), &buffer)
-            .context("Failed to write NDVI band")?;
-        band.set_no_data_value(Some(-999.0))
+        with_gdal_error_context("Failed to write NDVI band", || {
+            band.write((0, 0), (width, height), &buffer)
+                .map_err(Into::into)
+        })?;
+        band.set_no_data_value(Some(self.reclassify.nodata_value))
             .context("Failed to set no data value")?;
 
         Ok(())
     }
 
-    /// Polygonize NDVI raster to shapefile
+    /// Fill nodata gaps in `data` in-place via inverse-distance-weighted interpolation,
+    /// modeled on rasterio's `fillnodata`. For each nodata pixel, search outward along the 4
+    /// (or 8, per `config.eight_directions`) cardinal directions for the first valid pixel in
+    /// each, then set the filled value to those neighbors' inverse-distance-weighted average
+    /// (weight = 1/distance). Pixels with no valid neighbor within `config.max_search_distance`
+    /// on any searched direction are left as nodata. Runs `config.smoothing_iterations` 3x3
+    /// smoothing passes afterward, each re-averaging only the originally-nodata pixels over
+    /// their (now filled) neighborhood, to blend seams between fill regions.
+    fn fill_nodata(
+        data: &mut [f64],
+        width: usize,
+        height: usize,
+        nodata: f64,
+        config: &GapFillConfig,
+    ) {
+        const CARDINAL_DIRECTIONS: [(isize, isize); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+        const EIGHT_DIRECTIONS: [(isize, isize); 8] = [
+            (0, 1),
+            (0, -1),
+            (1, 0),
+            (-1, 0),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ];
+        let directions: &[(isize, isize)] = if config.eight_directions {
+            &EIGHT_DIRECTIONS
+        } else {
+            &CARDINAL_DIRECTIONS
+        };
+
+        let was_nodata: Vec<bool> = data.iter().map(|&v| v == nodata).collect();
+        let mut filled = data.to_vec();
+
+        for row in 0..height {
+            for col in 0..width {
+                let idx = row * width + col;
+                if !was_nodata[idx] {
+                    continue;
+                }
+
+                let mut weight_sum = 0.0;
+                let mut value_sum = 0.0;
+                for &(dr, dc) in directions {
+                    let (mut r, mut c) = (row as isize, col as isize);
+                    for distance in 1..=config.max_search_distance {
+                        r += dr;
+                        c += dc;
+                        if r < 0 || c < 0 || r as usize >= height || c as usize >= width {
+                            break;
+                        }
+                        let neighbor_idx = r as usize * width + c as usize;
+                        if !was_nodata[neighbor_idx] {
+                            let weight = 1.0 / distance as f64;
+                            weight_sum += weight;
+                            value_sum += weight * data[neighbor_idx];
+                            break;
+                        }
+                    }
+                }
+
+                if weight_sum > 0.0 {
+                    filled[idx] = value_sum / weight_sum;
+                }
+            }
+        }
+
+        for _ in 0..config.smoothing_iterations {
+            let snapshot = filled.clone();
+            for row in 0..height {
+                for col in 0..width {
+                    let idx = row * width + col;
+                    if !was_nodata[idx] {
+                        continue;
+                    }
+
+                    let mut sum = 0.0;
+                    let mut count = 0usize;
+                    for dr in -1..=1isize {
+                        for dc in -1..=1isize {
+                            let (r, c) = (row as isize + dr, col as isize + dc);
+                            if r < 0 || c < 0 || r as usize >= height || c as usize >= width {
+                                continue;
+                            }
+                            sum += snapshot[r as usize * width + c as usize];
+                            count += 1;
+                        }
+                    }
+                    if count > 0 {
+                        filled[idx] = sum / count as f64;
+                    }
+                }
+            }
+        }
+
+        data.copy_from_slice(&filled);
+    }
+
+    /// Polygonize the NDVI raster into GeoJSON features in a single in-process pass.
     /// Following Python: gdal.Polygonize(srcband, mask, layer, 0, ...)
-    /// Uses gdal_polygonize command-line tool
-    fn polygonize_ndvi(&self) -> Result<()> {
-        // Remove existing shapefile if it exists
-        // Python: if os.path.exists(self.ndvi_shp_path): driver.DeleteDataSource(self.ndvi_shp_path)
+    /// Runs `GDALPolygonize` straight into an in-memory "Memory"-driver OGR layer (no
+    /// shapefile round-trip), with each resulting polygon's field carrying its source
+    /// pixel value under the name "NDVI". Falls back to shelling into `gdal_polygonize`
+    /// against a real shapefile when the native call fails (e.g. an older GDAL build).
+    fn polygonize_ndvi(&self) -> Result<Vec<geojson::Feature>> {
+        match self.polygonize_ndvi_native() {
+            Ok(features) => Ok(features),
+            Err(e) => {
+                eprintln!(
+                    "Warning: native NDVI polygonize failed ({}), falling back to gdal_polygonize",
+                    e
+                );
+                self.polygonize_ndvi_gdal_polygonize()
+            }
+        }
+    }
+
+    /// Native path for [`Vegetation::polygonize_ndvi`]: polygonize band 1 of the NDVI
+    /// GeoTIFF directly into a "Memory"-driver layer and read the resulting features
+    /// back out without ever touching disk.
+    fn polygonize_ndvi_native(&self) -> Result<Vec<geojson::Feature>> {
+        use gdal::vector::{FieldDefn, LayerAccess, LayerOptions, OGRFieldType, OGRwkbGeometryType};
+
+        let dataset = with_gdal_error_context("Failed to open NDVI GeoTIFF", || {
+            Dataset::open(&self.ndvi_tif_path).map_err(Into::into)
+        })?;
+        let band = dataset.rasterband(1).context("Failed to get NDVI band")?;
+
+        let mem_driver = gdal::DriverManager::get_driver_by_name("Memory")
+            .context("Failed to get Memory driver")?;
+        let mut mem_dataset = mem_driver
+            .create_vector_only("")
+            .context("Failed to create in-memory vector dataset")?;
+        let mut layer = mem_dataset
+            .create_layer(LayerOptions {
+                name: "output",
+                srs: dataset.spatial_ref().ok().as_ref(),
+                ty: OGRwkbGeometryType::wkbPolygon,
+                options: None,
+            })
+            .context("Failed to create in-memory polygonize layer")?;
+        let ndvi_field = FieldDefn::new("NDVI", OGRFieldType::OFTReal)
+            .context("Failed to create NDVI field definition")?;
+        ndvi_field
+            .add_to_layer(&layer)
+            .context("Failed to add NDVI field to layer")?;
+
+        with_gdal_error_context("GDAL polygonize failed", || {
+            band.polygonize(None, &mut layer, 0, &[], |_, _| true)
+                .map_err(Into::into)
+        })?;
+
+        Self::features_from_layer(&mut layer, "NDVI")
+    }
+
+    /// Legacy fallback for [`Vegetation::polygonize_ndvi`]: shell into `gdal_polygonize`
+    /// to write a real shapefile, then read it back the same way
+    /// [`Vegetation::load_from_shapefile_native`] reads an input shapefile. Kept for
+    /// environments where the in-process polygonize call errors but `gdal_polygonize`
+    /// is still on PATH.
+    fn polygonize_ndvi_gdal_polygonize(&self) -> Result<Vec<geojson::Feature>> {
+        use gdal::vector::LayerAccess;
+        use std::process::Command;
+
         if self.ndvi_shp_path.exists() {
-            // Remove all shapefile components
             let base_path = self.ndvi_shp_path.with_extension("");
             for ext in &[".shp", ".shx", ".dbf", ".prj"] {
                 let file_path = base_path.with_extension(ext);
@@ -295,7 +847,6 @@ impl Vegetation {
             }
         }
 
-        // Use gdal_polygonize to convert raster to vector
         // gdal_polygonize input.tif -f "ESRI Shapefile" output.shp output NDVI
         let status = Command::new("gdal_polygonize")
             .arg(&self.ndvi_tif_path)
@@ -313,121 +864,127 @@ impl Vegetation {
             anyhow::bail!("gdal_polygonize failed to polygonize NDVI raster");
         }
 
-        Ok(())
+        let dataset = Dataset::open(&self.ndvi_shp_path)
+            .context("Failed to open gdal_polygonize output shapefile")?;
+        let mut layer = dataset.layer(0).context("Polygonized shapefile has no layers")?;
+        Self::features_from_layer(&mut layer, "NDVI")
     }
 
-    /// Filter vegetation polygons: NDVI == 0 and area > min_area
-    /// Following Python:
-    /// vegetation = vegetation.loc[(vegetation["NDVI"] == 0)]
-    /// mes_polygons = [x for x in vegetation["geometry"] if x.area > self.min_area]
-    fn filter_vegetation_polygons(&mut self) -> Result<()> {
-        // Convert shapefile to GeoJSON using ogr2ogr
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let temp_geojson = std::env::temp_dir().join(format!("vegetation_{}.geojson", timestamp));
-
-        let status = Command::new("ogr2ogr")
-            .arg("-f")
-            .arg("GeoJSON")
-            .arg(&temp_geojson)
-            .arg(&self.ndvi_shp_path)
-            .status()
-            .context(
-                "Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH",
-            )?;
+    /// Convert every feature in an OGR layer into a `geojson::Feature`, via a WKT/GEOS
+    /// round-trip (mirrors [`Water::load_from_shapefile_native`]), keeping only the
+    /// named double field (e.g. "NDVI") as a property.
+    fn features_from_layer(
+        layer: &mut gdal::vector::Layer,
+        double_field: &str,
+    ) -> Result<Vec<geojson::Feature>> {
+        use gdal::vector::LayerAccess;
+        use geo::Geometry as GeoGeometry;
+        use geojson::Feature;
+        use geos::Geometry as GeosGeometry;
+        use serde_json::Map;
+
+        let mut features = Vec::new();
+        for feature in layer.features() {
+            let Some(geom_ref) = feature.geometry() else {
+                continue;
+            };
+            let wkt = geom_ref.wkt().context("Failed to get WKT from OGR geometry")?;
+            let geos_geom =
+                GeosGeometry::new_from_wkt(&wkt).context("Failed to parse WKT with GEOS")?;
+            let geo_geom: GeoGeometry<f64> = geos_geom
+                .try_into()
+                .context("Failed to convert GEOS geometry to geo")?;
+            let geojson_geom: geojson::Geometry = (&geo_geom)
+                .try_into()
+                .context("Failed to convert geo geometry to GeoJSON geometry")?;
+
+            let mut properties = Map::new();
+            if let Some(value) = feature.field_as_double_by_name(double_field)? {
+                properties.insert(double_field.to_string(), serde_json::Value::from(value));
+            }
 
-        if !status.success() {
-            anyhow::bail!("ogr2ogr failed to convert shapefile to GeoJSON");
+            let mut json_feature = Feature::from(geojson_geom);
+            json_feature.properties = Some(properties);
+            features.push(json_feature);
         }
 
-        // Read GeoJSON
-        let geojson_bytes =
-            std::fs::read(&temp_geojson).context("Failed to read temporary GeoJSON file")?;
-        let _ = std::fs::remove_file(&temp_geojson);
-
-        let geojson_str = String::from_utf8_lossy(&geojson_bytes);
-        let geojson: GeoJson = geojson_str
-            .parse()
-            .context("Failed to parse GeoJSON from shapefile")?;
+        Ok(features)
+    }
 
-        // Filter polygons: NDVI == 0 and area > min_area
-        // Convert to FeatureCollection and filter
-        match geojson {
-            GeoJson::FeatureCollection(fc) => {
-                use geo::{Area, Geometry as GeoGeometry};
-
-                let mut filtered_features = Vec::new();
-
-                for feature in fc.features {
-                    // Check NDVI == 0
-                    if let Some(properties) = &feature.properties {
-                        if let Some(ndvi_value) = properties.get("NDVI") {
-                            let ndvi = if let Some(n) = ndvi_value.as_f64() {
-                                n
-                            } else if let Some(n) = ndvi_value.as_i64() {
-                                n as f64
-                            } else {
-                                continue; // Skip if NDVI is not a number
-                            };
-
-                            // Filter: NDVI == 0
-                            if ndvi != 0.0 {
-                                continue;
-                            }
-                        } else {
-                            continue; // Skip if no NDVI property
-                        }
+    /// Filter vegetation polygons: value == `self.reclassify.pixel_value` and area > min_area
+    /// Following Python:
+    /// vegetation = vegetation.loc[(vegetation["NDVI"] == 0)]
+    /// mes_polygons = [x for x in vegetation["geometry"] if x.area > self.min_area]
+    fn filter_vegetation_polygons(&mut self, features: Vec<geojson::Feature>) -> Result<()> {
+        use geo::{Area, Geometry as GeoGeometry};
+
+        let mut filtered_features = Vec::new();
+
+        for feature in features {
+            // Check value == configured pixel_value
+            if let Some(properties) = &feature.properties {
+                if let Some(ndvi_value) = properties.get("NDVI") {
+                    let ndvi = if let Some(n) = ndvi_value.as_f64() {
+                        n
+                    } else if let Some(n) = ndvi_value.as_i64() {
+                        n as f64
                     } else {
-                        continue; // Skip if no properties
-                    }
-
-                    // Check area > min_area
-                    if let Some(geometry) = &feature.geometry {
-                        let geo_geom: GeoGeometry<f64> = geometry
-                            .try_into()
-                            .context("Failed to convert GeoJSON geometry to geo::Geometry")?;
-
-                        let area = match &geo_geom {
-                            GeoGeometry::Polygon(poly) => poly.unsigned_area(),
-                            GeoGeometry::MultiPolygon(mp) => mp.unsigned_area(),
-                            _ => continue, // Skip non-polygon geometries
-                        };
+                        continue; // Skip if NDVI is not a number
+                    };
 
-                        if area > self.min_area {
-                            filtered_features.push(feature);
-                        }
+                    if ndvi != self.reclassify.pixel_value {
+                        continue;
                     }
+                } else {
+                    continue; // Skip if no NDVI property
                 }
+            } else {
+                continue; // Skip if no properties
+            }
+
+            // Check area > min_area
+            if let Some(geometry) = &feature.geometry {
+                let geo_geom: GeoGeometry<f64> = geometry
+                    .try_into()
+                    .context("Failed to convert GeoJSON geometry to geo::Geometry")?;
 
-                // Create filtered FeatureCollection
-                let filtered_fc = geojson::FeatureCollection {
-                    bbox: None,
-                    foreign_members: None,
-                    features: filtered_features,
+                let area = match &geo_geom {
+                    GeoGeometry::Polygon(poly) => poly.unsigned_area(),
+                    GeoGeometry::MultiPolygon(mp) => mp.unsigned_area(),
+                    _ => continue, // Skip non-polygon geometries
                 };
 
-                self.geojson = Some(GeoJson::from(filtered_fc));
-            }
-            _ => {
-                // If not a FeatureCollection, store as-is
-                self.geojson = Some(geojson);
+                if area > self.min_area {
+                    filtered_features.push(feature);
+                }
             }
         }
 
+        // Create filtered FeatureCollection
+        let filtered_fc = geojson::FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features: filtered_features,
+        };
+
+        self.geojson = Some(GeoJson::from(filtered_fc));
+
         Ok(())
     }
 
     /// Load vegetation data from shapefile
     /// Following Python: gpd.read_file(self.filepath_shp, driver="ESRI Shapefile")
     fn load_from_shapefile(&mut self) -> Result<()> {
-        // Copy values to avoid borrow checker issues
-        let filepath = self
-            .filepath_shp
-            .as_ref()
-            .context("No shapefile path provided")?
-            .clone();
+        use gdal::vector::LayerAccess;
+
+        // Copy values to avoid borrow checker issues. Normalize `s3://` to the `/vsis3/` form
+        // GDAL's I/O layer expects, so a shapefile can be read straight out of an object store
+        // without staging it locally first.
+        let filepath = normalize_vsi_path(
+            self.filepath_shp
+                .as_ref()
+                .context("No shapefile path provided")?,
+        );
         let epsg_to_set = self.set_crs;
 
         // Handle CRS before opening dataset
@@ -435,7 +992,91 @@ impl Vegetation {
             self.set_crs(epsg);
         }
 
-        // Use ogr2ogr to convert shapefile to GeoJSON
+        // Read the shapefile's own spatial reference via GDAL up front, since neither loader below
+        // keeps it alongside the GeoJSON coordinates, so `run_internal` knows what CRS to
+        // reproject from.
+        self.source_epsg = Dataset::open(&filepath)
+            .ok()
+            .and_then(|dataset| dataset.layer(0).ok())
+            .and_then(|mut layer| layer.spatial_ref())
+            .and_then(|srs| srs.to_epsg().ok());
+
+        // Prefer the native GDAL-bindings reader (no temp file, no ogr2ogr dependency).
+        // Fall back to shelling into ogr2ogr if that path fails for any reason.
+        match self.load_from_shapefile_native(&filepath) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                eprintln!(
+                    "Warning: native shapefile read failed ({}), falling back to ogr2ogr",
+                    e
+                );
+                self.load_from_shapefile_ogr2ogr(&filepath)
+            }
+        }
+    }
+
+    /// Read a shapefile directly through the GDAL Rust bindings in a single pass, converting
+    /// each feature's geometry and fields into a `geojson::Feature`. Mirrors
+    /// `Water::load_from_shapefile_native`.
+    fn load_from_shapefile_native(&mut self, filepath: &str) -> Result<()> {
+        use gdal::vector::LayerAccess;
+        use geo::Geometry as GeoGeometry;
+        use geojson::{Feature, FeatureCollection};
+        use geos::Geometry as GeosGeometry;
+        use serde_json::Map;
+
+        let dataset =
+            Dataset::open(filepath).context(format!("Failed to open shapefile: {}", filepath))?;
+        let mut layer = dataset.layer(0).context("Shapefile has no layers")?;
+
+        if let Some(bbox) = self.bbox {
+            layer.set_spatial_filter_rect(bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y);
+        }
+
+        let mut features = Vec::new();
+        for feature in layer.features() {
+            let Some(geom_ref) = feature.geometry() else {
+                continue;
+            };
+            let wkt = geom_ref.wkt().context("Failed to get WKT from OGR geometry")?;
+            let geos_geom =
+                GeosGeometry::new_from_wkt(&wkt).context("Failed to parse WKT with GEOS")?;
+            let geo_geom: GeoGeometry<f64> = geos_geom
+                .try_into()
+                .context("Failed to convert GEOS geometry to geo")?;
+            let geojson_geom: geojson::Geometry = (&geo_geom)
+                .try_into()
+                .context("Failed to convert geo geometry to GeoJSON geometry")?;
+
+            let mut properties = Map::new();
+            for (name, value) in feature.fields() {
+                if let Some(value) = value {
+                    properties.insert(name, ogr_field_to_json(&value));
+                }
+            }
+
+            let mut json_feature = Feature::from(geojson_geom);
+            json_feature.properties = Some(properties);
+            features.push(json_feature);
+        }
+
+        layer.clear_spatial_filter();
+
+        self.geojson = Some(GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        }));
+
+        Ok(())
+    }
+
+    /// Legacy fallback: shell into `ogr2ogr` to convert the shapefile to GeoJSON via a temp
+    /// file. Kept for environments where the native GDAL bindings path fails (e.g. an exotic
+    /// shapefile driver quirk) but ogr2ogr is still on PATH.
+    fn load_from_shapefile_ogr2ogr(&mut self, filepath: &str) -> Result<()> {
+        use std::process::Command;
+
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -446,7 +1087,7 @@ impl Vegetation {
             .arg("-f")
             .arg("GeoJSON")
             .arg(&temp_geojson)
-            .arg(&filepath)
+            .arg(filepath)
             .status()
             .context(
                 "Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH",
@@ -456,12 +1097,10 @@ impl Vegetation {
             anyhow::bail!("ogr2ogr failed to convert shapefile to GeoJSON");
         }
 
-        // Read the GeoJSON file
         let geojson_bytes =
             std::fs::read(&temp_geojson).context("Failed to read temporary GeoJSON file")?;
         let _ = std::fs::remove_file(&temp_geojson);
 
-        // Parse GeoJSON
         let geojson_str = String::from_utf8_lossy(&geojson_bytes);
         let geojson: GeoJson = geojson_str
             .parse()
@@ -472,6 +1111,41 @@ impl Vegetation {
         Ok(())
     }
 
+    /// Upload `contents` to a GDAL virtual filesystem destination (`/vsis3/...`,
+    /// `/vsicurl/...`) via a local staging file and `ogr2ogr`, since `std::fs::write` only
+    /// understands local paths. GDAL's GeoJSON driver performs the actual `/vsis3/` multipart
+    /// upload, chunked per [`Vegetation::set_vsi_chunk_size_mb`]'s `VSIS3_CHUNK_SIZE`.
+    fn upload_text_to_vsi(&self, vsi_path: &str, contents: &str) -> Result<()> {
+        use std::process::Command;
+
+        std::env::set_var("VSIS3_CHUNK_SIZE", self.vsi_chunk_size_mb.to_string());
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let temp_file = std::env::temp_dir().join(format!("vegetation_upload_{}.geojson", timestamp));
+        std::fs::write(&temp_file, contents).context("Failed to write local staging file")?;
+
+        let status = Command::new("ogr2ogr")
+            .arg("-f")
+            .arg("GeoJSON")
+            .arg(vsi_path)
+            .arg(&temp_file)
+            .status()
+            .context(
+                "Failed to execute ogr2ogr to upload to VSI destination. Make sure GDAL is installed and ogr2ogr is in PATH",
+            );
+        let _ = std::fs::remove_file(&temp_file);
+        let status = status?;
+
+        if !status.success() {
+            anyhow::bail!("ogr2ogr failed to upload GeoJSON to {}", vsi_path);
+        }
+
+        Ok(())
+    }
+
     /// Get the GeoJSON (equivalent to to_gdf() in Python)
     /// Following Python: def to_gdf(self) -> gpd.GeoDataFrame
     pub fn get_geojson(&self) -> Option<&GeoJson> {
@@ -494,8 +1168,15 @@ impl Vegetation {
         // Save as GeoJSON for now (GeoJSON export is complex with GDAL Rust bindings)
         let output_file = self.output_path.join(format!("{}.geojson", name));
         let geojson_str = geojson.to_string();
-        std::fs::write(&output_file, geojson_str)
-            .context(format!("Failed to write GeoJSON file: {:?}", output_file))?;
+        let vsi_output_file = normalize_vsi_path(&output_file.to_string_lossy());
+
+        if vsi_output_file.starts_with("/vsis3/") || vsi_output_file.starts_with("/vsicurl/") {
+            self.upload_text_to_vsi(&vsi_output_file, &geojson_str)
+                .context(format!("Failed to upload GeoJSON to: {}", vsi_output_file))?;
+        } else {
+            std::fs::write(&output_file, geojson_str)
+                .context(format!("Failed to write GeoJSON file: {:?}", output_file))?;
+        }
 
         println!(
             "Vegetation saved to: {:?} (as GeoJSON - GeoJSON export temporarily disabled)",
@@ -515,4 +1196,488 @@ impl Vegetation {
     pub fn get_min_area(&self) -> f64 {
         self.min_area
     }
+
+    /// Export the filtered vegetation polygons as a single-file PMTiles v3 archive of Mapbox
+    /// Vector Tiles, persisted alongside whatever [`Vegetation::to_geojson`] writes so the same
+    /// run yields both the raw polygons and a tiled archive web map clients can read directly.
+    /// Follows the same reproject-then-RTree-then-per-tile-encode approach as
+    /// [`crate::geometric::lcz::Lcz::to_mbtiles`], but packs the resulting MVT tiles into
+    /// PMTiles's own single-file directory/offset layout instead of an MBTiles sqlite database.
+    pub fn to_pmtiles(&self, name: Option<&str>, min_zoom: u8, max_zoom: u8) -> Result<PathBuf> {
+        use geo::algorithm::bounding_rect::BoundingRect;
+        use geo::Geometry as GeoGeometry;
+
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+
+        // Tile coordinates are always lon/lat (Web Mercator)-based, regardless of the working
+        // CRS, so reproject a throwaway copy back to EPSG:4326 first.
+        let mut geojson_4326 = geojson.clone();
+        GeoCore::reproject_geojson(&mut geojson_4326, self.geo_core.epsg, 4326)
+            .context("Failed to reproject vegetation polygons to EPSG:4326 for tiling")?;
+
+        let GeoJson::FeatureCollection(fc) = &geojson_4326 else {
+            anyhow::bail!("Expected a FeatureCollection; call run() first");
+        };
+
+        let mut indexed_geometries = Vec::new();
+        for (id, feature) in fc.features.iter().enumerate() {
+            let Some(geometry) = &feature.geometry else {
+                continue;
+            };
+            let Ok(geom): std::result::Result<GeoGeometry<f64>, _> = geometry.try_into() else {
+                continue;
+            };
+            indexed_geometries.push(VegetationTileGeometry {
+                geom,
+                id: id as u64,
+            });
+        }
+
+        let bounds = indexed_geometries
+            .iter()
+            .filter_map(|g| g.geom.bounding_rect())
+            .fold(None, |acc: Option<geo::Rect<f64>>, rect| match acc {
+                Some(acc) => Some(geo::Rect::new(
+                    (acc.min().x.min(rect.min().x), acc.min().y.min(rect.min().y)),
+                    (acc.max().x.max(rect.max().x), acc.max().y.max(rect.max().y)),
+                )),
+                None => Some(rect),
+            })
+            .context("No features to tile")?;
+
+        let tree = rstar::RTree::bulk_load(indexed_geometries);
+
+        let mut tiles: Vec<((u8, u32, u32), Vec<u8>)> = Vec::new();
+        for zoom in min_zoom..=max_zoom {
+            let (min_tx, max_ty) = lonlat_to_tile_xy(bounds.min().x, bounds.min().y, zoom);
+            let (max_tx, min_ty) = lonlat_to_tile_xy(bounds.max().x, bounds.max().y, zoom);
+
+            for tile_x in min_tx..=max_tx {
+                for tile_y in min_ty..=max_ty {
+                    let (west, south, east, north) = tile_bounds(tile_x, tile_y, zoom);
+                    let envelope = rstar::AABB::from_corners([west, south], [east, north]);
+                    let candidates: Vec<_> =
+                        tree.locate_in_envelope_intersecting(&envelope).collect();
+                    if candidates.is_empty() {
+                        continue;
+                    }
+
+                    let tile_bytes = encode_mvt_vegetation_tile(&candidates, zoom, tile_x, tile_y);
+                    tiles.push(((zoom, tile_x, tile_y), gzip_compress(&tile_bytes)?));
+                }
+            }
+        }
+
+        let name = name.unwrap_or("vegetation");
+        let output_file = self.output_path.join(format!("{}.pmtiles", name));
+        let archive = build_pmtiles_archive(&tiles, min_zoom, max_zoom, bounds)
+            .context("Failed to assemble PMTiles archive")?;
+        std::fs::write(&output_file, archive)
+            .context(format!("Failed to write PMTiles file: {:?}", output_file))?;
+
+        println!("Vegetation tiles saved to: {:?}", output_file);
+        Ok(output_file)
+    }
+}
+
+/// Slippy-map tile `(x, y)` covering `(lon, lat)` at `zoom`, per the standard Web Mercator tile
+/// scheme (see the OSM wiki's "Slippy map tilenames"). Duplicated from
+/// [`crate::geometric::lcz::Lcz`]'s identical helper since the two modules don't share a tiling
+/// utility module.
+fn lonlat_to_tile_xy(lon: f64, lat: f64, zoom: u8) -> (u32, u32) {
+    let n = (1u32 << zoom) as f64;
+    let lat_rad = lat.to_radians();
+    let x = ((lon + 180.0) / 360.0 * n).floor().clamp(0.0, n - 1.0) as u32;
+    let y = ((1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n)
+        .floor()
+        .clamp(0.0, n - 1.0) as u32;
+    (x, y)
+}
+
+/// Inverse of [`lonlat_to_tile_xy`]: the `(lon, lat)` of tile `(x, y)`'s top-left corner at `zoom`.
+fn tile_lonlat(x: u32, y: u32, zoom: u8) -> (f64, f64) {
+    let n = (1u32 << zoom) as f64;
+    let lon = x as f64 / n * 360.0 - 180.0;
+    let lat_rad = (std::f64::consts::PI * (1.0 - 2.0 * y as f64 / n)).sinh().atan();
+    (lon, lat_rad.to_degrees())
+}
+
+/// `(west, south, east, north)` bounds of tile `(x, y)` at `zoom`.
+fn tile_bounds(x: u32, y: u32, zoom: u8) -> (f64, f64, f64, f64) {
+    let (west, north) = tile_lonlat(x, y, zoom);
+    let (east, south) = tile_lonlat(x + 1, y + 1, zoom);
+    (west, south, east, north)
+}
+
+/// Project `(lon, lat)` into tile `(tile_x, tile_y)`'s local `0..extent` pixel space at `zoom`.
+fn lonlat_to_tile_pixel(lon: f64, lat: f64, zoom: u8, tile_x: u32, tile_y: u32, extent: u32) -> (i32, i32) {
+    let n = (1u32 << zoom) as f64;
+    let lat_rad = lat.to_radians();
+    let world_x = (lon + 180.0) / 360.0 * n;
+    let world_y =
+        (1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n;
+
+    let px = ((world_x - tile_x as f64) * extent as f64).round() as i32;
+    let py = ((world_y - tile_y as f64) * extent as f64).round() as i32;
+    (px, py)
+}
+
+/// Append a protobuf varint (base-128, little-endian, continuation bit in the MSB of each byte).
+/// Also used by [`encode_pmtiles_directory`] for PMTiles's own varint-columnar directory format.
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Append a protobuf field tag (`(field_number << 3) | wire_type`).
+fn write_tag(buf: &mut Vec<u8>, field_number: u32, wire_type: u8) {
+    write_varint(buf, ((field_number as u64) << 3) | wire_type as u64);
+}
+
+/// Append a length-delimited protobuf field (wire type 2): a string, an embedded message, or a
+/// packed repeated scalar field, all of which share this encoding.
+fn write_bytes_field(buf: &mut Vec<u8>, field_number: u32, data: &[u8]) {
+    write_tag(buf, field_number, 2);
+    write_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+fn write_string_field(buf: &mut Vec<u8>, field_number: u32, value: &str) {
+    write_bytes_field(buf, field_number, value.as_bytes());
+}
+
+fn write_uint32_field(buf: &mut Vec<u8>, field_number: u32, value: u32) {
+    write_tag(buf, field_number, 0);
+    write_varint(buf, value as u64);
+}
+
+/// Protobuf zigzag encoding (`sint32`), mapping signed deltas to varint-friendly unsigned values.
+fn zigzag_encode(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Flatten a geometry down to its constituent polygons, recursing through
+/// `GeometryCollection`. Vegetation polygons are always Polygon/MultiPolygon (optionally
+/// wrapped after [`Vegetation::dissolve`]), so other geometry types yield nothing.
+fn flatten_to_polygons(geo_geom: geo::Geometry<f64>) -> Vec<geo::Polygon<f64>> {
+    match geo_geom {
+        geo::Geometry::Polygon(p) => vec![p],
+        geo::Geometry::MultiPolygon(mp) => mp.0,
+        geo::Geometry::GeometryCollection(gc) => {
+            gc.into_iter().flat_map(flatten_to_polygons).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Encode `polygon`'s exterior ring and holes as Mapbox Vector Tile geometry commands (the
+/// `vector_tile.proto` `Tile.Feature.geometry` packed-`uint32` encoding: `MoveTo`/`LineTo`/
+/// `ClosePath` commands followed by zigzag-delta-encoded parameters), via `to_pixel` to map each
+/// ring vertex into the tile's local pixel space.
+fn encode_polygon_geometry(
+    polygon: &geo::Polygon<f64>,
+    to_pixel: &dyn Fn(f64, f64) -> (i32, i32),
+) -> Vec<u32> {
+    const MOVE_TO: u32 = 1;
+    const LINE_TO: u32 = 2;
+    const CLOSE_PATH: u32 = 7;
+
+    let mut commands = Vec::new();
+    let mut cursor = (0i32, 0i32);
+
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        let points: Vec<(i32, i32)> = ring.points().map(|p| to_pixel(p.x(), p.y())).collect();
+        if points.len() < 2 {
+            continue;
+        }
+        // geo::LineString rings repeat their first point as their last; MVT's ClosePath command
+        // implies the closing edge instead, so drop the duplicate.
+        let points = &points[..points.len() - 1];
+        if points.is_empty() {
+            continue;
+        }
+
+        commands.push((MOVE_TO & 0x7) | (1 << 3));
+        let (dx, dy) = (points[0].0 - cursor.0, points[0].1 - cursor.1);
+        commands.push(zigzag_encode(dx));
+        commands.push(zigzag_encode(dy));
+        cursor = points[0];
+
+        let remaining = points.len() - 1;
+        if remaining > 0 {
+            commands.push((LINE_TO & 0x7) | ((remaining as u32) << 3));
+            for &(x, y) in &points[1..] {
+                let (dx, dy) = (x - cursor.0, y - cursor.1);
+                commands.push(zigzag_encode(dx));
+                commands.push(zigzag_encode(dy));
+                cursor = (x, y);
+            }
+        }
+
+        commands.push((CLOSE_PATH & 0x7) | (1 << 3));
+    }
+
+    commands
+}
+
+/// Encode a `Value` message wrapping a single unsigned integer (`uint_value`, field 5).
+fn encode_value_uint(value: u64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_tag(&mut buf, 5, 0);
+    write_varint(&mut buf, value);
+    buf
+}
+
+/// Encode `features` as a single-layer Mapbox Vector Tile (`"vegetation"`, extent
+/// [`MVT_EXTENT`]), following the `vector_tile.proto` v2.1 schema. Every feature's geometry is a
+/// Polygon/MultiPolygon; non-polygonal or empty geometries are skipped. Carries each feature's
+/// index as an `id` property so tiles remain debuggable even with no other attributes.
+fn encode_mvt_vegetation_tile(
+    features: &[&VegetationTileGeometry],
+    zoom: u8,
+    tile_x: u32,
+    tile_y: u32,
+) -> Vec<u8> {
+    use std::collections::HashMap;
+
+    let to_pixel =
+        |lon: f64, lat: f64| lonlat_to_tile_pixel(lon, lat, zoom, tile_x, tile_y, MVT_EXTENT);
+
+    let keys = ["id"];
+    let mut values: Vec<Vec<u8>> = Vec::new();
+    let mut value_index: HashMap<Vec<u8>, u32> = HashMap::new();
+    let mut encoded_features = Vec::new();
+
+    for indexed in features {
+        let polygons = flatten_to_polygons(indexed.geom.clone());
+        if polygons.is_empty() {
+            continue;
+        }
+
+        let mut geometry_commands = Vec::new();
+        for polygon in &polygons {
+            geometry_commands.extend(encode_polygon_geometry(polygon, &to_pixel));
+        }
+        if geometry_commands.is_empty() {
+            continue;
+        }
+
+        let id_value = encode_value_uint(indexed.id);
+        let id_value_idx = *value_index.entry(id_value.clone()).or_insert_with(|| {
+            values.push(id_value);
+            (values.len() - 1) as u32
+        });
+
+        let mut tags_buf = Vec::new();
+        write_varint(&mut tags_buf, 0); // key index: "id"
+        write_varint(&mut tags_buf, id_value_idx as u64);
+
+        let mut feature_buf = Vec::new();
+        write_bytes_field(&mut feature_buf, 2, &tags_buf); // tags (packed uint32)
+        write_uint32_field(&mut feature_buf, 3, 3); // type = POLYGON
+        let mut geometry_buf = Vec::new();
+        for command in &geometry_commands {
+            write_varint(&mut geometry_buf, *command as u64);
+        }
+        write_bytes_field(&mut feature_buf, 4, &geometry_buf); // geometry (packed uint32)
+
+        let mut layer_feature_buf = Vec::new();
+        write_bytes_field(&mut layer_feature_buf, 2, &feature_buf); // Layer.features
+        encoded_features.push(layer_feature_buf);
+    }
+
+    let mut layer_buf = Vec::new();
+    write_uint32_field(&mut layer_buf, 15, 1); // version
+    write_string_field(&mut layer_buf, 1, "vegetation"); // name
+    for feature_buf in &encoded_features {
+        layer_buf.extend_from_slice(feature_buf);
+    }
+    for key in &keys {
+        write_string_field(&mut layer_buf, 3, key);
+    }
+    for value in &values {
+        write_bytes_field(&mut layer_buf, 4, value);
+    }
+    write_uint32_field(&mut layer_buf, 5, MVT_EXTENT);
+
+    let mut tile_buf = Vec::new();
+    write_bytes_field(&mut tile_buf, 3, &layer_buf); // Tile.layers
+    tile_buf
+}
+
+/// Gzip-compress `data` at the default compression level, used for both MVT tile bytes and the
+/// PMTiles root directory/metadata blobs, which PMTiles always stores gzip-compressed.
+fn gzip_compress(data: &[u8]) -> Result<Vec<u8>> {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(data)
+        .context("Failed to gzip-compress data")?;
+    encoder
+        .finish()
+        .context("Failed to finalize gzip compression")
+}
+
+/// Convert `(x, y)` on an `n`x`n` grid to its Hilbert curve distance, per the standard xy2d
+/// algorithm. PMTiles orders tiles by a global ID built from this distance so that
+/// spatially-adjacent tiles end up physically adjacent in the tile data section.
+fn hilbert_xy_to_d(n: u64, mut x: u64, mut y: u64) -> u64 {
+    let mut d: u64 = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx: u64 = if (x & s) > 0 { 1 } else { 0 };
+        let ry: u64 = if (y & s) > 0 { 1 } else { 0 };
+        d += s * s * ((3 * rx) ^ ry);
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    d
+}
+
+/// PMTiles v3 global tile ID for `(z, x, y)`: the count of tiles at every zoom level below `z`
+/// (`(4^z - 1) / 3`) plus `(x, y)`'s Hilbert curve distance within level `z`'s `2^z`x`2^z` grid.
+fn pmtiles_tile_id(z: u8, x: u32, y: u32) -> u64 {
+    let mut tiles_below: u64 = 0;
+    for level in 0..z {
+        tiles_below += 1u64 << (level as u64 * 2);
+    }
+    let n: u64 = 1 << z;
+    tiles_below + hilbert_xy_to_d(n, x as u64, y as u64)
+}
+
+/// Encode a PMTiles directory's columnar varint layout: entry count, then delta-encoded tile
+/// IDs, run lengths (always 1 here -- no run-length merging of identical tiles is attempted),
+/// tile lengths, and tile offsets (relative to the tile data section's start).
+fn encode_pmtiles_directory(entries: &[(u64, u64, u64)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_varint(&mut buf, entries.len() as u64);
+
+    let mut previous_id = 0u64;
+    for (tile_id, _, _) in entries {
+        write_varint(&mut buf, tile_id - previous_id);
+        previous_id = *tile_id;
+    }
+    for _ in entries {
+        write_varint(&mut buf, 1); // run_length
+    }
+    for (_, _, length) in entries {
+        write_varint(&mut buf, *length);
+    }
+    for (_, offset, _) in entries {
+        write_varint(&mut buf, *offset);
+    }
+    buf
+}
+
+/// Assemble a minimal single-file PMTiles v3 archive from already-gzip-compressed MVT tiles.
+/// Every tile is placed directly in the root directory (no leaf directories), which PMTiles
+/// allows as long as the root directory itself doesn't balloon past the size a client is willing
+/// to fetch in one request -- comfortably true for a single `Vegetation` run's tile count.
+fn build_pmtiles_archive(
+    tiles: &[((u8, u32, u32), Vec<u8>)],
+    min_zoom: u8,
+    max_zoom: u8,
+    bounds: geo::Rect<f64>,
+) -> Result<Vec<u8>> {
+    // Sort tiles by PMTiles global tile ID: the root directory's delta-encoded tile IDs must be
+    // non-decreasing, and ordering tile data this way clusters spatially-adjacent tiles together.
+    let mut sorted: Vec<(u64, &Vec<u8>)> = tiles
+        .iter()
+        .map(|((z, x, y), data)| (pmtiles_tile_id(*z, *x, *y), data))
+        .collect();
+    sorted.sort_by_key(|(id, _)| *id);
+
+    let mut tile_data = Vec::new();
+    let mut directory_entries = Vec::new();
+    for (tile_id, data) in &sorted {
+        let offset = tile_data.len() as u64;
+        let length = data.len() as u64;
+        tile_data.extend_from_slice(data);
+        directory_entries.push((*tile_id, offset, length));
+    }
+
+    let root_directory = gzip_compress(&encode_pmtiles_directory(&directory_entries))
+        .context("Failed to gzip-compress PMTiles root directory")?;
+
+    let metadata_json = serde_json::json!({
+        "name": "vegetation",
+        "format": "pbf",
+        "vector_layers": [{"id": "vegetation", "fields": {"id": "Number"}}],
+    })
+    .to_string();
+    let metadata = gzip_compress(metadata_json.as_bytes())
+        .context("Failed to gzip-compress PMTiles metadata")?;
+
+    const HEADER_LEN: u64 = 127;
+    let root_dir_offset = HEADER_LEN;
+    let root_dir_length = root_directory.len() as u64;
+    let metadata_offset = root_dir_offset + root_dir_length;
+    let metadata_length = metadata.len() as u64;
+    let leaf_dirs_offset = metadata_offset + metadata_length;
+    let tile_data_offset = leaf_dirs_offset; // no leaf directories
+    let tile_data_length = tile_data.len() as u64;
+
+    let mut header = Vec::with_capacity(HEADER_LEN as usize);
+    header.extend_from_slice(b"PMTiles");
+    header.push(3); // version
+    header.extend_from_slice(&root_dir_offset.to_le_bytes());
+    header.extend_from_slice(&root_dir_length.to_le_bytes());
+    header.extend_from_slice(&metadata_offset.to_le_bytes());
+    header.extend_from_slice(&metadata_length.to_le_bytes());
+    header.extend_from_slice(&leaf_dirs_offset.to_le_bytes());
+    header.extend_from_slice(&0u64.to_le_bytes()); // leaf_dirs_length
+    header.extend_from_slice(&tile_data_offset.to_le_bytes());
+    header.extend_from_slice(&tile_data_length.to_le_bytes());
+    header.extend_from_slice(&(sorted.len() as u64).to_le_bytes()); // addressed_tiles_count
+    header.extend_from_slice(&(sorted.len() as u64).to_le_bytes()); // tile_entries_count
+    header.extend_from_slice(&(sorted.len() as u64).to_le_bytes()); // tile_contents_count
+    header.push(1); // clustered
+    header.push(2); // internal_compression = gzip
+    header.push(2); // tile_compression = gzip
+    header.push(1); // tile_type = mvt
+    header.push(min_zoom);
+    header.push(max_zoom);
+    header.extend_from_slice(&((bounds.min().x * 1e7) as i32).to_le_bytes());
+    header.extend_from_slice(&((bounds.min().y * 1e7) as i32).to_le_bytes());
+    header.extend_from_slice(&((bounds.max().x * 1e7) as i32).to_le_bytes());
+    header.extend_from_slice(&((bounds.max().y * 1e7) as i32).to_le_bytes());
+    header.push(min_zoom); // center_zoom
+    let center_x = (bounds.min().x + bounds.max().x) / 2.0;
+    let center_y = (bounds.min().y + bounds.max().y) / 2.0;
+    header.extend_from_slice(&((center_x * 1e7) as i32).to_le_bytes());
+    header.extend_from_slice(&((center_y * 1e7) as i32).to_le_bytes());
+
+    anyhow::ensure!(
+        header.len() as u64 == HEADER_LEN,
+        "PMTiles header must be exactly 127 bytes"
+    );
+
+    let mut archive =
+        Vec::with_capacity(header.len() + root_directory.len() + metadata.len() + tile_data.len());
+    archive.extend_from_slice(&header);
+    archive.extend_from_slice(&root_directory);
+    archive.extend_from_slice(&metadata);
+    archive.extend_from_slice(&tile_data);
+    Ok(archive)
 }
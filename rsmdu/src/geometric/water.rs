@@ -1,19 +1,57 @@
 use anyhow::{Context, Result};
 use gdal::Dataset;
 use geojson::GeoJson;
+use serde_json::Map;
 use std::path::{Path, PathBuf};
 
 use crate::collect::ign::ign_collect::IgnCollect;
+use crate::collect::osm::osm_collect::OsmCollect;
 use crate::geo_core::{BoundingBox, GeoCore};
+use crate::geometric::export::{self, OutputFormat};
+use crate::geometric::query::QueryFilter;
+use crate::geometric::raster::Rasteriser;
+
+/// Convert an OGR field value into the closest serde_json representation, used when
+/// importing shapefile attributes natively (without round-tripping through ogr2ogr).
+fn ogr_field_to_json(value: &gdal::vector::FieldValue) -> serde_json::Value {
+    use gdal::vector::FieldValue;
+    use serde_json::Value as JsonValue;
+    match value {
+        FieldValue::IntegerValue(v) => JsonValue::from(*v),
+        FieldValue::Integer64Value(v) => JsonValue::from(*v),
+        FieldValue::RealValue(v) => JsonValue::from(*v),
+        FieldValue::StringValue(v) => JsonValue::from(v.clone()),
+        FieldValue::IntegerListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::Integer64ListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::RealListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::StringListValue(v) => JsonValue::from(v.clone()),
+        FieldValue::DateValue(v) => JsonValue::from(v.to_string()),
+        FieldValue::DateTimeValue(v) => JsonValue::from(v.to_string()),
+    }
+}
+
+/// Which backend Water should collect from when no shapefile is provided.
+/// Python always used `OsmCollect(key='"natural"="water"')`, falling back to IGN was a
+/// Rust-port stopgap; `Osm` is the default to match that behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaterSource {
+    Osm,
+    Ign,
+}
 
 /// Water structure
 /// Following Python implementation from pymdu.geometric.Water
-/// Provides methods to collect and process Water (plan d'eau) data from IGN API or shapefile
+/// Provides methods to collect and process Water (plan d'eau) data from IGN API, OSM/Overpass,
+/// or a shapefile
 pub struct Water {
     /// Optional shapefile path (Python: filepath_shp)
     filepath_shp: Option<String>,
+    /// Which backend to use when no shapefile is provided
+    source: WaterSource,
     /// IgnCollect instance for API requests
     ign_collect: Option<IgnCollect>,
+    /// OsmCollect instance for Overpass requests
+    osm_collect: Option<OsmCollect>,
     /// Output path for processed data
     output_path: PathBuf,
     /// GeoCore for CRS handling
@@ -29,10 +67,13 @@ pub struct Water {
 impl Water {
     /// Create a new Water instance
     /// Following Python: def __init__(self, filepath_shp=None, output_path=None, set_crs=None)
+    /// `source` selects the IGN/OSM backend used when `filepath_shp` is `None`; defaults to
+    /// `WaterSource::Osm` to mirror the Python implementation's `OsmCollect` usage.
     pub fn new(
         filepath_shp: Option<String>,
         output_path: Option<String>,
         set_crs: Option<i32>,
+        source: Option<WaterSource>,
     ) -> Result<Self> {
         use crate::collect::global_variables::TEMP_PATH;
 
@@ -54,7 +95,9 @@ impl Water {
 
         let mut water = Water {
             filepath_shp,
+            source: source.unwrap_or(WaterSource::Osm),
             ign_collect: None,
+            osm_collect: None,
             output_path: output_path_buf,
             geo_core,
             bbox: None,
@@ -62,12 +105,17 @@ impl Water {
             set_crs,
         };
 
-        // Initialize IgnCollect if no shapefile provided (will be used for IGN API)
+        // Initialize the selected backend if no shapefile provided
         // Python: if not self.filepath_shp: osm = OsmCollect(key='"natural"="water"')
-        // For now, we use IgnCollect with key "water" (BDTOPO_V3:plan_d_eau)
-        // TODO: Implement OsmCollect for OSM data if needed
         if water.filepath_shp.is_none() {
-            water.ign_collect = Some(IgnCollect::new()?);
+            match water.source {
+                WaterSource::Osm => {
+                    water.osm_collect = Some(OsmCollect::new(r#""natural"="water""#));
+                }
+                WaterSource::Ign => {
+                    water.ign_collect = Some(IgnCollect::new()?);
+                }
+            }
         }
 
         Ok(water)
@@ -80,6 +128,17 @@ impl Water {
         if let Some(ref mut ign_collect) = self.ign_collect {
             ign_collect.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
         }
+        if let Some(ref mut osm_collect) = self.osm_collect {
+            osm_collect.bbox = Some(BoundingBox::new(min_x, min_y, max_x, max_y));
+        }
+    }
+
+    /// Restrict `run()`'s output to features inside `boundary`, a GeoJSON Polygon/MultiPolygon
+    /// (or Feature/FeatureCollection wrapping one) expressed in EPSG:4326. Features entirely
+    /// outside it are dropped; features straddling its edge are clipped to it. See
+    /// [`GeoCore::set_limit_to`].
+    pub fn set_limit_to(&mut self, boundary: &[u8]) -> Result<()> {
+        self.geo_core.set_limit_to(boundary)
     }
 
     /// Set CRS
@@ -108,51 +167,140 @@ impl Water {
         // else:
         //     self.gdf = gpd.read_file(self.filepath_shp, driver="ESRI Shapefile")
         if self.filepath_shp.is_none() {
-            // Load from IGN API using IgnCollect with key "water"
-            // Python uses OsmCollect, but we use IgnCollect for IGN BDTOPO data
-            // TODO: Implement OsmCollect if OSM data is specifically needed
-            let mut ign_collect = self
-                .ign_collect
-                .take()
-                .context("IgnCollect not initialized")?;
-
-            // Execute IGN API request for water
-            ign_collect
-                .execute_ign("water")
-                .context("Failed to execute IGN request for water")?;
-
-            // Get content from IgnCollect
-            let content = ign_collect
-                .content
-                .as_ref()
-                .context("No content received from IGN API")?;
-
-            // Parse GeoJSON following Python: gpd.read_file(file, driver="GeoJSON")
-            let geojson_str = String::from_utf8_lossy(content);
-            let geojson: GeoJson = geojson_str
-                .parse()
-                .context("Failed to parse GeoJSON from IGN API response")?;
-
-            println!("GeoJSON: {:?}", geojson);
-
-            self.geojson = Some(geojson);
+            match self.source {
+                WaterSource::Osm => {
+                    // Python: osm = OsmCollect(key='"natural"="water"'); self.gdf = osm.run().to_gdf()
+                    let mut osm_collect = self
+                        .osm_collect
+                        .take()
+                        .context("OsmCollect not initialized")?;
+
+                    osm_collect
+                        .run_internal()
+                        .context("Failed to execute Overpass request for water")?;
+
+                    let geojson = osm_collect
+                        .to_geojson()
+                        .context("Failed to convert Overpass response to GeoJSON")?;
+
+                    self.osm_collect = Some(osm_collect);
+                    self.geojson = Some(geojson);
+                }
+                WaterSource::Ign => {
+                    // Load from IGN API using IgnCollect with key "water" (BDTOPO_V3:plan_d_eau)
+                    let mut ign_collect = self
+                        .ign_collect
+                        .take()
+                        .context("IgnCollect not initialized")?;
+
+                    // Execute IGN API request for water
+                    ign_collect
+                        .execute_ign("water")
+                        .context("Failed to execute IGN request for water")?;
+
+                    // Get content from IgnCollect
+                    let content = ign_collect
+                        .content
+                        .as_ref()
+                        .context("No content received from IGN API")?;
+
+                    // Parse GeoJSON following Python: gpd.read_file(file, driver="GeoJSON")
+                    let geojson_str = String::from_utf8_lossy(content);
+                    let geojson: GeoJson = geojson_str
+                        .parse()
+                        .context("Failed to parse GeoJSON from IGN API response")?;
+
+                    self.ign_collect = Some(ign_collect);
+                    self.geojson = Some(geojson);
+                }
+            }
         } else {
             // Load from shapefile
             // Python: self.gdf = gpd.read_file(self.filepath_shp, driver="ESRI Shapefile")
             self.load_from_shapefile()?;
         }
 
+        // Clip to the limit_to boundary (if any) while the OSM/IGN geojson is still in
+        // EPSG:4326, before it gets reprojected below. Shapefile input is assumed to already
+        // carry geo_core's target EPSG, so it's left out of this pass.
+        if self.filepath_shp.is_none() {
+            if let Some(ref mut geojson) = self.geojson {
+                self.geo_core
+                    .clip_to_limit(geojson)
+                    .context("Failed to clip water features to limit_to boundary")?;
+            }
+        }
+
         // Python: if self.set_crs:
         //         self.gdf = self.gdf.set_crs(crs=self.set_crs, inplace=True, allow_override=True)
         // else:
         //     self.gdf = self.gdf.to_crs(epsg=self._epsg)
-        // Note: CRS transformation would require GDAL reprojection
-        // For now, we store the GeoJSON as-is
-        // TODO: Implement CRS transformation using GDAL or proj crate
+        // OSM (Overpass) and IGN WFS output is EPSG:4326; shapefiles are assumed to already
+        // carry geo_core's EPSG. `set_crs` mirrors Python's set_crs(allow_override=True): it
+        // relabels the CRS without reprojecting, so only the OSM and IGN branches need an actual
+        // coordinate transform here.
+        if self.set_crs.is_none() && self.filepath_shp.is_none() {
+            self.reproject_to(4326, self.geo_core.epsg)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reproject the stored GeoJSON from `from_epsg` to `to_epsg`, transforming every
+    /// coordinate pair in place. Callers can also invoke this directly after `run()`
+    /// to re-target a different CRS without re-fetching the data.
+    pub fn reproject_to(&mut self, from_epsg: i32, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            GeoCore::reproject_geojson(geojson, from_epsg, to_epsg)?;
+        }
+        Ok(())
+    }
 
+    /// Reproject the stored GeoJSON from `geo_core`'s current EPSG to `to_epsg`, updating
+    /// `geo_core.epsg` on success. Unlike [`Water::reproject_to`], this goes through
+    /// `GeoCore::reproject`'s cached `Proj` pipeline, so calling it repeatedly (e.g. once per
+    /// exported file) doesn't rebuild the transformation each time.
+    pub fn reproject(&mut self, to_epsg: i32) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            self.geo_core.reproject(geojson, to_epsg)?;
+        } else {
+            self.geo_core.set_epsg(to_epsg);
+        }
         Ok(())
     }
 
+    /// Reproject back to EPSG:4326 (WGS84 lat/long), e.g. before exporting to a format that
+    /// expects geographic coordinates.
+    pub fn to_latlong(&mut self) -> Result<()> {
+        self.reproject(4326)
+    }
+
+    /// Keep only the stored features matching a small SQL-like WHERE expression over their
+    /// properties (e.g. `"nature = 'ETANG' OR nature = 'LAC'"`). Call this after `run()` and
+    /// before `to_geojson`/`get_geojson`/export to subset the result. See
+    /// [`GeoCore::filter`] for the accepted grammar. A no-op if `run()` hasn't been called yet.
+    pub fn filter(&mut self, expr: &str) -> Result<()> {
+        if let Some(ref mut geojson) = self.geojson {
+            self.geo_core.filter(geojson, expr)?;
+        }
+        Ok(())
+    }
+
+    /// Re-load the shapefile with an explicit field map, renaming (or, mapped to `""`,
+    /// dropping) attributes during import. Useful when the source shapefile's column
+    /// names don't match the conventions the rest of the pipeline expects.
+    pub fn load_from_shapefile_with_field_map(
+        &mut self,
+        field_map: std::collections::HashMap<String, String>,
+    ) -> Result<()> {
+        let filepath = self
+            .filepath_shp
+            .as_ref()
+            .context("No shapefile path provided")?
+            .clone();
+        self.load_from_shapefile_native(&filepath, Some(&field_map))
+    }
+
     /// Load water data from shapefile
     /// Following Python: gpd.read_file(self.filepath_shp, driver="ESRI Shapefile")
     fn load_from_shapefile(&mut self) -> Result<()> {
@@ -173,30 +321,106 @@ impl Water {
         }
         // TODO: Implement SRS detection from shapefile if set_crs is not provided
 
-        // Open shapefile using GDAL (we don't actually need to use it since we use ogr2ogr)
-        let _dataset =
-            Dataset::open(&filepath).context(format!("Failed to open shapefile: {}", filepath))?;
+        // Prefer the native GDAL-bindings reader (no temp file, no ogr2ogr dependency).
+        // Fall back to shelling into ogr2ogr if that path fails for any reason.
+        match self.load_from_shapefile_native(&filepath, None) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                eprintln!(
+                    "Warning: native shapefile read failed ({}), falling back to ogr2ogr",
+                    e
+                );
+                self.load_from_shapefile_ogr2ogr(&filepath)
+            }
+        }
+    }
+
+    /// Read a shapefile directly through the GDAL Rust bindings in a single pass: open
+    /// the dataset, set the layer's spatial filter from `self.bbox` (if set) to
+    /// extent-filter features before iteration, then convert each feature's geometry
+    /// and fields into a `geojson::Feature`. `field_map` optionally renames (or, when
+    /// a field maps to `""`, drops) attributes during import.
+    fn load_from_shapefile_native(
+        &mut self,
+        filepath: &str,
+        field_map: Option<&std::collections::HashMap<String, String>>,
+    ) -> Result<()> {
+        use gdal::vector::LayerAccess;
+        use geo::Geometry as GeoGeometry;
+        use geojson::{Feature, FeatureCollection};
+        use geos::Geometry as GeosGeometry;
+        use serde_json::Map;
+
+        let dataset =
+            Dataset::open(filepath).context(format!("Failed to open shapefile: {}", filepath))?;
+        let mut layer = dataset.layer(0).context("Shapefile has no layers")?;
+
+        if let Some(bbox) = self.bbox {
+            layer.set_spatial_filter_rect(bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y);
+        }
+
+        let mut features = Vec::new();
+        for feature in layer.features() {
+            let Some(geom_ref) = feature.geometry() else {
+                continue;
+            };
+            let wkt = geom_ref.wkt().context("Failed to get WKT from OGR geometry")?;
+            let geos_geom =
+                GeosGeometry::new_from_wkt(&wkt).context("Failed to parse WKT with GEOS")?;
+            let geo_geom: GeoGeometry<f64> = geos_geom
+                .try_into()
+                .context("Failed to convert GEOS geometry to geo")?;
+            let geojson_geom: geojson::Geometry = (&geo_geom)
+                .try_into()
+                .context("Failed to convert geo geometry to GeoJSON geometry")?;
+
+            let mut properties = Map::new();
+            for field in feature.fields() {
+                let (name, value) = field;
+                let target_name = match field_map.and_then(|m| m.get(&name)) {
+                    Some(renamed) if renamed.is_empty() => continue, // dropped
+                    Some(renamed) => renamed.clone(),
+                    None => name,
+                };
+                if let Some(value) = value {
+                    properties.insert(target_name, ogr_field_to_json(&value));
+                }
+            }
+
+            let mut json_feature = Feature::from(geojson_geom);
+            json_feature.properties = Some(properties);
+            features.push(json_feature);
+        }
+
+        layer.clear_spatial_filter();
 
-        // Use ogr2ogr command-line tool for reliable shapefile to GeoJSON conversion
-        // This is more reliable than using the GDAL Rust bindings directly
-        // which have complex API requirements for vector dataset creation
-        // Following the approach from lcz.rs
+        self.geojson = Some(GeoJson::from(FeatureCollection {
+            bbox: None,
+            foreign_members: None,
+            features,
+        }));
+
+        Ok(())
+    }
+
+    /// Legacy fallback: shell into `ogr2ogr` to convert the shapefile to GeoJSON via a
+    /// temp file. Kept for environments where the native GDAL bindings path fails
+    /// (e.g. an exotic shapefile driver quirk) but ogr2ogr is still on PATH.
+    fn load_from_shapefile_ogr2ogr(&mut self, filepath: &str) -> Result<()> {
         use std::process::Command;
         use std::time::{SystemTime, UNIX_EPOCH};
 
-        // Create a temporary file path for GeoJSON output
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
         let temp_geojson = std::env::temp_dir().join(format!("water_{}.geojson", timestamp));
 
-        // Use ogr2ogr to convert shapefile to GeoJSON
         let status = Command::new("ogr2ogr")
             .arg("-f")
             .arg("GeoJSON")
             .arg(&temp_geojson)
-            .arg(&filepath)
+            .arg(filepath)
             .status()
             .context(
                 "Failed to execute ogr2ogr. Make sure GDAL is installed and ogr2ogr is in PATH",
@@ -206,14 +430,10 @@ impl Water {
             anyhow::bail!("ogr2ogr failed to convert shapefile to GeoJSON");
         }
 
-        // Read the GeoJSON file we just created
         let geojson_bytes =
             std::fs::read(&temp_geojson).context("Failed to read temporary GeoJSON file")?;
-
-        // Clean up temporary file
         let _ = std::fs::remove_file(&temp_geojson);
 
-        // Parse GeoJSON
         let geojson_str = String::from_utf8_lossy(&geojson_bytes);
         let geojson: GeoJson = geojson_str
             .parse()
@@ -230,12 +450,44 @@ impl Water {
         self.geojson.as_ref()
     }
 
+    /// Subset the collected water features with a select/where/intersects query,
+    /// without mutating the stored GeoJSON. Callers can pass the result straight to
+    /// `to_file`/export helpers to write only the matching features.
+    pub fn query(&self, filter: &QueryFilter) -> Result<GeoJson> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        Ok(filter.apply(geojson))
+    }
+
     /// Save to GeoJSON file
     /// Following Python: def to_geojson(self, name: str = "water")
     /// Note: GeoJSON export requires GDAL and is complex
     /// For now, we save as GeoJSON - full GeoJSON export would require GDAL layer operations
     /// TODO: Implement full GeoJSON export using GDAL
-    pub fn to_geojson(&self, name: Option<&str>) -> Result<()> {
+    /// When `seq` is set, writes newline-delimited GeoJSON (one `Feature` per line, the
+    /// `.geojsonl`/GeoJSONSeq convention) instead of a single `FeatureCollection` document, so
+    /// downstream tools can process the output line-by-line without holding it all in memory.
+    pub fn to_geojson(&self, name: Option<&str>, seq: bool) -> Result<()> {
+        self.to_geojson_with_options(name, seq, None, None)
+    }
+
+    /// Like [`Water::to_geojson`], with two extra knobs for large IGN/OSM FeatureCollections:
+    /// `precision` rounds every emitted coordinate to that many decimal places (6 decimals is
+    /// about 0.1m for EPSG:4326 coordinates and a micrometre in a metric CRS such as the default
+    /// EPSG:2154, and cuts file size substantially), and `foreign_members`
+    /// attaches top-level members (e.g. a `bbox`, source API name, query timestamp) to the
+    /// written FeatureCollection. Both are no-ops when `None`. `foreign_members` has no effect
+    /// when `seq` is set, since GeoJSONSeq has no FeatureCollection wrapper to attach them to;
+    /// `precision` applies to both outputs.
+    pub fn to_geojson_with_options(
+        &self,
+        name: Option<&str>,
+        seq: bool,
+        precision: Option<u32>,
+        foreign_members: Option<Map<String, serde_json::Value>>,
+    ) -> Result<()> {
         // Python: self.gdf.to_file(f"{os.path.join(self.output_path, name)}.GeoJSON", driver="GeoJSON")
         // For now, save as GeoJSON as a workaround
         // Full GeoJSON export would require:
@@ -249,8 +501,40 @@ impl Water {
             .as_ref()
             .context("No GeoJSON data available. Call run() first.")?;
 
+        let mut geojson = geojson.clone();
+        if let Some(precision) = precision {
+            GeoCore::round_coordinates(&mut geojson, precision);
+        }
+        if let Some(foreign_members) = foreign_members {
+            if let GeoJson::FeatureCollection(fc) = &mut geojson {
+                fc.foreign_members = Some(foreign_members);
+            }
+        }
+        let geojson = &geojson;
+
         let name = name.unwrap_or("water");
 
+        if seq {
+            let output_file = self.output_path.join(format!("{}.geojsonl", name));
+            let features: Vec<&geojson::Feature> = match geojson {
+                GeoJson::FeatureCollection(fc) => fc.features.iter().collect(),
+                GeoJson::Feature(f) => vec![f],
+                GeoJson::Geometry(_) => {
+                    anyhow::bail!("Cannot write a bare Geometry as GeoJSONSeq features")
+                }
+            };
+            let mut lines = String::new();
+            for feature in features {
+                let line = serde_json::to_string(feature).context("Failed to serialize feature")?;
+                lines.push_str(&line);
+                lines.push('\n');
+            }
+            std::fs::write(&output_file, lines)
+                .context(format!("Failed to write GeoJSONSeq file: {:?}", output_file))?;
+            println!("Water saved to: {:?} (as GeoJSONSeq)", output_file);
+            return Ok(());
+        }
+
         // Save as GeoJSON for now (GeoJSON export is complex with GDAL Rust bindings)
         let output_file = self.output_path.join(format!("{}.geojson", name));
         let geojson_str = geojson.to_string();
@@ -269,4 +553,69 @@ impl Water {
     pub fn get_output_path(&self) -> &Path {
         &self.output_path
     }
+
+    /// Burn the water polygons into a GeoTIFF, for use as gridded input to urban-climate
+    /// models. `cell_size` is in geo_core's CRS units, `area_threshold` (0.0-1.0) requires
+    /// a minimum covered fraction of a cell before it is marked filled, and `nature_filter`
+    /// restricts which features participate by their `nature` property.
+    pub fn to_raster(
+        &self,
+        name: Option<&str>,
+        cell_size: f64,
+        area_threshold: Option<f64>,
+        nature_filter: Option<Vec<String>>,
+    ) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let bbox = self
+            .bbox
+            .context("Bounding box must be set before rasterizing")?;
+
+        let mut rasteriser = Rasteriser::new(cell_size);
+        if let Some(threshold) = area_threshold {
+            rasteriser = rasteriser.with_area_threshold(threshold);
+        }
+        if let Some(values) = nature_filter {
+            rasteriser = rasteriser.with_property_filter("nature", values);
+        }
+
+        let name = name.unwrap_or("water");
+        let output_path = self.output_path.join(format!("{}.tif", name));
+        rasteriser.rasterize(geojson, &bbox, self.geo_core.epsg, &output_path)
+    }
+
+    /// Export to any OGR-supported vector format (GeoPackage, Shapefile, GeoJSON, FlatGeobuf, KML, GPX)
+    /// via `ogr2ogr`, reprojecting to geo_core's EPSG on the way out.
+    pub fn to_file(&self, name: Option<&str>, format: OutputFormat) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("water");
+        export::to_file(geojson, &self.output_path, name, format, self.geo_core.epsg)
+    }
+
+    /// Export to GeoParquet via `ogr2ogr -f Parquet`, same `-t_srs` reprojection as [`Water::to_file`]
+    /// but with `compression` passed through as a `-lco COMPRESSION=...` flag.
+    pub fn to_geoparquet(
+        &self,
+        name: Option<&str>,
+        compression: export::GeoParquetCompression,
+    ) -> Result<PathBuf> {
+        let geojson = self
+            .geojson
+            .as_ref()
+            .context("No GeoJSON data available. Call run() first.")?;
+        let name = name.unwrap_or("water");
+        export::to_file_with_options(
+            geojson,
+            &self.output_path,
+            name,
+            OutputFormat::GeoParquet,
+            self.geo_core.epsg,
+            &[compression.as_layer_option()],
+        )
+    }
 }